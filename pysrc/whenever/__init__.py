@@ -61,11 +61,16 @@ __all__ = (
     "YearMonth",
     "MonthDay",
     "IsoWeekDate",
+    "ExpandedDate",
     "Time",
     "Instant",
     "OffsetDateTime",
     "ZonedDateTime",
     "PlainDateTime",
+    "OffsetPeriod",
+    "Interval",
+    "RepeatingInterval",
+    "Pattern",
     # Deltas and time units
     "DateDelta",
     "TimeDelta",
@@ -93,6 +98,7 @@ __all__ = (
     "SkippedTime",
     "RepeatedTime",
     "InvalidOffsetError",
+    "ParseError",
     "ImplicitlyIgnoringDST",
     "TimeZoneNotFoundError",
     # Enums/constants
@@ -109,7 +115,10 @@ __all__ = (
     "patch_current_time",
     "reset_tzpath",
     "clear_tzcache",
+    "derive_tz",
+    "remove_derived_tz",
     "available_timezones",
+    "register_humanize_locale",
     "AnyDelta",
 )
 
@@ -123,9 +132,11 @@ _LAZY_MODULES = {
         "OffsetDateTime",
         "ZonedDateTime",
         "PlainDateTime",
+        "ExpandedDate",
         "DateDelta",
         "TimeDelta",
         "DateTimeDelta",
+        "Pattern",
         # Unit constructors
         "years",
         "months",
@@ -147,6 +158,7 @@ _LAZY_MODULES = {
         "SkippedTime",
         "RepeatedTime",
         "InvalidOffsetError",
+        "ParseError",
         "ImplicitlyIgnoringDST",
         "TimeZoneNotFoundError",
         # Other
@@ -159,9 +171,11 @@ _LAZY_MODULES = {
         "_unpkl_inst",
         "_unpkl_local",
         "_unpkl_offset",
+        "_unpkl_pattern",
         "_unpkl_tdelta",
         "_unpkl_time",
         "_unpkl_utc",
+        "_unpkl_xdate",
         "_unpkl_zoned",
     ),
     f"{__package__}._ideltas": (
@@ -171,11 +185,19 @@ _LAZY_MODULES = {
         "_unpkl_iddelta",
         "_unpkl_idelta",
     ),
+    f"{__package__}._interval": (
+        "Interval",
+        "RepeatingInterval",
+        "_unpkl_interval",
+    ),
     f"{__package__}._utils": (
         "patch_current_time",
         "reset_tzpath",
         "clear_tzcache",
+        "derive_tz",
+        "remove_derived_tz",
         "available_timezones",
+        "register_humanize_locale",
     ),
     f"{__package__}._typing": (
         "RoundModeStr",
@@ -190,6 +212,7 @@ _LAZY_MODULES = {
         "MonthDay",
         "Weekday",
         "IsoWeekDate",
+        "OffsetPeriod",
         "_unpkl_iwd",
         "_unpkl_md",
         "_unpkl_ym",
@@ -213,6 +236,7 @@ TYPE_CHECKING = False
 if TYPE_CHECKING:
     from ._core import *
     from ._ideltas import *
+    from ._interval import *
     from ._shared import *
     from ._typing import *
     from ._utils import *
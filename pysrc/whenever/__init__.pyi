@@ -20,10 +20,12 @@ from datetime import (
 from os import PathLike
 from typing import (
     ClassVar,
+    Generic,
     Iterable,
     Literal,
     Sequence,
     TypeAlias,
+    TypeVar,
     final,
     overload,
     type_check_only,
@@ -35,6 +37,7 @@ else:
     from typing_extensions import deprecated
 
 from typing_extensions import Self, override
+from uuid import UUID
 
 __all__ = [
     # Date and time
@@ -42,11 +45,16 @@ __all__ = [
     "YearMonth",
     "MonthDay",
     "IsoWeekDate",
+    "ExpandedDate",
     "Time",
     "Instant",
     "OffsetDateTime",
     "ZonedDateTime",
     "PlainDateTime",
+    "OffsetPeriod",
+    "Interval",
+    "RepeatingInterval",
+    "Pattern",
     # Deltas and time units
     "DateDelta",
     "TimeDelta",
@@ -74,6 +82,7 @@ __all__ = [
     "SkippedTime",
     "RepeatedTime",
     "InvalidOffsetError",
+    "ParseError",
     "ImplicitlyIgnoringDST",
     "TimeZoneNotFoundError",
     # Enums/constants
@@ -90,7 +99,10 @@ __all__ = [
     "patch_current_time",
     "reset_tzpath",
     "clear_tzcache",
+    "derive_tz",
+    "remove_derived_tz",
     "available_timezones",
+    "register_humanize_locale",
     "AnyDelta",
 ]
 
@@ -187,6 +199,20 @@ class Date(_DateOrTimeMixin):
     def days_in_month(self) -> int: ...
     def days_in_year(self) -> int: ...
     def in_leap_year(self) -> bool: ...
+    def to_julian_day(self) -> int: ...
+    @classmethod
+    def from_julian_day(cls, n: int, /) -> Self: ...
+    def to_mjd(self) -> int: ...
+    @classmethod
+    def from_mjd(cls, n: int, /) -> Self: ...
+    def to_rata_die(self) -> int: ...
+    @classmethod
+    def from_rata_die(cls, n: int, /) -> Self: ...
+    def to_excel_serial(self, *, epoch_1904: bool = False) -> int: ...
+    @classmethod
+    def from_excel_serial(
+        cls, n: int, /, *, epoch_1904: bool = False
+    ) -> Self: ...
     def next_day(self) -> Date: ...
     def prev_day(self) -> Date: ...
     def nth_weekday_of_month(self, n: int, weekday: Weekday, /) -> Date: ...
@@ -219,10 +245,27 @@ class Date(_DateOrTimeMixin):
     @deprecated("Use Date() constructor instead")
     def from_py_date(cls, d: _date, /) -> Self: ...
     def format_iso(self, *, basic: bool = False) -> str: ...
-    def format(self, pattern: str, /) -> str: ...
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+    ) -> str: ...
     def __format__(self, spec: str, /) -> str: ...
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> Date: ...
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> Date: ...
     def replace(
         self, *, year: int = ..., month: int = ..., day: int = ...
     ) -> Self: ...
@@ -364,6 +407,121 @@ class IsoWeekDate(_DateOrTimeMixin):
     ) -> Self: ...
     def format_iso(self, *, basic: bool = False) -> str: ...
 
+@final
+class ExpandedDate(_DateOrTimeMixin):
+    @overload
+    def __init__(self, year: int, month: int, day: int, /) -> None: ...
+    @overload
+    def __init__(self, iso_string: str, /) -> None: ...
+    MIN: ClassVar[Self]
+    MAX: ClassVar[Self]
+    @classmethod
+    def from_date(cls, d: Date, /) -> Self: ...
+    def date(self) -> Date: ...
+    @property
+    def year(self) -> int: ...
+    @property
+    def month(self) -> int: ...
+    @property
+    def day(self) -> int: ...
+    def day_of_week(self) -> Weekday: ...
+    def day_of_year(self) -> int: ...
+    def days_in_month(self) -> int: ...
+    def days_in_year(self) -> int: ...
+    def in_leap_year(self) -> bool: ...
+    def replace(
+        self, /, *, year: int = ..., month: int = ..., day: int = ...
+    ) -> Self: ...
+    @overload
+    def add(
+        self, *, years: int = 0, months: int = 0, weeks: int = 0, days: int = 0
+    ) -> Self: ...
+    @overload
+    def add(self, delta: ItemizedDateDelta, /) -> Self: ...
+    @overload
+    def subtract(
+        self, *, years: int = 0, months: int = 0, weeks: int = 0, days: int = 0
+    ) -> Self: ...
+    @overload
+    def subtract(self, delta: ItemizedDateDelta, /) -> Self: ...
+    @overload
+    def since(
+        self,
+        other: Self,
+        /,
+        *,
+        total: Literal["years", "months", "weeks", "days"],
+    ) -> float: ...
+    @overload
+    def since(
+        self,
+        other: Self,
+        /,
+        *,
+        in_units: Sequence[Literal["years", "months", "weeks", "days"]],
+        round_increment: int = ...,
+        round_mode: Literal[
+            "ceil",
+            "expand",
+            "floor",
+            "trunc",
+            "half_ceil",
+            "half_expand",
+            "half_floor",
+            "half_trunc",
+            "half_even",
+        ] = ...,
+    ) -> ItemizedDateDelta: ...
+    @overload
+    def until(
+        self,
+        other: Self,
+        /,
+        *,
+        total: Literal["years", "months", "weeks", "days"],
+    ) -> float: ...
+    @overload
+    def until(
+        self,
+        other: Self,
+        /,
+        *,
+        in_units: Sequence[Literal["years", "months", "weeks", "days"]],
+        round_increment: int = ...,
+        round_mode: Literal[
+            "ceil",
+            "expand",
+            "floor",
+            "trunc",
+            "half_ceil",
+            "half_expand",
+            "half_floor",
+            "half_trunc",
+            "half_even",
+        ] = ...,
+    ) -> ItemizedDateDelta: ...
+    def format_iso(self, *, basic: bool = False) -> str: ...
+
+@final
+class OffsetPeriod:
+    @property
+    def start(self) -> Instant | None: ...
+    @property
+    def end(self) -> Instant | None: ...
+    @property
+    def offset(self) -> TimeDelta: ...
+    @property
+    def std_offset(self) -> TimeDelta: ...
+    @property
+    def dst_offset(self) -> TimeDelta: ...
+    @property
+    def is_dst(self) -> bool: ...
+    @property
+    def abbrev(self) -> str: ...
+    @property
+    def observes_dst(self) -> bool: ...
+    def __hash__(self) -> int: ...
+
 @final
 class Time(_DateOrTimeMixin):
     @overload
@@ -462,14 +620,33 @@ class Time(_DateOrTimeMixin):
         ] = "auto",
         basic: bool = False,
     ) -> str: ...
-    def format(self, pattern: str, /) -> str: ...
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+    ) -> str: ...
     def __format__(self, spec: str, /) -> str: ...
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> Time: ...
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+        lenient: bool = False,
+    ) -> Time: ...
 
 @type_check_only
 class _DeltaMixin(_ISOMixin):
     ZERO: ClassVar[Self]
+    def format_iso(
+        self, *, alternative: bool = False, basic: bool = False
+    ) -> str: ...
     def __bool__(self) -> bool: ...
     def __neg__(self) -> Self: ...
     def __pos__(self) -> Self: ...
@@ -534,6 +711,47 @@ class TimeDelta(_DeltaMixin, _OrderMixin):
         relative_to: ZonedDateTime | PlainDateTime | OffsetDateTime = ...,
         days_assumed_24h_ok: bool = ...,
     ) -> int: ...
+    def humanize(
+        self,
+        *,
+        granularity: Literal[
+            "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+        days_assumed_24h_ok: bool = False,
+    ) -> str: ...
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        style: Literal["pattern", "spreadsheet"] = "pattern",
+    ) -> str: ...
+    def __format__(self, spec: str, /) -> str: ...
+    @classmethod
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        lenient: bool = False,
+        style: Literal["pattern", "spreadsheet"] = "pattern",
+    ) -> TimeDelta: ...
+    def format_go(self) -> str: ...
+    @classmethod
+    def parse_go(cls, s: str, /) -> TimeDelta: ...
+    def to_cbor(self) -> bytes: ...
+    @classmethod
+    def from_cbor(cls, data: bytes, /) -> TimeDelta: ...
+    def to_msgpack(self) -> bytes: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes, /) -> TimeDelta: ...
+    @classmethod
+    def parse_human(
+        cls, s: str, /, *, days_assumed_24h_ok: bool = False
+    ) -> TimeDelta: ...
     @deprecated("Use total('days') instead")
     def in_days_of_24h(self) -> float: ...
     @deprecated("Use total('hours') instead")
@@ -1026,6 +1244,16 @@ class ItemizedDelta(
         *,
         relative_to: ZonedDateTime | PlainDateTime | OffsetDateTime,
     ) -> float: ...
+    def humanize(
+        self,
+        *,
+        relative_to: ZonedDateTime | PlainDateTime | OffsetDateTime,
+        granularity: Literal[
+            "years", "months", "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+    ) -> str: ...
     def __iter__(
         self,
     ) -> Iterator[
@@ -1675,13 +1903,90 @@ class Instant(_PyDateTimeMixin, _ExactTime):
     def from_timestamp_millis(cls, i: int, /) -> Self: ...
     @classmethod
     def from_timestamp_nanos(cls, i: int, /) -> Self: ...
+    def timestamp_micros(self) -> int: ...
+    @classmethod
+    def from_timestamp_micros(cls, i: int, /) -> Self: ...
+    def to_filetime(self) -> int: ...
+    @classmethod
+    def from_filetime(cls, i: int, /) -> Self: ...
+    def to_dotnet_ticks(self) -> int: ...
+    @classmethod
+    def from_dotnet_ticks(cls, i: int, /) -> Self: ...
+    def to_ntp(self) -> int: ...
+    @classmethod
+    def from_ntp(cls, i: int, /) -> Self: ...
+    def to_cocoa(self) -> float: ...
+    @classmethod
+    def from_cocoa(cls, i: int | float, /) -> Self: ...
+    def to_postgres_micros(self) -> int: ...
+    @classmethod
+    def from_postgres_micros(cls, i: int, /) -> Self: ...
+    def to_gps_nanos(self) -> int: ...
+    @classmethod
+    def from_gps_nanos(cls, i: int, /) -> Self: ...
+    def to_uuid7(self) -> UUID: ...
+    @classmethod
+    def from_uuid7(cls, u: UUID | bytes, /) -> Self: ...
+    def to_ulid(self) -> str: ...
+    @classmethod
+    def from_ulid(cls, s: str, /) -> Self: ...
+    def to_snowflake(
+        self, *, epoch: Instant | OffsetDateTime | ZonedDateTime
+    ) -> int: ...
+    @classmethod
+    def from_snowflake(
+        cls,
+        i: int,
+        /,
+        *,
+        epoch: Instant | OffsetDateTime | ZonedDateTime,
+    ) -> Self: ...
+    def to_cbor(self, *, tag: Literal[0, 1, 1001] = 1) -> bytes: ...
+    @classmethod
+    def from_cbor(cls, data: bytes, /) -> Self: ...
+    def to_msgpack(self) -> bytes: ...
+    @classmethod
+    def from_msgpack(cls, data: bytes, /) -> Self: ...
+    def to_julian_day(self) -> float: ...
+    @classmethod
+    def from_julian_day(cls, n: float, /) -> Self: ...
+    def to_mjd(self) -> float: ...
+    @classmethod
+    def from_mjd(cls, n: float, /) -> Self: ...
+    def to_rata_die(self) -> float: ...
+    @classmethod
+    def from_rata_die(cls, n: float, /) -> Self: ...
+    def to_excel_serial(self, *, epoch_1904: bool = False) -> float: ...
+    @classmethod
+    def from_excel_serial(
+        cls, n: float, /, *, epoch_1904: bool = False
+    ) -> Self: ...
     def format_rfc2822(self) -> str: ...
     @classmethod
-    def parse_rfc2822(cls, s: str, /) -> Self: ...
-    def format(self, pattern: str, /) -> str: ...
+    def parse_rfc2822(cls, s: str, /, *, strict: bool = False) -> Self: ...
+    @classmethod
+    def parse_http_date(cls, s: str, /) -> Self: ...
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+    ) -> str: ...
     def __format__(self, spec: str, /) -> str: ...
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> Instant: ...
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> Instant: ...
     @overload
     def add(self, d: TimeDelta, /) -> Self: ...
     @overload
@@ -1758,6 +2063,17 @@ class Instant(_PyDateTimeMixin, _ExactTime):
             "half_even",
         ] = "half_even",
     ) -> Self: ...
+    def humanize(
+        self,
+        *,
+        relative_to: Instant | OffsetDateTime | ZonedDateTime = ...,
+        granularity: Literal[
+            "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+        days_assumed_24h_ok: bool = False,
+    ) -> str: ...
 
 @final
 class OffsetDateTime(_PyDateTimeMixin, _ExactAndLocalTime):
@@ -1822,11 +2138,31 @@ class OffsetDateTime(_PyDateTimeMixin, _ExactAndLocalTime):
     def parse_strptime(cls, s: str, /, *, format: str) -> Self: ...
     def format_rfc2822(self) -> str: ...
     @classmethod
-    def parse_rfc2822(cls, s: str, /) -> Self: ...
-    def format(self, pattern: str, /) -> str: ...
+    def parse_rfc2822(cls, s: str, /, *, strict: bool = False) -> Self: ...
+    def to_cbor(self) -> bytes: ...
+    @classmethod
+    def from_cbor(cls, data: bytes, /) -> Self: ...
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+    ) -> str: ...
     def __format__(self, spec: str, /) -> str: ...
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> OffsetDateTime: ...
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> OffsetDateTime: ...
     def replace(
         self,
         *,
@@ -2152,9 +2488,22 @@ class ZonedDateTime(_PyDateTimeMixin, _ExactAndLocalTime):
     def prev_transition(self) -> ZonedDateTime | None: ...
     def dst_offset(self) -> TimeDelta: ...
     def tz_abbrev(self) -> str: ...
+    def offset_period(self) -> OffsetPeriod: ...
     def day_length(self) -> TimeDelta: ...
+    def humanize(
+        self,
+        *,
+        relative_to: ZonedDateTime = ...,
+        granularity: Literal[
+            "years", "months", "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+        relative_day: bool = False,
+    ) -> str: ...
     @deprecated('Use start_of("day") instead')
     def start_of_day(self) -> Self: ...
+    def format_rfc2822(self, *, zone_comment: bool = False) -> str: ...
     def format_iso(
         self,
         *,
@@ -2171,7 +2520,14 @@ class ZonedDateTime(_PyDateTimeMixin, _ExactAndLocalTime):
         sep: Literal["T", " "] = "T",
         tz: Literal["always", "never", "auto"] = "always",
     ) -> str: ...
-    def format(self, pattern: str, /) -> str: ...
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+    ) -> str: ...
     def __format__(self, spec: str, /) -> str: ...
     @classmethod
     def parse(
@@ -2179,10 +2535,14 @@ class ZonedDateTime(_PyDateTimeMixin, _ExactAndLocalTime):
         s: str,
         /,
         *,
-        format: str,
+        format: str | Sequence[str],
+        locale: str | None = None,
         disambiguate: Literal[
             "compatible", "raise", "earlier", "later"
         ] = "compatible",
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
     ) -> ZonedDateTime: ...
     def __add__(
         self,
@@ -2243,10 +2603,27 @@ class PlainDateTime(_PyDateTimeMixin, _DateOrTimeMixin, _LocalTime):
     @classmethod
     @deprecated("Use parse() with a pattern string instead")
     def parse_strptime(cls, s: str, /, *, format: str) -> Self: ...
-    def format(self, pattern: str, /) -> str: ...
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+    ) -> str: ...
     def __format__(self, spec: str, /) -> str: ...
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> PlainDateTime: ...
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> PlainDateTime: ...
     def replace(
         self,
         *,
@@ -2450,6 +2827,85 @@ class PlainDateTime(_PyDateTimeMixin, _DateOrTimeMixin, _LocalTime):
     @overload
     def __sub__(self, other: Self, /) -> TimeDelta: ...
 
+_P = TypeVar(
+    "_P",
+    Date,
+    Time,
+    PlainDateTime,
+    OffsetDateTime,
+    ZonedDateTime,
+    Instant,
+    TimeDelta,
+)
+
+@final
+class Pattern(Generic[_P]):
+    def __init__(
+        self,
+        pattern: str,
+        type: type[_P],
+        /,
+        *,
+        style: Literal["pattern", "strftime", "spreadsheet"] = "pattern",
+    ) -> None: ...
+    @property
+    def pattern(self) -> str: ...
+    def format(self, value: _P, /, *, locale: str | None = None) -> str: ...
+    def parse(
+        self,
+        s: str,
+        /,
+        *,
+        locale: str | None = None,
+        disambiguate: (
+            Literal["compatible", "raise", "earlier", "later"] | None
+        ) = None,
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> _P: ...
+    def __hash__(self) -> int: ...
+
+_I = TypeVar("_I", Date, PlainDateTime, OffsetDateTime, Instant)
+
+@final
+class Interval(Generic[_I]):
+    @overload
+    def __init__(self, start: _I, end: _I) -> None: ...
+    @overload
+    def __init__(
+        self, start: _I, *, duration: ItemizedDelta | ItemizedDateDelta
+    ) -> None: ...
+    @overload
+    def __init__(
+        self, *, end: _I, duration: ItemizedDelta | ItemizedDateDelta
+    ) -> None: ...
+    @property
+    def start(self) -> _I: ...
+    @property
+    def end(self) -> _I: ...
+    @property
+    def duration(self) -> ItemizedDelta | ItemizedDateDelta | None: ...
+    def __contains__(self, value: object, /) -> bool: ...
+    @classmethod
+    def parse_iso(cls, s: str, type: type[_I], /) -> Interval[_I]: ...
+    def format_iso(self, *, abbreviate: bool = False) -> str: ...
+    def __hash__(self) -> int: ...
+
+@final
+class RepeatingInterval(Generic[_I]):
+    def __init__(
+        self, interval: Interval[_I], repetitions: int | None = None
+    ) -> None: ...
+    @property
+    def interval(self) -> Interval[_I]: ...
+    @property
+    def repetitions(self) -> int | None: ...
+    def __iter__(self) -> Iterator[Interval[_I]]: ...
+    @classmethod
+    def parse_iso(cls, s: str, type: type[_I], /) -> RepeatingInterval[_I]: ...
+    def format_iso(self) -> str: ...
+    def __hash__(self) -> int: ...
+
 @final
 class RepeatedTime(ValueError): ...
 
@@ -2459,6 +2915,19 @@ class SkippedTime(ValueError): ...
 @final
 class InvalidOffsetError(ValueError): ...
 
+class ParseError(ValueError):
+    input: str
+    position: int | None
+    expected: str | None
+    def __init__(
+        self,
+        msg: str,
+        input: str,
+        position: int | None = None,
+        expected: str | None = None,
+    ) -> None: ...
+    def render(self) -> str: ...
+
 @final
 @deprecated(
     "This exception is longer raised. Replaced by warnings and context managers to ignore them."
@@ -2518,7 +2987,25 @@ def reset_tzpath(
     target: Iterable[str | PathLike[str]] | None = None, /
 ) -> None: ...
 def clear_tzcache(*, only_keys: Iterable[str] | None = None) -> None: ...
+def derive_tz(
+    key: str, /, base: str, *, start: _ExactTime, rule: str | TimeDelta
+) -> None: ...
+def remove_derived_tz(key: str, /) -> bool: ...
 def available_timezones() -> set[str]: ...
+def register_humanize_locale(
+    tag: str,
+    /,
+    *,
+    now: str,
+    future: Mapping[str, str | tuple[str, ...]],
+    past: Mapping[str, str | tuple[str, ...]],
+    yesterday: str,
+    tomorrow: str,
+    at_time: str,
+    plural: Literal[
+        "one_other", "east_slavic", "polish", "czech", "other"
+    ] = "one_other",
+) -> None: ...
 def reset_system_tz() -> None: ...
 
 class WheneverWarning(UserWarning): ...
@@ -0,0 +1,254 @@
+"""Byte-level codecs for the CBOR (RFC 8949) date and time tags,
+and the MessagePack timestamp extension type.
+
+These mirror ``common/cbor.rs`` and ``common/msgpack.rs`` in the Rust
+extension. Decoding functions raise ``ValueError`` on invalid input.
+"""
+
+from __future__ import annotations
+
+import re
+from math import isfinite, nextafter
+from struct import pack, unpack, unpack_from
+
+TAG_RFC3339 = 0
+TAG_EPOCH = 1
+TAG_EXTENDED_TIME = 1001
+TAG_DURATION = 1002
+
+CBOR_INVALID = "invalid CBOR data"
+MSGPACK_INVALID = "invalid MessagePack timestamp"
+
+# The strict subset of ISO 8601 allowed by RFC 3339, with fractions
+# of at most nine digits (the precision we support)
+_RFC3339 = re.compile(
+    r"\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d{1,9})?"
+    r"([Zz]|[+-]\d{2}:\d{2})",
+    re.ASCII,
+)
+
+_MAJOR_UINT = 0
+_MAJOR_NEGINT = 1
+_MAJOR_BYTES = 2
+_MAJOR_TEXT = 3
+_MAJOR_ARRAY = 4
+_MAJOR_MAP = 5
+_MAJOR_TAG = 6
+_MAJOR_SIMPLE = 7
+
+# Map keys of the extended time and duration tags
+_KEY_SECONDS = 1
+_KEY_NANOS = -9
+# Fraction keys, with their size in nanoseconds
+_FRACTION_KEYS = {-3: 1_000_000, -6: 1_000, -9: 1}
+
+_FLOAT_FORMATS = {25: ">e", 26: ">f", 27: ">d"}
+
+_NS_PER_SEC = 1_000_000_000
+
+
+def _head(major: int, arg: int) -> bytes:
+    m = major << 5
+    if arg < 24:
+        return bytes([m | arg])
+    elif arg < 1 << 8:
+        return pack(">BB", m | 24, arg)
+    elif arg < 1 << 16:
+        return pack(">BH", m | 25, arg)
+    elif arg < 1 << 32:
+        return pack(">BI", m | 26, arg)
+    return pack(">BQ", m | 27, arg)
+
+
+def _int(n: int) -> bytes:
+    # CBOR encodes negative integers as -1 - n
+    return _head(_MAJOR_UINT, n) if n >= 0 else _head(_MAJOR_NEGINT, ~n)
+
+
+def cbor_text(s: str) -> bytes:
+    """Encode as tag 0 (RFC 3339 text)"""
+    encoded = s.encode()
+    return (
+        _head(_MAJOR_TAG, TAG_RFC3339)
+        + _head(_MAJOR_TEXT, len(encoded))
+        + encoded
+    )
+
+
+def cbor_epoch(secs: int, nanos: int) -> bytes:
+    """Encode as tag 1. An integer if there are no fractional seconds,
+    otherwise a 64-bit float (which may lose precision, but is never
+    rounded up into the next second)"""
+    head = _head(_MAJOR_TAG, TAG_EPOCH)
+    if nanos == 0:
+        return head + _int(secs)
+    f = secs + nanos / 1e9
+    if f >= secs + 1:
+        f = nextafter(f, secs)
+    return head + pack(">Bd", _MAJOR_SIMPLE << 5 | 27, f)
+
+
+def cbor_secs_nanos(tag: int, secs: int, nanos: int) -> bytes:
+    """Encode as a map of seconds and (optional) nanoseconds,
+    as used by tags 1001 and 1002"""
+    out = (
+        _head(_MAJOR_TAG, tag)
+        + _head(_MAJOR_MAP, 2 if nanos else 1)
+        + _int(_KEY_SECONDS)
+        + _int(secs)
+    )
+    if nanos:
+        out += _int(_KEY_NANOS) + _int(nanos)
+    return out
+
+
+class _Reader:
+    __slots__ = ("data", "pos")
+
+    def __init__(self, data: bytes) -> None:
+        self.data = data
+        self.pos = 0
+
+    def take(self, n: int) -> bytes:
+        end = self.pos + n
+        if end > len(self.data):
+            raise ValueError(CBOR_INVALID)
+        chunk = self.data[self.pos : end]
+        self.pos = end
+        return chunk
+
+    def head(self) -> tuple[int, int, int]:
+        """Read the major type, additional info, and argument of a head"""
+        initial = self.take(1)[0]
+        major, info = initial >> 5, initial & 0x1F
+        if info < 24:
+            arg = info
+        elif info < 28:
+            arg = int.from_bytes(self.take(1 << (info - 24)), "big")
+        else:
+            # Reserved values and indefinite lengths
+            raise ValueError(CBOR_INVALID)
+        return major, info, arg
+
+    def number(self) -> int | float:
+        major, info, arg = self.head()
+        if major == _MAJOR_UINT and arg < 1 << 63:
+            return arg
+        elif major == _MAJOR_NEGINT and arg < 1 << 63:
+            return ~arg
+        elif major == _MAJOR_SIMPLE and info in _FLOAT_FORMATS:
+            fmt = _FLOAT_FORMATS[info]
+            return unpack(fmt, arg.to_bytes(1 << (info - 24), "big"))[0]
+        raise ValueError(CBOR_INVALID)
+
+    def integer(self) -> int:
+        n = self.number()
+        if type(n) is not int:
+            raise ValueError(CBOR_INVALID)
+        return n
+
+    def text(self) -> str:
+        major, _, length = self.head()
+        if major != _MAJOR_TEXT:
+            raise ValueError(CBOR_INVALID)
+        return self.take(length).decode()
+
+    def skip(self) -> None:
+        """Skip a complete data item, including any nested items"""
+        # Counting the pending items (instead of recursing)
+        # keeps deeply nested input from exceeding the recursion limit
+        pending = 1
+        while pending:
+            pending -= 1
+            major, _, arg = self.head()
+            if major in (_MAJOR_BYTES, _MAJOR_TEXT):
+                self.take(arg)
+            elif major == _MAJOR_ARRAY:
+                pending += arg
+            elif major == _MAJOR_MAP:
+                pending += arg * 2
+            elif major == _MAJOR_TAG:
+                pending += 1
+
+    def secs_nanos(self) -> int | float:
+        """Read the map of a tag 1001 or 1002 item, as total nanoseconds,
+        or float seconds if the base time is a float (without fraction).
+        Unknown keys are ignored if they're elective (non-negative),
+        but rejected if they're critical (negative)."""
+        major, _, length = self.head()
+        if major != _MAJOR_MAP:
+            raise ValueError(CBOR_INVALID)
+        secs: int | float | None = None
+        nanos = None
+        for _ in range(length):
+            key = self.integer()
+            if key == _KEY_SECONDS and secs is None:
+                secs = self.number()
+            elif key in _FRACTION_KEYS and nanos is None:
+                scale = _FRACTION_KEYS[key]
+                fraction = self.integer()
+                if not 0 <= fraction < _NS_PER_SEC // scale:
+                    raise ValueError(CBOR_INVALID)
+                nanos = fraction * scale
+            elif key >= 0 and key != _KEY_SECONDS:
+                self.skip()
+            else:
+                # Duplicate or unknown critical keys
+                raise ValueError(CBOR_INVALID)
+        if secs is None:
+            raise ValueError(CBOR_INVALID)
+        elif type(secs) is float:
+            if nanos is not None or not isfinite(secs):
+                raise ValueError(CBOR_INVALID)
+            return secs
+        return secs * _NS_PER_SEC + (nanos or 0)
+
+
+def cbor_decode(data: bytes) -> tuple[int, str | int | float]:
+    """Decode a single tagged item. The content is RFC 3339 text
+    (validated and uppercased) for tag 0, a number for tag 1,
+    and total nanoseconds (or float seconds) for tags 1001 and 1002."""
+    reader = _Reader(data)
+    major, _, tag = reader.head()
+    content: str | int | float
+    if major != _MAJOR_TAG:
+        raise ValueError(CBOR_INVALID)
+    elif tag == TAG_RFC3339:
+        content = reader.text()
+        if not _RFC3339.fullmatch(content):
+            raise ValueError(CBOR_INVALID)
+        content = content.upper()
+    elif tag == TAG_EPOCH:
+        content = reader.number()
+    elif tag in (TAG_EXTENDED_TIME, TAG_DURATION):
+        content = reader.secs_nanos()
+    else:
+        raise ValueError(CBOR_INVALID)
+    if reader.pos != len(data):
+        raise ValueError(CBOR_INVALID)
+    return tag, content
+
+
+def msgpack_encode(secs: int, nanos: int) -> bytes:
+    """Encode in the smallest timestamp extension variant which fits"""
+    if secs >> 34 == 0:
+        if nanos == 0 and secs >> 32 == 0:
+            return pack(">BBI", 0xD6, 0xFF, secs)
+        return pack(">BBQ", 0xD7, 0xFF, nanos << 34 | secs)
+    return pack(">BBBIq", 0xC7, 12, 0xFF, nanos, secs)
+
+
+def msgpack_decode(data: bytes) -> int:
+    """Decode a timestamp extension value into total nanoseconds"""
+    if len(data) == 6 and data[:2] == b"\xd6\xff":
+        secs, nanos = unpack_from(">I", data, 2)[0], 0
+    elif len(data) == 10 and data[:2] == b"\xd7\xff":
+        n = unpack_from(">Q", data, 2)[0]
+        secs, nanos = n & ((1 << 34) - 1), n >> 34
+    elif len(data) == 15 and data[:3] == b"\xc7\x0c\xff":
+        nanos, secs = unpack_from(">Iq", data, 3)
+    else:
+        raise ValueError(MSGPACK_INVALID)
+    if nanos >= _NS_PER_SEC:
+        raise ValueError(MSGPACK_INVALID)
+    return secs * _NS_PER_SEC + nanos
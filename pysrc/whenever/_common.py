@@ -102,6 +102,62 @@ class WheneverDeprecationWarning(WheneverWarning):
     """
 
 
+class ParseError(ValueError):
+    """Raised when a string can't be parsed.
+
+    It's a subclass of :class:`ValueError`, so existing ``except ValueError``
+    handlers keep working. It has extra attributes for reporting
+    the failure to users, e.g. in form validation:
+
+    - ``input``: the string that failed to parse
+    - ``position``: the index in ``input`` where the mismatching
+      field or literal starts, or ``None`` if the failure isn't
+      tied to a single location (e.g. a value out of range)
+    - ``expected``: the field or literal that was expected
+      at ``position``, or ``None`` if unknown (e.g. for trailing text)
+
+    >>> try:
+    ...     Date.parse("2024/03/15", format="YYYY-MM-DD")
+    ... except ParseError as e:
+    ...     print(e.render())
+    Expected '-' at position 4, got '/'
+      2024/03/15
+          ^
+    """
+
+    input: str
+    position: int | None
+    expected: str | None
+
+    def __init__(
+        self,
+        msg: str,
+        input: str,
+        position: int | None = None,
+        expected: str | None = None,
+    ) -> None:
+        super().__init__(msg)
+        self.input = input
+        self.position = position
+        self.expected = expected
+
+    def render(self) -> str:
+        """The error message, followed by the input with a caret (``^``)
+        pointing at the position where parsing failed.
+
+        If the position is unknown, only the message is returned.
+        """
+        if self.position is None:
+            return str(self)
+        return f"{self}\n  {self.input}\n  {' ' * self.position}^"
+
+    def __reduce__(self) -> tuple[object, ...]:
+        return (
+            type(self),
+            (str(self), self.input, self.position, self.expected),
+        )
+
+
 _T = TypeVar("_T")
 
 
@@ -6,9 +6,12 @@ try:  # pragma: no cover
     from ._whenever import (
         _clear_tz_cache as _clear_tz_cache,
         _clear_tz_cache_by_keys as _clear_tz_cache_by_keys,
+        _derive_tz as _derive_tz,
         _get_tzpath as _get_tzpath,
         _patch_time_frozen as _patch_time_frozen,
         _patch_time_keep_ticking as _patch_time_keep_ticking,
+        _register_humanize_locale as _register_humanize_locale,
+        _remove_derived_tz as _remove_derived_tz,
         _set_tzpath as _set_tzpath,
         _unpatch_time as _unpatch_time,
         _unpkl_date,
@@ -17,9 +20,11 @@ try:  # pragma: no cover
         _unpkl_inst,
         _unpkl_local,
         _unpkl_offset,
+        _unpkl_pattern,
         _unpkl_tdelta,
         _unpkl_time,
         _unpkl_utc,
+        _unpkl_xdate,
         _unpkl_zoned,
     )
 
@@ -33,9 +38,12 @@ except ModuleNotFoundError as e:
     from ._pywhenever import (
         _clear_tz_cache,
         _clear_tz_cache_by_keys,
+        _derive_tz,
         _get_tzpath,
         _patch_time_frozen,
         _patch_time_keep_ticking,
+        _register_humanize_locale,
+        _remove_derived_tz,
         _set_tzpath,
         _unpatch_time,
         _unpkl_date,
@@ -44,9 +52,11 @@ except ModuleNotFoundError as e:
         _unpkl_inst,
         _unpkl_local,
         _unpkl_offset,
+        _unpkl_pattern,
         _unpkl_tdelta,
         _unpkl_time,
         _unpkl_utc,
+        _unpkl_xdate,
         _unpkl_zoned,
     )
 
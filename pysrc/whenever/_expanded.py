@@ -0,0 +1,679 @@
+"""Pure-Python implementation of ExpandedDate.
+
+It covers the ISO 8601 expanded year range, which is too wide for the
+compact representation used by :class:`~whenever.Date`.
+The Rust extension implements the same type; this module is the fallback
+when the extension isn't available.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+from struct import pack, unpack
+from typing import TYPE_CHECKING, ClassVar, no_type_check, overload
+
+from ._common import (
+    SPHINX_RUNNING,
+    UNSET,
+    ParseError,
+    _Base,
+    add_alternate_constructors,
+    final,
+)
+from ._math import DATE_DELTA_UNITS, custom_round, days_in_month, is_leap
+from ._parse import _strict_int
+from ._shared import Weekday
+from ._typing import DateDeltaUnitStr, RoundModeStr
+
+if TYPE_CHECKING:
+    from . import _pywhenever as _whenever
+    from ._ideltas import ItemizedDateDelta
+    from ._pywhenever import Date
+else:
+    import whenever as _whenever
+
+_object_new = object.__new__
+
+MAX_YEAR = 999_999
+MIN_YEAR = -MAX_YEAR
+
+
+@final
+class ExpandedDate(_Base):
+    """A date in the proleptic Gregorian calendar, with an expanded
+    year range of -999,999 to 999,999.
+
+    Use this type for historical or astronomical dates beyond
+    the 1--9999 range of :class:`~whenever.Date`.
+    Years are numbered astronomically: year 0 is 1 BCE,
+    year -1 is 2 BCE, and so on.
+
+    >>> ExpandedDate(-44, 3, 15)
+    ExpandedDate("-000044-03-15")
+    >>> ExpandedDate(12025, 1, 1)
+    ExpandedDate("+012025-01-01")
+
+    Can also be constructed from an ISO 8601 string:
+
+    >>> ExpandedDate("+012025-01-01")
+    ExpandedDate("+012025-01-01")
+    """
+
+    __slots__ = ("_year", "_month", "_day")
+
+    MIN: ClassVar[ExpandedDate]
+    """The minimum possible expanded date"""
+    MAX: ClassVar[ExpandedDate]
+    """The maximum possible expanded date"""
+
+    if not TYPE_CHECKING:
+
+        @overload
+        def __init__(self, iso_string: str, /) -> None: ...
+
+        @overload
+        def __init__(self, year: int, month: int, day: int, /) -> None: ...
+
+    def __init__(self, year: int, month: int, day: int, /) -> None:
+        _check_ymd(year, month, day)
+        self._year = year
+        self._month = month
+        self._day = day
+
+    __init__ = add_alternate_constructors(__init__)
+
+    def _init_from_iso(self, s: str) -> None:
+        self._year, self._month, self._day = _parse_expanded_date(s)
+
+    @classmethod
+    def _from_parts_unchecked(
+        cls, year: int, month: int, day: int
+    ) -> ExpandedDate:
+        self = _object_new(cls)
+        self._year = year
+        self._month = month
+        self._day = day
+        return self
+
+    @classmethod
+    def _from_unix_days(cls, days: int) -> ExpandedDate:
+        year, month, day = _civil_from_days(days)
+        if not MIN_YEAR <= year <= MAX_YEAR:
+            raise ValueError("Resulting date out of range")
+        return cls._from_parts_unchecked(year, month, day)
+
+    def _unix_days(self) -> int:
+        return _days_from_civil(self._year, self._month, self._day)
+
+    @classmethod
+    def from_date(cls, d: Date, /) -> ExpandedDate:
+        """Create an expanded date from a :class:`~whenever.Date`
+
+        >>> ExpandedDate.from_date(Date(2024, 3, 15))
+        ExpandedDate("2024-03-15")
+        """
+        return cls._from_parts_unchecked(d.year, d.month, d.day)
+
+    def date(self) -> Date:
+        """Convert to a :class:`~whenever.Date`
+
+        >>> ExpandedDate(2024, 3, 15).date()
+        Date("2024-03-15")
+
+        Raises
+        ------
+        ValueError
+            If the year is outside the 1--9999 range supported by
+            :class:`~whenever.Date`.
+        """
+        if not 1 <= self._year <= 9999:
+            raise ValueError(
+                f"Year {self._year} is out of range for Date (1-9999)"
+            )
+        return _whenever.Date(self._year, self._month, self._day)
+
+    @property
+    def year(self) -> int:
+        """The (astronomical) year, which may be zero or negative
+
+        >>> ExpandedDate(-44, 3, 15).year
+        -44
+        """
+        return self._year
+
+    @property
+    def month(self) -> int:
+        """The month (1--12)
+
+        >>> ExpandedDate(-44, 3, 15).month
+        3
+        """
+        return self._month
+
+    @property
+    def day(self) -> int:
+        """The day of the month
+
+        >>> ExpandedDate(-44, 3, 15).day
+        15
+        """
+        return self._day
+
+    def day_of_week(self) -> Weekday:
+        """The day of the week
+
+        >>> ExpandedDate(-44, 3, 15).day_of_week()
+        Weekday.THURSDAY
+        """
+        return Weekday((self._unix_days() + 3) % 7 + 1)
+
+    def day_of_year(self) -> int:
+        """The day of the year (1--366)
+
+        >>> ExpandedDate(-44, 3, 15).day_of_year()
+        75
+        """
+        return self._unix_days() - _days_from_civil(self._year, 1, 1) + 1
+
+    def days_in_month(self) -> int:
+        """The number of days in the month
+
+        >>> ExpandedDate(-4, 2, 1).days_in_month()
+        29
+        """
+        return days_in_month(self._year, self._month)
+
+    def days_in_year(self) -> int:
+        """The number of days in the year (365 or 366)
+
+        >>> ExpandedDate(-4, 1, 1).days_in_year()
+        366
+        """
+        return 366 if is_leap(self._year) else 365
+
+    def in_leap_year(self) -> bool:
+        """Whether the year is a leap year in the proleptic
+        Gregorian calendar
+
+        >>> ExpandedDate(0, 1, 1).in_leap_year()
+        True
+        >>> ExpandedDate(-100, 1, 1).in_leap_year()
+        False
+        """
+        return is_leap(self._year)
+
+    def replace(
+        self,
+        /,
+        *,
+        year: int = UNSET,
+        month: int = UNSET,
+        day: int = UNSET,
+    ) -> ExpandedDate:
+        """Return a new :class:`ExpandedDate` with the given fields replaced
+
+        >>> ExpandedDate(-44, 3, 15).replace(year=-43)
+        ExpandedDate("-000043-03-15")
+        """
+        return ExpandedDate(
+            self._year if year is UNSET else year,
+            self._month if month is UNSET else month,
+            self._day if day is UNSET else day,
+        )
+
+    @overload
+    def add(self, delta: ItemizedDateDelta, /) -> ExpandedDate: ...
+
+    @overload
+    def add(
+        self,
+        *,
+        years: int = ...,
+        months: int = ...,
+        weeks: int = ...,
+        days: int = ...,
+    ) -> ExpandedDate: ...
+
+    @no_type_check
+    def add(self, *args, **kwargs) -> ExpandedDate:
+        """Add components to a date.
+
+        Years and months are added first, clamping the day to the end
+        of the month if needed. Weeks and days are added afterwards.
+        This matches the behavior of :meth:`Date.add <whenever.Date.add>`.
+
+        >>> ExpandedDate(-44, 3, 15).add(years=-1000, days=20)
+        ExpandedDate("-001044-04-04")
+        >>> ExpandedDate(-4, 2, 29).add(years=1)
+        ExpandedDate("-000003-02-28")
+        """
+        return self._shift(1, *args, **kwargs)
+
+    @overload
+    def subtract(self, delta: ItemizedDateDelta, /) -> ExpandedDate: ...
+
+    @overload
+    def subtract(
+        self,
+        *,
+        years: int = ...,
+        months: int = ...,
+        weeks: int = ...,
+        days: int = ...,
+    ) -> ExpandedDate: ...
+
+    @no_type_check
+    def subtract(self, *args, **kwargs) -> ExpandedDate:
+        """Subtract components from a date.
+        See :meth:`add` for more information.
+
+        >>> ExpandedDate(1, 1, 1).subtract(days=1)
+        ExpandedDate("0000-12-31")
+        """
+        return self._shift(-1, *args, **kwargs)
+
+    @no_type_check
+    def _shift(
+        self,
+        sign: int,
+        delta: ItemizedDateDelta = UNSET,
+        /,
+        **kwargs,
+    ) -> ExpandedDate:
+        if kwargs:
+            if delta is not UNSET:
+                raise TypeError(
+                    "Cannot combine positional and keyword arguments"
+                )
+        elif delta is not UNSET:
+            if not isinstance(delta, _whenever.ItemizedDateDelta):
+                raise TypeError("delta must be an ItemizedDateDelta")
+            kwargs = delta
+        else:  # no arguments, just return self
+            return self
+        return self._shift_kwargs(sign, **kwargs)
+
+    def _shift_kwargs(
+        self,
+        sign: int,
+        years: int = 0,
+        months: int = 0,
+        weeks: int = 0,
+        days: int = 0,
+    ) -> ExpandedDate:
+        return ExpandedDate._from_unix_days(
+            self._add_months(sign * (years * 12 + months))
+            + (weeks * 7 + days) * sign
+        )
+
+    def _add_months(self, mos: int) -> int:
+        # Returns unix days, since the result may be temporarily
+        # out of range when subsequently adding days.
+        year_overflow, month0 = divmod(self._month - 1 + mos, 12)
+        year = self._year + year_overflow
+        month = month0 + 1
+        return _days_from_civil(
+            year, month, min(self._day, days_in_month(year, month))
+        )
+
+    @overload
+    def since(
+        self,
+        b: ExpandedDate,
+        /,
+        *,
+        total: DateDeltaUnitStr,
+    ) -> float: ...
+
+    @overload
+    def since(
+        self,
+        b: ExpandedDate,
+        /,
+        *,
+        in_units: Sequence[DateDeltaUnitStr],
+        round_mode: RoundModeStr = "trunc",
+        round_increment: int = ...,
+    ) -> ItemizedDateDelta: ...
+
+    def since(
+        self,
+        b: ExpandedDate,
+        /,
+        *,
+        total: DateDeltaUnitStr = UNSET,
+        in_units: Sequence[DateDeltaUnitStr] = UNSET,
+        round_mode: RoundModeStr = UNSET,
+        round_increment: int = UNSET,
+    ) -> ItemizedDateDelta | float:
+        """Calculate the difference between this date and another date.
+        Works the same as :meth:`Date.since <whenever.Date.since>`.
+        Note that an :class:`~whenever.ItemizedDateDelta` is limited to
+        9999 years, so use ``total`` for larger differences.
+
+        >>> d = ExpandedDate(2025, 1, 1)
+        >>> d.since(ExpandedDate(-44, 3, 15), in_units=["years", "days"])
+        ItemizedDateDelta("P2068y292d")
+        >>> d.since(ExpandedDate(-44, 3, 15), total="days")
+        755614.0
+        """
+        from ._pywhenever import _normalize_units, _unit_index
+
+        if not isinstance(b, ExpandedDate):
+            raise TypeError("Can only compare with another ExpandedDate")
+        a_days = self._unix_days()
+        sign = 1 if self >= b else -1
+        if total is not UNSET:
+            if in_units is not UNSET:
+                raise TypeError("Cannot specify both 'total' and 'in_units'")
+            if round_mode is not UNSET or round_increment is not UNSET:
+                raise TypeError(
+                    "'round_mode' and 'round_increment' cannot be used with 'total'"
+                )
+            _unit_index(total, DATE_DELTA_UNITS)
+            results, trunc, expand = _date_diff(self, b, 1, [total], sign)
+            return (
+                results[total] + (a_days - trunc) / (expand - trunc)
+            ) * sign
+        elif in_units is UNSET:
+            raise TypeError("Must specify either `in_units` or `total`")
+
+        units = _normalize_units(in_units, valid_units=DATE_DELTA_UNITS)
+        increment = 1 if round_increment is UNSET else round_increment
+        if increment < 1 or increment != int(increment):
+            raise ValueError("Invalid increment. Must be a positive integer.")
+        results, trunc, expand = _date_diff(self, b, increment, units, sign)
+
+        if round_mode is not UNSET and round_mode != "trunc":
+            smallest_unit = units[-1]
+            results[smallest_unit] = custom_round(
+                results[smallest_unit],
+                abs(a_days - trunc),
+                abs(expand - trunc),
+                round_mode,
+                increment,
+                sign,
+            )
+
+        return _whenever.ItemizedDateDelta(
+            **{u: n * sign for u, n in results.items()}
+        )
+
+    @overload
+    def until(
+        self,
+        b: ExpandedDate,
+        /,
+        *,
+        total: DateDeltaUnitStr,
+    ) -> float: ...
+
+    @overload
+    def until(
+        self,
+        b: ExpandedDate,
+        /,
+        *,
+        in_units: Sequence[DateDeltaUnitStr],
+        round_mode: RoundModeStr = "trunc",
+        round_increment: int = ...,
+    ) -> ItemizedDateDelta: ...
+
+    def until(
+        self,
+        b: ExpandedDate,
+        /,
+        *,
+        total: DateDeltaUnitStr = UNSET,
+        in_units: Sequence[DateDeltaUnitStr] = UNSET,
+        round_mode: RoundModeStr = UNSET,
+        round_increment: int = UNSET,
+    ) -> ItemizedDateDelta | float:
+        """Companion to :meth:`since` that calculates the difference
+        until another date. See :meth:`since` for more information.
+        """
+        if not isinstance(b, ExpandedDate):
+            raise TypeError("Can only compare with another ExpandedDate")
+        return b.since(  # type: ignore[call-overload, no-any-return]
+            self,
+            total=total,
+            in_units=in_units,
+            round_mode=round_mode,
+            round_increment=round_increment,
+        )
+
+    def format_iso(self, *, basic: bool = False) -> str:
+        """Format as an ISO 8601 date string.
+
+        Years 0--9999 use the regular four-digit form.
+        Other years use the expanded form with a sign and six digits.
+
+        >>> ExpandedDate(2024, 3, 15).format_iso()
+        '2024-03-15'
+        >>> ExpandedDate(-44, 3, 15).format_iso()
+        '-000044-03-15'
+        >>> ExpandedDate(12025, 1, 1).format_iso(basic=True)
+        '+0120250101'
+        """
+        year = self._year
+        if 0 <= year <= 9999:
+            year_str = f"{year:04d}"
+        else:
+            year_str = f"{'-' if year < 0 else '+'}{abs(year):06d}"
+        if basic:
+            return f"{year_str}{self._month:02d}{self._day:02d}"
+        return f"{year_str}-{self._month:02d}-{self._day:02d}"
+
+    @classmethod
+    def parse_iso(cls, s: str, /) -> ExpandedDate:
+        """Parse an ISO 8601 date string, in either the regular
+        or the expanded (signed) year format.
+        Both the extended and basic formats are supported.
+
+        >>> ExpandedDate.parse_iso("-0044-03-15")
+        ExpandedDate("-000044-03-15")
+        >>> ExpandedDate.parse_iso("+0120250101")
+        ExpandedDate("+012025-01-01")
+        """
+        obj = _object_new(cls)
+        obj._init_from_iso(s)
+        return obj
+
+    def __str__(self) -> str:
+        return self.format_iso()
+
+    def __repr__(self) -> str:
+        return f'ExpandedDate("{self}")'
+
+    def __eq__(self, other: object) -> bool:
+        """Compare for equality
+
+        >>> ExpandedDate(-44, 3, 15) == ExpandedDate("-0044-03-15")
+        True
+
+        Like other types, it never equals a :class:`~whenever.Date`,
+        even on the same day. Convert explicitly to compare them:
+
+        >>> ExpandedDate(2024, 3, 15) == Date(2024, 3, 15)
+        False
+        >>> ExpandedDate(2024, 3, 15).date() == Date(2024, 3, 15)
+        True
+        """
+        if not isinstance(other, ExpandedDate):
+            return NotImplemented
+        return (
+            self._year == other._year
+            and self._month == other._month
+            and self._day == other._day
+        )
+
+    def __lt__(self, other: ExpandedDate) -> bool:
+        if not isinstance(other, ExpandedDate):
+            return NotImplemented
+        return (self._year, self._month, self._day) < (
+            other._year,
+            other._month,
+            other._day,
+        )
+
+    def __le__(self, other: ExpandedDate) -> bool:
+        if not isinstance(other, ExpandedDate):
+            return NotImplemented
+        return (self._year, self._month, self._day) <= (
+            other._year,
+            other._month,
+            other._day,
+        )
+
+    def __gt__(self, other: ExpandedDate) -> bool:
+        if not isinstance(other, ExpandedDate):
+            return NotImplemented
+        return (self._year, self._month, self._day) > (
+            other._year,
+            other._month,
+            other._day,
+        )
+
+    def __ge__(self, other: ExpandedDate) -> bool:
+        if not isinstance(other, ExpandedDate):
+            return NotImplemented
+        return (self._year, self._month, self._day) >= (
+            other._year,
+            other._month,
+            other._day,
+        )
+
+    def __hash__(self) -> int:
+        return hash((self._year, self._month, self._day))
+
+    @no_type_check
+    def __reduce__(self):
+        return _unpkl_xdate, (
+            pack("<iBB", self._year, self._month, self._day),
+        )
+
+
+@no_type_check
+def _unpkl_xdate(data: bytes) -> ExpandedDate:
+    return ExpandedDate._from_parts_unchecked(*unpack("<iBB", data))
+
+
+ExpandedDate.MIN = ExpandedDate._from_parts_unchecked(MIN_YEAR, 1, 1)
+ExpandedDate.MAX = ExpandedDate._from_parts_unchecked(MAX_YEAR, 12, 31)
+
+
+def _check_ymd(year: int, month: int, day: int) -> None:
+    if not MIN_YEAR <= year <= MAX_YEAR:
+        raise ValueError(f"year must be between {MIN_YEAR} and {MAX_YEAR}")
+    if not 1 <= month <= 12:
+        raise ValueError("month must be between 1 and 12")
+    if not 1 <= day <= days_in_month(year, month):
+        raise ValueError("day is out of range for month")
+
+
+# The conversions between (year, month, day) and days since the Unix epoch
+# are based on Howard Hinnant's algorithms, which work for any year
+# in the proleptic Gregorian calendar.
+# See https://howardhinnant.github.io/date_algorithms.html
+def _days_from_civil(year: int, month: int, day: int) -> int:
+    year -= month <= 2
+    era, yoe = divmod(year, 400)
+    doy = (153 * ((month + 9) % 12) + 2) // 5 + day - 1
+    doe = yoe * 365 + yoe // 4 - yoe // 100 + doy
+    return era * 146_097 + doe - 719_468
+
+
+def _civil_from_days(days: int) -> tuple[int, int, int]:
+    era, doe = divmod(days + 719_468, 146_097)
+    yoe = (doe - doe // 1460 + doe // 36524 - doe // 146_096) // 365
+    doy = doe - (365 * yoe + yoe // 4 - yoe // 100)
+    mp = (5 * doy + 2) // 153
+    day = doy - (153 * mp + 2) // 5 + 1
+    month = mp + 3 if mp < 10 else mp - 9
+    return yoe + era * 400 + (month <= 2), month, day
+
+
+def _date_diff(
+    a: ExpandedDate,
+    b: ExpandedDate,
+    increment: int,
+    units: Sequence[DateDeltaUnitStr],
+    sign: int,
+) -> tuple[dict[DateDeltaUnitStr, int], int, int]:
+    # Like the algorithm for Date, the units are progressively added to `b`
+    # until we reach `a`. Years and months are always applied together
+    # from the original day of `b`, so that day clamping only happens once.
+    # Returns the (absolute) amounts, along with the truncated and expanded
+    # dates in unix days.
+    a_days = a._unix_days()
+    a_months = a._year * 12 + a._month
+    b_months = b._year * 12 + b._month
+    months = 0  # months added to `b` so far
+    days = 0  # days added to `b` (after the months) so far
+    trunc = expand = b._unix_days()
+    results: dict[DateDeltaUnitStr, int] = {}
+    increments = [*[1] * (len(units) - 1), increment]
+    for unit, inc in zip(units, increments):
+        if unit == "years" or unit == "months":
+            step = (12 if unit == "years" else 1) * inc
+            diff = abs(a_months - b_months - months) // step * step * sign
+            trunc = b._add_months(months + diff)
+            # Check if we overshot
+            if trunc * sign > a_days * sign:
+                diff -= step * sign
+                trunc = b._add_months(months + diff)
+            expand = b._add_months(months + diff + step * sign)
+            months += diff
+            results[unit] = abs(diff) // step * inc
+        else:
+            step = (7 if unit == "weeks" else 1) * inc
+            base = b._add_months(months) + days
+            diff = abs(a_days - base) // step * step * sign
+            days += diff
+            trunc = base + diff
+            expand = trunc + step * sign
+            results[unit] = abs(diff) // step * inc
+    return results, trunc, expand
+
+
+def _parse_expanded_date(s: str) -> tuple[int, int, int]:
+    try:
+        if not s.isascii():
+            raise ValueError()
+        sign = 0
+        if s[:1] in ("+", "-"):
+            sign = -1 if s[0] == "-" else 1
+            s_rest = s[1:]
+        else:
+            s_rest = s
+        if len(s_rest) > 8 and s_rest[-3] == "-" and s_rest[-6] == "-":
+            year_str = s_rest[:-6]
+            month_str = s_rest[-5:-3]
+            day_str = s_rest[-2:]
+        elif "-" not in s_rest:
+            year_str = s_rest[:-4]
+            month_str = s_rest[-4:-2]
+            day_str = s_rest[-2:]
+        else:
+            raise ValueError()
+        # An unsigned year has exactly four digits. Signed years may
+        # have more, and negative zero isn't allowed.
+        if not ((sign and 4 <= len(year_str) <= 6) or len(year_str) == 4):
+            raise ValueError()
+        year = _strict_int(year_str)
+        if sign == -1 and year == 0:
+            raise ValueError()
+        year *= sign or 1
+        month = _strict_int(month_str)
+        day = _strict_int(day_str)
+        _check_ymd(year, month, day)
+    except ValueError:
+        raise ParseError(f"Invalid format: {s!r}", s) from None
+    return year, month, day
+
+
+# Set __module__ so these types and unpickle functions appear as 'whenever.X'
+if not SPHINX_RUNNING:  # pragma: no branch
+    for _obj in (ExpandedDate, _unpkl_xdate):
+        _obj.__module__ = "whenever"
+    del _obj
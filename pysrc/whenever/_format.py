@@ -7,63 +7,18 @@ be used to format values to strings or parse strings into values.
 from __future__ import annotations
 
 import warnings
-from collections.abc import Iterable
+from collections.abc import Callable, Iterable
+from datetime import date as _date
 from functools import lru_cache
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, TypeVar
 
-from ._common import WheneverWarning
+from ._common import ParseError, WheneverWarning
+from ._locale import EN, Locale, get_locale, locale_from_lcid
 
 if TYPE_CHECKING:
     from typing import Sequence
 
-# Month and weekday names (English only, invariant)
-_MONTH_ABBR = [
-    "",
-    "Jan",
-    "Feb",
-    "Mar",
-    "Apr",
-    "May",
-    "Jun",
-    "Jul",
-    "Aug",
-    "Sep",
-    "Oct",
-    "Nov",
-    "Dec",
-]
-_MONTH_FULL = [
-    "",
-    "January",
-    "February",
-    "March",
-    "April",
-    "May",
-    "June",
-    "July",
-    "August",
-    "September",
-    "October",
-    "November",
-    "December",
-]
-_WEEKDAY_ABBR = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
-_WEEKDAY_FULL = [
-    "Monday",
-    "Tuesday",
-    "Wednesday",
-    "Thursday",
-    "Friday",
-    "Saturday",
-    "Sunday",
-]
-
-# Reverse lookups for parsing (lowercase key → value)
-_MONTH_ABBR_LOOKUP = {n.lower(): i for i, n in enumerate(_MONTH_ABBR) if n}
-_MONTH_FULL_LOOKUP = {n.lower(): i for i, n in enumerate(_MONTH_FULL) if n}
-_WEEKDAY_ABBR_LOOKUP = {n.lower(): i for i, n in enumerate(_WEEKDAY_ABBR)}
-_WEEKDAY_FULL_LOOKUP = {n.lower(): i for i, n in enumerate(_WEEKDAY_FULL)}
-
+_T = TypeVar("_T")
 
 def _parse_digits(s: str, pos: int, count: int) -> tuple[int, int]:
     """Parse exactly ``count`` digits from s at pos.
@@ -83,6 +38,23 @@ def _parse_digits(s: str, pos: int, count: int) -> tuple[int, int]:
     return int(chunk), end
 
 
+def _parse_padded(
+    s: str, pos: int, count: int, state: _ParseState
+) -> tuple[int, int]:
+    """Parse a zero-padded number of ``count`` digits.
+    In lenient mode, the leading zeros may be omitted.
+    Returns (value, new_pos).
+    """
+    if not state.lenient:
+        return _parse_digits(s, pos, count)
+    end = pos
+    while end < len(s) and end - pos < count and s[end].isdigit():
+        end += 1
+    if end == pos:
+        raise ValueError(f"Expected 1-{count} digits at position {pos}")
+    return int(s[pos:end]), end
+
+
 def _parse_1or2_digits(s: str, pos: int) -> tuple[int, int]:
     """Parse 1 or 2 digits from s at pos (greedy).
     Returns (value, new_pos).
@@ -99,17 +71,21 @@ def _parse_1or2_digits(s: str, pos: int) -> tuple[int, int]:
 def _parse_text_match(
     s: str,
     pos: int,
-    lookup: dict[str, int],
+    names: Sequence[str],
     field_name: str,
 ) -> tuple[int, int]:
-    """Match text against a lookup dict (case-insensitive).
-    Returns (value, new_pos). Tries longest match first.
+    """Match the longest of the names at the position (case-insensitive).
+    Returns (index of the name, new_pos).
     """
-    s_lower = s[pos:].lower()
-    for key in sorted(lookup, key=len, reverse=True):
-        if s_lower.startswith(key):
-            return lookup[key], pos + len(key)
-    raise ValueError(f"Cannot parse {field_name} at position {pos}")
+    best = -1
+    best_len = 0
+    for i, name in enumerate(names):
+        n = len(name)
+        if n > best_len and s[pos : pos + n].lower() == name.lower():
+            best, best_len = i, n
+    if best < 0:
+        raise ValueError(f"Cannot parse {field_name} at position {pos}")
+    return best, pos + best_len
 
 
 # --- Format values (input to formatting) ---
@@ -130,6 +106,7 @@ class _FormatValues:
         "offset_secs",
         "tz_id",
         "tz_abbrev",
+        "locale",
     )
 
     def __init__(
@@ -146,6 +123,7 @@ class _FormatValues:
         offset_secs: int | None = None,
         tz_id: str | None = None,
         tz_abbrev: str | None = None,
+        locale: Locale = EN,
     ):
         self.year = year
         self.month = month
@@ -158,16 +136,30 @@ class _FormatValues:
         self.offset_secs = offset_secs
         self.tz_id = tz_id
         self.tz_abbrev = tz_abbrev
+        self.locale = locale
+
+    def date(self) -> _date:
+        return _date(self.year, self.month, self.day)
 
 
 # --- Parse state ---
 
+MISSING_DATE_FIELDS = (
+    "Pattern must include date fields: "
+    "year (YYYY/YY), month (MM/MMM/MMMM), and day (DD); "
+    "year and day of year (DDD); "
+    "or ISO week-based year (GGGG), week (WW/W), and weekday (EEE/EEEE)"
+)
+
+_MISSING_PIVOT_YEAR = "Parsing a two-digit year (YY) requires a pivot_year"
+
 
 class _ParseState:
     """Mutable parse state accumulating field values from input."""
 
     __slots__ = (
         "year",
+        "year2",
         "month",
         "day",
         "hour",
@@ -178,11 +170,25 @@ class _ParseState:
         "offset_secs",
         "tz_id",
         "weekday",
+        "day_of_year",
+        "iso_week",
+        "iso_year",
+        "quarter",
         "second_absent",
+        "locale",
+        "lenient",
+        "pivot_year",
     )
 
-    def __init__(self) -> None:
+    def __init__(
+        self,
+        locale: Locale = EN,
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> None:
         self.year: int | None = None
+        # A two-digit year (YY), resolved along with the rest of the date
+        self.year2: int | None = None
         self.month: int | None = None
         self.day: int | None = None
         self.hour: int | None = None
@@ -193,15 +199,116 @@ class _ParseState:
         self.offset_secs: int | None = None
         self.tz_id: str | None = None
         self.weekday: int | None = None
+        self.day_of_year: int | None = None
+        self.iso_week: int | None = None
+        self.iso_year: int | None = None
+        self.quarter: int | None = None
         self.second_absent: bool = False
+        self.locale = locale
+        self.lenient = lenient
+        self.pivot_year = pivot_year
 
     def resolve(self) -> None:
-        """Apply AM/PM adjustment after all fields are parsed."""
+        """Apply AM/PM adjustment after all fields are parsed,
+        and check that the time fields form a valid time."""
         if self.ampm is not None and self.hour is not None:
             if self.ampm == "PM" and self.hour < 12:
                 self.hour += 12
             elif self.ampm == "AM" and self.hour == 12:
                 self.hour = 0
+        if not (
+            (self.hour or 0) < 24
+            and (self.minute or 0) < 60
+            and (self.second or 0) < 60
+        ):
+            raise ValueError("Invalid time")
+
+    def resolve_date(self) -> None:
+        """Derive the calendar date from day-of-year or ISO week date fields,
+        and check that the date is valid and agrees with any such fields."""
+        if self.year2 is not None:
+            self.year = self.two_digit_year(self.year2)
+            self.year2 = None
+        if self.month is None and self.day is None:
+            if self.year is not None and self.day_of_year is not None:
+                days_in_year = 366 if _is_leap(self.year) else 365
+                if not 1 <= self.day_of_year <= days_in_year:
+                    raise ValueError(
+                        f"day of year out of range: {self.day_of_year}"
+                    )
+                self._set_date(
+                    _date.fromordinal(
+                        _date(self.year, 1, 1).toordinal()
+                        + self.day_of_year
+                        - 1
+                    )
+                )
+            elif (
+                self.iso_year is not None
+                and self.iso_week is not None
+                and self.weekday is not None
+            ):
+                try:
+                    d = _date.fromisocalendar(
+                        self.iso_year, self.iso_week, self.weekday + 1
+                    )
+                except (ValueError, OverflowError):
+                    raise ValueError("Invalid ISO week date") from None
+                if self.year is not None and self.year != d.year:
+                    raise ValueError(
+                        "Parsed year does not match the ISO week date"
+                    )
+                self._set_date(d)
+        if self.year is None or self.month is None or self.day is None:
+            return
+        try:
+            d = _date(self.year, self.month, self.day)
+        except ValueError:
+            raise ValueError("Invalid date") from None
+        if self.weekday is not None and self.weekday != d.weekday():
+            raise ValueError("Parsed weekday does not match the date")
+        if (
+            self.day_of_year is not None
+            and self.day_of_year != d.timetuple().tm_yday
+        ):
+            raise ValueError("Parsed day of year does not match the date")
+        if self.quarter is not None and self.quarter != _quarter(d.month):
+            raise ValueError("Parsed quarter does not match the date")
+        iso_year, week, _ = d.isocalendar()
+        if self.iso_week is not None and self.iso_week != week:
+            raise ValueError("Parsed ISO week does not match the date")
+        if self.iso_year is not None and self.iso_year != iso_year:
+            raise ValueError(
+                "Parsed ISO week-based year does not match the date"
+            )
+
+    def two_digit_year(self, yy: int) -> int:
+        """Resolve a two-digit year to the year in the 100-year window
+        starting 50 years before the pivot year.
+        For example, a pivot of 2000 gives years 1950-2049.
+        """
+        if self.pivot_year is None:
+            # Checked before parsing, see require_pivot_year()
+            raise ValueError(_MISSING_PIVOT_YEAR)
+        start = self.pivot_year - 50
+        year = start + (yy - start) % 100
+        if not 1 <= year <= 9999:
+            raise ValueError(f"year out of range: {year}")
+        return year
+
+    def _set_date(self, d: _date) -> None:
+        self.year = d.year
+        self.month = d.month
+        self.day = d.day
+
+
+def _is_leap(year: int) -> bool:
+    return year % 4 == 0 and (year % 100 != 0 or year % 400 == 0)
+
+
+def _quarter(month: int) -> int:
+    """The quarter (1-4) of the year that the month falls in."""
+    return (month - 1) // 3 + 1
 
 
 # --- Pattern elements ---
@@ -226,12 +333,16 @@ class _Field:
     - ``state_field``: name of the _ParseState field this writes to (for
       duplicate detection), or None if it doesn't write state
     - ``format_only``: True if the field cannot be used in parsing
+
+    Fields translated from a spreadsheet format also have a ``source``:
+    the code they were translated from, which errors show instead.
     """
 
     pattern: tuple[str, int]
     category: str
     state_field: str
     format_only: bool = False
+    source: str | None = None
 
     def format_value(self, v: _FormatValues) -> str:
         raise NotImplementedError
@@ -252,6 +363,11 @@ class _Field:
         letter, count = self.pattern
         return letter * count
 
+    @property
+    def name(self) -> str:
+        """How errors refer to this field"""
+        return repr(self) if self.source is None else self.source
+
 
 _Element = _Literal | _Field
 
@@ -276,11 +392,15 @@ class _Year2(_Field):
     pattern = ("Y", 2)
     category = "date"
     state_field = "year"
-    format_only = True
 
     def format_value(self, v: _FormatValues) -> str:
         return f"{v.year % 100:02d}"
 
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        yy, pos = _parse_digits(s, pos, 2)
+        state.year2 = yy
+        return pos
+
 
 class _MonthNum(_Field):
     pattern = ("M", 2)
@@ -291,7 +411,8 @@ class _MonthNum(_Field):
         return f"{v.month:02d}"
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.month, pos = _parse_digits(s, pos, 2)
+        month, pos = _parse_padded(s, pos, 2, state)
+        state.month = _check_month(month)
         return pos
 
 
@@ -304,22 +425,28 @@ class _MonthNumUnpadded(_Field):
         return str(v.month)
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.month, pos = _parse_1or2_digits(s, pos)
+        month, pos = _parse_1or2_digits(s, pos)
+        state.month = _check_month(month)
         return pos
 
 
+def _check_month(month: int) -> int:
+    if not 1 <= month <= 12:
+        raise ValueError(f"month out of range: {month}")
+    return month
+
+
 class _MonthAbbr(_Field):
     pattern = ("M", 3)
     category = "date"
     state_field = "month"
 
     def format_value(self, v: _FormatValues) -> str:
-        return _MONTH_ABBR[v.month]
+        return v.locale.month_abbr[v.month - 1]
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.month, pos = _parse_text_match(
-            s, pos, _MONTH_ABBR_LOOKUP, "month"
-        )
+        i, pos = _parse_text_match(s, pos, state.locale.month_abbr, "month")
+        state.month = i + 1
         return pos
 
 
@@ -329,12 +456,11 @@ class _MonthFull(_Field):
     state_field = "month"
 
     def format_value(self, v: _FormatValues) -> str:
-        return _MONTH_FULL[v.month]
+        return v.locale.month_full[v.month - 1]
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.month, pos = _parse_text_match(
-            s, pos, _MONTH_FULL_LOOKUP, "month"
-        )
+        i, pos = _parse_text_match(s, pos, state.locale.month_full, "month")
+        state.month = i + 1
         return pos
 
 
@@ -347,7 +473,7 @@ class _Day(_Field):
         return f"{v.day:02d}"
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.day, pos = _parse_digits(s, pos, 2)
+        state.day, pos = _parse_padded(s, pos, 2, state)
         return pos
 
 
@@ -370,11 +496,11 @@ class _WeekdayAbbr(_Field):
     state_field = "weekday"
 
     def format_value(self, v: _FormatValues) -> str:
-        return _WEEKDAY_ABBR[v.weekday]
+        return v.locale.weekday_abbr[v.weekday]
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
         state.weekday, pos = _parse_text_match(
-            s, pos, _WEEKDAY_ABBR_LOOKUP, "weekday"
+            s, pos, state.locale.weekday_abbr, "weekday"
         )
         return pos
 
@@ -385,15 +511,116 @@ class _WeekdayFull(_Field):
     state_field = "weekday"
 
     def format_value(self, v: _FormatValues) -> str:
-        return _WEEKDAY_FULL[v.weekday]
+        return v.locale.weekday_full[v.weekday]
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
         state.weekday, pos = _parse_text_match(
-            s, pos, _WEEKDAY_FULL_LOOKUP, "weekday"
+            s, pos, state.locale.weekday_full, "weekday"
         )
         return pos
 
 
+class _DayOfYear(_Field):
+    pattern = ("D", 3)
+    category = "date"
+    state_field = "day_of_year"
+
+    def format_value(self, v: _FormatValues) -> str:
+        return f"{v.date().timetuple().tm_yday:03d}"
+
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        doy, pos = _parse_padded(s, pos, 3, state)
+        if not 1 <= doy <= 366:
+            raise ValueError(f"day of year out of range: {doy}")
+        state.day_of_year = doy
+        return pos
+
+
+def _check_iso_week(week: int) -> int:
+    if not 1 <= week <= 53:
+        raise ValueError(f"ISO week out of range: {week}")
+    return week
+
+
+class _IsoWeek(_Field):
+    pattern = ("W", 2)
+    category = "date"
+    state_field = "iso_week"
+
+    def format_value(self, v: _FormatValues) -> str:
+        return f"{v.date().isocalendar()[1]:02d}"
+
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        week, pos = _parse_padded(s, pos, 2, state)
+        state.iso_week = _check_iso_week(week)
+        return pos
+
+
+class _IsoWeekUnpadded(_Field):
+    pattern = ("W", 1)
+    category = "date"
+    state_field = "iso_week"
+
+    def format_value(self, v: _FormatValues) -> str:
+        return str(v.date().isocalendar()[1])
+
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        week, pos = _parse_1or2_digits(s, pos)
+        state.iso_week = _check_iso_week(week)
+        return pos
+
+
+class _IsoYear(_Field):
+    pattern = ("G", 4)
+    category = "date"
+    state_field = "iso_year"
+
+    def format_value(self, v: _FormatValues) -> str:
+        return f"{v.date().isocalendar()[0]:04d}"
+
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        year, pos = _parse_digits(s, pos, 4)
+        if year == 0:
+            raise ValueError(f"year out of range: {year}")
+        state.iso_year = year
+        return pos
+
+
+class _Quarter(_Field):
+    pattern = ("Q", 1)
+    category = "date"
+    state_field = "quarter"
+
+    def format_value(self, v: _FormatValues) -> str:
+        return str(_quarter(v.month))
+
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        quarter, pos = _parse_digits(s, pos, 1)
+        if not 1 <= quarter <= 4:
+            raise ValueError(f"quarter out of range: {quarter}")
+        state.quarter = quarter
+        return pos
+
+
+class _Era(_Field):
+    pattern = ("N", 1)
+    category = "date"
+    state_field = "era"
+
+    def format_value(self, v: _FormatValues) -> str:
+        # Years before the common era aren't supported
+        return v.locale.era[1]
+
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        i, new_pos = _parse_text_match(s, pos, state.locale.era, "era")
+        if i == 0:
+            raise ValueError(
+                "Years before the common era are not supported "
+                f"(position {pos})"
+            )
+        return new_pos
+
+
 class _Hour24(_Field):
     pattern = ("h", 2)
     category = "time"
@@ -403,7 +630,7 @@ class _Hour24(_Field):
         return f"{v.hour:02d}"
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.hour, pos = _parse_digits(s, pos, 2)
+        state.hour, pos = _parse_padded(s, pos, 2, state)
         return pos
 
 
@@ -432,7 +659,7 @@ class _Hour12(_Field):
         return f"{h12:02d}"
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.hour, pos = _parse_digits(s, pos, 2)
+        state.hour, pos = _parse_padded(s, pos, 2, state)
         if not (1 <= state.hour <= 12):
             raise ValueError(
                 f"12-hour format requires hour in 1..12, got {state.hour}"
@@ -467,7 +694,7 @@ class _Minute(_Field):
         return f"{v.minute:02d}"
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.minute, pos = _parse_digits(s, pos, 2)
+        state.minute, pos = _parse_padded(s, pos, 2, state)
         return pos
 
 
@@ -493,7 +720,7 @@ class _Second(_Field):
         return f"{v.second:02d}"
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        state.second, pos = _parse_digits(s, pos, 2)
+        state.second, pos = _parse_padded(s, pos, 2, state)
         if state.second == 60:
             state.second = 59
         return pos
@@ -524,7 +751,7 @@ class _SecondOpt(_Field):
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
         if pos < len(s) and s[pos].isdigit():
-            state.second, pos = _parse_digits(s, pos, 2)
+            state.second, pos = _parse_padded(s, pos, 2, state)
             if state.second == 60:
                 state.second = 59
         else:
@@ -553,7 +780,7 @@ class _ColonSec(_Field):
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
         if pos < len(s) and s[pos] == ":":
             pos += 1  # consume the colon
-            state.second, pos = _parse_digits(s, pos, 2)
+            state.second, pos = _parse_padded(s, pos, 2, state)
             if state.second == 60:
                 state.second = 59
         else:
@@ -561,6 +788,9 @@ class _ColonSec(_Field):
             state.second_absent = True
         return pos
 
+    def __repr__(self) -> str:
+        return ":SS"
+
 
 class _FracExact(_Field):
     """Fixed-width fractional seconds (e.g. ``fff`` = 3 digits)."""
@@ -641,7 +871,11 @@ class _DotFrac(_Field):
         return f".{trimmed}" if trimmed else ""
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        if state.second_absent or pos >= len(s) or s[pos] != ".":
+        if (
+            state.second_absent
+            or pos >= len(s)
+            or not (s[pos] == "." or (state.lenient and s[pos] == ","))
+        ):
             state.nanos = 0
             return pos
         pos += 1  # consume the dot
@@ -669,17 +903,10 @@ class _AmPmShort(_Field):
     state_field = "ampm"
 
     def format_value(self, v: _FormatValues) -> str:
-        return "A" if v.hour < 12 else "P"
+        return v.locale.am_pm_short[v.hour >= 12]
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        ch = s[pos : pos + 1].upper()
-        if ch == "A":
-            state.ampm = "AM"
-        elif ch == "P":
-            state.ampm = "PM"
-        else:
-            raise ValueError(f"Expected AM/PM at position {pos}, got {ch!r}")
-        return pos + 1
+        return _parse_ampm(s, pos, state.locale.am_pm_short, state)
 
 
 class _AmPmFull(_Field):
@@ -688,19 +915,35 @@ class _AmPmFull(_Field):
     state_field = "ampm"
 
     def format_value(self, v: _FormatValues) -> str:
-        return "AM" if v.hour < 12 else "PM"
+        return v.locale.am_pm[v.hour >= 12]
 
     def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
-        chunk = s[pos : pos + 2].upper()
-        if chunk == "AM":
-            state.ampm = "AM"
-        elif chunk == "PM":
-            state.ampm = "PM"
-        else:
-            raise ValueError(
-                f"Expected AM/PM at position {pos}, got {chunk!r}"
-            )
-        return pos + 2
+        return _parse_ampm(s, pos, state.locale.am_pm, state)
+
+
+class _AmPmLetter(_AmPmShort):
+    """A spreadsheet ``a/p`` code. Each letter is shown in the case
+    it's written in.
+    """
+
+    def __init__(self, lower_am: bool, lower_pm: bool):
+        self.lower = (lower_am, lower_pm)
+
+    def format_value(self, v: _FormatValues) -> str:
+        marker = v.locale.am_pm_short[v.hour >= 12]
+        return marker.lower() if self.lower[v.hour >= 12] else marker.upper()
+
+    def __repr__(self) -> str:
+        lower_am, lower_pm = self.lower
+        return f"{'a' if lower_am else 'A'}/{'p' if lower_pm else 'P'}"
+
+
+def _parse_ampm(
+    s: str, pos: int, markers: tuple[str, str], state: _ParseState
+) -> int:
+    i, pos = _parse_text_match(s, pos, markers, "AM/PM")
+    state.ampm = "PM" if i else "AM"
+    return pos
 
 
 def _format_offset_value(offset_secs: int, width: int, use_z: bool) -> str:
@@ -851,6 +1094,48 @@ class _TzAbbrev(_Field):
         return v.tz_abbrev
 
 
+class _SignMinus(_Field):
+    """The sign of a delta: ``-`` if negative, otherwise nothing"""
+
+    pattern = ("n", 1)
+    category = "delta"
+    state_field = "sign"
+
+
+class _SignAlways(_Field):
+    """The sign of a delta: always ``+`` or ``-``"""
+
+    pattern = ("n", 2)
+    category = "delta"
+    state_field = "sign"
+
+
+class _Elapsed(_Field):
+    """A spreadsheet elapsed-time code such as ``[h]``, including the sign.
+    ``unit`` is an index into ``_DELTA_UNITS``.
+    """
+
+    category = "delta"
+
+    def __init__(self, unit: int, padded: bool):
+        self.unit = unit
+        self.padded = padded
+        self.state_field = ("second", "minute", "hour", "day")[unit]
+
+    def format_value(self, v: _FormatValues) -> str:
+        raise ValueError(
+            "Cannot format elapsed time: not available for this type"
+        )
+
+    def parse_value(self, s: str, pos: int, state: _ParseState) -> int:
+        raise ValueError(
+            "Cannot parse elapsed time: not available for this type"
+        )
+
+    def __repr__(self) -> str:
+        return f"[{'smhd'[self.unit] * (1 + self.padded)}]"
+
+
 # --- Spec table (auto-generated from field pattern attributes) ---
 
 _FIXED_FIELDS: list[type[_Field]] = [
@@ -864,6 +1149,12 @@ _FIXED_FIELDS: list[type[_Field]] = [
     _DayUnpadded,
     _WeekdayAbbr,
     _WeekdayFull,
+    _DayOfYear,
+    _IsoWeek,
+    _IsoWeekUnpadded,
+    _IsoYear,
+    _Quarter,
+    _Era,
     _Hour24,
     _Hour24Unpadded,
     _Hour12,
@@ -877,6 +1168,8 @@ _FIXED_FIELDS: list[type[_Field]] = [
     _AmPmFull,
     _TzId,
     _TzAbbrev,
+    _SignMinus,
+    _SignAlways,
 ]
 
 # Maps letter → {count → field_class}
@@ -920,8 +1213,8 @@ def _validate_cross_fields(elements: Iterable[_Element]) -> None:
         sf = el.state_field
         if sf in seen_state_fields:
             raise ValueError(
-                f"Duplicate field: {el!r} conflicts with "
-                f"{seen_state_fields[sf]!r} (both set {sf})"
+                f"Duplicate field: {el.name} conflicts with "
+                f"{seen_state_fields[sf].name} (both set {sf})"
             )
         seen_state_fields[sf] = el
 
@@ -930,6 +1223,27 @@ def _validate_cross_fields(elements: Iterable[_Element]) -> None:
             "24-hour format (h/hh) cannot be combined with "
             "AM/PM (a/aa). Use 12-hour format (i/ii) instead."
         )
+    has_week = "iso_week" in seen_state_fields
+    has_iso_year = "iso_year" in seen_state_fields
+    if has_iso_year and not has_week:
+        raise ValueError(
+            "Week-based year (GGGG) requires an ISO week field (W/WW). "
+            "Use YYYY for the calendar year."
+        )
+    # A common mistake: near the start and end of the year, the ISO week
+    # belongs to the week-based year, not the calendar year.
+    if (
+        has_week
+        and "year" in seen_state_fields
+        and not has_iso_year
+        and seen_state_fields.keys().isdisjoint(
+            ("month", "day", "day_of_year")
+        )
+    ):
+        raise ValueError(
+            "ISO week (W/WW) must be combined with the week-based year "
+            "(GGGG), not the calendar year (YYYY)."
+        )
     if has_12h and not has_ampm:
         warnings.warn(
             "The pattern uses a 12-hour clock (`i` or `ii`) without an AM/PM "
@@ -998,9 +1312,50 @@ def _compile_specifier(
         )
 
 
+class _Compiled(tuple):  # type: ignore[type-arg]
+    """The elements of a compiled pattern"""
+
+    # The locale set by the pattern itself,
+    # e.g. '[$-407]' in a spreadsheet format
+    locale: Locale | None = None
+
+
 @lru_cache(maxsize=64)
-def compile_pattern(pattern: str) -> tuple[_Element, ...]:
+def compile_pattern(pattern: str, style: str = "pattern") -> _Compiled:
     """Compile a pattern string into a tuple of elements."""
+    locale: Locale | None = None
+    if style == "pattern":
+        elements = _compile_native(pattern)
+    elif style == "strftime":
+        elements = _compile_strftime(pattern)
+    elif style == "spreadsheet":
+        elements, locale = _compile_spreadsheet(pattern)
+    else:
+        raise ValueError(f"Invalid value for style: {style!r}")
+    _validate_cross_fields(elements)
+    compiled = _Compiled(elements)
+    compiled.locale = locale
+    return compiled
+
+
+def pattern_locale(elements: Sequence[_Element], tag: str | None) -> Locale:
+    """The locale to format or parse with: the one set by the pattern
+    itself, if any, otherwise the ``locale`` argument (English by default).
+    Raises ``ValueError`` if both are given and they differ.
+    """
+    locale = get_locale(tag)
+    own = elements.locale if isinstance(elements, _Compiled) else None
+    if own is None:
+        return locale
+    elif tag is not None and locale is not own:
+        raise ValueError(
+            f"The pattern's locale ID selects locale '{own.tag}', "
+            f"which conflicts with locale='{locale.tag}'"
+        )
+    return own
+
+
+def _compile_native(pattern: str) -> list[_Element]:
     if len(pattern) > 1000:
         raise ValueError("Pattern string too long (max 1000 characters)")
     elements: list[_Element] = []
@@ -1084,9 +1439,375 @@ def compile_pattern(pattern: str) -> tuple[_Element, ...]:
     # Flush any pending prefix left at end of pattern (e.g. pattern = "hh:mm.")
     if pending is not None:
         elements.append(_Literal(pending))
+    return elements
+
+
+# --- strftime translation ---
+
+# Only directives with an exact pattern equivalent are supported,
+# so the result behaves just like the equivalent pattern.
+_STRFTIME_FIELDS: dict[str, Callable[[], _Field]] = {
+    "Y": _Year4,
+    "y": _Year2,
+    "m": _MonthNum,
+    "-m": _MonthNumUnpadded,
+    "b": _MonthAbbr,
+    "h": _MonthAbbr,
+    "B": _MonthFull,
+    "d": _Day,
+    "-d": _DayUnpadded,
+    "j": _DayOfYear,
+    "a": _WeekdayAbbr,
+    "A": _WeekdayFull,
+    "G": _IsoYear,
+    "V": _IsoWeek,
+    "-V": _IsoWeekUnpadded,
+    "H": _Hour24,
+    "-H": _Hour24Unpadded,
+    "I": _Hour12,
+    "-I": _Hour12Unpadded,
+    "M": _Minute,
+    "-M": _MinuteUnpadded,
+    "S": _Second,
+    "-S": _SecondUnpadded,
+    "f": lambda: _FracExact(6),
+    "p": _AmPmFull,
+    "z": lambda: _OffsetLower(4),
+    ":z": lambda: _OffsetLower(5),
+    "Z": _TzAbbrev,
+}
+_STRFTIME_LITERALS = {"%": "%", "n": "\n", "t": "\t"}
+# Shorthands for combinations of other directives
+_STRFTIME_SHORTHANDS = {
+    "F": "%Y-%m-%d",
+    "D": "%m/%d/%y",
+    "T": "%H:%M:%S",
+    "R": "%H:%M",
+}
+_STRFTIME_HINTS = {
+    "U": " Only ISO weeks are supported: use %G and %V instead.",
+    "W": " Only ISO weeks are supported: use %G and %V instead.",
+    "w": " Use %a or %A for the weekday instead.",
+    "u": " Use %a or %A for the weekday instead.",
+    "c": " Locale-dependent formats are not supported: "
+    "spell out the fields instead.",
+    "x": " Locale-dependent formats are not supported: "
+    "spell out the fields instead.",
+    "X": " Locale-dependent formats are not supported: "
+    "spell out the fields instead.",
+    "e": " Use %d or %-d instead.",
+}
 
-    _validate_cross_fields(elements)
-    return tuple(elements)
+
+def _compile_strftime(fmt: str) -> list[_Element]:
+    if len(fmt) > 1000:
+        raise ValueError("Pattern string too long (max 1000 characters)")
+    for i, ch in enumerate(fmt):
+        if not ch.isascii():
+            raise ValueError(
+                f"Non-ASCII character {ch!r} at position {i}. "
+                f"Patterns must be ASCII-only."
+            )
+    elements: list[_Element] = []
+    literal = _translate_strftime(fmt, elements, "")
+    if literal:
+        elements.append(_Literal(literal))
+    return elements
+
+
+def _translate_strftime(
+    fmt: str, elements: list[_Element], literal: str
+) -> str:
+    """Append the elements for ``fmt``, returning any unflushed literal"""
+    i = 0
+    n = len(fmt)
+    while i < n:
+        ch = fmt[i]
+        if ch != "%":
+            literal += ch
+            i += 1
+            continue
+        start = i
+        i += 1
+        # Flags: '-' (no padding, a glibc extension) and ':' (for '%:z')
+        flag = fmt[i] if i < n and fmt[i] in "-:" else ""
+        i += len(flag)
+        if i >= n:
+            raise ValueError(
+                f"Incomplete strftime directive at position {start}"
+            )
+        directive = flag + fmt[i]
+        i += 1
+        if directive in _STRFTIME_LITERALS:
+            literal += _STRFTIME_LITERALS[directive]
+        elif directive in _STRFTIME_SHORTHANDS:
+            literal = _translate_strftime(
+                _STRFTIME_SHORTHANDS[directive], elements, literal
+            )
+        elif directive in _STRFTIME_FIELDS:
+            if literal:
+                elements.append(_Literal(literal))
+                literal = ""
+            elements.append(_STRFTIME_FIELDS[directive]())
+        else:
+            raise ValueError(
+                f"Unsupported strftime directive '%{directive}' at "
+                f"position {start}.{_STRFTIME_HINTS.get(directive[-1], '')}"
+            )
+    return literal
+
+
+# --- Spreadsheet format translation ---
+
+_SPREADSHEET_FIELDS: dict[tuple[str, int], Callable[[], _Field]] = {
+    ("y", 1): _Year2,
+    ("y", 2): _Year2,
+    ("y", 3): _Year4,
+    ("y", 4): _Year4,
+    ("m", 1): _MonthNumUnpadded,
+    ("m", 2): _MonthNum,
+    ("m", 3): _MonthAbbr,
+    ("m", 4): _MonthFull,
+    ("d", 1): _DayUnpadded,
+    ("d", 2): _Day,
+    ("d", 3): _WeekdayAbbr,
+    ("d", 4): _WeekdayFull,
+    ("h", 1): _Hour24Unpadded,
+    ("h", 2): _Hour24,
+    ("s", 1): _SecondUnpadded,
+    ("s", 2): _Second,
+}
+_SPREADSHEET_ELAPSED = {
+    "h": (2, False),
+    "hh": (2, True),
+    "m": (1, False),
+    "mm": (1, True),
+    "s": (0, False),
+    "ss": (0, True),
+}
+# Colors only affect how the cell is displayed
+_SPREADSHEET_COLORS = frozenset(
+    ("black", "blue", "cyan", "green", "magenta", "red", "white", "yellow")
+)
+_SPREADSHEET_LITERALS = frozenset(" $-+/():!^&'~{}<>=,.")
+_HEX_DIGITS = frozenset("0123456789abcdefABCDEF")
+
+
+def _compile_spreadsheet(fmt: str) -> tuple[list[_Element], Locale | None]:
+    # Non-ASCII text is allowed only in quoted or escaped literals,
+    # and in currency symbols.
+    if len(fmt) > 1000:
+        raise ValueError("Pattern string too long (max 1000 characters)")
+    elements: list[_Element] = []
+    literal = ""
+    prev_field: _Field | None = None
+    locale: Locale | None = None
+    n = len(fmt)
+    i = 0
+    while i < n:
+        ch = fmt[i]
+        start = i
+        i += 1
+        if not ch.isascii():
+            raise ValueError(
+                f"Non-ASCII character at position {start}. "
+                'Use quotes for literal text: "..."'
+            )
+        lower = ch.lower()
+        field: _Field
+        if ch == '"':
+            end = fmt.find('"', i)
+            if end == -1:
+                raise ValueError(
+                    f"Unterminated quoted text at position {start}"
+                )
+            literal += fmt[i:end]
+            i = end + 1
+            continue
+        elif ch in "\\_*":
+            if i == n:
+                raise ValueError(
+                    f"Incomplete escape '{ch}' at position {start}"
+                )
+            if ch == "\\":
+                literal += fmt[i]
+            elif ch == "_":
+                # Space the width of the character
+                literal += " "
+            # '*' repeats the character to fill the cell: no equivalent
+            i += 1
+            continue
+        elif ch == "[":
+            end = fmt.find("]", i)
+            if end == -1:
+                raise ValueError(f"Unterminated bracket at position {start}")
+            code = fmt[i:end]
+            i = end + 1
+            bracket = _spreadsheet_bracket(code, start)
+            if isinstance(bracket, tuple):
+                text, lcid_locale = bracket
+                if lcid_locale is not None:
+                    if locale is not None and locale is not lcid_locale:
+                        raise ValueError(
+                            f"Locale ID '[{code}]' at position {start} "
+                            "conflicts with an earlier locale ID"
+                        )
+                    locale = lcid_locale
+                literal += text
+                continue
+            field = bracket
+        elif lower == "a":
+            if fmt[start : start + 5].lower() == "am/pm":
+                i += 4
+                field = _AmPmFull()
+            elif fmt[start : start + 3].lower() == "a/p":
+                i += 2
+                field = _AmPmLetter(ch == "a", fmt[start + 2] == "p")
+            else:
+                raise ValueError(_unsupported_spreadsheet_msg(ch, start))
+        elif lower in "ymdhs":
+            while i < n and fmt[i].lower() == lower:
+                i += 1
+            count = i - start
+            if lower == "m" and count <= 2 and _is_spreadsheet_minute(
+                prev_field, fmt[i:]
+            ):
+                field = _MinuteUnpadded() if count == 1 else _Minute()
+            elif (lower, count) in _SPREADSHEET_FIELDS:
+                field = _SPREADSHEET_FIELDS[lower, count]()
+            else:
+                raise ValueError(
+                    _unsupported_spreadsheet_msg(fmt[start:i], start)
+                )
+        # Fractional seconds, e.g. 'ss.000'
+        elif (
+            ch == "."
+            and fmt[i : i + 1] == "0"
+            and (
+                isinstance(prev_field, (_Second, _SecondUnpadded))
+                or (isinstance(prev_field, _Elapsed) and prev_field.unit == 0)
+            )
+        ):
+            while i < n and fmt[i] == "0":
+                i += 1
+            if i - start > 10:
+                raise ValueError(
+                    f"Unsupported fractional seconds '{fmt[start:i]}' at "
+                    f"position {start}. At most 9 decimals are supported."
+                )
+            literal += "."
+            field = _FracExact(i - start - 1)
+        elif ch in _SPREADSHEET_LITERALS:
+            literal += ch
+            continue
+        else:
+            raise ValueError(_unsupported_spreadsheet_msg(ch, start))
+        if literal:
+            elements.append(_Literal(literal))
+            literal = ""
+        field.source = fmt[start:i]
+        elements.append(field)
+        prev_field = field
+    if literal:
+        elements.append(_Literal(literal))
+    # Hours are on a 12-hour clock if there's an AM/PM code anywhere
+    if any(isinstance(el, (_AmPmFull, _AmPmShort)) for el in elements):
+        for i, el in enumerate(elements):
+            if isinstance(el, (_Hour24, _Hour24Unpadded)):
+                hour12 = (
+                    _Hour12() if isinstance(el, _Hour24) else _Hour12Unpadded()
+                )
+                hour12.source = el.source
+                elements[i] = hour12
+    return elements, locale
+
+
+def _spreadsheet_bracket(
+    code: str, pos: int
+) -> _Field | tuple[str, Locale | None]:
+    """The field for a code in square brackets, or the literal text
+    to output instead, along with the locale of its locale ID (if any)
+    """
+    # A currency symbol and/or locale ID (LCID, in hex),
+    # e.g. '[$-409]' or '[$USD-409]'.
+    if code.startswith("$"):
+        text, dash, lcid = code[1:].partition("-")
+        if not dash:
+            return text, None
+        if lcid.upper() in ("F800", "F400") or lcid[:5].lower() == "x-sys":
+            raise ValueError(
+                f"Unsupported system date or time format '[{code}]' at "
+                f"position {pos}. Spell out the fields instead."
+            )
+        if not (0 < len(lcid) <= 8 and all(c in _HEX_DIGITS for c in lcid)):
+            raise ValueError(f"Invalid locale ID '[{code}]' at position {pos}")
+        value = int(lcid, 16)
+        # The upper bits select a calendar or numeral system,
+        # which we don't support
+        locale = locale_from_lcid(value) if value <= 0xFFFF else None
+        if locale is None:
+            raise ValueError(
+                f"Unsupported locale ID '[{code}]' at position {pos}"
+            )
+        return text, locale
+    lower = code.lower()
+    if lower in _SPREADSHEET_ELAPSED:
+        return _Elapsed(*_SPREADSHEET_ELAPSED[lower])
+    elif lower in _SPREADSHEET_COLORS or (
+        lower.startswith("color") and lower[5:].isdigit()
+    ):
+        return "", None
+    raise ValueError(
+        f"Unsupported spreadsheet code '[{code}]' at position {pos}"
+    )
+
+
+def _is_spreadsheet_minute(prev_field: _Field | None, rest: str) -> bool:
+    """Whether 'm' or 'mm' means minutes rather than the month.
+    Like spreadsheets, this is the case right after hours
+    or right before seconds.
+    """
+    if isinstance(prev_field, (_Hour24, _Hour24Unpadded)) or (
+        isinstance(prev_field, _Elapsed) and prev_field.unit == 2
+    ):
+        return True
+    i = 0
+    while i < len(rest):
+        ch = rest[i]
+        if ch == '"':
+            end = rest.find('"', i + 1)
+            if end == -1:
+                return False
+            i = end + 1
+        elif ch in "\\_*":
+            i += 2
+        elif ch == "[":
+            end = rest.find("]", i + 1)
+            if end == -1:
+                return False
+            code = rest[i + 1 : end].lower()
+            if code in ("s", "ss"):
+                return True
+            elif code[:1] in ("h", "m"):
+                return False
+            i = end + 1
+        elif ch.isalpha():
+            return ch.lower() == "s"
+        else:
+            i += 1
+    return False
+
+
+def _unsupported_spreadsheet_msg(code: str, pos: int) -> str:
+    if code[0] == ";":
+        hint = " Only formats with a single section are supported."
+    elif code[0] in "0#?":
+        hint = " Digits are only supported as fractional seconds, e.g. ss.000"
+    elif code[0].isalpha():
+        hint = ' Use quotes for literal text: "..."'
+    else:
+        hint = ""
+    return f"Unsupported spreadsheet code '{code}' at position {pos}.{hint}"
 
 
 def validate_fields(
@@ -1098,10 +1819,80 @@ def validate_fields(
     for el in elements:
         if isinstance(el, _Field) and el.category not in allowed_categories:
             raise ValueError(
-                f"{type_name} does not support pattern field {el!r}"
+                f"{type_name} does not support pattern field {el.name}"
             )
 
 
+def require_pivot_year(
+    elements: Sequence[_Element], pivot_year: int | None
+) -> None:
+    """Raise ``ValueError`` if the pattern has a two-digit year,
+    but there's no ``pivot_year`` to resolve it with.
+    This is a misuse of the API, so it's checked before parsing any input.
+    """
+    if pivot_year is None and any(isinstance(el, _Year2) for el in elements):
+        raise ValueError(_MISSING_PIVOT_YEAR)
+
+
+def check_pivot_year(pivot_year: int | None) -> int | None:
+    """Validate the ``pivot_year`` argument of ``parse()``"""
+    if pivot_year is None:
+        return None
+    if not isinstance(pivot_year, int):
+        raise TypeError("pivot_year must be an integer")
+    if not 1 <= pivot_year <= 9999:
+        raise ValueError("pivot_year out of range")
+    return pivot_year
+
+
+_FORMATS_TYPE_ERR = "format must be str, or a list or tuple of str"
+
+
+def compile_formats(
+    format: str | Sequence[str],
+    style: str,
+    allowed_categories: frozenset[str],
+    type_name: str,
+) -> list[tuple[str, Sequence[_Element]]]:
+    """Compile the ``format`` argument of ``parse()``: either a single
+    pattern string, or a list or tuple of them.
+    Returns (pattern, elements) pairs.
+    """
+    return compile_formats_with(
+        format,
+        style,
+        lambda elements: validate_fields(
+            elements, allowed_categories, type_name
+        ),
+    )
+
+
+def compile_formats_with(
+    format: str | Sequence[str],
+    style: str,
+    validate: Callable[[Sequence[_Element]], None],
+) -> list[tuple[str, Sequence[_Element]]]:
+    """Like :func:`compile_formats`, but with a custom validation
+    of each pattern.
+    """
+    if isinstance(format, str):
+        patterns: Sequence[str] = (format,)
+    elif isinstance(format, (list, tuple)):
+        if not format:
+            raise ValueError("format must contain at least one pattern")
+        patterns = format
+    else:
+        raise TypeError(_FORMATS_TYPE_ERR)
+    result = []
+    for pattern in patterns:
+        if not isinstance(pattern, str):
+            raise TypeError(_FORMATS_TYPE_ERR)
+        elements = compile_pattern(pattern, style)
+        validate(elements)
+        result.append((pattern, elements))
+    return result
+
+
 # --- Format ---
 
 
@@ -1119,6 +1910,7 @@ def format_fields(
     offset_secs: int | None = None,
     tz_id: str | None = None,
     tz_abbrev: str | None = None,
+    locale: Locale = EN,
 ) -> str:
     """Format values into a string using compiled pattern elements."""
     vals = _FormatValues(
@@ -1133,6 +1925,7 @@ def format_fields(
         offset_secs=offset_secs,
         tz_id=tz_id,
         tz_abbrev=tz_abbrev,
+        locale=locale,
     )
     parts: list[str] = []
     for el in elements:
@@ -1148,38 +1941,450 @@ def format_fields(
 # --- Parse ---
 
 
+# The ASCII whitespace characters, as recognized by the Rust extension
+_WHITESPACE = " \t\n\r\f"
+
+
+def _match_literal_lenient(
+    text: str, s: str, pos: int, before_frac: bool
+) -> int | None:
+    """Match a literal leniently, returning the position after it.
+    Any run of whitespace matches any other (non-empty) run of whitespace.
+    If the literal precedes fractional seconds,
+    a trailing ``.`` or ``,`` matches either.
+    """
+    i = 0
+    n = len(text)
+    while i < n:
+        c = text[i]
+        if c in _WHITESPACE:
+            while i < n and text[i] in _WHITESPACE:
+                i += 1
+            start = pos
+            while pos < len(s) and s[pos] in _WHITESPACE:
+                pos += 1
+            if pos == start:
+                return None
+            continue
+        if pos >= len(s):
+            return None
+        b = s[pos]
+        if not (
+            b == c
+            or (b.isascii() and c.isascii() and b.lower() == c.lower())
+            or (before_frac and i + 1 == n and c in ".," and b in ".,")
+        ):
+            return None
+        i += 1
+        pos += 1
+    return pos
+
+
 def parse_fields(
     elements: Sequence[_Element],
     s: str,
+    locale: Locale = EN,
+    lenient: bool = False,
+    pivot_year: int | None = None,
 ) -> _ParseState:
-    """Parse a string using compiled pattern elements."""
+    """Parse a string using compiled pattern elements.
+
+    In lenient mode, literals match regardless of ASCII case and amount of
+    whitespace, leading zeros may be omitted from numeric fields, either
+    ``.`` or ``,`` may separate fractional seconds, and trailing whitespace
+    is ignored.
+    """
+    require_pivot_year(elements, pivot_year)
+    state = _ParseState(locale, lenient, pivot_year)
+    _parse_elements(
+        elements,
+        s,
+        lenient,
+        lambda field, pos: field.parse_value(s, pos, state),
+    )
+    try:
+        state.resolve()
+        state.resolve_date()
+    except ValueError as e:
+        raise ParseError(str(e), s) from None
+    return state
+
+
+def _parse_elements(
+    elements: Sequence[_Element],
+    s: str,
+    lenient: bool,
+    parse_field: Callable[[_Field, int], int],
+) -> None:
+    """Match the literals of the pattern against the input,
+    passing each field and its position to ``parse_field``,
+    which returns the position after the field.
+    """
     if len(s) > 1000:
-        raise ValueError("Input string too long (max 1000 characters)")
-    state = _ParseState()
+        raise ParseError("Input string too long (max 1000 characters)", s)
     pos = 0
 
-    for el in elements:
+    for i, el in enumerate(elements):
         if isinstance(el, _Literal):
-            end = pos + len(el.text)
-            if s[pos:end] != el.text:
-                raise ValueError(
+            end: int | None
+            if lenient:
+                before_frac = i + 1 < len(elements) and isinstance(
+                    elements[i + 1], (_FracExact, _FracTrim)
+                )
+                end = _match_literal_lenient(el.text, s, pos, before_frac)
+            else:
+                end = pos + len(el.text)
+                if s[pos:end] != el.text:
+                    end = None
+            if end is None:
+                raise ParseError(
                     f"Expected {el.text!r} at position {pos}, "
-                    f"got {s[pos:end]!r}"
+                    f"got {s[pos : pos + len(el.text)]!r}",
+                    s,
+                    pos,
+                    el.text,
                 )
             pos = end
         else:
             assert isinstance(el, _Field)
             if el.format_only:
-                raise ValueError(
+                raise ParseError(
                     f"Field {el!r} is only supported for "
-                    f"formatting, not parsing"
+                    f"formatting, not parsing",
+                    s,
+                    pos,
+                    repr(el),
                 )
-            pos = el.parse_value(s, pos, state)
+            try:
+                pos = parse_field(el, pos)
+            except ValueError as e:
+                raise ParseError(str(e), s, pos, repr(el)) from None
 
+    if lenient and not s[pos:].strip(_WHITESPACE):
+        pos = len(s)
     if pos != len(s):
+        raise ParseError(
+            f"Unexpected trailing text at position {pos}: {s[pos:]!r}",
+            s,
+            pos,
+        )
+
+
+def parse_formats(
+    formats: Sequence[tuple[str, Sequence[_Element]]],
+    s: str,
+    locale: str | None = None,
+    lenient: bool = False,
+    pivot_year: int | None = None,
+) -> _ParseState:
+    """Parse with the first of the compiled patterns that matches the input.
+    If none match, the error lists why each pattern failed,
+    and points to the failure that got furthest into the input.
+    """
+    # Reject a conflicting locale or missing pivot year
+    # before parsing with any of the patterns
+    for _, elements in formats:
+        pattern_locale(elements, locale)
+        require_pivot_year(elements, pivot_year)
+    return _parse_with(
+        formats,
+        s,
+        lambda elements: parse_fields(
+            elements, s, pattern_locale(elements, locale), lenient, pivot_year
+        ),
+    )
+
+
+def _parse_with(
+    formats: Sequence[tuple[str, Sequence[_Element]]],
+    s: str,
+    parse: Callable[[Sequence[_Element]], _T],
+) -> _T:
+    if len(formats) == 1:
+        return parse(formats[0][1])
+    reasons = []
+    position: int | None = None
+    expected: str | None = None
+    for pattern, elements in formats:
+        try:
+            return parse(elements)
+        except ParseError as e:
+            reasons.append(f"\n- {pattern!r}: {e}")
+            # Report the failure that got furthest into the input
+            if e.position is not None and (
+                position is None or e.position > position
+            ):
+                position, expected = e.position, e.expected
+    raise ParseError(
+        f"Input doesn't match any of the {len(formats)} patterns:"
+        + "".join(reasons),
+        s,
+        position,
+        expected,
+    )
+
+
+# --- Deltas ---
+
+# Smallest first: (name, fields, seconds, ratio to the next larger unit)
+_DELTA_UNITS = (
+    ("seconds", "s/ss", 1, 60),
+    ("minutes", "m/mm", 60, 60),
+    ("hours", "h/hh", 3600, 24),
+    ("days", "D/DD", 86_400, None),
+)
+
+# The unit index of each delta field, and whether it's zero-padded
+_DELTA_FIELDS: dict[type[_Field], tuple[int, bool]] = {
+    _Second: (0, True),
+    _SecondUnpadded: (0, False),
+    _Minute: (1, True),
+    _MinuteUnpadded: (1, False),
+    _Hour24: (2, True),
+    _Hour24Unpadded: (2, False),
+    _Day: (3, True),
+    _DayUnpadded: (3, False),
+}
+
+_DELTA_OUT_OF_RANGE = "delta out of range"
+
+
+def _delta_unit(el: _Element) -> tuple[int, bool] | None:
+    """The unit of a delta field, and whether it's zero-padded"""
+    if isinstance(el, _Elapsed):
+        return el.unit, el.padded
+    return _DELTA_FIELDS.get(type(el))
+
+
+def validate_delta_fields(
+    elements: Sequence[_Element], type_name: str, style: str = "pattern"
+) -> None:
+    """Check that a pattern only has delta fields, and that its units are
+    consecutive (e.g. not hours and seconds without minutes).
+    """
+    if style == "strftime":
+        raise ValueError(
+            f"{type_name} patterns don't support style='strftime'"
+        )
+    units = [False] * 4
+    has_frac = False
+    for el in elements:
+        if not isinstance(el, _Field):
+            continue
+        if isinstance(el, (_FracExact, _FracTrim, _DotFrac)):
+            has_frac = True
+        elif isinstance(el, (_SignMinus, _SignAlways)):
+            pass
+        elif (unit := _delta_unit(el)) is not None:
+            units[unit[0]] = True
+        else:
+            raise ValueError(
+                f"{type_name} does not support pattern field {el.name}"
+            )
+    if not any(units):
+        raise ValueError(
+            f"{type_name} pattern must include days (D/DD), hours (h/hh), "
+            "minutes (m/mm), or seconds (s/ss)"
+        )
+    smallest = units.index(True)
+    largest = 3 - units[::-1].index(True)
+    for i in range(smallest, largest):
+        if not units[i]:
+            name, fields, _, _ = _DELTA_UNITS[i]
+            raise ValueError(
+                "Pattern fields must be consecutive units, "
+                f"but {name} ({fields}) is missing"
+            )
+    if has_frac and not units[0]:
         raise ValueError(
-            f"Unexpected trailing text at position {pos}: {s[pos:]!r}"
+            "Fractional seconds (f/F) require a seconds field (s/ss)"
         )
+    _digits_after_largest(elements)
+    if style == "spreadsheet":
+        _validate_elapsed_fields(elements, type_name)
 
-    state.resolve()
-    return state
+
+def _validate_elapsed_fields(
+    elements: Sequence[_Element], type_name: str
+) -> None:
+    """Check that the field of the largest unit of a spreadsheet format
+    is an elapsed-time code (e.g. ``[h]``), and the other fields aren't.
+    Without one, spreadsheets show a time of day instead of a duration.
+    """
+    largest = _largest_delta_unit(elements)
+    for el in elements:
+        unit = _delta_unit(el)
+        if unit is not None and isinstance(el, _Elapsed) != (
+            unit[0] == largest
+        ):
+            raise ValueError(
+                f"Spreadsheet formats for {type_name} must use an "
+                "elapsed-time code ([h], [m], or [s]) for the largest unit, "
+                "e.g. [h]:mm:ss"
+            )
+
+
+def _largest_delta_unit(elements: Sequence[_Element]) -> int:
+    """The largest unit of a (validated) delta pattern.
+    Its field holds the total amount, while smaller units hold the remainder.
+    """
+    return max(
+        (unit[0] for el in elements if (unit := _delta_unit(el))),
+        default=0,
+    )
+
+
+def _digits_after_largest(elements: Sequence[_Element]) -> int:
+    """The number of digits of the fixed-width fields directly following the
+    field of the largest unit. Since the latter may have any number of
+    digits, it needs to leave these for the following fields when parsing.
+    Fails if a variable-width field follows without a separator,
+    which would be ambiguous.
+    """
+    largest = _largest_delta_unit(elements)
+    it = iter(elements)
+    for el in it:
+        if (unit := _delta_unit(el)) is not None and unit[0] == largest:
+            break
+    count = 0
+    for el in it:
+        if isinstance(el, _FracExact):
+            count += el.width
+            continue
+        unit = _delta_unit(el)
+        if isinstance(el, _FracTrim) or (unit is not None and not unit[1]):
+            raise ValueError(
+                f"Field {el.name} can't follow the field of the "
+                "largest unit without a separator, "
+                "since both have a variable number of digits"
+            )
+        elif unit is None:
+            break
+        count += 2
+    return count
+
+
+def format_delta(elements: Sequence[_Element], nanos: int) -> str:
+    """Format a delta of ``nanos`` with a validated delta pattern"""
+    neg = nanos < 0
+    secs, subsec = divmod(abs(nanos), 1_000_000_000)
+    largest = _largest_delta_unit(elements)
+    has_sign = False
+    frac = _FormatValues(nanos=subsec)
+    parts: list[str] = []
+    for el in elements:
+        if isinstance(el, _Literal):
+            parts.append(el.text)
+        elif isinstance(el, _SignMinus):
+            has_sign = True
+            if neg:
+                parts.append("-")
+        elif isinstance(el, _SignAlways):
+            has_sign = True
+            parts.append("-" if neg else "+")
+        elif isinstance(el, (_FracExact, _FracTrim, _DotFrac)):
+            parts.append(el.format_value(frac))
+        else:
+            unit, padded = _delta_unit(el)  # type: ignore[misc]
+            if isinstance(el, _Elapsed):
+                has_sign = True
+                if neg:
+                    parts.append("-")
+            _, _, unit_secs, ratio = _DELTA_UNITS[unit]
+            n = secs // unit_secs
+            if unit < largest:
+                n %= ratio
+            parts.append(f"{n:02d}" if padded else str(n))
+    if neg and not has_sign:
+        raise ValueError(
+            "Cannot format a negative delta without a sign field (n or nn)"
+        )
+    return "".join(parts)
+
+
+def parse_delta(
+    elements: Sequence[_Element], s: str, lenient: bool = False
+) -> int:
+    """Parse a delta with a validated delta pattern, returning nanoseconds.
+    The field of the largest unit may have any number of digits,
+    while the smaller units must be in range (e.g. minutes below 60).
+    """
+    largest = _largest_delta_unit(elements)
+    reserved = _digits_after_largest(elements)
+    neg = False
+    secs = 0
+    # Fractional seconds are parsed as for other types
+    frac = _ParseState(lenient=lenient)
+
+    def parse_field(field: _Field, pos: int) -> int:
+        nonlocal neg, secs
+        if isinstance(field, (_SignMinus, _SignAlways)):
+            c = s[pos : pos + 1]
+            if c == "-":
+                neg = True
+                return pos + 1
+            elif c == "+" and (lenient or isinstance(field, _SignAlways)):
+                return pos + 1
+            elif lenient or isinstance(field, _SignMinus):
+                return pos
+            raise ValueError(f"Expected sign (+ or -) at position {pos}")
+        elif isinstance(field, (_FracExact, _FracTrim, _DotFrac)):
+            return field.parse_value(s, pos, frac)
+        unit, padded = _delta_unit(field)  # type: ignore[misc]
+        name, _, unit_secs, ratio = _DELTA_UNITS[unit]
+        if isinstance(field, _Elapsed) and s[pos : pos + 1] == "-":
+            neg = True
+            pos += 1
+        if unit == largest:
+            n, pos = _parse_delta_total(
+                s, pos, padded and not lenient, reserved
+            )
+        else:
+            if padded:
+                n, pos = _parse_padded(s, pos, 2, frac)
+            else:
+                n, pos = _parse_1or2_digits(s, pos)
+            if n >= ratio:
+                raise ValueError(f"{name} out of range: {n}")
+        secs += n * unit_secs
+        # Mirror the overflow check of the Rust extension
+        if secs >= 2**64:
+            raise ValueError(_DELTA_OUT_OF_RANGE)
+        return pos
+
+    _parse_elements(elements, s, lenient, parse_field)
+    nanos = secs * 1_000_000_000 + frac.nanos
+    return -nanos if neg else nanos
+
+
+def _parse_delta_total(
+    s: str, pos: int, padded: bool, reserved: int
+) -> tuple[int, int]:
+    """Parse the total amount of the largest unit of a delta:
+    any number of digits, but at least two if ``padded``.
+    The last ``reserved`` digits are left for the fields that follow.
+    """
+    end = pos
+    while end < len(s) and s[end] in "0123456789":
+        end += 1
+    end = max(pos, end - reserved)
+    if end == pos or (padded and end - pos < 2):
+        expected = "at least 2 digits" if padded else "a digit"
+        raise ValueError(f"Expected {expected} at position {pos}")
+    n = int(s[pos:end])
+    if n >= 2**64:
+        raise ValueError(_DELTA_OUT_OF_RANGE)
+    return n, end
+
+
+def parse_delta_formats(
+    formats: Sequence[tuple[str, Sequence[_Element]]],
+    s: str,
+    lenient: bool = False,
+) -> int:
+    """Parse a delta with the first of the compiled patterns that matches.
+    See :func:`parse_formats`.
+    """
+    return _parse_with(
+        formats, s, lambda elements: parse_delta(elements, s, lenient)
+    )
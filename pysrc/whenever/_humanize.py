@@ -0,0 +1,143 @@
+"""Selecting a unit and amount to describe a difference in time,
+e.g. "in 3 hours" or "2 days ago".
+
+NOTE: keep this in sync with the Rust implementation in
+``src/domain/humanize.rs``.
+"""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+from math import modf
+
+from ._locale import RelativeTime
+
+# Largest first, matching the order of the locale tables
+UNITS = ("years", "months", "weeks", "days", "hours", "minutes", "seconds")
+_CALENDAR_UNITS = ("years", "months", "weeks", "days")
+_EXACT_NANOS = {
+    "weeks": 604_800_000_000_000,
+    "days": 86_400_000_000_000,
+    "hours": 3_600_000_000_000,
+    "minutes": 60_000_000_000,
+    "seconds": 1_000_000_000,
+}
+_ROUND_MODES = (
+    "floor",
+    "ceil",
+    "trunc",
+    "expand",
+    "half_floor",
+    "half_ceil",
+    "half_even",
+    "half_trunc",
+    "half_expand",
+)
+
+
+def _promote(unit: str, calendar: bool) -> tuple[str, int] | None:
+    """The next larger unit, if it always consists of a whole number
+    of this unit"""
+    if unit == "seconds":
+        return ("minutes", 60)
+    elif unit == "minutes":
+        return ("hours", 60)
+    elif unit == "hours" and not calendar:
+        # Calendar days aren't always 24 hours
+        return ("days", 24)
+    elif unit == "days":
+        return ("weeks", 7)
+    elif unit == "months":
+        return ("years", 12)
+    return None
+
+
+def _abs_mode(mode: str, neg: bool) -> str:
+    """The rounding mode to apply to the absolute amount"""
+    if mode == "floor":
+        return "expand" if neg else "trunc"
+    elif mode == "ceil":
+        return "trunc" if neg else "expand"
+    elif mode == "half_floor":
+        return "half_expand" if neg else "half_trunc"
+    elif mode == "half_ceil":
+        return "half_trunc" if neg else "half_expand"
+    return mode
+
+
+def _round(amount: int | float, unit_nanos: int | None, mode: str) -> int:
+    if unit_nanos is None:  # a fractional amount
+        frac, whole = modf(amount)
+        quotient, remainder_cmp = int(whole), (frac * 2 > 1) - (frac * 2 < 1)
+        has_remainder = frac != 0
+    else:
+        quotient, r = divmod(int(amount), unit_nanos)
+        remainder_cmp = (r * 2 > unit_nanos) - (r * 2 < unit_nanos)
+        has_remainder = r != 0
+    if mode == "expand":
+        expand = has_remainder
+    elif mode == "half_trunc":
+        expand = remainder_cmp > 0
+    elif mode == "half_expand":
+        expand = remainder_cmp >= 0
+    elif mode == "half_even":
+        expand = remainder_cmp > 0 or (remainder_cmp == 0 and quotient % 2)
+    else:
+        expand = False
+    return quotient + bool(expand)
+
+
+def check_args(granularity: object, round_mode: object) -> None:
+    if granularity not in UNITS:
+        raise ValueError(f"Invalid value for granularity: {granularity!r}")
+    if round_mode not in _ROUND_MODES:
+        raise ValueError(f"Invalid value for round_mode: {round_mode!r}")
+
+
+def select(
+    nanos: int,
+    calendar: Callable[[str], float] | None,
+    granularity: str,
+    mode: str,
+) -> tuple[str, int] | None:
+    """Select the unit and (rounded) amount to describe a difference
+    of ``nanos``.
+
+    The largest unit with an absolute amount of at least one is chosen,
+    but never one smaller than ``granularity``. If ``calendar`` is given,
+    it returns the fractional amount of a calendar unit (days, weeks,
+    months, years). Otherwise, days and weeks are taken to be 24 hours
+    and 7 days, and months and years aren't used.
+    Returns ``None`` if the granularity is too large.
+    """
+    neg = nanos < 0
+    units = UNITS[: UNITS.index(granularity) + 1]
+    if calendar is None:
+        units = units[2:]
+    chosen = None
+    for unit in units:
+        unit_nanos: int | None
+        if calendar is not None and unit in _CALENDAR_UNITS:
+            amount: int | float = abs(calendar(unit))
+            unit_nanos = None
+            at_least_one = amount >= 1
+        else:
+            amount = abs(nanos)
+            unit_nanos = _EXACT_NANOS[unit]
+            at_least_one = amount >= unit_nanos
+        chosen = (unit, amount, unit_nanos)
+        if at_least_one:
+            break
+    if chosen is None:
+        return None
+    unit, amount, unit_nanos = chosen
+    n = _round(amount, unit_nanos, _abs_mode(mode, neg))
+    # Rounding may have reached a whole larger unit, e.g. 60 minutes
+    promote = _promote(unit, calendar is not None)
+    if promote and promote[1] == n:
+        unit, n = promote[0], 1
+    return unit, -n if neg else n
+
+
+def format(phrases: RelativeTime, unit: str, amount: int) -> str:
+    return phrases.format(UNITS.index(unit), amount)
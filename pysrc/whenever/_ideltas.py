@@ -18,6 +18,7 @@ from typing import (
     TYPE_CHECKING,
     Any,
     Iterator,
+    Literal,
     Sequence,
     TypeVar,
     cast,
@@ -30,6 +31,7 @@ from ._common import (
     OFFSET_SHIFT_STALE_MSG,
     PLAIN_SHIFT_UNAWARE_MSG,
     SPHINX_RUNNING,  # noqa
+    ParseError,
     UNSET,
     WARNING_HANDLING_DOCS_MSG,
     WheneverWarning,
@@ -45,7 +47,12 @@ from ._math import (
     Sign,
     resolve_leap_day,
 )
-from ._parse import parse_timedelta_component
+from ._parse import (
+    locate_failure,
+    parse_timedelta_component,
+    read_itemized_date_delta_iso,
+    read_itemized_delta_iso,
+)
 from ._typing import DateDeltaUnitStr, DeltaUnitStr, RoundModeStr
 
 if TYPE_CHECKING:
@@ -665,6 +672,7 @@ class ItemizedDelta(_Base, Mapping[DeltaUnitStr, int]):
         return joined
 
     @classmethod
+    @locate_failure(read_itemized_delta_iso)
     def parse_iso(cls, s: str, /) -> ItemizedDelta:
         """Parse the *popular interpretation* of the ISO 8601 duration format.
         Does not parse all possible ISO 8601 durations.
@@ -685,7 +693,7 @@ class ItemizedDelta(_Base, Mapping[DeltaUnitStr, int]):
         >>> ItemizeDelta.parse_iso("-P1W11DT4H")
         ItemizeDelta("-P1w11dT4h")
         """
-        exc = ValueError(f"Invalid format: {s!r}")
+        exc = ParseError(f"Invalid format: {s!r}", s)
         prev_unit = ""
         years, months, weeks, days, hours, minutes, seconds, nanos = (
             None,
@@ -1426,6 +1434,79 @@ class ItemizedDelta(_Base, Mapping[DeltaUnitStr, int]):
             unit, relative_to=relative_to
         )
 
+    def humanize(
+        self,
+        *,
+        relative_to: _whenever.ZonedDateTime
+        | _whenever.PlainDateTime
+        | _whenever.OffsetDateTime,
+        granularity: Literal[
+            "years", "months", "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+    ) -> str:
+        """Describe the delta in words, such as "in 3 hours" or "2 months ago"
+
+        The delta is added to ``relative_to``, and the result is described
+        with :meth:`ZonedDateTime.humanize`.
+
+        Parameters
+        ----------
+        relative_to
+            A :class:`ZonedDateTime`, :class:`PlainDateTime`, or
+            :class:`OffsetDateTime` reference point.
+
+            - :class:`ZonedDateTime`: DST-aware; emits no warning
+            - :class:`PlainDateTime`: emits :class:`NaiveArithmeticWarning`
+              if the delta contains exact time units
+            - :class:`OffsetDateTime`: emits :class:`StaleOffsetWarning`
+
+        >>> d = ItemizedDelta(months=1, days=20)
+        >>> ref = ZonedDateTime(2024, 1, 1, tz="Europe/Paris")
+        >>> d.humanize(relative_to=ref)
+        'in 1 month'
+        >>> d.humanize(relative_to=ref, round_mode="half_even")
+        'in 2 months'
+        """
+        from ._core import (
+            NaiveArithmeticWarning,
+            OffsetDateTime,
+            PlainDateTime,
+            StaleOffsetWarning,
+            ZonedDateTime,
+        )
+        from ._pywhenever import (
+            PLAIN_RELATIVE_TO_UNAWARE_MSG,
+            STALE_OFFSET_CALENDAR_MSG,
+        )
+
+        if isinstance(relative_to, PlainDateTime):
+            if self._has_exact_time():
+                warn(
+                    PLAIN_RELATIVE_TO_UNAWARE_MSG,
+                    NaiveArithmeticWarning,
+                    stacklevel=2,
+                )
+            relative_to = relative_to.assume_tz("UTC")
+        elif isinstance(relative_to, OffsetDateTime):
+            warn(
+                StaleOffsetWarning(STALE_OFFSET_CALENDAR_MSG),
+                stacklevel=2,
+            )
+            relative_to = relative_to.to_plain().assume_tz("UTC")
+        elif not isinstance(relative_to, ZonedDateTime):
+            raise TypeError(
+                "relative_to must be a ZonedDateTime, PlainDateTime, "
+                "or OffsetDateTime"
+            )
+        return relative_to.add(self).humanize(
+            relative_to=relative_to,
+            granularity=granularity,
+            round_mode=round_mode,
+            locale=locale,
+        )
+
     if not TYPE_CHECKING:
         # This overload ensures it shows up nicely in the API docs, not just as "kwargs"
         @overload
@@ -1763,6 +1844,7 @@ class ItemizedDateDelta(_Base, Mapping[DateDeltaUnitStr, int]):
         return "".join(parts)
 
     @classmethod
+    @locate_failure(read_itemized_date_delta_iso)
     def parse_iso(cls, s: str, /) -> ItemizedDateDelta:
         """Parse the *popular interpretation* of the ISO 8601 duration format.
         Inverse of :meth:`format_iso`
@@ -1779,7 +1861,7 @@ class ItemizedDateDelta(_Base, Mapping[DateDeltaUnitStr, int]):
         it doesn't allow fractional values.
         See :ref:`here <iso8601-durations>` for more information.
         """
-        exc = ValueError(f"Invalid format: {s!r}")
+        exc = ParseError(f"Invalid format: {s!r}", s)
 
         # Catch certain invalid strings early, making parsing easier
         if len(s) < 3 or not s.isascii():
@@ -0,0 +1,602 @@
+"""Pure-Python implementation of Interval and RepeatingInterval.
+
+These types are always pure Python, even when the Rust extension is active.
+They build on the ISO 8601 parsing and arithmetic of the endpoint types.
+"""
+
+from __future__ import annotations
+
+from contextlib import contextmanager
+from itertools import count
+from typing import (
+    TYPE_CHECKING,
+    Any,
+    Callable,
+    Generic,
+    Iterator,
+    NoReturn,
+    TypeVar,
+    Union,
+    no_type_check,
+)
+
+from ._common import SPHINX_RUNNING, ParseError, final
+
+if TYPE_CHECKING:
+    from . import _pywhenever as _whenever
+    from ._ideltas import ItemizedDateDelta, ItemizedDelta
+    from ._pywhenever import Date, Instant, OffsetDateTime, PlainDateTime
+else:
+    import whenever as _whenever
+
+_object_new = object.__new__
+
+_T = TypeVar("_T", "Date", "PlainDateTime", "OffsetDateTime", "Instant")
+_Delta = Union["ItemizedDelta", "ItemizedDateDelta"]
+_R = TypeVar("_R")
+
+
+@final
+class Interval(Generic[_T]):
+    """A span of time between two dates or datetimes,
+    as used in the ISO 8601 interval format.
+
+    The interval includes its start, but not its end.
+    It can be defined by its start and end, or by one of them and
+    a duration:
+
+    >>> Interval(Date(2025, 2, 10), Date(2025, 2, 15))
+    Interval("2025-02-10/2025-02-15")
+    >>> Interval(Date(2025, 2, 10), duration=ItemizedDateDelta(days=5))
+    Interval("2025-02-10/P5D")
+    >>> Interval(end=Date(2025, 2, 15), duration=ItemizedDateDelta(days=5))
+    Interval("P5D/2025-02-15")
+
+    The endpoints are :class:`Date`, :class:`PlainDateTime`,
+    :class:`OffsetDateTime`, or :class:`Instant`, and the duration
+    is an :class:`ItemizedDateDelta` (for dates) or :class:`ItemizedDelta`.
+
+    Note
+    ----
+    A duration is applied in the local time of the endpoint,
+    keeping its fixed offset (if any). Instants use UTC.
+    This is how ISO 8601 defines intervals,
+    so no :class:`StaleOffsetWarning` or :class:`NaiveArithmeticWarning`
+    is emitted.
+    """
+
+    __slots__ = ("_start", "_end", "_duration", "_from_end")
+
+    def __init__(
+        self,
+        start: _T | None = None,
+        end: _T | None = None,
+        *,
+        duration: _Delta | None = None,
+    ) -> None:
+        if duration is None:
+            if start is None or end is None:
+                raise TypeError(
+                    "Interval requires two of start, end, and duration"
+                )
+            kind = _kind_of(start)
+            if _kind_of(end) is not kind:
+                raise TypeError(
+                    "start and end must be of the same type, "
+                    f"got {type(start).__name__} and {type(end).__name__}"
+                )
+            if end < start:  # type: ignore[operator]
+                raise ValueError("Interval end must not be before its start")
+            self._init(start, end, None, False)
+        elif start is not None and end is not None:
+            raise TypeError(
+                "Interval requires two of start, end, and duration, not three"
+            )
+        elif start is not None:
+            _check_duration(start, duration)
+            self._init(start, _shift(start, duration, 1), duration, False)
+        elif end is not None:
+            _check_duration(end, duration)
+            self._init(_shift(end, duration, -1), end, duration, True)
+        else:
+            raise TypeError(
+                "Interval requires two of start, end, and duration"
+            )
+
+    def _init(
+        self, start: _T, end: _T, duration: _Delta | None, from_end: bool
+    ) -> None:
+        self._start = start
+        self._end = end
+        self._duration = duration
+        self._from_end = from_end
+
+    @property
+    def start(self) -> _T:
+        """The start of the interval (inclusive)"""
+        return self._start
+
+    @property
+    def end(self) -> _T:
+        """The end of the interval (exclusive)"""
+        return self._end
+
+    @property
+    def duration(self) -> _Delta | None:
+        """The duration the interval was defined with,
+        or ``None`` if it was defined by its start and end"""
+        return self._duration
+
+    def __contains__(self, value: _T) -> bool:
+        """Whether the date or datetime lies within the interval
+
+        >>> Date(2025, 2, 12) in Interval.parse_iso("2025-02-10/15", Date)
+        True
+        """
+        if type(value) is not type(self._start):
+            return False
+        return self._start <= value < self._end  # type: ignore[operator]
+
+    @classmethod
+    def parse_iso(cls, s: str, type: type[_T], /) -> Interval[_T]:
+        """Parse an ISO 8601 interval with endpoints of the given type.
+
+        The forms ``start/end``, ``start/duration``,
+        and ``duration/end`` are supported.
+        The ``/`` may also be written as ``--``.
+        Leading components of the end which are the same
+        as the start may be omitted, as may its offset:
+
+        >>> Interval.parse_iso("2025-02-10/15", Date)
+        Interval("2025-02-10/2025-02-15")
+        >>> Interval.parse_iso("2025-02-10T09:00+01:00/PT8H", OffsetDateTime)
+        Interval("2025-02-10T09:00:00+01:00/PT8H")
+        >>> Interval.parse_iso("2025-02-10T09:00Z/17:30", Instant)
+        Interval("2025-02-10T09:00:00Z/2025-02-10T17:30:00Z")
+        """
+        kind = _check_kind(type)
+        sep, at = _find_separator(s)
+        first, second = s[:sep], s[at:]
+        point = "date" if kind is _whenever.Date else "datetime"
+        if _is_duration(first):
+            if _is_duration(second):
+                _interval_err(s, at, point)
+            duration = _segment(s, 0, _parse_duration, first, kind)
+            end = _segment(s, at, _parse_point, second, kind)
+            with _failing_at(s, 0, "duration"):
+                return cls(end=end, duration=duration)
+        start = _segment(s, 0, _parse_point, first, kind)
+        if _is_duration(second):
+            duration = _segment(s, at, _parse_duration, second, kind)
+            with _failing_at(s, at, "duration"):
+                return cls(start, duration=duration)
+        end = _segment(s, at, _parse_end, first, second, kind)
+        with _failing_at(s, at, point):
+            return cls(start, end)
+
+    def format_iso(self, *, abbreviate: bool = False) -> str:
+        """Format in the ISO 8601 interval format,
+        in the form it was defined with.
+
+        With ``abbreviate=True``, leading date components of the end
+        which are the same as the start are omitted.
+
+        >>> Interval(Date(2025, 2, 10), Date(2025, 2, 15)).format_iso()
+        '2025-02-10/2025-02-15'
+        >>> Interval(Date(2025, 2, 10), Date(2025, 2, 15)).format_iso(
+        ...     abbreviate=True
+        ... )
+        '2025-02-10/15'
+        """
+        start, end = self._start.format_iso(), self._end.format_iso()
+        if self._duration is not None:
+            if self._from_end:
+                return f"{self._duration.format_iso()}/{end}"
+            return f"{start}/{self._duration.format_iso()}"
+        if abbreviate:
+            end = _abbreviate(start, end)
+        return f"{start}/{end}"
+
+    def __str__(self) -> str:
+        return self.format_iso()
+
+    def __repr__(self) -> str:
+        return f'Interval("{self}")'
+
+    def __eq__(self, other: object) -> bool:
+        """Compare for equality. Intervals are equal if they have
+        the same endpoints and were defined the same way
+
+        >>> Interval.parse_iso("2025-02-10/15", Date) == Interval(
+        ...     Date(2025, 2, 10), Date(2025, 2, 15)
+        ... )
+        True
+        """
+        if not isinstance(other, Interval):
+            return NotImplemented
+        return (
+            _kind_of(self._start) is _kind_of(other._start)
+            and self._key() == other._key()
+        )
+
+    def __hash__(self) -> int:
+        # Deltas aren't hashable, but leaving them out is still
+        # consistent with equality
+        return hash(self._key()[:2])
+
+    def _key(self) -> tuple[Any, ...]:
+        start: Any = self._start
+        end: Any = self._end
+        # Use exact equality, so that intervals with the same
+        # instants but different offsets are not considered equal
+        if isinstance(start, _whenever.OffsetDateTime):
+            start = (start.to_plain(), start.offset)
+            end = (end.to_plain(), end.offset)
+        return (start, end, self._duration, self._from_end)
+
+    @no_type_check
+    def __reduce__(self):
+        return (
+            _unpkl_interval,
+            (self._start, self._end, self._duration, self._from_end),
+        )
+
+
+@final
+class RepeatingInterval(Generic[_T]):
+    """An interval which repeats a number of times,
+    or indefinitely.
+
+    Iterating over it gives the successive intervals.
+    Each one starts where the previous one ends.
+    For intervals defined by a duration and an end,
+    the repetitions go back in time instead.
+    The n-th interval is offset from the first by n times its duration,
+    so that calendar durations don't drift:
+
+    >>> r = RepeatingInterval.parse_iso("R3/2025-01-31/P1M", Date)
+    >>> for interval in r:
+    ...     print(interval.start, interval.end)
+    2025-01-31 2025-02-28
+    2025-02-28 2025-03-31
+    2025-03-31 2025-04-30
+
+    Unbounded repetition (``R/``) stops at the end of the supported range.
+    """
+
+    __slots__ = ("_interval", "_repetitions")
+
+    def __init__(
+        self, interval: Interval[_T], repetitions: int | None = None
+    ) -> None:
+        if not isinstance(interval, Interval):
+            raise TypeError("interval must be an Interval")
+        if repetitions is not None:
+            if not isinstance(repetitions, int):
+                raise TypeError("repetitions must be an integer or None")
+            if repetitions < 0:
+                raise ValueError("repetitions must not be negative")
+        self._interval = interval
+        self._repetitions = repetitions
+
+    @property
+    def interval(self) -> Interval[_T]:
+        """The first interval"""
+        return self._interval
+
+    @property
+    def repetitions(self) -> int | None:
+        """The number of intervals, or ``None`` if unbounded"""
+        return self._repetitions
+
+    def __iter__(self) -> Iterator[Interval[_T]]:
+        reps = self._repetitions
+        for n in count() if reps is None else range(reps):
+            try:
+                yield _nth_interval(self._interval, n)
+            except (ValueError, OverflowError):
+                return
+
+    @classmethod
+    def parse_iso(cls, s: str, type: type[_T], /) -> RepeatingInterval[_T]:
+        """Parse an ISO 8601 repeating interval like
+        ``R5/2025-01-01T00:00Z/P1D``, with endpoints of the given type.
+
+        The number of repetitions may be omitted (``R/...``)
+        for unbounded repetition.
+
+        >>> RepeatingInterval.parse_iso("R5/2025-01-01T00:00Z/P1D", Instant)
+        RepeatingInterval("R5/2025-01-01T00:00:00Z/P1D")
+        """
+        if s[:1] not in ("R", "r"):
+            _interval_err(s, 0, "R")
+        sep, at = _find_separator(s)
+        count = s[1:sep]
+        for i, c in enumerate(count, start=1):
+            if c not in _DIGITS:
+                _interval_err(s, i, "/")
+        interval = _segment(s, at, Interval.parse_iso, s[at:], type)
+        return cls(interval, int(count) if count else None)
+
+    def format_iso(self) -> str:
+        """Format in the ISO 8601 repeating interval format
+
+        >>> RepeatingInterval(
+        ...     Interval(Date(2025, 1, 1), duration=ItemizedDateDelta(weeks=1)),
+        ...     4,
+        ... ).format_iso()
+        'R4/2025-01-01/P1W'
+        """
+        count = "" if self._repetitions is None else self._repetitions
+        return f"R{count}/{self._interval.format_iso()}"
+
+    def __str__(self) -> str:
+        return self.format_iso()
+
+    def __repr__(self) -> str:
+        return f'RepeatingInterval("{self}")'
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, RepeatingInterval):
+            return NotImplemented
+        return (
+            self._interval == other._interval
+            and self._repetitions == other._repetitions
+        )
+
+    def __hash__(self) -> int:
+        return hash((self._interval, self._repetitions))
+
+    @no_type_check
+    def __reduce__(self):
+        return (RepeatingInterval, (self._interval, self._repetitions))
+
+
+def _check_kind(cls: type) -> type:
+    if cls not in (
+        _whenever.Date,
+        _whenever.PlainDateTime,
+        _whenever.OffsetDateTime,
+        _whenever.Instant,
+    ):
+        raise TypeError(
+            "interval endpoints must be Date, PlainDateTime, "
+            f"OffsetDateTime, or Instant, not {getattr(cls, '__name__', cls)}"
+        )
+    return cls
+
+
+def _kind_of(value: object) -> type:
+    return _check_kind(type(value))
+
+
+def _check_duration(point: object, duration: object) -> None:
+    expected = (
+        _whenever.ItemizedDateDelta
+        if _kind_of(point) is _whenever.Date
+        else _whenever.ItemizedDelta
+    )
+    if type(duration) is not expected:
+        raise TypeError(
+            f"duration must be an {expected.__name__} "
+            f"for {type(point).__name__} endpoints"
+        )
+    if duration.sign() < 0:  # type: ignore[attr-defined]
+        raise ValueError("Interval duration must not be negative")
+
+
+def _shift(point: Any, delta: Any, sign: int) -> Any:
+    if sign < 0:
+        delta = -delta
+    if isinstance(point, _whenever.Date):
+        return point.add(delta)
+    return _from_odt(_to_odt(point).add(delta, stale_offset_ok=True), point)
+
+
+def _to_odt(point: Any) -> OffsetDateTime:
+    if isinstance(point, _whenever.OffsetDateTime):
+        return point
+    elif isinstance(point, _whenever.Instant):
+        return point.to_fixed_offset()
+    return point.assume_fixed_offset(0)
+
+
+def _from_odt(odt: OffsetDateTime, like: object) -> Any:
+    if isinstance(like, _whenever.OffsetDateTime):
+        return odt
+    elif isinstance(like, _whenever.Instant):
+        return odt.to_instant()
+    return odt.to_plain()
+
+
+def _nth_interval(first: Interval[Any], n: int) -> Interval[Any]:
+    # Offset from the first interval (instead of the previous one),
+    # so that calendar units don't drift, e.g. from the 31st to the 28th.
+    if n == 0:
+        return first
+    start, end, duration = first._start, first._end, first._duration
+    if duration is not None:
+        if first._from_end:
+            return _interval_unchecked(
+                _shift(end, _times(duration, n + 1), -1),
+                _shift(end, _times(duration, n), -1),
+                duration,
+                True,
+            )
+        return _interval_unchecked(
+            _shift(start, _times(duration, n), 1),
+            _shift(start, _times(duration, n + 1), 1),
+            duration,
+            False,
+        )
+    if isinstance(start, _whenever.Date):
+        days = end.since(start, in_units=["days"])["days"]
+        return Interval(
+            start.add(days=days * n), start.add(days=days * (n + 1))
+        )
+    step = _to_odt(end) - _to_odt(start)
+    first_odt = _to_odt(start)
+    return Interval(
+        _from_odt(first_odt.add(step * n, stale_offset_ok=True), start),
+        _from_odt(first_odt.add(step * (n + 1), stale_offset_ok=True), start),
+    )
+
+
+def _times(delta: _Delta, n: int) -> _Delta:
+    return type(delta)(**{unit: v * n for unit, v in delta.items()})
+
+
+_DIGITS = "0123456789"
+
+
+def _find_separator(s: str) -> tuple[int, int]:
+    """The start and end of the first separator: a ``/``, or the ``--``
+    that ISO 8601 allows instead (e.g. for use in file names)"""
+    slash, hyphens = s.find("/"), s.find("--")
+    if hyphens >= 0 and (slash < 0 or hyphens < slash):
+        return hyphens, hyphens + 2
+    elif slash >= 0:
+        return slash, slash + 1
+    _interval_err(s, len(s), "/")
+
+
+def _interval_err(s: str, position: int, expected: str | None) -> NoReturn:
+    raise ParseError(f"Invalid format: {s!r}", s, position, expected)
+
+
+def _segment(s: str, start: int, parse: Callable[..., _R], *args: Any) -> _R:
+    """Parse the segment of ``s`` beginning at ``start``,
+    reporting any failure at its position within ``s``"""
+    try:
+        return parse(*args)
+    except _SegmentError as e:
+        _interval_err(s, start + e.position, e.expected)
+    except ParseError as e:
+        # Errors which aren't tied to a location point at the segment
+        _interval_err(s, start + (e.position or 0), e.expected)
+    except ValueError:  # e.g. a duration out of range
+        _interval_err(s, start, None)
+
+
+@contextmanager
+def _failing_at(s: str, position: int, expected: str) -> Iterator[None]:
+    """Report an invalid interval (e.g. one which ends before it starts)
+    as a failure at the given position"""
+    try:
+        yield
+    except (ValueError, OverflowError):
+        _interval_err(s, position, expected)
+
+
+class _SegmentError(Exception):
+    """A failure within a segment, which may have been parsed
+    with a prefix taken from the start of the interval"""
+
+    def __init__(self, position: int, expected: str | None) -> None:
+        self.position = position
+        self.expected = expected
+
+
+def _is_duration(s: str) -> bool:
+    return s[:1] in ("P", "p")
+
+
+def _parse_duration(s: str, kind: type) -> _Delta:
+    if kind is _whenever.Date:
+        return _whenever.ItemizedDateDelta.parse_iso(s)
+    return _whenever.ItemizedDelta.parse_iso(s)
+
+
+def _parse_point(s: str, kind: type) -> Any:
+    if kind is _whenever.Instant:
+        # Parse as OffsetDateTime, so that the offset remains available
+        # to complete an abbreviated end
+        return _whenever.OffsetDateTime.parse_iso(s).to_instant()
+    return kind.parse_iso(s)
+
+
+def _split_date(s: str) -> tuple[str, str]:
+    for i, c in enumerate(s):
+        if c in "Tt":
+            return s[:i], s[i:]
+    return s, ""
+
+
+def _completion(start_date: str, date: str) -> str:
+    """The leading components of the start date omitted from the end"""
+    missing = len(start_date) - len(date)
+    if missing <= 0:
+        return ""
+    # Only whole leading components may be omitted
+    elif start_date[missing - 1] == "-" or (
+        len(start_date) == 8 and missing in (4, 6)
+    ):
+        return start_date[:missing]
+    raise _SegmentError(0, "date")
+
+
+def _parse_end(start_str: str, s: str, kind: type) -> Any:
+    start_date, _ = _split_date(start_str)
+    date, rest = _split_date(s)
+    if not s:
+        raise _SegmentError(0, "year")
+    elif kind is not _whenever.Date and not rest:
+        # Only a time is given, e.g. "13:30/15:30"
+        prefix = start_date + "T"
+    else:
+        prefix = _completion(start_date, date)
+    try:
+        if kind is _whenever.Date or kind is _whenever.PlainDateTime:
+            return kind.parse_iso(prefix + s)
+        return _parse_offset_end(start_str, prefix + s, kind)
+    except ParseError as e:
+        # Point at the segment itself, not at the prefix taken from the start
+        raise _SegmentError(
+            max((e.position or 0) - len(prefix), 0), e.expected
+        ) from None
+
+
+def _parse_offset_end(start_str: str, s: str, kind: type) -> Any:
+    try:
+        end = _whenever.OffsetDateTime.parse_iso(s)
+    except ParseError as e:
+        # The offset may be omitted if it's the same as the start's
+        try:
+            plain = _whenever.PlainDateTime.parse_iso(s)
+        except ParseError:
+            raise e from None
+        start_odt = _whenever.OffsetDateTime.parse_iso(start_str)
+        end = plain.assume_fixed_offset(start_odt.offset)
+    return end.to_instant() if kind is _whenever.Instant else end
+
+
+def _abbreviate(start: str, end: str) -> str:
+    start_date, _ = _split_date(start)
+    end_date, end_rest = _split_date(end)
+    if end_date == start_date and end_rest:
+        return end_rest[1:]
+    # Omit the year and month (with their separators) if they're the same
+    for n in (8, 5):
+        if start_date[:n] == end_date[:n]:
+            return end_date[n:] + end_rest
+    return end
+
+
+@no_type_check
+def _unpkl_interval(start, end, duration, from_end):
+    return _interval_unchecked(start, end, duration, from_end)
+
+
+def _interval_unchecked(
+    start: Any, end: Any, duration: _Delta | None, from_end: bool
+) -> Interval[Any]:
+    self = _object_new(Interval)
+    self._init(start, end, duration, from_end)
+    return self
+
+
+# Set __module__ so these types and unpickle functions appear as 'whenever.X'
+if not SPHINX_RUNNING:  # pragma: no branch
+    for _obj in (Interval, RepeatingInterval, _unpkl_interval):
+        _obj.__module__ = "whenever"
+    del _obj
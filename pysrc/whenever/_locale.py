@@ -0,0 +1,1687 @@
+"""Localized month, weekday, AM/PM, and era names for patterns,
+and relative time phrases for humanizing.
+
+The tables are derived from the CLDR "format" context (i.e. the forms used
+within a date, such as the genitive "января" in Russian), in their "wide"
+and "abbreviated" widths. Relative time phrases use the CLDR "long"
+relative time patterns. Only the language subtag of a locale identifier
+is considered, so ``"de-AT"`` and ``"de_CH"`` both map to German.
+Users may register relative time phrases for other languages,
+or replace the built-in ones, with ``register_humanize_locale()``.
+
+NOTE: keep these tables in sync with the Rust implementation in
+``src/common/locale.rs``.
+"""
+
+from __future__ import annotations
+
+from typing import Literal, final
+
+PluralRule = Literal["one_other", "east_slavic", "polish", "czech", "other"]
+
+
+def plural_category(rule: PluralRule, n: int) -> int:
+    """The plural category of a positive amount:
+    0 (one), 1 (few), or 2 (other)"""
+    few = 2 <= n % 10 <= 4 and not 12 <= n % 100 <= 14
+    if rule in ("one_other", "polish", "czech") and n == 1:
+        return 0
+    elif rule == "east_slavic" and n % 10 == 1 and n % 100 != 11:
+        return 0
+    elif rule in ("east_slavic", "polish") and few:
+        return 1
+    elif rule == "czech" and 2 <= n <= 4:
+        return 1
+    return 2
+
+
+@final
+class RelativeTime:
+    """Phrases such as "in 3 hours" or "3 hours ago".
+
+    Phrases are given per unit (years first, seconds last) and per plural
+    category (one, few, other). ``{0}`` is replaced with the amount.
+    """
+
+    __slots__ = (
+        "now",
+        "yesterday",
+        "tomorrow",
+        "at_time",
+        "plural",
+        "future",
+        "past",
+    )
+
+    def __init__(
+        self,
+        *,
+        now: str,  # the phrase for a zero difference
+        yesterday: str,
+        tomorrow: str,
+        at_time: str,  # combines a day name ({0}) with a time ({1})
+        plural: PluralRule,
+        future: tuple[tuple[str, str, str], ...],
+        past: tuple[tuple[str, str, str], ...],
+    ) -> None:
+        self.now = now
+        self.yesterday = yesterday
+        self.tomorrow = tomorrow
+        self.at_time = at_time
+        self.plural = plural
+        self.future = future
+        self.past = past
+
+    def format(self, unit: int, amount: int) -> str:
+        """Format an amount of a unit (index 0 for years, 6 for seconds).
+        Positive amounts are in the future, negative ones in the past."""
+        if amount == 0:
+            return self.now
+        n = abs(amount)
+        phrases = self.future if amount > 0 else self.past
+        return phrases[unit][plural_category(self.plural, n)].replace(
+            "{0}", str(n)
+        )
+
+    def format_day(self, day: int, time: str) -> str:
+        """Format a time on the previous (-1) or next (1) day,
+        e.g. "yesterday at 14:05" """
+        name = self.yesterday if day < 0 else self.tomorrow
+        return self.at_time.replace("{0}", name).replace("{1}", time)
+
+
+@final
+class Locale:
+    """Names for a single locale"""
+
+    __slots__ = (
+        "tag",
+        "month_abbr",
+        "month_full",
+        "weekday_abbr",
+        "weekday_full",
+        "am_pm_short",
+        "am_pm",
+        "era",
+        "relative",
+    )
+
+    def __init__(
+        self,
+        tag: str,
+        *,
+        month_abbr: tuple[str, ...],  # January first
+        month_full: tuple[str, ...],
+        weekday_abbr: tuple[str, ...],  # Monday first
+        weekday_full: tuple[str, ...],
+        am_pm_short: tuple[str, str],  # for the `a` specifier
+        am_pm: tuple[str, str],  # for the `aa` specifier
+        era: tuple[str, str],  # BC first, for the `N` specifier
+        relative: RelativeTime,
+    ) -> None:
+        self.tag = tag
+        self.month_abbr = month_abbr
+        self.month_full = month_full
+        self.weekday_abbr = weekday_abbr
+        self.weekday_full = weekday_full
+        self.am_pm_short = am_pm_short
+        self.am_pm = am_pm
+        self.era = era
+        self.relative = relative
+
+
+EN = Locale(
+    "en",
+    month_abbr=(
+        "Jan",
+        "Feb",
+        "Mar",
+        "Apr",
+        "May",
+        "Jun",
+        "Jul",
+        "Aug",
+        "Sep",
+        "Oct",
+        "Nov",
+        "Dec",
+    ),
+    month_full=(
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ),
+    weekday_abbr=("Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"),
+    weekday_full=(
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("BC", "AD"),
+    relative=RelativeTime(
+        now="now",
+        yesterday="yesterday",
+        tomorrow="tomorrow",
+        at_time="{0} at {1}",
+        plural="one_other",
+        future=(
+            ("in {0} year", "in {0} years", "in {0} years"),
+            ("in {0} month", "in {0} months", "in {0} months"),
+            ("in {0} week", "in {0} weeks", "in {0} weeks"),
+            ("in {0} day", "in {0} days", "in {0} days"),
+            ("in {0} hour", "in {0} hours", "in {0} hours"),
+            ("in {0} minute", "in {0} minutes", "in {0} minutes"),
+            ("in {0} second", "in {0} seconds", "in {0} seconds"),
+        ),
+        past=(
+            ("{0} year ago", "{0} years ago", "{0} years ago"),
+            ("{0} month ago", "{0} months ago", "{0} months ago"),
+            ("{0} week ago", "{0} weeks ago", "{0} weeks ago"),
+            ("{0} day ago", "{0} days ago", "{0} days ago"),
+            ("{0} hour ago", "{0} hours ago", "{0} hours ago"),
+            ("{0} minute ago", "{0} minutes ago", "{0} minutes ago"),
+            ("{0} second ago", "{0} seconds ago", "{0} seconds ago"),
+        ),
+    ),
+)
+
+DE = Locale(
+    "de",
+    month_abbr=(
+        "Jan.",
+        "Feb.",
+        "März",
+        "Apr.",
+        "Mai",
+        "Juni",
+        "Juli",
+        "Aug.",
+        "Sept.",
+        "Okt.",
+        "Nov.",
+        "Dez.",
+    ),
+    month_full=(
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ),
+    weekday_abbr=("Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."),
+    weekday_full=(
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("v. Chr.", "n. Chr."),
+    relative=RelativeTime(
+        now="jetzt",
+        yesterday="gestern",
+        tomorrow="morgen",
+        at_time="{0} um {1}",
+        plural="one_other",
+        future=(
+            ("in {0} Jahr", "in {0} Jahren", "in {0} Jahren"),
+            ("in {0} Monat", "in {0} Monaten", "in {0} Monaten"),
+            ("in {0} Woche", "in {0} Wochen", "in {0} Wochen"),
+            ("in {0} Tag", "in {0} Tagen", "in {0} Tagen"),
+            ("in {0} Stunde", "in {0} Stunden", "in {0} Stunden"),
+            ("in {0} Minute", "in {0} Minuten", "in {0} Minuten"),
+            ("in {0} Sekunde", "in {0} Sekunden", "in {0} Sekunden"),
+        ),
+        past=(
+            ("vor {0} Jahr", "vor {0} Jahren", "vor {0} Jahren"),
+            ("vor {0} Monat", "vor {0} Monaten", "vor {0} Monaten"),
+            ("vor {0} Woche", "vor {0} Wochen", "vor {0} Wochen"),
+            ("vor {0} Tag", "vor {0} Tagen", "vor {0} Tagen"),
+            ("vor {0} Stunde", "vor {0} Stunden", "vor {0} Stunden"),
+            ("vor {0} Minute", "vor {0} Minuten", "vor {0} Minuten"),
+            ("vor {0} Sekunde", "vor {0} Sekunden", "vor {0} Sekunden"),
+        ),
+    ),
+)
+
+FR = Locale(
+    "fr",
+    month_abbr=(
+        "janv.",
+        "févr.",
+        "mars",
+        "avr.",
+        "mai",
+        "juin",
+        "juil.",
+        "août",
+        "sept.",
+        "oct.",
+        "nov.",
+        "déc.",
+    ),
+    month_full=(
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ),
+    weekday_abbr=("lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."),
+    weekday_full=(
+        "lundi",
+        "mardi",
+        "mercredi",
+        "jeudi",
+        "vendredi",
+        "samedi",
+        "dimanche",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("av. J.-C.", "ap. J.-C."),
+    relative=RelativeTime(
+        now="maintenant",
+        yesterday="hier",
+        tomorrow="demain",
+        at_time="{0} à {1}",
+        plural="one_other",
+        future=(
+            ("dans {0} an", "dans {0} ans", "dans {0} ans"),
+            ("dans {0} mois", "dans {0} mois", "dans {0} mois"),
+            ("dans {0} semaine", "dans {0} semaines", "dans {0} semaines"),
+            ("dans {0} jour", "dans {0} jours", "dans {0} jours"),
+            ("dans {0} heure", "dans {0} heures", "dans {0} heures"),
+            ("dans {0} minute", "dans {0} minutes", "dans {0} minutes"),
+            ("dans {0} seconde", "dans {0} secondes", "dans {0} secondes"),
+        ),
+        past=(
+            ("il y a {0} an", "il y a {0} ans", "il y a {0} ans"),
+            ("il y a {0} mois", "il y a {0} mois", "il y a {0} mois"),
+            (
+                "il y a {0} semaine",
+                "il y a {0} semaines",
+                "il y a {0} semaines",
+            ),
+            ("il y a {0} jour", "il y a {0} jours", "il y a {0} jours"),
+            ("il y a {0} heure", "il y a {0} heures", "il y a {0} heures"),
+            ("il y a {0} minute", "il y a {0} minutes", "il y a {0} minutes"),
+            (
+                "il y a {0} seconde",
+                "il y a {0} secondes",
+                "il y a {0} secondes",
+            ),
+        ),
+    ),
+)
+
+ES = Locale(
+    "es",
+    month_abbr=(
+        "ene",
+        "feb",
+        "mar",
+        "abr",
+        "may",
+        "jun",
+        "jul",
+        "ago",
+        "sept",
+        "oct",
+        "nov",
+        "dic",
+    ),
+    month_full=(
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ),
+    weekday_abbr=("lun", "mar", "mié", "jue", "vie", "sáb", "dom"),
+    weekday_full=(
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ),
+    am_pm_short=("a", "p"),
+    # CLDR separates these with a no-break space
+    am_pm=("a.\xa0m.", "p.\xa0m."),
+    era=("a. C.", "d. C."),
+    relative=RelativeTime(
+        now="ahora",
+        yesterday="ayer",
+        tomorrow="mañana",
+        at_time="{0} a las {1}",
+        plural="one_other",
+        future=(
+            ("dentro de {0} año", "dentro de {0} años", "dentro de {0} años"),
+            (
+                "dentro de {0} mes",
+                "dentro de {0} meses",
+                "dentro de {0} meses",
+            ),
+            (
+                "dentro de {0} semana",
+                "dentro de {0} semanas",
+                "dentro de {0} semanas",
+            ),
+            ("dentro de {0} día", "dentro de {0} días", "dentro de {0} días"),
+            (
+                "dentro de {0} hora",
+                "dentro de {0} horas",
+                "dentro de {0} horas",
+            ),
+            (
+                "dentro de {0} minuto",
+                "dentro de {0} minutos",
+                "dentro de {0} minutos",
+            ),
+            (
+                "dentro de {0} segundo",
+                "dentro de {0} segundos",
+                "dentro de {0} segundos",
+            ),
+        ),
+        past=(
+            ("hace {0} año", "hace {0} años", "hace {0} años"),
+            ("hace {0} mes", "hace {0} meses", "hace {0} meses"),
+            ("hace {0} semana", "hace {0} semanas", "hace {0} semanas"),
+            ("hace {0} día", "hace {0} días", "hace {0} días"),
+            ("hace {0} hora", "hace {0} horas", "hace {0} horas"),
+            ("hace {0} minuto", "hace {0} minutos", "hace {0} minutos"),
+            ("hace {0} segundo", "hace {0} segundos", "hace {0} segundos"),
+        ),
+    ),
+)
+
+IT = Locale(
+    "it",
+    month_abbr=(
+        "gen",
+        "feb",
+        "mar",
+        "apr",
+        "mag",
+        "giu",
+        "lug",
+        "ago",
+        "set",
+        "ott",
+        "nov",
+        "dic",
+    ),
+    month_full=(
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ),
+    weekday_abbr=("lun", "mar", "mer", "gio", "ven", "sab", "dom"),
+    weekday_full=(
+        "lunedì",
+        "martedì",
+        "mercoledì",
+        "giovedì",
+        "venerdì",
+        "sabato",
+        "domenica",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("a.C.", "d.C."),
+    relative=RelativeTime(
+        now="ora",
+        yesterday="ieri",
+        tomorrow="domani",
+        at_time="{0} alle {1}",
+        plural="one_other",
+        future=(
+            ("tra {0} anno", "tra {0} anni", "tra {0} anni"),
+            ("tra {0} mese", "tra {0} mesi", "tra {0} mesi"),
+            ("tra {0} settimana", "tra {0} settimane", "tra {0} settimane"),
+            ("tra {0} giorno", "tra {0} giorni", "tra {0} giorni"),
+            ("tra {0} ora", "tra {0} ore", "tra {0} ore"),
+            ("tra {0} minuto", "tra {0} minuti", "tra {0} minuti"),
+            ("tra {0} secondo", "tra {0} secondi", "tra {0} secondi"),
+        ),
+        past=(
+            ("{0} anno fa", "{0} anni fa", "{0} anni fa"),
+            ("{0} mese fa", "{0} mesi fa", "{0} mesi fa"),
+            ("{0} settimana fa", "{0} settimane fa", "{0} settimane fa"),
+            ("{0} giorno fa", "{0} giorni fa", "{0} giorni fa"),
+            ("{0} ora fa", "{0} ore fa", "{0} ore fa"),
+            ("{0} minuto fa", "{0} minuti fa", "{0} minuti fa"),
+            ("{0} secondo fa", "{0} secondi fa", "{0} secondi fa"),
+        ),
+    ),
+)
+
+PT = Locale(
+    "pt",
+    month_abbr=(
+        "jan.",
+        "fev.",
+        "mar.",
+        "abr.",
+        "mai.",
+        "jun.",
+        "jul.",
+        "ago.",
+        "set.",
+        "out.",
+        "nov.",
+        "dez.",
+    ),
+    month_full=(
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ),
+    weekday_abbr=("seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."),
+    weekday_full=(
+        "segunda-feira",
+        "terça-feira",
+        "quarta-feira",
+        "quinta-feira",
+        "sexta-feira",
+        "sábado",
+        "domingo",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("a.C.", "d.C."),
+    relative=RelativeTime(
+        now="agora",
+        yesterday="ontem",
+        tomorrow="amanhã",
+        at_time="{0} às {1}",
+        plural="one_other",
+        future=(
+            ("em {0} ano", "em {0} anos", "em {0} anos"),
+            ("em {0} mês", "em {0} meses", "em {0} meses"),
+            ("em {0} semana", "em {0} semanas", "em {0} semanas"),
+            ("em {0} dia", "em {0} dias", "em {0} dias"),
+            ("em {0} hora", "em {0} horas", "em {0} horas"),
+            ("em {0} minuto", "em {0} minutos", "em {0} minutos"),
+            ("em {0} segundo", "em {0} segundos", "em {0} segundos"),
+        ),
+        past=(
+            ("há {0} ano", "há {0} anos", "há {0} anos"),
+            ("há {0} mês", "há {0} meses", "há {0} meses"),
+            ("há {0} semana", "há {0} semanas", "há {0} semanas"),
+            ("há {0} dia", "há {0} dias", "há {0} dias"),
+            ("há {0} hora", "há {0} horas", "há {0} horas"),
+            ("há {0} minuto", "há {0} minutos", "há {0} minutos"),
+            ("há {0} segundo", "há {0} segundos", "há {0} segundos"),
+        ),
+    ),
+)
+
+NL = Locale(
+    "nl",
+    month_abbr=(
+        "jan",
+        "feb",
+        "mrt",
+        "apr",
+        "mei",
+        "jun",
+        "jul",
+        "aug",
+        "sep",
+        "okt",
+        "nov",
+        "dec",
+    ),
+    month_full=(
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ),
+    weekday_abbr=("ma", "di", "wo", "do", "vr", "za", "zo"),
+    weekday_full=(
+        "maandag",
+        "dinsdag",
+        "woensdag",
+        "donderdag",
+        "vrijdag",
+        "zaterdag",
+        "zondag",
+    ),
+    am_pm_short=("a", "p"),
+    am_pm=("a.m.", "p.m."),
+    era=("v.Chr.", "n.Chr."),
+    relative=RelativeTime(
+        now="nu",
+        yesterday="gisteren",
+        tomorrow="morgen",
+        at_time="{0} om {1}",
+        plural="one_other",
+        future=(
+            ("over {0} jaar", "over {0} jaar", "over {0} jaar"),
+            ("over {0} maand", "over {0} maanden", "over {0} maanden"),
+            ("over {0} week", "over {0} weken", "over {0} weken"),
+            ("over {0} dag", "over {0} dagen", "over {0} dagen"),
+            ("over {0} uur", "over {0} uur", "over {0} uur"),
+            ("over {0} minuut", "over {0} minuten", "over {0} minuten"),
+            ("over {0} seconde", "over {0} seconden", "over {0} seconden"),
+        ),
+        past=(
+            ("{0} jaar geleden", "{0} jaar geleden", "{0} jaar geleden"),
+            (
+                "{0} maand geleden",
+                "{0} maanden geleden",
+                "{0} maanden geleden",
+            ),
+            ("{0} week geleden", "{0} weken geleden", "{0} weken geleden"),
+            ("{0} dag geleden", "{0} dagen geleden", "{0} dagen geleden"),
+            ("{0} uur geleden", "{0} uur geleden", "{0} uur geleden"),
+            (
+                "{0} minuut geleden",
+                "{0} minuten geleden",
+                "{0} minuten geleden",
+            ),
+            (
+                "{0} seconde geleden",
+                "{0} seconden geleden",
+                "{0} seconden geleden",
+            ),
+        ),
+    ),
+)
+
+SV = Locale(
+    "sv",
+    month_abbr=(
+        "jan.",
+        "feb.",
+        "mars",
+        "apr.",
+        "maj",
+        "juni",
+        "juli",
+        "aug.",
+        "sep.",
+        "okt.",
+        "nov.",
+        "dec.",
+    ),
+    month_full=(
+        "januari",
+        "februari",
+        "mars",
+        "april",
+        "maj",
+        "juni",
+        "juli",
+        "augusti",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ),
+    weekday_abbr=("mån", "tis", "ons", "tors", "fre", "lör", "sön"),
+    weekday_full=(
+        "måndag",
+        "tisdag",
+        "onsdag",
+        "torsdag",
+        "fredag",
+        "lördag",
+        "söndag",
+    ),
+    am_pm_short=("f", "e"),
+    am_pm=("fm", "em"),
+    era=("f.Kr.", "e.Kr."),
+    relative=RelativeTime(
+        now="nu",
+        yesterday="i går",
+        tomorrow="i morgon",
+        at_time="{0} kl. {1}",
+        plural="one_other",
+        future=(
+            ("om {0} år", "om {0} år", "om {0} år"),
+            ("om {0} månad", "om {0} månader", "om {0} månader"),
+            ("om {0} vecka", "om {0} veckor", "om {0} veckor"),
+            ("om {0} dag", "om {0} dagar", "om {0} dagar"),
+            ("om {0} timme", "om {0} timmar", "om {0} timmar"),
+            ("om {0} minut", "om {0} minuter", "om {0} minuter"),
+            ("om {0} sekund", "om {0} sekunder", "om {0} sekunder"),
+        ),
+        past=(
+            ("för {0} år sedan", "för {0} år sedan", "för {0} år sedan"),
+            (
+                "för {0} månad sedan",
+                "för {0} månader sedan",
+                "för {0} månader sedan",
+            ),
+            (
+                "för {0} vecka sedan",
+                "för {0} veckor sedan",
+                "för {0} veckor sedan",
+            ),
+            (
+                "för {0} dag sedan",
+                "för {0} dagar sedan",
+                "för {0} dagar sedan",
+            ),
+            (
+                "för {0} timme sedan",
+                "för {0} timmar sedan",
+                "för {0} timmar sedan",
+            ),
+            (
+                "för {0} minut sedan",
+                "för {0} minuter sedan",
+                "för {0} minuter sedan",
+            ),
+            (
+                "för {0} sekund sedan",
+                "för {0} sekunder sedan",
+                "för {0} sekunder sedan",
+            ),
+        ),
+    ),
+)
+
+DA = Locale(
+    "da",
+    month_abbr=(
+        "jan.",
+        "feb.",
+        "mar.",
+        "apr.",
+        "maj",
+        "jun.",
+        "jul.",
+        "aug.",
+        "sep.",
+        "okt.",
+        "nov.",
+        "dec.",
+    ),
+    month_full=(
+        "januar",
+        "februar",
+        "marts",
+        "april",
+        "maj",
+        "juni",
+        "juli",
+        "august",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ),
+    weekday_abbr=("man.", "tirs.", "ons.", "tors.", "fre.", "lør.", "søn."),
+    weekday_full=(
+        "mandag",
+        "tirsdag",
+        "onsdag",
+        "torsdag",
+        "fredag",
+        "lørdag",
+        "søndag",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("f.Kr.", "e.Kr."),
+    relative=RelativeTime(
+        now="nu",
+        yesterday="i går",
+        tomorrow="i morgen",
+        at_time="{0} kl. {1}",
+        plural="one_other",
+        future=(
+            ("om {0} år", "om {0} år", "om {0} år"),
+            ("om {0} måned", "om {0} måneder", "om {0} måneder"),
+            ("om {0} uge", "om {0} uger", "om {0} uger"),
+            ("om {0} dag", "om {0} dage", "om {0} dage"),
+            ("om {0} time", "om {0} timer", "om {0} timer"),
+            ("om {0} minut", "om {0} minutter", "om {0} minutter"),
+            ("om {0} sekund", "om {0} sekunder", "om {0} sekunder"),
+        ),
+        past=(
+            ("for {0} år siden", "for {0} år siden", "for {0} år siden"),
+            (
+                "for {0} måned siden",
+                "for {0} måneder siden",
+                "for {0} måneder siden",
+            ),
+            ("for {0} uge siden", "for {0} uger siden", "for {0} uger siden"),
+            ("for {0} dag siden", "for {0} dage siden", "for {0} dage siden"),
+            (
+                "for {0} time siden",
+                "for {0} timer siden",
+                "for {0} timer siden",
+            ),
+            (
+                "for {0} minut siden",
+                "for {0} minutter siden",
+                "for {0} minutter siden",
+            ),
+            (
+                "for {0} sekund siden",
+                "for {0} sekunder siden",
+                "for {0} sekunder siden",
+            ),
+        ),
+    ),
+)
+
+NB = Locale(
+    "nb",
+    month_abbr=(
+        "jan.",
+        "feb.",
+        "mar.",
+        "apr.",
+        "mai",
+        "jun.",
+        "jul.",
+        "aug.",
+        "sep.",
+        "okt.",
+        "nov.",
+        "des.",
+    ),
+    month_full=(
+        "januar",
+        "februar",
+        "mars",
+        "april",
+        "mai",
+        "juni",
+        "juli",
+        "august",
+        "september",
+        "oktober",
+        "november",
+        "desember",
+    ),
+    weekday_abbr=("man.", "tir.", "ons.", "tor.", "fre.", "lør.", "søn."),
+    weekday_full=(
+        "mandag",
+        "tirsdag",
+        "onsdag",
+        "torsdag",
+        "fredag",
+        "lørdag",
+        "søndag",
+    ),
+    am_pm_short=("a", "p"),
+    am_pm=("a.m.", "p.m."),
+    era=("f.Kr.", "e.Kr."),
+    relative=RelativeTime(
+        now="nå",
+        yesterday="i går",
+        tomorrow="i morgen",
+        at_time="{0} kl. {1}",
+        plural="one_other",
+        future=(
+            ("om {0} år", "om {0} år", "om {0} år"),
+            ("om {0} måned", "om {0} måneder", "om {0} måneder"),
+            ("om {0} uke", "om {0} uker", "om {0} uker"),
+            ("om {0} døgn", "om {0} døgn", "om {0} døgn"),
+            ("om {0} time", "om {0} timer", "om {0} timer"),
+            ("om {0} minutt", "om {0} minutter", "om {0} minutter"),
+            ("om {0} sekund", "om {0} sekunder", "om {0} sekunder"),
+        ),
+        past=(
+            ("for {0} år siden", "for {0} år siden", "for {0} år siden"),
+            (
+                "for {0} måned siden",
+                "for {0} måneder siden",
+                "for {0} måneder siden",
+            ),
+            ("for {0} uke siden", "for {0} uker siden", "for {0} uker siden"),
+            ("for {0} døgn siden", "for {0} døgn siden", "for {0} døgn siden"),
+            (
+                "for {0} time siden",
+                "for {0} timer siden",
+                "for {0} timer siden",
+            ),
+            (
+                "for {0} minutt siden",
+                "for {0} minutter siden",
+                "for {0} minutter siden",
+            ),
+            (
+                "for {0} sekund siden",
+                "for {0} sekunder siden",
+                "for {0} sekunder siden",
+            ),
+        ),
+    ),
+)
+
+FI = Locale(
+    "fi",
+    month_abbr=(
+        "tammik.",
+        "helmik.",
+        "maalisk.",
+        "huhtik.",
+        "toukok.",
+        "kesäk.",
+        "heinäk.",
+        "elok.",
+        "syysk.",
+        "lokak.",
+        "marrask.",
+        "jouluk.",
+    ),
+    month_full=(
+        "tammikuuta",
+        "helmikuuta",
+        "maaliskuuta",
+        "huhtikuuta",
+        "toukokuuta",
+        "kesäkuuta",
+        "heinäkuuta",
+        "elokuuta",
+        "syyskuuta",
+        "lokakuuta",
+        "marraskuuta",
+        "joulukuuta",
+    ),
+    weekday_abbr=("ma", "ti", "ke", "to", "pe", "la", "su"),
+    weekday_full=(
+        "maanantaina",
+        "tiistaina",
+        "keskiviikkona",
+        "torstaina",
+        "perjantaina",
+        "lauantaina",
+        "sunnuntaina",
+    ),
+    am_pm_short=("ap.", "ip."),
+    am_pm=("ap.", "ip."),
+    era=("eKr.", "jKr."),
+    relative=RelativeTime(
+        now="nyt",
+        yesterday="eilen",
+        tomorrow="huomenna",
+        at_time="{0} klo {1}",
+        plural="one_other",
+        future=(
+            ("{0} vuoden päästä", "{0} vuoden päästä", "{0} vuoden päästä"),
+            (
+                "{0} kuukauden päästä",
+                "{0} kuukauden päästä",
+                "{0} kuukauden päästä",
+            ),
+            ("{0} viikon päästä", "{0} viikon päästä", "{0} viikon päästä"),
+            ("{0} päivän päästä", "{0} päivän päästä", "{0} päivän päästä"),
+            ("{0} tunnin päästä", "{0} tunnin päästä", "{0} tunnin päästä"),
+            (
+                "{0} minuutin päästä",
+                "{0} minuutin päästä",
+                "{0} minuutin päästä",
+            ),
+            (
+                "{0} sekunnin päästä",
+                "{0} sekunnin päästä",
+                "{0} sekunnin päästä",
+            ),
+        ),
+        past=(
+            ("{0} vuosi sitten", "{0} vuotta sitten", "{0} vuotta sitten"),
+            (
+                "{0} kuukausi sitten",
+                "{0} kuukautta sitten",
+                "{0} kuukautta sitten",
+            ),
+            ("{0} viikko sitten", "{0} viikkoa sitten", "{0} viikkoa sitten"),
+            ("{0} päivä sitten", "{0} päivää sitten", "{0} päivää sitten"),
+            ("{0} tunti sitten", "{0} tuntia sitten", "{0} tuntia sitten"),
+            (
+                "{0} minuutti sitten",
+                "{0} minuuttia sitten",
+                "{0} minuuttia sitten",
+            ),
+            (
+                "{0} sekunti sitten",
+                "{0} sekuntia sitten",
+                "{0} sekuntia sitten",
+            ),
+        ),
+    ),
+)
+
+PL = Locale(
+    "pl",
+    month_abbr=(
+        "sty",
+        "lut",
+        "mar",
+        "kwi",
+        "maj",
+        "cze",
+        "lip",
+        "sie",
+        "wrz",
+        "paź",
+        "lis",
+        "gru",
+    ),
+    month_full=(
+        "stycznia",
+        "lutego",
+        "marca",
+        "kwietnia",
+        "maja",
+        "czerwca",
+        "lipca",
+        "sierpnia",
+        "września",
+        "października",
+        "listopada",
+        "grudnia",
+    ),
+    weekday_abbr=("pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."),
+    weekday_full=(
+        "poniedziałek",
+        "wtorek",
+        "środa",
+        "czwartek",
+        "piątek",
+        "sobota",
+        "niedziela",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("p.n.e.", "n.e."),
+    relative=RelativeTime(
+        now="teraz",
+        yesterday="wczoraj",
+        tomorrow="jutro",
+        at_time="{0} o {1}",
+        plural="polish",
+        future=(
+            ("za {0} rok", "za {0} lata", "za {0} lat"),
+            ("za {0} miesiąc", "za {0} miesiące", "za {0} miesięcy"),
+            ("za {0} tydzień", "za {0} tygodnie", "za {0} tygodni"),
+            ("za {0} dzień", "za {0} dni", "za {0} dni"),
+            ("za {0} godzinę", "za {0} godziny", "za {0} godzin"),
+            ("za {0} minutę", "za {0} minuty", "za {0} minut"),
+            ("za {0} sekundę", "za {0} sekundy", "za {0} sekund"),
+        ),
+        past=(
+            ("{0} rok temu", "{0} lata temu", "{0} lat temu"),
+            ("{0} miesiąc temu", "{0} miesiące temu", "{0} miesięcy temu"),
+            ("{0} tydzień temu", "{0} tygodnie temu", "{0} tygodni temu"),
+            ("{0} dzień temu", "{0} dni temu", "{0} dni temu"),
+            ("{0} godzinę temu", "{0} godziny temu", "{0} godzin temu"),
+            ("{0} minutę temu", "{0} minuty temu", "{0} minut temu"),
+            ("{0} sekundę temu", "{0} sekundy temu", "{0} sekund temu"),
+        ),
+    ),
+)
+
+CS = Locale(
+    "cs",
+    month_abbr=(
+        "led",
+        "úno",
+        "bře",
+        "dub",
+        "kvě",
+        "čvn",
+        "čvc",
+        "srp",
+        "zář",
+        "říj",
+        "lis",
+        "pro",
+    ),
+    month_full=(
+        "ledna",
+        "února",
+        "března",
+        "dubna",
+        "května",
+        "června",
+        "července",
+        "srpna",
+        "září",
+        "října",
+        "listopadu",
+        "prosince",
+    ),
+    weekday_abbr=("po", "út", "st", "čt", "pá", "so", "ne"),
+    weekday_full=(
+        "pondělí",
+        "úterý",
+        "středa",
+        "čtvrtek",
+        "pátek",
+        "sobota",
+        "neděle",
+    ),
+    am_pm_short=("dop.", "odp."),
+    am_pm=("dop.", "odp."),
+    era=("př. n. l.", "n. l."),
+    relative=RelativeTime(
+        now="nyní",
+        yesterday="včera",
+        tomorrow="zítra",
+        at_time="{0} v {1}",
+        plural="czech",
+        future=(
+            ("za {0} rok", "za {0} roky", "za {0} let"),
+            ("za {0} měsíc", "za {0} měsíce", "za {0} měsíců"),
+            ("za {0} týden", "za {0} týdny", "za {0} týdnů"),
+            ("za {0} den", "za {0} dny", "za {0} dní"),
+            ("za {0} hodinu", "za {0} hodiny", "za {0} hodin"),
+            ("za {0} minutu", "za {0} minuty", "za {0} minut"),
+            ("za {0} sekundu", "za {0} sekundy", "za {0} sekund"),
+        ),
+        past=(
+            ("před {0} rokem", "před {0} lety", "před {0} lety"),
+            ("před {0} měsícem", "před {0} měsíci", "před {0} měsíci"),
+            ("před {0} týdnem", "před {0} týdny", "před {0} týdny"),
+            ("před {0} dnem", "před {0} dny", "před {0} dny"),
+            ("před {0} hodinou", "před {0} hodinami", "před {0} hodinami"),
+            ("před {0} minutou", "před {0} minutami", "před {0} minutami"),
+            ("před {0} sekundou", "před {0} sekundami", "před {0} sekundami"),
+        ),
+    ),
+)
+
+RU = Locale(
+    "ru",
+    month_abbr=(
+        "янв.",
+        "февр.",
+        "мар.",
+        "апр.",
+        "мая",
+        "июн.",
+        "июл.",
+        "авг.",
+        "сент.",
+        "окт.",
+        "нояб.",
+        "дек.",
+    ),
+    month_full=(
+        "января",
+        "февраля",
+        "марта",
+        "апреля",
+        "мая",
+        "июня",
+        "июля",
+        "августа",
+        "сентября",
+        "октября",
+        "ноября",
+        "декабря",
+    ),
+    weekday_abbr=("пн", "вт", "ср", "чт", "пт", "сб", "вс"),
+    weekday_full=(
+        "понедельник",
+        "вторник",
+        "среда",
+        "четверг",
+        "пятница",
+        "суббота",
+        "воскресенье",
+    ),
+    am_pm_short=("A", "P"),
+    am_pm=("AM", "PM"),
+    era=("до н. э.", "н. э."),
+    relative=RelativeTime(
+        now="сейчас",
+        yesterday="вчера",
+        tomorrow="завтра",
+        at_time="{0} в {1}",
+        plural="east_slavic",
+        future=(
+            ("через {0} год", "через {0} года", "через {0} лет"),
+            ("через {0} месяц", "через {0} месяца", "через {0} месяцев"),
+            ("через {0} неделю", "через {0} недели", "через {0} недель"),
+            ("через {0} день", "через {0} дня", "через {0} дней"),
+            ("через {0} час", "через {0} часа", "через {0} часов"),
+            ("через {0} минуту", "через {0} минуты", "через {0} минут"),
+            ("через {0} секунду", "через {0} секунды", "через {0} секунд"),
+        ),
+        past=(
+            ("{0} год назад", "{0} года назад", "{0} лет назад"),
+            ("{0} месяц назад", "{0} месяца назад", "{0} месяцев назад"),
+            ("{0} неделю назад", "{0} недели назад", "{0} недель назад"),
+            ("{0} день назад", "{0} дня назад", "{0} дней назад"),
+            ("{0} час назад", "{0} часа назад", "{0} часов назад"),
+            ("{0} минуту назад", "{0} минуты назад", "{0} минут назад"),
+            ("{0} секунду назад", "{0} секунды назад", "{0} секунд назад"),
+        ),
+    ),
+)
+
+UK = Locale(
+    "uk",
+    month_abbr=(
+        "січ.",
+        "лют.",
+        "бер.",
+        "квіт.",
+        "трав.",
+        "черв.",
+        "лип.",
+        "серп.",
+        "вер.",
+        "жовт.",
+        "лист.",
+        "груд.",
+    ),
+    month_full=(
+        "січня",
+        "лютого",
+        "березня",
+        "квітня",
+        "травня",
+        "червня",
+        "липня",
+        "серпня",
+        "вересня",
+        "жовтня",
+        "листопада",
+        "грудня",
+    ),
+    weekday_abbr=("пн", "вт", "ср", "чт", "пт", "сб", "нд"),
+    weekday_full=(
+        "понеділок",
+        "вівторок",
+        "середа",
+        "четвер",
+        "пʼятниця",
+        "субота",
+        "неділя",
+    ),
+    am_pm_short=("дп", "пп"),
+    am_pm=("дп", "пп"),
+    era=("до н. е.", "н. е."),
+    relative=RelativeTime(
+        now="зараз",
+        yesterday="учора",
+        tomorrow="завтра",
+        at_time="{0} о {1}",
+        plural="east_slavic",
+        future=(
+            ("через {0} рік", "через {0} роки", "через {0} років"),
+            ("через {0} місяць", "через {0} місяці", "через {0} місяців"),
+            ("через {0} тиждень", "через {0} тижні", "через {0} тижнів"),
+            ("через {0} день", "через {0} дні", "через {0} днів"),
+            ("через {0} годину", "через {0} години", "через {0} годин"),
+            ("через {0} хвилину", "через {0} хвилини", "через {0} хвилин"),
+            ("через {0} секунду", "через {0} секунди", "через {0} секунд"),
+        ),
+        past=(
+            ("{0} рік тому", "{0} роки тому", "{0} років тому"),
+            ("{0} місяць тому", "{0} місяці тому", "{0} місяців тому"),
+            ("{0} тиждень тому", "{0} тижні тому", "{0} тижнів тому"),
+            ("{0} день тому", "{0} дні тому", "{0} днів тому"),
+            ("{0} годину тому", "{0} години тому", "{0} годин тому"),
+            ("{0} хвилину тому", "{0} хвилини тому", "{0} хвилин тому"),
+            ("{0} секунду тому", "{0} секунди тому", "{0} секунд тому"),
+        ),
+    ),
+)
+
+TR = Locale(
+    "tr",
+    month_abbr=(
+        "Oca",
+        "Şub",
+        "Mar",
+        "Nis",
+        "May",
+        "Haz",
+        "Tem",
+        "Ağu",
+        "Eyl",
+        "Eki",
+        "Kas",
+        "Ara",
+    ),
+    month_full=(
+        "Ocak",
+        "Şubat",
+        "Mart",
+        "Nisan",
+        "Mayıs",
+        "Haziran",
+        "Temmuz",
+        "Ağustos",
+        "Eylül",
+        "Ekim",
+        "Kasım",
+        "Aralık",
+    ),
+    weekday_abbr=("Pzt", "Sal", "Çar", "Per", "Cum", "Cmt", "Paz"),
+    weekday_full=(
+        "Pazartesi",
+        "Salı",
+        "Çarşamba",
+        "Perşembe",
+        "Cuma",
+        "Cumartesi",
+        "Pazar",
+    ),
+    am_pm_short=("ÖÖ", "ÖS"),
+    am_pm=("ÖÖ", "ÖS"),
+    era=("MÖ", "MS"),
+    relative=RelativeTime(
+        now="şimdi",
+        yesterday="dün",
+        tomorrow="yarın",
+        at_time="{0} {1}",
+        plural="one_other",
+        future=(
+            ("{0} yıl sonra", "{0} yıl sonra", "{0} yıl sonra"),
+            ("{0} ay sonra", "{0} ay sonra", "{0} ay sonra"),
+            ("{0} hafta sonra", "{0} hafta sonra", "{0} hafta sonra"),
+            ("{0} gün sonra", "{0} gün sonra", "{0} gün sonra"),
+            ("{0} saat sonra", "{0} saat sonra", "{0} saat sonra"),
+            ("{0} dakika sonra", "{0} dakika sonra", "{0} dakika sonra"),
+            ("{0} saniye sonra", "{0} saniye sonra", "{0} saniye sonra"),
+        ),
+        past=(
+            ("{0} yıl önce", "{0} yıl önce", "{0} yıl önce"),
+            ("{0} ay önce", "{0} ay önce", "{0} ay önce"),
+            ("{0} hafta önce", "{0} hafta önce", "{0} hafta önce"),
+            ("{0} gün önce", "{0} gün önce", "{0} gün önce"),
+            ("{0} saat önce", "{0} saat önce", "{0} saat önce"),
+            ("{0} dakika önce", "{0} dakika önce", "{0} dakika önce"),
+            ("{0} saniye önce", "{0} saniye önce", "{0} saniye önce"),
+        ),
+    ),
+)
+
+JA = Locale(
+    "ja",
+    month_abbr=(
+        "1月",
+        "2月",
+        "3月",
+        "4月",
+        "5月",
+        "6月",
+        "7月",
+        "8月",
+        "9月",
+        "10月",
+        "11月",
+        "12月",
+    ),
+    month_full=(
+        "1月",
+        "2月",
+        "3月",
+        "4月",
+        "5月",
+        "6月",
+        "7月",
+        "8月",
+        "9月",
+        "10月",
+        "11月",
+        "12月",
+    ),
+    weekday_abbr=("月", "火", "水", "木", "金", "土", "日"),
+    weekday_full=(
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+    ),
+    am_pm_short=("午前", "午後"),
+    am_pm=("午前", "午後"),
+    era=("紀元前", "西暦"),
+    relative=RelativeTime(
+        now="今",
+        yesterday="昨日",
+        tomorrow="明日",
+        at_time="{0} {1}",
+        plural="other",
+        future=(
+            ("{0} 年後", "{0} 年後", "{0} 年後"),
+            ("{0} か月後", "{0} か月後", "{0} か月後"),
+            ("{0} 週間後", "{0} 週間後", "{0} 週間後"),
+            ("{0} 日後", "{0} 日後", "{0} 日後"),
+            ("{0} 時間後", "{0} 時間後", "{0} 時間後"),
+            ("{0} 分後", "{0} 分後", "{0} 分後"),
+            ("{0} 秒後", "{0} 秒後", "{0} 秒後"),
+        ),
+        past=(
+            ("{0} 年前", "{0} 年前", "{0} 年前"),
+            ("{0} か月前", "{0} か月前", "{0} か月前"),
+            ("{0} 週間前", "{0} 週間前", "{0} 週間前"),
+            ("{0} 日前", "{0} 日前", "{0} 日前"),
+            ("{0} 時間前", "{0} 時間前", "{0} 時間前"),
+            ("{0} 分前", "{0} 分前", "{0} 分前"),
+            ("{0} 秒前", "{0} 秒前", "{0} 秒前"),
+        ),
+    ),
+)
+
+ZH = Locale(
+    "zh",
+    month_abbr=(
+        "1月",
+        "2月",
+        "3月",
+        "4月",
+        "5月",
+        "6月",
+        "7月",
+        "8月",
+        "9月",
+        "10月",
+        "11月",
+        "12月",
+    ),
+    month_full=(
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ),
+    weekday_abbr=("周一", "周二", "周三", "周四", "周五", "周六", "周日"),
+    weekday_full=(
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ),
+    am_pm_short=("上午", "下午"),
+    am_pm=("上午", "下午"),
+    era=("公元前", "公元"),
+    relative=RelativeTime(
+        now="现在",
+        yesterday="昨天",
+        tomorrow="明天",
+        at_time="{0} {1}",
+        plural="other",
+        future=(
+            ("{0}年后", "{0}年后", "{0}年后"),
+            ("{0}个月后", "{0}个月后", "{0}个月后"),
+            ("{0}周后", "{0}周后", "{0}周后"),
+            ("{0}天后", "{0}天后", "{0}天后"),
+            ("{0}小时后", "{0}小时后", "{0}小时后"),
+            ("{0}分钟后", "{0}分钟后", "{0}分钟后"),
+            ("{0}秒钟后", "{0}秒钟后", "{0}秒钟后"),
+        ),
+        past=(
+            ("{0}年前", "{0}年前", "{0}年前"),
+            ("{0}个月前", "{0}个月前", "{0}个月前"),
+            ("{0}周前", "{0}周前", "{0}周前"),
+            ("{0}天前", "{0}天前", "{0}天前"),
+            ("{0}小时前", "{0}小时前", "{0}小时前"),
+            ("{0}分钟前", "{0}分钟前", "{0}分钟前"),
+            ("{0}秒钟前", "{0}秒钟前", "{0}秒钟前"),
+        ),
+    ),
+)
+
+KO = Locale(
+    "ko",
+    month_abbr=(
+        "1월",
+        "2월",
+        "3월",
+        "4월",
+        "5월",
+        "6월",
+        "7월",
+        "8월",
+        "9월",
+        "10월",
+        "11월",
+        "12월",
+    ),
+    month_full=(
+        "1월",
+        "2월",
+        "3월",
+        "4월",
+        "5월",
+        "6월",
+        "7월",
+        "8월",
+        "9월",
+        "10월",
+        "11월",
+        "12월",
+    ),
+    weekday_abbr=("월", "화", "수", "목", "금", "토", "일"),
+    weekday_full=(
+        "월요일",
+        "화요일",
+        "수요일",
+        "목요일",
+        "금요일",
+        "토요일",
+        "일요일",
+    ),
+    am_pm_short=("오전", "오후"),
+    am_pm=("오전", "오후"),
+    era=("BC", "AD"),
+    relative=RelativeTime(
+        now="지금",
+        yesterday="어제",
+        tomorrow="내일",
+        at_time="{0} {1}",
+        plural="other",
+        future=(
+            ("{0}년 후", "{0}년 후", "{0}년 후"),
+            ("{0}개월 후", "{0}개월 후", "{0}개월 후"),
+            ("{0}주 후", "{0}주 후", "{0}주 후"),
+            ("{0}일 후", "{0}일 후", "{0}일 후"),
+            ("{0}시간 후", "{0}시간 후", "{0}시간 후"),
+            ("{0}분 후", "{0}분 후", "{0}분 후"),
+            ("{0}초 후", "{0}초 후", "{0}초 후"),
+        ),
+        past=(
+            ("{0}년 전", "{0}년 전", "{0}년 전"),
+            ("{0}개월 전", "{0}개월 전", "{0}개월 전"),
+            ("{0}주 전", "{0}주 전", "{0}주 전"),
+            ("{0}일 전", "{0}일 전", "{0}일 전"),
+            ("{0}시간 전", "{0}시간 전", "{0}시간 전"),
+            ("{0}분 전", "{0}분 전", "{0}분 전"),
+            ("{0}초 전", "{0}초 전", "{0}초 전"),
+        ),
+    ),
+)
+
+_BY_TAG = {
+    loc.tag: loc
+    for loc in (
+        EN,
+        DE,
+        FR,
+        ES,
+        IT,
+        PT,
+        NL,
+        SV,
+        DA,
+        NB,
+        FI,
+        PL,
+        CS,
+        RU,
+        UK,
+        TR,
+        JA,
+        ZH,
+        KO,
+    )
+}
+
+
+# Windows primary language IDs, i.e. the lower 10 bits of an LCID
+_BY_LANG_ID = {
+    0x04: ZH,
+    0x05: CS,
+    0x06: DA,
+    0x07: DE,
+    0x09: EN,
+    0x0A: ES,
+    0x0B: FI,
+    0x0C: FR,
+    0x10: IT,
+    0x11: JA,
+    0x12: KO,
+    0x13: NL,
+    0x14: NB,
+    0x15: PL,
+    0x16: PT,
+    0x19: RU,
+    0x1D: SV,
+    0x1F: TR,
+    0x22: UK,
+}
+
+
+def locale_from_lcid(lcid: int) -> Locale | None:
+    """Look up a locale by Windows locale ID (LCID), e.g. ``0x407`` for
+    German, as used in spreadsheet formats. Like ``locale=`` arguments,
+    only the language is considered, not the region."""
+    # Norwegian Nynorsk shares its primary language ID with Bokmål
+    if lcid == 0x814:
+        return None
+    return _BY_LANG_ID.get(lcid & 0x3FF)
+
+
+def _language(tag: str) -> str:
+    """The (lowercase) language subtag of a locale identifier"""
+    lang = tag.replace("_", "-").partition("-")[0].lower()
+    # Norwegian without a written standard defaults to Bokmål, as in CLDR
+    return "nb" if lang == "no" else lang
+
+
+def get_locale(tag: str | None) -> Locale:
+    """Interpret the value of a ``locale=`` argument.
+    ``None`` selects English."""
+    if tag is None:
+        return EN
+    if type(tag) is not str:
+        raise TypeError("locale must be str")
+    try:
+        return _BY_TAG[_language(tag)]
+    except KeyError:
+        raise ValueError(f"Unsupported locale: {tag!r}") from None
+
+
+# Relative time phrases registered with register_humanize_locale(),
+# by language subtag
+_REGISTERED: dict[str, RelativeTime] = {}
+
+
+def register_relative_time(lang: str, phrases: RelativeTime) -> None:
+    """Register phrases for a language, replacing any previous ones"""
+    _REGISTERED[_language(lang)] = phrases
+
+
+def relative_time(tag: str | None) -> RelativeTime:
+    """Interpret the value of a ``locale=`` argument for humanizing.
+    Registered phrases take precedence over the built-in ones.
+    ``None`` selects English."""
+    if _REGISTERED:
+        if tag is None:
+            tag = "en"
+        elif type(tag) is not str:
+            raise TypeError("locale must be str")
+        try:
+            return _REGISTERED[_language(tag)]
+        except KeyError:
+            pass
+    return get_locale(tag).relative
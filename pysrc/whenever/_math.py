@@ -175,6 +175,59 @@ def days_in_month(year: int, month: int) -> int:
     return _MONTHDAYS[month] + (month == 2 and is_leap(year))
 
 
+# The day number of 1970-01-01 in other day counts
+_DAY_COUNT_UNIX_EPOCH = {
+    "julian_day": 2_440_588,
+    "mjd": 40_587,
+    "rata_die": 719_163,
+    "excel1900": 25_569,
+    "excel1904": 24_107,
+}
+DayCount = Literal["julian_day", "mjd", "rata_die", "excel1900", "excel1904"]
+
+
+def unix_days_from_day_count(count: DayCount, n: int) -> int:
+    epoch = _DAY_COUNT_UNIX_EPOCH[count]
+    # Excel's 1900 system treats 1900 as a leap year, so serial 60 is the
+    # nonexistent 1900-02-29, and earlier serials are offset by one day.
+    if count == "excel1900" and n < 61:
+        if n == 60:
+            raise ValueError("Excel serial 60 (1900-02-29) doesn't exist")
+        epoch -= 1
+    return n - epoch
+
+
+def day_count_from_unix_days(count: DayCount, days: int) -> int:
+    n = days + _DAY_COUNT_UNIX_EPOCH[count]
+    if count == "excel1900" and n < 61:
+        n -= 1
+    return n
+
+
+def _day_count_unix_epoch_f(count: DayCount) -> float:
+    # Julian days start at noon, so they're offset by half a day
+    if count == "julian_day":
+        return 2_440_587.5
+    return float(_DAY_COUNT_UNIX_EPOCH[count])
+
+
+def unix_secs_from_day_count(count: DayCount, days: float) -> float:
+    epoch = _day_count_unix_epoch_f(count)
+    if count == "excel1900":
+        if days < 60:
+            epoch -= 1
+        elif days < 61:
+            raise ValueError("Excel serial 60 (1900-02-29) doesn't exist")
+    return (days - epoch) * 86_400
+
+
+def day_count_from_unix_secs(count: DayCount, secs: float) -> float:
+    n = secs / 86_400 + _day_count_unix_epoch_f(count)
+    if count == "excel1900" and n < 61:
+        n -= 1
+    return n
+
+
 NS_PER_UNIT_SINGULAR = {
     "week": 604_800_000_000_000,
     "day": 86_400_000_000_000,
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import sys
+from collections.abc import Callable
 from datetime import (
     date as _date,
     datetime as _datetime,
@@ -8,12 +9,14 @@ from datetime import (
     timedelta as _timedelta,
     timezone as _timezone,
 )
-from typing import TYPE_CHECKING, Literal, NoReturn, cast
+from functools import wraps
+from typing import TYPE_CHECKING, Any, Literal, NoReturn, TypeVar, cast
 
 from ._common import (
     DUMMY_LEAP_YEAR,
     UTC,
     Nanos,
+    ParseError,
     check_utc_bounds,
     mk_fixed_tzinfo,
 )
@@ -22,12 +25,131 @@ if TYPE_CHECKING:
     from ._tz import SafeTzId, TimeZone
 
 
+_ParseMethod = TypeVar("_ParseMethod", bound=Callable[[Any, str], Any])
+
+
 class InvalidOffsetError(ValueError):
     """A string has an invalid offset for the given zone"""
 
 
-def _parse_err(s: str) -> NoReturn:
-    raise ValueError(f"Invalid format: {s!r}") from None
+class _Mismatch(Exception):
+    """Where the input first failed to match, and what was expected there.
+    The position is ``None`` if the failure isn't tied to a location."""
+
+    def __init__(self, pos: int | None, expected: str | None) -> None:
+        self.pos = pos
+        self.expected = expected
+
+
+class _Scan:
+    """A cursor over the input of a failed parse, for locating the failure.
+    Mirrors the scanner of the Rust extension."""
+
+    __slots__ = ("s", "pos")
+
+    def __init__(self, s: str) -> None:
+        self.s = s
+        self.pos = 0
+
+    def peek(self, n: int = 0) -> str:
+        """The character ``n`` places after the cursor, or "" at the end"""
+        return self.s[self.pos + n : self.pos + n + 1]
+
+    def done(self) -> bool:
+        return self.pos == len(self.s)
+
+    def fail(self, expected: str, pos: int | None = None) -> NoReturn:
+        raise _Mismatch(self.pos if pos is None else pos, expected)
+
+    def advance_on(self, c: str) -> bool:
+        if self.s.startswith(c, self.pos):
+            self.pos += 1
+            return True
+        return False
+
+    def expect(self, c: str, ignore_case: bool = False) -> None:
+        if self.peek() == c or (ignore_case and self.peek() == c.lower()):
+            self.pos += 1
+        else:
+            self.fail(c)
+
+    def count_digits(self, limit: int | None = None) -> int:
+        end = self.pos
+        stop = len(self.s) if limit is None else self.pos + limit
+        while end < stop and self.s[end : end + 1] in _DIGITS:
+            end += 1
+        return end - self.pos
+
+    def digits(
+        self, n: int, expected: str, lo: int = 0, hi: int | None = None
+    ) -> int:
+        """Read exactly ``n`` digits with a value in the given range"""
+        if self.count_digits(n) != n:
+            self.fail(expected)
+        value = int(self.s[self.pos : self.pos + n])
+        if value < lo or (hi is not None and value > hi):
+            self.fail(expected)
+        self.pos += n
+        return value
+
+    def fraction(self) -> int:
+        """Read an optional '.' or ',' followed by 1-9 digits"""
+        if self.peek() not in (".", ","):
+            return 0
+        self.pos += 1
+        n = self.count_digits(9)
+        if n == 0:
+            self.fail("fraction", self.pos - 1)
+        self.pos += n
+        return int(self.s[self.pos - n : self.pos].ljust(9, "0"))
+
+    def whitespace(self) -> bool:
+        start = self.pos
+        while self.peek() and self.peek() in _RFC2822_WS:
+            self.pos += 1
+        return self.pos > start
+
+
+def _locate(
+    s: str, read: Callable[[_Scan], object]
+) -> tuple[int | None, str | None]:
+    """Find where the input first fails to match the grammar of ``read``,
+    and what was expected there. Leftover input has no expected token."""
+    scan = _Scan(s)
+    try:
+        read(scan)
+        if not scan.done():
+            return scan.pos, None
+    except _Mismatch as m:
+        return m.pos, m.expected
+    return None, None
+
+
+def _parse_err(
+    s: str, read: Callable[[_Scan], object] | None = None
+) -> NoReturn:
+    position, expected = (None, None) if read is None else _locate(s, read)
+    raise ParseError(f"Invalid format: {s!r}", s, position, expected) from None
+
+
+def locate_failure(
+    read: Callable[[_Scan], object],
+) -> Callable[[_ParseMethod], _ParseMethod]:
+    """Decorate a method parsing a string, so that the ParseError it raises
+    points at where the string first failed to match ``read``"""
+
+    def decorator(method: _ParseMethod) -> _ParseMethod:
+        @wraps(method)
+        def wrapper(self: Any, s: str) -> Any:
+            try:
+                return method(self, s)
+            except ParseError as e:
+                e.position, e.expected = _locate(s, read)
+                raise
+
+        return cast(_ParseMethod, wrapper)
+
+    return decorator
 
 
 def _parse_nanos(s: str) -> Nanos:
@@ -56,10 +178,11 @@ _is_sep = " Tt".__contains__
 
 
 def _split_iso_date_time(s: str) -> tuple[str, _date]:
-    if s[4] == "-" and s[7] == "-" and _is_sep(s[10]):
-        return s[11:], _date_from_iso_ascii(s[:10])
-    if _is_sep(s[8]):
-        return s[9:], _date_from_iso_ascii(s[:8])
+    # The date is one of the ISO formats accepted by date_from_iso,
+    # which are 10, 8 or 7 characters long
+    for date_len in (10, 8, 7):
+        if len(s) > date_len and _is_sep(s[date_len]):
+            return s[date_len + 1 :], _date_from_iso_ascii(s[:date_len])
     raise ValueError()
 
 
@@ -94,22 +217,22 @@ def _offset_from_iso(s: str) -> int:
 
 
 def datetime_from_iso(s: str) -> tuple[_datetime, Nanos]:
-    if len(s) < 11 or "W" in s or not s.isascii():
-        _parse_err(s)
+    if len(s) < 10 or not s.isascii():
+        _parse_err(s, _read_datetime)
 
     # OPTIMIZE: the happy path can be faster
     try:
         rest, date = _split_iso_date_time(s)
-        time, nanos = time_from_iso(rest)
+        time, nanos = _time_from_iso(rest)
     except ValueError:
-        _parse_err(s)
+        _parse_err(s, _read_datetime)
 
     return _datetime.combine(date, time), nanos
 
 
 def offset_dt_from_iso(s: str) -> tuple[_datetime, Nanos]:
-    if len(s) < 11 or "W" in s[:11] or not s.isascii():
-        _parse_err(s)
+    if len(s) < 10 or not s.isascii():
+        _parse_err(s, _read_offset_datetime)
 
     try:
         rest, date = _split_iso_date_time(s)
@@ -127,23 +250,23 @@ def offset_dt_from_iso(s: str) -> tuple[_datetime, Nanos]:
             nanos,
         )
     except ValueError:
-        _parse_err(s)
+        _parse_err(s, _read_offset_datetime)
 
 
 def zdt_from_iso(s: str) -> tuple[_datetime, Nanos, TimeZone]:
     from ._tz import get_tz, resolve_ambiguity
 
-    if len(s) < 11 or "W" in s[:11] or not s.isascii():
-        _parse_err(s)
+    if len(s) < 10 or not s.isascii():
+        _parse_err(s, _read_zoned_datetime)
 
     try:
         rest, date = _split_iso_date_time(s)
         time, nanos, offset, tzid = _time_offset_tz_from_iso(rest)
     except ValueError:
-        _parse_err(s)
+        _parse_err(s, _read_zoned_datetime)
 
     if tzid is None:
-        _parse_err(s)
+        _parse_err(s, _read_zoned_datetime)
 
     tz = get_tz(tzid)
 
@@ -168,16 +291,19 @@ def zdt_from_iso(s: str) -> tuple[_datetime, Nanos, TimeZone]:
     return (dt, nanos, tz)
 
 
-def time_from_iso(s_orig: str) -> tuple[_time, Nanos]:
-    s, sep, nanos_raw = _split_nextchar(s_orig, ".,", 6, 9)
-
+def time_from_iso(s: str) -> tuple[_time, Nanos]:
     try:
-        return (
-            _time_from_iso_nofrac(s),
-            _parse_nanos(nanos_raw) if sep else 0,
-        )
+        return _time_from_iso(s)
     except ValueError:
-        _parse_err(s_orig)
+        _parse_err(s, _read_time)
+
+
+def _time_from_iso(s_orig: str) -> tuple[_time, Nanos]:
+    s, sep, nanos_raw = _split_nextchar(s_orig, ".,", 6, 9)
+    return (
+        _time_from_iso_nofrac(s),
+        _parse_nanos(nanos_raw) if sep else 0,
+    )
 
 
 # Parse the time, UTC offset, and timezone ID
@@ -210,7 +336,7 @@ def _time_offset_tz_from_iso(
                 offset_secs = -offset_secs
             offset = mk_fixed_tzinfo(offset_secs)
 
-    time, nanos = time_from_iso(s_time)
+    time, nanos = _time_from_iso(s_time)
     return (time, nanos, offset, tz)
 
 
@@ -254,8 +380,20 @@ else:  # pragma: no cover
 
 
 def _date_from_iso_ascii(s: str) -> _date:
+    if len(s) == 10 and s[4:6] == "-W" and s[8] == "-":
+        # Week date: YYYY-Www-D
+        return _date_from_iso_week(s[:4], s[6:8], s[9])
+    if len(s) == 8 and s[4] == "W":
+        # Week date: YYYYWwwD
+        return _date_from_iso_week(s[:4], s[5:7], s[7])
     if not s.replace("-", "").isdigit():
         raise ValueError()
+    if len(s) == 8 and s[4] == "-":
+        # Ordinal date: YYYY-DDD
+        return _date_from_iso_ordinal(s[:4], s[5:])
+    if len(s) == 7:
+        # Ordinal date: YYYYDDD
+        return _date_from_iso_ordinal(s[:4], s[4:])
     if len(s) == 8:
         return _date_from_iso_basic(s)
     if len(s) == 10 and s[4] == "-" and s[7] == "-":
@@ -263,13 +401,28 @@ def _date_from_iso_ascii(s: str) -> _date:
     raise ValueError()
 
 
+def _date_from_iso_ordinal(year: str, day: str) -> _date:
+    jan1 = _date(_strict_int(year), 1, 1)
+    day_of_year = _strict_int(day)
+    d = _date.fromordinal(jan1.toordinal() + day_of_year - 1)
+    if day_of_year < 1 or d.year != jan1.year:
+        raise ValueError()
+    return d
+
+
+def _date_from_iso_week(year: str, week: str, weekday: str) -> _date:
+    return _date.fromisocalendar(
+        _strict_int(year), _strict_int(week), _strict_int(weekday)
+    )
+
+
 def date_from_iso(s: str) -> _date:
     if not s.isascii():
-        _parse_err(s)
+        _parse_err(s, _read_date)
     try:
         return _date_from_iso_ascii(s)
     except ValueError:
-        _parse_err(s)
+        _parse_err(s, _read_date)
 
 
 # The ISO parsing functions were improved in Python 3.11,
@@ -316,6 +469,127 @@ else:  # pragma: no cover
         raise ValueError()
 
 
+# The readers below port the grammar of the Rust extension, to locate
+# where (and why) an input failed to parse. They only run on failure.
+def _read_date(s: _Scan) -> _date:
+    year = s.digits(4, "year", lo=1)
+    extended = s.advance_on("-")
+
+    def sep() -> None:
+        if extended:
+            s.expect("-")
+
+    if s.advance_on("W"):
+        pos = s.pos
+        week = s.digits(2, "week")
+        sep()
+        weekday = s.digits(1, "weekday", lo=1, hi=7)
+        try:
+            return _date.fromisocalendar(year, week, weekday)
+        except (ValueError, OverflowError):
+            s.fail("week", pos)
+    elif s.count_digits(4) == 3:
+        pos = s.pos
+        day_of_year = s.digits(3, "day of year")
+        if not 0 < day_of_year <= _date(year, 12, 31).timetuple().tm_yday:
+            s.fail("day of year", pos)
+        return _date(year, 1, 1) + _timedelta(day_of_year - 1)
+    else:
+        month = s.digits(2, "month", lo=1, hi=12)
+        sep()
+        pos = s.pos
+        day = s.digits(2, "day")
+        try:
+            return _date(year, month, day)
+        except ValueError:
+            s.fail("day", pos)
+
+
+def _read_time(s: _Scan) -> _time:
+    extended = s.peek(2) == ":"
+    hour = s.digits(2, "hour", hi=23)
+    minute = second = 0
+    if extended:
+        if s.advance_on(":"):
+            minute = s.digits(2, "minute", hi=59)
+            if s.advance_on(":"):
+                second = _read_seconds(s)
+    # No valid input continues with a digit after a component,
+    # so a digit always starts the next component
+    elif s.peek() in _DIGITS:
+        minute = s.digits(2, "minute", hi=59)
+        if s.peek() in _DIGITS:
+            second = _read_seconds(s)
+    return _time(hour, minute, second)
+
+
+def _read_seconds(s: _Scan) -> int:
+    # Normalize leap seconds (60) to 59
+    second = min(s.digits(2, "second", hi=60), 59)
+    s.fraction()
+    return second
+
+
+def _read_datetime(s: _Scan) -> _datetime:
+    date = _read_date(s)
+    if not (s.peek() and _is_sep(s.peek())):
+        s.fail("T")
+    s.pos += 1
+    return _datetime.combine(date, _read_time(s))
+
+
+def _read_offset(s: _Scan) -> int:
+    sign = s.peek()
+    if sign in ("Z", "z"):
+        s.pos += 1
+        return 0
+    elif sign not in ("+", "-"):
+        s.fail("offset")
+    s.pos += 1
+    secs = s.digits(2, "offset hour", hi=23) * 3600
+    if s.advance_on(":"):
+        secs += s.digits(2, "offset minute", hi=59) * 60
+        if s.advance_on(":"):
+            secs += s.digits(2, "offset second", hi=59)
+    elif s.peek() in _DIGITS:
+        secs += s.digits(2, "offset minute", hi=59) * 60
+        if s.peek() in _DIGITS:
+            secs += s.digits(2, "offset second", hi=59)
+    return -secs if sign == "-" else secs
+
+
+def _read_tzname(s: _Scan) -> None:
+    from ._tz import is_valid_tzid
+
+    pos = s.pos
+    end = s.s.find("]", pos)
+    if end == -1:
+        s.fail("]", len(s.s))
+    s.pos = end + 1
+    if not is_valid_tzid(s.s[pos:end]):
+        s.fail("timezone ID", pos)
+
+
+def _read_offset_datetime(s: _Scan) -> None:
+    dt = _read_datetime(s)
+    try:
+        check_utc_bounds(dt.replace(tzinfo=mk_fixed_tzinfo(_read_offset(s))))
+    except ValueError:
+        raise _Mismatch(None, None)
+    if s.advance_on("["):
+        _read_tzname(s)
+
+
+def _read_zoned_datetime(s: _Scan) -> None:
+    _read_datetime(s)
+    if s.peek() in ("Z", "z"):
+        s.pos += 1
+    elif s.peek() != "[":
+        _read_offset(s)
+    s.expect("[")
+    _read_tzname(s)
+
+
 _RFC2822_WEEKDAY_TO_ISO = {
     "mon": 1,
     "tue": 2,
@@ -360,15 +634,28 @@ _RFC2822_ZONES = {
 }
 
 
-def parse_rfc2822(s: str) -> _datetime:
+# Consistent with the whitespace accepted by the Rust extension
+_RFC2822_WS = " \t\n\r\x0b\x0c"
+
+
+def parse_rfc2822(s: str, strict: bool = False) -> _datetime:
     # Technically, only tab, space and CRLF are allowed in RFC2822,
     # but we allow any ASCII whitespace
-    if not s.isascii():
-        _parse_err(s)
-
-    # Parse the weekday
     try:
-        first, second, *parts = s.split()
+        if s.isascii():
+            if strict:
+                return _parse_rfc2822_strict(s)
+            return _parse_rfc2822_lenient(s)
+    except ParseError:
+        pass
+    _parse_err(s, lambda scan: _read_rfc2822(scan, strict))
+
+
+def _parse_rfc2822_lenient(s: str) -> _datetime:
+    # Parse the weekday.
+    # In the obsolete syntax, comments may appear wherever whitespace can.
+    try:
+        first, second, *parts = _rfc2822_uncomment(s).split()
         if first.isdigit():
             iso_weekday = None
             parts = [first, second, *parts]
@@ -423,9 +710,14 @@ def parse_rfc2822(s: str) -> _datetime:
 
     # Parse the time
     try:
-        # time components may be separated by whitespace
+        # time components may be separated by whitespace,
+        # but the components themselves may not be split
         *time_parts, offset_raw = parts
-        time_raw = "".join(time_parts)
+        time_raw = ":".join(
+            p.strip() for p in " ".join(time_parts).split(":")
+        )
+        if " " in time_raw:
+            _parse_err(s)
         # Normalize leap seconds (60) to 59
         if len(time_raw) == 5 and time_raw[2] == ":":
             time = _time(int(time_raw[:2]), int(time_raw[3:]))
@@ -441,7 +733,11 @@ def parse_rfc2822(s: str) -> _datetime:
 
     # Parse the offset
     try:
-        if offset_raw.startswith(("+", "-")) and len(offset_raw) == 5:
+        if (
+            offset_raw.startswith(("+", "-"))
+            and len(offset_raw) == 5
+            and int(offset_raw[3:5]) < 60
+        ):
             sign = 1 if offset_raw[0] == "+" else -1
             offset = (
                 _timedelta(
@@ -464,6 +760,423 @@ def parse_rfc2822(s: str) -> _datetime:
     return check_utc_bounds(_datetime.combine(date, time, tzinfo=tzinfo))
 
 
+def _parse_rfc2822_strict(s: str) -> _datetime:
+    """Parse the modern syntax of RFC 5322 §3.3,
+    which only allows a comment at the end, and GMT as the only named zone.
+    """
+    pos = _skip_rfc2822_ws(s, 0)
+
+    def take_digits(min_len: int, max_len: int) -> int:
+        nonlocal pos
+        end = pos
+        while end < pos + max_len and s[end : end + 1] in _DIGITS:
+            end += 1
+        if end - pos < min_len:
+            _parse_err(s)
+        value = int(s[pos:end])
+        pos = end
+        return value
+
+    def take_ws() -> None:
+        nonlocal pos
+        end = _skip_rfc2822_ws(s, pos)
+        if end == pos:
+            _parse_err(s)
+        pos = end
+
+    iso_weekday = None
+    if s[pos : pos + 1].isalpha():
+        iso_weekday = _RFC2822_WEEKDAY_TO_ISO.get(s[pos : pos + 3].lower())
+        if iso_weekday is None or s[pos + 3 : pos + 4] != ",":
+            _parse_err(s)
+        pos = _skip_rfc2822_ws(s, pos + 4)
+    day = take_digits(1, 2)
+    take_ws()
+    month = _RFC2822_MONTH_NAMES.get(s[pos : pos + 3].lower())
+    if month is None:
+        _parse_err(s)
+    pos += 3
+    take_ws()
+    year = take_digits(4, 4)
+    take_ws()
+    hour = take_digits(2, 2)
+    if s[pos : pos + 1] != ":":
+        _parse_err(s)
+    pos += 1
+    minute = take_digits(2, 2)
+    second = 0
+    if s[pos : pos + 1] == ":":
+        pos += 1
+        second = take_digits(2, 2)
+        # Normalize leap seconds (60) to 59
+        if second == 60:
+            second = 59
+    take_ws()
+    sign = s[pos : pos + 1]
+    # Besides numeric offsets, we accept GMT:
+    # it's what format_rfc2822() and HTTP dates use.
+    if s[pos : pos + 3].upper() == "GMT":
+        sign = "+"
+        offset_h = offset_m = 0
+        pos += 3
+    elif sign in ("+", "-"):
+        pos += 1
+        offset_h = take_digits(2, 2)
+        offset_m = take_digits(2, 2)
+    else:
+        _parse_err(s)
+    if _rfc2822_uncomment(s, pos).strip(_RFC2822_WS) or offset_m > 59:
+        _parse_err(s)
+    try:
+        offset = _timedelta(hours=offset_h, minutes=offset_m)
+        dt = _datetime(
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            tzinfo=_timezone(-offset if sign == "-" else offset),
+        )
+    except ValueError:
+        _parse_err(s)
+    if iso_weekday and iso_weekday != dt.isoweekday():
+        _parse_err(s)
+    return check_utc_bounds(dt)
+
+
+_DIGITS = frozenset("0123456789")
+
+
+def _skip_rfc2822_ws(s: str, pos: int) -> int:
+    while s[pos : pos + 1] and s[pos] in _RFC2822_WS:
+        pos += 1
+    return pos
+
+
+def _rfc2822_uncomment(s: str, start: int = 0) -> str:
+    """Replace the (possibly nested) comments in parentheses
+    with whitespace, starting at the given position.
+    """
+    result = []
+    depth = 0
+    chars = iter(s[start:])
+    for c in chars:
+        if c == "(":
+            if depth == 0:
+                result.append(" ")
+            depth += 1
+        elif depth == 0:
+            result.append(c)
+        elif c == ")":
+            depth -= 1
+        elif c == "\\" and next(chars, None) is None:
+            _parse_err(s)
+    if depth:
+        _parse_err(s)
+    return "".join(result)
+
+
+def _read_rfc2822(s: _Scan, strict: bool) -> None:
+    s.whitespace()
+    if not strict:
+        _rfc2822_cfws(s)
+    weekday = None
+    if s.peek().isascii() and s.peek().isalpha():
+        weekday_pos = s.pos
+        weekday = _read_rfc2822_weekday(s, strict)
+    day_pos = s.pos
+    day = s.digits(2 if s.count_digits(2) == 2 else 1, "day")
+    _rfc2822_require_fws(s, strict)
+    month = _read_rfc2822_name(s, _RFC2822_MONTH_NAMES, "month")
+    _rfc2822_require_fws(s, strict)
+    year = _read_rfc2822_year(s, strict)
+    _rfc2822_fws(s, strict)
+    try:
+        date = _date(year, month, day)
+    except ValueError:
+        s.fail("day", day_pos)
+    if weekday and weekday != date.isoweekday():
+        s.fail("weekday", weekday_pos)
+    _read_rfc2822_time(s, strict)
+    _read_rfc2822_offset(s, strict)
+    _rfc2822_cfws(s)
+
+
+def _rfc2822_cfws(s: _Scan) -> bool:
+    """Skip whitespace and (possibly nested) comments in parentheses"""
+    skipped = s.whitespace()
+    while s.peek() == "(":
+        depth = 0
+        while True:
+            c = s.peek()
+            if c == "(":
+                depth += 1
+            elif c == ")":
+                depth -= 1
+            elif c == "\\" and s.peek(1) and s.peek(1).isascii():
+                s.pos += 1
+            elif not (c and c.isascii()) or c == "\\":
+                s.fail(")")
+            s.pos += 1
+            if depth == 0:
+                break
+        s.whitespace()
+        skipped = True
+    return skipped
+
+
+def _rfc2822_fws(s: _Scan, strict: bool) -> bool:
+    return s.whitespace() if strict else _rfc2822_cfws(s)
+
+
+def _rfc2822_require_fws(s: _Scan, strict: bool) -> None:
+    if not _rfc2822_fws(s, strict):
+        s.fail("whitespace")
+
+
+def _read_rfc2822_name(s: _Scan, names: dict[str, int], expected: str) -> int:
+    name = s.s[s.pos : s.pos + 3]
+    if not name.isascii() or (value := names.get(name.lower())) is None:
+        s.fail(expected)
+    s.pos += 3
+    return value
+
+
+def _read_rfc2822_weekday(s: _Scan, strict: bool) -> int:
+    weekday = _read_rfc2822_name(s, _RFC2822_WEEKDAY_TO_ISO, "weekday")
+    if not strict:
+        _rfc2822_cfws(s)
+    s.expect(",")
+    _rfc2822_fws(s, strict)
+    return weekday
+
+
+def _read_rfc2822_year(s: _Scan, strict: bool) -> int:
+    end = s.pos
+    while end < len(s.s) and s.s[end] not in _RFC2822_WS and s.s[end] != "(":
+        end += 1
+    digits = s.s[s.pos : end]
+    if end == len(s.s) or not all(c in _DIGITS for c in digits):
+        s.fail("year")
+    elif len(digits) == 4 and digits != "0000":
+        year = int(digits)
+    elif len(digits) == 2 and not strict:
+        year = int(digits) + (2000 if int(digits) < 50 else 1900)
+    elif len(digits) == 3 and not strict:
+        year = int(digits) + 1900
+    else:
+        s.fail("year")
+    s.pos = end
+    return year
+
+
+def _read_rfc2822_time(s: _Scan, strict: bool) -> None:
+    # Only the obsolete syntax allows whitespace within the time
+    def inner_ws() -> bool:
+        return False if strict else _rfc2822_cfws(s)
+
+    s.digits(2, "hour", hi=23)
+    inner_ws()
+    s.expect(":")
+    inner_ws()
+    s.digits(2, "minute", hi=59)
+    whitespace_after_mins = inner_ws()
+    if s.advance_on(":"):
+        inner_ws()
+        s.digits(2, "second", hi=60)
+        # Whitespace after seconds is required!
+        _rfc2822_require_fws(s, strict)
+    elif not (whitespace_after_mins or (strict and s.whitespace())):
+        # In strict mode, whitespace before the zone is still required
+        s.fail("whitespace")
+
+
+def _read_rfc2822_offset(s: _Scan, strict: bool) -> None:
+    if s.peek() in ("+", "-"):
+        s.pos += 1
+        s.digits(2, "offset hour", hi=23)
+        s.digits(2, "offset minute", hi=59)
+    elif s.peek().isascii() and s.peek().isalpha():
+        start = s.pos
+        while s.peek().isascii() and s.peek().isalpha():
+            s.pos += 1
+        if strict and s.s[start : s.pos].upper() != "GMT":
+            s.fail("offset", start)
+    else:
+        s.fail("offset")
+
+
+_HTTP_WEEKDAYS_FULL = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+]
+_HTTP_MONTHS = {m: i for i, m in enumerate(MONTH_TO_RFC2822) if m}
+
+
+def parse_http_date(s: str, current_year: int) -> _datetime:
+    """Parse an HTTP-date (RFC 9110 §5.6.7) in IMF-fixdate,
+    RFC 850, or asctime format. The result is a naive UTC datetime.
+    """
+
+    def read(scan: _Scan) -> None:
+        _read_http_date(scan, current_year)
+
+    try:
+        if s[3:5] == ", " and len(s) == 29:
+            # IMF-fixdate: Sun, 06 Nov 1994 08:49:37 GMT
+            if s[7] + s[11] + s[16] + s[25:] != "    GMT":
+                raise ValueError()
+            weekday = WEEKDAY_TO_RFC2822.index(s[:3])
+            year = _http_digits(s[12:16])
+            day = _http_digits(s[5:7])
+            month = _HTTP_MONTHS[s[8:11]]
+            time = s[17:25]
+        elif s[3:4] == " " and len(s) == 24:
+            # asctime: Sun Nov  6 08:49:37 1994
+            if s[7] + s[10] + s[19] != "   ":
+                raise ValueError()
+            weekday = WEEKDAY_TO_RFC2822.index(s[:3])
+            year = _http_digits(s[20:24])
+            day = _http_digits(s[9] if s[8] == " " else s[8:10])
+            month = _HTTP_MONTHS[s[4:7]]
+            time = s[11:19]
+        elif s[-24:-22] == ", ":
+            # RFC 850: Sunday, 06-Nov-94 08:49:37 GMT
+            if s[-20] + s[-16] + s[-13] + s[-4:] != "--  GMT":
+                raise ValueError()
+            weekday = _HTTP_WEEKDAYS_FULL.index(s[:-24])
+            # A year more than 50 years in the future is interpreted as
+            # the most recent year in the past with the same last digits.
+            latest = current_year + 50
+            year = latest - (latest - _http_digits(s[-15:-13])) % 100
+            day = _http_digits(s[-22:-20])
+            month = _HTTP_MONTHS[s[-19:-16]]
+            time = s[-12:-4]
+        else:
+            raise ValueError()
+        if time[2] + time[5] != "::":
+            raise ValueError()
+        second = _http_digits(time[6:])
+        dt = _datetime(
+            year,
+            month,
+            day,
+            _http_digits(time[:2]),
+            _http_digits(time[3:5]),
+            # Normalize leap seconds (60) to 59
+            59 if second == 60 else second,
+        )
+    except (ValueError, KeyError):
+        _parse_err(s, read)
+    if dt.weekday() != weekday:
+        _parse_err(s, read)
+    return dt
+
+
+def _read_http_date(s: _Scan, current_year: int) -> None:
+    """Mirrors the HTTP-date reader of the Rust extension"""
+    start = s.pos
+    end = start
+    while s.s[end : end + 1].isascii() and s.s[end : end + 1].isalpha():
+        end += 1
+    if end == len(s.s):
+        s.fail("weekday")
+    name = s.s[start:end]
+    s.pos = end
+    if name in WEEKDAY_TO_RFC2822:
+        weekday = WEEKDAY_TO_RFC2822.index(name)
+        if s.advance_on(","):
+            # IMF-fixdate
+            s.expect(" ")
+            day_pos = s.pos
+            day = s.digits(2, "day")
+            s.expect(" ")
+            month = _read_http_month(s)
+            s.expect(" ")
+            year = s.digits(4, "year", lo=1)
+            s.expect(" ")
+            _read_http_time(s)
+            s.expect(" ")
+            _read_http_gmt(s)
+        elif s.advance_on(" "):
+            # asctime
+            month = _read_http_month(s)
+            s.expect(" ")
+            day_pos = s.pos
+            if s.peek() == " " and s.peek(1) and s.peek(1) in _DIGITS:
+                day = int(s.peek(1))
+                s.pos += 2
+            else:
+                day = s.digits(2, "day")
+            s.expect(" ")
+            _read_http_time(s)
+            s.expect(" ")
+            year = s.digits(4, "year", lo=1)
+        else:
+            s.fail(",")
+    else:
+        # RFC 850
+        if name not in _HTTP_WEEKDAYS_FULL:
+            s.fail("weekday", start)
+        weekday = _HTTP_WEEKDAYS_FULL.index(name)
+        s.expect(",")
+        s.expect(" ")
+        day_pos = s.pos
+        day = s.digits(2, "day")
+        s.expect("-")
+        month = _read_http_month(s)
+        s.expect("-")
+        year_pos = s.pos
+        yy = s.digits(2, "year")
+        s.expect(" ")
+        _read_http_time(s)
+        s.expect(" ")
+        _read_http_gmt(s)
+        latest = current_year + 50
+        year = latest - (latest - yy) % 100
+        if not 0 < year <= 9999:
+            s.fail("year", year_pos)
+    try:
+        date = _date(year, month, day)
+    except ValueError:
+        s.fail("day", day_pos)
+    if date.weekday() != weekday:
+        s.fail("weekday", start)
+
+
+def _read_http_month(s: _Scan) -> int:
+    if (month := _HTTP_MONTHS.get(s.s[s.pos : s.pos + 3])) is None:
+        s.fail("month")
+    s.pos += 3
+    return month
+
+
+def _read_http_time(s: _Scan) -> None:
+    s.digits(2, "hour", hi=23)
+    s.expect(":")
+    s.digits(2, "minute", hi=59)
+    s.expect(":")
+    s.digits(2, "second", hi=60)
+
+
+def _read_http_gmt(s: _Scan) -> None:
+    if s.s[s.pos : s.pos + 3] != "GMT":
+        s.fail("GMT")
+    s.pos += 3
+
+
+def _http_digits(s: str) -> int:
+    if not s or not all(c in _DIGITS for c in s):
+        raise ValueError()
+    return int(s)
+
+
 _MAX_TDELTA_DIGITS = 35  # consistent with Rust extension
 
 
@@ -497,3 +1210,359 @@ def parse_timedelta_component(
         value = int(raw)
 
     return rest, value, cast(Literal["H", "M", "S"], unit)
+
+
+_ALTERNATIVE_MAX_NANOS = 86_400_000_000_000
+
+
+def delta_from_iso_alternative(s: str) -> tuple[int, int, int] | None:
+    """Parse the ISO 8601 alternative duration format,
+    e.g. ``P0001-02-03T04:05:06``. Either the date or the time part
+    may be omitted. Returns (months, days, nanos), or None if invalid."""
+    if not s.isascii():
+        return None
+    s = s.upper()
+    sign = -1 if s[:1] == "-" else 1
+    if s[:1] in ("-", "+"):
+        s = s[1:]
+    if s[:1] != "P":
+        return None
+    date, has_time, time = s[1:].partition("T")
+    months = days = nanos = 0
+    date_basic = None
+    if date:
+        if len(date) == 10 and date[4] == date[7] == "-":
+            date_basic = False
+            date = date[:4] + date[5:7] + date[8:]
+        elif len(date) == 8:
+            date_basic = True
+        if date_basic is None or not date.isdigit():
+            return None
+        months, days = int(date[4:6]), int(date[6:])
+        if months > 12 or days > 30:
+            return None
+        months += int(date[:4]) * 12
+    elif not has_time:
+        return None
+    if has_time:
+        whole, sep, frac = _split_nextchar(time, ".,")
+        if len(whole) == 8 and whole[2] == whole[5] == ":":
+            basic = False
+            whole = whole[:2] + whole[3:5] + whole[6:]
+        elif len(whole) == 6:
+            basic = True
+        else:
+            return None
+        # Basic and extended format can't be mixed
+        if (
+            (date_basic is not None and basic != date_basic)
+            or not whole.isdigit()
+            or (sep and not (frac.isdigit() and len(frac) <= 9))
+        ):
+            return None
+        hrs, mins, secs = int(whole[:2]), int(whole[2:4]), int(whole[4:])
+        nanos = (hrs * 3600 + mins * 60 + secs) * 1_000_000_000 + int(
+            frac.ljust(9, "0")
+        )
+        if mins > 59 or secs > 59 or nanos > _ALTERNATIVE_MAX_NANOS:
+            return None
+    return sign * months, sign * days, sign * nanos
+
+
+_MAX_DDELTA_DIGITS = 8  # consistent with Rust extension
+_DDELTA_UNITS = {"Y": 0, "M": 1, "W": 2, "D": 3}
+
+
+def _read_delta_prefix(s: _Scan) -> None:
+    if s.peek() in ("-", "+"):
+        s.pos += 1
+    s.expect("P", ignore_case=True)
+
+
+def _read_ddelta_components(s: _Scan, until_time: bool) -> bool:
+    """Read components like ``1Y2D``. Returns whether there were any."""
+    prev_unit = None
+    while not (s.done() or (until_time and s.peek() in ("T", "t"))):
+        digits = s.count_digits()
+        if not 0 < digits <= _MAX_DDELTA_DIGITS:
+            s.fail("number")
+        s.pos += digits
+        unit = s.peek().upper() if s.peek().isascii() else ""
+        if unit not in _DDELTA_UNITS:
+            s.fail("unit")
+        s.pos += 1
+        if unit != "D" and prev_unit is not None and (
+            _DDELTA_UNITS[prev_unit] >= _DDELTA_UNITS[unit]
+        ):
+            s.fail("unit", s.pos - 1)
+        prev_unit = unit
+        # Days are always the last component
+        if unit == "D":
+            break
+    return prev_unit is not None
+
+
+def _read_tdelta_components(s: _Scan) -> None:
+    """Read components like ``4H30M``. At least one is required."""
+    prev_unit = None
+    while True:
+        digits = s.count_digits()
+        if not 0 < digits < _MAX_TDELTA_DIGITS:
+            s.fail("number")
+        s.pos += digits
+        unit = s.peek().upper() if s.peek().isascii() else ""
+        if unit in (".", ","):
+            s.pos += 1
+            digits = s.count_digits(9)
+            if digits == 0:
+                s.fail("fraction")
+            s.pos += digits
+            s.expect("S", ignore_case=True)
+            return
+        elif unit not in ("H", "M", "S"):
+            s.fail("unit")
+        s.pos += 1
+        # Seconds are always the last component
+        if unit == "S":
+            return
+        elif prev_unit is not None and (unit == "H" or prev_unit == "M"):
+            s.fail("unit", s.pos - 1)
+        prev_unit = unit
+        if s.done():
+            return
+
+
+def _read_ddelta_alternative(s: _Scan) -> bool:
+    """Read the date part of the alternative format, e.g. ``0001-02-03``.
+    Returns whether it's in basic format."""
+    s.digits(4, "year")
+    basic = not s.advance_on("-")
+    s.digits(2, "month", hi=12)
+    if not basic:
+        s.expect("-")
+    s.digits(2, "day", hi=30)
+    return basic
+
+
+def _read_tdelta_alternative(s: _Scan, basic: bool | None) -> None:
+    """Read the time part of the alternative format, e.g. ``04:05:06``.
+    If ``basic`` is given, the format must match it."""
+    start = s.pos
+    hours = s.digits(2, "hour")
+    if basic is None:
+        basic = not s.advance_on(":")
+    elif not basic:
+        s.expect(":")
+    minutes = s.digits(2, "minute", hi=59)
+    if not basic:
+        s.expect(":")
+    seconds = s.digits(2, "second", hi=59)
+    nanos = (hours * 3600 + minutes * 60 + seconds) * 1_000_000_000
+    if nanos + s.fraction() > _ALTERNATIVE_MAX_NANOS:
+        s.fail("hour", start)
+
+
+def _read_dtdelta_alternative(s: _Scan) -> None:
+    _read_delta_prefix(s)
+    basic = None if s.peek() in ("T", "t") else _read_ddelta_alternative(s)
+    if not s.done():
+        s.expect("T", ignore_case=True)
+        # Basic and extended format can't be mixed
+        _read_tdelta_alternative(s, basic)
+
+
+def read_time_delta_iso(s: _Scan) -> None:
+    if s.s[-1:] in _DIGITS:
+        _read_dtdelta_alternative(s)
+    else:
+        _read_delta_prefix(s)
+        s.expect("T", ignore_case=True)
+        _read_tdelta_components(s)
+
+
+def read_date_delta_iso(s: _Scan) -> None:
+    _read_delta_prefix(s)
+    if s.s[-1:] in _DIGITS:
+        _read_ddelta_alternative(s)
+    elif not _read_ddelta_components(s, until_time=False):
+        s.fail("number")
+
+
+def read_datetime_delta_iso(s: _Scan) -> None:
+    if s.s[-1:] in _DIGITS:
+        _read_dtdelta_alternative(s)
+    else:
+        read_itemized_delta_iso(s)
+
+
+def read_itemized_delta_iso(s: _Scan) -> None:
+    _read_delta_prefix(s)
+    has_date = _read_ddelta_components(s, until_time=True)
+    if not s.done():
+        s.expect("T", ignore_case=True)
+        _read_tdelta_components(s)
+    elif not has_date:
+        s.fail("number")
+
+
+def read_itemized_date_delta_iso(s: _Scan) -> None:
+    _read_delta_prefix(s)
+    if not _read_ddelta_components(s, until_time=False):
+        s.fail("number")
+
+
+# Go-style and English durations, e.g. "1h30m" or "2 days, 3 hours"
+_NS_PER_US = 1_000
+_NS_PER_MS = 1_000_000
+_NS_PER_S = 1_000_000_000
+_NS_PER_MIN = 60 * _NS_PER_S
+_NS_PER_H = 60 * _NS_PER_MIN
+_NS_PER_D = 24 * _NS_PER_H
+_NS_PER_W = 7 * _NS_PER_D
+
+# The micro sign (U+00B5) and the Greek small letter mu (U+03BC)
+_MICRO_SIGNS = ("µs", "μs")
+
+_GO_UNITS = {
+    "ns": 1,
+    "us": _NS_PER_US,
+    **dict.fromkeys(_MICRO_SIGNS, _NS_PER_US),
+    "ms": _NS_PER_MS,
+    "s": _NS_PER_S,
+    "m": _NS_PER_MIN,
+    "h": _NS_PER_H,
+}
+
+_ENGLISH_UNITS = {
+    **dict.fromkeys(
+        ("ns", "nsec", "nsecs", "nanosecond", "nanoseconds"), 1
+    ),
+    **dict.fromkeys(
+        ("us", *_MICRO_SIGNS, "usec", "usecs", "microsecond", "microseconds"),
+        _NS_PER_US,
+    ),
+    **dict.fromkeys(
+        ("ms", "msec", "msecs", "millisecond", "milliseconds"), _NS_PER_MS
+    ),
+    **dict.fromkeys(("s", "sec", "secs", "second", "seconds"), _NS_PER_S),
+    **dict.fromkeys(("m", "min", "mins", "minute", "minutes"), _NS_PER_MIN),
+    **dict.fromkeys(("h", "hr", "hrs", "hour", "hours"), _NS_PER_H),
+    **dict.fromkeys(("d", "day", "days"), _NS_PER_D),
+    **dict.fromkeys(("w", "wk", "wks", "week", "weeks"), _NS_PER_W),
+}
+
+_ASCII_DIGITS = "0123456789"
+# Consistent with the Rust extension
+_ASCII_WHITESPACE = " \t\n\r\x0b\x0c"
+# Beyond this, a number is out of range anyway
+_MAX_DURATION_DIGITS = 40
+
+
+def _take_decimal(s: str, i: int) -> tuple[str, str, int] | None:
+    start = i
+    while i < len(s) and s[i] in _ASCII_DIGITS:
+        i += 1
+    whole = s[start:i]
+    frac = ""
+    if s[i : i + 1] == ".":
+        start = i = i + 1
+        while i < len(s) and s[i] in _ASCII_DIGITS:
+            i += 1
+        frac = s[start:i]
+    if not (whole or frac):
+        return None
+    return whole, frac, i
+
+
+def _decimal_nanos(whole: str, frac: str, unit: int) -> int:
+    whole = whole.lstrip("0")
+    if len(whole) > _MAX_DURATION_DIGITS:
+        return 10**_MAX_DURATION_DIGITS
+    # Working from the last digit keeps the truncation exact,
+    # consistent with the Rust extension.
+    fraction = 0
+    for c in reversed(frac):
+        fraction = (int(c) * unit + fraction) // 10
+    return int(whole or "0") * unit + fraction
+
+
+def _skip_whitespace(s: str, i: int) -> int:
+    while i < len(s) and s[i] in _ASCII_WHITESPACE:
+        i += 1
+    return i
+
+
+def _duration_err(s: str, position: int, expected: str) -> NoReturn:
+    raise ParseError(f"Invalid format: {s!r}", s, position, expected)
+
+
+def timedelta_from_go(s: str) -> int:
+    sign = 1
+    i = 0
+    if s[:1] in ("-", "+"):
+        sign = -1 if s[0] == "-" else 1
+        i = 1
+    if s[i:] == "0":
+        return 0
+    nanos = 0
+    while True:
+        if (decimal := _take_decimal(s, i)) is None:
+            _duration_err(s, i, "number")
+        whole, frac, i = decimal
+        # Like Go, the unit is everything up to the next number
+        end = i
+        while end < len(s) and s[end] not in "." + _ASCII_DIGITS:
+            end += 1
+        try:
+            unit = _GO_UNITS[s[i:end]]
+        except KeyError:
+            _duration_err(s, i, "unit")
+        nanos += _decimal_nanos(whole, frac, unit)
+        i = end
+        if i == len(s):
+            break
+    return sign * nanos
+
+
+def timedelta_from_english(fullstr: str) -> tuple[int, bool]:
+    s = fullstr.strip(_ASCII_WHITESPACE)
+    # The position of `s` in the full string
+    offset = len(fullstr) - len(fullstr.lstrip(_ASCII_WHITESPACE))
+    sign = 1
+    i = 0
+    if s[:1] in ("-", "+"):
+        sign = -1 if s[0] == "-" else 1
+        i = _skip_whitespace(s, 1)
+    if s[i:] == "0":
+        return 0, False
+    nanos = 0
+    uses_days = False
+    while True:
+        if (decimal := _take_decimal(s, i)) is None:
+            _duration_err(fullstr, offset + i, "number")
+        whole, frac, i = decimal
+        i = end = _skip_whitespace(s, i)
+        while end < len(s) and (s[end].isalpha() or not s[end].isascii()):
+            end += 1
+        name = "".join(c.lower() if c.isascii() else c for c in s[i:end])
+        try:
+            unit = _ENGLISH_UNITS[name]
+        except KeyError:
+            _duration_err(fullstr, offset + i, "unit")
+        uses_days |= unit >= _NS_PER_D
+        nanos += _decimal_nanos(whole, frac, unit)
+        i = end
+        if i == len(s):
+            break
+        # Components may be separated by whitespace, a comma, and/or "and"
+        i = _skip_whitespace(s, i)
+        if s[i : i + 1] == ",":
+            i = _skip_whitespace(s, i + 1)
+        if (
+            len(s) > i + 3
+            and s[i : i + 3].isascii()
+            and s[i : i + 3].lower() == "and"
+            and s[i + 3] in _ASCII_WHITESPACE
+        ):
+            i = _skip_whitespace(s, i + 3)
+    return sign * nanos, uses_days
@@ -11,6 +11,7 @@
 #   - It saves some overhead
 from __future__ import annotations
 
+from bisect import bisect_right
 from datetime import (
     date as _date,
     datetime as _datetime,
@@ -29,18 +30,20 @@ from typing import (
     Sequence,
     TypeVar,
     cast,
+    get_args,
     no_type_check,
     overload,
 )
 from warnings import warn
 
-from . import _ideltas
+from . import _codecs, _humanize, _ideltas
 from ._common import (
     OFFSET_SHIFT_STALE_MSG,
     PLAIN_SHIFT_UNAWARE_MSG,
     SPHINX_RUNNING,
     UNSET,
     WARNING_HANDLING_DOCS_MSG,
+    ParseError,
     WheneverDeprecationWarning,
     WheneverWarning,
     _Base,
@@ -50,11 +53,32 @@ from ._common import (
     mk_fixed_tzinfo,
 )
 from ._format import (
+    MISSING_DATE_FIELDS,
+    _Element,
+    _ParseState,
+    check_pivot_year,
+    compile_formats,
+    compile_formats_with,
     compile_pattern,
+    format_delta,
     format_fields,
+    parse_delta,
+    parse_delta_formats,
     parse_fields,
+    parse_formats,
+    pattern_locale,
+    validate_delta_fields,
     validate_fields,
 )
+from ._locale import (
+    EN,
+    Locale,
+    PluralRule,
+    RelativeTime,
+    get_locale,
+    register_relative_time,
+    relative_time,
+)
 from ._math import (
     DATE_DELTA_UNITS,
     DELTA_UNITS,
@@ -62,13 +86,18 @@ from ._math import (
     EXACT_UNITS_STRICT,
     NS_PER_UNIT_PLURAL,
     Sign,
+    DayCount,
     custom_round,
     date_diff,
+    day_count_from_unix_days,
+    day_count_from_unix_secs,
     days_in_month,
     increment_to_ns_for_datetime,
     increment_to_ns_for_delta,
     is_leap,
     resolve_leap_day,
+    unix_days_from_day_count,
+    unix_secs_from_day_count,
 )
 from ._parse import (
     MONTH_TO_RFC2822,
@@ -76,15 +105,24 @@ from ._parse import (
     InvalidOffsetError,
     date_from_iso,
     datetime_from_iso,
+    delta_from_iso_alternative,
+    locate_failure,
     offset_dt_from_iso,
+    parse_http_date,
     parse_rfc2822,
     parse_timedelta_component,
+    read_date_delta_iso,
+    read_datetime_delta_iso,
+    read_time_delta_iso,
     time_from_iso,
+    timedelta_from_english,
+    timedelta_from_go,
     zdt_from_iso,
 )
 from ._shared import (
     IsoWeekDate,
     MonthDay,
+    OffsetPeriod,
     Weekday,
     YearMonth,
     _nth_weekday_of_month,
@@ -107,7 +145,9 @@ from ._tz import (  # noqa: F401
     Unambiguous,
     _clear_tz_cache as _clear_tz_cache,
     _clear_tz_cache_by_keys as _clear_tz_cache_by_keys,
+    _derive_tz as _derive_tz,
     _get_tzpath as _get_tzpath,
+    _remove_derived_tz as _remove_derived_tz,
     _set_tzpath as _set_tzpath,
     get_system_tz,
     get_tz,
@@ -116,6 +156,9 @@ from ._tz import (  # noqa: F401
     resolve_ambiguity_using_prev_offset,
 )
 
+if TYPE_CHECKING:
+    from uuid import UUID
+
 CalendarUnitCompositionWarning = _ideltas.CalendarUnitCompositionWarning
 
 __all__ = (
@@ -129,6 +172,9 @@ __all__ = (
     "OffsetDateTime",
     "ZonedDateTime",
     "PlainDateTime",
+    "OffsetPeriod",
+    "ExpandedDate",
+    "Pattern",
     # Deltas and time units
     "DateDelta",
     "TimeDelta",
@@ -156,6 +202,7 @@ __all__ = (
     "SkippedTime",
     "RepeatedTime",
     "InvalidOffsetError",
+    "ParseError",
     "ImplicitlyIgnoringDST",
     "TimeZoneNotFoundError",
     # Other
@@ -168,9 +215,11 @@ __all__ = (
     "_unpkl_inst",
     "_unpkl_local",
     "_unpkl_offset",
+    "_unpkl_pattern",
     "_unpkl_tdelta",
     "_unpkl_time",
     "_unpkl_utc",
+    "_unpkl_xdate",
     "_unpkl_zoned",
 )
 
@@ -494,6 +543,118 @@ class Date(_Base):
         """
         return is_leap(self._py_date.year)
 
+    def to_julian_day(self) -> int:
+        """The Julian Day Number: the number of days since
+        4714-11-24 BCE in the proleptic Gregorian calendar.
+
+        Note that Julian days start at noon UTC.
+        The number returned is that of the Julian day starting
+        at noon on this date.
+        Use :meth:`Instant.to_julian_day` for fractional Julian dates.
+
+        >>> Date(2000, 1, 1).to_julian_day()
+        2451545
+        """
+        return self._day_count("julian_day")
+
+    @classmethod
+    def from_julian_day(cls, n: int, /) -> Date:
+        """Create a date from a Julian Day Number.
+        Inverse of :meth:`to_julian_day`.
+
+        >>> Date.from_julian_day(2451545)
+        Date("2000-01-01")
+        """
+        return cls._from_day_count("julian_day", n)
+
+    def to_mjd(self) -> int:
+        """The Modified Julian Date: the number of days since 1858-11-17
+
+        >>> Date(2000, 1, 1).to_mjd()
+        51544
+        """
+        return self._day_count("mjd")
+
+    @classmethod
+    def from_mjd(cls, n: int, /) -> Date:
+        """Create a date from a Modified Julian Date.
+        Inverse of :meth:`to_mjd`.
+
+        >>> Date.from_mjd(51544)
+        Date("2000-01-01")
+        """
+        return cls._from_day_count("mjd", n)
+
+    def to_rata_die(self) -> int:
+        """The Rata Die day number, where 0001-01-01 is day 1
+
+        >>> Date(2000, 1, 1).to_rata_die()
+        730120
+        """
+        return self._day_count("rata_die")
+
+    @classmethod
+    def from_rata_die(cls, n: int, /) -> Date:
+        """Create a date from a Rata Die day number.
+        Inverse of :meth:`to_rata_die`.
+
+        >>> Date.from_rata_die(730120)
+        Date("2000-01-01")
+        """
+        return cls._from_day_count("rata_die", n)
+
+    def to_excel_serial(self, *, epoch_1904: bool = False) -> int:
+        """The serial date number used by spreadsheets such as Excel
+
+        By default, Excel's 1900 date system is used, in which
+        1900-01-01 is serial 1.
+        For compatibility with Lotus 1-2-3, this system treats 1900
+        as a leap year. Dates before 1900-03-01 are therefore
+        one lower than the actual number of days.
+        Lotus 1-2-3 serial dates are the same as this system.
+
+        Pass ``epoch_1904=True`` to use the 1904 date system instead,
+        in which 1904-01-01 is serial 0.
+
+        >>> Date(2024, 3, 15).to_excel_serial()
+        45366
+        >>> Date(2024, 3, 15).to_excel_serial(epoch_1904=True)
+        43904
+        """
+        return self._day_count(_excel_day_count(epoch_1904))
+
+    @classmethod
+    def from_excel_serial(
+        cls, n: int, /, *, epoch_1904: bool = False
+    ) -> Date:
+        """Create a date from a spreadsheet serial date number.
+        Inverse of :meth:`to_excel_serial`.
+
+        >>> Date.from_excel_serial(45366)
+        Date("2024-03-15")
+
+        Raises
+        ------
+        ValueError
+            If the serial is out of range, or for serial 60 in the 1900
+            date system, which is the nonexistent date 1900-02-29.
+        """
+        return cls._from_day_count(_excel_day_count(epoch_1904), n)
+
+    def _day_count(self, count: DayCount) -> int:
+        return day_count_from_unix_days(
+            count, self._py_date.toordinal() - 719_163
+        )
+
+    @classmethod
+    def _from_day_count(cls, count: DayCount, n: int) -> Date:
+        if not isinstance(n, int):
+            raise TypeError("day number must be an integer")
+        ordinal = unix_days_from_day_count(count, n) + 719_163
+        if not 1 <= ordinal <= _MAX_ORDINAL:
+            raise ValueError("Date out of range")
+        return cls._from_py_unchecked(_date.fromordinal(ordinal))
+
     def next_day(self) -> Date:
         """The date immediately following
 
@@ -727,11 +888,17 @@ class Date(_Base):
         The following formats are accepted:
         - ``YYYY-MM-DD`` ("extended" format)
         - ``YYYYMMDD`` ("basic" format)
+        - ``YYYY-DDD`` or ``YYYYDDD`` (ordinal date)
+        - ``YYYY-Www-D`` or ``YYYYWwwD`` (week date)
 
         Inverse of :meth:`format_iso`
 
         >>> Date.parse_iso("2021-01-02")
         Date("2021-01-02")
+        >>> Date.parse_iso("2021-002")
+        Date("2021-01-02")
+        >>> Date.parse_iso("2020-W53-6")
+        Date("2021-01-02")
         """
         return cls._from_py_unchecked(date_from_iso(s))
 
@@ -740,7 +907,14 @@ class Date(_Base):
 
     _PATTERN_CATS = frozenset({"date"})
 
-    def format(self, pattern: str, /) -> str:
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: str = "pattern",
+    ) -> str:
         """Format as a custom pattern string.
 
         See :ref:`pattern-format` for details.
@@ -749,9 +923,18 @@ class Date(_Base):
         '2024/03/15'
         >>> Date(2024, 3, 15).format("DD MMM YYYY")
         '15 Mar 2024'
+        >>> Date(2024, 3, 15).format("D. MMMM YYYY", locale="de")
+        '15. März 2024'
         """
-        elements = compile_pattern(pattern)
+        elements = compile_pattern(pattern, style)
         validate_fields(elements, self._PATTERN_CATS, "Date")
+        return self._format_elements(
+            elements, pattern_locale(elements, locale)
+        )
+
+    def _format_elements(
+        self, elements: Sequence[_Element], locale: Locale
+    ) -> str:
         d = self._py_date
         return format_fields(
             elements,
@@ -759,13 +942,24 @@ class Date(_Base):
             month=d.month,
             day=d.day,
             weekday=d.weekday(),
+            locale=locale,
         )
 
     def __format__(self, spec: str, /) -> str:
         return str(self) if not spec else self.format(spec)
 
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> Date:
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: str = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> Date:
         """Parse a date from a custom pattern string.
 
         See :ref:`pattern-format` for details.
@@ -774,22 +968,23 @@ class Date(_Base):
         Date("2024-03-15")
         >>> Date.parse("15 Mar 2024", format="DD MMM YYYY")
         Date("2024-03-15")
+        >>> Date.parse("15 mars 2024", format="DD MMMM YYYY", locale="fr")
+        Date("2024-03-15")
         """
-        elements = compile_pattern(format)
-        validate_fields(elements, cls._PATTERN_CATS, "Date")
-        state = parse_fields(elements, s)
+        state = parse_formats(
+            compile_formats(format, style, cls._PATTERN_CATS, "Date"),
+            s,
+            locale,
+            _expect_bool(lenient, "lenient"),
+            check_pivot_year(pivot_year),
+        )
+        return cls._from_parsed(state)
+
+    @classmethod
+    def _from_parsed(cls, state: _ParseState) -> Date:
         if state.year is None or state.month is None or state.day is None:
-            raise ValueError(
-                "Pattern must include year (YYYY/YY), "
-                "month (MM/MMM/MMMM), and day (DD) fields"
-            )
-        result = cls(state.year, state.month, state.day)
-        if (
-            state.weekday is not None
-            and result._py_date.weekday() != state.weekday
-        ):
-            raise ValueError("Parsed weekday does not match the date")
-        return result
+            raise ValueError(MISSING_DATE_FIELDS)
+        return cls(state.year, state.month, state.day)
 
     if not TYPE_CHECKING:  # for a nice autodoc
 
@@ -1514,7 +1709,14 @@ class Time(_Base):
 
     _PATTERN_CATS = frozenset({"time"})
 
-    def format(self, pattern: str, /) -> str:
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: str = "pattern",
+    ) -> str:
         """Format as a custom pattern string.
 
         See :ref:`pattern-format` for details.
@@ -1524,8 +1726,15 @@ class Time(_Base):
         >>> Time(14, 30).format("ii:mm aa")
         '02:30 PM'
         """
-        elements = compile_pattern(pattern)
+        elements = compile_pattern(pattern, style)
         validate_fields(elements, self._PATTERN_CATS, "Time")
+        return self._format_elements(
+            elements, pattern_locale(elements, locale)
+        )
+
+    def _format_elements(
+        self, elements: Sequence[_Element], locale: Locale
+    ) -> str:
         t = self._py
         return format_fields(
             elements,
@@ -1533,13 +1742,23 @@ class Time(_Base):
             minute=t.minute,
             second=t.second,
             nanos=self._nanos,
+            locale=locale,
         )
 
     def __format__(self, spec: str, /) -> str:
         return str(self) if not spec else self.format(spec)
 
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> Time:
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: str = "pattern",
+        lenient: bool = False,
+    ) -> Time:
         """Parse a time from a custom pattern string.
 
         See :ref:`pattern-format` for details.
@@ -1549,9 +1768,16 @@ class Time(_Base):
         >>> Time.parse("02:30 PM", format="ii:mm aa")
         Time(14:30:00)
         """
-        elements = compile_pattern(format)
-        validate_fields(elements, cls._PATTERN_CATS, "Time")
-        state = parse_fields(elements, s)
+        state = parse_formats(
+            compile_formats(format, style, cls._PATTERN_CATS, "Time"),
+            s,
+            locale,
+            _expect_bool(lenient, "lenient"),
+        )
+        return cls._from_parsed(state)
+
+    @classmethod
+    def _from_parsed(cls, state: _ParseState) -> Time:
         return cls(
             hour=state.hour or 0,
             minute=state.minute or 0,
@@ -1964,6 +2190,45 @@ class TimeDelta(_Base):
         except KeyError:
             raise ValueError(f"Invalid unit: {unit!r}")
 
+    def humanize(
+        self,
+        *,
+        granularity: Literal[
+            "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+        days_assumed_24h_ok: bool = False,
+    ) -> str:
+        """Describe the delta in words, such as "in 3 hours" or "2 days ago"
+
+        The largest unit (up to weeks) of which the delta is at least one
+        is used, but never a unit smaller than ``granularity``.
+        The amount is rounded with ``round_mode``.
+        Positive deltas are described as being in the future,
+        negative ones as being in the past.
+
+        Since days and weeks are taken to be 24 hours and 7 days,
+        a :class:`DaysAssumed24HoursWarning` is emitted if they're used,
+        unless ``days_assumed_24h_ok=True``.
+
+        >>> TimeDelta(hours=3, minutes=40).humanize()
+        'in 3 hours'
+        >>> TimeDelta(minutes=-90).humanize(round_mode="half_even")
+        '2 hours ago'
+        >>> TimeDelta(seconds=20).humanize(granularity="minutes")
+        'now'
+        >>> TimeDelta(minutes=5).humanize(locale="de")
+        'in 5 Minuten'
+        """
+        return _humanize_exact(
+            self._total_ns,
+            granularity,
+            round_mode,
+            locale,
+            days_assumed_24h_ok,
+        )
+
     def in_days_of_24h(self) -> float:
         """The total size in days (of exactly 24 hours each)
 
@@ -2298,7 +2563,9 @@ class TimeDelta(_Base):
         self._init_from_py(td)
         return self
 
-    def format_iso(self) -> str:
+    def format_iso(
+        self, *, alternative: bool = False, basic: bool = False
+    ) -> str:
         """Format as the *popular interpretation* of the ISO 8601 duration format.
         May not strictly adhere to (all versions of) the standard.
         See :ref:`here <iso8601-durations>` for more information.
@@ -2307,7 +2574,22 @@ class TimeDelta(_Base):
 
         >>> TimeDelta(hours=1, minutes=30).format_iso()
         'PT1H30M'
+
+        With ``alternative=True``, the ISO 8601 alternative format is used
+        (in basic format if ``basic=True``).
+        It can only express durations of up to 24 hours.
+
+        >>> TimeDelta(hours=1, minutes=30).format_iso(alternative=True)
+        'PT01:30:00'
         """
+        if _check_alternative_args(alternative, basic):
+            time = _format_alternative_time(abs(self._total_ns), basic)
+            if time is None:
+                raise ValueError(
+                    "TimeDelta over 24 hours can't be expressed "
+                    "in the alternative format"
+                )
+            return f"{(self._total_ns < 0) * '-'}PT{time}"
         hrs, mins, secs, ns = abs(self).in_hrs_mins_secs_nanos()
         seconds = (
             f"{secs + ns / 1_000_000_000:.9f}".rstrip("0") if ns else str(secs)
@@ -2321,11 +2603,20 @@ class TimeDelta(_Base):
             or "0S"
         )
 
+    @locate_failure(read_time_delta_iso)
     def _init_from_iso(self, s: str) -> None:
-        exc = ValueError(f"Invalid format: {s!r}")
+        exc = ParseError(f"Invalid format: {s!r}", s)
         prev_unit = ""
         nanos = 0
 
+        if s[-1:].isdigit():
+            # The alternative format, with a date part (if any) of zero
+            parsed = delta_from_iso_alternative(s)
+            if parsed is None or parsed[:2] != (0, 0):
+                raise exc
+            self._total_ns = parsed[2]
+            return
+
         if len(s) < 4 or not s.isascii():
             raise exc
 
@@ -2370,6 +2661,9 @@ class TimeDelta(_Base):
         Does not parse all possible ISO 8601 durations.
         See :ref:`here <iso8601-durations>` for more information.
 
+        The :ref:`alternative format <iso8601-alternative-durations>`
+        (e.g. ``PT01:30:00``) is also accepted.
+
         Inverse of :meth:`format_iso`
 
         >>> TimeDelta.parse_iso("PT1H80M")
@@ -2384,6 +2678,230 @@ class TimeDelta(_Base):
         self._init_from_iso(s)
         return self
 
+    def format_go(self) -> str:
+        """Format in the style of Go's ``Duration.String()``.
+
+        Hours, minutes, and seconds are used,
+        or a single smaller unit if the delta is less than a second.
+        The result can be parsed by :meth:`parse_go`
+        and :meth:`parse_human`.
+
+        >>> TimeDelta(hours=1, minutes=30).format_go()
+        '1h30m0s'
+        >>> TimeDelta(milliseconds=-250).format_go()
+        '-250ms'
+        >>> TimeDelta(microseconds=1.5).format_go()
+        '1.5µs'
+        """
+        nanos = abs(self._total_ns)
+        sign = "-" * (self._total_ns < 0)
+        if nanos == 0:
+            return "0s"
+        elif nanos < 1_000:
+            return f"{sign}{nanos}ns"
+        elif nanos < 1_000_000:
+            return f"{sign}{_format_go_decimal(nanos, 1_000)}µs"
+        elif nanos < 1_000_000_000:
+            return f"{sign}{_format_go_decimal(nanos, 1_000_000)}ms"
+        hrs, nanos = divmod(nanos, 3_600_000_000_000)
+        mins, nanos = divmod(nanos, 60_000_000_000)
+        return (
+            sign
+            + f"{hrs}h" * bool(hrs)
+            + f"{mins}m" * bool(hrs or mins)
+            + f"{_format_go_decimal(nanos, 1_000_000_000)}s"
+        )
+
+    @classmethod
+    def parse_go(cls, s: str, /) -> TimeDelta:
+        """Parse the duration syntax of Go's ``time.ParseDuration``.
+
+        This is a sequence of decimal numbers, each with a unit
+        (``ns``, ``us``/``µs``, ``ms``, ``s``, ``m``, or ``h``),
+        optionally preceded by a sign. Units may not be separated by spaces.
+        Fractions smaller than a nanosecond are truncated.
+
+        Inverse of :meth:`format_go`.
+
+        >>> TimeDelta.parse_go("1h30m")
+        TimeDelta("PT1h30m")
+        >>> TimeDelta.parse_go("-2.5s")
+        TimeDelta("-PT2.5s")
+        >>> TimeDelta.parse_go("250ms")
+        TimeDelta("PT0.25s")
+        """
+        if not isinstance(s, str):
+            raise TypeError("parse_go() argument must be str")
+        return cls._from_parsed_nanos(timedelta_from_go(s), s)
+
+    def to_cbor(self) -> bytes:
+        """Encode as a CBOR item with tag 1002: a duration (RFC 9581),
+        as a map of seconds and nanoseconds.
+
+        >>> TimeDelta(seconds=-1.5).to_cbor().hex()
+        'd903eaa20121281a1dcd6500'
+        """
+        secs, nanos = divmod(self._total_ns, 1_000_000_000)
+        return _codecs.cbor_secs_nanos(_codecs.TAG_DURATION, secs, nanos)
+
+    @classmethod
+    def from_cbor(cls, data: bytes, /) -> TimeDelta:
+        """Decode a CBOR item with tag 1002 (duration).
+        See :meth:`to_cbor`.
+
+        >>> TimeDelta.from_cbor(bytes.fromhex("d903eaa101185a"))
+        TimeDelta("PT1m30s")
+        """
+        if type(data) is not bytes:
+            raise TypeError("expected bytes argument")
+        try:
+            tag, content = _codecs.cbor_decode(data)
+            if tag == _codecs.TAG_DURATION:
+                if type(content) is float:
+                    # Truncated like in the Rust extension
+                    content = int(content * 1_000_000_000)
+                return cls(nanoseconds=cast(int, content))
+        except ValueError:
+            pass
+        raise ValueError(_codecs.CBOR_INVALID)
+
+    def to_msgpack(self) -> bytes:
+        """Encode in the format of the MessagePack timestamp extension
+        (type -1), counting from zero instead of the UNIX epoch.
+
+        >>> TimeDelta(minutes=1, seconds=30).to_msgpack().hex()
+        'd6ff0000005a'
+        """
+        return _codecs.msgpack_encode(
+            *divmod(self._total_ns, 1_000_000_000)
+        )
+
+    @classmethod
+    def from_msgpack(cls, data: bytes, /) -> TimeDelta:
+        """Decode the format of :meth:`to_msgpack`.
+
+        >>> TimeDelta.from_msgpack(bytes.fromhex("d6ff0000005a"))
+        TimeDelta("PT1m30s")
+        """
+        if type(data) is not bytes:
+            raise TypeError("expected bytes argument")
+        try:
+            return cls(nanoseconds=_codecs.msgpack_decode(data))
+        except ValueError:
+            raise ValueError(_codecs.MSGPACK_INVALID) from None
+
+    @classmethod
+    def parse_human(
+        cls, s: str, /, *, days_assumed_24h_ok: bool = False
+    ) -> TimeDelta:
+        """Parse a lenient, English duration string such as
+        ``"1 day 3 hours"`` or ``"5 min, 30.5 sec"``.
+
+        Units may be written in full or abbreviated
+        (e.g. ``hours``, ``hour``, ``hrs``, ``hr``, ``h``), in any case.
+        Components may be separated by whitespace, commas, or ``and``.
+        The Go-style syntax accepted by :meth:`parse_go` is accepted too.
+
+        Since days and weeks are taken to be 24 hours and 7 days,
+        a :class:`DaysAssumed24HoursWarning` is emitted if they're used,
+        unless ``days_assumed_24h_ok=True``.
+
+        >>> TimeDelta.parse_human("1 hour and 30 minutes")
+        TimeDelta("PT1h30m")
+        >>> TimeDelta.parse_human("2 Days, 3 hrs", days_assumed_24h_ok=True)
+        TimeDelta("PT51h")
+        >>> TimeDelta.parse_human("-1m30s")
+        TimeDelta("-PT1m30s")
+        """
+        if not isinstance(s, str):
+            raise TypeError("parse_human() argument must be str")
+        nanos, uses_days = timedelta_from_english(s)
+        self = cls._from_parsed_nanos(nanos, s)
+        if uses_days and not days_assumed_24h_ok:
+            warn(
+                DAYS_NOT_ALWAYS_24H_MSG,
+                DaysAssumed24HoursWarning,
+                stacklevel=2,
+            )
+        return self
+
+    def format(self, pattern: str, /, *, style: str = "pattern") -> str:
+        """Format as a custom pattern string.
+
+        The largest unit in the pattern holds the total amount,
+        while smaller units hold the remainder.
+        See :ref:`delta-patterns` for details.
+        With ``style="spreadsheet"``, spreadsheet formats with an
+        elapsed-time code (e.g. ``[h]:mm:ss``) are accepted instead.
+
+        >>> d = TimeDelta(hours=50, minutes=15, seconds=9, milliseconds=250)
+        >>> d.format("hh:mm:ss.fff")
+        '50:15:09.250'
+        >>> d.format("D'd' hh'h'")
+        '2d 02h'
+        >>> (-d).format("nh:mm")
+        '-50:15'
+        >>> d.format("[h]:mm:ss", style="spreadsheet")
+        '50:15:09'
+        """
+        if not isinstance(pattern, str):
+            raise TypeError("format() argument must be str")
+        elements = compile_pattern(pattern, style)
+        validate_delta_fields(elements, "TimeDelta", style)
+        return self._format_elements(elements, EN)
+
+    def _format_elements(
+        self, elements: Sequence[_Element], locale: Locale
+    ) -> str:
+        # Deltas have no localized fields
+        return format_delta(elements, self._total_ns)
+
+    def __format__(self, spec: str, /) -> str:
+        return str(self) if not spec else self.format(spec)
+
+    @classmethod
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        lenient: bool = False,
+        style: str = "pattern",
+    ) -> TimeDelta:
+        """Parse a delta from a custom pattern string.
+
+        Inverse of :meth:`format`.
+        See :ref:`delta-patterns` for details.
+
+        >>> TimeDelta.parse("50:15:09.250", format="hh:mm:ss.fff")
+        TimeDelta("PT50h15m9.25s")
+        >>> TimeDelta.parse("-1:30", format="nh:mm")
+        TimeDelta("-PT1h30m")
+        >>> TimeDelta.parse("-26:00", format="[h]:mm", style="spreadsheet")
+        TimeDelta("-PT26h")
+        """
+        if not isinstance(s, str):
+            raise TypeError("parse() argument must be str")
+        nanos = parse_delta_formats(
+            compile_formats_with(
+                format,
+                style,
+                lambda elements: validate_delta_fields(
+                    elements, "TimeDelta", style
+                ),
+            ),
+            s,
+            _expect_bool(lenient, "lenient"),
+        )
+        return cls._from_parsed_nanos(nanos, s)
+
+    @classmethod
+    def _from_parsed_nanos(cls, nanos: int, s: str) -> TimeDelta:
+        if abs(nanos) > _MAX_DELTA_NANOS:
+            raise ParseError("delta out of range", s)
+        return TimeDelta(nanoseconds=nanos)
+
     def round(
         self,
         unit: (
@@ -2855,7 +3373,9 @@ class DateDelta(_Base):
         months = int(fmod(self._months, 12))
         return years, months, self._days
 
-    def format_iso(self) -> str:
+    def format_iso(
+        self, *, alternative: bool = False, basic: bool = False
+    ) -> str:
         """Format as the *popular interpretation* of the ISO 8601 duration format.
         May not strictly adhere to (all versions of) the standard.
         See :ref:`here <iso8601-durations>` for more information.
@@ -2882,6 +3402,13 @@ class DateDelta(_Base):
             P2M
             P1Y2M3W4D
 
+        With ``alternative=True``, the ISO 8601 alternative format is used
+        (in basic format if ``basic=True``).
+        It can only express up to 30 days.
+
+        >>> p = DateDelta(years=1, months=2, days=3)
+        >>> p.format_iso(alternative=True)
+        'P0001-02-03'
         """
         if self._months < 0 or self._days < 0:
             sign = "-"
@@ -2890,6 +3417,14 @@ class DateDelta(_Base):
             sign = ""
             months, days = self._months, self._days
 
+        if _check_alternative_args(alternative, basic):
+            if (date := _format_alternative_date(months, days, basic)) is None:
+                raise ValueError(
+                    "DateDelta with more than 30 days can't be expressed "
+                    "in the alternative format"
+                )
+            return f"{sign}P{date}"
+
         years = months // 12
         months %= 12
 
@@ -2902,8 +3437,9 @@ class DateDelta(_Base):
 
     __str__ = format_iso
 
+    @locate_failure(read_date_delta_iso)
     def _init_from_iso(self, s: str) -> None:
-        exc = ValueError(f"Invalid format: {s!r}")
+        exc = ParseError(f"Invalid format: {s!r}", s)
         prev_unit = ""
         months = 0
         days = 0
@@ -2924,6 +3460,13 @@ class DateDelta(_Base):
         else:
             raise exc
 
+        if rest[-1:].isdigit():
+            parsed = delta_from_iso_alternative("P" + rest)
+            if parsed is None or "T" in rest or parsed[0] > _MAX_DELTA_MONTHS:
+                raise exc
+            self._months, self._days = sign * parsed[0], sign * parsed[1]
+            return
+
         while rest:
             rest, value, unit = _parse_datedelta_component(rest, exc)
 
@@ -2955,6 +3498,9 @@ class DateDelta(_Base):
         Does not parse all possible ISO 8601 durations.
         See :ref:`here <iso8601-durations>` for more information.
 
+        The :ref:`alternative format <iso8601-alternative-durations>`
+        (e.g. ``P0001-02-03``) is also accepted.
+
         Inverse of :meth:`format_iso`
 
         >>> DateDelta.parse_iso("P1W11D")
@@ -3165,10 +3711,37 @@ class DateDelta(_Base):
 def _unpkl_ddelta(months: int, days: int) -> DateDelta:
     return DateDelta._from_months_days(months, days)
 
-
 _MAX_DDELTA_DIGITS = 8  # consistent with Rust extension
 
 
+def _check_alternative_args(alternative: bool, basic: bool) -> bool:
+    if basic and not alternative:
+        raise ValueError(
+            "basic format is only available with alternative=True"
+        )
+    return alternative
+
+
+def _format_alternative_date(
+    months: int, days: int, basic: bool
+) -> str | None:
+    if days > 30:
+        return None
+    sep = "" if basic else "-"
+    return f"{months // 12:04}{sep}{months % 12:02}{sep}{days:02}"
+
+
+def _format_alternative_time(nanos: int, basic: bool) -> str | None:
+    if nanos > 86_400_000_000_000:
+        return None
+    secs, subsec = divmod(nanos, 1_000_000_000)
+    sep = "" if basic else ":"
+    return (
+        f"{secs // 3600:02}{sep}{secs // 60 % 60:02}{sep}{secs % 60:02}"
+        + f".{subsec:09}".rstrip("0") * bool(subsec)
+    )
+
+
 # Returns (rest_of_string, value, unit), e.g. ("3D", 2, "Y")
 def _parse_datedelta_component(s: str, exc: Exception) -> tuple[str, int, str]:
     try:
@@ -3307,7 +3880,9 @@ class DateTimeDelta(_Base):
         whole_seconds = int(self._time_part._total_ns / 1_000_000_000)
         return self._date_part.in_months_days() + (whole_seconds, subsec_nanos)
 
-    def format_iso(self) -> str:
+    def format_iso(
+        self, *, alternative: bool = False, basic: bool = False
+    ) -> str:
         """Format as the *popular interpretation* of the ISO 8601 duration format.
         May not strictly adhere to (all versions of) the standard.
         See :ref:`here <iso8601-durations>` for more information.
@@ -3328,18 +3903,40 @@ class DateTimeDelta(_Base):
         ... )
         >>> d.format_iso()
         'P1W11DT4H0.012S'
+
+        With ``alternative=True``, the ISO 8601 alternative format is used
+        (in basic format if ``basic=True``).
+        It can only express up to 30 days and 24 hours.
+
+        >>> DateTimeDelta(months=2, hours=4).format_iso(alternative=True)
+        'P0000-02-00T04:00:00'
         """
         sign = (
             self._date_part._months < 0
             or self._date_part._days < 0
             or self._time_part._total_ns < 0
         ) * "-"
+        if _check_alternative_args(alternative, basic):
+            ddelta = abs(self._date_part)
+            date = _format_alternative_date(
+                ddelta._months, ddelta._days, basic
+            )
+            time = _format_alternative_time(
+                abs(self._time_part._total_ns), basic
+            )
+            if date is None or time is None:
+                raise ValueError(
+                    "DateTimeDelta with more than 30 days or 24 hours "
+                    "can't be expressed in the alternative format"
+                )
+            return f"{sign}P{date}T{time}"
         date = abs(self._date_part).format_iso()[1:] * bool(self._date_part)
         time = abs(self._time_part).format_iso()[1:] * bool(self._time_part)
         return sign + "P" + ((date + time) or "0D")
 
+    @locate_failure(read_datetime_delta_iso)
     def _init_from_iso(self, s: str) -> None:
-        exc = ValueError(f"Invalid format: {s!r}")
+        exc = ParseError(f"Invalid format: {s!r}", s)
         prev_unit = ""
         months = 0
         days = 0
@@ -3361,6 +3958,15 @@ class DateTimeDelta(_Base):
         else:
             raise exc
 
+        if rest[-1:].isdigit():
+            if (parsed := delta_from_iso_alternative("P" + rest)) is None:
+                raise exc
+            months, days, nanos = parsed
+            return self._init_from_parts(
+                DateDelta._from_months_days(sign * months, sign * days),
+                TimeDelta._from_nanos_unchecked(sign * nanos),
+            )
+
         while rest and not rest.startswith("T"):
             rest, value, unit = _parse_datedelta_component(rest, exc)
 
@@ -3428,6 +4034,9 @@ class DateTimeDelta(_Base):
            -PT7H4M    # -7 hours and -4 minutes (-7:04:00)
            +PT7H4M    # 7 hours and 4 minutes (7:04:00)
 
+        The :ref:`alternative format <iso8601-alternative-durations>`
+        (e.g. ``P0001-02-03T04:05:06``) is also accepted.
+
         Inverse of :meth:`format_iso`
 
         >>> DateTimeDelta.parse_iso("-P1W11DT4H")
@@ -4199,49 +4808,562 @@ class Instant(_ExactTime):
         )
 
     @classmethod
-    def now(cls) -> Instant:
-        """Create an Instant from the current time.
+    def now(cls) -> Instant:
+        """Create an Instant from the current time.
+
+        >>> Instant.now()
+        Instant("2024-06-15 12:34:56.789123456Z")
+        """
+        secs, nanos = divmod(time_ns(), 1_000_000_000)
+        return cls._from_py_unchecked(_fromtimestamp(secs, _UTC), nanos)
+
+    @classmethod
+    def from_timestamp(cls, i: int | float, /) -> Instant:
+        """Create an Instant from a UNIX timestamp (in seconds).
+
+        The inverse of the ``timestamp()`` method.
+        """
+        secs, fract = divmod(i, 1)
+        return cls._from_py_unchecked(
+            _fromtimestamp(secs, _UTC), _fract_nanos(fract)
+        )
+
+    @classmethod
+    def from_timestamp_millis(cls, i: int, /) -> Instant:
+        """Create an Instant from a UNIX timestamp (in milliseconds).
+
+        The inverse of the ``timestamp_millis()`` method.
+        """
+        if not isinstance(i, int):
+            raise TypeError("method requires an integer")
+        secs, millis = divmod(i, 1_000)
+        return cls._from_py_unchecked(
+            _fromtimestamp(secs, _UTC), millis * 1_000_000
+        )
+
+    @classmethod
+    def from_timestamp_nanos(cls, i: int, /) -> Instant:
+        """Create an Instant from a UNIX timestamp (in nanoseconds).
+
+        The inverse of the ``timestamp_nanos()`` method.
+        """
+        if not isinstance(i, int):
+            raise TypeError("method requires an integer")
+        secs, nanos = divmod(i, 1_000_000_000)
+        return cls._from_py_unchecked(_fromtimestamp(secs, _UTC), nanos)
+
+    def timestamp_micros(self) -> int:
+        """Like :meth:`timestamp`, but with microsecond precision."""
+        return self._ticks(0, 1_000_000)
+
+    @classmethod
+    def from_timestamp_micros(cls, i: int, /) -> Instant:
+        """Create an Instant from a UNIX timestamp (in microseconds).
+
+        The inverse of the ``timestamp_micros()`` method.
+        """
+        return cls._from_ticks(i, 0, 1_000_000)
+
+    def to_filetime(self) -> int:
+        """The Windows FILETIME value: the number of 100-nanosecond
+        intervals since 1601-01-01 00:00 UTC.
+
+        >>> Instant.from_utc(2024, 3, 15).to_filetime()
+        133549344000000000
+        """
+        return self._ticks(_FILETIME_EPOCH, 10_000_000)
+
+    @classmethod
+    def from_filetime(cls, i: int, /) -> Instant:
+        """Create an Instant from a Windows FILETIME value.
+        Inverse of :meth:`to_filetime`.
+
+        >>> Instant.from_filetime(133549344000000000)
+        Instant("2024-03-15 00:00:00Z")
+        """
+        return cls._from_ticks(i, _FILETIME_EPOCH, 10_000_000)
+
+    def to_dotnet_ticks(self) -> int:
+        """The number of .NET ticks: 100-nanosecond intervals
+        since 0001-01-01 00:00 UTC, as used by ``DateTime.Ticks``.
+
+        >>> Instant.from_utc(2024, 3, 15).to_dotnet_ticks()
+        638460576000000000
+        """
+        return self._ticks(_DOTNET_EPOCH, 10_000_000)
+
+    @classmethod
+    def from_dotnet_ticks(cls, i: int, /) -> Instant:
+        """Create an Instant from a number of .NET ticks, interpreted as UTC.
+        Inverse of :meth:`to_dotnet_ticks`.
+
+        >>> Instant.from_dotnet_ticks(638460576000000000)
+        Instant("2024-03-15 00:00:00Z")
+        """
+        return cls._from_ticks(i, _DOTNET_EPOCH, 10_000_000)
+
+    def to_ntp(self) -> int:
+        """The 64-bit NTP timestamp: seconds since 1900-01-01 00:00 UTC
+        as a fixed-point number, with the fraction in the lower 32 bits.
+
+        >>> Instant.from_utc(2024, 3, 15, 12).to_ntp() >> 32
+        3919492800
+
+        Raises ValueError if the instant is outside NTP era 0,
+        which ends at 2036-02-07 06:28:16 UTC.
+
+        Note
+        ----
+        The fraction is rounded up to the next 1/2^32 second,
+        so that :meth:`from_ntp` returns the original instant.
+        """
+        nanos = self.timestamp_nanos() - _NTP_EPOCH * 1_000_000_000
+        ntp = -((-nanos << 32) // 1_000_000_000)
+        if not 0 <= ntp < 1 << 64:
+            raise ValueError("Instant is outside NTP era 0 (1900-2036)")
+        return ntp
+
+    @classmethod
+    def from_ntp(cls, i: int, /) -> Instant:
+        """Create an Instant from a 64-bit NTP timestamp in era 0.
+        Inverse of :meth:`to_ntp`.
+        Fractions smaller than a nanosecond are truncated.
+
+        >>> Instant.from_ntp(3919492800 << 32 | 1 << 31)
+        Instant("2024-03-15 12:00:00.5Z")
+        """
+        if not isinstance(i, int):
+            raise TypeError("method requires an integer")
+        if not 0 <= i < 1 << 64:
+            raise ValueError("NTP timestamp out of range")
+        return cls.from_timestamp_nanos(
+            ((i * 1_000_000_000) >> 32) + _NTP_EPOCH * 1_000_000_000
+        )
+
+    def to_cocoa(self) -> float:
+        """The number of seconds since Apple's reference date,
+        2001-01-01 00:00 UTC, as used by ``NSDate`` and Core Data.
+
+        >>> Instant.from_utc(2024, 3, 15, 12).to_cocoa()
+        732196800.0
+        """
+        return (
+            int(self._py_dt.timestamp()) - _COCOA_EPOCH
+        ) + self._nanos / 1_000_000_000
+
+    @classmethod
+    def from_cocoa(cls, i: int | float, /) -> Instant:
+        """Create an Instant from a number of seconds since Apple's
+        reference date. Inverse of :meth:`to_cocoa`.
+
+        >>> Instant.from_cocoa(732196800.25)
+        Instant("2024-03-15 12:00:00.25Z")
+        """
+        if not isinstance(i, (int, float)):
+            raise TypeError("timestamp must be an integer or float")
+        # Any fraction of the last second is still in range
+        if not (
+            _MIN_TIMESTAMP - _COCOA_EPOCH
+            <= i
+            < _MAX_TIMESTAMP - _COCOA_EPOCH + 1
+        ):
+            raise ValueError("Instant out of range")
+        secs, fract = divmod(i, 1)
+        return cls._from_py_unchecked(
+            _fromtimestamp(int(secs) + _COCOA_EPOCH, _UTC),
+            _fract_nanos(fract),
+        )
+
+    def to_postgres_micros(self) -> int:
+        """The number of microseconds since 2000-01-01 00:00 UTC,
+        as used by PostgreSQL's binary ``timestamptz`` format.
+
+        >>> Instant.from_utc(2024, 3, 15).to_postgres_micros()
+        763776000000000
+        """
+        return self._ticks(_POSTGRES_EPOCH, 1_000_000)
+
+    @classmethod
+    def from_postgres_micros(cls, i: int, /) -> Instant:
+        """Create an Instant from a number of microseconds
+        since 2000-01-01 00:00 UTC. Inverse of :meth:`to_postgres_micros`.
+
+        >>> Instant.from_postgres_micros(763776000000000)
+        Instant("2024-03-15 00:00:00Z")
+        """
+        return cls._from_ticks(i, _POSTGRES_EPOCH, 1_000_000)
+
+    def to_gps_nanos(self) -> int:
+        """The number of nanoseconds since the GPS epoch,
+        1980-01-06 00:00 UTC, as counted by GPS time.
+
+        >>> Instant.from_utc(2024, 3, 15).to_gps_nanos()
+        1394496018000000000
+
+        Note
+        ----
+        GPS time doesn't have leap seconds, so it's ahead of UTC by
+        the leap seconds inserted since 1980: 18 seconds since 2017.
+        The conversion uses a table of these leap seconds,
+        so future leap seconds (if any) require an update of whenever.
+        """
+        secs = int(self._py_dt.timestamp())
+        leaps = bisect_right(_GPS_LEAP_SECONDS, secs)
+        return (secs - _GPS_EPOCH + leaps) * 1_000_000_000 + self._nanos
+
+    @classmethod
+    def from_gps_nanos(cls, i: int, /) -> Instant:
+        """Create an Instant from a number of nanoseconds in GPS time.
+        Inverse of :meth:`to_gps_nanos`.
+        A leap second itself (23:59:60 UTC), which an Instant can't
+        represent, becomes the second before it.
+
+        >>> Instant.from_gps_nanos(1394496018000000000)
+        Instant("2024-03-15 00:00:00Z")
+        """
+        if not isinstance(i, int):
+            raise TypeError("method requires an integer")
+        secs = i // 1_000_000_000
+        leaps = sum(
+            secs >= t - _GPS_EPOCH + n
+            for n, t in enumerate(_GPS_LEAP_SECONDS)
+        )
+        return cls._from_ticks(
+            i - leaps * 1_000_000_000, _GPS_EPOCH, 1_000_000_000
+        )
+
+    def to_uuid7(self) -> UUID:
+        """The smallest UUIDv7 with this instant's (millisecond) timestamp.
+        All bits except the timestamp, version, and variant are zero.
+        This makes it useful as a bound when querying time-ordered IDs.
+
+        >>> Instant.from_utc(2024, 3, 15, 12).to_uuid7()
+        UUID('018e41fb-ba00-7000-8000-000000000000')
+
+        Raises ValueError for instants before 1970,
+        which UUIDv7 can't represent.
+        """
+        from uuid import UUID
+
+        millis = self.timestamp_millis()
+        if millis < 0:
+            raise ValueError("UUIDv7 can't represent instants before 1970")
+        return UUID(int=millis << 80 | 0x7000 << 64 | 1 << 63)
+
+    @classmethod
+    def from_uuid7(cls, u: UUID | bytes, /) -> Instant:
+        """Extract the timestamp of a UUIDv7, given as a
+        :class:`~uuid.UUID` or as its 16 bytes.
+
+        >>> Instant.from_uuid7(UUID("018e41fb-bb2a-7b3c-9d4e-5f6a7b8c9d0e"))
+        Instant("2024-03-15 12:00:00.298Z")
+
+        Raises ValueError if the UUID isn't version 7.
+        """
+        from uuid import UUID
+
+        if type(u) is UUID:
+            u = u.bytes
+        elif type(u) is not bytes:
+            raise TypeError("expected a UUID or bytes")
+        if len(u) != 16 or u[6] >> 4 != 7 or u[8] >> 6 != 0b10:
+            raise ValueError("Not a version 7 UUID")
+        return cls._from_id_millis(int.from_bytes(u[:6], "big"))
+
+    def to_ulid(self) -> str:
+        """The smallest ULID with this instant's (millisecond) timestamp.
+        The random part is all zeros.
+        This makes it useful as a bound when querying time-ordered IDs.
+
+        >>> Instant.from_utc(2024, 3, 15, 12).to_ulid()
+        '01HS0ZQEG00000000000000000'
+
+        Raises ValueError for instants before 1970,
+        which ULIDs can't represent.
+        """
+        millis = self.timestamp_millis()
+        if millis < 0:
+            raise ValueError("ULID can't represent instants before 1970")
+        return "".join(
+            _CROCKFORD[millis >> (45 - 5 * i) & 31] for i in range(10)
+        ).ljust(26, "0")
+
+    @classmethod
+    def from_ulid(cls, s: str, /) -> Instant:
+        """Extract the timestamp of a ULID string. Case-insensitive.
+
+        >>> Instant.from_ulid("01HS0ZQEKXQ2Z5VHKCM5H6G1B8")
+        Instant("2024-03-15 12:00:00.125Z")
+        """
+        if type(s) is not str:
+            raise TypeError("ULID must be a string")
+        if (
+            len(s) != 26
+            or not _CROCKFORD_VALUES.keys() >= set(s)
+            or _CROCKFORD_VALUES[s[0]] > 7
+        ):
+            raise ValueError(f"Invalid ULID: {s!r}")
+        millis = 0
+        for c in s[:10]:
+            millis = millis << 5 | _CROCKFORD_VALUES[c]
+        return cls._from_id_millis(millis)
+
+    @classmethod
+    def _from_id_millis(cls, millis: int) -> Instant:
+        # The timestamps of IDs can exceed the year 9999
+        if millis > _MAX_TIMESTAMP * 1_000 + 999:
+            raise ValueError("Value or calculation out of range")
+        return cls.from_timestamp_millis(millis)
+
+    def to_snowflake(
+        self, *, epoch: Instant | OffsetDateTime | ZonedDateTime
+    ) -> int:
+        """The smallest Snowflake ID with this instant's (millisecond)
+        timestamp. The worker ID and sequence number are zero.
+        This makes it useful as a bound when querying time-ordered IDs.
+
+        Snowflake IDs count milliseconds since an epoch which differs
+        per issuer. For example, Discord's epoch is 2015-01-01 00:00 UTC,
+        and Twitter's is 1288834974657 milliseconds after the UNIX epoch.
+
+        >>> discord = Instant.from_utc(2015, 1, 1)
+        >>> Instant.from_utc(2024, 3, 15, 12).to_snowflake(epoch=discord)
+        1218166810214400000
+        """
+        millis = self.timestamp_millis() - _snowflake_epoch(
+            "to_snowflake", epoch
+        )
+        if not 0 <= millis < 1 << 41:
+            raise ValueError(
+                "Instant out of range for a Snowflake ID with this epoch"
+            )
+        return millis << 22
+
+    @classmethod
+    def from_snowflake(
+        cls,
+        i: int,
+        /,
+        *,
+        epoch: Instant | OffsetDateTime | ZonedDateTime,
+    ) -> Instant:
+        """Extract the timestamp of a Snowflake ID,
+        given the epoch of its issuer. See :meth:`to_snowflake`.
+
+        >>> discord = Instant.from_utc(2015, 1, 1)
+        >>> Instant.from_snowflake(175928847299117063, epoch=discord)
+        Instant("2016-04-30 11:18:25.796Z")
+        """
+        if not isinstance(i, int):
+            raise TypeError("method requires an integer")
+        if not 0 <= i < 1 << 63:
+            raise ValueError("Invalid Snowflake ID")
+        return cls._from_id_millis(
+            (i >> 22) + _snowflake_epoch("from_snowflake", epoch)
+        )
+
+    def to_cbor(self, *, tag: Literal[0, 1, 1001] = 1) -> bytes:
+        """Encode as a CBOR (RFC 8949) tagged date/time item.
+
+        The ``tag`` determines the representation:
+
+        - ``1`` (default): seconds since the UNIX epoch.
+          An integer if there are no fractional seconds,
+          otherwise a float (which may lose precision).
+        - ``0``: RFC 3339 text, in UTC.
+        - ``1001``: extended time (RFC 9581), a map of seconds and
+          nanoseconds. Unlike tag 1, this is always lossless.
+
+        >>> Instant.from_utc(2013, 3, 21, 20, 4).to_cbor().hex()
+        'c11a514b67b0'
+        >>> Instant.from_utc(2013, 3, 21, 20, 4).to_cbor(tag=1001).hex()
+        'd903e9a1011a514b67b0'
+        """
+        if type(tag) is not int or tag not in (0, 1, 1001):
+            raise ValueError("tag must be 0, 1, or 1001")
+        secs = int(self._py_dt.timestamp())
+        if tag == _codecs.TAG_RFC3339:
+            return _codecs.cbor_text(self.format_iso())
+        elif tag == _codecs.TAG_EPOCH:
+            return _codecs.cbor_epoch(secs, self._nanos)
+        return _codecs.cbor_secs_nanos(tag, secs, self._nanos)
+
+    @classmethod
+    def from_cbor(cls, data: bytes, /) -> Instant:
+        """Decode a CBOR item with tag 0 (RFC 3339 text),
+        1 (epoch-based), or 1001 (extended time).
+        See :meth:`to_cbor`.
+
+        >>> Instant.from_cbor(bytes.fromhex("c11a514b67b0"))
+        Instant("2013-03-21 20:04:00Z")
+        """
+        if type(data) is not bytes:
+            raise TypeError("expected bytes argument")
+        try:
+            tag, content = _codecs.cbor_decode(data)
+            if tag == _codecs.TAG_RFC3339:
+                return OffsetDateTime.parse_iso(
+                    cast(str, content)
+                ).to_instant()
+            elif tag == _codecs.TAG_EXTENDED_TIME and type(content) is int:
+                return cls._from_ticks(content, 0, 1_000_000_000)
+            elif tag in (_codecs.TAG_EPOCH, _codecs.TAG_EXTENDED_TIME):
+                secs = cast(float, content)
+                if _MIN_TIMESTAMP <= secs < _MAX_TIMESTAMP + 1:
+                    return cls.from_timestamp(secs)
+        except ValueError:
+            pass
+        raise ValueError(_codecs.CBOR_INVALID)
+
+    def to_msgpack(self) -> bytes:
+        """Encode as a MessagePack timestamp extension value (type -1),
+        including its header. The smallest of the 32, 64, and 96-bit
+        variants is used.
+
+        >>> Instant.from_utc(2013, 3, 21, 20, 4).to_msgpack().hex()
+        'd6ff514b67b0'
+        """
+        return _codecs.msgpack_encode(
+            int(self._py_dt.timestamp()), self._nanos
+        )
+
+    @classmethod
+    def from_msgpack(cls, data: bytes, /) -> Instant:
+        """Decode a MessagePack timestamp extension value,
+        including its header. See :meth:`to_msgpack`.
+
+        >>> Instant.from_msgpack(bytes.fromhex("d6ff514b67b0"))
+        Instant("2013-03-21 20:04:00Z")
+        """
+        if type(data) is not bytes:
+            raise TypeError("expected bytes argument")
+        try:
+            return cls._from_ticks(
+                _codecs.msgpack_decode(data), 0, 1_000_000_000
+            )
+        except ValueError:
+            raise ValueError(_codecs.MSGPACK_INVALID) from None
+
+    def _ticks(self, epoch: int, per_sec: int) -> int:
+        return (int(self._py_dt.timestamp()) - epoch) * per_sec + (
+            self._nanos // (1_000_000_000 // per_sec)
+        )
+
+    @classmethod
+    def _from_ticks(cls, i: int, epoch: int, per_sec: int) -> Instant:
+        if not isinstance(i, int):
+            raise TypeError("method requires an integer")
+        secs, ticks = divmod(i, per_sec)
+        secs += epoch
+        if not _MIN_TIMESTAMP <= secs <= _MAX_TIMESTAMP:
+            raise ValueError("Instant out of range")
+        return cls._from_py_unchecked(
+            _fromtimestamp(secs, _UTC),
+            ticks * (1_000_000_000 // per_sec),
+        )
+
+    def to_julian_day(self) -> float:
+        """The Julian date: the fractional number of days since
+        noon UTC on 4714-11-24 BCE in the proleptic Gregorian calendar.
+
+        >>> Instant.from_utc(2000, 1, 1, hour=12).to_julian_day()
+        2451545.0
+
+        Note
+        ----
+        A float can't represent all instants exactly.
+        For Julian dates in the present era, the precision is
+        around 40 microseconds.
+        """
+        return self._day_count("julian_day")
+
+    @classmethod
+    def from_julian_day(cls, n: float, /) -> Instant:
+        """Create an Instant from a (fractional) Julian date.
+        Inverse of :meth:`to_julian_day`.
 
-        >>> Instant.now()
-        Instant("2024-06-15 12:34:56.789123456Z")
+        >>> Instant.from_julian_day(2451545.25)
+        Instant("2000-01-01 18:00:00Z")
         """
-        secs, nanos = divmod(time_ns(), 1_000_000_000)
-        return cls._from_py_unchecked(_fromtimestamp(secs, _UTC), nanos)
+        return cls._from_day_count("julian_day", n)
+
+    def to_mjd(self) -> float:
+        """The Modified Julian Date: the fractional number of days since
+        1858-11-17 00:00 UTC
+
+        >>> Instant.from_utc(2000, 1, 1, hour=12).to_mjd()
+        51544.5
+        """
+        return self._day_count("mjd")
 
     @classmethod
-    def from_timestamp(cls, i: int | float, /) -> Instant:
-        """Create an Instant from a UNIX timestamp (in seconds).
+    def from_mjd(cls, n: float, /) -> Instant:
+        """Create an Instant from a (fractional) Modified Julian Date.
+        Inverse of :meth:`to_mjd`.
 
-        The inverse of the ``timestamp()`` method.
+        >>> Instant.from_mjd(51544.5)
+        Instant("2000-01-01 12:00:00Z")
         """
-        secs, fract = divmod(i, 1)
-        return cls._from_py_unchecked(
-            _fromtimestamp(secs, _UTC), int(fract * 1_000_000_000)
-        )
+        return cls._from_day_count("mjd", n)
+
+    def to_rata_die(self) -> float:
+        """The fractional Rata Die day number,
+        where 0001-01-01 00:00 UTC is day 1.0
+
+        >>> Instant.from_utc(2000, 1, 1, hour=6).to_rata_die()
+        730120.25
+        """
+        return self._day_count("rata_die")
 
     @classmethod
-    def from_timestamp_millis(cls, i: int, /) -> Instant:
-        """Create an Instant from a UNIX timestamp (in milliseconds).
+    def from_rata_die(cls, n: float, /) -> Instant:
+        """Create an Instant from a (fractional) Rata Die day number.
+        Inverse of :meth:`to_rata_die`.
 
-        The inverse of the ``timestamp_millis()`` method.
+        >>> Instant.from_rata_die(730120.25)
+        Instant("2000-01-01 06:00:00Z")
         """
-        if not isinstance(i, int):
-            raise TypeError("method requires an integer")
-        secs, millis = divmod(i, 1_000)
-        return cls._from_py_unchecked(
-            _fromtimestamp(secs, _UTC), millis * 1_000_000
-        )
+        return cls._from_day_count("rata_die", n)
+
+    def to_excel_serial(self, *, epoch_1904: bool = False) -> float:
+        """The fractional serial date number used by spreadsheets
+        such as Excel, in UTC.
+        See :meth:`Date.to_excel_serial` for details on the date systems.
+
+        >>> Instant.from_utc(2024, 3, 15, hour=18).to_excel_serial()
+        45366.75
+        """
+        return self._day_count(_excel_day_count(epoch_1904))
 
     @classmethod
-    def from_timestamp_nanos(cls, i: int, /) -> Instant:
-        """Create an Instant from a UNIX timestamp (in nanoseconds).
+    def from_excel_serial(
+        cls, n: float, /, *, epoch_1904: bool = False
+    ) -> Instant:
+        """Create an Instant from a (fractional) spreadsheet serial date
+        number, interpreted as UTC.
+        Inverse of :meth:`to_excel_serial`.
 
-        The inverse of the ``timestamp_nanos()`` method.
+        >>> Instant.from_excel_serial(45366.75)
+        Instant("2024-03-15 18:00:00Z")
         """
-        if not isinstance(i, int):
-            raise TypeError("method requires an integer")
-        secs, nanos = divmod(i, 1_000_000_000)
-        return cls._from_py_unchecked(_fromtimestamp(secs, _UTC), nanos)
+        return cls._from_day_count(_excel_day_count(epoch_1904), n)
+
+    def _day_count(self, count: DayCount) -> float:
+        return day_count_from_unix_secs(
+            count, int(self._py_dt.timestamp()) + self._nanos / 1e9
+        )
+
+    @classmethod
+    def _from_day_count(cls, count: DayCount, n: float) -> Instant:
+        if not isinstance(n, (int, float)):
+            raise TypeError("day number must be an integer or float")
+        try:
+            secs = unix_secs_from_day_count(count, float(n))
+        except OverflowError:
+            raise ValueError("Instant out of range") from None
+        if not _MIN_TIMESTAMP <= secs < _MAX_TIMESTAMP + 1:
+            raise ValueError("Instant out of range")
+        return cls.from_timestamp(secs)
 
     def _init_from_py(self, d: _datetime) -> None:
         if d.tzinfo is None or d.utcoffset() is None:
@@ -4256,7 +5378,7 @@ class Instant(_ExactTime):
     @classmethod
     def parse_iso(cls, s: str, /) -> Instant:
         """Parse an ISO 8601 string. Supports basic and extended formats,
-        but not week dates or ordinal dates.
+        as well as week dates and ordinal dates.
 
         See the `docs on ISO8601 support <https://whenever.rtfd.io/en/latest/reference/iso8601.html>`__ for more information.
 
@@ -4312,7 +5434,7 @@ class Instant(_ExactTime):
         )
 
     @classmethod
-    def parse_rfc2822(cls, s: str, /) -> Instant:
+    def parse_rfc2822(cls, s: str, /, *, strict: bool = False) -> Instant:
         """Parse a UTC datetime in RFC 2822 format.
 
         The inverse of the ``format_rfc2822()`` method.
@@ -4326,17 +5448,64 @@ class Instant(_ExactTime):
         >>> Instant.parse_rfc2822("Sat, 15 Aug 2020 23:12:00 -0000")
         >>> Instant.parse_rfc2822("Sat, 15 Aug 2020 23:12:00 UT")
         >>> Instant.parse_rfc2822("Sat, 15 Aug 2020 23:12:00 MST")
+        >>> Instant.parse_rfc2822("15 Aug 20 23:12 (UTC) Z")
 
         Note
         ----
-        - Although technically part of the RFC 2822 standard,
-          comments within folding whitespace are not supported.
+        By default, the obsolete syntax of RFC 5322 is accepted too:
+        named and military zones, two-digit years, omitted seconds,
+        and comments in parentheses. Unrecognized zones are taken as UTC.
+        With ``strict=True``, only the modern syntax is accepted:
+        a numeric offset or ``GMT`` (as output by :meth:`format_rfc2822`),
+        four-digit year, and at most a trailing comment.
         """
-        return cls._from_py_unchecked(parse_rfc2822(s).astimezone(_UTC), 0)
+        return cls._from_py_unchecked(
+            parse_rfc2822(s, _expect_bool(strict, "strict")).astimezone(
+                _UTC
+            ),
+            0,
+        )
+
+    @classmethod
+    def parse_http_date(cls, s: str, /) -> Instant:
+        """Parse an HTTP date in any of the formats of RFC 9110.
+
+        Next to the preferred IMF-fixdate format (as produced by
+        ``format_rfc2822()``), the obsolete RFC 850 and asctime formats
+        are accepted too.
+
+        >>> Instant.parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT")
+        Instant("1994-11-06 08:49:37Z")
+        >>> Instant.parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT")
+        Instant("1994-11-06 08:49:37Z")
+        >>> Instant.parse_http_date("Sun Nov  6 08:49:37 1994")
+        Instant("1994-11-06 08:49:37Z")
+
+        Note
+        ----
+        As the specification requires, parsing is case-sensitive,
+        and the two-digit year of the RFC 850 format is interpreted
+        relative to the current year:
+        a date more than 50 years in the future is taken to be
+        in the previous century.
+        """
+        if not isinstance(s, str):
+            raise TypeError("expected a string")
+        current_year = cls.now()._py_dt.year
+        return cls._from_py_unchecked(
+            parse_http_date(s, current_year).replace(tzinfo=_UTC), 0
+        )
 
     _PATTERN_CATS = frozenset({"date", "time", "offset"})
 
-    def format(self, pattern: str, /) -> str:
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: str = "pattern",
+    ) -> str:
         """Format as a custom pattern string.
 
         Instant formats as UTC; See :ref:`pattern-format` for details.
@@ -4344,8 +5513,15 @@ class Instant(_ExactTime):
         >>> Instant.from_utc(2024, 3, 15, 14, 30).format("YYYY-MM-DD hh:mm:ssXXX")
         '2024-03-15 14:30:00Z'
         """
-        elements = compile_pattern(pattern)
+        elements = compile_pattern(pattern, style)
         validate_fields(elements, self._PATTERN_CATS, "Instant")
+        return self._format_elements(
+            elements, pattern_locale(elements, locale)
+        )
+
+    def _format_elements(
+        self, elements: Sequence[_Element], locale: Locale
+    ) -> str:
         d = self._py_dt
         return format_fields(
             elements,
@@ -4358,13 +5534,24 @@ class Instant(_ExactTime):
             second=d.second,
             nanos=self._nanos,
             offset_secs=0,
+            locale=locale,
         )
 
     def __format__(self, spec: str, /) -> str:
         return str(self) if not spec else self.format(spec)
 
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> Instant:
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: str = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> Instant:
         """Parse an instant from a custom pattern string.
 
         The pattern **must** include an offset field (``x``/``X``)
@@ -4383,17 +5570,23 @@ class Instant(_ExactTime):
         >>> Instant.parse("2024-03-15 14:30+05:30", format="YYYY-MM-DD hh:mmxxx")
         Instant("2024-03-15 09:00:00Z")
         """
-        elements = compile_pattern(format)
-        validate_fields(elements, cls._PATTERN_CATS, "Instant")
-        state = parse_fields(elements, s)
+        state = parse_formats(
+            compile_formats(format, style, cls._PATTERN_CATS, "Instant"),
+            s,
+            locale,
+            _expect_bool(lenient, "lenient"),
+            check_pivot_year(pivot_year),
+        )
+        return cls._from_parsed(state)
+
+    @classmethod
+    def _from_parsed(cls, state: _ParseState) -> Instant:
         if state.offset_secs is None:
             raise ValueError(
                 "Instant.parse() pattern must include an offset field (x/X)"
             )
         if state.year is None or state.month is None or state.day is None:
-            raise ValueError(
-                "Pattern must include year, month, and day fields"
-            )
+            raise ValueError(MISSING_DATE_FIELDS)
         dt = check_utc_bounds(
             _datetime(
                 state.year,
@@ -4575,6 +5768,45 @@ class Instant(_ExactTime):
             rounded_time._nanos,
         )
 
+    def humanize(
+        self,
+        *,
+        relative_to: Instant | OffsetDateTime | ZonedDateTime = UNSET,
+        granularity: Literal[
+            "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+        days_assumed_24h_ok: bool = False,
+    ) -> str:
+        """Describe this moment relative to another (by default, now),
+        such as "in 3 hours" or "2 days ago"
+
+        This is equivalent to calling :meth:`TimeDelta.humanize` on the
+        difference between the two. To use calendar days, months,
+        and years, see :meth:`ZonedDateTime.humanize`.
+
+        >>> i = Instant.from_utc(2024, 3, 1, 12)
+        >>> i.humanize(relative_to=Instant.from_utc(2024, 3, 1, 9, 30))
+        'in 2 hours'
+        >>> i.humanize(relative_to=Instant.from_utc(2024, 3, 1, 12, 45))
+        '45 minutes ago'
+        """
+        if relative_to is UNSET:
+            relative_to = Instant.now()
+        elif not isinstance(relative_to, _ExactTime):
+            raise TypeError(
+                "humanize() argument must be an OffsetDateTime, "
+                "Instant, or ZonedDateTime"
+            )
+        return _humanize_exact(
+            self.timestamp_nanos() - relative_to.timestamp_nanos(),
+            granularity,
+            round_mode,
+            locale,
+            days_assumed_24h_ok,
+        )
+
     def __add__(self, delta: TimeDelta) -> Instant:
         """Add a time amount to this datetime.
 
@@ -4830,6 +6062,43 @@ class OffsetDateTime(_ExactAndLocalTime):
     def _init_from_iso(self, s: str) -> None:
         self._py_dt, self._nanos = offset_dt_from_iso(s)
 
+    def to_cbor(self) -> bytes:
+        """Encode as a CBOR (RFC 8949) item with tag 0: RFC 3339 text.
+
+        >>> d = OffsetDateTime(2013, 3, 21, 21, 4, offset=1)
+        >>> d.to_cbor()[3:]  # after the tag and text headers
+        b'2013-03-21T21:04:00+01:00'
+
+        Raises ValueError if the offset has seconds,
+        which RFC 3339 doesn't allow.
+        The other CBOR date/time tags don't store an offset.
+        Use :meth:`Instant.to_cbor` for those.
+        """
+        if self._py_dt.utcoffset().seconds % 60:  # type: ignore[union-attr]
+            raise ValueError(
+                "CBOR (RFC 3339) doesn't support offsets with seconds"
+            )
+        return _codecs.cbor_text(self.format_iso())
+
+    @classmethod
+    def from_cbor(cls, data: bytes, /) -> OffsetDateTime:
+        """Decode a CBOR item with tag 0 (RFC 3339 text).
+        See :meth:`to_cbor`.
+
+        >>> data = OffsetDateTime(2013, 3, 21, 21, 4, offset=1).to_cbor()
+        >>> OffsetDateTime.from_cbor(data)
+        OffsetDateTime("2013-03-21 21:04:00+01:00")
+        """
+        if type(data) is not bytes:
+            raise TypeError("expected bytes argument")
+        try:
+            tag, content = _codecs.cbor_decode(data)
+            if tag == _codecs.TAG_RFC3339:
+                return cls.parse_iso(cast(str, content))
+        except ValueError:
+            pass
+        raise ValueError(_codecs.CBOR_INVALID)
+
     @classmethod
     def from_timestamp(
         cls,
@@ -4869,7 +6138,7 @@ class OffsetDateTime(_ExactAndLocalTime):
         secs, fract = divmod(i, 1)
         return cls._from_py_unchecked(
             _fromtimestamp(secs, _load_offset(offset)),
-            int(fract * 1_000_000_000),
+            _fract_nanos(fract),
         )
 
     @classmethod
@@ -5260,7 +6529,9 @@ class OffsetDateTime(_ExactAndLocalTime):
         )
 
     @classmethod
-    def parse_rfc2822(cls, s: str, /) -> OffsetDateTime:
+    def parse_rfc2822(
+        cls, s: str, /, *, strict: bool = False
+    ) -> OffsetDateTime:
         """Parse an offset datetime in RFC 2822 format.
 
         The inverse of the ``format_rfc2822()`` method.
@@ -5271,19 +6542,33 @@ class OffsetDateTime(_ExactAndLocalTime):
         >>> OffsetDateTime.parse_rfc2822("Sat, 15 Aug 2020 23:12:00 UT")
         >>> OffsetDateTime.parse_rfc2822("Sat, 15 Aug 2020 23:12:00 GMT")
         >>> OffsetDateTime.parse_rfc2822("Sat, 15 Aug 2020 23:12:00 MST")
+        >>> OffsetDateTime.parse_rfc2822("Sat, 15 Aug 20 23:12 -0330 (NST)")
 
         Note
         ----
+        - By default, the obsolete syntax of RFC 5322 is accepted too:
+          named and military zones, two-digit years, omitted seconds,
+          and comments in parentheses. Unrecognized zones are taken as UTC.
+          With ``strict=True``, only the modern syntax is accepted:
+          a numeric offset or ``GMT``, four-digit year,
+          and at most a trailing comment.
         - Strictly speaking, an offset of ``-0000`` means that the offset
           is "unknown". Here, we treat it the same as +0000.
-        - Although technically part of the RFC 2822 standard,
-          comments within folding whitespace are not supported.
         """
-        return cls._from_py_unchecked(parse_rfc2822(s), 0)
+        return cls._from_py_unchecked(
+            parse_rfc2822(s, _expect_bool(strict, "strict")), 0
+        )
 
     _PATTERN_CATS = frozenset({"date", "time", "offset"})
 
-    def format(self, pattern: str, /) -> str:
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: str = "pattern",
+    ) -> str:
         """Format as a custom pattern string.
 
         See :ref:`pattern-format` for details.
@@ -5293,8 +6578,15 @@ class OffsetDateTime(_ExactAndLocalTime):
         ... )
         '2024-03-15 14:30+02:00'
         """
-        elements = compile_pattern(pattern)
+        elements = compile_pattern(pattern, style)
         validate_fields(elements, self._PATTERN_CATS, "OffsetDateTime")
+        return self._format_elements(
+            elements, pattern_locale(elements, locale)
+        )
+
+    def _format_elements(
+        self, elements: Sequence[_Element], locale: Locale
+    ) -> str:
         d = self._py_dt
         return format_fields(
             elements,
@@ -5309,13 +6601,24 @@ class OffsetDateTime(_ExactAndLocalTime):
             offset_secs=int(
                 d.utcoffset().total_seconds()  # type: ignore[union-attr]
             ),
+            locale=locale,
         )
 
     def __format__(self, spec: str, /) -> str:
         return str(self) if not spec else self.format(spec)
 
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> OffsetDateTime:
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: str = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> OffsetDateTime:
         """Parse an offset datetime from a custom pattern string.
 
         The pattern **must** include an offset field (``x``/``X``).
@@ -5331,19 +6634,27 @@ class OffsetDateTime(_ExactAndLocalTime):
         >>> OffsetDateTime.parse("2024-03-15 14:30+02:00", format="YYYY-MM-DD hh:mmxxx")
         OffsetDateTime("2024-03-15 14:30:00+02:00")
         """
-        elements = compile_pattern(format)
-        validate_fields(elements, cls._PATTERN_CATS, "OffsetDateTime")
-        state = parse_fields(elements, s)
+        state = parse_formats(
+            compile_formats(
+                format, style, cls._PATTERN_CATS, "OffsetDateTime"
+            ),
+            s,
+            locale,
+            _expect_bool(lenient, "lenient"),
+            check_pivot_year(pivot_year),
+        )
+        return cls._from_parsed(state)
+
+    @classmethod
+    def _from_parsed(cls, state: _ParseState) -> OffsetDateTime:
         if state.offset_secs is None:
             raise ValueError(
                 "OffsetDateTime.parse() pattern must include an offset "
                 "field (x/X)"
             )
         if state.year is None or state.month is None or state.day is None:
-            raise ValueError(
-                "Pattern must include year, month, and day fields"
-            )
-        result = cls(
+            raise ValueError(MISSING_DATE_FIELDS)
+        return cls(
             state.year,
             state.month,
             state.day,
@@ -5353,12 +6664,6 @@ class OffsetDateTime(_ExactAndLocalTime):
             nanosecond=state.nanos,
             offset=TimeDelta(seconds=state.offset_secs),
         )
-        if (
-            state.weekday is not None
-            and result._py_dt.weekday() != state.weekday
-        ):
-            raise ValueError("Parsed weekday does not match the date")
-        return result
 
     if not TYPE_CHECKING:  # for a nicer autodoc
 
@@ -5888,6 +7193,23 @@ class ZonedDateTime(_ExactAndLocalTime):
         secs, nanos = divmod(time_ns(), 1_000_000_000)
         return cls._from_py_unchecked(_from_epoch(secs, tz), nanos, tz)
 
+    def format_rfc2822(self, *, zone_comment: bool = False) -> str:
+        """Format as an RFC 2822 string, with a numeric offset.
+
+        With ``zone_comment=True``, the timezone abbreviation
+        is added as a comment, as is common in email headers.
+
+        >>> zdt = ZonedDateTime(2020, 8, 15, hour=23, tz="Europe/Paris")
+        >>> zdt.format_rfc2822()
+        'Sat, 15 Aug 2020 23:00:00 +0200'
+        >>> zdt.format_rfc2822(zone_comment=True)
+        'Sat, 15 Aug 2020 23:00:00 +0200 (CEST)'
+        """
+        formatted = self.to_fixed_offset().format_rfc2822()
+        if _expect_bool(zone_comment, "zone_comment"):
+            return f"{formatted} ({self.tz_abbrev()})"
+        return formatted
+
     def format_iso(
         self,
         *,
@@ -5980,7 +7302,14 @@ class ZonedDateTime(_ExactAndLocalTime):
 
     _PATTERN_CATS = frozenset({"date", "time", "offset", "tz"})
 
-    def format(self, pattern: str, /) -> str:
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: str = "pattern",
+    ) -> str:
         """Format as a custom pattern string.
 
         See :ref:`pattern-format` for details.
@@ -5990,8 +7319,15 @@ class ZonedDateTime(_ExactAndLocalTime):
         ... )
         '2024-03-15 14:30+01:00[Europe/Paris]'
         """
-        elements = compile_pattern(pattern)
+        elements = compile_pattern(pattern, style)
         validate_fields(elements, self._PATTERN_CATS, "ZonedDateTime")
+        return self._format_elements(
+            elements, pattern_locale(elements, locale)
+        )
+
+    def _format_elements(
+        self, elements: Sequence[_Element], locale: Locale
+    ) -> str:
         d = self._py_dt
         return format_fields(
             elements,
@@ -6008,6 +7344,7 @@ class ZonedDateTime(_ExactAndLocalTime):
             ),
             tz_id=self._tz.key,
             tz_abbrev=self.tz_abbrev(),
+            locale=locale,
         )
 
     def __format__(self, spec: str, /) -> str:
@@ -6019,8 +7356,12 @@ class ZonedDateTime(_ExactAndLocalTime):
         s: str,
         /,
         *,
-        format: str,
+        format: str | Sequence[str],
+        locale: str | None = None,
         disambiguate: DisambiguateStr = "compatible",
+        style: str = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
     ) -> ZonedDateTime:
         """Parse a zoned datetime from a custom pattern string.
 
@@ -6041,18 +7382,26 @@ class ZonedDateTime(_ExactAndLocalTime):
         ... )
         ZonedDateTime("2024-03-15 14:30:00+01:00[Europe/Paris]")
         """
-        elements = compile_pattern(format)
-        validate_fields(elements, cls._PATTERN_CATS, "ZonedDateTime")
-        state = parse_fields(elements, s)
+        state = parse_formats(
+            compile_formats(format, style, cls._PATTERN_CATS, "ZonedDateTime"),
+            s,
+            locale,
+            _expect_bool(lenient, "lenient"),
+            check_pivot_year(pivot_year),
+        )
+        return cls._from_parsed(state, disambiguate)
+
+    @classmethod
+    def _from_parsed(
+        cls, state: _ParseState, disambiguate: DisambiguateStr = "compatible"
+    ) -> ZonedDateTime:
         if state.tz_id is None:
             raise ValueError(
                 "ZonedDateTime.parse() pattern must include a "
                 "timezone ID field (VV)"
             )
         if state.year is None or state.month is None or state.day is None:
-            raise ValueError(
-                "Pattern must include year, month, and day fields"
-            )
+            raise ValueError(MISSING_DATE_FIELDS)
         tz = get_tz(state.tz_id)
         dt = _datetime(
             state.year,
@@ -6100,8 +7449,6 @@ class ZonedDateTime(_ExactAndLocalTime):
         self._py_dt = resolved
         self._nanos = state.nanos
         self._tz = tz
-        if state.weekday is not None and resolved.weekday() != state.weekday:
-            raise ValueError("Parsed weekday does not match the date")
         return self
 
     @classmethod
@@ -6113,7 +7460,7 @@ class ZonedDateTime(_ExactAndLocalTime):
         secs, fract = divmod(i, 1)
         _tz = get_tz(tz)
         return cls._from_py_unchecked(
-            _from_epoch(int(secs), _tz), int(fract * 1_000_000_000), _tz
+            _from_epoch(int(secs), _tz), _fract_nanos(fract), _tz
         )
 
     @classmethod
@@ -6657,6 +8004,44 @@ class ZonedDateTime(_ExactAndLocalTime):
         """
         return self._tz.meta_for_instant(int(self._py_dt.timestamp()))[1]
 
+    def offset_period(self) -> OffsetPeriod:
+        """Information about the span of time during which the
+        current UTC offset and abbreviation apply.
+
+        >>> d = ZonedDateTime(2024, 7, 1, tz="Europe/Amsterdam")
+        >>> p = d.offset_period()
+        >>> p.start
+        Instant("2024-03-31 01:00:00Z")
+        >>> p.end
+        Instant("2024-10-27 01:00:00Z")
+        >>> p.is_dst, p.std_offset, p.abbrev
+        (True, TimeDelta("PT1h"), 'CEST')
+
+        Unlike :meth:`next_transition` and :meth:`prev_transition`,
+        transitions which change neither the offset nor the abbreviation
+        are skipped. The bounds are ``None`` if there is no such transition.
+        """
+        tz = self._tz
+        epoch = int(self._py_dt.timestamp())
+        start, end = tz.period_for_instant(epoch)
+        dst_saving, abbrev = tz.meta_for_instant(epoch)
+        offset_secs = tz.offset_for_instant(epoch)
+        year = self._py_dt.year
+        year_start = (
+            _date(year, 1, 1).toordinal() - 719_163
+        ) * 86_400 - offset_secs
+        year_end = year_start + (366 if is_leap(year) else 365) * 86_400
+        return OffsetPeriod._from_parts_unchecked(
+            None if start is None else _instant_from_epoch(start),
+            None if end is None else _instant_from_epoch(end),
+            TimeDelta._from_nanos_unchecked(offset_secs * 1_000_000_000),
+            TimeDelta._from_nanos_unchecked(
+                (offset_secs - dst_saving) * 1_000_000_000
+            ),
+            abbrev,
+            tz.has_dst_between(year_start, year_end),
+        )
+
     def day_length(self) -> TimeDelta:
         """The duration between the start of the current day and the next.
         This is usually 24 hours, but may be different due to timezone transitions.
@@ -6923,6 +8308,65 @@ class ZonedDateTime(_ExactAndLocalTime):
         )
 
     # An override with shortcut for efficiency if the timezone stays the same
+    def humanize(
+        self,
+        *,
+        relative_to: ZonedDateTime = UNSET,
+        granularity: Literal[
+            "years", "months", "weeks", "days", "hours", "minutes", "seconds"
+        ] = "seconds",
+        round_mode: RoundModeStr = "trunc",
+        locale: str | None = None,
+        relative_day: bool = False,
+    ) -> str:
+        """Describe this datetime relative to another (by default, now),
+        such as "in 3 hours" or "2 months ago"
+
+        This works like :meth:`TimeDelta.humanize`, except that calendar
+        units (days, weeks, months, and years) are also available.
+        These are measured in the timezone of ``relative_to``,
+        so days aren't necessarily 24 hours.
+
+        With ``relative_day=True``, a datetime on the day before or after
+        ``relative_to`` is described by that day and its time of day,
+        such as "yesterday at 14:05".
+
+        >>> d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        >>> d.add(days=1).humanize(relative_to=d)  # only 23 hours due to DST
+        'in 1 day'
+        >>> d.humanize(relative_to=d.add(months=2, days=20))
+        '2 months ago'
+        >>> d.add(hours=-20).humanize(relative_to=d, relative_day=True)
+        'yesterday at 16:00'
+        """
+        _humanize.check_args(granularity, round_mode)
+        phrases = relative_time(locale)
+        if relative_to is UNSET:
+            now = Instant.now()
+            relative_to = self._from_py_unchecked(
+                _to_tz(now._py_dt, self._tz), now._nanos, self._tz
+            )
+        elif not isinstance(relative_to, ZonedDateTime):
+            raise TypeError("relative_to must be a ZonedDateTime")
+        if relative_day:
+            # The day is determined in the timezone of `relative_to`
+            local = _to_tz(self._py_dt, relative_to._tz)
+            days = (local.date() - relative_to._py_dt.date()).days
+            if days in (-1, 1):
+                return phrases.format_day(
+                    days, f"{local.hour:02}:{local.minute:02}"
+                )
+        delta = self - relative_to
+
+        def calendar(unit: Any) -> float:
+            return delta.total(unit, relative_to=relative_to)
+
+        selected = _humanize.select(
+            delta._total_ns, calendar, granularity, round_mode
+        )
+        assert selected is not None
+        return _humanize.format(phrases, *selected)
+
     def to_tz(self, tz: str, /) -> ZonedDateTime:
         if (_tz := get_tz(tz)) == self._tz:
             return self
@@ -7096,7 +8540,14 @@ class PlainDateTime(_LocalTime):
 
     _PATTERN_CATS = frozenset({"date", "time"})
 
-    def format(self, pattern: str, /) -> str:
+    def format(
+        self,
+        pattern: str,
+        /,
+        *,
+        locale: str | None = None,
+        style: str = "pattern",
+    ) -> str:
         """Format as a custom pattern string.
 
         Also available via ``f"{dt:YYYY-MM-DD hh:mm}"`` (Python's ``__format__``
@@ -7107,8 +8558,15 @@ class PlainDateTime(_LocalTime):
         >>> PlainDateTime(2024, 3, 15, 14, 30).format("YYYY-MM-DD hh:mm")
         '2024-03-15 14:30'
         """
-        elements = compile_pattern(pattern)
+        elements = compile_pattern(pattern, style)
         validate_fields(elements, self._PATTERN_CATS, "PlainDateTime")
+        return self._format_elements(
+            elements, pattern_locale(elements, locale)
+        )
+
+    def _format_elements(
+        self, elements: Sequence[_Element], locale: Locale
+    ) -> str:
         d = self._py_dt
         return format_fields(
             elements,
@@ -7120,13 +8578,24 @@ class PlainDateTime(_LocalTime):
             minute=d.minute,
             second=d.second,
             nanos=self._nanos,
+            locale=locale,
         )
 
     def __format__(self, spec: str, /) -> str:
         return str(self) if not spec else self.format(spec)
 
     @classmethod
-    def parse(cls, s: str, /, *, format: str) -> PlainDateTime:
+    def parse(
+        cls,
+        s: str,
+        /,
+        *,
+        format: str | Sequence[str],
+        locale: str | None = None,
+        style: str = "pattern",
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> PlainDateTime:
         """Parse a plain datetime from a custom pattern string.
 
         See :ref:`pattern-format` for details.
@@ -7134,14 +8603,20 @@ class PlainDateTime(_LocalTime):
         >>> PlainDateTime.parse("2024-03-15 14:30", format="YYYY-MM-DD hh:mm")
         PlainDateTime("2024-03-15 14:30:00")
         """
-        elements = compile_pattern(format)
-        validate_fields(elements, cls._PATTERN_CATS, "PlainDateTime")
-        state = parse_fields(elements, s)
+        state = parse_formats(
+            compile_formats(format, style, cls._PATTERN_CATS, "PlainDateTime"),
+            s,
+            locale,
+            _expect_bool(lenient, "lenient"),
+            check_pivot_year(pivot_year),
+        )
+        return cls._from_parsed(state)
+
+    @classmethod
+    def _from_parsed(cls, state: _ParseState) -> PlainDateTime:
         if state.year is None or state.month is None or state.day is None:
-            raise ValueError(
-                "Pattern must include year, month, and day fields"
-            )
-        result = cls(
+            raise ValueError(MISSING_DATE_FIELDS)
+        return cls(
             state.year,
             state.month,
             state.day,
@@ -7150,12 +8625,6 @@ class PlainDateTime(_LocalTime):
             state.second or 0,
             nanosecond=state.nanos,
         )
-        if (
-            state.weekday is not None
-            and result._py_dt.weekday() != state.weekday
-        ):
-            raise ValueError("Parsed weekday does not match the date")
-        return result
 
     def _init_from_py(self, d: _datetime) -> None:
         if d.tzinfo is not None:
@@ -7855,6 +9324,161 @@ def _unpkl_local(data: bytes) -> PlainDateTime:
     return PlainDateTime(*args, nanosecond=nanos)
 
 
+_PATTERN_TYPES = (
+    Date,
+    Time,
+    PlainDateTime,
+    OffsetDateTime,
+    ZonedDateTime,
+    Instant,
+    TimeDelta,
+)
+
+
+@final
+class Pattern:
+    """A pattern, compiled once for formatting and parsing values of one type.
+
+    Prefer this over passing the same pattern string to ``format()``
+    or ``parse()`` many times, for example in a loop.
+    The pattern is validated against the type when it's created.
+    See :ref:`pattern-format` for the pattern syntax.
+
+    >>> p = Pattern("YYYY/MM/DD", Date)
+    >>> p.format(Date(2024, 3, 15))
+    '2024/03/15'
+    >>> p.parse("2024/03/15")
+    Date("2024-03-15")
+    """
+
+    __slots__ = ("_pattern", "_cls", "_style", "_elements")
+
+    def __init__(
+        self, pattern: str, type: type[Any], /, *, style: str = "pattern"
+    ) -> None:
+        if type not in _PATTERN_TYPES:
+            raise TypeError(
+                "Pattern type must be Date, Time, PlainDateTime, "
+                "OffsetDateTime, ZonedDateTime, Instant, or TimeDelta, "
+                f"got {type!r}"
+            )
+        if not isinstance(pattern, str):
+            raise TypeError("pattern must be str")
+        elements = compile_pattern(pattern, style)
+        if type is TimeDelta:
+            validate_delta_fields(elements, "TimeDelta", style)
+        else:
+            validate_fields(elements, type._PATTERN_CATS, type.__name__)
+        self._pattern = pattern
+        self._cls = type
+        self._style = style
+        self._elements = elements
+
+    @property
+    def pattern(self) -> str:
+        """The pattern string"""
+        return self._pattern
+
+    def format(self, value: Any, /, *, locale: str | None = None) -> str:
+        """Format a value of the pattern's type.
+
+        >>> Pattern("hh:mm", Time).format(Time(14, 30))
+        '14:30'
+        """
+        if type(value) is not self._cls:
+            raise TypeError(
+                f"Expected a {self._cls.__name__} instance, "
+                f"got {type(value)!r}"
+            )
+        return value._format_elements(
+            self._elements, pattern_locale(self._elements, locale)
+        )
+
+    def parse(
+        self,
+        s: str,
+        /,
+        *,
+        locale: str | None = None,
+        disambiguate: DisambiguateStr | None = None,
+        lenient: bool = False,
+        pivot_year: int | None = None,
+    ) -> Any:
+        """Parse a string into a value of the pattern's type.
+
+        The ``disambiguate`` argument is only accepted by
+        :class:`ZonedDateTime` patterns. See :meth:`ZonedDateTime.parse`.
+
+        >>> Pattern("hh:mm", Time).parse("14:30")
+        Time("14:30:00")
+        """
+        if self._cls is TimeDelta:
+            pattern_locale(self._elements, locale)
+            check_pivot_year(pivot_year)
+            if disambiguate is not None:
+                raise TypeError(
+                    "disambiguate is only supported for ZonedDateTime "
+                    "patterns, not TimeDelta"
+                )
+            nanos = parse_delta(
+                self._elements, s, _expect_bool(lenient, "lenient")
+            )
+            return TimeDelta._from_parsed_nanos(nanos, s)
+        state = parse_fields(
+            self._elements,
+            s,
+            pattern_locale(self._elements, locale),
+            _expect_bool(lenient, "lenient"),
+            check_pivot_year(pivot_year),
+        )
+        if self._cls is ZonedDateTime:
+            return ZonedDateTime._from_parsed(
+                state, disambiguate or "compatible"
+            )
+        elif disambiguate is not None:
+            raise TypeError(
+                "disambiguate is only supported for ZonedDateTime "
+                f"patterns, not {self._cls.__name__}"
+            )
+        return self._cls._from_parsed(state)  # type: ignore[no-any-return]
+
+    def __repr__(self) -> str:
+        if self._style != "pattern":
+            return (
+                f"Pattern({self._pattern!r}, {self._cls.__name__}, "
+                f"style={self._style!r})"
+            )
+        return f"Pattern({self._pattern!r}, {self._cls.__name__})"
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, Pattern):
+            return NotImplemented
+        return (
+            self._cls is other._cls
+            and self._style == other._style
+            and self._pattern == other._pattern
+        )
+
+    def __hash__(self) -> int:
+        return hash((self._pattern, self._cls, self._style))
+
+    def __copy__(self) -> Pattern:
+        return self
+
+    def __deepcopy__(self, _: object) -> Pattern:
+        return self
+
+    def __reduce__(self) -> tuple[object, ...]:
+        return (_unpkl_pattern, (self._pattern, self._cls, self._style))
+
+
+# A separate unpickling function allows us to make backwards-compatible changes
+# to the pickling format in the future
+@no_type_check
+def _unpkl_pattern(pattern: str, type: type[Any], style: str) -> Pattern:
+    return Pattern(pattern, type, style=style)
+
+
 class PotentialDstBugWarning(WheneverWarning):
     """Base class for warnings about potential DST-related bugs in user code.
 
@@ -8148,9 +9772,71 @@ def _to_tz(dt: _datetime, tz: TimeZone) -> _datetime:
 
 
 _MAX_ORDINAL = _date.max.toordinal()
+_MIN_TIMESTAMP = -62_135_596_800
+_MAX_TIMESTAMP = 253_402_300_799
+
+
+def _excel_day_count(epoch_1904: bool) -> DayCount:
+    if not isinstance(epoch_1904, bool):
+        raise TypeError("epoch_1904 must be a boolean")
+    return "excel1904" if epoch_1904 else "excel1900"
+
+
+# Crockford's base32 alphabet, as used by ULIDs
+_CROCKFORD = "0123456789ABCDEFGHJKMNPQRSTVWXYZ"
+_CROCKFORD_VALUES = {
+    **{c: i for i, c in enumerate(_CROCKFORD)},
+    **{c.lower(): i for i, c in enumerate(_CROCKFORD)},
+}
+
+
+def _snowflake_epoch(fname: str, epoch: _ExactTime) -> int:
+    if not isinstance(epoch, _ExactTime):
+        raise TypeError(
+            f"{fname}() argument must be an OffsetDateTime, "
+            "Instant, or ZonedDateTime"
+        )
+    return epoch.timestamp_millis()
+
+
+# Epochs of other timestamp formats, in seconds since 1970-01-01
+_FILETIME_EPOCH = -11_644_473_600  # 1601-01-01
+_DOTNET_EPOCH = -62_135_596_800  # 0001-01-01
+_NTP_EPOCH = -2_208_988_800  # 1900-01-01
+_COCOA_EPOCH = 978_307_200  # 2001-01-01
+_POSTGRES_EPOCH = 946_684_800  # 2000-01-01
+_GPS_EPOCH = 315_964_800  # 1980-01-06
+# The UTC timestamps at which leap seconds took effect since the GPS epoch.
+# GPS time doesn't have leap seconds, so it's ahead of UTC by their count.
+_GPS_LEAP_SECONDS = (
+    362_793_600,  # 1981-07-01
+    394_329_600,  # 1982-07-01
+    425_865_600,  # 1983-07-01
+    489_024_000,  # 1985-07-01
+    567_993_600,  # 1988-01-01
+    631_152_000,  # 1990-01-01
+    662_688_000,  # 1991-01-01
+    709_948_800,  # 1992-07-01
+    741_484_800,  # 1993-07-01
+    773_020_800,  # 1994-07-01
+    820_454_400,  # 1996-01-01
+    867_715_200,  # 1997-07-01
+    915_148_800,  # 1999-01-01
+    1_136_073_600,  # 2006-01-01
+    1_230_768_000,  # 2009-01-01
+    1_341_100_800,  # 2012-07-01
+    1_435_708_800,  # 2015-07-01
+    1_483_228_800,  # 2017-01-01
+)
+
+
 _EPOCH_DT = _datetime(1970, 1, 1, tzinfo=_UTC)
 
 
+def _instant_from_epoch(ts: int) -> Instant:
+    return Instant._from_py_unchecked(_EPOCH_DT + _timedelta(seconds=ts), 0)
+
+
 def _from_epoch(ts: int, tz: TimeZone) -> _datetime:
     return _from_epoch_offset(ts, tz.offset_for_instant(ts))
 
@@ -8194,6 +9880,11 @@ _no_tzinfo_fold_or_ms = {"tzinfo", "fold", "microsecond"}.isdisjoint
 _fromtimestamp = _datetime.fromtimestamp
 
 
+def _fract_nanos(fract: float) -> int:
+    # A tiny negative fraction may round up to a whole second
+    return min(int(fract * 1_000_000_000), _MAX_SUBSEC_NANOS)
+
+
 def _format_date(d: _date, basic: bool) -> str:
     sep = "" if basic else "-"
     return f"{d.year:04d}{sep}{d.month:02d}{sep}{d.day:02d}"
@@ -8243,6 +9934,14 @@ def _format_nanos(ns: _Nanos, precision: str) -> str:
         raise ValueError(f"Invalid precision unit: {precision!r}. ")
 
 
+def _format_go_decimal(value: int, unit: int) -> str:
+    whole, frac = divmod(value, unit)
+    if not frac:
+        return str(whole)
+    width = len(str(unit)) - 1
+    return f"{whole}.{frac:0{width}d}".rstrip("0")
+
+
 def _format_dt(
     dt: _datetime,
     ns: _Nanos,
@@ -8262,6 +9961,62 @@ def _format_dt(
     )
 
 
+def _humanize_exact(
+    nanos: int,
+    granularity: str,
+    round_mode: str,
+    locale: str | None,
+    days_assumed_24h_ok: bool,
+) -> str:
+    _humanize.check_args(granularity, round_mode)
+    phrases = relative_time(locale)
+    selected = _humanize.select(nanos, None, granularity, round_mode)
+    if selected is None:
+        raise ValueError(
+            "a granularity of months or years requires "
+            "a `relative_to` datetime"
+        )
+    if selected[0] in ("days", "weeks") and not days_assumed_24h_ok:
+        warn(DAYS_NOT_ALWAYS_24H_MSG, DaysAssumed24HoursWarning, stacklevel=3)
+    return _humanize.format(phrases, *selected)
+
+
+# The number of phrases in a flattened table: "now", 3 plural forms
+# of 7 units for both future and past, and "yesterday", "tomorrow", "at time"
+_NUM_PHRASES = 1 + 2 * 7 * 3 + 3
+
+
+def _register_humanize_locale(
+    lang: str, plural: str, phrases: tuple[str, ...]
+) -> None:
+    if type(lang) is not str:
+        raise TypeError("language must be a string")
+    if type(plural) is not str:
+        raise TypeError("plural must be a string")
+    if plural not in get_args(PluralRule):
+        raise ValueError(f"Invalid plural rule: {plural!r}")
+    if type(phrases) is not tuple:
+        raise TypeError("phrases must be a tuple")
+    if len(phrases) != _NUM_PHRASES:
+        raise ValueError(f"Expected {_NUM_PHRASES} phrases")
+    if any(type(p) is not str for p in phrases):
+        raise TypeError("phrase must be a string")
+    future = phrases[1:22]
+    past = phrases[22:43]
+    register_relative_time(
+        lang,
+        RelativeTime(
+            now=phrases[0],
+            yesterday=phrases[43],
+            tomorrow=phrases[44],
+            at_time=phrases[45],
+            plural=cast(PluralRule, plural),
+            future=tuple(zip(future[::3], future[1::3], future[2::3])),
+            past=tuple(zip(past[::3], past[1::3], past[2::3])),
+        ),
+    )
+
+
 def _expect_bool(value: object, name: str) -> bool:
     if type(value) is not bool:
         raise TypeError(f"{name} must be a boolean")
@@ -8785,6 +10540,10 @@ AnyDelta: Any = (
     DateTimeDelta | TimeDelta | DateDelta | ItemizedDelta | ItemizedDateDelta
 )
 
+# ExpandedDate lives in its own module, but needs to subclass _Base
+# before it's made final below.
+from ._expanded import ExpandedDate, _unpkl_xdate  # noqa: E402
+
 # We expose the public members in the root of the module.
 # For clarity, we remove the "_pywhenever" part from the names,
 # since this is an implementation detail.
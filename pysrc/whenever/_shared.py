@@ -17,6 +17,7 @@ from ._common import (
     DUMMY_LEAP_YEAR,
     SPHINX_RUNNING,
     UNSET,
+    ParseError,
     _Base,
     add_alternate_constructors,
     final,
@@ -24,9 +25,9 @@ from ._common import (
 from ._math import days_in_month, is_leap
 from ._parse import _strict_int, monthday_from_iso, yearmonth_from_iso
 
-# Avoid circular import: Date is referenced in type annotations only
+# Avoid circular import: these are referenced in type annotations only
 if TYPE_CHECKING:
-    from whenever import Date
+    from whenever import Date, Instant, TimeDelta
 
 _object_new = object.__new__
 
@@ -736,27 +737,154 @@ def _unpkl_iwd(data: bytes) -> IsoWeekDate:
     return IsoWeekDate._from_parts_unchecked(year, week, Weekday(day))
 
 
+@final
+class OffsetPeriod:
+    """The span of time during which a timezone keeps the same
+    UTC offset and abbreviation.
+
+    Returned by :meth:`~whenever.ZonedDateTime.offset_period`.
+
+    >>> d = ZonedDateTime(2024, 7, 1, tz="Europe/Amsterdam")
+    >>> p = d.offset_period()
+    >>> p.start, p.end
+    (Instant("2024-03-31 01:00:00Z"), Instant("2024-10-27 01:00:00Z"))
+    >>> p.abbrev, p.is_dst
+    ('CEST', True)
+    """
+
+    __slots__ = (
+        "_start",
+        "_end",
+        "_offset",
+        "_std_offset",
+        "_abbrev",
+        "_observes_dst",
+    )
+
+    def __init__(self) -> None:
+        raise TypeError("OffsetPeriod cannot be instantiated directly")
+
+    @classmethod
+    def _from_parts_unchecked(
+        cls,
+        start: Instant | None,
+        end: Instant | None,
+        offset: TimeDelta,
+        std_offset: TimeDelta,
+        abbrev: str,
+        observes_dst: bool,
+    ) -> OffsetPeriod:
+        self = _object_new(cls)
+        self._start = start
+        self._end = end
+        self._offset = offset
+        self._std_offset = std_offset
+        self._abbrev = abbrev
+        self._observes_dst = observes_dst
+        return self
+
+    @property
+    def start(self) -> Instant | None:
+        """The moment the period starts (inclusive),
+        or ``None`` if there is no earlier transition"""
+        return self._start
+
+    @property
+    def end(self) -> Instant | None:
+        """The moment the period ends (exclusive),
+        or ``None`` if there is no later transition"""
+        return self._end
+
+    @property
+    def offset(self) -> TimeDelta:
+        """The UTC offset during the period"""
+        return self._offset
+
+    @property
+    def std_offset(self) -> TimeDelta:
+        """The standard UTC offset during the period, i.e.
+        the offset without any DST adjustment"""
+        return self._std_offset
+
+    @property
+    def dst_offset(self) -> TimeDelta:
+        """The DST adjustment during the period.
+        Zero if DST is not in effect."""
+        return self._offset - self._std_offset
+
+    @property
+    def is_dst(self) -> bool:
+        """Whether DST is in effect during the period"""
+        return self._offset != self._std_offset
+
+    @property
+    def abbrev(self) -> str:
+        """The timezone abbreviation during the period (e.g. ``"CEST"``)"""
+        return self._abbrev
+
+    @property
+    def observes_dst(self) -> bool:
+        """Whether the timezone observes DST at any point
+        in the (local) calendar year of the originating datetime"""
+        return self._observes_dst
+
+    def __repr__(self) -> str:
+        return (
+            f"OffsetPeriod(start={self._start!r}, end={self._end!r}, "
+            f"offset={self._offset!r}, std_offset={self._std_offset!r}, "
+            f"abbrev={self._abbrev!r}, observes_dst={self._observes_dst})"
+        )
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, OffsetPeriod):
+            return NotImplemented
+        return (
+            self._start == other._start
+            and self._end == other._end
+            and self._offset == other._offset
+            and self._std_offset == other._std_offset
+            and self._abbrev == other._abbrev
+            and self._observes_dst == other._observes_dst
+        )
+
+    def __hash__(self) -> int:
+        return hash(
+            (
+                self._start,
+                self._end,
+                self._offset,
+                self._std_offset,
+                self._abbrev,
+                self._observes_dst,
+            )
+        )
+
+
 def _parse_iso_week_date(s: str) -> tuple[int, int, int]:
     """Parse an ISO 8601 week date string like '2024-W01-1' or '2024W011'"""
+    exc = ParseError(f"Invalid ISO 8601 week date: {s!r}", s)
     if not s.isascii():
-        raise ValueError(f"Invalid ISO 8601 week date: {s!r}")
-    if len(s) == 10 and s[4] == "-" and s[5] == "W" and s[8] == "-":
-        # Extended format: YYYY-Www-D
-        year = _strict_int(s[:4])
-        week = _strict_int(s[6:8])
-        day = _strict_int(s[9])
-    elif len(s) == 8 and s[4] == "W":
-        # Basic format: YYYYWwwD
-        year = _strict_int(s[:4])
-        week = _strict_int(s[5:7])
-        day = _strict_int(s[7])
-    else:
-        raise ValueError(f"Invalid ISO 8601 week date: {s!r}")
+        raise exc
+    try:
+        if len(s) == 10 and s[4] == "-" and s[5] == "W" and s[8] == "-":
+            # Extended format: YYYY-Www-D
+            year = _strict_int(s[:4])
+            week = _strict_int(s[6:8])
+            day = _strict_int(s[9])
+        elif len(s) == 8 and s[4] == "W":
+            # Basic format: YYYYWwwD
+            year = _strict_int(s[:4])
+            week = _strict_int(s[5:7])
+            day = _strict_int(s[7])
+        else:
+            raise exc
+    except ValueError:
+        raise exc from None
     if not 1 <= day <= 7:
-        raise ValueError(f"Invalid ISO weekday: {day}")
+        raise ParseError(f"Invalid ISO weekday: {day}", s)
     max_weeks = 53 if _is_long_year(year) else 52
     if not 1 <= week <= max_weeks:
-        raise ValueError(f"Invalid ISO week: {week}")
+        raise ParseError(f"Invalid ISO week: {week}", s)
     return year, week, day
 
 
@@ -10,10 +10,13 @@ from .store import (
     TimeZoneNotFoundError,
     _clear_tz_cache,
     _clear_tz_cache_by_keys,
+    _derive_tz,
     _get_tzpath,
+    _remove_derived_tz,
     _set_tzpath,
     get_system_tz,
     get_tz,
+    is_valid_tzid,
     reset_system_tz,
     validate_tzid,
 )
@@ -30,10 +33,13 @@ __all__ = [
     "Unambiguous",
     "_clear_tz_cache",
     "_clear_tz_cache_by_keys",
+    "_derive_tz",
     "_get_tzpath",
+    "_remove_derived_tz",
     "_set_tzpath",
     "get_system_tz",
     "get_tz",
+    "is_valid_tzid",
     "reset_system_tz",
     "resolve_ambiguity",
     "resolve_ambiguity_using_prev_offset",
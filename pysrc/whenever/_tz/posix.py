@@ -16,12 +16,16 @@ DEFAULT_RULE_TIME = 2 * 3600
 MAX_OFFSET = 24 * 3600
 Weekday = int  # Different than usual! Sunday=0, Saturday=6
 UTC = timezone.utc
+_MAX_ORDINAL = date.max.toordinal()
 
 
 def year_for_epoch(ts: int) -> int:
     # Note: we can't use fromtimestamp() because it fails on extreme values
     # on some platforms. Instead, we go through the ordinal.
-    return date.fromordinal(ts // 86400 + 719163).year
+    # Like the Rust version, we saturate at the ends of the supported range,
+    # which rules near the extremes may exceed after applying their offset.
+    ordinal = ts // 86400 + 719163
+    return date.fromordinal(max(1, min(_MAX_ORDINAL, ordinal))).year
 
 
 def epoch_for_date(d: date) -> int:
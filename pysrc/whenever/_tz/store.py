@@ -9,7 +9,8 @@ from typing import TYPE_CHECKING, NewType
 from weakref import WeakValueDictionary
 
 from . import system
-from .tzif import TimeZone
+from .posix import TzStr
+from .tzif import EPOCH_SECS_MAX, EPOCH_SECS_MIN, TimeZone
 
 __all__ = [
     "TimeZoneNotFoundError",
@@ -17,6 +18,8 @@ __all__ = [
     "get_system_tz",
     "_clear_tz_cache",
     "_clear_tz_cache_by_keys",
+    "_derive_tz",
+    "_remove_derived_tz",
     "_get_tzpath",
     "_set_tzpath",
     "reset_system_tz",
@@ -49,6 +52,10 @@ else:
 
 _tzcache_lru_lock = _Lock()
 
+# User-defined timezones derived from others. These take precedence over
+# the cache, and are kept alive until explicitly removed.
+_derived: dict[str, TimeZone] = {}
+
 # One-entry fast path: skips LRU update for repeated lookups of the same zone.
 # Thread-safe for GIL Python (atomic assignment); under free-threading the
 # worst case is a benign missed cache-hit, which falls back to the normal path.
@@ -85,11 +92,40 @@ def _clear_tz_cache_by_keys(keys: tuple[str, ...]) -> None:
             _tzcache_lru.pop(k, None)
 
 
+def _derive_tz(key: str, base: str, start: int, rule: str) -> None:
+    global _last_tz_key, _last_tz_val
+    try:
+        validate_tzid(key)
+    except TimeZoneNotFoundError:
+        raise ValueError(f"Invalid time zone key: {key!r}") from None
+    if not EPOCH_SECS_MIN <= start <= EPOCH_SECS_MAX:
+        raise ValueError("start is out of range")
+    try:
+        tz_rule = TzStr.parse(rule)
+    except ValueError:
+        raise ValueError(f"Invalid POSIX TZ string: {rule!r}") from None
+    _derived[key] = get_tz(base).derive(key, start, tz_rule)
+    if _last_tz_key == key:
+        _last_tz_key = None
+        _last_tz_val = None
+
+
+def _remove_derived_tz(key: str) -> bool:
+    global _last_tz_key, _last_tz_val
+    if _last_tz_key == key:
+        _last_tz_key = None
+        _last_tz_val = None
+    return _derived.pop(key, None) is not None
+
+
 def get_tz(key: str) -> TimeZone:
     global _last_tz_key, _last_tz_val
     if key == _last_tz_key:
         return _last_tz_val  # type: ignore[return-value]
 
+    if _derived and (derived := _derived.get(key)) is not None:
+        return derived
+
     instance = _tzcache_lookup.get(key)
     if instance is None:
         # Concurrency note: we accept the possibility of multiple threads
@@ -114,7 +150,14 @@ def get_tz(key: str) -> TimeZone:
 
 def validate_tzid(key: str) -> SafeTzId:
     """Checks for invalid characters and path traversal in the key."""
-    if (
+    if is_valid_tzid(key):
+        return SafeTzId(key)
+    else:
+        raise TimeZoneNotFoundError.for_key(key)
+
+
+def is_valid_tzid(key: str) -> bool:
+    return (
         key.isascii()
         # There's no standard limit on IANA tz IDs, but we have to draw
         # the line somewhere to prevent abuse.
@@ -127,10 +170,7 @@ def validate_tzid(key: str) -> SafeTzId:
         # specific restrictions on the first and list characters
         and key[0] not in ".-+/"
         and key[-1] != "/"
-    ):
-        return SafeTzId(key)
-    else:
-        raise TimeZoneNotFoundError.for_key(key)
+    )
 
 
 # Alias for a TZ key that has been confirmed not to be a path traversal
@@ -177,6 +177,85 @@ class TimeZone:
             return (self._utc_epochs[idx], self._utc_offsets[idx])
         return None
 
+    def period_for_instant(
+        self, t: EpochSecs
+    ) -> tuple[EpochSecs | None, EpochSecs | None]:
+        """The bounds of the period containing `t` during which the offset
+        and metadata stay the same. A transition exactly at `t` starts
+        the period. Transitions which change neither are skipped."""
+        offset = self.offset_for_instant(t)
+        meta = self.meta_for_instant(t)
+
+        def unchanged(x: EpochSecs) -> bool:
+            return (
+                self.offset_for_instant(x) == offset
+                and self.meta_for_instant(x) == meta
+            )
+
+        start = self.prev_transition(t + 1)
+        while start is not None and unchanged(start[0] - 1):
+            start = self.prev_transition(start[0])
+        end = self.next_transition(t)
+        while end is not None and unchanged(end[0]):
+            end = self.next_transition(end[0])
+        return (
+            None if start is None else start[0],
+            None if end is None else end[0],
+        )
+
+    def has_dst_between(self, start: EpochSecs, end: EpochSecs) -> bool:
+        """Whether DST is in effect at any point in the range [start, end)"""
+        t = start
+        while True:
+            if self.meta_for_instant(t)[0] != 0:
+                return True
+            result = self.next_transition(t)
+            if result is None or result[0] >= end:
+                return False
+            t = result[0]
+
+    def derive(self, key: str, start: EpochSecs, rule: TzStr) -> TimeZone:
+        """Derive a new timezone which follows this one until `start`,
+        and the given POSIX TZ rule from then on."""
+        keep = _bisect_left(self._utc_epochs, start)
+        offsets = list(zip(self._utc_epochs[:keep], self._utc_offsets[:keep]))
+        meta = list(self._meta_by_utc[:keep])
+
+        # If `start` lies beyond the recorded transitions, the POSIX tail
+        # defines the transitions in between. These need to be made explicit,
+        # since the tail itself is replaced by the new rule.
+        if self._end is not None and keep == len(self._utc_epochs):
+            # A timezone without any transitions (e.g. from a TZ variable)
+            # is defined entirely by its tail
+            if not offsets:
+                first = self._end.offset_for_instant(EPOCH_SECS_MIN)
+                offsets.append((EPOCH_SECS_MIN, first))
+                meta.append(self._end.meta_for_instant(EPOCH_SECS_MIN))
+            t = offsets[-1][0]
+            while (
+                transition := self._end.next_transition(t)
+            ) is not None and transition[0] < start:
+                offsets.append(transition)
+                meta.append(self._end.meta_for_instant(transition[0]))
+                t = transition[0]
+
+        # The new rule takes effect at `start`. This entry is needed even if
+        # the offset doesn't change, since it marks where the rule begins.
+        offsets.append((start, rule.offset_for_instant(start)))
+        meta.append(rule.meta_for_instant(start))
+        _extend_with_posix(offsets, meta, rule)
+
+        local_transitions = _local_transitions(offsets)
+        return TimeZone(
+            key=key,
+            _utc_epochs=tuple(t for t, _ in offsets),
+            _utc_offsets=tuple(v for _, v in offsets),
+            _local_epochs=tuple(t for t, _ in local_transitions),
+            _local_values=tuple(v for _, v in local_transitions),
+            _end=rule,
+            _meta_by_utc=tuple(meta),
+        )
+
     # NOTE: this equality check needs to be fast, since it's used in
     # some routines to check if the timezone is indeed changing.
     def __eq__(self, other: object) -> bool:
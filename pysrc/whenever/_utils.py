@@ -1,21 +1,26 @@
-"""Misc public utilities, e.g. to manage the timezone cache, or patch the time"""
+"""Misc public utilities, e.g. to manage the timezone cache, patch the time,
+or register humanize phrases"""
 
 from __future__ import annotations
 
 import os.path  # NOTE: we don't use pathlib here to keep our imports light
 from contextlib import contextmanager
 from functools import partial
-from typing import Any, Iterable, Iterator, no_type_check
+from typing import Any, Iterable, Iterator, Literal, Mapping, no_type_check
 
 from ._core import (
     Instant,
     OffsetDateTime,
+    TimeDelta,
     ZonedDateTime,
     _clear_tz_cache,
     _clear_tz_cache_by_keys,
+    _derive_tz,
     _get_tzpath,
     _patch_time_frozen,
     _patch_time_keep_ticking,
+    _register_humanize_locale,
+    _remove_derived_tz,
     _set_tzpath,
     _unpatch_time,
 )
@@ -31,7 +36,10 @@ __all__ = [
     "patch_current_time",
     "reset_tzpath",
     "clear_tzcache",
+    "derive_tz",
+    "remove_derived_tz",
     "available_timezones",
+    "register_humanize_locale",
 ]
 
 
@@ -169,6 +177,73 @@ def clear_tzcache(*, only_keys: Iterable[str] | None = None) -> None:
         _clear_tz_cache_by_keys(tuple(only_keys))
 
 
+def derive_tz(
+    key: str,
+    /,
+    base: str,
+    *,
+    start: Instant | ZonedDateTime | OffsetDateTime,
+    rule: str | TimeDelta,
+) -> None:
+    """Define a timezone which follows an existing one up to ``start``,
+    and a new rule from then on. This is useful to simulate proposed
+    changes to a timezone before they're published in the tz database.
+
+    The new timezone is available under the given ``key`` wherever
+    a timezone ID is accepted. The rule is either a POSIX TZ string
+    (e.g. ``"EST5EDT,M3.2.0,M11.1.0"``) or a fixed UTC offset.
+
+    >>> derive_tz(
+    ...     "Custom/Amsterdam",
+    ...     "Europe/Amsterdam",
+    ...     start=Instant.from_utc(2027, 3, 1),
+    ...     rule="CET-1",
+    ... )
+    >>> ZonedDateTime(2027, 7, 1, tz="Custom/Amsterdam")
+    ZonedDateTime("2027-07-01 00:00:00+01:00[Custom/Amsterdam]")
+
+    Note
+    ----
+    - Derived timezones take precedence over the timezone database.
+      Using an existing key (e.g. ``"Europe/Amsterdam"``) replaces
+      the definition for all new lookups with that key.
+    - Sub-second precision of ``start`` is truncated.
+    - Existing ``ZonedDateTime`` instances are unaffected, as they keep
+      a reference to the definition at the time of their creation.
+    - Conversion to the standard library (e.g. ``to_stdlib()``) uses
+      :class:`~zoneinfo.ZoneInfo`, which doesn't know about derived timezones.
+    """
+    instant = start if isinstance(start, Instant) else start.to_instant()
+    if isinstance(rule, TimeDelta):
+        rule = _fixed_offset_rule(rule)
+    _derive_tz(key, base, instant.timestamp(), rule)
+
+
+def remove_derived_tz(key: str, /) -> bool:
+    """Remove a timezone defined with :func:`derive_tz`.
+    Returns whether the timezone was present.
+
+    Lookups with this key will use the timezone database again.
+    """
+    return _remove_derived_tz(key)
+
+
+def _fixed_offset_rule(offset: TimeDelta) -> str:
+    secs, remainder = divmod(offset.total("nanoseconds"), 1_000_000_000)
+    if remainder:
+        raise ValueError("offset must be a whole number of seconds")
+    sign = "-" if secs < 0 else "+"
+    hrs, rest = divmod(abs(secs), 3600)
+    mins, secs = divmod(rest, 60)
+    abbrev = f"{sign}{hrs:02}"
+    if mins or secs:
+        abbrev += f"{mins:02}"
+    if secs:
+        abbrev += f"{secs:02}"
+    # POSIX TZ strings have the offset sign inverted
+    return f"<{abbrev}>{'+' if sign == '-' else '-'}{hrs}:{mins:02}:{secs:02}"
+
+
 def available_timezones() -> set[str]:
     """Gather the set of all available timezones.
 
@@ -286,3 +361,113 @@ def pydantic_schema(cls):
             serialization=core_schema.to_string_ser_schema(),
         ),
     )
+
+
+_HUMANIZE_UNITS = (
+    "years",
+    "months",
+    "weeks",
+    "days",
+    "hours",
+    "minutes",
+    "seconds",
+)
+# The plural categories (one, few, other) used by each rule
+_PLURAL_FORMS = {
+    "other": (2,),
+    "one_other": (0, 2),
+    "east_slavic": (0, 1, 2),
+    "polish": (0, 1, 2),
+    "czech": (0, 1, 2),
+}
+
+
+def register_humanize_locale(
+    tag: str,
+    /,
+    *,
+    now: str,
+    future: Mapping[str, str | tuple[str, ...]],
+    past: Mapping[str, str | tuple[str, ...]],
+    yesterday: str,
+    tomorrow: str,
+    at_time: str,
+    plural: Literal[
+        "one_other", "east_slavic", "polish", "czech", "other"
+    ] = "one_other",
+) -> None:
+    """Register the phrases used by ``humanize()`` for a language,
+    replacing any built-in or previously registered phrases.
+
+    Only the language subtag of ``tag`` is considered, so registering
+    ``"eo"`` also applies to ``locale="eo-XX"``.
+
+    ``future`` and ``past`` map each unit (``"years"`` through
+    ``"seconds"``) to its phrase, in which ``{0}`` is replaced with
+    the amount. If the phrase depends on the amount, give a tuple with
+    one phrase per plural category of the ``plural`` rule:
+
+    - ``"one_other"``: 1, and other amounts (e.g. English)
+    - ``"east_slavic"``, ``"polish"``, ``"czech"``: one, few, and other
+      amounts, following the rules for these languages
+    - ``"other"``: the same phrase for all amounts (e.g. Japanese)
+
+    ``at_time`` combines ``yesterday`` or ``tomorrow`` (``{0}``) with a
+    time of day (``{1}``), for ``ZonedDateTime.humanize(relative_day=True)``.
+
+    >>> units = ("years", "months", "weeks", "days", "hours", "minutes", "seconds")
+    >>> register_humanize_locale(
+    ...     "eo",
+    ...     now="nun",
+    ...     future={u: "post {0} " + u for u in units},
+    ...     past={u: "antaŭ {0} " + u for u in units},
+    ...     yesterday="hieraŭ",
+    ...     tomorrow="morgaŭ",
+    ...     at_time="{0} je {1}",
+    ... )
+    >>> TimeDelta(hours=3).humanize(locale="eo")
+    'post 3 hours'
+
+    Note
+    ----
+    Registered phrases apply to ``humanize()`` only.
+    Names used in patterns (e.g. months) are unaffected.
+    """
+    if type(tag) is not str:
+        raise TypeError("locale must be str")
+    lang = tag.replace("_", "-").partition("-")[0]
+    if not (2 <= len(lang) <= 8 and lang.isascii() and lang.isalpha()):
+        raise ValueError(f"Invalid locale: {tag!r}")
+    try:
+        forms = _PLURAL_FORMS[plural]
+    except (KeyError, TypeError):
+        raise ValueError(f"Invalid plural rule: {plural!r}") from None
+    phrases = [now]
+    for name, table in (("future", future), ("past", past)):
+        if set(table) != set(_HUMANIZE_UNITS):
+            raise ValueError(
+                f"{name} must contain exactly the units "
+                + ", ".join(_HUMANIZE_UNITS)
+            )
+        for unit in _HUMANIZE_UNITS:
+            phrases.extend(_plural_phrases(table[unit], forms, plural))
+    phrases += (yesterday, tomorrow, at_time)
+    if any(type(p) is not str for p in phrases):
+        raise TypeError("phrases must be strings")
+    _register_humanize_locale(lang, plural, tuple(phrases))
+
+
+def _plural_phrases(
+    value: str | tuple[str, ...], forms: tuple[int, ...], plural: str
+) -> tuple[str, str, str]:
+    """Expand a phrase to one per plural category (one, few, other)"""
+    if isinstance(value, str):
+        return (value, value, value)
+    if len(value) != len(forms):
+        raise ValueError(
+            f"the {plural!r} plural rule requires "
+            f"{len(forms)} phrase(s) per unit"
+        )
+    by_category = dict(zip(forms, value))
+    other = by_category[2]
+    return (by_category.get(0, other), by_category.get(1, other), other)
@@ -125,6 +125,8 @@ minute=None, second=None, nanosecond=None)""",
 ($self, delta=None, /, *, hours=0, minutes=0, seconds=0, \
 milliseconds=0, microseconds=0, nanoseconds=0)""",
     W.Date.add: "($self, delta=None, /, *, years=0, months=0, weeks=0, days=0)",
+    W.ExpandedDate.replace: "($self, /, *, year=None, month=None, day=None)",
+    W.ExpandedDate.add: "($self, delta=None, /, *, years=0, months=0, weeks=0, days=0)",
 }
 MANUALLY_DEFINED_SIGS.update(
     {
@@ -133,6 +135,7 @@ MANUALLY_DEFINED_SIGS.update(
         W.PlainDateTime.subtract: MANUALLY_DEFINED_SIGS[W.PlainDateTime.add],
         W.Instant.subtract: MANUALLY_DEFINED_SIGS[W.Instant.add],
         W.Date.subtract: MANUALLY_DEFINED_SIGS[W.Date.add],
+        W.ExpandedDate.subtract: MANUALLY_DEFINED_SIGS[W.ExpandedDate.add],
     }
 )
 SKIP = {
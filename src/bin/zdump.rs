@@ -0,0 +1,77 @@
+//! A minimal `zdump -v` clone, printing timezone transitions as interpreted
+//! by whenever. Its output can be diffed against the system's `zdump -v`.
+//!
+//! Usage: whenever-zdump [-v] [-c [loyear,]hiyear] zonename...
+use _whenever::tz::{tzif::TimeZone, zdump::zdump};
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::PathBuf,
+    process::ExitCode,
+};
+
+// The same defaults as zdump
+const DEFAULT_LO: i32 = -500;
+const DEFAULT_HI: i32 = 2500;
+
+fn parse_cutoff(s: &str) -> Option<(i32, i32)> {
+    match s.split_once(',') {
+        Some((lo, hi)) => Some((lo.parse().ok()?, hi.parse().ok()?)),
+        None => Some((DEFAULT_LO, s.parse().ok()?)),
+    }
+}
+
+fn zone_path(name: &str) -> PathBuf {
+    if name.starts_with('/') {
+        PathBuf::from(name)
+    } else {
+        env::var_os("TZDIR")
+            .map_or_else(|| PathBuf::from("/usr/share/zoneinfo"), PathBuf::from)
+            .join(name)
+    }
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+    let mut cutoff = (DEFAULT_LO, DEFAULT_HI);
+    let mut zones = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // Verbose output is the only mode supported
+            "-v" => {}
+            "-c" => match args.next().as_deref().and_then(parse_cutoff) {
+                Some(c) => cutoff = c,
+                None => {
+                    eprintln!("whenever-zdump: invalid -c argument");
+                    return ExitCode::FAILURE;
+                }
+            },
+            _ => zones.push(arg),
+        }
+    }
+    if zones.is_empty() {
+        eprintln!("usage: whenever-zdump [-v] [-c [loyear,]hiyear] zonename...");
+        return ExitCode::FAILURE;
+    }
+    for name in zones {
+        let tz = fs::read(zone_path(&name))
+            .ok()
+            .and_then(|data| TimeZone::parse_tzif(&data, Some(&name)).ok());
+        match tz {
+            Some(tz) => {
+                // Stop quietly if the output is closed early (e.g. piped into `head`)
+                if io::stdout()
+                    .write_all(zdump(&tz, &name, cutoff.0, cutoff.1).as_bytes())
+                    .is_err()
+                {
+                    return ExitCode::FAILURE;
+                }
+            }
+            None => {
+                eprintln!("whenever-zdump: unknown timezone '{name}'");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
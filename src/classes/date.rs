@@ -10,7 +10,11 @@ pub(crate) use crate::domain::date::DateBoundaryUnit;
 use crate::{
     classes::{date_delta::DateDelta, itemized_date_delta::ItemizedDateDelta},
     common::{
-        format_args, pattern, pickle, round_args as round,
+        day_count_args::{excel_system, parse_day_count},
+        format_args,
+        locale::Locale,
+        parse::Scan,
+        pattern, pickle, round_args as round,
         shift_args::{parse_calendar_shift_arg, parse_calendar_shift_kwargs},
     },
     docstrings as doc,
@@ -224,15 +228,15 @@ fn format_iso(cls: PyClass<Date>, slf: Date, args: &[PyObj], kwargs: &mut IterKw
 }
 
 fn parse_iso(cls: PyClass<Date>, s: PyObj) -> PyReturn {
-    Date::parse_iso(
-        s.cast_allow_subclass::<PyStr>()
-            // NOTE: this exception message also needs to make sense when
-            // called through the constructor
-            .ok_or_type_err("when parsing from ISO format, the argument must be str")?
-            .as_utf8()?,
-    )
-    .ok_or_else_value_err(|| format!("Invalid format: {s}"))?
-    .to_obj(cls)
+    let py_str = s
+        .cast_allow_subclass::<PyStr>()
+        // NOTE: this exception message also needs to make sense when
+        // called through the constructor
+        .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    scan.parse_all(Date::read_iso)
+        .ok_or_else(|| scan.invalid_format(*cls.state().exc_parse, s))?
+        .to_obj(cls)
 }
 
 fn day_of_week(cls: PyClass<Date>, slf: Date) -> PyReturn {
@@ -267,6 +271,51 @@ fn in_leap_year(_: PyClass<Date>, slf: Date) -> PyReturn {
     slf.is_in_leap_year().to_py()
 }
 
+fn to_julian_day(_: PyClass<Date>, slf: Date) -> PyReturn {
+    slf.unix_days().day_count(DayCount::JulianDay).to_py()
+}
+
+fn from_julian_day(cls: PyClass<Date>, n: PyObj) -> PyReturn {
+    parse_day_count(DayCount::JulianDay, n)?.date().to_obj(cls)
+}
+
+fn to_mjd(_: PyClass<Date>, slf: Date) -> PyReturn {
+    slf.unix_days()
+        .day_count(DayCount::ModifiedJulianDay)
+        .to_py()
+}
+
+fn from_mjd(cls: PyClass<Date>, n: PyObj) -> PyReturn {
+    parse_day_count(DayCount::ModifiedJulianDay, n)?
+        .date()
+        .to_obj(cls)
+}
+
+fn to_rata_die(_: PyClass<Date>, slf: Date) -> PyReturn {
+    slf.unix_days().day_count(DayCount::RataDie).to_py()
+}
+
+fn from_rata_die(cls: PyClass<Date>, n: PyObj) -> PyReturn {
+    parse_day_count(DayCount::RataDie, n)?.date().to_obj(cls)
+}
+
+fn to_excel_serial(
+    cls: PyClass<Date>,
+    slf: Date,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    handle_no_args("to_excel_serial", args)?;
+    let count = excel_system("to_excel_serial", cls.state(), kwargs)?;
+    slf.unix_days().day_count(count).to_py()
+}
+
+fn from_excel_serial(cls: PyClass<Date>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let n = handle_one_arg("from_excel_serial", args)?;
+    let count = excel_system("from_excel_serial", cls.state(), kwargs)?;
+    parse_day_count(count, n)?.date().to_obj(cls)
+}
+
 fn next_day(cls: PyClass<Date>, slf: Date) -> PyReturn {
     slf.shift(DeltaMonths::ZERO, DeltaDays::new_unchecked(1))
         .ok_or_range_err()?
@@ -675,20 +724,39 @@ fn today_in_system_tz(cls: PyClass<Date>) -> PyReturn {
         .to_obj(cls)
 }
 
-fn format(cls: PyClass<Date>, slf: Date, pattern_obj: PyObj) -> PyReturn {
-    let pattern_pystr = pattern_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format() argument must be str")?;
-    let pattern_str = pattern_pystr.as_utf8()?;
-    let pattern = pattern::CompiledPattern::compile(pattern_str).into_value_err()?;
+fn format(cls: PyClass<Date>, slf: Date, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let pattern_obj = handle_one_arg("format", args)?;
+    let (locale, style) = pattern::format_kwargs(cls.state(), kwargs)?;
+    format_pattern(cls, slf, pattern_obj, locale, style)
+}
+
+fn format_pattern(
+    cls: PyClass<Date>,
+    slf: Date,
+    pattern_obj: PyObj,
+    locale: Option<&'static Locale>,
+    style: pattern::Style,
+) -> PyReturn {
+    let state = cls.state();
+    let pattern =
+        pattern::compile_cached(state, pattern_obj, style, "format() argument must be str")?;
     pattern.validate(pattern::CategorySet::DATE, "Date")?;
-    pattern.warn_if_ambiguous_12h(*cls.state().warn_whenever)?;
-    pattern.format(&slf.pattern_values())
+    pattern.warn_if_ambiguous_12h(*state.warn_whenever)?;
+    let locale = pattern.locale(locale)?;
+    format_compiled(slf, &pattern, locale)
+}
+
+pub(crate) fn format_compiled(
+    slf: Date,
+    pattern: &pattern::CompiledPattern,
+    locale: &'static Locale,
+) -> PyReturn {
+    pattern.format(&slf.pattern_values().with_locale(locale))
 }
 
 fn __format__(cls: PyClass<Date>, slf: Date, spec_obj: PyObj) -> PyReturn {
     if spec_obj.is_truthy()? {
-        format(cls, slf, spec_obj)
+        format_pattern(cls, slf, spec_obj, None, pattern::Style::Pattern)
     } else {
         __str__(cls.into(), slf)
     }
@@ -701,21 +769,47 @@ fn parse(cls: PyClass<Date>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyRetur
         .ok_or_type_err("parse() argument must be str")?;
     let s = s_pystr.as_utf8()?;
 
-    let fmt_obj = handle_one_kwarg("parse", *cls.state().str_format, kwargs)?.ok_or_else(|| {
+    let state = cls.state();
+    let mut fmt_obj = None;
+    let mut locale_obj = None;
+    let mut style = pattern::Style::Pattern;
+    let mut lenient = false;
+    let mut pivot_year = None;
+    handle_kwargs("parse", kwargs, |key, value, eq| {
+        if eq(key, *state.str_format) {
+            fmt_obj = Some(value);
+        } else if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else if eq(key, *state.str_pivot_year) {
+            pivot_year = pattern::pivot_year_from_py(value)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let fmt_obj = fmt_obj.ok_or_else(|| {
         raise_type_err::<(), _>("parse() requires 'format' keyword argument").unwrap_err()
     })?;
-    let fmt_pystr = fmt_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format must be str")?;
-    let fmt_bytes = fmt_pystr.as_utf8()?;
+    let locale = Locale::from_py_opt(locale_obj)?;
+    let formats =
+        pattern::Formats::compile(state, fmt_obj, style, pattern::CategorySet::DATE, "Date")?;
+    formats.check_locale(locale)?;
+    formats.check_pivot_year(pivot_year)?;
+    from_parsed(
+        cls,
+        formats
+            .parse(s, locale, lenient, pivot_year)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?,
+    )
+}
 
-    let pattern = pattern::CompiledPattern::compile(fmt_bytes).into_value_err()?;
-    pattern.validate(pattern::CategorySet::DATE, "Date")?;
-    let parsed = pattern.parse(s).into_value_err()?;
-    let date = parsed
-        .date("Pattern must include year (YYYY/YY), month (MM/MMM/MMMM), and day (DD) fields")?;
-    parsed.validate_weekday(date)?;
-    date.to_obj(cls)
+/// Create a `Date` from the result of parsing a pattern
+pub(crate) fn from_parsed(cls: PyClass<Date>, parsed: pattern::ParseState) -> PyReturn {
+    parsed.date()?.to_obj(cls)
 }
 
 static mut METHODS: &mut [PyMethodDef] = &mut [
@@ -736,6 +830,14 @@ static mut METHODS: &mut [PyMethodDef] = &mut [
     method0!(Date, days_in_month, doc::DATE_DAYS_IN_MONTH),
     method0!(Date, days_in_year, doc::DATE_DAYS_IN_YEAR),
     method0!(Date, in_leap_year, doc::DATE_IN_LEAP_YEAR),
+    method0!(Date, to_julian_day, doc::DATE_TO_JULIAN_DAY),
+    classmethod1!(Date, from_julian_day, doc::DATE_FROM_JULIAN_DAY),
+    method0!(Date, to_mjd, doc::DATE_TO_MJD),
+    classmethod1!(Date, from_mjd, doc::DATE_FROM_MJD),
+    method0!(Date, to_rata_die, doc::DATE_TO_RATA_DIE),
+    classmethod1!(Date, from_rata_die, doc::DATE_FROM_RATA_DIE),
+    method_kwargs!(Date, to_excel_serial, doc::DATE_TO_EXCEL_SERIAL),
+    classmethod_kwargs!(Date, from_excel_serial, doc::DATE_FROM_EXCEL_SERIAL),
     method0!(Date, next_day, doc::DATE_NEXT_DAY),
     method0!(Date, prev_day, doc::DATE_PREV_DAY),
     method_vararg!(Date, nth_weekday_of_month, doc::DATE_NTH_WEEKDAY_OF_MONTH),
@@ -750,7 +852,7 @@ static mut METHODS: &mut [PyMethodDef] = &mut [
     method_kwargs!(Date, since, doc::DATE_SINCE),
     method_kwargs!(Date, until, doc::DATE_UNTIL),
     method_kwargs!(Date, replace, doc::DATE_REPLACE),
-    method1!(Date, format, doc::DATE_FORMAT),
+    method_kwargs!(Date, format, doc::DATE_FORMAT),
     method1!(Date, __format__, c""),
     classmethod_kwargs!(Date, parse, doc::DATE_PARSE),
     classmethod_kwargs!(Date, __get_pydantic_core_schema__, doc::PYDANTIC_SCHEMA),
@@ -6,7 +6,7 @@ pub(crate) use crate::domain::date_delta::{DateDelta, InitError};
 
 use crate::{
     classes::{datetime_delta::DateTimeDelta, time_delta::TimeDelta},
-    common::{pickle, shift_args::parse_calendar_shift_kwargs},
+    common::{format_args, parse::Scan, pickle, shift_args::parse_calendar_shift_kwargs},
     docstrings as doc,
     domain::{scalar::*, shift::CalendarShift},
     py::*,
@@ -287,8 +287,21 @@ static mut SLOTS: &[PyType_Slot] = &[
     },
 ];
 
-fn format_iso(_: PyType, slf: DateDelta) -> PyReturn {
-    slf.fmt_iso().to_py()
+fn format_iso(
+    cls: PyClass<DateDelta>,
+    slf: DateDelta,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    match format_args::delta_iso_alternative(cls.state(), args, kwargs)? {
+        Some(basic) => slf
+            .fmt_iso_alternative(basic)
+            .ok_or_value_err(
+                "DateDelta with more than 30 days can't be expressed in the alternative format",
+            )?
+            .to_py(),
+        None => slf.fmt_iso().to_py(),
+    }
 }
 
 fn parse_iso(cls: PyClass<DateDelta>, arg: PyObj) -> PyReturn {
@@ -306,10 +319,9 @@ fn parse_iso_inner(cls: PyClass<DateDelta>, arg: PyObj) -> PyReturn {
         // NOTE: this exception message also needs to make sense when
         // called through the constructor
         .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
-    let s = py_str.as_utf8()?;
-    let err = || format!("Invalid format: {arg}");
-    DateDelta::parse_iso(s)
-        .ok_or_else_value_err(err)?
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    scan.parse_all(DateDelta::read_iso)
+        .ok_or_else(|| scan.invalid_format(*cls.state().exc_parse, arg))?
         .to_obj(cls)
 }
 
@@ -360,7 +372,7 @@ pub(crate) fn unpickle(state: &State, args: &[PyObj]) -> PyReturn {
 static mut METHODS: &[PyMethodDef] = &[
     COPY_METHOD,
     DEEPCOPY_METHOD,
-    method0!(DateDelta, format_iso, doc::DATEDELTA_FORMAT_ISO),
+    method_kwargs!(DateDelta, format_iso, doc::DATEDELTA_FORMAT_ISO),
     classmethod1!(DateDelta, parse_iso, doc::DATEDELTA_PARSE_ISO),
     method0!(DateDelta, in_months_days, doc::DATEDELTA_IN_MONTHS_DAYS),
     method0!(
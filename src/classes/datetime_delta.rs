@@ -11,7 +11,7 @@ use crate::{
             MAX_HOURS, MAX_MICROSECONDS, MAX_MILLISECONDS, MAX_MINUTES, MAX_SECS, TimeDelta,
         },
     },
-    common::pickle,
+    common::{format_args, parse::Scan, pickle},
     docstrings as doc,
     domain::scalar::*,
     py::*,
@@ -334,8 +334,22 @@ static mut SLOTS: &[PyType_Slot] = &[
     },
 ];
 
-fn format_iso(_: PyType, d: DateTimeDelta) -> PyReturn {
-    d.fmt_iso().to_py()
+fn format_iso(
+    cls: PyClass<DateTimeDelta>,
+    d: DateTimeDelta,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    match format_args::delta_iso_alternative(cls.state(), args, kwargs)? {
+        Some(basic) => d
+            .fmt_iso_alternative(basic)
+            .ok_or_value_err(
+                "DateTimeDelta with more than 30 days or 24 hours \
+                can't be expressed in the alternative format",
+            )?
+            .to_py(),
+        None => d.fmt_iso().to_py(),
+    }
 }
 
 fn parse_iso(cls: PyClass<DateTimeDelta>, arg: PyObj) -> PyReturn {
@@ -354,10 +368,15 @@ fn parse_iso_inner(cls: PyClass<DateTimeDelta>, arg: PyObj) -> PyReturn {
         // called through the constructor
         .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
 
-    let s = binding.as_utf8()?;
-    let err = || format!("Invalid format or out of range: {arg}");
-    DateTimeDelta::parse_iso(s)
-        .ok_or_else_value_err(err)?
+    let mut scan = Scan::new(binding.as_utf8()?);
+    scan.parse_all(DateTimeDelta::read_iso)
+        .ok_or_else(|| {
+            scan.parse_err(
+                *cls.state().exc_parse,
+                format!("Invalid format or out of range: {arg}"),
+                arg,
+            )
+        })?
         .to_obj(cls)
 }
 
@@ -459,7 +478,7 @@ pub(crate) fn unpickle(state: &State, args: &[PyObj]) -> PyReturn {
 static mut METHODS: &[PyMethodDef] = &[
     COPY_METHOD,
     DEEPCOPY_METHOD,
-    method0!(DateTimeDelta, format_iso, doc::DATETIMEDELTA_FORMAT_ISO),
+    method_kwargs!(DateTimeDelta, format_iso, doc::DATETIMEDELTA_FORMAT_ISO),
     method0!(DateTimeDelta, date_part, doc::DATETIMEDELTA_DATE_PART),
     method0!(DateTimeDelta, time_part, doc::DATETIMEDELTA_TIME_PART),
     classmethod1!(DateTimeDelta, parse_iso, doc::DATETIMEDELTA_PARSE_ISO),
@@ -0,0 +1,481 @@
+use core::{
+    ffi::{CStr, c_int, c_void},
+    ptr::null_mut as NULL,
+};
+use pyo3_ffi::*;
+
+pub use crate::domain::date::ExpandedDate;
+
+use crate::{
+    classes::itemized_date_delta::ItemizedDateDelta,
+    common::{parse::Scan, pickle, round_args as round},
+    docstrings as doc,
+    domain::{
+        difference::{self, CalendarIncrement, CalendarUnit, CalendarUnitSet, DateDifferenceUnits},
+        scalar::*,
+    },
+    py::*,
+    pymodule::State,
+};
+
+pub(crate) const SINGLETONS: &[(&CStr, ExpandedDate); 2] =
+    &[(c"MIN", ExpandedDate::MIN), (c"MAX", ExpandedDate::MAX)];
+
+impl PyPayload for ExpandedDate {}
+
+/// Check the components, with a message pointing out which one is invalid
+fn check_ymd(year: i64, month: i64, day: i64) -> PyResult<ExpandedDate> {
+    let year = ExpandedYear::from_i64(year).ok_or_else_value_err(|| {
+        format!(
+            "year must be between {} and {}",
+            ExpandedYear::MIN.get(),
+            ExpandedYear::MAX.get()
+        )
+    })?;
+    let month = Month::from_i64(month).ok_or_value_err("month must be between 1 and 12")?;
+    u8::try_from(day)
+        .ok()
+        .and_then(|day| ExpandedDate::new(year, month, day))
+        .ok_or_value_err("day is out of range for month")
+}
+
+fn __new__(cls: PyClass<ExpandedDate>, args: PyTuple, kwargs: Option<PyDict>) -> PyReturn {
+    if kwargs.map_or(0, |d| d.len()) != 0 {
+        raise_type_err("ExpandedDate() takes no keyword arguments")?
+    }
+    let mut it = args.iter();
+    match args.len() {
+        1 => parse_iso(cls, it.next().unwrap()),
+        3 => check_ymd(
+            it.next().unwrap().expect_int("year")?.to_i64()?,
+            it.next().unwrap().expect_int("month")?.to_i64()?,
+            it.next().unwrap().expect_int("day")?.to_i64()?,
+        )?
+        .to_obj(cls),
+        _ => raise_type_err("ExpandedDate() requires an ISO 8601 string or 3 arguments"),
+    }
+}
+
+fn __richcmp__(cls: PyClass<ExpandedDate>, a: ExpandedDate, b_obj: PyObj, op: c_int) -> PyReturn {
+    match b_obj.extract(cls) {
+        Some(b) => CompareOp::from_ffi(op).apply(a, b).to_py(),
+        None => not_implemented(),
+    }
+}
+
+fn __str__(_: PyType, slf: ExpandedDate) -> PyReturn {
+    PyAsciiStrBuilder::format(slf.iso_format(false))
+}
+
+fn __repr__(_: PyType, slf: ExpandedDate) -> PyReturn {
+    PyAsciiStrBuilder::format((b"ExpandedDate(\"", slf.iso_format(false), b"\")"))
+}
+
+extern "C" fn __hash__(slf: PyObj) -> Py_hash_t {
+    // SAFETY: we know self is passed to this method
+    let (_, date) = unsafe { slf.assume_heaptype::<ExpandedDate>() };
+    // The day count is unique, and fits even on 32-bit platforms
+    hashmask(date.unix_days() as Py_hash_t)
+}
+
+#[allow(static_mut_refs)]
+static mut SLOTS: &[PyType_Slot] = &[
+    slotmethod!(ExpandedDate, Py_tp_new, __new__),
+    slotmethod!(ExpandedDate, Py_tp_str, __str__, 1),
+    slotmethod!(ExpandedDate, Py_tp_repr, __repr__, 1),
+    slotmethod!(ExpandedDate, Py_tp_richcompare, __richcmp__),
+    PyType_Slot {
+        slot: Py_tp_doc,
+        pfunc: doc::EXPANDEDDATE.as_ptr() as *mut c_void,
+    },
+    PyType_Slot {
+        slot: Py_tp_methods,
+        pfunc: unsafe { METHODS.as_mut_ptr().cast() },
+    },
+    PyType_Slot {
+        slot: Py_tp_getset,
+        pfunc: unsafe { GETSETTERS.as_mut_ptr().cast() },
+    },
+    PyType_Slot {
+        slot: Py_tp_hash,
+        pfunc: __hash__ as *mut c_void,
+    },
+    PyType_Slot {
+        slot: Py_tp_dealloc,
+        pfunc: generic_dealloc as *mut c_void,
+    },
+    PyType_Slot {
+        slot: 0,
+        pfunc: NULL(),
+    },
+];
+
+fn from_date(cls: PyClass<ExpandedDate>, arg: PyObj) -> PyReturn {
+    ExpandedDate::from(
+        arg.extract(*cls.state().date_type)
+            .ok_or_type_err("argument must be a whenever.Date")?,
+    )
+    .to_obj(cls)
+}
+
+fn date(cls: PyClass<ExpandedDate>, slf: ExpandedDate) -> PyReturn {
+    slf.to_date()
+        .ok_or_else_value_err(|| {
+            format!("Year {} is out of range for Date (1-9999)", slf.year.get())
+        })?
+        .to_obj(*cls.state().date_type)
+}
+
+fn format_iso(
+    cls: PyClass<ExpandedDate>,
+    slf: ExpandedDate,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    handle_no_args("format_iso", args)?;
+    let state = cls.state();
+    let mut basic = false;
+    handle_kwargs("format_iso", kwargs, |key, value, eq| {
+        if eq(key, *state.str_basic) {
+            basic = value.expect_bool("basic")?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    PyAsciiStrBuilder::format(slf.iso_format(basic))
+}
+
+fn parse_iso(cls: PyClass<ExpandedDate>, s: PyObj) -> PyReturn {
+    let py_str = s
+        .cast_allow_subclass::<PyStr>()
+        // NOTE: this exception message also needs to make sense when
+        // called through the constructor
+        .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    scan.parse_all(ExpandedDate::read_iso)
+        .ok_or_else(|| scan.invalid_format(*cls.state().exc_parse, s))?
+        .to_obj(cls)
+}
+
+fn day_of_week(cls: PyClass<ExpandedDate>, slf: ExpandedDate) -> PyReturn {
+    let members = cls.state().weekday_enum_members.get()?;
+    Ok(members[(slf.day_of_week() as u8 - 1) as usize].newref())
+}
+
+fn day_of_year(_: PyClass<ExpandedDate>, slf: ExpandedDate) -> PyReturn {
+    slf.day_of_year().to_py()
+}
+
+fn days_in_month(_: PyClass<ExpandedDate>, slf: ExpandedDate) -> PyReturn {
+    slf.days_in_month().to_py()
+}
+
+fn days_in_year(_: PyClass<ExpandedDate>, slf: ExpandedDate) -> PyReturn {
+    slf.days_in_year().to_py()
+}
+
+fn in_leap_year(_: PyClass<ExpandedDate>, slf: ExpandedDate) -> PyReturn {
+    slf.is_in_leap_year().to_py()
+}
+
+fn __reduce__(cls: PyClass<ExpandedDate>, slf: ExpandedDate) -> PyReturn {
+    let data = pickle::encode_expanded_date(slf);
+    [
+        cls.state().unpickle_expanded_date.newref(),
+        [data.to_py()?].into_pytuple()?,
+    ]
+    .into_pytuple()
+}
+
+fn add(
+    cls: PyClass<ExpandedDate>,
+    slf: PyRef<'_, ExpandedDate>,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    shift_method(cls, slf, args, kwargs, false)
+}
+
+fn subtract(
+    cls: PyClass<ExpandedDate>,
+    slf: PyRef<'_, ExpandedDate>,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    shift_method(cls, slf, args, kwargs, true)
+}
+
+#[inline(never)]
+fn shift_method(
+    cls: PyClass<ExpandedDate>,
+    slf: PyRef<'_, ExpandedDate>,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+    negate: bool,
+) -> PyReturn {
+    let fname = if negate { "subtract" } else { "add" };
+    let state = cls.state();
+    // Unlike Date, the amounts aren't limited to the range of DateDelta
+    let (years, months, weeks, days) = match (args, kwargs.original_len()) {
+        (&[arg], 0) => {
+            let d = ItemizedDateDelta::extract(arg, state)?
+                .ok_or_type_err(format!("{fname}() argument must be an ItemizedDateDelta"))?;
+            (
+                d.years.get_or(0).into(),
+                d.months.get_or(0).into(),
+                d.weeks.get_or(0).into(),
+                d.days.get_or(0).into(),
+            )
+        }
+        ([], 0) => return Ok(slf.newref()),
+        ([], _) => {
+            let mut amounts = (0, 0, 0, 0);
+            handle_kwargs(fname, kwargs, |key, value, eq| {
+                let (field, name) = if eq(key, *state.str_years) {
+                    (&mut amounts.0, "years")
+                } else if eq(key, *state.str_months) {
+                    (&mut amounts.1, "months")
+                } else if eq(key, *state.str_weeks) {
+                    (&mut amounts.2, "weeks")
+                } else if eq(key, *state.str_days) {
+                    (&mut amounts.3, "days")
+                } else {
+                    return Ok(false);
+                };
+                *field = value.expect_int(name)?.to_i64()?;
+                Ok(true)
+            })?;
+            amounts
+        }
+        _ => raise_type_err(format!(
+            "{fname}() takes either only kwargs or 1 positional arg"
+        ))?,
+    };
+    let total_months = years
+        .checked_mul(12)
+        .and_then(|m: i64| m.checked_add(months))
+        .and_then(|m| m.checked_mul(if negate { -1 } else { 1 }));
+    let total_days = weeks
+        .checked_mul(7)
+        .and_then(|d: i64| d.checked_add(days))
+        .and_then(|d| d.checked_mul(if negate { -1 } else { 1 }));
+    total_months
+        .zip(total_days)
+        .and_then(|(m, d)| slf.shift(m, d))
+        .ok_or_range_err()?
+        .to_obj(cls)
+}
+
+fn since(
+    cls: PyClass<ExpandedDate>,
+    slf: ExpandedDate,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    since_inner(cls, slf, args, kwargs, "since", false)
+}
+
+fn until(
+    cls: PyClass<ExpandedDate>,
+    slf: ExpandedDate,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    since_inner(cls, slf, args, kwargs, "until", true)
+}
+
+#[inline(never)]
+fn since_inner(
+    cls: PyClass<ExpandedDate>,
+    slf: ExpandedDate,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+    fname: &str,
+    negate: bool,
+) -> PyReturn {
+    let state = cls.state();
+
+    let other = handle_one_arg(fname, args)?
+        .extract(cls)
+        .ok_or_type_err("argument must be an ExpandedDate")?;
+
+    let mut units: Option<DateDifferenceUnits> = None;
+    let mut round_mode = None;
+    let mut round_increment = CalendarIncrement::MIN;
+    let mut round_was_set = false;
+    handle_kwargs(fname, kwargs, |key, value, eq| {
+        if eq(key, *state.str_total) {
+            if units.is_some() {
+                return raise_type_err("cannot specify both 'total' and 'in_units'");
+            }
+            units = Some(DateDifferenceUnits::Total(CalendarUnit::from_py(
+                value, state,
+            )?));
+        } else if eq(key, *state.str_in_units) {
+            if units.is_some() {
+                return raise_type_err("cannot specify both 'total' and 'in_units'");
+            }
+            units = Some(DateDifferenceUnits::InUnits(CalendarUnitSet::from_py(
+                value, state,
+            )?));
+        } else if eq(key, *state.str_round_mode) {
+            round_mode =
+                round::Mode::from_py_named("round_mode", value, &state.round_mode_strs)?.into();
+            round_was_set = true;
+        } else if eq(key, *state.str_round_increment) {
+            round_increment = CalendarIncrement::from_py(value)?;
+            round_was_set = true;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+
+    let (a, b) = if negate { (other, slf) } else { (slf, other) };
+    match units {
+        Some(DateDifferenceUnits::Total(unit)) => {
+            if round_was_set {
+                raise_type_err("'round_mode' and 'round_increment' cannot be used with 'total'")
+            } else {
+                since_float(a, b, unit)
+            }
+        }
+        Some(DateDifferenceUnits::InUnits(units)) => since_iddelta(
+            a,
+            b,
+            units,
+            round_mode.unwrap_or(round::Mode::Trunc),
+            round_increment,
+        )?
+        .to_obj(state),
+        None => raise_type_err("must specify either 'total' or 'in_units'"),
+    }
+}
+
+fn since_iddelta(
+    a: ExpandedDate,
+    b: ExpandedDate,
+    units: CalendarUnitSet,
+    round_mode: round::Mode,
+    round_increment: CalendarIncrement,
+) -> PyResult<ItemizedDateDelta> {
+    let neg = a < b;
+    let (mut result, trunc, expand) =
+        difference::expanded_date_diff(a, b, round_increment, units, neg).ok_or_range_err()?;
+    result.round_by_day_counts(
+        units.smallest(),
+        a.unix_days(),
+        trunc,
+        expand,
+        round_mode.to_abs_trunc(neg),
+        round_increment,
+        neg,
+    );
+    if !result.is_in_range() {
+        raise_value_err("Difference is out of range for ItemizedDateDelta")?
+    }
+    Ok(result)
+}
+
+fn since_float(a: ExpandedDate, b: ExpandedDate, unit: CalendarUnit) -> PyReturn {
+    let neg = a < b;
+    let mut units = CalendarUnitSet::EMPTY;
+    units.insert(unit);
+    let (mut result, trunc, expand) =
+        difference::expanded_date_diff(a, b, CalendarIncrement::MIN, units, neg)
+            .ok_or_range_err()?;
+    let value = unit.field(&mut result).get_or(0);
+    // The ratio is always positive, since `a` and `expand` are
+    // on the same side of `trunc`.
+    let num = (a.unix_days() - trunc) as f64;
+    let denom = (expand - trunc) as f64;
+    ((value.abs() as f64 + num / denom).negate_if(neg)).to_py()
+}
+
+fn replace(
+    cls: PyClass<ExpandedDate>,
+    slf: ExpandedDate,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    handle_no_args("replace", args)?;
+
+    let state = cls.state();
+    let mut year = slf.year.get().into();
+    let mut month = slf.month.get().into();
+    let mut day = slf.day.into();
+    handle_kwargs("replace", kwargs, |k, v, eq| {
+        if eq(k, *state.str_year) {
+            year = v.expect_int("year")?.to_i64()?;
+        } else if eq(k, *state.str_month) {
+            month = v.expect_int("month")?.to_i64()?;
+        } else if eq(k, *state.str_day) {
+            day = v.expect_int("day")?.to_i64()?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    check_ymd(year, month, day)?.to_obj(cls)
+}
+
+static mut METHODS: &mut [PyMethodDef] = &mut [
+    method_kwargs!(ExpandedDate, format_iso, doc::EXPANDEDDATE_FORMAT_ISO),
+    classmethod1!(ExpandedDate, parse_iso, doc::EXPANDEDDATE_PARSE_ISO),
+    classmethod1!(ExpandedDate, from_date, doc::EXPANDEDDATE_FROM_DATE),
+    method0!(ExpandedDate, date, doc::EXPANDEDDATE_DATE),
+    COPY_METHOD,
+    DEEPCOPY_METHOD,
+    method0!(ExpandedDate, day_of_week, doc::EXPANDEDDATE_DAY_OF_WEEK),
+    method0!(ExpandedDate, day_of_year, doc::EXPANDEDDATE_DAY_OF_YEAR),
+    method0!(ExpandedDate, days_in_month, doc::EXPANDEDDATE_DAYS_IN_MONTH),
+    method0!(ExpandedDate, days_in_year, doc::EXPANDEDDATE_DAYS_IN_YEAR),
+    method0!(ExpandedDate, in_leap_year, doc::EXPANDEDDATE_IN_LEAP_YEAR),
+    method0!(ExpandedDate, __reduce__, c""),
+    method_kwargs!(ExpandedDate, add, doc::EXPANDEDDATE_ADD),
+    method_kwargs!(ExpandedDate, subtract, doc::EXPANDEDDATE_SUBTRACT),
+    method_kwargs!(ExpandedDate, since, doc::EXPANDEDDATE_SINCE),
+    method_kwargs!(ExpandedDate, until, doc::EXPANDEDDATE_UNTIL),
+    method_kwargs!(ExpandedDate, replace, doc::EXPANDEDDATE_REPLACE),
+    classmethod_kwargs!(
+        ExpandedDate,
+        __get_pydantic_core_schema__,
+        doc::PYDANTIC_SCHEMA
+    ),
+    PyMethodDef::zeroed(),
+];
+
+pub(crate) fn unpickle(state: &State, arg: PyObj) -> PyReturn {
+    pickle::decode_expanded_date(arg.expect_bytes()?)
+        .ok_or_value_err(pickle::INVALID_DATA)?
+        .to_obj(*state.expanded_date_type)
+}
+
+fn year(_: PyType, slf: ExpandedDate) -> PyReturn {
+    slf.year.get().to_py()
+}
+
+fn month(_: PyType, slf: ExpandedDate) -> PyReturn {
+    slf.month.get().to_py()
+}
+
+fn day(_: PyType, slf: ExpandedDate) -> PyReturn {
+    slf.day.to_py()
+}
+
+static mut GETSETTERS: &mut [PyGetSetDef] = &mut [
+    getter!(ExpandedDate, year, doc::EXPANDEDDATE_YEAR),
+    getter!(ExpandedDate, month, doc::EXPANDEDDATE_MONTH),
+    getter!(ExpandedDate, day, doc::EXPANDEDDATE_DAY),
+    PyGetSetDef {
+        name: NULL(),
+        get: None,
+        set: None,
+        doc: NULL(),
+        closure: NULL(),
+    },
+];
+
+pub(crate) static mut SPEC: PyType_Spec =
+    type_spec::<ExpandedDate>(c"whenever.ExpandedDate", unsafe { SLOTS });
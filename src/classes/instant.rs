@@ -10,15 +10,22 @@ use crate::{
         offset_datetime::OffsetDateTime,
         plain_datetime::PlainDateTime,
         time::Time,
-        time_delta::{DeltaIncrement, TimeDelta, timedelta_from_kwargs},
+        time_delta::{DeltaIncrement, TimeDelta, humanize_exact, timedelta_from_kwargs},
     },
     common::{
+        cbor,
+        day_count_args::{excel_system, parse_day_count_f64},
         fmt,
         format_args::{self, Suffix},
+        humanize_args::HumanizeArgs,
+        ids,
         instant::{
-            extract_instant, parse_instant_arg, parse_timestamp, parse_timestamp_millis,
-            parse_timestamp_nanos,
+            extract_instant, parse_cocoa, parse_gps_nanos, parse_instant_arg, parse_ntp,
+            parse_tick_count, parse_timestamp, parse_timestamp_millis, parse_timestamp_nanos,
         },
+        locale::Locale,
+        msgpack,
+        parse::Scan,
         pattern, pickle, rfc2822, round_args as round,
     },
     docstrings as doc,
@@ -297,6 +304,232 @@ fn from_timestamp_nanos(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
     parse_timestamp_nanos(ts)?.to_obj(cls)
 }
 
+fn timestamp_micros(_: PyType, slf: Instant) -> PyReturn {
+    slf.tick_count(TickCount::UnixMicros).to_py()
+}
+
+fn from_timestamp_micros(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
+    parse_tick_count(TickCount::UnixMicros, ts)?.to_obj(cls)
+}
+
+fn to_filetime(_: PyType, slf: Instant) -> PyReturn {
+    slf.tick_count(TickCount::FileTime).to_py()
+}
+
+fn from_filetime(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
+    parse_tick_count(TickCount::FileTime, ts)?.to_obj(cls)
+}
+
+fn to_dotnet_ticks(_: PyType, slf: Instant) -> PyReturn {
+    slf.tick_count(TickCount::DotNet).to_py()
+}
+
+fn from_dotnet_ticks(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
+    parse_tick_count(TickCount::DotNet, ts)?.to_obj(cls)
+}
+
+fn to_postgres_micros(_: PyType, slf: Instant) -> PyReturn {
+    slf.tick_count(TickCount::PostgresMicros).to_py()
+}
+
+fn from_postgres_micros(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
+    parse_tick_count(TickCount::PostgresMicros, ts)?.to_obj(cls)
+}
+
+fn to_gps_nanos(_: PyType, slf: Instant) -> PyReturn {
+    slf.gps_nanos().to_py()
+}
+
+fn from_gps_nanos(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
+    parse_gps_nanos(ts)?.to_obj(cls)
+}
+
+fn to_ntp(_: PyType, slf: Instant) -> PyReturn {
+    i128::from(
+        slf.ntp()
+            .ok_or_value_err("Instant is outside NTP era 0 (1900-2036)")?,
+    )
+    .to_py()
+}
+
+fn from_ntp(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
+    parse_ntp(ts)?.to_obj(cls)
+}
+
+fn to_cocoa(_: PyType, slf: Instant) -> PyReturn {
+    slf.cocoa().to_py()
+}
+
+fn from_cocoa(cls: PyClass<Instant>, ts: PyObj) -> PyReturn {
+    parse_cocoa(ts)?.to_obj(cls)
+}
+
+fn to_uuid7(cls: PyClass<Instant>, slf: Instant) -> PyReturn {
+    let uuid = ids::uuid7_min(slf.timestamp_millis())
+        .ok_or_value_err("UUIDv7 can't represent instants before 1970")?;
+    cls.state()
+        .uuid_type
+        .get()?
+        .call1(*format!("{uuid:032x}").to_py()?)
+}
+
+fn from_uuid7(cls: PyClass<Instant>, arg: PyObj) -> PyReturn {
+    let uuid_bytes;
+    let bytes = if arg.type_().as_ptr() == cls.state().uuid_type.get()?.as_ptr() {
+        uuid_bytes = arg.getattr(c"bytes")?;
+        uuid_bytes.expect_bytes()?
+    } else {
+        arg.expect_bytes()?
+    };
+    let millis = ids::uuid7_millis(bytes).ok_or_value_err("Not a version 7 UUID")?;
+    Instant::from_timestamp_millis(millis)
+        .ok_or_range_err()?
+        .to_obj(cls)
+}
+
+fn to_ulid(_: PyType, slf: Instant) -> PyReturn {
+    ids::ulid_min(slf.timestamp_millis())
+        .ok_or_value_err("ULID can't represent instants before 1970")?
+        .to_py()
+}
+
+fn from_ulid(cls: PyClass<Instant>, arg: PyObj) -> PyReturn {
+    let s = arg
+        .cast_exact::<PyStr>()
+        .ok_or_type_err("ULID must be a string")?;
+    let millis = ids::ulid_millis(s.as_utf8()?)
+        .ok_or_else_raise(exc_value_error(), || format!("Invalid ULID: {arg}"))?;
+    Instant::from_timestamp_millis(millis)
+        .ok_or_range_err()?
+        .to_obj(cls)
+}
+
+fn snowflake_epoch(fname: &str, state: &State, kwargs: &mut IterKwargs) -> PyResult<i64> {
+    let mut epoch = None;
+    handle_kwargs(fname, kwargs, |key, value, eq| {
+        if eq(key, *state.str_epoch) {
+            epoch = Some(parse_instant_arg(fname, value, state)?);
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    match epoch {
+        Some(e) => Ok(e.timestamp_millis()),
+        None => raise_type_err(format!(
+            "{fname}() missing required keyword argument: 'epoch'"
+        )),
+    }
+}
+
+fn to_snowflake(
+    cls: PyClass<Instant>,
+    slf: Instant,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    handle_no_args("to_snowflake", args)?;
+    let epoch = snowflake_epoch("to_snowflake", cls.state(), kwargs)?;
+    ids::snowflake_min(slf.timestamp_millis(), epoch)
+        .ok_or_value_err("Instant out of range for a Snowflake ID with this epoch")?
+        .to_py()
+}
+
+fn from_snowflake(cls: PyClass<Instant>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let n = handle_one_arg("from_snowflake", args)?;
+    let epoch = snowflake_epoch("from_snowflake", cls.state(), kwargs)?;
+    let millis = ids::snowflake_millis(n.expect_int("Snowflake ID")?.to_i128()?, epoch)
+        .ok_or_value_err("Invalid Snowflake ID")?;
+    Instant::from_timestamp_millis(millis)
+        .ok_or_range_err()?
+        .to_obj(cls)
+}
+
+fn to_cbor(
+    cls: PyClass<Instant>,
+    slf: Instant,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    handle_no_args("to_cbor", args)?;
+    let state = cls.state();
+    let mut tag = cbor::TAG_EPOCH;
+    handle_kwargs("to_cbor", kwargs, |key, value, eq| {
+        if eq(key, *state.str_tag) {
+            tag = value
+                .cast_exact::<PyInt>()
+                .map(|i| i.to_i64())
+                .transpose()?
+                .and_then(|i| u64::try_from(i).ok())
+                .filter(|t| {
+                    [cbor::TAG_RFC3339, cbor::TAG_EPOCH, cbor::TAG_EXTENDED_TIME].contains(t)
+                })
+                .ok_or_value_err("tag must be 0, 1, or 1001")?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    cbor::encode_instant(slf, tag).to_py()
+}
+
+fn from_cbor(cls: PyClass<Instant>, data: PyObj) -> PyReturn {
+    cbor::decode_instant(data.expect_bytes()?)
+        .ok_or_value_err(cbor::INVALID_DATA)?
+        .to_obj(cls)
+}
+
+fn to_msgpack(_: PyType, slf: Instant) -> PyReturn {
+    msgpack::encode_instant(slf).to_py()
+}
+
+fn from_msgpack(cls: PyClass<Instant>, data: PyObj) -> PyReturn {
+    msgpack::decode_instant(data.expect_bytes()?)
+        .ok_or_value_err(msgpack::INVALID_DATA)?
+        .to_obj(cls)
+}
+
+fn to_julian_day(_: PyType, slf: Instant) -> PyReturn {
+    slf.day_count_f64(DayCount::JulianDay).to_py()
+}
+
+fn from_julian_day(cls: PyClass<Instant>, n: PyObj) -> PyReturn {
+    parse_day_count_f64(DayCount::JulianDay, n)?.to_obj(cls)
+}
+
+fn to_mjd(_: PyType, slf: Instant) -> PyReturn {
+    slf.day_count_f64(DayCount::ModifiedJulianDay).to_py()
+}
+
+fn from_mjd(cls: PyClass<Instant>, n: PyObj) -> PyReturn {
+    parse_day_count_f64(DayCount::ModifiedJulianDay, n)?.to_obj(cls)
+}
+
+fn to_rata_die(_: PyType, slf: Instant) -> PyReturn {
+    slf.day_count_f64(DayCount::RataDie).to_py()
+}
+
+fn from_rata_die(cls: PyClass<Instant>, n: PyObj) -> PyReturn {
+    parse_day_count_f64(DayCount::RataDie, n)?.to_obj(cls)
+}
+
+fn to_excel_serial(
+    cls: PyClass<Instant>,
+    slf: Instant,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    handle_no_args("to_excel_serial", args)?;
+    let count = excel_system("to_excel_serial", cls.state(), kwargs)?;
+    slf.day_count_f64(count).to_py()
+}
+
+fn from_excel_serial(cls: PyClass<Instant>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let n = handle_one_arg("from_excel_serial", args)?;
+    let count = excel_system("from_excel_serial", cls.state(), kwargs)?;
+    parse_day_count_f64(count, n)?.to_obj(cls)
+}
+
 fn to_stdlib(cls: PyClass<Instant>, slf: Instant) -> PyReturn {
     slf.to_stdlib_datetime(cls.state().py_api()?)
 }
@@ -342,17 +575,16 @@ fn format_iso(
 }
 
 fn parse_iso(cls: PyClass<Instant>, s_obj: PyObj) -> PyReturn {
-    OffsetDateTime::parse_iso(
-        s_obj
-            .cast_allow_subclass::<PyStr>()
-            // NOTE: this exception message also needs to make sense when
-            // called through the constructor
-            .ok_or_type_err("when parsing from ISO format, the argument must be str")?
-            .as_utf8()?,
-    )
-    .ok_or_else_value_err(|| format!("Invalid format: {s_obj}"))?
-    .to_instant()
-    .to_obj(cls)
+    let py_str = s_obj
+        .cast_allow_subclass::<PyStr>()
+        // NOTE: this exception message also needs to make sense when
+        // called through the constructor
+        .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    scan.parse_all(OffsetDateTime::read_iso)
+        .ok_or_else(|| scan.invalid_format(*cls.state().exc_parse, s_obj))?
+        .to_instant()
+        .to_obj(cls)
 }
 
 fn add(cls: PyClass<Instant>, slf: Instant, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
@@ -409,6 +641,32 @@ fn difference(cls: PyClass<Instant>, slf: Instant, obj_b: PyObj) -> PyReturn {
     slf.diff(inst_b).to_obj(*state.time_delta_type)
 }
 
+fn humanize(
+    cls: PyClass<Instant>,
+    slf: Instant,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let state = cls.state();
+    let mut relative_to = None;
+    let mut days_assumed_24h_ok = false;
+    let h = HumanizeArgs::parse_with("humanize", args, kwargs, state, |key, value, eq| {
+        if eq(key, *state.str_relative_to) {
+            relative_to = Some(parse_instant_arg("humanize", value, state)?);
+        } else if eq(key, *state.str_days_assumed_24h_ok) {
+            days_assumed_24h_ok = value.is_truthy()?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let relative_to = match relative_to {
+        Some(i) => i,
+        None => state.now()?,
+    };
+    humanize_exact(state, slf.diff(relative_to), &h, days_assumed_24h_ok)
+}
+
 fn to_tz(cls: PyClass<Instant>, slf: Instant, tz_obj: PyObj) -> PyReturn {
     let state = cls.state();
     slf.into_zoned_obj(state.tz_store.obj_get(tz_obj)?, *state.zoned_datetime_type)
@@ -436,12 +694,25 @@ fn format_rfc2822(_: PyType, slf: Instant) -> PyReturn {
     unsafe { std::str::from_utf8_unchecked(&fmt[..]) }.to_py()
 }
 
-fn parse_rfc2822(cls: PyClass<Instant>, s_obj: PyObj) -> PyReturn {
+fn parse_rfc2822(cls: PyClass<Instant>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let s_obj = handle_one_arg("parse_rfc2822", args)?;
     let s = s_obj
         .cast_allow_subclass::<PyStr>()
         .ok_or_type_err("expected a string")?;
-    let (date, time, offset) =
-        rfc2822::parse(s.as_utf8()?).ok_or_else_value_err(|| format!("Invalid format: {s_obj}"))?;
+    let state = cls.state();
+    let mut strict = false;
+    handle_kwargs("parse_rfc2822", kwargs, |key, value, eq| {
+        if eq(key, *state.str_strict) {
+            strict = value.expect_bool("strict")?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let mut scan = Scan::new(s.as_utf8()?);
+    let (date, time, offset) = scan
+        .parse_all(|s| rfc2822::read(s, strict))
+        .ok_or_else(|| scan.invalid_format(*state.exc_parse, s_obj))?;
     date.at(time)
         .assume_offset(offset)
         .ok_or_range_err()?
@@ -449,6 +720,19 @@ fn parse_rfc2822(cls: PyClass<Instant>, s_obj: PyObj) -> PyReturn {
         .to_obj(cls)
 }
 
+fn parse_http_date(cls: PyClass<Instant>, s_obj: PyObj) -> PyReturn {
+    let s = s_obj
+        .cast_allow_subclass::<PyStr>()
+        .ok_or_type_err("expected a string")?;
+    let state = cls.state();
+    let current_year = state.now()?.to_utc_plain().date.year;
+    let mut scan = Scan::new(s.as_utf8()?);
+    let (date, time) = scan
+        .parse_all(|s| rfc2822::read_http_date(s, current_year))
+        .ok_or_else(|| scan.invalid_format(*state.exc_parse, s_obj))?;
+    date.at(time).assume_utc().to_obj(cls)
+}
+
 fn round(cls: PyClass<Instant>, slf: Instant, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
     let round::Args {
         increment, mode, ..
@@ -471,24 +755,49 @@ fn round(cls: PyClass<Instant>, slf: Instant, args: &[PyObj], kwargs: &mut IterK
     .to_obj(cls)
 }
 
-fn format(cls: PyClass<Instant>, slf: Instant, pattern_obj: PyObj) -> PyReturn {
-    let pattern_pystr = pattern_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format() argument must be str")?;
-    let pattern_str = pattern_pystr.as_utf8()?;
-    let pattern = pattern::CompiledPattern::compile(pattern_str).into_value_err()?;
+fn format(
+    cls: PyClass<Instant>,
+    slf: Instant,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let pattern_obj = handle_one_arg("format", args)?;
+    let (locale, style) = pattern::format_kwargs(cls.state(), kwargs)?;
+    format_pattern(cls, slf, pattern_obj, locale, style)
+}
+
+fn format_pattern(
+    cls: PyClass<Instant>,
+    slf: Instant,
+    pattern_obj: PyObj,
+    locale: Option<&'static Locale>,
+    style: pattern::Style,
+) -> PyReturn {
+    let state = cls.state();
+    let pattern =
+        pattern::compile_cached(state, pattern_obj, style, "format() argument must be str")?;
     pattern.validate(pattern::CategorySet::DATE_TIME_OFFSET, "Instant")?;
-    pattern.warn_if_ambiguous_12h(*cls.state().warn_whenever)?;
+    pattern.warn_if_ambiguous_12h(*state.warn_whenever)?;
+    let locale = pattern.locale(locale)?;
+    format_compiled(slf, &pattern, locale)
+}
+
+pub(crate) fn format_compiled(
+    slf: Instant,
+    pattern: &pattern::CompiledPattern,
+    locale: &'static Locale,
+) -> PyReturn {
     pattern.format(
         &slf.to_utc_plain()
             .pattern_values()
-            .with_offset(Offset::ZERO),
+            .with_offset(Offset::ZERO)
+            .with_locale(locale),
     )
 }
 
 fn __format__(cls: PyClass<Instant>, slf: Instant, spec_obj: PyObj) -> PyReturn {
     if spec_obj.is_truthy()? {
-        format(cls, slf, spec_obj)
+        format_pattern(cls, slf, spec_obj, None, pattern::Style::Pattern)
     } else {
         __str__(cls.into(), slf)
     }
@@ -501,23 +810,56 @@ fn parse(cls: PyClass<Instant>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyRe
         .ok_or_type_err("parse() argument must be str")?;
     let s = s_pystr.as_utf8()?;
 
-    let fmt_obj = handle_one_kwarg("parse", *cls.state().str_format, kwargs)?.ok_or_else(|| {
+    let state = cls.state();
+    let mut fmt_obj = None;
+    let mut locale_obj = None;
+    let mut style = pattern::Style::Pattern;
+    let mut lenient = false;
+    let mut pivot_year = None;
+    handle_kwargs("parse", kwargs, |key, value, eq| {
+        if eq(key, *state.str_format) {
+            fmt_obj = Some(value);
+        } else if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else if eq(key, *state.str_pivot_year) {
+            pivot_year = pattern::pivot_year_from_py(value)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let fmt_obj = fmt_obj.ok_or_else(|| {
         raise_type_err::<(), _>("parse() requires 'format' keyword argument").unwrap_err()
     })?;
-    let fmt_pystr = fmt_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format must be str")?;
-    let fmt_bytes = fmt_pystr.as_utf8()?;
+    let locale = Locale::from_py_opt(locale_obj)?;
+    let formats = pattern::Formats::compile(
+        state,
+        fmt_obj,
+        style,
+        pattern::CategorySet::DATE_TIME_OFFSET,
+        "Instant",
+    )?;
+    formats.check_locale(locale)?;
+    formats.check_pivot_year(pivot_year)?;
+    from_parsed(
+        cls,
+        formats
+            .parse(s, locale, lenient, pivot_year)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?,
+    )
+}
 
-    let pattern = pattern::CompiledPattern::compile(fmt_bytes).into_value_err()?;
-    pattern.validate(pattern::CategorySet::DATE_TIME_OFFSET, "Instant")?;
-    let parsed = pattern.parse(s).into_value_err()?;
+/// Create an `Instant` from the result of parsing a pattern
+pub(crate) fn from_parsed(cls: PyClass<Instant>, parsed: pattern::ParseState) -> PyReturn {
     let offset = parsed
         .offset_secs
         .ok_or_value_err("Instant.parse() pattern must include an offset field (x/X)")?;
-    let date = parsed
-        .date("Pattern must include year (YYYY/YY), month (MM/MMM/MMMM), and day (DD) fields")?;
-    let time = parsed.time()?;
+    let date = parsed.date()?;
+    let time = parsed.time();
     // offset is already validated (scalar::Offset) — no range check needed here.
     date.at(time)
         .assume_utc()
@@ -545,6 +887,38 @@ static mut METHODS: &[PyMethodDef] = &[
         from_timestamp_nanos,
         doc::INSTANT_FROM_TIMESTAMP_NANOS
     ),
+    method0!(Instant, timestamp_micros, doc::INSTANT_TIMESTAMP_MICROS),
+    classmethod1!(
+        Instant,
+        from_timestamp_micros,
+        doc::INSTANT_FROM_TIMESTAMP_MICROS
+    ),
+    method0!(Instant, to_filetime, doc::INSTANT_TO_FILETIME),
+    classmethod1!(Instant, from_filetime, doc::INSTANT_FROM_FILETIME),
+    method0!(Instant, to_dotnet_ticks, doc::INSTANT_TO_DOTNET_TICKS),
+    classmethod1!(Instant, from_dotnet_ticks, doc::INSTANT_FROM_DOTNET_TICKS),
+    method0!(Instant, to_ntp, doc::INSTANT_TO_NTP),
+    classmethod1!(Instant, from_ntp, doc::INSTANT_FROM_NTP),
+    method0!(Instant, to_cocoa, doc::INSTANT_TO_COCOA),
+    classmethod1!(Instant, from_cocoa, doc::INSTANT_FROM_COCOA),
+    method0!(Instant, to_postgres_micros, doc::INSTANT_TO_POSTGRES_MICROS),
+    classmethod1!(
+        Instant,
+        from_postgres_micros,
+        doc::INSTANT_FROM_POSTGRES_MICROS
+    ),
+    method0!(Instant, to_gps_nanos, doc::INSTANT_TO_GPS_NANOS),
+    classmethod1!(Instant, from_gps_nanos, doc::INSTANT_FROM_GPS_NANOS),
+    method0!(Instant, to_uuid7, doc::INSTANT_TO_UUID7),
+    classmethod1!(Instant, from_uuid7, doc::INSTANT_FROM_UUID7),
+    method0!(Instant, to_ulid, doc::INSTANT_TO_ULID),
+    classmethod1!(Instant, from_ulid, doc::INSTANT_FROM_ULID),
+    method_kwargs!(Instant, to_snowflake, doc::INSTANT_TO_SNOWFLAKE),
+    classmethod_kwargs!(Instant, from_snowflake, doc::INSTANT_FROM_SNOWFLAKE),
+    method_kwargs!(Instant, to_cbor, doc::INSTANT_TO_CBOR),
+    classmethod1!(Instant, from_cbor, doc::INSTANT_FROM_CBOR),
+    method0!(Instant, to_msgpack, doc::INSTANT_TO_MSGPACK),
+    classmethod1!(Instant, from_msgpack, doc::INSTANT_FROM_MSGPACK),
     // This method is defined different because it
     // makes use of the arg/kwargs processing macro.
     // Other types only use it for the __new__ method.
@@ -570,6 +944,14 @@ static mut METHODS: &[PyMethodDef] = &[
         ml_flags: METH_CLASS | METH_VARARGS | METH_KEYWORDS,
         ml_doc: doc::INSTANT_FROM_UTC.as_ptr(),
     },
+    method0!(Instant, to_julian_day, doc::INSTANT_TO_JULIAN_DAY),
+    classmethod1!(Instant, from_julian_day, doc::INSTANT_FROM_JULIAN_DAY),
+    method0!(Instant, to_mjd, doc::INSTANT_TO_MJD),
+    classmethod1!(Instant, from_mjd, doc::INSTANT_FROM_MJD),
+    method0!(Instant, to_rata_die, doc::INSTANT_TO_RATA_DIE),
+    classmethod1!(Instant, from_rata_die, doc::INSTANT_FROM_RATA_DIE),
+    method_kwargs!(Instant, to_excel_serial, doc::INSTANT_TO_EXCEL_SERIAL),
+    classmethod_kwargs!(Instant, from_excel_serial, doc::INSTANT_FROM_EXCEL_SERIAL),
     method0!(Instant, to_stdlib, doc::BASICCONVERSIONS_TO_STDLIB),
     method0!(Instant, py_datetime, doc::BASICCONVERSIONS_PY_DATETIME),
     classmethod1!(
@@ -579,7 +961,8 @@ static mut METHODS: &[PyMethodDef] = &[
     ),
     classmethod0!(Instant, now, doc::INSTANT_NOW),
     method0!(Instant, format_rfc2822, doc::INSTANT_FORMAT_RFC2822),
-    classmethod1!(Instant, parse_rfc2822, doc::INSTANT_PARSE_RFC2822),
+    classmethod_kwargs!(Instant, parse_rfc2822, doc::INSTANT_PARSE_RFC2822),
+    classmethod1!(Instant, parse_http_date, doc::INSTANT_PARSE_HTTP_DATE),
     method_kwargs!(Instant, format_iso, doc::INSTANT_FORMAT_ISO),
     classmethod1!(Instant, parse_iso, doc::INSTANT_PARSE_ISO),
     method_kwargs!(Instant, add, doc::INSTANT_ADD),
@@ -589,7 +972,8 @@ static mut METHODS: &[PyMethodDef] = &[
     method_vararg!(Instant, to_fixed_offset, doc::EXACTTIME_TO_FIXED_OFFSET),
     method1!(Instant, difference, doc::EXACTTIME_DIFFERENCE),
     method_kwargs!(Instant, round, doc::INSTANT_ROUND),
-    method1!(Instant, format, doc::INSTANT_FORMAT),
+    method_kwargs!(Instant, humanize, doc::INSTANT_HUMANIZE),
+    method_kwargs!(Instant, format, doc::INSTANT_FORMAT),
     method1!(Instant, __format__, c""),
     classmethod_kwargs!(Instant, parse, doc::INSTANT_PARSE),
     classmethod_kwargs!(Instant, __get_pydantic_core_schema__, doc::PYDANTIC_SCHEMA),
@@ -2,10 +2,12 @@
 pub mod date;
 pub mod date_delta;
 pub mod datetime_delta;
+pub mod expanded_date;
 pub mod instant;
 pub mod itemized_date_delta;
 pub mod itemized_delta;
 pub mod offset_datetime;
+pub mod pattern;
 pub mod plain_datetime;
 pub mod time;
 pub mod time_delta;
@@ -8,12 +8,14 @@ use crate::classes::plain_datetime::DateTimeBoundaryUnit;
 use crate::{
     classes::{date::Date, plain_datetime, time::Time, time_delta::TimeDelta},
     common::{
-        fmt,
+        cbor, fmt,
         format_args::{self, Suffix},
         instant::{
             extract_instant, parse_instant_arg, parse_timestamp, parse_timestamp_millis,
             parse_timestamp_nanos,
         },
+        locale::Locale,
+        parse::Scan,
         pattern, pickle, rfc2822, round_args as round,
         shift_args::{parse_datetime_shift_arg, parse_datetime_shift_kwargs},
     },
@@ -360,6 +362,18 @@ pub(crate) fn unpickle(state: &State, arg: PyObj) -> PyReturn {
         .to_obj(*state.offset_datetime_type)
 }
 
+fn to_cbor(_: PyType, slf: OffsetDateTime) -> PyReturn {
+    cbor::encode_offset(slf)
+        .ok_or_value_err("CBOR (RFC 3339) doesn't support offsets with seconds")?
+        .to_py()
+}
+
+fn from_cbor(cls: PyClass<OffsetDateTime>, data: PyObj) -> PyReturn {
+    cbor::decode_offset(data.expect_bytes()?)
+        .ok_or_value_err(cbor::INVALID_DATA)?
+        .to_obj(cls)
+}
+
 fn to_stdlib(cls: PyClass<OffsetDateTime>, slf: OffsetDateTime) -> PyReturn {
     slf.to_stdlib_datetime(cls.state().py_api()?)
         .map(Owned::into_obj)
@@ -543,15 +557,15 @@ fn format_iso(
 }
 
 fn parse_iso(cls: PyClass<OffsetDateTime>, arg: PyObj) -> PyReturn {
-    OffsetDateTime::parse_iso(
-        arg.cast_allow_subclass::<PyStr>()
-            // NOTE: this exception message also needs to make sense when
-            // called through the constructor
-            .ok_or_type_err("when parsing from ISO format, the argument must be str")?
-            .as_utf8()?,
-    )
-    .ok_or_else_value_err(|| format!("Invalid format: {arg}"))?
-    .to_obj(cls)
+    let py_str = arg
+        .cast_allow_subclass::<PyStr>()
+        // NOTE: this exception message also needs to make sense when
+        // called through the constructor
+        .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    scan.parse_all(OffsetDateTime::read_iso)
+        .ok_or_else(|| scan.invalid_format(*cls.state().exc_parse, arg))?
+        .to_obj(cls)
 }
 
 fn replace(
@@ -840,12 +854,29 @@ fn format_rfc2822(_: PyType, slf: OffsetDateTime) -> PyReturn {
     unsafe { std::str::from_utf8_unchecked(&fmt[..]) }.to_py()
 }
 
-fn parse_rfc2822(cls: PyClass<OffsetDateTime>, arg: PyObj) -> PyReturn {
+fn parse_rfc2822(
+    cls: PyClass<OffsetDateTime>,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let arg = handle_one_arg("parse_rfc2822", args)?;
     let s = arg
         .cast_allow_subclass::<PyStr>()
         .ok_or_type_err("expected a string")?;
-    let (date, time, offset) =
-        rfc2822::parse(s.as_utf8()?).ok_or_else_value_err(|| format!("Invalid format: {arg}"))?;
+    let state = cls.state();
+    let mut strict = false;
+    handle_kwargs("parse_rfc2822", kwargs, |key, value, eq| {
+        if eq(key, *state.str_strict) {
+            strict = value.expect_bool("strict")?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let mut scan = Scan::new(s.as_utf8()?);
+    let (date, time, offset) = scan
+        .parse_all(|s| rfc2822::read(s, strict))
+        .ok_or_else(|| scan.invalid_format(*state.exc_parse, arg))?;
     date.at(time)
         .assume_offset(offset)
         .ok_or_range_err()?
@@ -1005,20 +1036,49 @@ fn offset_since(
     }
 }
 
-fn format(cls: PyClass<OffsetDateTime>, slf: OffsetDateTime, pattern_obj: PyObj) -> PyReturn {
-    let pattern_pystr = pattern_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format() argument must be str")?;
-    let pattern_str = pattern_pystr.as_utf8()?;
-    let pattern = pattern::CompiledPattern::compile(pattern_str).into_value_err()?;
+fn format(
+    cls: PyClass<OffsetDateTime>,
+    slf: OffsetDateTime,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let pattern_obj = handle_one_arg("format", args)?;
+    let (locale, style) = pattern::format_kwargs(cls.state(), kwargs)?;
+    format_pattern(cls, slf, pattern_obj, locale, style)
+}
+
+fn format_pattern(
+    cls: PyClass<OffsetDateTime>,
+    slf: OffsetDateTime,
+    pattern_obj: PyObj,
+    locale: Option<&'static Locale>,
+    style: pattern::Style,
+) -> PyReturn {
+    let state = cls.state();
+    let pattern =
+        pattern::compile_cached(state, pattern_obj, style, "format() argument must be str")?;
     pattern.validate(pattern::CategorySet::DATE_TIME_OFFSET, "OffsetDateTime")?;
-    pattern.warn_if_ambiguous_12h(*cls.state().warn_whenever)?;
-    pattern.format(&slf.to_plain().pattern_values().with_offset(slf.offset))
+    pattern.warn_if_ambiguous_12h(*state.warn_whenever)?;
+    let locale = pattern.locale(locale)?;
+    format_compiled(slf, &pattern, locale)
+}
+
+pub(crate) fn format_compiled(
+    slf: OffsetDateTime,
+    pattern: &pattern::CompiledPattern,
+    locale: &'static Locale,
+) -> PyReturn {
+    pattern.format(
+        &slf.to_plain()
+            .pattern_values()
+            .with_offset(slf.offset)
+            .with_locale(locale),
+    )
 }
 
 fn __format__(cls: PyClass<OffsetDateTime>, slf: OffsetDateTime, spec_obj: PyObj) -> PyReturn {
     if spec_obj.is_truthy()? {
-        format(cls, slf, spec_obj)
+        format_pattern(cls, slf, spec_obj, None, pattern::Style::Pattern)
     } else {
         __str__(cls.into(), slf)
     }
@@ -1031,24 +1091,56 @@ fn parse(cls: PyClass<OffsetDateTime>, args: &[PyObj], kwargs: &mut IterKwargs)
         .ok_or_type_err("parse() argument must be str")?;
     let s = s_pystr.as_utf8()?;
 
-    let fmt_obj = handle_one_kwarg("parse", *cls.state().str_format, kwargs)?.ok_or_else(|| {
+    let state = cls.state();
+    let mut fmt_obj = None;
+    let mut locale_obj = None;
+    let mut style = pattern::Style::Pattern;
+    let mut lenient = false;
+    let mut pivot_year = None;
+    handle_kwargs("parse", kwargs, |key, value, eq| {
+        if eq(key, *state.str_format) {
+            fmt_obj = Some(value);
+        } else if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else if eq(key, *state.str_pivot_year) {
+            pivot_year = pattern::pivot_year_from_py(value)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let fmt_obj = fmt_obj.ok_or_else(|| {
         raise_type_err::<(), _>("parse() requires 'format' keyword argument").unwrap_err()
     })?;
-    let fmt_pystr = fmt_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format must be str")?;
-    let fmt_bytes = fmt_pystr.as_utf8()?;
+    let locale = Locale::from_py_opt(locale_obj)?;
+    let formats = pattern::Formats::compile(
+        state,
+        fmt_obj,
+        style,
+        pattern::CategorySet::DATE_TIME_OFFSET,
+        "OffsetDateTime",
+    )?;
+    formats.check_locale(locale)?;
+    formats.check_pivot_year(pivot_year)?;
+    from_parsed(
+        cls,
+        formats
+            .parse(s, locale, lenient, pivot_year)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?,
+    )
+}
 
-    let pattern = pattern::CompiledPattern::compile(fmt_bytes).into_value_err()?;
-    pattern.validate(pattern::CategorySet::DATE_TIME_OFFSET, "OffsetDateTime")?;
-    let parsed = pattern.parse(s).into_value_err()?;
+/// Create an `OffsetDateTime` from the result of parsing a pattern
+pub(crate) fn from_parsed(cls: PyClass<OffsetDateTime>, parsed: pattern::ParseState) -> PyReturn {
     let offset = parsed
         .offset_secs
         .ok_or_value_err("OffsetDateTime.parse() pattern must include an offset field (x/X)")?;
-    let date = parsed
-        .date("Pattern must include year (YYYY/YY), month (MM/MMM/MMMM), and day (DD) fields")?;
-    parsed.validate_weekday(date)?;
-    let time = parsed.time()?;
+    let date = parsed.date()?;
+    let time = parsed.time();
     // offset is already validated (scalar::Offset) — no range check needed here.
     date.at(time)
         .assume_offset(offset)
@@ -1100,13 +1192,15 @@ static mut METHODS: &[PyMethodDef] = &[
         format_rfc2822,
         doc::OFFSETDATETIME_FORMAT_RFC2822
     ),
-    classmethod1!(
+    classmethod_kwargs!(
         OffsetDateTime,
         parse_rfc2822,
         doc::OFFSETDATETIME_PARSE_RFC2822
     ),
     method_kwargs!(OffsetDateTime, format_iso, doc::OFFSETDATETIME_FORMAT_ISO),
     classmethod1!(OffsetDateTime, parse_iso, doc::OFFSETDATETIME_PARSE_ISO),
+    method0!(OffsetDateTime, to_cbor, doc::OFFSETDATETIME_TO_CBOR),
+    classmethod1!(OffsetDateTime, from_cbor, doc::OFFSETDATETIME_FROM_CBOR),
     method0!(OffsetDateTime, timestamp, doc::EXACTTIME_TIMESTAMP),
     method0!(
         OffsetDateTime,
@@ -1155,7 +1249,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method_kwargs!(OffsetDateTime, round, doc::OFFSETDATETIME_ROUND),
     method_kwargs!(OffsetDateTime, since, doc::OFFSETDATETIME_SINCE),
     method_kwargs!(OffsetDateTime, until, doc::OFFSETDATETIME_UNTIL),
-    method1!(OffsetDateTime, format, doc::OFFSETDATETIME_FORMAT),
+    method_kwargs!(OffsetDateTime, format, doc::OFFSETDATETIME_FORMAT),
     method1!(OffsetDateTime, __format__, c""),
     classmethod_kwargs!(OffsetDateTime, parse, doc::OFFSETDATETIME_PARSE),
     classmethod_kwargs!(
@@ -0,0 +1,410 @@
+use core::{
+    ffi::{c_int, c_void},
+    ptr::null_mut as NULL,
+};
+use pyo3_ffi::*;
+use std::sync::Arc;
+
+use crate::{
+    classes::{
+        date, instant, offset_datetime, plain_datetime, time, time_delta::TimeDelta, zoned_datetime,
+    },
+    common::{
+        disambiguation::Disambiguation,
+        locale::Locale,
+        pattern::{self, CategorySet, CompiledPattern, Style},
+        pickle,
+    },
+    docstrings as doc,
+    py::*,
+    pymodule::State,
+};
+
+/// A pattern string compiled for one specific type
+pub(crate) struct Pattern {
+    source: Owned<PyObj>,
+    compiled: Arc<CompiledPattern>,
+    target: Target,
+    style: Style,
+}
+
+impl PyPayload for Pattern {}
+
+/// The class a pattern formats and parses
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Target {
+    Date,
+    Time,
+    PlainDateTime,
+    OffsetDateTime,
+    ZonedDateTime,
+    Instant,
+    TimeDelta,
+}
+
+impl Target {
+    fn from_py(obj: PyObj, state: &State) -> PyResult<Self> {
+        Ok(if obj.is(*state.date_type) {
+            Self::Date
+        } else if obj.is(*state.time_type) {
+            Self::Time
+        } else if obj.is(*state.plain_datetime_type) {
+            Self::PlainDateTime
+        } else if obj.is(*state.offset_datetime_type) {
+            Self::OffsetDateTime
+        } else if obj.is(*state.zoned_datetime_type) {
+            Self::ZonedDateTime
+        } else if obj.is(*state.instant_type) {
+            Self::Instant
+        } else if obj.is(*state.time_delta_type) {
+            Self::TimeDelta
+        } else {
+            raise_type_err(format!(
+                "Pattern type must be Date, Time, PlainDateTime, \
+                 OffsetDateTime, ZonedDateTime, Instant, or TimeDelta, got {obj}"
+            ))?
+        })
+    }
+
+    fn to_py(self, state: &State) -> Owned<PyObj> {
+        match self {
+            Self::Date => state.date_type.as_type().as_py_obj().newref(),
+            Self::Time => state.time_type.as_type().as_py_obj().newref(),
+            Self::PlainDateTime => state.plain_datetime_type.as_type().as_py_obj().newref(),
+            Self::OffsetDateTime => state.offset_datetime_type.as_type().as_py_obj().newref(),
+            Self::ZonedDateTime => state.zoned_datetime_type.as_type().as_py_obj().newref(),
+            Self::Instant => state.instant_type.as_type().as_py_obj().newref(),
+            Self::TimeDelta => state.time_delta_type.as_type().as_py_obj().newref(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Date => "Date",
+            Self::Time => "Time",
+            Self::PlainDateTime => "PlainDateTime",
+            Self::OffsetDateTime => "OffsetDateTime",
+            Self::ZonedDateTime => "ZonedDateTime",
+            Self::Instant => "Instant",
+            Self::TimeDelta => "TimeDelta",
+        }
+    }
+
+    fn validate(self, compiled: &CompiledPattern, style: Style) -> PyResult<()> {
+        let allowed = match self {
+            Self::Date => CategorySet::DATE,
+            Self::Time => CategorySet::TIME,
+            Self::PlainDateTime => CategorySet::DATE_TIME,
+            Self::OffsetDateTime | Self::Instant => CategorySet::DATE_TIME_OFFSET,
+            Self::ZonedDateTime => CategorySet::DATE_TIME_OFFSET_TZ,
+            Self::TimeDelta => return compiled.validate_delta(self.name(), style),
+        };
+        compiled.validate(allowed, self.name())
+    }
+}
+
+fn __new__(cls: PyClass<Pattern>, args: PyTuple, kwargs: Option<PyDict>) -> PyReturn {
+    let mut iter = args.iter();
+    let (Some(pattern_obj), Some(type_obj), None) = (iter.next(), iter.next(), iter.next()) else {
+        raise_type_err(format!(
+            "Pattern() takes exactly 2 positional arguments ({} given)",
+            args.len()
+        ))?
+    };
+    let state = cls.state();
+    let style = handle_one_kwarg(
+        "Pattern",
+        *state.str_style,
+        kwargs.into_iter().flat_map(|d| d.iteritems()),
+    )?
+    .map_or(Ok(Style::Pattern), |v| Style::from_py(v, state))?;
+    Pattern::new(state, pattern_obj, type_obj, style)?.to_obj(cls)
+}
+
+impl Pattern {
+    fn new(state: &State, pattern_obj: PyObj, type_obj: PyObj, style: Style) -> PyResult<Self> {
+        let target = Target::from_py(type_obj, state)?;
+        let compiled = pattern::compile_cached(state, pattern_obj, style, "pattern must be str")?;
+        target.validate(&compiled, style)?;
+        compiled.warn_if_ambiguous_12h(*state.warn_whenever)?;
+        Ok(Pattern {
+            source: pattern_obj.newref(),
+            compiled,
+            target,
+            style,
+        })
+    }
+}
+
+extern "C" fn dealloc(arg: PyObj) {
+    // SAFETY: in dealloc we have exclusive access. We must drop the owned
+    // fields before freeing the memory, since generic_dealloc won't run
+    // Rust destructors.
+    unsafe {
+        let ptr = &raw mut (*(arg.as_ptr() as *mut PyObjectLayout<Pattern>)).data;
+        std::ptr::drop_in_place(ptr);
+    }
+    generic_dealloc(arg)
+}
+
+fn __repr__(_: PyType, slf: &Pattern) -> PyReturn {
+    match slf.style {
+        Style::Pattern => format!("Pattern({}, {})", *slf.source, slf.target.name()),
+        Style::Strftime => format!(
+            "Pattern({}, {}, style='strftime')",
+            *slf.source,
+            slf.target.name()
+        ),
+        Style::Spreadsheet => format!(
+            "Pattern({}, {}, style='spreadsheet')",
+            *slf.source,
+            slf.target.name()
+        ),
+    }
+    .to_py()
+}
+
+fn __richcmp__(cls: PyClass<Pattern>, a: &Pattern, b_obj: PyObj, op: c_int) -> PyReturn {
+    let Some(b) = b_obj.extract_ref(cls) else {
+        return not_implemented();
+    };
+    let eq = a.target == b.target
+        && a.style == b.style
+        // SAFETY: both sources are always exact `str` objects
+        && unsafe { PyUnicode_Compare(a.source.as_ptr(), b.source.as_ptr()) } == 0;
+    match op {
+        pyo3_ffi::Py_EQ => eq,
+        pyo3_ffi::Py_NE => !eq,
+        _ => return not_implemented(),
+    }
+    .to_py()
+}
+
+extern "C" fn __hash__(arg: PyObj) -> Py_hash_t {
+    // SAFETY: the first arg to this function is the self type
+    let (_, slf) = unsafe { arg.assume_heaptype_ref::<Pattern>() };
+    // SAFETY: hashing a `str` never fails
+    let source_hash = unsafe { PyObject_Hash(slf.source.as_ptr()) };
+    hashmask(source_hash ^ (slf.target as Py_hash_t) ^ ((slf.style as Py_hash_t) << 3))
+}
+
+#[allow(static_mut_refs)]
+static mut SLOTS: &[PyType_Slot] = &[
+    slotmethod!(Pattern, Py_tp_new, __new__),
+    slotmethod!(Pattern, Py_tp_repr, __repr__, 1),
+    slotmethod!(Pattern, Py_tp_richcompare, __richcmp__),
+    PyType_Slot {
+        slot: Py_tp_doc,
+        pfunc: doc::PATTERN.as_ptr() as *mut c_void,
+    },
+    PyType_Slot {
+        slot: Py_tp_hash,
+        pfunc: __hash__ as *mut c_void,
+    },
+    PyType_Slot {
+        slot: Py_tp_methods,
+        pfunc: unsafe { METHODS.as_ptr() as *mut c_void },
+    },
+    PyType_Slot {
+        slot: Py_tp_getset,
+        pfunc: unsafe { GETSETTERS.as_ptr() as *mut c_void },
+    },
+    PyType_Slot {
+        slot: Py_tp_dealloc,
+        pfunc: dealloc as *mut c_void,
+    },
+    PyType_Slot {
+        slot: 0,
+        pfunc: NULL(),
+    },
+];
+
+#[cold]
+fn raise_wrong_type<T>(target: Target, value: PyObj) -> PyResult<T> {
+    raise_type_err(format!(
+        "Expected a {} instance, got {}",
+        target.name(),
+        value.type_()
+    ))
+}
+
+fn format(
+    cls: PyClass<Pattern>,
+    slf: &Pattern,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let value = handle_one_arg("format", args)?;
+    let state = cls.state();
+    let locale_obj = handle_one_kwarg("format", *state.str_locale, kwargs)?;
+    let pattern = &slf.compiled;
+    let locale = pattern.locale(Locale::from_py_opt(locale_obj)?)?;
+    let target = slf.target;
+    match target {
+        Target::Date => {
+            let Some(d) = value.extract(*state.date_type) else {
+                return raise_wrong_type(target, value);
+            };
+            date::format_compiled(d, pattern, locale)
+        }
+        Target::Time => {
+            let Some(t) = value.extract(*state.time_type) else {
+                return raise_wrong_type(target, value);
+            };
+            time::format_compiled(t, pattern, locale)
+        }
+        Target::PlainDateTime => {
+            let Some(dt) = value.extract(*state.plain_datetime_type) else {
+                return raise_wrong_type(target, value);
+            };
+            plain_datetime::format_compiled(dt, pattern, locale)
+        }
+        Target::OffsetDateTime => {
+            let Some(dt) = value.extract(*state.offset_datetime_type) else {
+                return raise_wrong_type(target, value);
+            };
+            offset_datetime::format_compiled(dt, pattern, locale)
+        }
+        Target::ZonedDateTime => {
+            let Some(dt) = value.extract_ref(*state.zoned_datetime_type) else {
+                return raise_wrong_type(target, value);
+            };
+            zoned_datetime::format_compiled(dt, pattern, locale)
+        }
+        Target::Instant => {
+            let Some(i) = value.extract(*state.instant_type) else {
+                return raise_wrong_type(target, value);
+            };
+            instant::format_compiled(i, pattern, locale)
+        }
+        Target::TimeDelta => {
+            let Some(d) = value.extract::<TimeDelta>(*state.time_delta_type) else {
+                return raise_wrong_type(target, value);
+            };
+            pattern.format_delta(d)
+        }
+    }
+}
+
+fn parse(
+    cls: PyClass<Pattern>,
+    slf: &Pattern,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let s_obj = handle_one_arg("parse", args)?;
+    let s_pystr = s_obj
+        .cast_exact::<PyStr>()
+        .ok_or_type_err("parse() argument must be str")?;
+    let s = s_pystr.as_utf8()?;
+
+    let state = cls.state();
+    let target = slf.target;
+    let mut locale_obj = None;
+    let mut dis = None;
+    let mut lenient = false;
+    let mut pivot_year = None;
+    handle_kwargs("parse", kwargs, |key, value, eq| {
+        if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else if eq(key, *state.str_pivot_year) {
+            pivot_year = pattern::pivot_year_from_py(value)?;
+        } else if eq(key, *state.str_disambiguate) {
+            // None is allowed for any type, for easy pass-through
+            if !value.is_none() {
+                if target != Target::ZonedDateTime {
+                    raise_type_err(format!(
+                        "disambiguate is only supported for ZonedDateTime patterns, not {}",
+                        target.name()
+                    ))?
+                }
+                dis = Some(Disambiguation::from_py(value, state)?);
+            }
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let locale = slf.compiled.locale(Locale::from_py_opt(locale_obj)?)?;
+    slf.compiled.check_pivot_year(pivot_year)?;
+    let parsed = || {
+        slf.compiled
+            .parse(s, Some(locale), lenient, pivot_year)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))
+    };
+    match target {
+        Target::Date => date::from_parsed(*state.date_type, parsed()?),
+        Target::Time => time::from_parsed(*state.time_type, parsed()?),
+        Target::PlainDateTime => plain_datetime::from_parsed(*state.plain_datetime_type, parsed()?),
+        Target::OffsetDateTime => {
+            offset_datetime::from_parsed(*state.offset_datetime_type, parsed()?)
+        }
+        Target::ZonedDateTime => zoned_datetime::from_parsed(
+            *state.zoned_datetime_type,
+            parsed()?,
+            dis.unwrap_or(Disambiguation::Compatible),
+        ),
+        Target::Instant => instant::from_parsed(*state.instant_type, parsed()?),
+        Target::TimeDelta => slf
+            .compiled
+            .parse_delta(s, lenient)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?
+            .to_obj(*state.time_delta_type),
+    }
+}
+
+fn __reduce__(cls: PyClass<Pattern>, slf: &Pattern) -> PyReturn {
+    let state = cls.state();
+    [
+        state.unpickle_pattern.newref(),
+        [
+            slf.source.newref(),
+            slf.target.to_py(state),
+            slf.style.to_py_str(state).newref(),
+        ]
+        .into_pytuple()?,
+    ]
+    .into_pytuple()
+}
+
+pub(crate) fn unpickle(state: &State, args: &[PyObj]) -> PyReturn {
+    let &[pattern_obj, type_obj, style_obj] = args else {
+        raise_type_err(pickle::INVALID_DATA)?
+    };
+    Pattern::new(
+        state,
+        pattern_obj,
+        type_obj,
+        Style::from_py(style_obj, state)?,
+    )?
+    .to_obj(*state.pattern_type)
+}
+
+static mut METHODS: &[PyMethodDef] = &[
+    COPY_METHOD,
+    DEEPCOPY_METHOD,
+    method0!(Pattern, __reduce__, c""),
+    method_kwargs!(Pattern, format, doc::PATTERN_FORMAT),
+    method_kwargs!(Pattern, parse, doc::PATTERN_PARSE),
+    PyMethodDef::zeroed(),
+];
+
+fn pattern(_: PyType, slf: &Pattern) -> PyReturn {
+    Ok(slf.source.newref())
+}
+
+static mut GETSETTERS: &[PyGetSetDef] = &[
+    getter!(Pattern, pattern, doc::PATTERN_PATTERN),
+    PyGetSetDef {
+        name: NULL(),
+        get: None,
+        set: None,
+        doc: NULL(),
+        closure: NULL(),
+    },
+];
+
+pub(crate) static mut SPEC: PyType_Spec =
+    type_spec::<Pattern>(c"whenever.Pattern", unsafe { SLOTS });
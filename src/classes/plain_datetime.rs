@@ -11,6 +11,8 @@ use crate::{
         disambiguation::*,
         fmt,
         format_args::{self, Suffix},
+        locale::Locale,
+        parse::Scan,
         pattern, pickle, round_args as round,
         shift_args::{parse_datetime_shift_arg, parse_datetime_shift_kwargs},
     },
@@ -152,15 +154,15 @@ fn format_iso(
 }
 
 fn parse_iso(cls: PyClass<PlainDateTime>, arg: PyObj) -> PyReturn {
-    PlainDateTime::parse_iso(
-        arg.cast_allow_subclass::<PyStr>()
-            // NOTE: this exception message also needs to make sense when
-            // called through the constructor
-            .ok_or_type_err("when parsing from ISO format, the argument must be str")?
-            .as_utf8()?,
-    )
-    .ok_or_else_value_err(|| format!("Invalid format: {arg}"))?
-    .to_obj(cls)
+    let py_str = arg
+        .cast_allow_subclass::<PyStr>()
+        // NOTE: this exception message also needs to make sense when
+        // called through the constructor
+        .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    scan.parse_all(PlainDateTime::read_iso)
+        .ok_or_else(|| scan.invalid_format(*cls.state().exc_parse, arg))?
+        .to_obj(cls)
 }
 
 fn __richcmp__(
@@ -886,20 +888,44 @@ fn round(
     slf.with_date(date).with_time(time_rounded).to_obj(cls)
 }
 
-fn format(cls: PyClass<PlainDateTime>, slf: PlainDateTime, pattern_obj: PyObj) -> PyReturn {
-    let pattern_pystr = pattern_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format() argument must be str")?;
-    let pattern_str = pattern_pystr.as_utf8()?;
-    let pattern = pattern::CompiledPattern::compile(pattern_str).into_value_err()?;
+fn format(
+    cls: PyClass<PlainDateTime>,
+    slf: PlainDateTime,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let pattern_obj = handle_one_arg("format", args)?;
+    let (locale, style) = pattern::format_kwargs(cls.state(), kwargs)?;
+    format_pattern(cls, slf, pattern_obj, locale, style)
+}
+
+fn format_pattern(
+    cls: PyClass<PlainDateTime>,
+    slf: PlainDateTime,
+    pattern_obj: PyObj,
+    locale: Option<&'static Locale>,
+    style: pattern::Style,
+) -> PyReturn {
+    let state = cls.state();
+    let pattern =
+        pattern::compile_cached(state, pattern_obj, style, "format() argument must be str")?;
     pattern.validate(pattern::CategorySet::DATE_TIME, "PlainDateTime")?;
-    pattern.warn_if_ambiguous_12h(*cls.state().warn_whenever)?;
-    pattern.format(&slf.pattern_values())
+    pattern.warn_if_ambiguous_12h(*state.warn_whenever)?;
+    let locale = pattern.locale(locale)?;
+    format_compiled(slf, &pattern, locale)
+}
+
+pub(crate) fn format_compiled(
+    slf: PlainDateTime,
+    pattern: &pattern::CompiledPattern,
+    locale: &'static Locale,
+) -> PyReturn {
+    pattern.format(&slf.pattern_values().with_locale(locale))
 }
 
 fn __format__(cls: PyClass<PlainDateTime>, slf: PlainDateTime, spec_obj: PyObj) -> PyReturn {
     if spec_obj.is_truthy()? {
-        format(cls, slf, spec_obj)
+        format_pattern(cls, slf, spec_obj, None, pattern::Style::Pattern)
     } else {
         __str__(cls.into(), slf)
     }
@@ -912,21 +938,53 @@ fn parse(cls: PyClass<PlainDateTime>, args: &[PyObj], kwargs: &mut IterKwargs) -
         .ok_or_type_err("parse() argument must be str")?;
     let s = s_pystr.as_utf8()?;
 
-    let fmt_obj = handle_one_kwarg("parse", *cls.state().str_format, kwargs)?.ok_or_else(|| {
+    let state = cls.state();
+    let mut fmt_obj = None;
+    let mut locale_obj = None;
+    let mut style = pattern::Style::Pattern;
+    let mut lenient = false;
+    let mut pivot_year = None;
+    handle_kwargs("parse", kwargs, |key, value, eq| {
+        if eq(key, *state.str_format) {
+            fmt_obj = Some(value);
+        } else if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else if eq(key, *state.str_pivot_year) {
+            pivot_year = pattern::pivot_year_from_py(value)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let fmt_obj = fmt_obj.ok_or_else(|| {
         raise_type_err::<(), _>("parse() requires 'format' keyword argument").unwrap_err()
     })?;
-    let fmt_pystr = fmt_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format must be str")?;
-    let fmt_bytes = fmt_pystr.as_utf8()?;
+    let locale = Locale::from_py_opt(locale_obj)?;
+    let formats = pattern::Formats::compile(
+        state,
+        fmt_obj,
+        style,
+        pattern::CategorySet::DATE_TIME,
+        "PlainDateTime",
+    )?;
+    formats.check_locale(locale)?;
+    formats.check_pivot_year(pivot_year)?;
+    from_parsed(
+        cls,
+        formats
+            .parse(s, locale, lenient, pivot_year)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?,
+    )
+}
 
-    let pattern = pattern::CompiledPattern::compile(fmt_bytes).into_value_err()?;
-    pattern.validate(pattern::CategorySet::DATE_TIME, "PlainDateTime")?;
-    let parsed = pattern.parse(s).into_value_err()?;
-    let date = parsed
-        .date("Pattern must include year (YYYY/YY), month (MM/MMM/MMMM), and day (DD) fields")?;
-    parsed.validate_weekday(date)?;
-    date.at(parsed.time()?).to_obj(cls)
+/// Create a `PlainDateTime` from the result of parsing a pattern
+pub(crate) fn from_parsed(cls: PyClass<PlainDateTime>, parsed: pattern::ParseState) -> PyReturn {
+    let date = parsed.date()?;
+    date.at(parsed.time()).to_obj(cls)
 }
 
 static mut METHODS: &[PyMethodDef] = &[
@@ -980,7 +1038,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method_kwargs!(PlainDateTime, since, doc::PLAINDATETIME_SINCE),
     method_kwargs!(PlainDateTime, until, doc::PLAINDATETIME_UNTIL),
     method_kwargs!(PlainDateTime, round, doc::PLAINDATETIME_ROUND),
-    method1!(PlainDateTime, format, doc::PLAINDATETIME_FORMAT),
+    method_kwargs!(PlainDateTime, format, doc::PLAINDATETIME_FORMAT),
     method1!(PlainDateTime, __format__, c""),
     classmethod_kwargs!(PlainDateTime, parse, doc::PLAINDATETIME_PARSE),
     classmethod_kwargs!(
@@ -1,7 +1,5 @@
-#[cfg(test)]
-use crate::common::{fmt::Sink, parse::Scan};
 use crate::{
-    common::{fmt, format_args, pattern, pickle, round_args as round},
+    common::{fmt, format_args, locale::Locale, parse::Scan, pattern, pickle, round_args as round},
     docstrings as doc,
     domain::scalar::*,
     py::*,
@@ -246,15 +244,15 @@ fn format_iso(cls: PyClass<Time>, slf: Time, args: &[PyObj], kwargs: &mut IterKw
 }
 
 fn parse_iso(cls: PyClass<Time>, s: PyObj) -> PyReturn {
-    Time::parse_iso(
-        s.cast_allow_subclass::<PyStr>()
-            // NOTE: this exception message also needs to make sense when
-            // called through the constructor
-            .ok_or_type_err("when parsing from ISO format, the argument must be str")?
-            .as_utf8()?,
-    )
-    .ok_or_else_value_err(|| format!("Invalid format: {s}"))?
-    .to_obj(cls)
+    let py_str = s
+        .cast_allow_subclass::<PyStr>()
+        // NOTE: this exception message also needs to make sense when
+        // called through the constructor
+        .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    scan.parse_all(Time::read_iso)
+        .ok_or_else(|| scan.invalid_format(*cls.state().exc_parse, s))?
+        .to_obj(cls)
 }
 
 fn __reduce__(cls: PyClass<Time>, slf: Time) -> PyReturn {
@@ -313,20 +311,39 @@ fn round(cls: PyClass<Time>, slf: Time, args: &[PyObj], kwargs: &mut IterKwargs)
     slf.round(increment_ns, mode).0.to_obj(cls)
 }
 
-fn format(cls: PyClass<Time>, slf: Time, pattern_obj: PyObj) -> PyReturn {
-    let pattern_pystr = pattern_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format() argument must be str")?;
-    let pattern_str = pattern_pystr.as_utf8()?;
-    let pattern = pattern::CompiledPattern::compile(pattern_str).into_value_err()?;
+fn format(cls: PyClass<Time>, slf: Time, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let pattern_obj = handle_one_arg("format", args)?;
+    let (locale, style) = pattern::format_kwargs(cls.state(), kwargs)?;
+    format_pattern(cls, slf, pattern_obj, locale, style)
+}
+
+fn format_pattern(
+    cls: PyClass<Time>,
+    slf: Time,
+    pattern_obj: PyObj,
+    locale: Option<&'static Locale>,
+    style: pattern::Style,
+) -> PyReturn {
+    let state = cls.state();
+    let pattern =
+        pattern::compile_cached(state, pattern_obj, style, "format() argument must be str")?;
     pattern.validate(pattern::CategorySet::TIME, "Time")?;
-    pattern.warn_if_ambiguous_12h(*cls.state().warn_whenever)?;
-    pattern.format(&slf.pattern_values())
+    pattern.warn_if_ambiguous_12h(*state.warn_whenever)?;
+    let locale = pattern.locale(locale)?;
+    format_compiled(slf, &pattern, locale)
+}
+
+pub(crate) fn format_compiled(
+    slf: Time,
+    pattern: &pattern::CompiledPattern,
+    locale: &'static Locale,
+) -> PyReturn {
+    pattern.format(&slf.pattern_values().with_locale(locale))
 }
 
 fn __format__(cls: PyClass<Time>, slf: Time, spec_obj: PyObj) -> PyReturn {
     if spec_obj.is_truthy()? {
-        format(cls, slf, spec_obj)
+        format_pattern(cls, slf, spec_obj, None, pattern::Style::Pattern)
     } else {
         __str__(cls.into(), slf)
     }
@@ -339,17 +356,43 @@ fn parse(cls: PyClass<Time>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyRetur
         .ok_or_type_err("parse() argument must be str")?;
     let s = s_pystr.as_utf8()?;
 
-    let fmt_obj = handle_one_kwarg("parse", *cls.state().str_format, kwargs)?.ok_or_else(|| {
+    let state = cls.state();
+    let mut fmt_obj = None;
+    let mut locale_obj = None;
+    let mut style = pattern::Style::Pattern;
+    let mut lenient = false;
+    handle_kwargs("parse", kwargs, |key, value, eq| {
+        if eq(key, *state.str_format) {
+            fmt_obj = Some(value);
+        } else if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let fmt_obj = fmt_obj.ok_or_else(|| {
         raise_type_err::<(), _>("parse() requires 'format' keyword argument").unwrap_err()
     })?;
-    let fmt_pystr = fmt_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format must be str")?;
-    let fmt_bytes = fmt_pystr.as_utf8()?;
+    let locale = Locale::from_py_opt(locale_obj)?;
+    let formats =
+        pattern::Formats::compile(state, fmt_obj, style, pattern::CategorySet::TIME, "Time")?;
+    formats.check_locale(locale)?;
+    from_parsed(
+        cls,
+        formats
+            .parse(s, locale, lenient, None)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?,
+    )
+}
 
-    let pattern = pattern::CompiledPattern::compile(fmt_bytes).into_value_err()?;
-    pattern.validate(pattern::CategorySet::TIME, "Time")?;
-    pattern.parse(s).into_value_err()?.time()?.to_obj(cls)
+/// Create a `Time` from the result of parsing a pattern
+pub(crate) fn from_parsed(cls: PyClass<Time>, parsed: pattern::ParseState) -> PyReturn {
+    parsed.time().to_obj(cls)
 }
 
 static mut METHODS: &[PyMethodDef] = &[
@@ -364,7 +407,7 @@ static mut METHODS: &[PyMethodDef] = &[
     classmethod1!(Time, from_py_time, doc::TIME_FROM_PY_TIME),
     method1!(Time, on, doc::TIME_ON),
     method_kwargs!(Time, round, doc::TIME_ROUND),
-    method1!(Time, format, doc::TIME_FORMAT),
+    method_kwargs!(Time, format, doc::TIME_FORMAT),
     method1!(Time, __format__, c""),
     classmethod_kwargs!(Time, parse, doc::TIME_PARSE),
     classmethod_kwargs!(Time, __get_pydantic_core_schema__, doc::PYDANTIC_SCHEMA),
@@ -414,15 +457,6 @@ mod tests {
     use super::*;
     use crate::common::fmt::Chunk;
 
-    impl Sink for Vec<u8> {
-        fn write(&mut self, bytes: &[u8]) {
-            self.extend_from_slice(bytes);
-        }
-        fn write_byte(&mut self, b: u8) {
-            self.push(b);
-        }
-    }
-
     #[test]
     fn format_iso() {
         let t1 = Time {
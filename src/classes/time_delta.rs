@@ -13,13 +13,20 @@ use crate::{
         plain_datetime::{plain_since_inner, resolve_local_relative_to, total_calendar_plain},
         zoned_datetime::{ZonedDateTime, zoned_since_in_units, zoned_target},
     },
-    common::{pickle, round_args as round},
+    common::{
+        cbor, format_args,
+        humanize_args::HumanizeArgs,
+        msgpack,
+        parse::{Scan, parse_err},
+        pattern, pickle, round_args as round,
+    },
     docstrings as doc,
     domain::{
         difference::{
             self, CalendarIncrement, DifferenceSpec, DifferenceUnitSet, ExactUnit, ExactUnitSet,
             TotalUnit,
         },
+        humanize::{self, Unit},
         scalar::*,
         time_delta::ParseError,
     },
@@ -259,8 +266,8 @@ fn __repr__(_: PyType, slf: TimeDelta) -> PyReturn {
     format!("TimeDelta(\"{slf}\")").to_py()
 }
 
-fn __str__(cls: PyType, slf: TimeDelta) -> PyReturn {
-    format_iso(cls, slf)
+fn __str__(_: PyType, slf: TimeDelta) -> PyReturn {
+    slf.fmt_iso().to_py()
 }
 
 fn __mul__(obj_a: PyObj, obj_b: PyObj) -> PyReturn {
@@ -569,6 +576,26 @@ pub(crate) fn unpickle(state: &State, arg: PyObj) -> PyReturn {
         .to_obj(*state.time_delta_type)
 }
 
+fn to_cbor(_: PyType, slf: TimeDelta) -> PyReturn {
+    cbor::encode_time_delta(slf).to_py()
+}
+
+fn from_cbor(cls: PyClass<TimeDelta>, data: PyObj) -> PyReturn {
+    cbor::decode_time_delta(data.expect_bytes()?)
+        .ok_or_value_err(cbor::INVALID_DATA)?
+        .to_obj(cls)
+}
+
+fn to_msgpack(_: PyType, slf: TimeDelta) -> PyReturn {
+    msgpack::encode_time_delta(slf).to_py()
+}
+
+fn from_msgpack(cls: PyClass<TimeDelta>, data: PyObj) -> PyReturn {
+    msgpack::decode_time_delta(data.expect_bytes()?)
+        .ok_or_value_err(msgpack::INVALID_DATA)?
+        .to_obj(cls)
+}
+
 fn in_nanoseconds(cls: PyClass<TimeDelta>, slf: TimeDelta) -> PyReturn {
     warn_with_class(
         *cls.state().warn_deprecation,
@@ -688,8 +715,21 @@ fn in_hrs_mins_secs_nanos(_: PyType, slf: TimeDelta) -> PyReturn {
     .into_pytuple()
 }
 
-fn format_iso(_: PyType, slf: TimeDelta) -> PyReturn {
-    slf.fmt_iso().to_py()
+fn format_iso(
+    cls: PyClass<TimeDelta>,
+    slf: TimeDelta,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    match format_args::delta_iso_alternative(cls.state(), args, kwargs)? {
+        Some(basic) => slf
+            .fmt_iso_alternative(basic)
+            .ok_or_value_err(
+                "TimeDelta over 24 hours can't be expressed in the alternative format",
+            )?
+            .to_py(),
+        None => slf.fmt_iso().to_py(),
+    }
 }
 
 fn parse_iso(cls: PyClass<TimeDelta>, arg: PyObj) -> PyReturn {
@@ -698,13 +738,141 @@ fn parse_iso(cls: PyClass<TimeDelta>, arg: PyObj) -> PyReturn {
         // NOTE: this exception message also needs to make sense when
         // called through the constructor
         .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
-    match TimeDelta::parse_iso(py_str.as_utf8()?) {
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    match TimeDelta::parse_iso(&mut scan) {
         Ok(d) => d.to_obj(cls),
-        Err(ParseError::Invalid) => raise_value_err(format!("Invalid format: {arg}")),
+        Err(ParseError::Invalid) => Err(scan.invalid_format(*cls.state().exc_parse, arg)),
         Err(ParseError::OutOfRange) => raise_range_err(),
     }
 }
 
+fn format_go(_: PyType, slf: TimeDelta) -> PyReturn {
+    slf.fmt_go().to_py()
+}
+
+fn parse_go(cls: PyClass<TimeDelta>, arg: PyObj) -> PyReturn {
+    let py_str = arg
+        .cast_allow_subclass::<PyStr>()
+        .ok_or_type_err("parse_go() argument must be str")?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    TimeDelta::parse_go(&mut scan)
+        .map_err(|e| delta_parse_err(*cls.state().exc_parse, e, &scan, arg))?
+        .to_obj(cls)
+}
+
+fn parse_human(cls: PyClass<TimeDelta>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let arg = handle_one_arg("parse_human", args)?;
+    let py_str = arg
+        .cast_allow_subclass::<PyStr>()
+        .ok_or_type_err("parse_human() argument must be str")?;
+    let state = cls.state();
+    let mut days_assumed_24h_ok = false;
+    handle_kwargs("parse_human", kwargs, |key, value, eq| {
+        if eq(key, *state.str_days_assumed_24h_ok) {
+            days_assumed_24h_ok = value.is_truthy()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let mut scan = Scan::new(py_str.as_utf8()?);
+    let (delta, uses_days) = TimeDelta::parse_english(&mut scan)
+        .map_err(|e| delta_parse_err(*state.exc_parse, e, &scan, arg))?;
+    if uses_days && !days_assumed_24h_ok {
+        warn_with_class(
+            *state.warn_days_not_always_24h,
+            doc::DAYS_NOT_ALWAYS_24H_MSG,
+            1,
+        )?;
+    }
+    delta.to_obj(cls)
+}
+
+fn delta_parse_err(exc: PyObj, err: ParseError, scan: &Scan, input: PyObj) -> PyErrMarker {
+    match err {
+        ParseError::Invalid => scan.invalid_format(exc, input),
+        ParseError::OutOfRange => parse_err(exc, "delta out of range", input, None, None),
+    }
+}
+
+fn format(
+    cls: PyClass<TimeDelta>,
+    slf: TimeDelta,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let pattern_obj = handle_one_arg("format", args)?;
+    let state = cls.state();
+    let mut style = pattern::Style::Pattern;
+    handle_kwargs("format", kwargs, |key, value, eq| {
+        if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    format_pattern(cls, slf, pattern_obj, style)
+}
+
+fn format_pattern(
+    cls: PyClass<TimeDelta>,
+    slf: TimeDelta,
+    pattern_obj: PyObj,
+    style: pattern::Style,
+) -> PyReturn {
+    let pattern = pattern::compile_cached(
+        cls.state(),
+        pattern_obj,
+        style,
+        "format() argument must be str",
+    )?;
+    pattern.validate_delta("TimeDelta", style)?;
+    pattern.format_delta(slf)
+}
+
+fn __format__(cls: PyClass<TimeDelta>, slf: TimeDelta, spec_obj: PyObj) -> PyReturn {
+    if spec_obj.is_truthy()? {
+        format_pattern(cls, slf, spec_obj, pattern::Style::Pattern)
+    } else {
+        __str__(cls.into(), slf)
+    }
+}
+
+fn parse(cls: PyClass<TimeDelta>, args: &[PyObj], kwargs: &mut IterKwargs) -> PyReturn {
+    let s_obj = handle_one_arg("parse", args)?;
+    let s_pystr = s_obj
+        .cast_exact::<PyStr>()
+        .ok_or_type_err("parse() argument must be str")?;
+    let s = s_pystr.as_utf8()?;
+
+    let state = cls.state();
+    let mut fmt_obj = None;
+    let mut lenient = false;
+    let mut style = pattern::Style::Pattern;
+    handle_kwargs("parse", kwargs, |key, value, eq| {
+        if eq(key, *state.str_format) {
+            fmt_obj = Some(value);
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let fmt_obj = fmt_obj.ok_or_else(|| {
+        raise_type_err::<(), _>("parse() requires 'format' keyword argument").unwrap_err()
+    })?;
+    pattern::Formats::compile_with(state, fmt_obj, style, |p| {
+        p.validate_delta("TimeDelta", style)
+    })?
+    .parse_delta(s, lenient)
+    .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?
+    .to_obj(cls)
+}
+
 fn round(
     cls: PyClass<TimeDelta>,
     slf: TimeDelta,
@@ -925,6 +1093,44 @@ fn total(
     total_calendar_plain(neg, calendar_unit, a_inst, b_dt, target_date)
 }
 
+fn humanize(
+    cls: PyClass<TimeDelta>,
+    slf: TimeDelta,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let state = cls.state();
+    let mut days_assumed_24h_ok = false;
+    let h = HumanizeArgs::parse_with("humanize", args, kwargs, state, |key, value, eq| {
+        if eq(key, *state.str_days_assumed_24h_ok) {
+            days_assumed_24h_ok = value.is_truthy()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    humanize_exact(state, slf, &h, days_assumed_24h_ok)
+}
+
+/// Humanize an exact difference, taking days to be 24 hours.
+pub(crate) fn humanize_exact(
+    state: &State,
+    delta: TimeDelta,
+    h: &HumanizeArgs,
+    days_assumed_24h_ok: bool,
+) -> PyReturn {
+    let (unit, amount) = humanize::select_exact(delta.total_nanos(), h.granularity, h.mode)
+        .ok_or_value_err("a granularity of months or years requires a `relative_to` datetime")?;
+    if matches!(unit, Unit::Days | Unit::Weeks) && !days_assumed_24h_ok {
+        warn_with_class(
+            *state.warn_days_not_always_24h,
+            doc::DAYS_NOT_ALWAYS_24H_MSG,
+            1,
+        )?;
+    }
+    h.format(unit, amount)
+}
+
 #[inline(never)]
 pub(crate) fn total_calendar(
     neg: bool,
@@ -933,8 +1139,20 @@ pub(crate) fn total_calendar(
     shifted: OffsetDateTime,
     shifted_inst: Instant,
 ) -> PyReturn {
-    let target_date =
-        zoned_target(shifted.date, shifted_inst, relative_to, neg).ok_or_range_err()?;
+    total_calendar_f64(neg, unit, relative_to, shifted, shifted_inst)
+        .ok_or_range_err()?
+        .to_py()
+}
+
+/// The fractional amount of a calendar unit between `relative_to` and `shifted`.
+pub(crate) fn total_calendar_f64(
+    neg: bool,
+    unit: difference::CalendarUnit,
+    relative_to: &ZonedDateTime,
+    shifted: OffsetDateTime,
+    shifted_inst: Instant,
+) -> Option<f64> {
+    let target_date = zoned_target(shifted.date, shifted_inst, relative_to, neg)?;
 
     let (trunc_amount, trunc_date, expand_date) = difference::date_diff_single_unit(
         target_date,
@@ -942,26 +1160,33 @@ pub(crate) fn total_calendar(
         CalendarIncrement::MIN,
         unit,
         neg,
-    )
-    .ok_or_range_err()?;
+    )?;
 
-    let trunc_odt = relative_to.with_date(trunc_date.into()).ok_or_range_err()?;
-    let expand_odt = relative_to
-        .with_date(expand_date.into())
-        .ok_or_range_err()?;
+    let trunc_odt = relative_to.with_date(trunc_date.into())?;
+    let expand_odt = relative_to.with_date(expand_date.into())?;
 
     let r = shifted_inst.diff(trunc_odt.to_instant()).abs();
     let e = expand_odt.to_instant().diff(trunc_odt.to_instant());
 
-    (trunc_amount as f64 + r.to_nanos_f64() / e.to_nanos_f64()).to_py()
+    Some(trunc_amount as f64 + r.to_nanos_f64() / e.to_nanos_f64())
 }
 
 static mut METHODS: &[PyMethodDef] = &[
     COPY_METHOD,
     DEEPCOPY_METHOD,
     method0!(TimeDelta, __reduce__, c""),
-    method0!(TimeDelta, format_iso, doc::TIMEDELTA_FORMAT_ISO),
+    method_kwargs!(TimeDelta, format_iso, doc::TIMEDELTA_FORMAT_ISO),
     classmethod1!(TimeDelta, parse_iso, doc::TIMEDELTA_PARSE_ISO),
+    method0!(TimeDelta, format_go, doc::TIMEDELTA_FORMAT_GO),
+    classmethod1!(TimeDelta, parse_go, doc::TIMEDELTA_PARSE_GO),
+    method0!(TimeDelta, to_cbor, doc::TIMEDELTA_TO_CBOR),
+    classmethod1!(TimeDelta, from_cbor, doc::TIMEDELTA_FROM_CBOR),
+    method0!(TimeDelta, to_msgpack, doc::TIMEDELTA_TO_MSGPACK),
+    classmethod1!(TimeDelta, from_msgpack, doc::TIMEDELTA_FROM_MSGPACK),
+    classmethod_kwargs!(TimeDelta, parse_human, doc::TIMEDELTA_PARSE_HUMAN),
+    method_kwargs!(TimeDelta, format, doc::TIMEDELTA_FORMAT),
+    method1!(TimeDelta, __format__, c""),
+    classmethod_kwargs!(TimeDelta, parse, doc::TIMEDELTA_PARSE),
     method0!(TimeDelta, in_nanoseconds, doc::TIMEDELTA_IN_NANOSECONDS),
     method0!(TimeDelta, in_microseconds, doc::TIMEDELTA_IN_MICROSECONDS),
     method0!(TimeDelta, in_milliseconds, doc::TIMEDELTA_IN_MILLISECONDS),
@@ -986,6 +1211,7 @@ static mut METHODS: &[PyMethodDef] = &[
     method_kwargs!(TimeDelta, subtract, doc::TIMEDELTA_SUBTRACT),
     method_kwargs!(TimeDelta, in_units, doc::TIMEDELTA_IN_UNITS),
     method_kwargs!(TimeDelta, total, doc::TIMEDELTA_TOTAL),
+    method_kwargs!(TimeDelta, humanize, doc::TIMEDELTA_HUMANIZE),
     classmethod_kwargs!(
         TimeDelta,
         __get_pydantic_core_schema__,
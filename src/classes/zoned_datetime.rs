@@ -5,23 +5,26 @@ use crate::{
         offset_datetime::OffsetDateTime,
         plain_datetime::{DateTimeBoundaryUnit, PlainDateTime},
         time::Time,
-        time_delta::TimeDelta,
+        time_delta::{TimeDelta, total_calendar_f64},
     },
     common::{
         disambiguation::*,
         fmt,
         format_args::{self, Suffix},
+        humanize_args::HumanizeArgs,
         instant::{
             extract_instant, parse_instant_arg, parse_timestamp, parse_timestamp_millis,
             parse_timestamp_nanos,
         },
+        locale::Locale,
         parse::Scan,
-        pattern, pickle, round_args as round,
+        pattern, pickle, rfc2822, round_args as round,
         shift_args::{parse_datetime_shift_arg, parse_datetime_shift_kwargs},
     },
     docstrings as doc,
     domain::{
         difference::{self, CalendarIncrement, DifferenceSpec},
+        humanize,
         local::{LocalMapping, ResolveError, ResolvePolicy},
         scalar::*,
         shift::{CalendarShift, DateTimeShift},
@@ -629,6 +632,36 @@ fn format_iso(
     )
 }
 
+fn format_rfc2822(
+    cls: PyClass<ZonedDateTime>,
+    slf: &ZonedDateTime,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    handle_no_args("format_rfc2822", args)?;
+    let state = cls.state();
+    let mut zone_comment = false;
+    handle_kwargs("format_rfc2822", kwargs, |key, value, eq| {
+        if eq(key, *state.str_zone_comment) {
+            zone_comment = value.expect_bool("zone_comment")?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    let fmt = rfc2822::format(slf.to_fixed_offset());
+    // SAFETY: we know the format is ASCII only
+    let s = unsafe { std::str::from_utf8_unchecked(&fmt[..]) };
+    if zone_comment {
+        let meta = slf.tz.meta_for_instant(slf.to_instant().epoch);
+        // SAFETY: TzAbbrev always contains valid ASCII bytes
+        let abbrev = unsafe { std::str::from_utf8_unchecked(meta.abbrev.as_bytes()) };
+        format!("{s} ({abbrev})").to_py()
+    } else {
+        s.to_py()
+    }
+}
+
 fn parse_iso(cls: PyClass<ZonedDateTime>, arg: PyObj) -> PyReturn {
     let py_str = arg
         .cast_allow_subclass::<PyStr>()
@@ -636,9 +669,9 @@ fn parse_iso(cls: PyClass<ZonedDateTime>, arg: PyObj) -> PyReturn {
         // called through the constructor
         .ok_or_type_err("when parsing from ISO format, the argument must be str")?;
     let mut s = Scan::new(py_str.as_utf8()?);
-    let (dt, (offset, tzstr)) = PlainDateTime::read_iso(&mut s)
-        .zip(read_offset_and_tzname(&mut s))
-        .ok_or_else_value_err(|| format!("Invalid format: {arg}"))?;
+    let (dt, (offset, tzstr)) = s
+        .parse_all(|s| Some((PlainDateTime::read_iso(s)?, read_offset_and_tzname(s)?)))
+        .ok_or_else(|| s.invalid_format(*cls.state().exc_parse, arg))?;
     let state = cls.state();
     let tz = state.tz_store.get(tzstr)?;
     match offset {
@@ -961,6 +994,42 @@ fn tz_abbrev(_: PyType, slf: &ZonedDateTime) -> PyReturn {
     unsafe { std::str::from_utf8_unchecked(meta.abbrev.as_bytes()) }.to_py()
 }
 
+fn offset_period(cls: PyClass<ZonedDateTime>, slf: &ZonedDateTime) -> PyReturn {
+    let state = cls.state();
+    let tz = &slf.tz;
+    let epoch = slf.to_instant().epoch;
+    let (start, end) = tz.period_for_instant(epoch);
+    let meta = tz.meta_for_instant(epoch);
+    let year = slf.date.year;
+    let year_start = year
+        .unix_days_at_jan1()
+        .epoch_at(Time::MIN)
+        .saturating_add_i32(-slf.offset.get());
+    let days_in_year = if year.is_leap() { 366 } else { 365 };
+    let year_end = year_start.saturating_add_i32(days_in_year * S_PER_DAY);
+    let bound = |t: Option<EpochSecs>| match t {
+        Some(epoch) => Instant {
+            epoch,
+            subsec: SubSecNanos::MIN,
+        }
+        .to_obj(*state.instant_type),
+        None => Ok(none()),
+    };
+    let secs_to_delta = |secs: i32| {
+        TimeDelta::from_nanos_unchecked(secs as i128 * 1_000_000_000).to_obj(*state.time_delta_type)
+    };
+    // SAFETY: TzAbbrev always contains valid ASCII bytes
+    let abbrev = unsafe { std::str::from_utf8_unchecked(meta.abbrev.as_bytes()) };
+    state.offset_period_new.get()?.call_args([
+        *bound(start)?,
+        *bound(end)?,
+        *secs_to_delta(slf.offset.get())?,
+        *secs_to_delta(slf.offset.get() - meta.dst_saving)?,
+        *abbrev.to_py()?,
+        *tz.has_dst_between(year_start, year_end).to_py()?,
+    ])
+}
+
 fn add(
     cls: PyClass<ZonedDateTime>,
     slf: &ZonedDateTime,
@@ -1111,6 +1180,60 @@ fn until(
     zoned_since(cls, slf, args, kwargs, true)
 }
 
+fn humanize(
+    cls: PyClass<ZonedDateTime>,
+    slf: &ZonedDateTime,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let state = cls.state();
+    let mut relative_to_arg = None;
+    let mut relative_day = false;
+    let h = HumanizeArgs::parse_with("humanize", args, kwargs, state, |key, value, eq| {
+        if eq(key, *state.str_relative_to) {
+            relative_to_arg = Some(value);
+        } else if eq(key, *state.str_relative_day) {
+            relative_day = value.is_truthy()?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    let now;
+    let relative_to = match &relative_to_arg {
+        Some(arg) => arg
+            .extract_ref(*state.zoned_datetime_type)
+            .ok_or_type_err("relative_to must be a ZonedDateTime")?,
+        None => {
+            now = state.now()?.in_timezone(slf.tz.clone()).ok_or_range_err()?;
+            &now
+        }
+    };
+    let inst = slf.to_instant();
+    let nanos = inst.diff(relative_to.to_instant()).total_nanos();
+    // Calendar units are measured in the timezone of `relative_to`
+    let shifted = inst.to_offset_in(&relative_to.tz).ok_or_range_err()?;
+    if relative_day {
+        let days = shifted
+            .date
+            .unix_days()
+            .diff(relative_to.date.unix_days())
+            .get();
+        if days.abs() == 1 {
+            let time = format!("{:02}:{:02}", shifted.time.hour, shifted.time.minute);
+            return h.phrases.format_day(days as i8, &time).to_py();
+        }
+    }
+    let (unit, amount) = humanize::select(
+        nanos,
+        Some(|unit| total_calendar_f64(nanos < 0, unit, relative_to, shifted, inst)),
+        h.granularity,
+        h.mode,
+    )
+    .ok_or_range_err()?;
+    h.format(unit, amount)
+}
+
 fn zoned_since_float(
     a: OffsetDateTime,
     b: &ZonedDateTime,
@@ -1209,14 +1332,38 @@ fn zoned_since(
     }
 }
 
-fn format(cls: PyClass<ZonedDateTime>, slf: &ZonedDateTime, pattern_obj: PyObj) -> PyReturn {
-    let pattern_pystr = pattern_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format() argument must be str")?;
-    let pattern_str = pattern_pystr.as_utf8()?;
-    let pattern = pattern::CompiledPattern::compile(pattern_str).into_value_err()?;
+fn format(
+    cls: PyClass<ZonedDateTime>,
+    slf: &ZonedDateTime,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyReturn {
+    let pattern_obj = handle_one_arg("format", args)?;
+    let (locale, style) = pattern::format_kwargs(cls.state(), kwargs)?;
+    format_pattern(cls, slf, pattern_obj, locale, style)
+}
+
+fn format_pattern(
+    cls: PyClass<ZonedDateTime>,
+    slf: &ZonedDateTime,
+    pattern_obj: PyObj,
+    locale: Option<&'static Locale>,
+    style: pattern::Style,
+) -> PyReturn {
+    let state = cls.state();
+    let pattern =
+        pattern::compile_cached(state, pattern_obj, style, "format() argument must be str")?;
     pattern.validate(pattern::CategorySet::DATE_TIME_OFFSET_TZ, "ZonedDateTime")?;
-    pattern.warn_if_ambiguous_12h(*cls.state().warn_whenever)?;
+    pattern.warn_if_ambiguous_12h(*state.warn_whenever)?;
+    let locale = pattern.locale(locale)?;
+    format_compiled(slf, &pattern, locale)
+}
+
+pub(crate) fn format_compiled(
+    slf: &ZonedDateTime,
+    pattern: &pattern::CompiledPattern,
+    locale: &'static Locale,
+) -> PyReturn {
     let meta = slf.tz.meta_for_instant(slf.to_instant().epoch);
     // SAFETY: TzAbbrev always contains valid ASCII bytes
     let abbrev_str = unsafe { std::str::from_utf8_unchecked(meta.abbrev.as_bytes()) };
@@ -1225,13 +1372,14 @@ fn format(cls: PyClass<ZonedDateTime>, slf: &ZonedDateTime, pattern_obj: PyObj)
         &slf.to_plain()
             .pattern_values()
             .with_offset(slf.offset)
-            .with_timezone(tz_key, abbrev_str),
+            .with_timezone(tz_key, abbrev_str)
+            .with_locale(locale),
     )
 }
 
 fn __format__(cls: PyClass<ZonedDateTime>, slf: &ZonedDateTime, spec_obj: PyObj) -> PyReturn {
     if spec_obj.is_truthy()? {
-        format(cls, slf, spec_obj)
+        format_pattern(cls, slf, spec_obj, None, pattern::Style::Pattern)
     } else {
         __str__(cls.into(), slf)
     }
@@ -1246,10 +1394,22 @@ fn parse(cls: PyClass<ZonedDateTime>, args: &[PyObj], kwargs: &mut IterKwargs) -
 
     let state = cls.state();
     let mut fmt_obj = None;
+    let mut locale_obj = None;
+    let mut style = pattern::Style::Pattern;
+    let mut lenient = false;
+    let mut pivot_year = None;
     let mut dis = Disambiguation::Compatible;
     handle_kwargs("parse", kwargs, |key, value, eq| {
         if eq(key, *state.str_format) {
             fmt_obj = Some(value);
+        } else if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_style) {
+            style = pattern::Style::from_py(value, state)?;
+        } else if eq(key, *state.str_lenient) {
+            lenient = value.expect_bool("lenient")?;
+        } else if eq(key, *state.str_pivot_year) {
+            pivot_year = pattern::pivot_year_from_py(value)?;
         } else if eq(key, *state.str_disambiguate) {
             dis = Disambiguation::from_py(value, state)?;
         } else {
@@ -1261,23 +1421,40 @@ fn parse(cls: PyClass<ZonedDateTime>, args: &[PyObj], kwargs: &mut IterKwargs) -
     let fmt_obj = fmt_obj.ok_or_else(|| {
         raise_type_err::<(), _>("parse() requires 'format' keyword argument").unwrap_err()
     })?;
-    let fmt_pystr = fmt_obj
-        .cast_exact::<PyStr>()
-        .ok_or_type_err("format must be str")?;
-    let fmt_bytes = fmt_pystr.as_utf8()?;
+    let locale = Locale::from_py_opt(locale_obj)?;
+    let formats = pattern::Formats::compile(
+        state,
+        fmt_obj,
+        style,
+        pattern::CategorySet::DATE_TIME_OFFSET_TZ,
+        "ZonedDateTime",
+    )?;
+    formats.check_locale(locale)?;
+    formats.check_pivot_year(pivot_year)?;
+    from_parsed(
+        cls,
+        formats
+            .parse(s, locale, lenient, pivot_year)
+            .map_err(|e| e.into_exc(*state.exc_parse, s_pystr))?,
+        dis,
+    )
+}
 
-    let pattern = pattern::CompiledPattern::compile(fmt_bytes).into_value_err()?;
-    pattern.validate(pattern::CategorySet::DATE_TIME_OFFSET_TZ, "ZonedDateTime")?;
-    let parsed = pattern.parse(s).into_value_err()?;
+/// Create a `ZonedDateTime` from the result of parsing a pattern
+pub(crate) fn from_parsed(
+    cls: PyClass<ZonedDateTime>,
+    parsed: pattern::ParseState,
+    dis: Disambiguation,
+) -> PyReturn {
+    let state = cls.state();
 
     let tz_id = parsed
         .tz_id
         .as_deref()
         .ok_or_value_err("ZonedDateTime.parse() pattern must include a timezone ID field (VV)")?;
 
-    let date = parsed.date("Pattern must include year, month, and day fields")?;
-    parsed.validate_weekday(date)?;
-    let dt = date.at(parsed.time()?);
+    let date = parsed.date()?;
+    let dt = date.at(parsed.time());
     let tz = state.tz_store.get(tz_id)?;
     // NOTE: we can't reuse resolve_in() because we need to outright
     // reject invalid offsets, rather than just disambiguate them.
@@ -1335,6 +1512,11 @@ static mut METHODS: &[PyMethodDef] = &[
     method0!(ZonedDateTime, in_leap_year, doc::LOCALTIME_IN_LEAP_YEAR),
     method1!(ZonedDateTime, start_of, doc::ZONEDDATETIME_START_OF),
     method1!(ZonedDateTime, end_of, doc::ZONEDDATETIME_END_OF),
+    method_kwargs!(
+        ZonedDateTime,
+        format_rfc2822,
+        doc::ZONEDDATETIME_FORMAT_RFC2822
+    ),
     method_kwargs!(ZonedDateTime, format_iso, doc::ZONEDDATETIME_FORMAT_ISO),
     classmethod1!(ZonedDateTime, parse_iso, doc::ZONEDDATETIME_PARSE_ISO),
     classmethod1!(ZonedDateTime, now, doc::ZONEDDATETIME_NOW),
@@ -1397,6 +1579,11 @@ static mut METHODS: &[PyMethodDef] = &[
     ),
     method0!(ZonedDateTime, dst_offset, doc::ZONEDDATETIME_DST_OFFSET),
     method0!(ZonedDateTime, tz_abbrev, doc::ZONEDDATETIME_TZ_ABBREV),
+    method0!(
+        ZonedDateTime,
+        offset_period,
+        doc::ZONEDDATETIME_OFFSET_PERIOD
+    ),
     classmethod_kwargs!(
         ZonedDateTime,
         from_timestamp,
@@ -1423,7 +1610,8 @@ static mut METHODS: &[PyMethodDef] = &[
     method_kwargs!(ZonedDateTime, round, doc::ZONEDDATETIME_ROUND),
     method_kwargs!(ZonedDateTime, since, doc::ZONEDDATETIME_SINCE),
     method_kwargs!(ZonedDateTime, until, doc::ZONEDDATETIME_UNTIL),
-    method1!(ZonedDateTime, format, doc::ZONEDDATETIME_FORMAT),
+    method_kwargs!(ZonedDateTime, humanize, doc::ZONEDDATETIME_HUMANIZE),
+    method_kwargs!(ZonedDateTime, format, doc::ZONEDDATETIME_FORMAT),
     method1!(ZonedDateTime, __format__, c""),
     classmethod_kwargs!(ZonedDateTime, parse, doc::ZONEDDATETIME_PARSE),
     classmethod_kwargs!(
@@ -0,0 +1,560 @@
+//! Byte-level CBOR (RFC 8949) codecs for the standard date and time tags.
+//!
+//! | Tag | Content | Types |
+//! | ---: | --- | --- |
+//! | 0 | RFC 3339 text | Instant, OffsetDateTime |
+//! | 1 | seconds since the UNIX epoch (integer or float) | Instant |
+//! | 1001 | extended time (RFC 9581): map of seconds and fraction | Instant |
+//! | 1002 | duration (RFC 9581): like 1001, but relative | TimeDelta |
+//!
+//! Only definite-length items are supported.
+//! Decoding requires the input to consist of exactly one tagged item.
+
+use crate::{
+    common::fmt::{self, Chunk},
+    domain::{
+        instant::Instant, offset_datetime::OffsetDateTime, scalar::NS_PER_SEC,
+        time_delta::TimeDelta,
+    },
+};
+
+pub(crate) const INVALID_DATA: &str = "invalid CBOR data";
+
+pub(crate) const TAG_RFC3339: u64 = 0;
+pub(crate) const TAG_EPOCH: u64 = 1;
+pub(crate) const TAG_EXTENDED_TIME: u64 = 1001;
+pub(crate) const TAG_DURATION: u64 = 1002;
+
+const MAJOR_UINT: u8 = 0;
+const MAJOR_NEGINT: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+// Map keys of the extended time and duration tags
+const KEY_SECONDS: i64 = 1;
+const KEY_MILLIS: i64 = -3;
+const KEY_MICROS: i64 = -6;
+const KEY_NANOS: i64 = -9;
+
+fn write_head(out: &mut Vec<u8>, major: u8, arg: u64) {
+    let m = major << 5;
+    match arg {
+        0..=23 => out.push(m | arg as u8),
+        24..=0xff => out.extend([m | 24, arg as u8]),
+        0x100..=0xffff => {
+            out.push(m | 25);
+            out.extend((arg as u16).to_be_bytes());
+        }
+        0x1_0000..=0xffff_ffff => {
+            out.push(m | 26);
+            out.extend((arg as u32).to_be_bytes());
+        }
+        _ => {
+            out.push(m | 27);
+            out.extend(arg.to_be_bytes());
+        }
+    }
+}
+
+fn write_int(out: &mut Vec<u8>, n: i64) {
+    if n >= 0 {
+        write_head(out, MAJOR_UINT, n as u64);
+    } else {
+        // CBOR encodes negative integers as -1 - n
+        write_head(out, MAJOR_NEGINT, !n as u64);
+    }
+}
+
+fn write_text(out: &mut Vec<u8>, text: impl Chunk) {
+    write_head(out, MAJOR_TEXT, text.len() as u64);
+    text.write(out);
+}
+
+/// A map of seconds and (optional) nanoseconds, as used by tags 1001 and 1002
+fn write_secs_nanos_map(out: &mut Vec<u8>, secs: i64, nanos: u32) {
+    write_head(out, MAJOR_MAP, if nanos == 0 { 1 } else { 2 });
+    write_int(out, KEY_SECONDS);
+    write_int(out, secs);
+    if nanos != 0 {
+        write_int(out, KEY_NANOS);
+        write_int(out, nanos.into());
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+/// The time in the map of a tag 1001 or 1002 item
+enum SecsNanos {
+    /// An integer number of seconds, plus an optional fraction
+    Nanos(i128),
+    /// A floating point number of seconds, without fraction
+    FloatSecs(f64),
+}
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Option<&[u8]> {
+        let (head, rest) = self.0.split_at_checked(n)?;
+        self.0 = rest;
+        Some(head)
+    }
+
+    /// Read the major type, additional info, and argument of an item's head
+    fn head(&mut self) -> Option<(u8, u8, u64)> {
+        let initial = self.take(1)?[0];
+        let (major, info) = (initial >> 5, initial & 0x1f);
+        let arg = match info {
+            0..=23 => info.into(),
+            24 => self.take(1)?[0].into(),
+            25 => u16::from_be_bytes(self.take(2)?.try_into().unwrap()).into(),
+            26 => u32::from_be_bytes(self.take(4)?.try_into().unwrap()).into(),
+            27 => u64::from_be_bytes(self.take(8)?.try_into().unwrap()),
+            // Reserved values and indefinite lengths
+            _ => None?,
+        };
+        Some((major, info, arg))
+    }
+
+    fn number(&mut self) -> Option<Number> {
+        Some(match self.head()? {
+            (MAJOR_UINT, _, n) => Number::Int(i64::try_from(n).ok()?),
+            (MAJOR_NEGINT, _, n) => Number::Int(!i64::try_from(n).ok()?),
+            (MAJOR_SIMPLE, 25, bits) => Number::Float(f16_to_f64(bits as u16)),
+            (MAJOR_SIMPLE, 26, bits) => Number::Float(f32::from_bits(bits as u32).into()),
+            (MAJOR_SIMPLE, 27, bits) => Number::Float(f64::from_bits(bits)),
+            _ => None?,
+        })
+    }
+
+    fn int(&mut self) -> Option<i64> {
+        match self.number()? {
+            Number::Int(n) => Some(n),
+            Number::Float(_) => None,
+        }
+    }
+
+    fn text(&mut self) -> Option<&[u8]> {
+        match self.head()? {
+            (MAJOR_TEXT, _, len) => self.take(usize::try_from(len).ok()?),
+            _ => None,
+        }
+    }
+
+    /// Skip a complete data item, including any nested items
+    fn skip(&mut self) -> Option<()> {
+        // Counting the pending items (instead of recursing)
+        // keeps deeply nested input from overflowing the stack
+        let mut pending: u64 = 1;
+        while pending > 0 {
+            pending -= 1;
+            match self.head()? {
+                (MAJOR_BYTES | MAJOR_TEXT, _, len) => {
+                    self.take(usize::try_from(len).ok()?)?;
+                }
+                (MAJOR_ARRAY, _, len) => pending = pending.checked_add(len)?,
+                (MAJOR_MAP, _, len) => pending = pending.checked_add(len.checked_mul(2)?)?,
+                (MAJOR_TAG, _, _) => pending += 1,
+                // Integers and simple values have no content beyond the head
+                _ => {}
+            }
+        }
+        Some(())
+    }
+
+    /// Read the map of a tag 1001 or 1002 item.
+    /// The base time may be an integer or a float, but only an integer
+    /// may have a fraction. Unknown keys are ignored if they're elective
+    /// (non-negative), but rejected if they're critical (negative).
+    fn secs_nanos_map(&mut self) -> Option<SecsNanos> {
+        let (MAJOR_MAP, _, len) = self.head()? else {
+            return None;
+        };
+        let mut secs = None;
+        let mut nanos = None;
+        for _ in 0..len {
+            match self.int()? {
+                KEY_SECONDS if secs.is_none() => secs = Some(self.number()?),
+                key @ (KEY_MILLIS | KEY_MICROS | KEY_NANOS) if nanos.is_none() => {
+                    let scale = 10_i64.pow((key + 9) as u32);
+                    let fraction = self.int()?;
+                    if !(0..NS_PER_SEC as i64 / scale).contains(&fraction) {
+                        return None;
+                    }
+                    nanos = Some(fraction * scale);
+                }
+                KEY_SECONDS | KEY_MILLIS | KEY_MICROS | KEY_NANOS => return None,
+                key if key >= 0 => self.skip()?,
+                _ => return None,
+            }
+        }
+        match (secs?, nanos) {
+            (Number::Int(s), n) => Some(SecsNanos::Nanos(
+                i128::from(s) * i128::from(NS_PER_SEC) + i128::from(n.unwrap_or(0)),
+            )),
+            (Number::Float(f), None) => Some(SecsNanos::FloatSecs(f)),
+            (Number::Float(_), Some(_)) => None,
+        }
+    }
+
+    /// Read a tag, and the reader for its content
+    fn tag(data: &[u8]) -> Option<(u64, Reader<'_>)> {
+        let mut reader = Reader(data);
+        match reader.head()? {
+            (MAJOR_TAG, _, tag) => Some((tag, reader)),
+            _ => None,
+        }
+    }
+
+    fn finish<T>(self, value: T) -> Option<T> {
+        self.0.is_empty().then_some(value)
+    }
+}
+
+/// Convert the bits of an IEEE 754 half-precision float
+fn f16_to_f64(bits: u16) -> f64 {
+    let exp = (bits >> 10) & 0x1f;
+    let mant = f64::from(bits & 0x3ff);
+    let value = match exp {
+        0 => mant * 2f64.powi(-24),
+        31 if mant == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (mant + 1024.0) * 2f64.powi(i32::from(exp) - 25),
+    };
+    if bits & 0x8000 != 0 { -value } else { value }
+}
+
+/// Encode an instant with the given tag (0, 1, or 1001).
+/// Tag 1 uses an integer if there are no fractional seconds,
+/// and a float (which may lose precision) otherwise.
+/// The float is never rounded up into the next second,
+/// so that it stays in range (e.g. for `Instant::MAX`).
+pub(crate) fn encode_instant(value: Instant, tag: u64) -> Vec<u8> {
+    let mut out = Vec::with_capacity(48);
+    write_head(&mut out, MAJOR_TAG, tag);
+    let secs = value.epoch.get();
+    let nanos = value.subsec.get() as u32;
+    match tag {
+        TAG_RFC3339 => {
+            let dt = value.to_utc_plain();
+            write_text(
+                &mut out,
+                (
+                    dt.date.iso_format(false),
+                    b'T',
+                    dt.time.iso_format(fmt::Precision::Auto, false),
+                    b'Z',
+                ),
+            );
+        }
+        TAG_EPOCH if nanos == 0 => write_int(&mut out, secs),
+        TAG_EPOCH => {
+            // Always a 64-bit float, to keep as much precision as possible
+            out.push(MAJOR_SIMPLE << 5 | 27);
+            let f = secs as f64 + f64::from(nanos) / 1e9;
+            let f = if f < (secs + 1) as f64 {
+                f
+            } else {
+                f.next_down()
+            };
+            out.extend(f.to_be_bytes());
+        }
+        TAG_EXTENDED_TIME => write_secs_nanos_map(&mut out, secs, nanos),
+        _ => unreachable!("unsupported tag"),
+    }
+    out
+}
+
+/// Decode an instant from tag 0, 1, or 1001
+pub(crate) fn decode_instant(data: &[u8]) -> Option<Instant> {
+    let (tag, mut reader) = Reader::tag(data)?;
+    let value = match tag {
+        TAG_RFC3339 => parse_rfc3339(reader.text()?)?.to_instant(),
+        TAG_EPOCH => match reader.number()? {
+            Number::Int(n) => Instant::from_timestamp(n)?,
+            Number::Float(f) => Instant::from_timestamp_f64(f)?,
+        },
+        TAG_EXTENDED_TIME => match reader.secs_nanos_map()? {
+            SecsNanos::Nanos(n) => Instant::from_timestamp_nanos(n)?,
+            SecsNanos::FloatSecs(f) => Instant::from_timestamp_f64(f)?,
+        },
+        _ => None?,
+    };
+    reader.finish(value)
+}
+
+/// Encode as tag 0 (RFC 3339 text).
+/// Returns `None` if the offset has seconds, which RFC 3339 doesn't allow.
+pub(crate) fn encode_offset(value: OffsetDateTime) -> Option<Vec<u8>> {
+    if value.offset.get() % 60 != 0 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(48);
+    write_head(&mut out, MAJOR_TAG, TAG_RFC3339);
+    write_text(
+        &mut out,
+        (
+            value.date.iso_format(false),
+            b'T',
+            value.time.iso_format(fmt::Precision::Auto, false),
+            value.offset.iso_format(false),
+        ),
+    );
+    Some(out)
+}
+
+/// Decode from tag 0 (RFC 3339 text)
+pub(crate) fn decode_offset(data: &[u8]) -> Option<OffsetDateTime> {
+    let (TAG_RFC3339, mut reader) = Reader::tag(data)? else {
+        return None;
+    };
+    let value = parse_rfc3339(reader.text()?)?;
+    reader.finish(value)
+}
+
+/// Parse RFC 3339 text, which is a strict subset of ISO 8601:
+/// extended format with seconds, and an optional fraction of at most
+/// nine digits (the precision we support).
+fn parse_rfc3339(s: &[u8]) -> Option<OffsetDateTime> {
+    const SHAPE: &[u8; 19] = b"0000-00-00T00:00:00";
+    let (head, mut rest) = s.split_at_checked(SHAPE.len())?;
+    if !head.iter().zip(SHAPE).all(|(&b, &c)| match c {
+        b'0' => b.is_ascii_digit(),
+        b'T' => b.eq_ignore_ascii_case(&b'T'),
+        _ => b == c,
+    }) {
+        return None;
+    }
+    if let [b'.', tail @ ..] = rest {
+        let digits = tail.iter().take_while(|b| b.is_ascii_digit()).count();
+        if !(1..=9).contains(&digits) {
+            return None;
+        }
+        rest = &tail[digits..];
+    }
+    match rest {
+        [b'Z' | b'z'] => {}
+        [b'+' | b'-', h1, h2, b':', m1, m2]
+            if [h1, h2, m1, m2].iter().all(|b| b.is_ascii_digit()) => {}
+        _ => None?,
+    }
+    OffsetDateTime::parse_iso(&s.to_ascii_uppercase())
+}
+
+/// Encode as tag 1002 (duration)
+pub(crate) fn encode_time_delta(value: TimeDelta) -> Vec<u8> {
+    let mut out = Vec::with_capacity(24);
+    write_head(&mut out, MAJOR_TAG, TAG_DURATION);
+    write_secs_nanos_map(&mut out, value.secs.get(), value.subsec.get() as u32);
+    out
+}
+
+/// Decode from tag 1002 (duration)
+pub(crate) fn decode_time_delta(data: &[u8]) -> Option<TimeDelta> {
+    let (TAG_DURATION, mut reader) = Reader::tag(data)? else {
+        return None;
+    };
+    let value = match reader.secs_nanos_map()? {
+        SecsNanos::Nanos(n) => TimeDelta::from_nanos(n)?,
+        SecsNanos::FloatSecs(f) => TimeDelta::from_nanos_f64(f * NS_PER_SEC as f64)?,
+    };
+    reader.finish(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::scalar::{EpochSecs, SubSecNanos};
+
+    fn instant(secs: i64, nanos: i32) -> Instant {
+        Instant {
+            epoch: EpochSecs::new(secs).unwrap(),
+            subsec: SubSecNanos::new(nanos).unwrap(),
+        }
+    }
+
+    #[test]
+    fn instant_roundtrip() {
+        let cases: &[(Instant, u64, &[u8])] = &[
+            // Example from RFC 8949, appendix A
+            (
+                instant(1363896240, 0),
+                TAG_EPOCH,
+                b"\xc1\x1a\x51\x4b\x67\xb0",
+            ),
+            (
+                instant(1363896240, 500_000_000),
+                TAG_EPOCH,
+                b"\xc1\xfb\x41\xd4\x52\xd9\xec\x20\x00\x00",
+            ),
+            (instant(-1, 0), TAG_EPOCH, b"\xc1\x20"),
+            (
+                instant(1363896240, 0),
+                TAG_RFC3339,
+                b"\xc0\x742013-03-21T20:04:00Z",
+            ),
+            (
+                instant(1363896240, 0),
+                TAG_EXTENDED_TIME,
+                b"\xd9\x03\xe9\xa1\x01\x1a\x51\x4b\x67\xb0",
+            ),
+            (
+                instant(-1, 1),
+                TAG_EXTENDED_TIME,
+                b"\xd9\x03\xe9\xa2\x01\x20\x28\x01",
+            ),
+        ];
+        for &(value, tag, bytes) in cases {
+            assert_eq!(encode_instant(value, tag), bytes);
+            assert_eq!(decode_instant(bytes), Some(value));
+        }
+    }
+
+    #[test]
+    fn instant_decode_variants() {
+        let t = instant(1363896240, 500_000_000);
+        // fraction in milliseconds
+        assert_eq!(
+            decode_instant(b"\xd9\x03\xe9\xa2\x01\x1a\x51\x4b\x67\xb0\x22\x19\x01\xf4"),
+            Some(t)
+        );
+        // keys in a different order
+        assert_eq!(
+            decode_instant(b"\xd9\x03\xe9\xa2\x22\x19\x01\xf4\x01\x1a\x51\x4b\x67\xb0"),
+            Some(t)
+        );
+        // half and single precision floats
+        assert_eq!(
+            decode_instant(b"\xc1\xf9\x3e\x00"),
+            Some(instant(1, 500_000_000))
+        );
+        assert_eq!(
+            decode_instant(b"\xc1\xfa\x3f\xc0\x00\x00"),
+            Some(instant(1, 500_000_000))
+        );
+        // RFC 3339 with an offset
+        assert_eq!(
+            decode_instant(b"\xc0\x78\x1b2013-03-21T21:04:00.5+01:00"),
+            Some(t)
+        );
+        // RFC 3339 in lowercase
+        assert_eq!(decode_instant(b"\xc0\x762013-03-21t20:04:00.5z"), Some(t));
+        // extended time with a float base time
+        assert_eq!(
+            decode_instant(b"\xd9\x03\xe9\xa1\x01\xfb\x41\xd4\x52\xd9\xec\x20\x00\x00"),
+            Some(t)
+        );
+        // unknown elective keys, with values of any type
+        assert_eq!(
+            decode_instant(
+                b"\xd9\x03\xe9\xa4\x01\x1a\x51\x4b\x67\xb0\x22\x19\x01\xf4\
+                  \x0a\x82\x61x\xa1\x00\xc1\x00\x18\x2a\x43abc"
+            ),
+            Some(t)
+        );
+    }
+
+    #[test]
+    fn instant_epoch_float_in_range() {
+        let max = Instant {
+            epoch: EpochSecs::MAX,
+            subsec: SubSecNanos::new(999_999_999).unwrap(),
+        };
+        let decoded = decode_instant(&encode_instant(max, TAG_EPOCH)).unwrap();
+        assert_eq!(decoded.epoch, EpochSecs::MAX);
+        let t = instant(EpochSecs::MIN.get(), 500_000_000);
+        assert_eq!(decode_instant(&encode_instant(t, TAG_EPOCH)), Some(t));
+    }
+
+    #[test]
+    fn rfc3339_strict() {
+        for s in [
+            &b"2013-03-21T21:04:00+01:00"[..],
+            b"2013-03-21t21:04:00.123456789z",
+            b"2013-03-21T21:04:00-00:00",
+        ] {
+            assert!(parse_rfc3339(s).is_some(), "{s:?}");
+        }
+        for s in [
+            &b"2013-03-21 21:04:00+01:00"[..],
+            b"2013-03-21T21:04+01:00",
+            b"20130321T210400+0100",
+            b"2013-03-21T21:04:00+01",
+            b"2013-03-21T21:04:00,5Z",
+            b"2013-03-21T21:04:00.Z",
+            b"2013-03-21T21:04:00.1234567891Z",
+            b"2013-03-21T21:04:00",
+            b"2013-03-21T21:04:00Z ",
+        ] {
+            assert!(parse_rfc3339(s).is_none(), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn instant_decode_invalid() {
+        for bytes in [
+            &b""[..],
+            b"\xc1",
+            // not a tag
+            b"\x1a\x51\x4b\x67\xb0",
+            // unsupported tag
+            b"\xc2\x1a\x51\x4b\x67\xb0",
+            // trailing data
+            b"\xc1\x1a\x51\x4b\x67\xb0\x00",
+            // truncated
+            b"\xc1\x1a\x51\x4b\x67",
+            // out of range
+            b"\xc1\x1b\x7f\xff\xff\xff\xff\xff\xff\xff",
+            b"\xc1\xf9\x7c\x00",
+            b"\xc1\xf9\x7e\x00",
+            // indefinite length text
+            b"\xc0\x7f\x61\x31\xff",
+            // invalid text
+            b"\xc0\x63abc",
+            // missing seconds, duplicate or unknown critical keys,
+            // fraction out of range
+            b"\xd9\x03\xe9\xa1\x28\x01",
+            b"\xd9\x03\xe9\xa2\x01\x01\x01\x01",
+            b"\xd9\x03\xe9\xa2\x01\x01\x20\x01",
+            b"\xd9\x03\xe9\xa2\x01\x01\x22\x19\x03\xe8",
+            b"\xd9\x03\xe9\xa2\x01\x01\x28\x21",
+            b"\xd9\x03\xe9\xa3\x01\x01\x28\x01\x28\x01",
+            // float base time with a fraction
+            b"\xd9\x03\xe9\xa2\x01\xf9\x3e\x00\x28\x01",
+            // truncated or indefinite length value of an elective key
+            b"\xd9\x03\xe9\xa2\x01\x01\x0a\x82\x00",
+            b"\xd9\x03\xe9\xa2\x01\x01\x0a\x9f\x00\xff",
+            b"\xd9\x03\xe9\xa2\x01\x01\x0a\x9b\xff\xff\xff\xff\xff\xff\xff\xff",
+        ] {
+            assert_eq!(decode_instant(bytes), None, "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn offset_and_delta() {
+        let odt = OffsetDateTime::parse_iso(b"2013-03-21T21:04:00.5+01:00").unwrap();
+        let bytes = b"\xc0\x78\x1b2013-03-21T21:04:00.5+01:00";
+        assert_eq!(encode_offset(odt).unwrap(), bytes);
+        assert_eq!(decode_offset(bytes), Some(odt));
+        assert_eq!(decode_offset(b"\xc1\x1a\x51\x4b\x67\xb0"), None);
+        let odt = OffsetDateTime::parse_iso(b"2013-03-21T21:04:00+01:00:30").unwrap();
+        assert_eq!(encode_offset(odt), None);
+
+        let delta = TimeDelta::from_nanos(-1_500_000_000).unwrap();
+        let bytes = b"\xd9\x03\xea\xa2\x01\x21\x28\x1a\x1d\xcd\x65\x00";
+        assert_eq!(encode_time_delta(delta), bytes);
+        assert_eq!(decode_time_delta(bytes), Some(delta));
+        assert_eq!(decode_time_delta(b"\xd9\x03\xe9\xa1\x01\x01"), None);
+        assert_eq!(
+            decode_time_delta(b"\xd9\x03\xea\xa1\x01\xf9\xbe\x00"),
+            Some(delta)
+        );
+    }
+}
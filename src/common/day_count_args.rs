@@ -0,0 +1,62 @@
+//! Python argument parsing for day count conversions.
+use crate::{
+    domain::{
+        instant::Instant,
+        scalar::{DayCount, UnixDays},
+    },
+    py::*,
+    pymodule::State,
+};
+
+// Excel's 1900 system pretends 1900 was a leap year
+const EXCEL_SERIAL_60_MSG: &str = "Excel serial 60 (1900-02-29) doesn't exist";
+
+/// Parse the `epoch_1904` keyword argument of the Excel serial methods
+pub(crate) fn excel_system(
+    fname: &str,
+    state: &State,
+    kwargs: &mut IterKwargs,
+) -> PyResult<DayCount> {
+    let mut epoch_1904 = false;
+    handle_kwargs(fname, kwargs, |key, value, eq| {
+        if eq(key, *state.str_epoch_1904) {
+            epoch_1904 = value.expect_bool("epoch_1904")?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    })?;
+    Ok(if epoch_1904 {
+        DayCount::Excel1904
+    } else {
+        DayCount::Excel1900
+    })
+}
+
+/// Convert an int to i64, reporting overflow as a range error
+/// (like the pure-Python version) instead of an `OverflowError`.
+fn int_to_i64(i: PyInt) -> PyResult<i64> {
+    i.to_i64().catch(exc_overflow_error())?.ok_or_range_err()
+}
+
+pub(crate) fn parse_day_count(count: DayCount, obj: PyObj) -> PyResult<UnixDays> {
+    let n = int_to_i64(obj.expect_int("day number")?)?;
+    if count == DayCount::Excel1900 && n == 60 {
+        raise_value_err(EXCEL_SERIAL_60_MSG)?
+    }
+    UnixDays::from_day_count(count, n).ok_or_range_err()
+}
+
+pub(crate) fn parse_day_count_f64(count: DayCount, obj: PyObj) -> PyResult<Instant> {
+    let days = if let Some(i) = obj.cast_allow_subclass::<PyInt>() {
+        int_to_i64(i)? as f64
+    } else if let Some(f) = obj.cast_allow_subclass::<PyFloat>() {
+        f.to_f64()?
+    } else {
+        raise_type_err("day number must be an integer or float")?
+    };
+    if count == DayCount::Excel1900 && (60.0..61.0).contains(&days) {
+        raise_value_err(EXCEL_SERIAL_60_MSG)?
+    }
+    Instant::from_day_count_f64(count, days).ok_or_range_err()
+}
@@ -180,3 +180,29 @@ pub(crate) fn format_datetime_iso(
         suffix,
     ))
 }
+
+/// Parse the arguments of the delta types' `format_iso()`.
+/// Returns whether to use basic format, if the alternative format is requested.
+pub(crate) fn delta_iso_alternative(
+    state: &State,
+    args: &[PyObj],
+    kwargs: &mut IterKwargs,
+) -> PyResult<Option<bool>> {
+    handle_no_args("format_iso", args)?;
+    let mut alternative = false;
+    let mut basic = false;
+    handle_kwargs("format_iso", kwargs, |key, value, eq| {
+        if eq(key, *state.str_alternative) {
+            alternative = value.expect_bool("alternative")?;
+        } else if eq(key, *state.str_basic) {
+            basic = value.expect_bool("basic")?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    if basic && !alternative {
+        raise_value_err("basic format is only available with alternative=True")?;
+    }
+    Ok(alternative.then_some(basic))
+}
@@ -0,0 +1,75 @@
+//! Python argument parsing for humanizing differences.
+
+use crate::{
+    common::locale::Phrases,
+    domain::{humanize::Unit, round::Mode},
+    py::*,
+    pymodule::State,
+};
+
+impl Unit {
+    fn from_py(v: PyObj, state: &State) -> PyResult<Self> {
+        match_interned_str(
+            "granularity",
+            v,
+            &[
+                (*state.str_years, Self::Years),
+                (*state.str_months, Self::Months),
+                (*state.str_weeks, Self::Weeks),
+                (*state.str_days, Self::Days),
+                (*state.str_hours, Self::Hours),
+                (*state.str_minutes, Self::Minutes),
+                (*state.str_seconds, Self::Seconds),
+            ],
+        )
+    }
+}
+
+/// The arguments shared by all `humanize()` methods
+#[derive(Debug)]
+pub(crate) struct HumanizeArgs {
+    pub(crate) granularity: Unit,
+    pub(crate) mode: Mode,
+    pub(crate) phrases: Phrases,
+}
+
+impl HumanizeArgs {
+    /// Parse the shared arguments, passing any other keyword arguments to `extra`.
+    pub(crate) fn parse_with<F>(
+        fname: &str,
+        args: &[PyObj],
+        kwargs: &mut IterKwargs,
+        state: &State,
+        mut extra: F,
+    ) -> PyResult<Self>
+    where
+        F: FnMut(PyObj, PyObj, StrEqFn) -> PyResult<bool>,
+    {
+        handle_no_args(fname, args)?;
+        let mut granularity = Unit::Seconds;
+        let mut mode = Mode::Trunc;
+        let mut locale = None;
+        handle_kwargs(fname, kwargs, |k, v, eq| {
+            if eq(k, *state.str_granularity) {
+                granularity = Unit::from_py(v, state)?;
+            } else if eq(k, *state.str_round_mode) {
+                mode = Mode::from_py_named("round_mode", v, &state.round_mode_strs)?;
+            } else if eq(k, *state.str_locale) {
+                locale = Some(v);
+            } else {
+                return extra(k, v, eq);
+            }
+            Ok(true)
+        })?;
+        Ok(Self {
+            granularity,
+            mode,
+            phrases: Phrases::from_py(locale, &state.humanize_locales)?,
+        })
+    }
+
+    /// Format the selected unit and amount
+    pub(crate) fn format(&self, unit: Unit, amount: i64) -> PyReturn {
+        self.phrases.format(unit.table_index(), amount).to_py()
+    }
+}
@@ -0,0 +1,155 @@
+//! Reading and writing the timestamp portion of time-ordered IDs:
+//! UUIDv7, ULID, and Snowflake IDs.
+//! All of these embed a UNIX timestamp in milliseconds.
+
+/// Crockford's base32 alphabet, as used by ULIDs
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// UUIDv7 and ULIDs both have a 48-bit timestamp
+const MAX_MILLIS: u64 = (1 << 48) - 1;
+
+/// The number of bits below the timestamp in a Snowflake ID
+const SNOWFLAKE_SHIFT: u32 = 22;
+
+/// Extract the timestamp of a UUIDv7, given its 16 bytes.
+/// Returns `None` if it isn't a (RFC 9562 variant) version 7 UUID.
+pub(crate) fn uuid7_millis(b: &[u8]) -> Option<i64> {
+    let b: &[u8; 16] = b.try_into().ok()?;
+    (b[6] >> 4 == 7 && b[8] >> 6 == 0b10)
+        .then(|| b[..6].iter().fold(0, |acc, &x| acc << 8 | i64::from(x)))
+}
+
+/// The smallest UUIDv7 with the given timestamp.
+/// All bits except the timestamp, version, and variant are zero.
+pub(crate) fn uuid7_min(millis: i64) -> Option<u128> {
+    let millis = u64::try_from(millis).ok().filter(|&m| m <= MAX_MILLIS)?;
+    Some(u128::from(millis) << 80 | 0x7000 << 64 | 0x8000_0000_0000_0000)
+}
+
+/// Extract the timestamp of a ULID string (case-insensitive)
+pub(crate) fn ulid_millis(s: &[u8]) -> Option<i64> {
+    if s.len() != 26 {
+        return None;
+    }
+    let mut millis = 0;
+    for (i, &c) in s.iter().enumerate() {
+        let value = CROCKFORD
+            .iter()
+            .position(|&x| x == c.to_ascii_uppercase())?;
+        if i < 10 {
+            millis = millis << 5 | value as i64;
+        }
+    }
+    // The first character may only hold 3 of its 5 bits
+    (millis <= MAX_MILLIS as i64).then_some(millis)
+}
+
+/// The smallest ULID with the given timestamp.
+/// The 80 bits of randomness are all zero.
+pub(crate) fn ulid_min(millis: i64) -> Option<String> {
+    let millis = u64::try_from(millis).ok().filter(|&m| m <= MAX_MILLIS)?;
+    let mut s = [b'0'; 26];
+    for (i, c) in s[..10].iter_mut().enumerate() {
+        *c = CROCKFORD[(millis >> (45 - 5 * i) & 31) as usize];
+    }
+    Some(s.iter().map(|&c| c as char).collect())
+}
+
+/// Extract the timestamp of a Snowflake ID,
+/// relative to the epoch (in UNIX milliseconds) of its issuer.
+pub(crate) fn snowflake_millis(id: i128, epoch_millis: i64) -> Option<i64> {
+    i64::try_from(id)
+        .ok()
+        .filter(|&id| id >= 0)
+        .and_then(|id| (id >> SNOWFLAKE_SHIFT).checked_add(epoch_millis))
+}
+
+/// The smallest Snowflake ID with the given timestamp.
+/// The worker ID and sequence number are zero.
+pub(crate) fn snowflake_min(millis: i64, epoch_millis: i64) -> Option<i64> {
+    millis
+        .checked_sub(epoch_millis)
+        .filter(|&m| (0..1 << (63 - SNOWFLAKE_SHIFT)).contains(&m))
+        .map(|m| m << SNOWFLAKE_SHIFT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid7() {
+        // Example from RFC 9562, appendix A.6
+        let uuid = 0x017F22E2_79B0_7CC3_98C4_DC0C0C07398F_u128;
+        assert_eq!(uuid7_millis(&uuid.to_be_bytes()), Some(0x017F22E279B0));
+        assert_eq!(
+            uuid7_min(0x017F22E279B0),
+            Some(0x017F22E2_79B0_7000_8000_000000000000)
+        );
+        assert_eq!(uuid7_millis(&uuid7_min(0).unwrap().to_be_bytes()), Some(0));
+        assert_eq!(
+            uuid7_millis(&uuid7_min(MAX_MILLIS as i64).unwrap().to_be_bytes()),
+            Some(MAX_MILLIS as i64)
+        );
+        assert_eq!(uuid7_min(-1), None);
+        assert_eq!(uuid7_min(1 << 48), None);
+        // wrong version, variant, or length
+        assert_eq!(uuid7_millis(&(uuid ^ 0x3 << 76).to_be_bytes()), None);
+        assert_eq!(uuid7_millis(&(uuid ^ 0x1 << 63).to_be_bytes()), None);
+        assert_eq!(uuid7_millis(&uuid.to_be_bytes()[1..]), None);
+    }
+
+    #[test]
+    fn test_ulid() {
+        // Example from the ULID spec
+        assert_eq!(
+            ulid_millis(b"01ARZ3NDEKTSV4RRFFQ69G5FAV"),
+            Some(1469922850259)
+        );
+        assert_eq!(
+            ulid_millis(b"01arz3ndektsv4rrffq69g5fav"),
+            Some(1469922850259)
+        );
+        assert_eq!(
+            ulid_min(1469922850259).unwrap(),
+            "01ARZ3NDEK0000000000000000"
+        );
+        assert_eq!(ulid_min(0).unwrap(), "00000000000000000000000000");
+        assert_eq!(
+            ulid_min(MAX_MILLIS as i64).unwrap(),
+            "7ZZZZZZZZZ0000000000000000"
+        );
+        assert_eq!(
+            ulid_millis(b"7ZZZZZZZZZZZZZZZZZZZZZZZZZ"),
+            Some(MAX_MILLIS as i64)
+        );
+        assert_eq!(ulid_min(-1), None);
+        assert_eq!(ulid_min(1 << 48), None);
+        // overflow, invalid characters, or wrong length
+        assert_eq!(ulid_millis(b"80000000000000000000000000"), None);
+        assert_eq!(ulid_millis(b"01ARZ3NDEKTSV4RRFFQ69G5FAU"), None);
+        assert_eq!(ulid_millis(b"01ARZ3NDEKTSV4RRFFQ69G5FA"), None);
+        assert_eq!(ulid_millis(b""), None);
+    }
+
+    #[test]
+    fn test_snowflake() {
+        // Example from Discord's API documentation
+        let discord_epoch = 1_420_070_400_000;
+        assert_eq!(
+            snowflake_millis(175928847299117063, discord_epoch),
+            Some(1462015105796)
+        );
+        assert_eq!(
+            snowflake_min(1462015105796, discord_epoch),
+            Some(175928847299117063 >> 22 << 22)
+        );
+        assert_eq!(snowflake_min(discord_epoch - 1, discord_epoch), None);
+        assert_eq!(
+            snowflake_min(discord_epoch + (1 << 41), discord_epoch),
+            None
+        );
+        assert_eq!(snowflake_millis(-1, discord_epoch), None);
+        assert_eq!(snowflake_millis(1 << 63, discord_epoch), None);
+    }
+}
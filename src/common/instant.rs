@@ -1,4 +1,8 @@
-use crate::{domain::instant::Instant, py::*, pymodule::State};
+use crate::{
+    domain::{instant::Instant, scalar::TickCount},
+    py::*,
+    pymodule::State,
+};
 
 pub(crate) fn extract_instant(obj: PyObj, state: &State) -> Option<Instant> {
     if let Some(i) = obj.extract(*state.instant_type) {
@@ -35,3 +39,32 @@ pub(crate) fn parse_timestamp_millis(obj: PyObj) -> PyResult<Instant> {
 pub(crate) fn parse_timestamp_nanos(obj: PyObj) -> PyResult<Instant> {
     Instant::from_timestamp_nanos(obj.expect_int("timestamp")?.to_i128()?).ok_or_range_err()
 }
+
+pub(crate) fn parse_tick_count(count: TickCount, obj: PyObj) -> PyResult<Instant> {
+    Instant::from_tick_count(count, obj.expect_int("timestamp")?.to_i128()?).ok_or_range_err()
+}
+
+pub(crate) fn parse_gps_nanos(obj: PyObj) -> PyResult<Instant> {
+    Instant::from_gps_nanos(obj.expect_int("timestamp")?.to_i128()?).ok_or_range_err()
+}
+
+pub(crate) fn parse_ntp(obj: PyObj) -> PyResult<Instant> {
+    u64::try_from(obj.expect_int("timestamp")?.to_i128()?)
+        .ok()
+        .and_then(Instant::from_ntp)
+        .ok_or_range_err()
+}
+
+pub(crate) fn parse_cocoa(obj: PyObj) -> PyResult<Instant> {
+    if let Some(i) = obj.cast_allow_subclass::<PyInt>() {
+        // Overflow is a range error, like in the pure-Python version
+        i.to_i64()
+            .catch(exc_overflow_error())?
+            .and_then(Instant::from_cocoa)
+    } else if let Some(f) = obj.cast_allow_subclass::<PyFloat>() {
+        Instant::from_cocoa_f64(f.to_f64()?)
+    } else {
+        raise_type_err("timestamp must be an integer or float")?
+    }
+    .ok_or_range_err()
+}
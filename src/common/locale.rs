@@ -0,0 +1,1634 @@
+//! Localized month, weekday, AM/PM, and era names for pattern formatting and parsing,
+//! and relative time phrases for humanizing.
+//!
+//! The tables are derived from the CLDR "format" context (i.e. the forms used
+//! within a date, such as the genitive "января" in Russian), in their
+//! "wide" and "abbreviated" widths. Relative time phrases use the CLDR
+//! "long" relative time patterns. Only the language subtag of a locale
+//! identifier is considered, so `"de-AT"` and `"de_CH"` both map to German.
+//! Users may register relative time phrases for other languages,
+//! or replace the built-in ones, with `register_humanize_locale()`.
+//!
+//! NOTE: keep these tables in sync with the Python implementation in `_locale.py`.
+
+use crate::{
+    common::sync::SyncCell,
+    py::{
+        PyObj, PyResult, PyStr,
+        exc::{RaiseExt, raise_value_err},
+    },
+};
+use ahash::AHashMap;
+use std::sync::Arc;
+
+/// Names for a single locale.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Locale {
+    pub(crate) tag: &'static str,
+    /// Abbreviated month names, January first
+    pub(crate) month_abbr: [&'static str; 12],
+    /// Full month names, January first
+    pub(crate) month_full: [&'static str; 12],
+    /// Abbreviated weekday names, Monday first
+    pub(crate) weekday_abbr: [&'static str; 7],
+    /// Full weekday names, Monday first
+    pub(crate) weekday_full: [&'static str; 7],
+    /// Short AM/PM markers, as used by the `a` specifier
+    pub(crate) am_pm_short: [&'static str; 2],
+    /// AM/PM markers, as used by the `aa` specifier
+    pub(crate) am_pm: [&'static str; 2],
+    /// Abbreviated era names (BC, AD), as used by the `N` specifier
+    pub(crate) era: [&'static str; 2],
+    /// Phrases such as "in 3 hours" or "3 hours ago"
+    pub(crate) relative: RelativeTime,
+}
+
+/// Relative time phrases for a single locale.
+/// Phrases are given per unit (years first, seconds last) and per plural
+/// category (one, few, other). `{0}` is replaced with the amount.
+///
+/// The built-in tables are static, while registered ones own their phrases.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct RelativeTime<S = &'static str> {
+    /// The phrase for a zero difference
+    pub(crate) now: S,
+    /// The names of the previous and next day
+    pub(crate) yesterday: S,
+    pub(crate) tomorrow: S,
+    /// Combines a day name (`{0}`) with a time (`{1}`)
+    pub(crate) at_time: S,
+    pub(crate) plural: PluralRule,
+    pub(crate) future: [[S; 3]; 7],
+    pub(crate) past: [[S; 3]; 7],
+}
+
+/// How a (positive) amount selects a plural category, following the
+/// CLDR rules for integers.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum PluralRule {
+    /// "one" for 1, "other" for everything else (e.g. English)
+    OneOther,
+    /// Russian and Ukrainian: 1, 21, 31... are "one";
+    /// 2-4, 22-24... are "few"; the rest are "other"
+    EastSlavic,
+    /// 1 is "one"; 2-4, 22-24... are "few"; the rest are "other"
+    Polish,
+    /// 1 is "one"; 2-4 are "few"; the rest are "other"
+    Czech,
+    /// No plural forms (e.g. Japanese)
+    Other,
+}
+
+impl PluralRule {
+    /// Look up a rule by its name in the Python API, e.g. `"one_other"`
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "one_other" => Self::OneOther,
+            "east_slavic" => Self::EastSlavic,
+            "polish" => Self::Polish,
+            "czech" => Self::Czech,
+            "other" => Self::Other,
+            _ => return None,
+        })
+    }
+
+    /// The plural category of an amount: 0 (one), 1 (few), or 2 (other)
+    pub(crate) fn category(self, n: u64) -> usize {
+        let few = (2..=4).contains(&(n % 10)) && !(12..=14).contains(&(n % 100));
+        match self {
+            Self::OneOther | Self::Polish | Self::Czech if n == 1 => 0,
+            Self::OneOther | Self::Other => 2,
+            Self::EastSlavic if n % 10 == 1 && n % 100 != 11 => 0,
+            Self::EastSlavic | Self::Polish if few => 1,
+            Self::Czech if (2..=4).contains(&n) => 1,
+            _ => 2,
+        }
+    }
+}
+
+impl<S: AsRef<str>> RelativeTime<S> {
+    /// Format an amount of a unit (index 0 for years, 6 for seconds).
+    /// Positive amounts are in the future, negative ones in the past.
+    pub(crate) fn format(&self, unit: usize, amount: i64) -> String {
+        if amount == 0 {
+            return self.now.as_ref().to_string();
+        }
+        let n = amount.unsigned_abs();
+        let phrases = if amount > 0 { &self.future } else { &self.past };
+        phrases[unit][self.plural.category(n)]
+            .as_ref()
+            .replace("{0}", &n.to_string())
+    }
+
+    /// Format a time on the previous (`-1`) or next (`1`) day,
+    /// e.g. "yesterday at 14:05"
+    pub(crate) fn format_day(&self, day: i8, time: &str) -> String {
+        let name = if day < 0 {
+            &self.yesterday
+        } else {
+            &self.tomorrow
+        };
+        self.at_time
+            .as_ref()
+            .replace("{0}", name.as_ref())
+            .replace("{1}", time)
+    }
+}
+
+/// The relative time phrases of a built-in or registered locale
+#[derive(Debug)]
+pub(crate) enum Phrases {
+    Builtin(&'static RelativeTime),
+    Registered(Arc<RelativeTime<Box<str>>>),
+}
+
+impl Phrases {
+    /// Interpret the value of a `locale=` argument for humanizing.
+    /// Registered phrases take precedence over the built-in ones.
+    /// `None` selects English.
+    pub(crate) fn from_py(obj: Option<PyObj>, registry: &Registry) -> PyResult<Self> {
+        let Some(obj) = obj.filter(|o| !o.is_none()) else {
+            return Ok(registry
+                .get("en")
+                .map_or(Self::Builtin(&EN.relative), Self::Registered));
+        };
+        let py_str = obj
+            .cast_exact::<PyStr>()
+            .ok_or_type_err("locale must be str")?;
+        let tag = py_str.as_str()?;
+        if let Some(phrases) = registry.get(tag) {
+            Ok(Self::Registered(phrases))
+        } else if let Some(loc) = Locale::lookup(tag) {
+            Ok(Self::Builtin(&loc.relative))
+        } else {
+            raise_value_err(format!("Unsupported locale: {obj}"))
+        }
+    }
+
+    pub(crate) fn format(&self, unit: usize, amount: i64) -> String {
+        match self {
+            Self::Builtin(r) => r.format(unit, amount),
+            Self::Registered(r) => r.format(unit, amount),
+        }
+    }
+
+    pub(crate) fn format_day(&self, day: i8, time: &str) -> String {
+        match self {
+            Self::Builtin(r) => r.format_day(day, time),
+            Self::Registered(r) => r.format_day(day, time),
+        }
+    }
+}
+
+/// Relative time phrases registered with `register_humanize_locale()`,
+/// by (lowercase) language subtag
+#[derive(Debug)]
+pub(crate) struct Registry(SyncCell<AHashMap<String, Arc<RelativeTime<Box<str>>>>>);
+
+impl Registry {
+    pub(crate) fn new() -> Self {
+        Self(SyncCell::new(AHashMap::new()))
+    }
+
+    /// Register phrases for a language, replacing any previous ones
+    pub(crate) fn set(&self, lang: &str, phrases: RelativeTime<Box<str>>) {
+        let key = language(lang).to_ascii_lowercase();
+        self.0.with_mut(|m| m.insert(key, Arc::new(phrases)));
+    }
+
+    fn get(&self, tag: &str) -> Option<Arc<RelativeTime<Box<str>>>> {
+        self.0.with(|m| {
+            if m.is_empty() {
+                return None;
+            }
+            m.get(&language(tag).to_ascii_lowercase()).map(Arc::clone)
+        })
+    }
+}
+
+/// The language subtag of a locale identifier
+fn language(tag: &str) -> &str {
+    let lang = tag.split(['-', '_']).next().unwrap_or(tag);
+    // Norwegian without a written standard defaults to Bokmål, as in CLDR
+    if lang.eq_ignore_ascii_case("no") {
+        "nb"
+    } else {
+        lang
+    }
+}
+
+impl Locale {
+    /// Look up a locale by identifier (e.g. `"fr"`, `"pt-BR"`, `"nb_NO"`),
+    /// ignoring case and anything after the language subtag.
+    pub(crate) fn lookup(tag: &str) -> Option<&'static Locale> {
+        let lang = language(tag);
+        ALL.iter()
+            .copied()
+            .find(|loc| loc.tag.eq_ignore_ascii_case(lang))
+    }
+
+    /// Look up a locale by Windows locale ID (LCID), e.g. `0x407` for
+    /// German, as used in spreadsheet formats. Like [`Locale::lookup`],
+    /// only the language is considered, not the region.
+    pub(crate) fn from_lcid(lcid: u32) -> Option<&'static Locale> {
+        // Norwegian Nynorsk shares its primary language ID with Bokmål
+        if lcid == 0x814 {
+            return None;
+        }
+        let tag = match lcid & 0x3FF {
+            0x04 => "zh",
+            0x05 => "cs",
+            0x06 => "da",
+            0x07 => "de",
+            0x09 => "en",
+            0x0A => "es",
+            0x0B => "fi",
+            0x0C => "fr",
+            0x10 => "it",
+            0x11 => "ja",
+            0x12 => "ko",
+            0x13 => "nl",
+            0x14 => "nb",
+            0x15 => "pl",
+            0x16 => "pt",
+            0x19 => "ru",
+            0x1D => "sv",
+            0x1F => "tr",
+            0x22 => "uk",
+            _ => return None,
+        };
+        Self::lookup(tag)
+    }
+
+    /// Interpret the value of a `locale=` argument, or `None` if no locale is given
+    pub(crate) fn from_py_opt(obj: Option<PyObj>) -> PyResult<Option<&'static Locale>> {
+        let Some(obj) = obj.filter(|o| !o.is_none()) else {
+            return Ok(None);
+        };
+        let py_str = obj
+            .cast_exact::<PyStr>()
+            .ok_or_type_err("locale must be str")?;
+        let tag = py_str.as_str()?;
+        match Self::lookup(tag) {
+            Some(loc) => Ok(Some(loc)),
+            None => raise_value_err(format!("Unsupported locale: {obj}")),
+        }
+    }
+}
+
+pub(crate) static EN: Locale = Locale {
+    tag: "en",
+    month_abbr: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ],
+    month_full: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    weekday_abbr: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+    weekday_full: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["BC", "AD"],
+    relative: RelativeTime {
+        now: "now",
+        yesterday: "yesterday",
+        tomorrow: "tomorrow",
+        at_time: "{0} at {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["in {0} year", "in {0} years", "in {0} years"],
+            ["in {0} month", "in {0} months", "in {0} months"],
+            ["in {0} week", "in {0} weeks", "in {0} weeks"],
+            ["in {0} day", "in {0} days", "in {0} days"],
+            ["in {0} hour", "in {0} hours", "in {0} hours"],
+            ["in {0} minute", "in {0} minutes", "in {0} minutes"],
+            ["in {0} second", "in {0} seconds", "in {0} seconds"],
+        ],
+        past: [
+            ["{0} year ago", "{0} years ago", "{0} years ago"],
+            ["{0} month ago", "{0} months ago", "{0} months ago"],
+            ["{0} week ago", "{0} weeks ago", "{0} weeks ago"],
+            ["{0} day ago", "{0} days ago", "{0} days ago"],
+            ["{0} hour ago", "{0} hours ago", "{0} hours ago"],
+            ["{0} minute ago", "{0} minutes ago", "{0} minutes ago"],
+            ["{0} second ago", "{0} seconds ago", "{0} seconds ago"],
+        ],
+    },
+};
+
+static DE: Locale = Locale {
+    tag: "de",
+    month_abbr: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    month_full: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    weekday_abbr: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+    weekday_full: [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["v. Chr.", "n. Chr."],
+    relative: RelativeTime {
+        now: "jetzt",
+        yesterday: "gestern",
+        tomorrow: "morgen",
+        at_time: "{0} um {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["in {0} Jahr", "in {0} Jahren", "in {0} Jahren"],
+            ["in {0} Monat", "in {0} Monaten", "in {0} Monaten"],
+            ["in {0} Woche", "in {0} Wochen", "in {0} Wochen"],
+            ["in {0} Tag", "in {0} Tagen", "in {0} Tagen"],
+            ["in {0} Stunde", "in {0} Stunden", "in {0} Stunden"],
+            ["in {0} Minute", "in {0} Minuten", "in {0} Minuten"],
+            ["in {0} Sekunde", "in {0} Sekunden", "in {0} Sekunden"],
+        ],
+        past: [
+            ["vor {0} Jahr", "vor {0} Jahren", "vor {0} Jahren"],
+            ["vor {0} Monat", "vor {0} Monaten", "vor {0} Monaten"],
+            ["vor {0} Woche", "vor {0} Wochen", "vor {0} Wochen"],
+            ["vor {0} Tag", "vor {0} Tagen", "vor {0} Tagen"],
+            ["vor {0} Stunde", "vor {0} Stunden", "vor {0} Stunden"],
+            ["vor {0} Minute", "vor {0} Minuten", "vor {0} Minuten"],
+            ["vor {0} Sekunde", "vor {0} Sekunden", "vor {0} Sekunden"],
+        ],
+    },
+};
+
+static FR: Locale = Locale {
+    tag: "fr",
+    month_abbr: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    month_full: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    weekday_abbr: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+    weekday_full: [
+        "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["av. J.-C.", "ap. J.-C."],
+    relative: RelativeTime {
+        now: "maintenant",
+        yesterday: "hier",
+        tomorrow: "demain",
+        at_time: "{0} à {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["dans {0} an", "dans {0} ans", "dans {0} ans"],
+            ["dans {0} mois", "dans {0} mois", "dans {0} mois"],
+            ["dans {0} semaine", "dans {0} semaines", "dans {0} semaines"],
+            ["dans {0} jour", "dans {0} jours", "dans {0} jours"],
+            ["dans {0} heure", "dans {0} heures", "dans {0} heures"],
+            ["dans {0} minute", "dans {0} minutes", "dans {0} minutes"],
+            ["dans {0} seconde", "dans {0} secondes", "dans {0} secondes"],
+        ],
+        past: [
+            ["il y a {0} an", "il y a {0} ans", "il y a {0} ans"],
+            ["il y a {0} mois", "il y a {0} mois", "il y a {0} mois"],
+            [
+                "il y a {0} semaine",
+                "il y a {0} semaines",
+                "il y a {0} semaines",
+            ],
+            ["il y a {0} jour", "il y a {0} jours", "il y a {0} jours"],
+            ["il y a {0} heure", "il y a {0} heures", "il y a {0} heures"],
+            [
+                "il y a {0} minute",
+                "il y a {0} minutes",
+                "il y a {0} minutes",
+            ],
+            [
+                "il y a {0} seconde",
+                "il y a {0} secondes",
+                "il y a {0} secondes",
+            ],
+        ],
+    },
+};
+
+static ES: Locale = Locale {
+    tag: "es",
+    month_abbr: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    month_full: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    weekday_abbr: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+    weekday_full: [
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+        "domingo",
+    ],
+    am_pm_short: ["a", "p"],
+    // CLDR separates these with a no-break space
+    am_pm: ["a.\u{a0}m.", "p.\u{a0}m."],
+    era: ["a. C.", "d. C."],
+    relative: RelativeTime {
+        now: "ahora",
+        yesterday: "ayer",
+        tomorrow: "mañana",
+        at_time: "{0} a las {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            [
+                "dentro de {0} año",
+                "dentro de {0} años",
+                "dentro de {0} años",
+            ],
+            [
+                "dentro de {0} mes",
+                "dentro de {0} meses",
+                "dentro de {0} meses",
+            ],
+            [
+                "dentro de {0} semana",
+                "dentro de {0} semanas",
+                "dentro de {0} semanas",
+            ],
+            [
+                "dentro de {0} día",
+                "dentro de {0} días",
+                "dentro de {0} días",
+            ],
+            [
+                "dentro de {0} hora",
+                "dentro de {0} horas",
+                "dentro de {0} horas",
+            ],
+            [
+                "dentro de {0} minuto",
+                "dentro de {0} minutos",
+                "dentro de {0} minutos",
+            ],
+            [
+                "dentro de {0} segundo",
+                "dentro de {0} segundos",
+                "dentro de {0} segundos",
+            ],
+        ],
+        past: [
+            ["hace {0} año", "hace {0} años", "hace {0} años"],
+            ["hace {0} mes", "hace {0} meses", "hace {0} meses"],
+            ["hace {0} semana", "hace {0} semanas", "hace {0} semanas"],
+            ["hace {0} día", "hace {0} días", "hace {0} días"],
+            ["hace {0} hora", "hace {0} horas", "hace {0} horas"],
+            ["hace {0} minuto", "hace {0} minutos", "hace {0} minutos"],
+            ["hace {0} segundo", "hace {0} segundos", "hace {0} segundos"],
+        ],
+    },
+};
+
+static IT: Locale = Locale {
+    tag: "it",
+    month_abbr: [
+        "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+    ],
+    month_full: [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+    weekday_abbr: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+    weekday_full: [
+        "lunedì",
+        "martedì",
+        "mercoledì",
+        "giovedì",
+        "venerdì",
+        "sabato",
+        "domenica",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["a.C.", "d.C."],
+    relative: RelativeTime {
+        now: "ora",
+        yesterday: "ieri",
+        tomorrow: "domani",
+        at_time: "{0} alle {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["tra {0} anno", "tra {0} anni", "tra {0} anni"],
+            ["tra {0} mese", "tra {0} mesi", "tra {0} mesi"],
+            [
+                "tra {0} settimana",
+                "tra {0} settimane",
+                "tra {0} settimane",
+            ],
+            ["tra {0} giorno", "tra {0} giorni", "tra {0} giorni"],
+            ["tra {0} ora", "tra {0} ore", "tra {0} ore"],
+            ["tra {0} minuto", "tra {0} minuti", "tra {0} minuti"],
+            ["tra {0} secondo", "tra {0} secondi", "tra {0} secondi"],
+        ],
+        past: [
+            ["{0} anno fa", "{0} anni fa", "{0} anni fa"],
+            ["{0} mese fa", "{0} mesi fa", "{0} mesi fa"],
+            ["{0} settimana fa", "{0} settimane fa", "{0} settimane fa"],
+            ["{0} giorno fa", "{0} giorni fa", "{0} giorni fa"],
+            ["{0} ora fa", "{0} ore fa", "{0} ore fa"],
+            ["{0} minuto fa", "{0} minuti fa", "{0} minuti fa"],
+            ["{0} secondo fa", "{0} secondi fa", "{0} secondi fa"],
+        ],
+    },
+};
+
+static PT: Locale = Locale {
+    tag: "pt",
+    month_abbr: [
+        "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+        "dez.",
+    ],
+    month_full: [
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ],
+    weekday_abbr: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+    weekday_full: [
+        "segunda-feira",
+        "terça-feira",
+        "quarta-feira",
+        "quinta-feira",
+        "sexta-feira",
+        "sábado",
+        "domingo",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["a.C.", "d.C."],
+    relative: RelativeTime {
+        now: "agora",
+        yesterday: "ontem",
+        tomorrow: "amanhã",
+        at_time: "{0} às {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["em {0} ano", "em {0} anos", "em {0} anos"],
+            ["em {0} mês", "em {0} meses", "em {0} meses"],
+            ["em {0} semana", "em {0} semanas", "em {0} semanas"],
+            ["em {0} dia", "em {0} dias", "em {0} dias"],
+            ["em {0} hora", "em {0} horas", "em {0} horas"],
+            ["em {0} minuto", "em {0} minutos", "em {0} minutos"],
+            ["em {0} segundo", "em {0} segundos", "em {0} segundos"],
+        ],
+        past: [
+            ["há {0} ano", "há {0} anos", "há {0} anos"],
+            ["há {0} mês", "há {0} meses", "há {0} meses"],
+            ["há {0} semana", "há {0} semanas", "há {0} semanas"],
+            ["há {0} dia", "há {0} dias", "há {0} dias"],
+            ["há {0} hora", "há {0} horas", "há {0} horas"],
+            ["há {0} minuto", "há {0} minutos", "há {0} minutos"],
+            ["há {0} segundo", "há {0} segundos", "há {0} segundos"],
+        ],
+    },
+};
+
+static NL: Locale = Locale {
+    tag: "nl",
+    month_abbr: [
+        "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+    ],
+    month_full: [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    weekday_abbr: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+    weekday_full: [
+        "maandag",
+        "dinsdag",
+        "woensdag",
+        "donderdag",
+        "vrijdag",
+        "zaterdag",
+        "zondag",
+    ],
+    am_pm_short: ["a", "p"],
+    am_pm: ["a.m.", "p.m."],
+    era: ["v.Chr.", "n.Chr."],
+    relative: RelativeTime {
+        now: "nu",
+        yesterday: "gisteren",
+        tomorrow: "morgen",
+        at_time: "{0} om {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["over {0} jaar", "over {0} jaar", "over {0} jaar"],
+            ["over {0} maand", "over {0} maanden", "over {0} maanden"],
+            ["over {0} week", "over {0} weken", "over {0} weken"],
+            ["over {0} dag", "over {0} dagen", "over {0} dagen"],
+            ["over {0} uur", "over {0} uur", "over {0} uur"],
+            ["over {0} minuut", "over {0} minuten", "over {0} minuten"],
+            ["over {0} seconde", "over {0} seconden", "over {0} seconden"],
+        ],
+        past: [
+            ["{0} jaar geleden", "{0} jaar geleden", "{0} jaar geleden"],
+            [
+                "{0} maand geleden",
+                "{0} maanden geleden",
+                "{0} maanden geleden",
+            ],
+            ["{0} week geleden", "{0} weken geleden", "{0} weken geleden"],
+            ["{0} dag geleden", "{0} dagen geleden", "{0} dagen geleden"],
+            ["{0} uur geleden", "{0} uur geleden", "{0} uur geleden"],
+            [
+                "{0} minuut geleden",
+                "{0} minuten geleden",
+                "{0} minuten geleden",
+            ],
+            [
+                "{0} seconde geleden",
+                "{0} seconden geleden",
+                "{0} seconden geleden",
+            ],
+        ],
+    },
+};
+
+static SV: Locale = Locale {
+    tag: "sv",
+    month_abbr: [
+        "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+        "dec.",
+    ],
+    month_full: [
+        "januari",
+        "februari",
+        "mars",
+        "april",
+        "maj",
+        "juni",
+        "juli",
+        "augusti",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    weekday_abbr: ["mån", "tis", "ons", "tors", "fre", "lör", "sön"],
+    weekday_full: [
+        "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag",
+    ],
+    am_pm_short: ["f", "e"],
+    am_pm: ["fm", "em"],
+    era: ["f.Kr.", "e.Kr."],
+    relative: RelativeTime {
+        now: "nu",
+        yesterday: "i går",
+        tomorrow: "i morgon",
+        at_time: "{0} kl. {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["om {0} år", "om {0} år", "om {0} år"],
+            ["om {0} månad", "om {0} månader", "om {0} månader"],
+            ["om {0} vecka", "om {0} veckor", "om {0} veckor"],
+            ["om {0} dag", "om {0} dagar", "om {0} dagar"],
+            ["om {0} timme", "om {0} timmar", "om {0} timmar"],
+            ["om {0} minut", "om {0} minuter", "om {0} minuter"],
+            ["om {0} sekund", "om {0} sekunder", "om {0} sekunder"],
+        ],
+        past: [
+            ["för {0} år sedan", "för {0} år sedan", "för {0} år sedan"],
+            [
+                "för {0} månad sedan",
+                "för {0} månader sedan",
+                "för {0} månader sedan",
+            ],
+            [
+                "för {0} vecka sedan",
+                "för {0} veckor sedan",
+                "för {0} veckor sedan",
+            ],
+            [
+                "för {0} dag sedan",
+                "för {0} dagar sedan",
+                "för {0} dagar sedan",
+            ],
+            [
+                "för {0} timme sedan",
+                "för {0} timmar sedan",
+                "för {0} timmar sedan",
+            ],
+            [
+                "för {0} minut sedan",
+                "för {0} minuter sedan",
+                "för {0} minuter sedan",
+            ],
+            [
+                "för {0} sekund sedan",
+                "för {0} sekunder sedan",
+                "för {0} sekunder sedan",
+            ],
+        ],
+    },
+};
+
+static DA: Locale = Locale {
+    tag: "da",
+    month_abbr: [
+        "jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+        "dec.",
+    ],
+    month_full: [
+        "januar",
+        "februar",
+        "marts",
+        "april",
+        "maj",
+        "juni",
+        "juli",
+        "august",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    weekday_abbr: ["man.", "tirs.", "ons.", "tors.", "fre.", "lør.", "søn."],
+    weekday_full: [
+        "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["f.Kr.", "e.Kr."],
+    relative: RelativeTime {
+        now: "nu",
+        yesterday: "i går",
+        tomorrow: "i morgen",
+        at_time: "{0} kl. {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["om {0} år", "om {0} år", "om {0} år"],
+            ["om {0} måned", "om {0} måneder", "om {0} måneder"],
+            ["om {0} uge", "om {0} uger", "om {0} uger"],
+            ["om {0} dag", "om {0} dage", "om {0} dage"],
+            ["om {0} time", "om {0} timer", "om {0} timer"],
+            ["om {0} minut", "om {0} minutter", "om {0} minutter"],
+            ["om {0} sekund", "om {0} sekunder", "om {0} sekunder"],
+        ],
+        past: [
+            ["for {0} år siden", "for {0} år siden", "for {0} år siden"],
+            [
+                "for {0} måned siden",
+                "for {0} måneder siden",
+                "for {0} måneder siden",
+            ],
+            [
+                "for {0} uge siden",
+                "for {0} uger siden",
+                "for {0} uger siden",
+            ],
+            [
+                "for {0} dag siden",
+                "for {0} dage siden",
+                "for {0} dage siden",
+            ],
+            [
+                "for {0} time siden",
+                "for {0} timer siden",
+                "for {0} timer siden",
+            ],
+            [
+                "for {0} minut siden",
+                "for {0} minutter siden",
+                "for {0} minutter siden",
+            ],
+            [
+                "for {0} sekund siden",
+                "for {0} sekunder siden",
+                "for {0} sekunder siden",
+            ],
+        ],
+    },
+};
+
+static NB: Locale = Locale {
+    tag: "nb",
+    month_abbr: [
+        "jan.", "feb.", "mar.", "apr.", "mai", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+        "des.",
+    ],
+    month_full: [
+        "januar",
+        "februar",
+        "mars",
+        "april",
+        "mai",
+        "juni",
+        "juli",
+        "august",
+        "september",
+        "oktober",
+        "november",
+        "desember",
+    ],
+    weekday_abbr: ["man.", "tir.", "ons.", "tor.", "fre.", "lør.", "søn."],
+    weekday_full: [
+        "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+    ],
+    am_pm_short: ["a", "p"],
+    am_pm: ["a.m.", "p.m."],
+    era: ["f.Kr.", "e.Kr."],
+    relative: RelativeTime {
+        now: "nå",
+        yesterday: "i går",
+        tomorrow: "i morgen",
+        at_time: "{0} kl. {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["om {0} år", "om {0} år", "om {0} år"],
+            ["om {0} måned", "om {0} måneder", "om {0} måneder"],
+            ["om {0} uke", "om {0} uker", "om {0} uker"],
+            ["om {0} døgn", "om {0} døgn", "om {0} døgn"],
+            ["om {0} time", "om {0} timer", "om {0} timer"],
+            ["om {0} minutt", "om {0} minutter", "om {0} minutter"],
+            ["om {0} sekund", "om {0} sekunder", "om {0} sekunder"],
+        ],
+        past: [
+            ["for {0} år siden", "for {0} år siden", "for {0} år siden"],
+            [
+                "for {0} måned siden",
+                "for {0} måneder siden",
+                "for {0} måneder siden",
+            ],
+            [
+                "for {0} uke siden",
+                "for {0} uker siden",
+                "for {0} uker siden",
+            ],
+            [
+                "for {0} døgn siden",
+                "for {0} døgn siden",
+                "for {0} døgn siden",
+            ],
+            [
+                "for {0} time siden",
+                "for {0} timer siden",
+                "for {0} timer siden",
+            ],
+            [
+                "for {0} minutt siden",
+                "for {0} minutter siden",
+                "for {0} minutter siden",
+            ],
+            [
+                "for {0} sekund siden",
+                "for {0} sekunder siden",
+                "for {0} sekunder siden",
+            ],
+        ],
+    },
+};
+
+static FI: Locale = Locale {
+    tag: "fi",
+    month_abbr: [
+        "tammik.", "helmik.", "maalisk.", "huhtik.", "toukok.", "kesäk.", "heinäk.", "elok.",
+        "syysk.", "lokak.", "marrask.", "jouluk.",
+    ],
+    month_full: [
+        "tammikuuta",
+        "helmikuuta",
+        "maaliskuuta",
+        "huhtikuuta",
+        "toukokuuta",
+        "kesäkuuta",
+        "heinäkuuta",
+        "elokuuta",
+        "syyskuuta",
+        "lokakuuta",
+        "marraskuuta",
+        "joulukuuta",
+    ],
+    weekday_abbr: ["ma", "ti", "ke", "to", "pe", "la", "su"],
+    weekday_full: [
+        "maanantaina",
+        "tiistaina",
+        "keskiviikkona",
+        "torstaina",
+        "perjantaina",
+        "lauantaina",
+        "sunnuntaina",
+    ],
+    am_pm_short: ["ap.", "ip."],
+    am_pm: ["ap.", "ip."],
+    era: ["eKr.", "jKr."],
+    relative: RelativeTime {
+        now: "nyt",
+        yesterday: "eilen",
+        tomorrow: "huomenna",
+        at_time: "{0} klo {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            [
+                "{0} vuoden päästä",
+                "{0} vuoden päästä",
+                "{0} vuoden päästä",
+            ],
+            [
+                "{0} kuukauden päästä",
+                "{0} kuukauden päästä",
+                "{0} kuukauden päästä",
+            ],
+            [
+                "{0} viikon päästä",
+                "{0} viikon päästä",
+                "{0} viikon päästä",
+            ],
+            [
+                "{0} päivän päästä",
+                "{0} päivän päästä",
+                "{0} päivän päästä",
+            ],
+            [
+                "{0} tunnin päästä",
+                "{0} tunnin päästä",
+                "{0} tunnin päästä",
+            ],
+            [
+                "{0} minuutin päästä",
+                "{0} minuutin päästä",
+                "{0} minuutin päästä",
+            ],
+            [
+                "{0} sekunnin päästä",
+                "{0} sekunnin päästä",
+                "{0} sekunnin päästä",
+            ],
+        ],
+        past: [
+            ["{0} vuosi sitten", "{0} vuotta sitten", "{0} vuotta sitten"],
+            [
+                "{0} kuukausi sitten",
+                "{0} kuukautta sitten",
+                "{0} kuukautta sitten",
+            ],
+            [
+                "{0} viikko sitten",
+                "{0} viikkoa sitten",
+                "{0} viikkoa sitten",
+            ],
+            ["{0} päivä sitten", "{0} päivää sitten", "{0} päivää sitten"],
+            ["{0} tunti sitten", "{0} tuntia sitten", "{0} tuntia sitten"],
+            [
+                "{0} minuutti sitten",
+                "{0} minuuttia sitten",
+                "{0} minuuttia sitten",
+            ],
+            [
+                "{0} sekunti sitten",
+                "{0} sekuntia sitten",
+                "{0} sekuntia sitten",
+            ],
+        ],
+    },
+};
+
+static PL: Locale = Locale {
+    tag: "pl",
+    month_abbr: [
+        "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+    ],
+    month_full: [
+        "stycznia",
+        "lutego",
+        "marca",
+        "kwietnia",
+        "maja",
+        "czerwca",
+        "lipca",
+        "sierpnia",
+        "września",
+        "października",
+        "listopada",
+        "grudnia",
+    ],
+    weekday_abbr: ["pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."],
+    weekday_full: [
+        "poniedziałek",
+        "wtorek",
+        "środa",
+        "czwartek",
+        "piątek",
+        "sobota",
+        "niedziela",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["p.n.e.", "n.e."],
+    relative: RelativeTime {
+        now: "teraz",
+        yesterday: "wczoraj",
+        tomorrow: "jutro",
+        at_time: "{0} o {1}",
+        plural: PluralRule::Polish,
+        future: [
+            ["za {0} rok", "za {0} lata", "za {0} lat"],
+            ["za {0} miesiąc", "za {0} miesiące", "za {0} miesięcy"],
+            ["za {0} tydzień", "za {0} tygodnie", "za {0} tygodni"],
+            ["za {0} dzień", "za {0} dni", "za {0} dni"],
+            ["za {0} godzinę", "za {0} godziny", "za {0} godzin"],
+            ["za {0} minutę", "za {0} minuty", "za {0} minut"],
+            ["za {0} sekundę", "za {0} sekundy", "za {0} sekund"],
+        ],
+        past: [
+            ["{0} rok temu", "{0} lata temu", "{0} lat temu"],
+            ["{0} miesiąc temu", "{0} miesiące temu", "{0} miesięcy temu"],
+            ["{0} tydzień temu", "{0} tygodnie temu", "{0} tygodni temu"],
+            ["{0} dzień temu", "{0} dni temu", "{0} dni temu"],
+            ["{0} godzinę temu", "{0} godziny temu", "{0} godzin temu"],
+            ["{0} minutę temu", "{0} minuty temu", "{0} minut temu"],
+            ["{0} sekundę temu", "{0} sekundy temu", "{0} sekund temu"],
+        ],
+    },
+};
+
+static CS: Locale = Locale {
+    tag: "cs",
+    month_abbr: [
+        "led", "úno", "bře", "dub", "kvě", "čvn", "čvc", "srp", "zář", "říj", "lis", "pro",
+    ],
+    month_full: [
+        "ledna",
+        "února",
+        "března",
+        "dubna",
+        "května",
+        "června",
+        "července",
+        "srpna",
+        "září",
+        "října",
+        "listopadu",
+        "prosince",
+    ],
+    weekday_abbr: ["po", "út", "st", "čt", "pá", "so", "ne"],
+    weekday_full: [
+        "pondělí",
+        "úterý",
+        "středa",
+        "čtvrtek",
+        "pátek",
+        "sobota",
+        "neděle",
+    ],
+    am_pm_short: ["dop.", "odp."],
+    am_pm: ["dop.", "odp."],
+    era: ["př. n. l.", "n. l."],
+    relative: RelativeTime {
+        now: "nyní",
+        yesterday: "včera",
+        tomorrow: "zítra",
+        at_time: "{0} v {1}",
+        plural: PluralRule::Czech,
+        future: [
+            ["za {0} rok", "za {0} roky", "za {0} let"],
+            ["za {0} měsíc", "za {0} měsíce", "za {0} měsíců"],
+            ["za {0} týden", "za {0} týdny", "za {0} týdnů"],
+            ["za {0} den", "za {0} dny", "za {0} dní"],
+            ["za {0} hodinu", "za {0} hodiny", "za {0} hodin"],
+            ["za {0} minutu", "za {0} minuty", "za {0} minut"],
+            ["za {0} sekundu", "za {0} sekundy", "za {0} sekund"],
+        ],
+        past: [
+            ["před {0} rokem", "před {0} lety", "před {0} lety"],
+            ["před {0} měsícem", "před {0} měsíci", "před {0} měsíci"],
+            ["před {0} týdnem", "před {0} týdny", "před {0} týdny"],
+            ["před {0} dnem", "před {0} dny", "před {0} dny"],
+            ["před {0} hodinou", "před {0} hodinami", "před {0} hodinami"],
+            ["před {0} minutou", "před {0} minutami", "před {0} minutami"],
+            [
+                "před {0} sekundou",
+                "před {0} sekundami",
+                "před {0} sekundami",
+            ],
+        ],
+    },
+};
+
+static RU: Locale = Locale {
+    tag: "ru",
+    month_abbr: [
+        "янв.",
+        "февр.",
+        "мар.",
+        "апр.",
+        "мая",
+        "июн.",
+        "июл.",
+        "авг.",
+        "сент.",
+        "окт.",
+        "нояб.",
+        "дек.",
+    ],
+    month_full: [
+        "января",
+        "февраля",
+        "марта",
+        "апреля",
+        "мая",
+        "июня",
+        "июля",
+        "августа",
+        "сентября",
+        "октября",
+        "ноября",
+        "декабря",
+    ],
+    weekday_abbr: ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+    weekday_full: [
+        "понедельник",
+        "вторник",
+        "среда",
+        "четверг",
+        "пятница",
+        "суббота",
+        "воскресенье",
+    ],
+    am_pm_short: ["A", "P"],
+    am_pm: ["AM", "PM"],
+    era: ["до н. э.", "н. э."],
+    relative: RelativeTime {
+        now: "сейчас",
+        yesterday: "вчера",
+        tomorrow: "завтра",
+        at_time: "{0} в {1}",
+        plural: PluralRule::EastSlavic,
+        future: [
+            ["через {0} год", "через {0} года", "через {0} лет"],
+            ["через {0} месяц", "через {0} месяца", "через {0} месяцев"],
+            ["через {0} неделю", "через {0} недели", "через {0} недель"],
+            ["через {0} день", "через {0} дня", "через {0} дней"],
+            ["через {0} час", "через {0} часа", "через {0} часов"],
+            ["через {0} минуту", "через {0} минуты", "через {0} минут"],
+            ["через {0} секунду", "через {0} секунды", "через {0} секунд"],
+        ],
+        past: [
+            ["{0} год назад", "{0} года назад", "{0} лет назад"],
+            ["{0} месяц назад", "{0} месяца назад", "{0} месяцев назад"],
+            ["{0} неделю назад", "{0} недели назад", "{0} недель назад"],
+            ["{0} день назад", "{0} дня назад", "{0} дней назад"],
+            ["{0} час назад", "{0} часа назад", "{0} часов назад"],
+            ["{0} минуту назад", "{0} минуты назад", "{0} минут назад"],
+            ["{0} секунду назад", "{0} секунды назад", "{0} секунд назад"],
+        ],
+    },
+};
+
+static UK: Locale = Locale {
+    tag: "uk",
+    month_abbr: [
+        "січ.",
+        "лют.",
+        "бер.",
+        "квіт.",
+        "трав.",
+        "черв.",
+        "лип.",
+        "серп.",
+        "вер.",
+        "жовт.",
+        "лист.",
+        "груд.",
+    ],
+    month_full: [
+        "січня",
+        "лютого",
+        "березня",
+        "квітня",
+        "травня",
+        "червня",
+        "липня",
+        "серпня",
+        "вересня",
+        "жовтня",
+        "листопада",
+        "грудня",
+    ],
+    weekday_abbr: ["пн", "вт", "ср", "чт", "пт", "сб", "нд"],
+    weekday_full: [
+        "понеділок",
+        "вівторок",
+        "середа",
+        "четвер",
+        "пʼятниця",
+        "субота",
+        "неділя",
+    ],
+    am_pm_short: ["дп", "пп"],
+    am_pm: ["дп", "пп"],
+    era: ["до н. е.", "н. е."],
+    relative: RelativeTime {
+        now: "зараз",
+        yesterday: "учора",
+        tomorrow: "завтра",
+        at_time: "{0} о {1}",
+        plural: PluralRule::EastSlavic,
+        future: [
+            ["через {0} рік", "через {0} роки", "через {0} років"],
+            ["через {0} місяць", "через {0} місяці", "через {0} місяців"],
+            ["через {0} тиждень", "через {0} тижні", "через {0} тижнів"],
+            ["через {0} день", "через {0} дні", "через {0} днів"],
+            ["через {0} годину", "через {0} години", "через {0} годин"],
+            ["через {0} хвилину", "через {0} хвилини", "через {0} хвилин"],
+            ["через {0} секунду", "через {0} секунди", "через {0} секунд"],
+        ],
+        past: [
+            ["{0} рік тому", "{0} роки тому", "{0} років тому"],
+            ["{0} місяць тому", "{0} місяці тому", "{0} місяців тому"],
+            ["{0} тиждень тому", "{0} тижні тому", "{0} тижнів тому"],
+            ["{0} день тому", "{0} дні тому", "{0} днів тому"],
+            ["{0} годину тому", "{0} години тому", "{0} годин тому"],
+            ["{0} хвилину тому", "{0} хвилини тому", "{0} хвилин тому"],
+            ["{0} секунду тому", "{0} секунди тому", "{0} секунд тому"],
+        ],
+    },
+};
+
+static TR: Locale = Locale {
+    tag: "tr",
+    month_abbr: [
+        "Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara",
+    ],
+    month_full: [
+        "Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül", "Ekim",
+        "Kasım", "Aralık",
+    ],
+    weekday_abbr: ["Pzt", "Sal", "Çar", "Per", "Cum", "Cmt", "Paz"],
+    weekday_full: [
+        "Pazartesi",
+        "Salı",
+        "Çarşamba",
+        "Perşembe",
+        "Cuma",
+        "Cumartesi",
+        "Pazar",
+    ],
+    am_pm_short: ["ÖÖ", "ÖS"],
+    am_pm: ["ÖÖ", "ÖS"],
+    era: ["MÖ", "MS"],
+    relative: RelativeTime {
+        now: "şimdi",
+        yesterday: "dün",
+        tomorrow: "yarın",
+        at_time: "{0} {1}",
+        plural: PluralRule::OneOther,
+        future: [
+            ["{0} yıl sonra", "{0} yıl sonra", "{0} yıl sonra"],
+            ["{0} ay sonra", "{0} ay sonra", "{0} ay sonra"],
+            ["{0} hafta sonra", "{0} hafta sonra", "{0} hafta sonra"],
+            ["{0} gün sonra", "{0} gün sonra", "{0} gün sonra"],
+            ["{0} saat sonra", "{0} saat sonra", "{0} saat sonra"],
+            ["{0} dakika sonra", "{0} dakika sonra", "{0} dakika sonra"],
+            ["{0} saniye sonra", "{0} saniye sonra", "{0} saniye sonra"],
+        ],
+        past: [
+            ["{0} yıl önce", "{0} yıl önce", "{0} yıl önce"],
+            ["{0} ay önce", "{0} ay önce", "{0} ay önce"],
+            ["{0} hafta önce", "{0} hafta önce", "{0} hafta önce"],
+            ["{0} gün önce", "{0} gün önce", "{0} gün önce"],
+            ["{0} saat önce", "{0} saat önce", "{0} saat önce"],
+            ["{0} dakika önce", "{0} dakika önce", "{0} dakika önce"],
+            ["{0} saniye önce", "{0} saniye önce", "{0} saniye önce"],
+        ],
+    },
+};
+
+static JA: Locale = Locale {
+    tag: "ja",
+    month_abbr: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    month_full: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekday_abbr: ["月", "火", "水", "木", "金", "土", "日"],
+    weekday_full: [
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+        "日曜日",
+    ],
+    am_pm_short: ["午前", "午後"],
+    am_pm: ["午前", "午後"],
+    era: ["紀元前", "西暦"],
+    relative: RelativeTime {
+        now: "今",
+        yesterday: "昨日",
+        tomorrow: "明日",
+        at_time: "{0} {1}",
+        plural: PluralRule::Other,
+        future: [
+            ["{0} 年後", "{0} 年後", "{0} 年後"],
+            ["{0} か月後", "{0} か月後", "{0} か月後"],
+            ["{0} 週間後", "{0} 週間後", "{0} 週間後"],
+            ["{0} 日後", "{0} 日後", "{0} 日後"],
+            ["{0} 時間後", "{0} 時間後", "{0} 時間後"],
+            ["{0} 分後", "{0} 分後", "{0} 分後"],
+            ["{0} 秒後", "{0} 秒後", "{0} 秒後"],
+        ],
+        past: [
+            ["{0} 年前", "{0} 年前", "{0} 年前"],
+            ["{0} か月前", "{0} か月前", "{0} か月前"],
+            ["{0} 週間前", "{0} 週間前", "{0} 週間前"],
+            ["{0} 日前", "{0} 日前", "{0} 日前"],
+            ["{0} 時間前", "{0} 時間前", "{0} 時間前"],
+            ["{0} 分前", "{0} 分前", "{0} 分前"],
+            ["{0} 秒前", "{0} 秒前", "{0} 秒前"],
+        ],
+    },
+};
+
+static ZH: Locale = Locale {
+    tag: "zh",
+    month_abbr: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    month_full: [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    weekday_abbr: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+    weekday_full: [
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+        "星期日",
+    ],
+    am_pm_short: ["上午", "下午"],
+    am_pm: ["上午", "下午"],
+    era: ["公元前", "公元"],
+    relative: RelativeTime {
+        now: "现在",
+        yesterday: "昨天",
+        tomorrow: "明天",
+        at_time: "{0} {1}",
+        plural: PluralRule::Other,
+        future: [
+            ["{0}年后", "{0}年后", "{0}年后"],
+            ["{0}个月后", "{0}个月后", "{0}个月后"],
+            ["{0}周后", "{0}周后", "{0}周后"],
+            ["{0}天后", "{0}天后", "{0}天后"],
+            ["{0}小时后", "{0}小时后", "{0}小时后"],
+            ["{0}分钟后", "{0}分钟后", "{0}分钟后"],
+            ["{0}秒钟后", "{0}秒钟后", "{0}秒钟后"],
+        ],
+        past: [
+            ["{0}年前", "{0}年前", "{0}年前"],
+            ["{0}个月前", "{0}个月前", "{0}个月前"],
+            ["{0}周前", "{0}周前", "{0}周前"],
+            ["{0}天前", "{0}天前", "{0}天前"],
+            ["{0}小时前", "{0}小时前", "{0}小时前"],
+            ["{0}分钟前", "{0}分钟前", "{0}分钟前"],
+            ["{0}秒钟前", "{0}秒钟前", "{0}秒钟前"],
+        ],
+    },
+};
+
+static KO: Locale = Locale {
+    tag: "ko",
+    month_abbr: [
+        "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+    ],
+    month_full: [
+        "1월", "2월", "3월", "4월", "5월", "6월", "7월", "8월", "9월", "10월", "11월", "12월",
+    ],
+    weekday_abbr: ["월", "화", "수", "목", "금", "토", "일"],
+    weekday_full: [
+        "월요일",
+        "화요일",
+        "수요일",
+        "목요일",
+        "금요일",
+        "토요일",
+        "일요일",
+    ],
+    am_pm_short: ["오전", "오후"],
+    am_pm: ["오전", "오후"],
+    era: ["BC", "AD"],
+    relative: RelativeTime {
+        now: "지금",
+        yesterday: "어제",
+        tomorrow: "내일",
+        at_time: "{0} {1}",
+        plural: PluralRule::Other,
+        future: [
+            ["{0}년 후", "{0}년 후", "{0}년 후"],
+            ["{0}개월 후", "{0}개월 후", "{0}개월 후"],
+            ["{0}주 후", "{0}주 후", "{0}주 후"],
+            ["{0}일 후", "{0}일 후", "{0}일 후"],
+            ["{0}시간 후", "{0}시간 후", "{0}시간 후"],
+            ["{0}분 후", "{0}분 후", "{0}분 후"],
+            ["{0}초 후", "{0}초 후", "{0}초 후"],
+        ],
+        past: [
+            ["{0}년 전", "{0}년 전", "{0}년 전"],
+            ["{0}개월 전", "{0}개월 전", "{0}개월 전"],
+            ["{0}주 전", "{0}주 전", "{0}주 전"],
+            ["{0}일 전", "{0}일 전", "{0}일 전"],
+            ["{0}시간 전", "{0}시간 전", "{0}시간 전"],
+            ["{0}분 전", "{0}분 전", "{0}분 전"],
+            ["{0}초 전", "{0}초 전", "{0}초 전"],
+        ],
+    },
+};
+
+static ALL: [&Locale; 19] = [
+    &EN, &DE, &FR, &ES, &IT, &PT, &NL, &SV, &DA, &NB, &FI, &PL, &CS, &RU, &UK, &TR, &JA, &ZH, &KO,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(Locale::lookup("en"), Some(&EN));
+        assert_eq!(Locale::lookup("EN-us"), Some(&EN));
+        assert_eq!(Locale::lookup("de_AT"), Some(&DE));
+        assert_eq!(Locale::lookup("pt-BR"), Some(&PT));
+        assert_eq!(Locale::lookup("no"), Some(&NB));
+        assert_eq!(Locale::lookup("nb-NO"), Some(&NB));
+        assert_eq!(Locale::lookup("zh-Hans-CN"), Some(&ZH));
+        assert_eq!(Locale::lookup("xx"), None);
+        assert_eq!(Locale::lookup(""), None);
+        assert_eq!(Locale::lookup("english"), None);
+    }
+
+    #[test]
+    fn test_from_lcid() {
+        assert_eq!(Locale::from_lcid(0x409), Some(&EN));
+        assert_eq!(Locale::from_lcid(0x809), Some(&EN));
+        assert_eq!(Locale::from_lcid(0x407), Some(&DE));
+        assert_eq!(Locale::from_lcid(0xC07), Some(&DE));
+        assert_eq!(Locale::from_lcid(0x40C), Some(&FR));
+        assert_eq!(Locale::from_lcid(0x416), Some(&PT));
+        assert_eq!(Locale::from_lcid(0x411), Some(&JA));
+        assert_eq!(Locale::from_lcid(0x804), Some(&ZH));
+        assert_eq!(Locale::from_lcid(0x414), Some(&NB));
+        assert_eq!(Locale::from_lcid(0x814), None);
+        assert_eq!(Locale::from_lcid(0x41E), None);
+        assert_eq!(Locale::from_lcid(0), None);
+    }
+
+    #[test]
+    fn test_names_distinct() {
+        // Parsing relies on names within a table being unambiguous
+        fn check(names: &[&str]) {
+            for (i, a) in names.iter().enumerate() {
+                for b in &names[i + 1..] {
+                    assert_ne!(a.to_lowercase(), b.to_lowercase());
+                }
+            }
+        }
+        for loc in ALL {
+            check(&loc.month_abbr);
+            check(&loc.month_full);
+            check(&loc.weekday_abbr);
+            check(&loc.weekday_full);
+            check(&loc.am_pm_short);
+            check(&loc.am_pm);
+            check(&loc.era);
+        }
+    }
+    #[test]
+    fn test_plural_category() {
+        let cases = [
+            (PluralRule::OneOther, [2, 0, 2, 2, 2, 2, 2, 2, 2]),
+            (PluralRule::EastSlavic, [2, 0, 1, 2, 2, 2, 0, 1, 2]),
+            (PluralRule::Polish, [2, 0, 1, 2, 2, 2, 2, 1, 2]),
+            (PluralRule::Czech, [2, 0, 1, 2, 2, 2, 2, 2, 2]),
+            (PluralRule::Other, [2, 2, 2, 2, 2, 2, 2, 2, 2]),
+        ];
+        for (rule, expected) in cases {
+            for (n, cat) in [0, 1, 2, 5, 11, 12, 21, 22, 25].into_iter().zip(expected) {
+                assert_eq!(rule.category(n), cat, "{rule:?} {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_relative_format() {
+        assert_eq!(EN.relative.format(4, 3), "in 3 hours");
+        assert_eq!(EN.relative.format(3, -1), "1 day ago");
+        assert_eq!(EN.relative.format(6, 0), "now");
+        assert_eq!(RU.relative.format(0, -22), "22 года назад");
+        assert_eq!(JA.relative.format(5, 10), "10 分後");
+        assert_eq!(EN.relative.format_day(-1, "14:05"), "yesterday at 14:05");
+        assert_eq!(DE.relative.format_day(1, "09:30"), "morgen um 09:30");
+    }
+}
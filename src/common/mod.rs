@@ -1,9 +1,15 @@
 //! Module with reusable components used elsewhere in the crate
+pub mod cbor;
+pub mod day_count_args;
 pub mod difference_args;
 pub mod disambiguation;
 pub mod fmt;
 pub mod format_args;
+pub mod humanize_args;
+pub mod ids;
 pub mod instant;
+pub mod locale;
+pub mod msgpack;
 pub mod parse;
 pub mod pattern;
 pub mod pickle;
@@ -0,0 +1,133 @@
+//! Byte-level codecs for the MessagePack timestamp extension type (-1).
+//! Encoding picks the smallest of the three variants which fits:
+//!
+//! | Variant | Header | Payload (big-endian) | Bytes |
+//! | --- | --- | --- | ---: |
+//! | 32-bit | `d6 ff` | `u32 seconds` | 6 |
+//! | 64-bit | `d7 ff` | `u30 nanos` and `u34 seconds` packed in a `u64` | 10 |
+//! | 96-bit | `c7 0c ff` | `u32 nanos, i64 seconds` | 15 |
+//!
+//! `TimeDelta` uses the same format, with the seconds and nanoseconds
+//! counting from zero instead of the UNIX epoch.
+
+use crate::domain::{instant::Instant, scalar::NS_PER_SEC, time_delta::TimeDelta};
+
+pub(crate) const INVALID_DATA: &str = "invalid MessagePack timestamp";
+
+const FIXEXT4: u8 = 0xd6;
+const FIXEXT8: u8 = 0xd7;
+const EXT8: u8 = 0xc7;
+const TIMESTAMP_TYPE: u8 = -1_i8 as u8;
+
+fn encode(secs: i64, nanos: u32) -> Vec<u8> {
+    let mut out = Vec::with_capacity(15);
+    if secs >> 34 == 0 {
+        if nanos == 0 && secs >> 32 == 0 {
+            out.extend([FIXEXT4, TIMESTAMP_TYPE]);
+            out.extend((secs as u32).to_be_bytes());
+        } else {
+            out.extend([FIXEXT8, TIMESTAMP_TYPE]);
+            out.extend((u64::from(nanos) << 34 | secs as u64).to_be_bytes());
+        }
+    } else {
+        out.extend([EXT8, 12, TIMESTAMP_TYPE]);
+        out.extend(nanos.to_be_bytes());
+        out.extend(secs.to_be_bytes());
+    }
+    out
+}
+
+/// Decode into total nanoseconds
+fn decode(data: &[u8]) -> Option<i128> {
+    let (secs, nanos) = match data {
+        [FIXEXT4, TIMESTAMP_TYPE, payload @ ..] => {
+            (u32::from_be_bytes(payload.try_into().ok()?).into(), 0)
+        }
+        [FIXEXT8, TIMESTAMP_TYPE, payload @ ..] => {
+            let n = u64::from_be_bytes(payload.try_into().ok()?);
+            ((n & ((1 << 34) - 1)) as i64, (n >> 34) as u32)
+        }
+        [EXT8, 12, TIMESTAMP_TYPE, payload @ ..] => {
+            let payload: &[u8; 12] = payload.try_into().ok()?;
+            (
+                i64::from_be_bytes(payload[4..].try_into().unwrap()),
+                u32::from_be_bytes(payload[..4].try_into().unwrap()),
+            )
+        }
+        _ => None?,
+    };
+    (nanos < NS_PER_SEC).then(|| i128::from(secs) * i128::from(NS_PER_SEC) + i128::from(nanos))
+}
+
+pub(crate) fn encode_instant(value: Instant) -> Vec<u8> {
+    encode(value.epoch.get(), value.subsec.get() as u32)
+}
+
+pub(crate) fn decode_instant(data: &[u8]) -> Option<Instant> {
+    Instant::from_timestamp_nanos(decode(data)?)
+}
+
+pub(crate) fn encode_time_delta(value: TimeDelta) -> Vec<u8> {
+    encode(value.secs.get(), value.subsec.get() as u32)
+}
+
+pub(crate) fn decode_time_delta(data: &[u8]) -> Option<TimeDelta> {
+    TimeDelta::from_nanos(decode(data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::scalar::{EpochSecs, SubSecNanos};
+
+    fn instant(secs: i64, nanos: i32) -> Instant {
+        Instant {
+            epoch: EpochSecs::new(secs).unwrap(),
+            subsec: SubSecNanos::new(nanos).unwrap(),
+        }
+    }
+
+    #[test]
+    fn variants() {
+        let cases: &[(Instant, &[u8])] = &[
+            (instant(0, 0), b"\xd6\xff\x00\x00\x00\x00"),
+            (instant(0xffff_ffff, 0), b"\xd6\xff\xff\xff\xff\xff"),
+            (instant(1, 1), b"\xd7\xff\x00\x00\x00\x04\x00\x00\x00\x01"),
+            (
+                instant(1 << 32, 0),
+                b"\xd7\xff\x00\x00\x00\x01\x00\x00\x00\x00",
+            ),
+            (
+                instant(-1, 500_000_000),
+                b"\xc7\x0c\xff\x1d\xcd\x65\x00\xff\xff\xff\xff\xff\xff\xff\xff",
+            ),
+            (
+                instant(1 << 34, 0),
+                b"\xc7\x0c\xff\x00\x00\x00\x00\x00\x00\x00\x04\x00\x00\x00\x00",
+            ),
+        ];
+        for &(value, bytes) in cases {
+            assert_eq!(encode_instant(value), bytes);
+            assert_eq!(decode_instant(bytes), Some(value));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for bytes in [
+            &b""[..],
+            b"\xd6\xff\x00\x00\x00",
+            b"\xd6\x00\x00\x00\x00\x00",
+            b"\xd6\xff\x00\x00\x00\x00\x00",
+            // nanoseconds out of range
+            b"\xd7\xff\xee\x6b\x28\x00\x00\x00\x00\x00",
+            b"\xc7\x0c\xff\x3b\x9a\xca\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+            // wrong length
+            b"\xc7\x0b\xff\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+            // out of range for Instant
+            b"\xc7\x0c\xff\x00\x00\x00\x00\x7f\xff\xff\xff\xff\xff\xff\xff",
+        ] {
+            assert_eq!(decode_instant(bytes), None);
+        }
+    }
+}
@@ -1,64 +1,84 @@
 //! Helpers related to parsing
-use crate::domain::scalar::SubSecNanos;
+use crate::{
+    domain::scalar::SubSecNanos,
+    py::{PyBase, PyErrMarker, PyObj, PyReturn, PyStr, ToPy, none},
+};
 use std::{
-    fmt::Debug,
+    borrow::Cow,
+    fmt::{self, Debug, Display},
     ops::{Index, RangeInclusive, RangeTo},
 };
 
 #[derive(PartialEq, Eq)]
-pub(crate) struct Scan<'a>(&'a [u8]);
+pub(crate) struct Scan<'a> {
+    input: &'a [u8],
+    /// Byte offset of the cursor in `input`
+    pos: usize,
+    /// Where parsing first failed, and what was expected there (if known)
+    failure: Option<(usize, Option<&'static str>)>,
+}
 
 impl<'a> Scan<'a> {
     /// Create a new scanner from a byte slice.
     pub(crate) fn new(inner: &'a [u8]) -> Self {
-        Self(inner)
+        Self {
+            input: inner,
+            pos: 0,
+            failure: None,
+        }
     }
 
     /// Return the next byte in the scanner without consuming it.
     pub(crate) fn peek(&self) -> Option<u8> {
-        self.0.first().copied()
+        self.rest().first().copied()
     }
 
     /// Get the byte at the given index without consuming it.
     pub(crate) fn get(&self, n: usize) -> Option<u8> {
-        self.0.get(n).copied()
+        self.rest().get(n).copied()
     }
 
     /// Consume the next `n` bytes in the scanner.
     pub(crate) fn skip(&mut self, n: usize) -> &mut Self {
-        self.0 = &self.0[n..];
+        assert!(n <= self.rest().len());
+        self.pos += n;
         self
     }
 
     /// Consume the next byte in the scanner.
     pub(crate) fn next(&mut self) -> Option<u8> {
         let a = self.peek()?;
-        self.0 = &self.0[1..];
+        self.pos += 1;
         Some(a)
     }
 
     /// Return the rest of the scanner as a byte slice.
-    pub(crate) fn rest(&self) -> &[u8] {
-        self.0
+    pub(crate) fn rest(&self) -> &'a [u8] {
+        &self.input[self.pos..]
+    }
+
+    /// The byte offset of the cursor in the input.
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
     }
 
     /// Return the rest of the scanner as a byte slice and consume it.
     pub(crate) fn drain(&mut self) -> &'a [u8] {
-        let a = self.0;
-        self.0 = &[];
+        let a = self.rest();
+        self.pos = self.input.len();
         a
     }
 
     /// Take the next `n` bytes from the scanner without checking if they exist.
     pub(crate) fn take_unchecked(&mut self, n: usize) -> &'a [u8] {
-        let (a, b) = self.0.split_at(n);
-        self.0 = b;
+        let a = &self.rest()[..n];
+        self.pos += n;
         a
     }
 
     /// Take the next `n` bytes from the scanner IF they exist.
     pub(crate) fn take(&mut self, n: usize) -> Option<&'a [u8]> {
-        (self.0.len() >= n).then(|| self.take_unchecked(n))
+        (self.rest().len() >= n).then(|| self.take_unchecked(n))
     }
 
     /// Advance the scanner only if the next byte is the expected one.
@@ -82,6 +102,12 @@ impl<'a> Scan<'a> {
         self.advance_on(c).filter(|&b| b).map(|_| ())
     }
 
+    /// Advance the scanner if the next byte is the expected one,
+    /// ignoring ASCII case. Returns None if the byte was not consumed.
+    pub(crate) fn expect_ignore_case(&mut self, c: u8) -> Option<()> {
+        self.transform(|b| b.eq_ignore_ascii_case(&c).then_some(()))
+    }
+
     /// Consume a single ASCII digit from the scanner.
     /// Returns None if the next byte is absent or not a digit.
     pub(crate) fn digit(&mut self) -> Option<u8> {
@@ -96,9 +122,9 @@ impl<'a> Scan<'a> {
 
     /// Parse two digits in the range 00-59.
     pub(crate) fn digits00_59(&mut self) -> Option<u8> {
-        match self.0 {
+        match *self.rest() {
             [a @ b'0'..=b'5', b @ b'0'..=b'9', ..] => {
-                self.0 = &self.0[2..];
+                self.pos += 2;
                 Some((a - b'0') * 10 + b - b'0')
             }
             _ => None,
@@ -108,15 +134,15 @@ impl<'a> Scan<'a> {
     /// Parse two digits in the range 00-60 (allowing leap seconds).
     /// Returns the parsed value, but values of 60 are normalized to 59.
     pub(crate) fn digits00_60_leap(&mut self) -> Option<u8> {
-        match self.0 {
+        match *self.rest() {
             [a @ b'0'..=b'5', b @ b'0'..=b'9', ..] => {
                 // Normal case: 00-59
-                self.0 = &self.0[2..];
+                self.pos += 2;
                 Some((a - b'0') * 10 + (b - b'0'))
             }
             [b'6', b'0', ..] => {
                 // Special case: exactly 60 (leap second) -> normalize to 59
-                self.0 = &self.0[2..];
+                self.pos += 2;
                 Some(59)
             }
             _ => None,
@@ -125,9 +151,9 @@ impl<'a> Scan<'a> {
 
     /// Parse two digits in the range 00-23.
     pub(crate) fn digits00_23(&mut self) -> Option<u8> {
-        match self.0 {
+        match *self.rest() {
             [a @ b'0'..=b'2', b @ b'0'..=b'9', ..] => {
-                self.0 = &self.0[2..];
+                self.pos += 2;
                 Some((a - b'0') * 10 + b - b'0').filter(|&n| n < 24)
             }
             _ => None,
@@ -159,6 +185,16 @@ impl<'a> Scan<'a> {
         Some(total)
     }
 
+    /// Parse exactly `n` ASCII digits (at most 9).
+    pub(crate) fn digits(&mut self, n: usize) -> Option<u32> {
+        debug_assert!(n <= 9);
+        let value = self.rest().get(..n)?.iter().try_fold(0, |total, &c| {
+            c.is_ascii_digit().then(|| total * 10 + u32::from(c - b'0'))
+        })?;
+        self.pos += n;
+        Some(value)
+    }
+
     /// Parse '.' or ',' and up to 9 digits after it. If empty, return 0.
     pub(crate) fn subsec(&mut self) -> Option<SubSecNanos> {
         Some(match self.peek() {
@@ -166,11 +202,11 @@ impl<'a> Scan<'a> {
                 self.skip(1);
                 // If there's a decimal point, the first digit is required
                 let mut total = self.digit()? as i32 * 100_000_000;
-                for (byte, pwr) in self.0.iter().zip((0..8).rev()) {
+                for (byte, pwr) in self.rest().iter().zip((0..8).rev()) {
                     if byte.is_ascii_digit() {
                         total += (byte - b'0') as i32 * 10_i32.pow(pwr);
                     } else {
-                        self.0 = &self.0[(7 - pwr) as usize..];
+                        self.pos += (7 - pwr) as usize;
                         // Safe: 9 digits are always in range of SubSecNanos
                         return Some(SubSecNanos::new_unchecked(total));
                     }
@@ -179,7 +215,7 @@ impl<'a> Scan<'a> {
                 // OR we've reached the end of the scanner.
                 // Remember we've already skipped the first digit,
                 // so we skip ahead 8 more (at most).
-                self.0 = &self.0[self.0.len().min(8)..];
+                self.pos += self.rest().len().min(8);
                 SubSecNanos::new_unchecked(total)
             }
             _ => SubSecNanos::MIN,
@@ -204,7 +240,7 @@ impl<'a> Scan<'a> {
 
     /// Take bytes from the scanner until a predicate is true.
     /// Returns None if the predicate is never true.
-    pub(crate) fn take_until<F>(&mut self, mut f: F) -> Option<&[u8]>
+    pub(crate) fn take_until<F>(&mut self, mut f: F) -> Option<&'a [u8]>
     where
         F: FnMut(u8) -> bool,
     {
@@ -216,7 +252,7 @@ impl<'a> Scan<'a> {
 
     /// Take bytes from the scanner until a predicate is true.
     /// Returns None if the predicate is never true.
-    pub(crate) fn take_until_inclusive<F>(&mut self, mut f: F) -> Option<&[u8]>
+    pub(crate) fn take_until_inclusive<F>(&mut self, mut f: F) -> Option<&'a [u8]>
     where
         F: FnMut(u8) -> bool,
     {
@@ -237,10 +273,10 @@ impl<'a> Scan<'a> {
                 true
             }
             // No whitespace: we're at the end of input
-            None if self.0.is_empty() => false,
+            None if self.is_done() => false,
             // Found whitespace until end of input
             None => {
-                self.0 = &[];
+                self.pos = self.input.len();
                 true
             }
         }
@@ -256,19 +292,63 @@ impl<'a> Scan<'a> {
         self.peek().is_none()
     }
 
-    /// Return the remaining length of the scanner.
-    pub(crate) fn len(&self) -> usize {
-        self.0.len()
-    }
-
     /// Pass the scanner to the function, and check if the scanner is done
-    /// afterwards.
+    /// afterwards. Leftover input is recorded as a failure.
     pub(crate) fn parse_all<F, R>(&mut self, mut f: F) -> Option<R>
     where
         F: FnMut(&mut Self) -> Option<R>,
     {
         let result = f(self)?;
-        self.is_done().then_some(result)
+        if !self.is_done() {
+            self.record(self.pos, None);
+            return None;
+        }
+        Some(result)
+    }
+
+    fn record(&mut self, pos: usize, expected: Option<&'static str>) {
+        self.failure.get_or_insert((pos, expected));
+    }
+
+    /// Record that `expected` didn't match at the given byte offset,
+    /// unless an earlier failure was recorded already.
+    /// Always returns `None`, so it can end a reader with `?`.
+    pub(crate) fn fail_at<T>(&mut self, pos: usize, expected: &'static str) -> Option<T> {
+        self.record(pos, Some(expected));
+        None
+    }
+
+    /// Record that `expected` didn't match at the cursor.
+    pub(crate) fn fail<T>(&mut self, expected: &'static str) -> Option<T> {
+        self.fail_at(self.pos, expected)
+    }
+
+    /// Apply a reader, recording a failure at the cursor
+    /// if it returns `None`.
+    pub(crate) fn read<F, T>(&mut self, expected: &'static str, f: F) -> Option<T>
+    where
+        F: FnOnce(&mut Self) -> Option<T>,
+    {
+        let start = self.pos;
+        f(self).or_else(|| self.fail_at(start, expected))
+    }
+
+    /// Set a `whenever.ParseError` for input that doesn't match the expected
+    /// format, pointing at the recorded failure (if any).
+    #[cold]
+    pub(crate) fn invalid_format(&self, exc: PyObj, input: PyObj) -> PyErrMarker {
+        self.parse_err(exc, format!("Invalid format: {input}"), input)
+    }
+
+    /// Set a `whenever.ParseError` with the given message,
+    /// pointing at the recorded failure (if any).
+    #[cold]
+    pub(crate) fn parse_err(&self, exc: PyObj, msg: impl ToPy, input: PyObj) -> PyErrMarker {
+        let (position, expected) = match self.failure {
+            Some((pos, expected)) => (Some(char_pos(self.input, pos)), expected),
+            None => (None, None),
+        };
+        parse_err(exc, msg, input, position, expected)
     }
 }
 
@@ -278,7 +358,7 @@ impl Index<RangeInclusive<usize>> for Scan<'_> {
     fn index(&self, index: RangeInclusive<usize>) -> &[u8] {
         let start = *index.start();
         let end = *index.end();
-        &self.0[start..=end]
+        &self.rest()[start..=end]
     }
 }
 
@@ -286,7 +366,7 @@ impl Index<RangeTo<usize>> for Scan<'_> {
     type Output = [u8];
 
     fn index(&self, index: RangeTo<usize>) -> &[u8] {
-        &self.0[..index.end]
+        &self.rest()[..index.end]
     }
 }
 
@@ -294,13 +374,13 @@ impl Index<usize> for Scan<'_> {
     type Output = u8;
 
     fn index(&self, index: usize) -> &u8 {
-        &self.0[index]
+        &self.rest()[index]
     }
 }
 
 impl Debug for Scan<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = std::str::from_utf8(self.0).unwrap_or("<invalid utf8>");
+        let s = std::str::from_utf8(self.rest()).unwrap_or("<invalid utf8>");
         f.debug_struct("Scan").field("s", &s).finish()
     }
 }
@@ -318,6 +398,131 @@ pub(crate) fn extract_2_digits(s: &[u8], index: usize) -> Option<u8> {
     Some(extract_digit(s, index)? * 10 + extract_digit(s, index + 1)?)
 }
 
+/// Why (and where) parsing a string failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ParseFailure {
+    pub(crate) msg: String,
+    /// Character index in the input where parsing failed, if known
+    pub(crate) pos: Option<usize>,
+    /// The pattern field or literal expected at `pos`, if known
+    pub(crate) expected: Option<String>,
+}
+
+impl ParseFailure {
+    /// A failure at the given byte offset in the (UTF-8) input `s`
+    pub(crate) fn at(msg: String, s: &[u8], pos: usize, expected: Option<String>) -> Self {
+        Self {
+            msg,
+            pos: Some(char_pos(s, pos)),
+            expected,
+        }
+    }
+
+    /// Set a `whenever.ParseError` for the given input as the current exception.
+    #[cold]
+    pub(crate) fn into_exc(self, exc: PyObj, input: PyStr) -> PyErrMarker {
+        parse_err(
+            exc,
+            self.msg,
+            input.as_py_obj(),
+            self.pos,
+            self.expected.as_deref(),
+        )
+    }
+}
+
+impl From<String> for ParseFailure {
+    fn from(msg: String) -> Self {
+        Self {
+            msg,
+            pos: None,
+            expected: None,
+        }
+    }
+}
+
+impl Display for ParseFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.msg)
+    }
+}
+
+/// Convert a byte offset in UTF-8 input into a character index,
+/// which is how positions are reported to Python.
+pub(crate) fn char_pos(s: &[u8], pos: usize) -> usize {
+    s[..pos].iter().filter(|&&b| b & 0xC0 != 0x80).count()
+}
+
+/// The first `n` characters of UTF-8 input, for showing in error messages
+pub(crate) fn char_prefix(s: &[u8], n: usize) -> Cow<'_, str> {
+    let end = s
+        .iter()
+        .enumerate()
+        .filter(|&(_, &b)| b & 0xC0 != 0x80)
+        .nth(n)
+        .map_or(s.len(), |(i, _)| i);
+    String::from_utf8_lossy(&s[..end])
+}
+
+/// Displays a string quoted the way Python's `repr()` does,
+/// so error messages read the same as in the pure-Python version.
+pub(crate) struct PyRepr<'a>(pub(crate) &'a str);
+
+impl Display for PyRepr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        let quote = if self.0.contains('\'') && !self.0.contains('"') {
+            '"'
+        } else {
+            '\''
+        };
+        f.write_char(quote)?;
+        for c in self.0.chars() {
+            match c {
+                '\\' => f.write_str("\\\\")?,
+                '\t' => f.write_str("\\t")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                c if c == quote => write!(f, "\\{c}")?,
+                // Control characters. Other non-printable characters
+                // are rare enough not to bother matching Python exactly.
+                '\0'..='\x1f' | '\x7f'..='\u{a0}' => write!(f, "\\x{:02x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char(quote)
+    }
+}
+
+/// Set a `whenever.ParseError` as the current exception.
+/// `exc` is the exception class, stored in the module state.
+#[cold]
+pub(crate) fn parse_err(
+    exc: PyObj,
+    msg: impl ToPy,
+    input: PyObj,
+    position: Option<usize>,
+    expected: Option<&str>,
+) -> PyErrMarker {
+    let create = || -> PyReturn {
+        let position = match position {
+            Some(p) => (p as i64).to_py()?,
+            None => none(),
+        };
+        let expected = match expected {
+            Some(e) => e.to_py()?,
+            None => none(),
+        };
+        exc.call_args([*msg.to_py()?, input, *position, *expected])
+    };
+    // If creating the exception fails, that error is set instead
+    if let Ok(err) = create() {
+        // SAFETY: both pointers are valid, and `err` is an instance of `exc`
+        unsafe { pyo3_ffi::PyErr_SetObject(exc.as_ptr(), err.as_ptr()) }
+    }
+    PyErrMarker
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -547,4 +752,27 @@ mod tests {
         assert!(!scan.ascii_whitespace());
         assert!(!scan.ascii_whitespace());
     }
+
+    #[test]
+    fn test_char_pos_and_prefix() {
+        let s = "éé24/€".as_bytes();
+        assert_eq!(char_pos(s, 0), 0);
+        assert_eq!(char_pos(s, 4), 2);
+        assert_eq!(char_pos(s, 7), 5);
+        assert_eq!(char_pos(s, s.len()), 6);
+        assert_eq!(char_prefix(s, 0), "");
+        assert_eq!(char_prefix(s, 3), "éé2");
+        assert_eq!(char_prefix(&s[4..], 4), "24/€");
+        assert_eq!(char_prefix(s, 10), "éé24/€");
+    }
+
+    #[test]
+    fn test_py_repr() {
+        let repr = |s| PyRepr(s).to_string();
+        assert_eq!(repr("-"), "'-'");
+        assert_eq!(repr("it's"), "\"it's\"");
+        assert_eq!(repr("'\""), "'\\'\"'");
+        assert_eq!(repr("a\\b\tc\n"), "'a\\\\b\\tc\\n'");
+        assert_eq!(repr("\x00\u{a0}é"), "'\\x00\\xa0é'");
+    }
 }
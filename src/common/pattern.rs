@@ -8,50 +8,29 @@
 // Optimizations can always be done in a future release.
 
 use crate::{
-    common::fmt::{Sink, format_2_digits, format_4_digits},
+    common::{
+        fmt::{Sink, format_2_digits, format_4_digits},
+        locale::{self, Locale},
+        parse::{ParseFailure, PyRepr, char_pos, char_prefix},
+    },
     domain::{
         date::Date,
         plain_datetime::PlainDateTime,
-        scalar::{Month, Offset, SubSecNanos, Weekday, Year},
+        scalar::{Month, NS_PER_SEC, Offset, SubSecNanos, Weekday, Year},
         time::Time,
+        time_delta::TimeDelta,
     },
     py::{
-        PyAsciiStrBuilder, PyObj, PyResult, PyReturn,
-        exc::{RaiseExt, ResultExt, raise_value_err, warn_with_class},
+        IterKwargs, PyAsciiStrBuilder, PyBase, PyObj, PyResult, PyReturn, PyStaticType, PyStr,
+        PyTuple, ToPy,
+        exc::{RaiseExt, ResultExt, raise_type_err, raise_value_err, warn_with_class},
+        handle_kwargs, match_interned_str,
     },
+    pymodule::State,
     tz::tzif::is_tz_id_char,
 };
-
-// ---- Name tables ----
-
-static MONTH_ABBR: [&str; 13] = [
-    "", "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-];
-static MONTH_FULL: [&str; 13] = [
-    "",
-    "January",
-    "February",
-    "March",
-    "April",
-    "May",
-    "June",
-    "July",
-    "August",
-    "September",
-    "October",
-    "November",
-    "December",
-];
-static WEEKDAY_ABBR: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
-static WEEKDAY_FULL: [&str; 7] = [
-    "Monday",
-    "Tuesday",
-    "Wednesday",
-    "Thursday",
-    "Friday",
-    "Saturday",
-    "Sunday",
-];
+use ahash::AHashMap;
+use std::{fmt::Write, sync::Arc};
 
 // ---- Categories ----
 
@@ -62,6 +41,7 @@ pub(crate) enum Category {
     Time,
     Offset,
     Tz,
+    Delta,
 }
 
 /// A bitmask of allowed categories.
@@ -81,6 +61,7 @@ impl CategorySet {
             Category::Time => 2,
             Category::Offset => 4,
             Category::Tz => 8,
+            Category::Delta => 16,
         };
         self.0 & bit != 0
     }
@@ -101,9 +82,15 @@ pub(crate) struct PatternValues<'a> {
     offset_secs: Option<Offset>,
     tz_id: Option<&'a str>,
     tz_abbrev: Option<&'a str>,
+    locale: &'static Locale,
 }
 
 impl<'a> PatternValues<'a> {
+    pub(crate) fn with_locale(mut self, locale: &'static Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
     pub(crate) fn with_offset(mut self, offset: Offset) -> Self {
         self.offset_secs = Some(offset);
         self
@@ -114,6 +101,14 @@ impl<'a> PatternValues<'a> {
         self.tz_abbrev = Some(abbreviation);
         self
     }
+
+    fn date(&self) -> Date {
+        Date {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+        }
+    }
 }
 
 impl Date {
@@ -142,16 +137,26 @@ impl PlainDateTime {
             offset_secs: None,
             tz_id: None,
             tz_abbrev: None,
+            locale: &locale::EN,
         }
     }
 }
 
 // ---- Parse state ----
 
+const MISSING_DATE_FIELDS: &str = "Pattern must include date fields: \
+     year (YYYY/YY), month (MM/MMM/MMMM), and day (DD); \
+     year and day of year (DDD); \
+     or ISO week-based year (GGGG), week (WW/W), and weekday (EEE/EEEE)";
+
+const MISSING_PIVOT_YEAR: &str = "Parsing a two-digit year (YY) requires a pivot_year";
+
 /// Mutable state accumulating parsed field values.
 #[derive(Debug, Default)]
 pub(crate) struct ParseState {
     year: Option<Year>,
+    /// A two-digit year (YY), resolved along with the rest of the date
+    year2: Option<u8>,
     month: Option<Month>,
     day: Option<u8>,
     hour: Option<u8>,
@@ -162,7 +167,13 @@ pub(crate) struct ParseState {
     pub(crate) offset_secs: Option<Offset>,
     pub(crate) tz_id: Option<String>,
     weekday: Option<Weekday>,
+    day_of_year: Option<u16>,
+    iso_week: Option<u8>,
+    iso_year: Option<Year>,
+    quarter: Option<u8>,
     second_absent: bool,
+    lenient: bool,
+    pivot_year: Option<Year>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -172,8 +183,9 @@ pub(crate) enum AmPm {
 }
 
 impl ParseState {
-    /// Apply AM/PM adjustment after all fields are parsed.
-    pub(crate) fn resolve(&mut self) {
+    /// Apply AM/PM adjustment after all fields are parsed,
+    /// and check that the time fields form a valid time.
+    fn resolve(&mut self) -> Result<(), String> {
         if let (Some(ampm), Some(hour)) = (self.ampm, self.hour) {
             match ampm {
                 AmPm::Pm if hour < 12 => self.hour = Some(hour + 12),
@@ -181,32 +193,104 @@ impl ParseState {
                 _ => {}
             }
         }
+        self.try_time()
+            .map(|_| ())
+            .ok_or_else(|| "Invalid time".into())
+    }
+
+    /// Derive the calendar date from day-of-year or ISO week date fields,
+    /// and check that the date is valid and agrees with any such fields.
+    fn resolve_date(&mut self) -> Result<(), String> {
+        if self.month.is_none() && self.day.is_none() {
+            if let (Some(year), Some(doy)) = (self.year()?, self.day_of_year) {
+                let date = Date::from_ordinal(year, doy)
+                    .ok_or_else(|| format!("day of year out of range: {}", doy))?;
+                self.set_date(date);
+            } else if let (Some(iso_year), Some(week), Some(weekday)) =
+                (self.iso_year, self.iso_week, self.weekday)
+            {
+                let date = Date::from_iso_week_date(iso_year, week, weekday)
+                    .ok_or("Invalid ISO week date")?;
+                if self.year()?.is_some_and(|y| y != date.year) {
+                    return Err("Parsed year does not match the ISO week date".into());
+                }
+                self.set_date(date);
+            }
+        }
+        let (Some(year), Some(month), Some(day)) = (self.year()?, self.month, self.day) else {
+            return Ok(());
+        };
+        let date = Date::new(year, month, day).ok_or("Invalid date")?;
+        if self.weekday.is_some_and(|w| w != date.day_of_week()) {
+            return Err("Parsed weekday does not match the date".into());
+        }
+        if self
+            .day_of_year
+            .is_some_and(|doy| doy != date.day_of_year())
+        {
+            return Err("Parsed day of year does not match the date".into());
+        }
+        if self.quarter.is_some_and(|q| q != quarter(month)) {
+            return Err("Parsed quarter does not match the date".into());
+        }
+        let (iso_year, week) = date.iso_year_week();
+        if self.iso_week.is_some_and(|w| w != week) {
+            return Err("Parsed ISO week does not match the date".into());
+        }
+        if self.iso_year.is_some_and(|y| y.get() as i32 != iso_year) {
+            return Err("Parsed ISO week-based year does not match the date".into());
+        }
+        Ok(())
+    }
+
+    /// The parsed year. A two-digit year is resolved to the year in
+    /// the 100-year window starting 50 years before the pivot year.
+    /// For example, a pivot of 2000 gives years 1950-2049.
+    fn year(&self) -> Result<Option<Year>, String> {
+        let Some(yy) = self.year2 else {
+            return Ok(self.year);
+        };
+        // Checked before parsing, see CompiledPattern::check_pivot_year
+        let pivot = self.pivot_year.ok_or(MISSING_PIVOT_YEAR)?;
+        let start = pivot.get() as i32 - 50;
+        let year = start + (yy as i32 - start).rem_euclid(100);
+        Year::from_i32(year)
+            .map(Some)
+            .ok_or_else(|| format!("year out of range: {}", year))
+    }
+
+    fn set_date(&mut self, date: Date) {
+        self.year = Some(date.year);
+        self.year2 = None;
+        self.month = Some(date.month);
+        self.day = Some(date.day);
+    }
+
+    /// The parsed date, with any two-digit year resolved using the pivot year.
+    /// Its validity has already been checked while parsing,
+    /// so this only fails if the pattern lacks any of the required fields.
+    pub(crate) fn date(&self) -> PyResult<Date> {
+        self.year()
+            .into_value_err()?
+            .zip(self.month)
+            .zip(self.day)
+            .and_then(|((year, month), day)| Date::new(year, month, day))
+            .ok_or_value_err(MISSING_DATE_FIELDS)
     }
 
-    pub(crate) fn date(&self, required_fields_message: &str) -> PyResult<Date> {
-        let year = self.year.ok_or_value_err(required_fields_message)?;
-        let month = self.month.ok_or_value_err(required_fields_message)?;
-        let day = self.day.ok_or_value_err(required_fields_message)?;
-        Date::new(year, month, day).ok_or_value_err("Invalid date")
+    /// The parsed time, with absent fields set to zero
+    pub(crate) fn time(&self) -> Time {
+        // SAFETY: the time fields have been checked while parsing
+        self.try_time().unwrap()
     }
 
-    pub(crate) fn time(&self) -> PyResult<Time> {
+    fn try_time(&self) -> Option<Time> {
         Time::new(
             self.hour.unwrap_or(0),
             self.minute.unwrap_or(0),
             self.second.unwrap_or(0),
             self.nanos,
         )
-        .ok_or_value_err("Invalid time")
-    }
-
-    pub(crate) fn validate_weekday(&self, date: Date) -> PyResult<()> {
-        if let Some(weekday) = self.weekday
-            && date.day_of_week() != weekday
-        {
-            raise_value_err("Parsed weekday does not match the date")?;
-        }
-        Ok(())
     }
 }
 
@@ -214,24 +298,81 @@ impl ParseState {
 
 /// Compiled pattern element: either a literal string or a field specifier.
 #[derive(Debug)]
-enum Element<'a> {
-    /// A run of literal bytes, borrowing directly from the compiled pattern string.
-    Literal(&'a [u8]),
+enum Element {
+    /// A run of literal bytes, copied from the pattern string.
+    Literal(Box<[u8]>),
     Field(Field),
 }
 
 #[derive(Debug)]
-pub(crate) struct CompiledPattern<'a> {
-    elements: Vec<Element<'a>>,
+pub(crate) struct CompiledPattern {
+    elements: Vec<Element>,
+    /// The locale set by the pattern itself, e.g. `[$-407]` in a spreadsheet format
+    locale: Option<&'static Locale>,
+    /// The code each element was translated from, for spreadsheet formats.
+    /// Errors show these instead of the equivalent pattern fields.
+    sources: Vec<Box<str>>,
 }
 
-impl<'a> CompiledPattern<'a> {
-    pub(crate) fn compile(pattern: &'a [u8]) -> Result<Self, String> {
-        compile(pattern).map(|elements| Self { elements })
+impl CompiledPattern {
+    pub(crate) fn compile(pattern: &[u8]) -> Result<Self, String> {
+        compile(pattern).map(|elements| Self {
+            elements,
+            locale: None,
+            sources: Vec::new(),
+        })
+    }
+
+    /// Compile a strftime-style format string (e.g. `%Y-%m-%d`)
+    /// into the equivalent pattern.
+    pub(crate) fn compile_strftime(fmt: &[u8]) -> Result<Self, String> {
+        compile_strftime(fmt).map(|elements| Self {
+            elements,
+            locale: None,
+            sources: Vec::new(),
+        })
+    }
+
+    /// Compile a spreadsheet number format (e.g. `dd/mm/yyyy hh:mm`)
+    /// into the equivalent pattern.
+    pub(crate) fn compile_spreadsheet(fmt: &[u8]) -> Result<Self, String> {
+        compile_spreadsheet(fmt).map(|(elements, sources, locale)| Self {
+            elements,
+            locale,
+            sources,
+        })
+    }
+
+    /// The locale to format or parse with: the one set by the pattern itself,
+    /// if any, otherwise the `locale` argument (English by default).
+    /// Raises a `ValueError` if both are given and they differ.
+    pub(crate) fn locale(&self, arg: Option<&'static Locale>) -> PyResult<&'static Locale> {
+        match (self.locale, arg) {
+            (Some(own), Some(arg)) if !std::ptr::eq(own, arg) => raise_value_err(format!(
+                "The pattern's locale ID selects locale '{}', which conflicts with locale='{}'",
+                own.tag, arg.tag
+            )),
+            (own, arg) => Ok(own.or(arg).unwrap_or(&locale::EN)),
+        }
     }
 
     pub(crate) fn validate(&self, allowed: CategorySet, type_name: &str) -> PyResult<()> {
-        validate_fields(&self.elements, allowed, type_name)
+        validate_fields(&self.elements, &self.sources, allowed, type_name)
+    }
+
+    /// Raise a `ValueError` if the pattern has a two-digit year,
+    /// but there's no `pivot_year` to resolve it with.
+    /// This is a misuse of the API, so it's checked before parsing any input.
+    pub(crate) fn check_pivot_year(&self, pivot_year: Option<Year>) -> PyResult<()> {
+        if pivot_year.is_none()
+            && self
+                .elements
+                .iter()
+                .any(|el| matches!(el, Element::Field(Field::Year2)))
+        {
+            raise_value_err(MISSING_PIVOT_YEAR)?
+        }
+        Ok(())
     }
 
     pub(crate) fn warn_if_ambiguous_12h(&self, warning_cls: PyObj) -> PyResult<()> {
@@ -249,8 +390,292 @@ impl<'a> CompiledPattern<'a> {
         format_to_py(&self.elements, values)
     }
 
-    pub(crate) fn parse(&self, input: &[u8]) -> Result<ParseState, String> {
-        parse_to_state(&self.elements, input)
+    /// Parse into fields. A conflicting `locale` argument must have been
+    /// rejected already, see [`Self::locale`].
+    pub(crate) fn parse(
+        &self,
+        input: &[u8],
+        locale: Option<&'static Locale>,
+        lenient: bool,
+        pivot_year: Option<Year>,
+    ) -> Result<ParseState, ParseFailure> {
+        let locale = self.locale.or(locale).unwrap_or(&locale::EN);
+        parse_to_state(&self.elements, input, locale, lenient, pivot_year)
+    }
+
+    /// Raise a `ValueError` if this isn't a valid pattern for a delta.
+    pub(crate) fn validate_delta(&self, type_name: &str, style: Style) -> PyResult<()> {
+        match style {
+            Style::Pattern => validate_delta_fields(&self.elements, &self.sources, type_name),
+            Style::Strftime => Err(format!(
+                "{} patterns don't support style='strftime'",
+                type_name
+            )),
+            Style::Spreadsheet => validate_delta_fields(&self.elements, &self.sources, type_name)
+                .and_then(|()| validate_elapsed_fields(&self.elements, type_name)),
+        }
+        .into_value_err()
+    }
+
+    /// Format a delta. The pattern must have passed [`Self::validate_delta`].
+    pub(crate) fn format_delta(&self, delta: TimeDelta) -> PyReturn {
+        format_delta(&self.elements, delta)
+            .into_value_err()?
+            .to_py()
+    }
+
+    /// Parse a delta. The pattern must have passed [`Self::validate_delta`].
+    pub(crate) fn parse_delta(
+        &self,
+        input: &[u8],
+        lenient: bool,
+    ) -> Result<TimeDelta, ParseFailure> {
+        parse_delta(&self.elements, input, lenient)
+    }
+}
+
+/// The syntax of a pattern string
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Style {
+    /// whenever's own pattern syntax, e.g. `YYYY-MM-DD`
+    Pattern,
+    /// strftime directives, e.g. `%Y-%m-%d`
+    Strftime,
+    /// Spreadsheet number formats, e.g. `dd/mm/yyyy hh:mm AM/PM`
+    Spreadsheet,
+}
+
+impl Style {
+    pub(crate) fn from_py(obj: PyObj, state: &State) -> PyResult<Self> {
+        match_interned_str(
+            "style",
+            obj,
+            &[
+                (*state.str_pattern, Style::Pattern),
+                (*state.str_strftime, Style::Strftime),
+                (*state.str_spreadsheet, Style::Spreadsheet),
+            ],
+        )
+    }
+
+    pub(crate) fn to_py_str(self, state: &State) -> PyObj {
+        match self {
+            Style::Pattern => *state.str_pattern,
+            Style::Strftime => *state.str_strftime,
+            Style::Spreadsheet => *state.str_spreadsheet,
+        }
+    }
+}
+
+/// A small cache of compiled patterns, keyed by the pattern string.
+/// Saves recompiling when the same `str` pattern is passed repeatedly,
+/// e.g. to `format()` in a loop.
+#[derive(Debug, Default)]
+pub(crate) struct PatternCache {
+    pattern: AHashMap<Box<[u8]>, Arc<CompiledPattern>>,
+    strftime: AHashMap<Box<[u8]>, Arc<CompiledPattern>>,
+    spreadsheet: AHashMap<Box<[u8]>, Arc<CompiledPattern>>,
+}
+
+impl PatternCache {
+    const MAX_SIZE: usize = 64;
+
+    pub(crate) fn get_or_compile(
+        &mut self,
+        pattern: &[u8],
+        style: Style,
+    ) -> Result<Arc<CompiledPattern>, String> {
+        let (cache, compile): (_, fn(&[u8]) -> _) = match style {
+            Style::Pattern => (&mut self.pattern, CompiledPattern::compile),
+            Style::Strftime => (&mut self.strftime, CompiledPattern::compile_strftime),
+            Style::Spreadsheet => (&mut self.spreadsheet, CompiledPattern::compile_spreadsheet),
+        };
+        if let Some(compiled) = cache.get(pattern) {
+            return Ok(compiled.clone());
+        }
+        let compiled = Arc::new(compile(pattern)?);
+        // Simply start over once full. Real-world programs
+        // use only a handful of distinct patterns.
+        if cache.len() >= Self::MAX_SIZE {
+            cache.clear();
+        }
+        cache.insert(pattern.into(), compiled.clone());
+        Ok(compiled)
+    }
+}
+
+/// Handle the keyword arguments of the `format()` methods: `locale` and `style`
+pub(crate) fn format_kwargs(
+    state: &State,
+    kwargs: &mut IterKwargs,
+) -> PyResult<(Option<&'static Locale>, Style)> {
+    let mut locale_obj = None;
+    let mut style = Style::Pattern;
+    handle_kwargs("format", kwargs, |key, value, eq| {
+        if eq(key, *state.str_locale) {
+            locale_obj = Some(value);
+        } else if eq(key, *state.str_style) {
+            style = Style::from_py(value, state)?;
+        } else {
+            return Ok(false);
+        }
+        Ok(true)
+    })?;
+    Ok((Locale::from_py_opt(locale_obj)?, style))
+}
+
+/// Convert the `pivot_year` argument of `parse()`, which may be `None`
+pub(crate) fn pivot_year_from_py(obj: PyObj) -> PyResult<Option<Year>> {
+    if obj.is_none() {
+        return Ok(None);
+    }
+    Year::from_i64(obj.expect_int("pivot_year")?.to_i64()?)
+        .ok_or_value_err("pivot_year out of range")
+        .map(Some)
+}
+
+/// Compile a pattern passed as a Python `str`, using the module's cache.
+pub(crate) fn compile_cached(
+    state: &State,
+    pattern_obj: PyObj,
+    style: Style,
+    type_err_msg: &'static str,
+) -> PyResult<Arc<CompiledPattern>> {
+    let pattern_pystr = pattern_obj
+        .cast_exact::<PyStr>()
+        .ok_or_type_err(type_err_msg)?;
+    let pattern = pattern_pystr.as_utf8()?;
+    state
+        .pattern_cache
+        .with_mut(|cache| cache.get_or_compile(pattern, style))
+        .into_value_err()
+}
+
+/// The compiled `format` argument of a `parse()` method
+pub(crate) enum Formats {
+    One(Arc<CompiledPattern>),
+    /// Several patterns to try in order, along with the repr of their source
+    Many(Vec<(String, Arc<CompiledPattern>)>),
+}
+
+impl Formats {
+    /// Compile the `format` argument: either a single pattern `str`,
+    /// or a list or tuple of them.
+    /// Each pattern is validated against the fields `allowed` for the type.
+    pub(crate) fn compile(
+        state: &State,
+        fmt_obj: PyObj,
+        style: Style,
+        allowed: CategorySet,
+        type_name: &str,
+    ) -> PyResult<Self> {
+        Self::compile_with(state, fmt_obj, style, |p| p.validate(allowed, type_name))
+    }
+
+    /// Like [`Formats::compile`], but with a custom validation of each pattern.
+    pub(crate) fn compile_with(
+        state: &State,
+        fmt_obj: PyObj,
+        style: Style,
+        validate: impl Fn(&CompiledPattern) -> PyResult<()>,
+    ) -> PyResult<Self> {
+        const TYPE_ERR: &str = "format must be str, or a list or tuple of str";
+        if PyStr::isinstance_exact(fmt_obj) {
+            let compiled = compile_cached(state, fmt_obj, style, TYPE_ERR)?;
+            validate(&compiled)?;
+            return Ok(Formats::One(compiled));
+        }
+        // SAFETY: calling the C API with a valid object pointer
+        if !PyTuple::isinstance(fmt_obj) && unsafe { pyo3_ffi::PyList_Check(fmt_obj.as_ptr()) } == 0
+        {
+            raise_type_err(TYPE_ERR)?
+        }
+        let items = fmt_obj.to_tuple()?;
+        if items.len() == 0 {
+            raise_value_err("format must contain at least one pattern")?
+        }
+        let mut patterns = Vec::with_capacity(items.len() as usize);
+        for item in items.iter() {
+            let compiled = compile_cached(state, item, style, TYPE_ERR)?;
+            validate(&compiled)?;
+            // The repr identifies the pattern in error messages
+            patterns.push((item.to_string(), compiled));
+        }
+        if let [(_, compiled)] = &patterns[..] {
+            return Ok(Formats::One(compiled.clone()));
+        }
+        Ok(Formats::Many(patterns))
+    }
+
+    /// Raise a `ValueError` if the locale set by any of the patterns
+    /// conflicts with the `locale` argument. See [`CompiledPattern::locale`].
+    pub(crate) fn check_locale(&self, arg: Option<&'static Locale>) -> PyResult<()> {
+        match self {
+            Formats::One(pattern) => pattern.locale(arg).map(drop),
+            Formats::Many(patterns) => patterns
+                .iter()
+                .try_for_each(|(_, pattern)| pattern.locale(arg).map(drop)),
+        }
+    }
+
+    /// See [`CompiledPattern::check_pivot_year`]
+    pub(crate) fn check_pivot_year(&self, pivot_year: Option<Year>) -> PyResult<()> {
+        match self {
+            Formats::One(pattern) => pattern.check_pivot_year(pivot_year),
+            Formats::Many(patterns) => patterns
+                .iter()
+                .try_for_each(|(_, pattern)| pattern.check_pivot_year(pivot_year)),
+        }
+    }
+
+    /// Parse with the first pattern that matches the input.
+    /// If none match, the error lists why each pattern failed,
+    /// and points to the failure that got furthest into the input.
+    pub(crate) fn parse(
+        &self,
+        input: &[u8],
+        locale: Option<&'static Locale>,
+        lenient: bool,
+        pivot_year: Option<Year>,
+    ) -> Result<ParseState, ParseFailure> {
+        self.parse_with(|p| p.parse(input, locale, lenient, pivot_year))
+    }
+
+    /// Parse with a delta pattern. See [`CompiledPattern::parse_delta`].
+    pub(crate) fn parse_delta(
+        &self,
+        input: &[u8],
+        lenient: bool,
+    ) -> Result<TimeDelta, ParseFailure> {
+        self.parse_with(|p| p.parse_delta(input, lenient))
+    }
+
+    fn parse_with<T>(
+        &self,
+        mut parse: impl FnMut(&CompiledPattern) -> Result<T, ParseFailure>,
+    ) -> Result<T, ParseFailure> {
+        match self {
+            Formats::One(pattern) => parse(pattern),
+            Formats::Many(patterns) => {
+                let mut err = ParseFailure::from(format!(
+                    "Input doesn't match any of the {} patterns:",
+                    patterns.len()
+                ));
+                for (source, pattern) in patterns {
+                    match parse(pattern) {
+                        Ok(parsed) => return Ok(parsed),
+                        Err(e) => {
+                            write!(err.msg, "\n- {}: {}", source, e).unwrap();
+                            if e.pos.is_some() && (err.pos.is_none() || e.pos > err.pos) {
+                                err.pos = e.pos;
+                                err.expected = e.expected;
+                            }
+                        }
+                    }
+                }
+                Err(err)
+            }
+        }
     }
 }
 
@@ -267,6 +692,12 @@ enum Field {
     DayUnpadded,
     WeekdayAbbr,
     WeekdayFull,
+    DayOfYear,
+    IsoWeek,
+    IsoWeekUnpadded,
+    IsoYear,
+    Quarter,
+    Era,
     Hour24,
     Hour24Unpadded,
     Hour12,
@@ -282,10 +713,16 @@ enum Field {
     DotFrac(u8),   // decimal point followed by trimmed fractional seconds (width 1-9)
     AmPmShort,
     AmPmFull,
+    /// A spreadsheet `a/p` code: whether the AM and PM letters are lowercase
+    AmPmLetter(bool, bool),
     OffsetLower(u8), // width 1-5
     OffsetUpper(u8), // width 1-5
     TzId,
     TzAbbrev,
+    SignMinus,
+    SignAlways,
+    /// A spreadsheet elapsed-time code such as `[h]`, including the sign
+    Elapsed(DeltaUnit, bool),
 }
 
 impl Field {
@@ -302,7 +739,13 @@ impl Field {
             | Self::Day
             | Self::DayUnpadded
             | Self::WeekdayAbbr
-            | Self::WeekdayFull => Category::Date,
+            | Self::WeekdayFull
+            | Self::DayOfYear
+            | Self::IsoWeek
+            | Self::IsoWeekUnpadded
+            | Self::IsoYear
+            | Self::Quarter
+            | Self::Era => Category::Date,
             Self::Hour24
             | Self::Hour24Unpadded
             | Self::Hour12
@@ -317,9 +760,11 @@ impl Field {
             | Self::FracTrim(_)
             | Self::DotFrac(_)
             | Self::AmPmShort
-            | Self::AmPmFull => Category::Time,
+            | Self::AmPmFull
+            | Self::AmPmLetter(..) => Category::Time,
             Self::OffsetLower(_) | Self::OffsetUpper(_) => Category::Offset,
             Self::TzId | Self::TzAbbrev => Category::Tz,
+            Self::SignMinus | Self::SignAlways | Self::Elapsed(..) => Category::Delta,
         }
     }
 
@@ -335,15 +780,27 @@ impl Field {
             Self::Minute | Self::MinuteUnpadded => 5,
             Self::Second | Self::SecondUnpadded | Self::SecondOpt | Self::ColonSec => 6,
             Self::FracExact(_) | Self::FracTrim(_) | Self::DotFrac(_) => 7,
-            Self::AmPmShort | Self::AmPmFull => 8,
+            Self::AmPmShort | Self::AmPmFull | Self::AmPmLetter(..) => 8,
             Self::OffsetLower(_) | Self::OffsetUpper(_) => 9,
             Self::TzId => 10,
             Self::TzAbbrev => 11,
+            Self::DayOfYear => 12,
+            Self::IsoWeek | Self::IsoWeekUnpadded => 13,
+            Self::IsoYear => 14,
+            Self::Quarter => 15,
+            Self::Era => 16,
+            Self::SignMinus | Self::SignAlways => 17,
+            Self::Elapsed(unit, _) => match unit {
+                DeltaUnit::Seconds => 6,
+                DeltaUnit::Minutes => 5,
+                DeltaUnit::Hours => 4,
+                DeltaUnit::Days => 2,
+            },
         })
     }
 
     fn is_format_only(self) -> bool {
-        matches!(self, Self::Year2 | Self::TzAbbrev)
+        matches!(self, Self::TzAbbrev)
     }
 
     /// Display name for error messages.
@@ -359,6 +816,12 @@ impl Field {
             Self::DayUnpadded => "D",
             Self::WeekdayAbbr => "EEE",
             Self::WeekdayFull => "EEEE",
+            Self::DayOfYear => "DDD",
+            Self::IsoWeek => "WW",
+            Self::IsoWeekUnpadded => "W",
+            Self::IsoYear => "GGGG",
+            Self::Quarter => "Q",
+            Self::Era => "N",
             Self::Hour24 => "hh",
             Self::Hour24Unpadded => "h",
             Self::Hour12 => "ii",
@@ -404,6 +867,12 @@ impl Field {
             },
             Self::AmPmShort => "a",
             Self::AmPmFull => "aa",
+            Self::AmPmLetter(lower_am, lower_pm) => match (lower_am, lower_pm) {
+                (true, true) => "a/p",
+                (true, false) => "a/P",
+                (false, true) => "A/p",
+                (false, false) => "A/P",
+            },
             Self::OffsetLower(w) => match w {
                 1 => "x",
                 2 => "xx",
@@ -420,6 +889,18 @@ impl Field {
             },
             Self::TzId => "VV",
             Self::TzAbbrev => "zz",
+            Self::SignMinus => "n",
+            Self::SignAlways => "nn",
+            Self::Elapsed(unit, padded) => match (unit, padded) {
+                (DeltaUnit::Seconds, false) => "[s]",
+                (DeltaUnit::Seconds, true) => "[ss]",
+                (DeltaUnit::Minutes, false) => "[m]",
+                (DeltaUnit::Minutes, true) => "[mm]",
+                (DeltaUnit::Hours, false) => "[h]",
+                (DeltaUnit::Hours, true) => "[hh]",
+                (DeltaUnit::Days, false) => "[d]",
+                (DeltaUnit::Days, true) => "[dd]",
+            },
         }
     }
 }
@@ -467,7 +948,7 @@ fn is_reserved_char(ch: u8) -> bool {
 // ---- Pattern compilation ----
 
 /// Compile a pattern string into a list of elements.
-fn compile(pattern: &[u8]) -> Result<Vec<Element<'_>>, String> {
+fn compile(pattern: &[u8]) -> Result<Vec<Element>, String> {
     if pattern.len() > 1000 {
         return Err("Pattern string too long (max 1000 characters)".to_string());
     }
@@ -492,7 +973,7 @@ fn compile(pattern: &[u8]) -> Result<Vec<Element<'_>>, String> {
         // Quoted literal — flush pending first (never consumed by a quoted literal)
         if ch == b'\'' {
             if let Some(pos) = pending.take() {
-                elements.push(Element::Literal(&pattern[pos..pos + 1]));
+                elements.push(Element::Literal(pattern[pos..pos + 1].into()));
             }
             i = compile_quoted_literal(pattern, i, n, &mut elements)?;
             continue;
@@ -506,7 +987,7 @@ fn compile(pattern: &[u8]) -> Result<Vec<Element<'_>>, String> {
                 (Some(pos), Field::SecondOpt) if pattern[pos] == b':' => Field::ColonSec,
                 (Some(pos), f) => {
                     // pending not consumed — flush as a literal first
-                    elements.push(Element::Literal(&pattern[pos..pos + 1]));
+                    elements.push(Element::Literal(pattern[pos..pos + 1].into()));
                     f
                 }
                 (None, f) => f,
@@ -518,7 +999,7 @@ fn compile(pattern: &[u8]) -> Result<Vec<Element<'_>>, String> {
 
         // From here on, pending (if any) is not consumable — flush it
         if let Some(pos) = pending.take() {
-            elements.push(Element::Literal(&pattern[pos..pos + 1]));
+            elements.push(Element::Literal(pattern[pos..pos + 1].into()));
         }
 
         // Other ASCII letters are errors
@@ -550,22 +1031,23 @@ fn compile(pattern: &[u8]) -> Result<Vec<Element<'_>>, String> {
             while i < n && is_literal_char(pattern[i]) {
                 i += 1;
             }
-            elements.push(Element::Literal(&pattern[start..i]));
+            elements.push(Element::Literal(pattern[start..i].into()));
             continue;
         }
 
         return Err(format!(
-            "Unexpected character {:?} at position {}. Use quotes for literal text: '...'",
-            ch as char, i
+            "Unexpected character {} at position {}. Use quotes for literal text: '...'",
+            PyRepr(&(ch as char).to_string()),
+            i
         ));
     }
 
     // Flush any pending prefix left at end of pattern
     if let Some(pos) = pending {
-        elements.push(Element::Literal(&pattern[pos..pos + 1]));
+        elements.push(Element::Literal(pattern[pos..pos + 1].into()));
     }
 
-    validate_cross_fields(&elements)?;
+    validate_cross_fields(&elements, &[])?;
     Ok(elements)
 }
 
@@ -575,6 +1057,11 @@ fn is_spec_char(ch: u8) -> bool {
         b'Y' | b'M'
             | b'D'
             | b'E'
+            | b'W'
+            | b'G'
+            | b'Q'
+            | b'N'
+            | b'n'
             | b'h'
             | b'i'
             | b'm'
@@ -590,16 +1077,16 @@ fn is_spec_char(ch: u8) -> bool {
     )
 }
 
-fn compile_quoted_literal<'a>(
-    pattern: &'a [u8],
+fn compile_quoted_literal(
+    pattern: &[u8],
     start: usize,
     n: usize,
-    elements: &mut Vec<Element<'a>>,
+    elements: &mut Vec<Element>,
 ) -> Result<usize, String> {
     let mut i = start + 1; // skip opening quote
     if i < n && pattern[i] == b'\'' {
         // '' is an escaped single quote — emit exactly one apostrophe byte
-        elements.push(Element::Literal(&pattern[i..i + 1]));
+        elements.push(Element::Literal(pattern[i..i + 1].into()));
         return Ok(i + 1);
     }
     let text_start = i;
@@ -610,7 +1097,7 @@ fn compile_quoted_literal<'a>(
         return Err("Unterminated quoted literal in pattern".into());
     }
     if i > text_start {
-        elements.push(Element::Literal(&pattern[text_start..i]));
+        elements.push(Element::Literal(pattern[text_start..i].into()));
     }
     Ok(i + 1) // skip closing quote
 }
@@ -668,13 +1155,36 @@ fn compile_specifier(
         b'D' => match count {
             1 => Field::DayUnpadded,
             2 => Field::Day,
-            _ => return Err(bad_count_err(ch, count, start, "2, 1")),
+            3 => Field::DayOfYear,
+            _ => return Err(bad_count_err(ch, count, start, "3, 2, 1")),
         },
         b'E' => match count {
             3 => Field::WeekdayAbbr,
             4 => Field::WeekdayFull,
             _ => return Err(bad_count_err(ch, count, start, "4, 3")),
         },
+        b'W' => match count {
+            1 => Field::IsoWeekUnpadded,
+            2 => Field::IsoWeek,
+            _ => return Err(bad_count_err(ch, count, start, "2, 1")),
+        },
+        b'G' => match count {
+            4 => Field::IsoYear,
+            _ => return Err(bad_count_err(ch, count, start, "4")),
+        },
+        b'Q' => match count {
+            1 => Field::Quarter,
+            _ => return Err(bad_count_err(ch, count, start, "1")),
+        },
+        b'N' => match count {
+            1 => Field::Era,
+            _ => return Err(bad_count_err(ch, count, start, "1")),
+        },
+        b'n' => match count {
+            1 => Field::SignMinus,
+            2 => Field::SignAlways,
+            _ => return Err(bad_count_err(ch, count, start, "2, 1")),
+        },
         b'h' => match count {
             1 => Field::Hour24Unpadded,
             2 => Field::Hour24,
@@ -726,12 +1236,494 @@ fn bad_count_err(ch: u8, count: usize, start: usize, valid: &str) -> String {
     )
 }
 
-fn validate_cross_fields(elements: &[Element<'_>]) -> Result<(), String> {
+// ---- strftime translation ----
+
+/// Translate a strftime format string into pattern elements.
+/// Only directives with an exact pattern equivalent are supported,
+/// so the result behaves just like the equivalent pattern.
+fn compile_strftime(fmt: &[u8]) -> Result<Vec<Element>, String> {
+    if fmt.len() > 1000 {
+        return Err("Pattern string too long (max 1000 characters)".to_string());
+    }
+    if let Some(pos) = fmt.iter().position(|b| !b.is_ascii()) {
+        return Err(format!(
+            "Non-ASCII character at position {}. Patterns must be ASCII-only.",
+            pos
+        ));
+    }
+    let mut elements = Vec::new();
+    let mut literal = Vec::new();
+    translate_strftime(fmt, &mut elements, &mut literal)?;
+    if !literal.is_empty() {
+        elements.push(Element::Literal(literal.into()));
+    }
+    validate_cross_fields(&elements, &[])?;
+    Ok(elements)
+}
+
+fn translate_strftime(
+    fmt: &[u8],
+    elements: &mut Vec<Element>,
+    literal: &mut Vec<u8>,
+) -> Result<(), String> {
+    let n = fmt.len();
+    let mut i = 0;
+    while i < n {
+        let ch = fmt[i];
+        if ch != b'%' {
+            literal.push(ch);
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        // Flags: `-` (no padding, a glibc extension) and `:` (for `%:z`)
+        let flag = match fmt.get(i) {
+            Some(&f @ (b'-' | b':')) => {
+                i += 1;
+                Some(f)
+            }
+            _ => None,
+        };
+        let Some(&directive) = fmt.get(i) else {
+            return Err(format!(
+                "Incomplete strftime directive at position {}",
+                start
+            ));
+        };
+        i += 1;
+        let field = match (directive, flag) {
+            (b'%', None) => {
+                literal.push(b'%');
+                continue;
+            }
+            (b'n', None) => {
+                literal.push(b'\n');
+                continue;
+            }
+            (b't', None) => {
+                literal.push(b'\t');
+                continue;
+            }
+            // Shorthands for combinations of other directives
+            (b'F', None) => {
+                translate_strftime(b"%Y-%m-%d", elements, literal)?;
+                continue;
+            }
+            (b'D', None) => {
+                translate_strftime(b"%m/%d/%y", elements, literal)?;
+                continue;
+            }
+            (b'T', None) => {
+                translate_strftime(b"%H:%M:%S", elements, literal)?;
+                continue;
+            }
+            (b'R', None) => {
+                translate_strftime(b"%H:%M", elements, literal)?;
+                continue;
+            }
+            (b'Y', None) => Field::Year4,
+            (b'y', None) => Field::Year2,
+            (b'm', None) => Field::MonthNum,
+            (b'm', Some(b'-')) => Field::MonthNumUnpadded,
+            (b'b' | b'h', None) => Field::MonthAbbr,
+            (b'B', None) => Field::MonthFull,
+            (b'd', None) => Field::Day,
+            (b'd', Some(b'-')) => Field::DayUnpadded,
+            (b'j', None) => Field::DayOfYear,
+            (b'a', None) => Field::WeekdayAbbr,
+            (b'A', None) => Field::WeekdayFull,
+            (b'G', None) => Field::IsoYear,
+            (b'V', None) => Field::IsoWeek,
+            (b'V', Some(b'-')) => Field::IsoWeekUnpadded,
+            (b'H', None) => Field::Hour24,
+            (b'H', Some(b'-')) => Field::Hour24Unpadded,
+            (b'I', None) => Field::Hour12,
+            (b'I', Some(b'-')) => Field::Hour12Unpadded,
+            (b'M', None) => Field::Minute,
+            (b'M', Some(b'-')) => Field::MinuteUnpadded,
+            (b'S', None) => Field::Second,
+            (b'S', Some(b'-')) => Field::SecondUnpadded,
+            (b'f', None) => Field::FracExact(6),
+            (b'p', None) => Field::AmPmFull,
+            (b'z', None) => Field::OffsetLower(4),
+            (b'z', Some(b':')) => Field::OffsetLower(5),
+            (b'Z', None) => Field::TzAbbrev,
+            _ => return Err(unsupported_strftime_err(&fmt[start..i], start)),
+        };
+        if !literal.is_empty() {
+            elements.push(Element::Literal(std::mem::take(literal).into()));
+        }
+        elements.push(Element::Field(field));
+    }
+    Ok(())
+}
+
+fn unsupported_strftime_err(directive: &[u8], pos: usize) -> String {
+    let hint = match directive.last() {
+        Some(b'U' | b'W') => " Only ISO weeks are supported: use %G and %V instead.",
+        Some(b'w' | b'u') => " Use %a or %A for the weekday instead.",
+        Some(b'c' | b'x' | b'X') => {
+            " Locale-dependent formats are not supported: spell out the fields instead."
+        }
+        Some(b'e') => " Use %d or %-d instead.",
+        _ => "",
+    };
+    format!(
+        "Unsupported strftime directive '{}' at position {}.{}",
+        // SAFETY: the format string was checked to be ASCII
+        unsafe { std::str::from_utf8_unchecked(directive) },
+        pos,
+        hint
+    )
+}
+
+// ---- Spreadsheet format translation ----
+
+/// Translate a spreadsheet (Excel or LibreOffice) number format into pattern
+/// elements, along with the code each was translated from
+/// and the locale selected by its locale ID (if any).
+/// Only date and time codes are supported.
+/// Non-ASCII text is allowed only in quoted or escaped literals,
+/// and in currency symbols.
+type Spreadsheet = (Vec<Element>, Vec<Box<str>>, Option<&'static Locale>);
+
+fn compile_spreadsheet(fmt: &[u8]) -> Result<Spreadsheet, String> {
+    if fmt.len() > 1000 {
+        return Err("Pattern string too long (max 1000 characters)".to_string());
+    }
+    let mut elements = Vec::new();
+    let mut sources: Vec<Box<str>> = Vec::new();
+    let mut literal = Vec::new();
+    let mut prev_field = None;
+    let mut locale: Option<&'static Locale> = None;
+    let n = fmt.len();
+    let mut i = 0;
+    while i < n {
+        let ch = fmt[i];
+        let start = i;
+        i += 1;
+        if !ch.is_ascii() {
+            return Err(format!(
+                "Non-ASCII character at position {}. Use quotes for literal text: \"...\"",
+                char_pos(fmt, start)
+            ));
+        }
+        let field = match ch.to_ascii_lowercase() {
+            b'"' => {
+                let len = fmt[i..].iter().position(|&c| c == b'"').ok_or_else(|| {
+                    format!(
+                        "Unterminated quoted text at position {}",
+                        char_pos(fmt, start)
+                    )
+                })?;
+                literal.extend_from_slice(&fmt[i..i + len]);
+                i += len + 1;
+                continue;
+            }
+            b'\\' | b'_' | b'*' => {
+                if i == n {
+                    return Err(format!(
+                        "Incomplete escape '{}' at position {}",
+                        ch as char,
+                        char_pos(fmt, start)
+                    ));
+                };
+                // The escaped character may take up several UTF-8 bytes
+                let len = 1 + fmt[i + 1..]
+                    .iter()
+                    .take_while(|&&b| b & 0xC0 == 0x80)
+                    .count();
+                match ch {
+                    b'\\' => literal.extend_from_slice(&fmt[i..i + len]),
+                    // Space the width of the character
+                    b'_' => literal.push(b' '),
+                    // Repeat the character to fill the cell: no equivalent
+                    _ => {}
+                }
+                i += len;
+                continue;
+            }
+            b'[' => {
+                let len = fmt[i..].iter().position(|&c| c == b']').ok_or_else(|| {
+                    format!("Unterminated bracket at position {}", char_pos(fmt, start))
+                })?;
+                let code = &fmt[i..i + len];
+                i += len + 1;
+                match spreadsheet_bracket(code, char_pos(fmt, start))? {
+                    Bracket::Field(f) => f,
+                    Bracket::Literal(text) => {
+                        literal.extend_from_slice(text);
+                        continue;
+                    }
+                    Bracket::Currency(text, lcid_locale) => {
+                        if let Some(loc) = lcid_locale {
+                            if locale.is_some_and(|prev| !std::ptr::eq(prev, loc)) {
+                                return Err(format!(
+                                    "Locale ID '[{}]' at position {} conflicts \
+                                     with an earlier locale ID",
+                                    // SAFETY: the format string is UTF-8, and the
+                                    // brackets around the code are ASCII
+                                    unsafe { std::str::from_utf8_unchecked(code) },
+                                    char_pos(fmt, start)
+                                ));
+                            }
+                            locale = Some(loc);
+                        }
+                        literal.extend_from_slice(text);
+                        continue;
+                    }
+                }
+            }
+            b'a' => {
+                let rest = &fmt[start..];
+                if rest.len() >= 5 && rest[..5].eq_ignore_ascii_case(b"am/pm") {
+                    i += 4;
+                    Field::AmPmFull
+                } else if rest.len() >= 3 && rest[..3].eq_ignore_ascii_case(b"a/p") {
+                    i += 2;
+                    // Each letter is shown in the case it's written in
+                    Field::AmPmLetter(rest[0] == b'a', rest[2] == b'p')
+                } else {
+                    return Err(unsupported_spreadsheet_err(fmt, start, i));
+                }
+            }
+            c @ (b'y' | b'm' | b'd' | b'h' | b's') => {
+                i += fmt[i..]
+                    .iter()
+                    .take_while(|b| b.eq_ignore_ascii_case(&ch))
+                    .count();
+                match (c, i - start) {
+                    (b'y', 1 | 2) => Field::Year2,
+                    (b'y', 3 | 4) => Field::Year4,
+                    (b'm', 1) if is_spreadsheet_minute(prev_field, &fmt[i..]) => {
+                        Field::MinuteUnpadded
+                    }
+                    (b'm', 2) if is_spreadsheet_minute(prev_field, &fmt[i..]) => Field::Minute,
+                    (b'm', 1) => Field::MonthNumUnpadded,
+                    (b'm', 2) => Field::MonthNum,
+                    (b'm', 3) => Field::MonthAbbr,
+                    (b'm', 4) => Field::MonthFull,
+                    (b'd', 1) => Field::DayUnpadded,
+                    (b'd', 2) => Field::Day,
+                    (b'd', 3) => Field::WeekdayAbbr,
+                    (b'd', 4) => Field::WeekdayFull,
+                    (b'h', 1) => Field::Hour24Unpadded,
+                    (b'h', 2) => Field::Hour24,
+                    (b's', 1) => Field::SecondUnpadded,
+                    (b's', 2) => Field::Second,
+                    _ => return Err(unsupported_spreadsheet_err(fmt, start, i)),
+                }
+            }
+            // Fractional seconds, e.g. `ss.000`
+            b'.' if fmt.get(i) == Some(&b'0')
+                && matches!(
+                    prev_field,
+                    Some(
+                        Field::Second
+                            | Field::SecondUnpadded
+                            | Field::Elapsed(DeltaUnit::Seconds, _)
+                    )
+                ) =>
+            {
+                i += fmt[i..].iter().take_while(|&&c| c == b'0').count();
+                if i - start > 10 {
+                    return Err(format!(
+                        "Unsupported fractional seconds '{}' at position {}. \
+                         At most 9 decimals are supported.",
+                        // SAFETY: the fractional seconds code is ASCII
+                        unsafe { std::str::from_utf8_unchecked(&fmt[start..i]) },
+                        char_pos(fmt, start)
+                    ));
+                }
+                literal.push(b'.');
+                Field::FracExact((i - start - 1) as u8)
+            }
+            b' ' | b'$' | b'-' | b'+' | b'/' | b'(' | b')' | b':' | b'!' | b'^' | b'&' | b'\''
+            | b'~' | b'{' | b'}' | b'<' | b'>' | b'=' | b',' | b'.' => {
+                literal.push(ch);
+                continue;
+            }
+            _ => return Err(unsupported_spreadsheet_err(fmt, start, i)),
+        };
+        if !literal.is_empty() {
+            elements.push(Element::Literal(std::mem::take(&mut literal).into()));
+            sources.push("".into());
+        }
+        elements.push(Element::Field(field));
+        // SAFETY: the codes of fields are ASCII
+        sources.push(unsafe { std::str::from_utf8_unchecked(&fmt[start..i]) }.into());
+        prev_field = Some(field);
+    }
+    if !literal.is_empty() {
+        elements.push(Element::Literal(literal.into()));
+        sources.push("".into());
+    }
+    // Hours are on a 12-hour clock if there's an AM/PM code anywhere
+    let is_12h = elements.iter().any(|el| {
+        matches!(
+            el,
+            Element::Field(Field::AmPmFull | Field::AmPmShort | Field::AmPmLetter(..))
+        )
+    });
+    if is_12h {
+        for el in elements.iter_mut() {
+            match el {
+                Element::Field(f @ Field::Hour24) => *f = Field::Hour12,
+                Element::Field(f @ Field::Hour24Unpadded) => *f = Field::Hour12Unpadded,
+                _ => {}
+            }
+        }
+    }
+    validate_cross_fields(&elements, &sources)?;
+    Ok((elements, sources, locale))
+}
+
+/// The meaning of a spreadsheet code in square brackets
+enum Bracket<'a> {
+    Field(Field),
+    Literal(&'a [u8]),
+    /// A currency symbol, and the locale of the locale ID (if any)
+    Currency(&'a [u8], Option<&'static Locale>),
+}
+
+fn spreadsheet_bracket(code: &[u8], pos: usize) -> Result<Bracket<'_>, String> {
+    // A currency symbol and/or locale ID (LCID, in hex),
+    // e.g. `[$-409]` or `[$USD-409]`.
+    if let [b'$', rest @ ..] = code {
+        let Some(p) = rest.iter().position(|&c| c == b'-') else {
+            return Ok(Bracket::Currency(rest, None));
+        };
+        let (text, lcid) = (&rest[..p], &rest[p + 1..]);
+        // SAFETY: the code is UTF-8 and delimited by ASCII brackets
+        let code_str = unsafe { std::str::from_utf8_unchecked(code) };
+        if lcid.eq_ignore_ascii_case(b"F800")
+            || lcid.eq_ignore_ascii_case(b"F400")
+            || lcid.len() >= 5 && lcid[..5].eq_ignore_ascii_case(b"x-sys")
+        {
+            return Err(format!(
+                "Unsupported system date or time format '[{}]' at position {}. \
+                 Spell out the fields instead.",
+                code_str, pos
+            ));
+        }
+        if lcid.is_empty() || lcid.len() > 8 || !lcid.iter().all(u8::is_ascii_hexdigit) {
+            return Err(format!(
+                "Invalid locale ID '[{}]' at position {}",
+                code_str, pos
+            ));
+        }
+        // SAFETY: checked to be at most 8 hex digits above
+        let lcid = u32::from_str_radix(unsafe { std::str::from_utf8_unchecked(lcid) }, 16).unwrap();
+        // The upper bits select a calendar or numeral system, which we don't support
+        return match Locale::from_lcid(lcid).filter(|_| lcid <= 0xFFFF) {
+            Some(loc) => Ok(Bracket::Currency(text, Some(loc))),
+            None => Err(format!(
+                "Unsupported locale ID '[{}]' at position {}",
+                code_str, pos
+            )),
+        };
+    }
+    let mut lower = [0; 16];
+    let lower = match lower.get_mut(..code.len()) {
+        Some(buf) => {
+            buf.copy_from_slice(code);
+            buf.make_ascii_lowercase();
+            &*buf
+        }
+        None => code,
+    };
+    Ok(Bracket::Field(match lower {
+        b"h" => Field::Elapsed(DeltaUnit::Hours, false),
+        b"hh" => Field::Elapsed(DeltaUnit::Hours, true),
+        b"m" => Field::Elapsed(DeltaUnit::Minutes, false),
+        b"mm" => Field::Elapsed(DeltaUnit::Minutes, true),
+        b"s" => Field::Elapsed(DeltaUnit::Seconds, false),
+        b"ss" => Field::Elapsed(DeltaUnit::Seconds, true),
+        // Colors only affect how the cell is displayed
+        b"black" | b"blue" | b"cyan" | b"green" | b"magenta" | b"red" | b"white" | b"yellow" => {
+            return Ok(Bracket::Literal(b""));
+        }
+        [b'c', b'o', b'l', b'o', b'r', digits @ ..]
+            if !digits.is_empty() && digits.iter().all(u8::is_ascii_digit) =>
+        {
+            return Ok(Bracket::Literal(b""));
+        }
+        _ => {
+            return Err(format!(
+                "Unsupported spreadsheet code '[{}]' at position {}",
+                // SAFETY: the code is UTF-8 and delimited by ASCII brackets
+                unsafe { std::str::from_utf8_unchecked(code) },
+                pos
+            ));
+        }
+    }))
+}
+
+/// Whether `m` or `mm` means minutes rather than the month.
+/// Like spreadsheets, this is the case right after hours or right before seconds.
+fn is_spreadsheet_minute(prev_field: Option<Field>, rest: &[u8]) -> bool {
+    if let Some(Field::Hour24 | Field::Hour24Unpadded | Field::Elapsed(DeltaUnit::Hours, _)) =
+        prev_field
+    {
+        return true;
+    }
+    let mut i = 0;
+    while let Some(&ch) = rest.get(i) {
+        match ch {
+            b'"' => match rest[i + 1..].iter().position(|&c| c == b'"') {
+                Some(len) => i += len + 2,
+                None => return false,
+            },
+            b'\\' | b'_' | b'*' => i += 2,
+            b'[' => {
+                let Some(len) = rest[i + 1..].iter().position(|&c| c == b']') else {
+                    return false;
+                };
+                let code = &rest[i + 1..i + 1 + len];
+                if code.eq_ignore_ascii_case(b"s") || code.eq_ignore_ascii_case(b"ss") {
+                    return true;
+                } else if code.first().is_some_and(|c| b"hHmM".contains(c)) {
+                    return false;
+                }
+                i += len + 2;
+            }
+            c if c.is_ascii_alphabetic() => return c.eq_ignore_ascii_case(&b's'),
+            _ => i += 1,
+        }
+    }
+    false
+}
+
+fn unsupported_spreadsheet_err(fmt: &[u8], start: usize, end: usize) -> String {
+    let code = &fmt[start..end];
+    let hint = match code.first().map(u8::to_ascii_lowercase) {
+        Some(b';') => " Only formats with a single section are supported.",
+        Some(b'0' | b'#' | b'?') => " Digits are only supported as fractional seconds, e.g. ss.000",
+        Some(b'a'..=b'z') => " Use quotes for literal text: \"...\"",
+        _ => "",
+    };
+    format!(
+        "Unsupported spreadsheet code '{}' at position {}.{}",
+        // SAFETY: the code consists of ASCII characters
+        unsafe { std::str::from_utf8_unchecked(code) },
+        char_pos(fmt, start),
+        hint
+    )
+}
+
+/// How errors refer to the field at `index` of the elements:
+/// the code it was translated from, if any, otherwise the pattern field.
+fn field_name(sources: &[Box<str>], index: usize, field: Field) -> &str {
+    sources.get(index).map_or(field.display_name(), |s| s)
+}
+
+fn validate_cross_fields(elements: &[Element], sources: &[Box<str>]) -> Result<(), String> {
     let mut has_24h = false;
     let mut has_ampm = false;
-    let mut seen_keys: [Option<&'static str>; 12] = [None; 12];
+    let mut seen_keys: [Option<&str>; 18] = [None; 18];
 
-    for el in elements {
+    for (index, el) in elements.iter().enumerate() {
         let field = match el {
             Element::Field(f) => *f,
             _ => continue,
@@ -739,7 +1731,7 @@ fn validate_cross_fields(elements: &[Element<'_>]) -> Result<(), String> {
 
         match field {
             Field::Hour24 | Field::Hour24Unpadded => has_24h = true,
-            Field::AmPmShort | Field::AmPmFull => has_ampm = true,
+            Field::AmPmShort | Field::AmPmFull | Field::AmPmLetter(..) => has_ampm = true,
             _ => {}
         }
 
@@ -748,12 +1740,12 @@ fn validate_cross_fields(elements: &[Element<'_>]) -> Result<(), String> {
             if let Some(prev) = seen_keys[idx] {
                 return Err(format!(
                     "Duplicate field: {} conflicts with {} (both set {})",
-                    field.display_name(),
+                    field_name(sources, index, field),
                     prev,
                     state_key_name(key)
                 ));
             }
-            seen_keys[idx] = Some(field.display_name());
+            seen_keys[idx] = Some(field_name(sources, index, field));
         }
     }
 
@@ -762,6 +1754,22 @@ fn validate_cross_fields(elements: &[Element<'_>]) -> Result<(), String> {
             "24-hour format (h/hh) cannot be combined with AM/PM (a/aa). Use 12-hour format (i/ii) instead.".into(),
         );
     }
+    let has = |key: u8| seen_keys[key as usize].is_some();
+    let has_week = has(13);
+    let has_iso_year = has(14);
+    let has_month_or_day = has(1) || has(2) || has(12);
+    if has_iso_year && !has_week {
+        return Err(
+            "Week-based year (GGGG) requires an ISO week field (W/WW). Use YYYY for the calendar year.".into(),
+        );
+    }
+    // A common mistake: near the start and end of the year, the ISO week
+    // belongs to the week-based year, not the calendar year.
+    if has_week && has(0) && !has_iso_year && !has_month_or_day {
+        return Err(
+            "ISO week (W/WW) must be combined with the week-based year (GGGG), not the calendar year (YYYY).".into(),
+        );
+    }
     // 12h without AM/PM: we return Ok but the Python side emits a warning.
     // The warning is handled by the caller since we don't have Python API access here.
     Ok(())
@@ -781,18 +1789,26 @@ fn state_key_name(key: u8) -> &'static str {
         9 => "offset_secs",
         10 => "tz_id",
         11 => "tz_abbrev",
+        12 => "day_of_year",
+        13 => "iso_week",
+        14 => "iso_year",
+        15 => "quarter",
+        16 => "era",
+        17 => "sign",
         _ => unreachable!(),
     }
 }
 
 /// Check if the pattern has 12-hour without AM/PM (for warning by caller).
-fn has_12h_without_ampm(elements: &[Element<'_>]) -> bool {
+fn has_12h_without_ampm(elements: &[Element]) -> bool {
     let mut has_12h = false;
     let mut has_ampm = false;
     for el in elements {
         match el {
             Element::Field(Field::Hour12 | Field::Hour12Unpadded) => has_12h = true,
-            Element::Field(Field::AmPmShort | Field::AmPmFull) => has_ampm = true,
+            Element::Field(Field::AmPmShort | Field::AmPmFull | Field::AmPmLetter(..)) => {
+                has_ampm = true
+            }
             _ => {}
         }
     }
@@ -802,24 +1818,36 @@ fn has_12h_without_ampm(elements: &[Element<'_>]) -> bool {
 // ---- Formatting ----
 
 /// A [`Sink`] that only counts bytes, used for the first (dry-run) pass of
-/// two-pass formatting.
-struct ByteCounter(usize);
+/// two-pass formatting. It also records whether any non-ASCII bytes were
+/// written, which may happen for localized names.
+struct ByteCounter {
+    len: usize,
+    ascii: bool,
+}
 
 impl ByteCounter {
     fn new() -> Self {
-        Self(0)
+        Self {
+            len: 0,
+            ascii: true,
+        }
     }
     fn len(&self) -> usize {
-        self.0
+        self.len
+    }
+    fn is_ascii(&self) -> bool {
+        self.ascii
     }
 }
 
 impl Sink for ByteCounter {
-    fn write_byte(&mut self, _: u8) {
-        self.0 += 1;
+    fn write_byte(&mut self, b: u8) {
+        self.len += 1;
+        self.ascii &= b.is_ascii();
     }
     fn write(&mut self, s: &[u8]) {
-        self.0 += s.len();
+        self.len += s.len();
+        self.ascii &= s.is_ascii();
     }
 }
 
@@ -903,8 +1931,12 @@ fn write_field<S: Sink>(field: Field, vals: &PatternValues, sink: &mut S) -> Res
             let mut buf = [0u8; 2];
             sink.write(fmt_unpadded(vals.month.get(), &mut buf));
         }
-        Field::MonthAbbr => sink.write(MONTH_ABBR[vals.month.get() as usize].as_bytes()),
-        Field::MonthFull => sink.write(MONTH_FULL[vals.month.get() as usize].as_bytes()),
+        Field::MonthAbbr => {
+            sink.write(vals.locale.month_abbr[vals.month.get() as usize - 1].as_bytes())
+        }
+        Field::MonthFull => {
+            sink.write(vals.locale.month_full[vals.month.get() as usize - 1].as_bytes())
+        }
         Field::Day => sink.write(&format_2_digits(vals.day)),
         Field::DayUnpadded => {
             let mut buf = [0u8; 2];
@@ -912,11 +1944,24 @@ fn write_field<S: Sink>(field: Field, vals: &PatternValues, sink: &mut S) -> Res
         }
         Field::WeekdayAbbr => {
             // Weekday::iso() is 1-based (Mon=1); array is 0-based.
-            sink.write(WEEKDAY_ABBR[vals.weekday.iso() as usize - 1].as_bytes());
+            sink.write(vals.locale.weekday_abbr[vals.weekday.iso() as usize - 1].as_bytes());
         }
         Field::WeekdayFull => {
-            sink.write(WEEKDAY_FULL[vals.weekday.iso() as usize - 1].as_bytes());
+            sink.write(vals.locale.weekday_full[vals.weekday.iso() as usize - 1].as_bytes());
         }
+        Field::DayOfYear => sink.write(&format_4_digits(vals.date().day_of_year())[1..]),
+        Field::IsoWeek => sink.write(&format_2_digits(vals.date().iso_year_week().1)),
+        Field::IsoWeekUnpadded => {
+            let mut buf = [0u8; 2];
+            sink.write(fmt_unpadded(vals.date().iso_year_week().1, &mut buf));
+        }
+        Field::IsoYear => {
+            // The week-based year of dates within year 1..=9999 stays in that range
+            sink.write(&format_4_digits(vals.date().iso_year_week().0 as u16))
+        }
+        Field::Quarter => sink.write_byte(b'0' + quarter(vals.month)),
+        // Years before the common era aren't supported
+        Field::Era => sink.write(vals.locale.era[1].as_bytes()),
         Field::Hour24 => sink.write(&format_2_digits(vals.hour)),
         Field::Hour24Unpadded => {
             let mut buf = [0u8; 2];
@@ -969,8 +2014,21 @@ fn write_field<S: Sink>(field: Field, vals: &PatternValues, sink: &mut S) -> Res
             }
             // Empty: write nothing (dot is omitted together with the digits)
         }
-        Field::AmPmShort => sink.write_byte(if vals.hour < 12 { b'A' } else { b'P' }),
-        Field::AmPmFull => sink.write(if vals.hour < 12 { b"AM" } else { b"PM" }),
+        Field::AmPmShort => {
+            sink.write(vals.locale.am_pm_short[(vals.hour >= 12) as usize].as_bytes())
+        }
+        Field::AmPmFull => sink.write(vals.locale.am_pm[(vals.hour >= 12) as usize].as_bytes()),
+        Field::AmPmLetter(lower_am, lower_pm) => {
+            let is_pm = vals.hour >= 12;
+            let marker = vals.locale.am_pm_short[is_pm as usize];
+            let lower = if is_pm { lower_pm } else { lower_am };
+            let cased = if lower {
+                marker.to_lowercase()
+            } else {
+                marker.to_uppercase()
+            };
+            sink.write(cased.as_bytes())
+        }
         Field::OffsetLower(w) => {
             let offset = vals
                 .offset_secs
@@ -995,6 +2053,12 @@ fn write_field<S: Sink>(field: Field, vals: &PatternValues, sink: &mut S) -> Res
                 .ok_or("Cannot format timezone abbreviation: not available for this type")?;
             sink.write(abbrev.as_bytes());
         }
+        Field::SignMinus | Field::SignAlways => {
+            return Err("Cannot format sign: not available for this type".into());
+        }
+        Field::Elapsed(..) => {
+            return Err("Cannot format elapsed time: not available for this type".into());
+        }
     }
     Ok(())
 }
@@ -1005,7 +2069,7 @@ fn write_field<S: Sink>(field: Field, vals: &PatternValues, sink: &mut S) -> Res
 /// output length, then with a `PyAsciiStrBuilder` to write the actual bytes.
 /// Both passes must produce identical output for the same `(elements, vals)`.
 fn format_elements<S: Sink>(
-    elements: &[Element<'_>],
+    elements: &[Element],
     vals: &PatternValues,
     sink: &mut S,
 ) -> Result<(), String> {
@@ -1023,64 +2087,193 @@ fn format_elements<S: Sink>(
 /// Uses a two-pass approach: the first pass counts the output bytes (and validates
 /// all required values are present); the second pass writes directly into a
 /// presized Python string object via [`PyAsciiStrBuilder`], avoiding any
-/// intermediate Rust string allocation.
-fn format_to_py(elements: &[Element<'_>], vals: &PatternValues) -> PyReturn {
+/// intermediate Rust string allocation. Output containing localized non-ASCII
+/// names is instead written to an intermediate buffer.
+fn format_to_py(elements: &[Element], vals: &PatternValues) -> PyReturn {
     let mut counter = ByteCounter::new();
     format_elements(elements, vals, &mut counter).into_value_err()?;
-    let mut builder = PyAsciiStrBuilder::new(counter.len())?;
-    // SAFETY: the second pass uses the same `elements` and `vals`, so it is
-    // deterministic and cannot fail after the first pass succeeded.
-    format_elements(elements, vals, &mut builder)
-        .expect("second pass cannot fail after successful first pass");
-    Ok(builder.finish())
+    // SAFETY (both branches): the second pass uses the same `elements` and `vals`,
+    // so it is deterministic and cannot fail after the first pass succeeded.
+    if counter.is_ascii() {
+        let mut builder = PyAsciiStrBuilder::new(counter.len())?;
+        format_elements(elements, vals, &mut builder)
+            .expect("second pass cannot fail after successful first pass");
+        Ok(builder.finish())
+    } else {
+        let mut buf = Vec::with_capacity(counter.len());
+        format_elements(elements, vals, &mut buf)
+            .expect("second pass cannot fail after successful first pass");
+        // SAFETY: patterns are ASCII, and all other output is either ASCII
+        // or complete UTF-8 strings from the locale tables.
+        unsafe { std::str::from_utf8_unchecked(&buf) }.to_py()
+    }
 }
 
 // ---- Parsing ----
 
 /// Parse a string using compiled pattern elements.
-fn parse_to_state(elements: &[Element<'_>], s: &[u8]) -> Result<ParseState, String> {
+///
+/// In lenient mode, literals match regardless of ASCII case and amount of
+/// whitespace, leading zeros may be omitted from numeric fields, either `.`
+/// or `,` may separate fractional seconds, and trailing whitespace is ignored.
+fn parse_to_state(
+    elements: &[Element],
+    s: &[u8],
+    locale: &Locale,
+    lenient: bool,
+    pivot_year: Option<Year>,
+) -> Result<ParseState, ParseFailure> {
+    let mut state = ParseState {
+        lenient,
+        pivot_year,
+        ..ParseState::default()
+    };
+    parse_elements(elements, s, lenient, |field, pos| {
+        parse_field(field, s, pos, &mut state, locale)
+    })?;
+    state.resolve()?;
+    state.resolve_date()?;
+    Ok(state)
+}
+
+/// Match the literals of the pattern against the input,
+/// passing each field and its position to `parse_field`,
+/// which returns the position after the field.
+fn parse_elements<F>(
+    elements: &[Element],
+    s: &[u8],
+    lenient: bool,
+    mut parse_field: F,
+) -> Result<(), ParseFailure>
+where
+    F: FnMut(Field, usize) -> Result<usize, String>,
+{
     if s.len() > 1000 {
-        return Err("Input string too long (max 1000 characters)".to_string());
+        return Err(ParseFailure::from(
+            "Input string too long (max 1000 characters)".to_string(),
+        ));
     }
-    let mut state = ParseState::default();
     let mut pos = 0;
 
-    for el in elements {
+    for (i, el) in elements.iter().enumerate() {
         match el {
             Element::Literal(text) => {
-                let end = pos + text.len();
-                if end > s.len() || &s[pos..end] != *text {
+                let matched = if lenient {
+                    let before_frac = matches!(
+                        elements.get(i + 1),
+                        Some(Element::Field(Field::FracExact(_) | Field::FracTrim(_)))
+                    );
+                    match_literal_lenient(text, s, pos, before_frac)
+                } else {
+                    let end = pos + text.len();
+                    (end <= s.len() && s[pos..end] == **text).then_some(end)
+                };
+                pos = matched.ok_or_else(|| {
                     let expected = std::str::from_utf8(text).unwrap_or("?");
-                    let got = std::str::from_utf8(&s[pos..s.len().min(end)]).unwrap_or("?");
-                    return Err(format!(
-                        "Expected {:?} at position {}, got {:?}",
-                        expected, pos, got
-                    ));
-                }
-                pos = end;
+                    let got = char_prefix(&s[pos..], expected.chars().count());
+                    ParseFailure::at(
+                        format!(
+                            "Expected {} at position {}, got {}",
+                            PyRepr(expected),
+                            char_pos(s, pos),
+                            PyRepr(&got)
+                        ),
+                        s,
+                        pos,
+                        Some(expected.to_string()),
+                    )
+                })?;
             }
             Element::Field(field) => {
+                let expected = || Some(field.display_name().to_string());
                 if field.is_format_only() {
-                    return Err(format!(
-                        "Field {} is only supported for formatting, not parsing",
-                        field.display_name()
+                    return Err(ParseFailure::at(
+                        format!(
+                            "Field {} is only supported for formatting, not parsing",
+                            field.display_name()
+                        ),
+                        s,
+                        pos,
+                        expected(),
                     ));
                 }
-                pos = parse_field(*field, s, pos, &mut state)?;
+                pos = parse_field(*field, pos)
+                    .map_err(|msg| ParseFailure::at(msg, s, pos, expected()))?;
             }
         }
     }
 
+    if lenient && s[pos..].iter().all(u8::is_ascii_whitespace) {
+        pos = s.len();
+    }
     if pos != s.len() {
-        let trailing = std::str::from_utf8(&s[pos..]).unwrap_or("?");
-        return Err(format!(
-            "Unexpected trailing text at position {}: {:?}",
-            pos, trailing
+        let trailing = String::from_utf8_lossy(&s[pos..]);
+        return Err(ParseFailure::at(
+            format!(
+                "Unexpected trailing text at position {}: {}",
+                char_pos(s, pos),
+                PyRepr(&trailing)
+            ),
+            s,
+            pos,
+            None,
         ));
     }
+    Ok(())
+}
 
-    state.resolve();
-    Ok(state)
+/// Match a literal leniently, returning the position after it.
+/// Any run of whitespace matches any other (non-empty) run of whitespace.
+/// If the literal precedes fractional seconds, a trailing `.` or `,` matches either.
+fn match_literal_lenient(text: &[u8], s: &[u8], pos: usize, before_frac: bool) -> Option<usize> {
+    let is_frac_sep = |b: u8| b == b'.' || b == b',';
+    let mut i = 0;
+    let mut p = pos;
+    while i < text.len() {
+        let c = text[i];
+        if c.is_ascii_whitespace() {
+            while i < text.len() && text[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            let start = p;
+            while p < s.len() && s[p].is_ascii_whitespace() {
+                p += 1;
+            }
+            if p == start {
+                return None;
+            }
+            continue;
+        }
+        let &b = s.get(p)?;
+        let frac_sep = before_frac && i + 1 == text.len() && is_frac_sep(c) && is_frac_sep(b);
+        if !frac_sep && !b.eq_ignore_ascii_case(&c) {
+            return None;
+        }
+        i += 1;
+        p += 1;
+    }
+    Some(p)
+}
+
+/// Parse a zero-padded number of `count` digits.
+/// In lenient mode, the leading zeros may be omitted.
+fn parse_padded(s: &[u8], pos: usize, count: usize, lenient: bool) -> Result<(u32, usize), String> {
+    if !lenient {
+        return parse_digits(s, pos, count);
+    }
+    let found = s[pos..]
+        .iter()
+        .take(count)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if found == 0 {
+        return Err(format!(
+            "Expected 1-{} digits at position {}",
+            count,
+            char_pos(s, pos)
+        ));
+    }
+    parse_digits(s, pos, found)
 }
 
 fn parse_digits(s: &[u8], pos: usize, count: usize) -> Result<(u32, usize), String> {
@@ -1088,16 +2281,19 @@ fn parse_digits(s: &[u8], pos: usize, count: usize) -> Result<(u32, usize), Stri
     if end > s.len() {
         return Err(format!(
             "Expected {} digits at position {}, but input is too short",
-            count, pos
+            count,
+            char_pos(s, pos)
         ));
     }
     let mut val = 0u32;
     for &b in &s[pos..end] {
         if !b.is_ascii_digit() {
-            let chunk = std::str::from_utf8(&s[pos..end]).unwrap_or("?");
+            let chunk = char_prefix(&s[pos..], count);
             return Err(format!(
-                "Expected {} digits at position {}, got {:?}",
-                count, pos, chunk
+                "Expected {} digits at position {}, got {}",
+                count,
+                char_pos(s, pos),
+                PyRepr(&chunk)
             ));
         }
         val = val * 10 + (b - b'0') as u32;
@@ -1118,7 +2314,10 @@ fn fmt_unpadded(val: u8, buf: &mut [u8; 2]) -> &[u8] {
 
 fn parse_1or2_digits(s: &[u8], pos: usize) -> Result<(u32, usize), String> {
     if pos >= s.len() || !s[pos].is_ascii_digit() {
-        return Err(format!("Expected 1-2 digits at position {}", pos));
+        return Err(format!(
+            "Expected 1-2 digits at position {}",
+            char_pos(s, pos)
+        ));
     }
     let mut val = (s[pos] - b'0') as u32;
     let mut end = pos + 1;
@@ -1129,73 +2328,47 @@ fn parse_1or2_digits(s: &[u8], pos: usize) -> Result<(u32, usize), String> {
     Ok((val, end))
 }
 
-static MONTH_ABBR_SORTED: [(usize, &str); 12] = [
-    (1, "Jan"),
-    (2, "Feb"),
-    (3, "Mar"),
-    (4, "Apr"),
-    (5, "May"),
-    (6, "Jun"),
-    (7, "Jul"),
-    (8, "Aug"),
-    (9, "Sep"),
-    (10, "Oct"),
-    (11, "Nov"),
-    (12, "Dec"),
-];
-
-// Month name arrays sorted by length (longest first) for matching
-static MONTH_FULL_SORTED: [(usize, &str); 12] = [
-    (9, "September"),
-    (11, "November"),
-    (12, "December"),
-    (2, "February"),
-    (8, "August"),
-    (1, "January"),
-    (10, "October"),
-    (3, "March"),
-    (4, "April"),
-    (7, "July"),
-    (6, "June"),
-    (5, "May"),
-];
-
-static WEEKDAY_ABBR_SORTED: [(usize, &str); 7] = [
-    (0, "Mon"),
-    (1, "Tue"),
-    (2, "Wed"),
-    (3, "Thu"),
-    (4, "Fri"),
-    (5, "Sat"),
-    (6, "Sun"),
-];
-
-static WEEKDAY_FULL_SORTED: [(usize, &str); 7] = [
-    (2, "Wednesday"),
-    (3, "Thursday"),
-    (5, "Saturday"),
-    (4, "Friday"),
-    (0, "Monday"),
-    (6, "Sunday"),
-    (1, "Tuesday"),
-];
-
+/// Match the longest of `names` at `pos`, ignoring case.
+/// Returns the index of the matching name and the position after it.
 fn parse_name_match(
     s: &[u8],
     pos: usize,
-    candidates: &[(usize, &str)],
+    names: &[&str],
     field_name: &str,
 ) -> Result<(usize, usize), String> {
-    let remaining = &s[pos..];
-    for &(value, name) in candidates {
-        let name_bytes = name.as_bytes();
-        if remaining.len() >= name_bytes.len()
-            && remaining[..name_bytes.len()].eq_ignore_ascii_case(name_bytes)
-        {
-            return Ok((value, pos + name_bytes.len()));
+    let not_found = || {
+        format!(
+            "Cannot parse {} at position {}",
+            field_name,
+            char_pos(s, pos)
+        )
+    };
+    let remaining = std::str::from_utf8(&s[pos..]).map_err(|_| not_found())?;
+    names
+        .iter()
+        .enumerate()
+        .filter_map(|(i, name)| Some((i, starts_with_ignore_case(remaining, name)?)))
+        .max_by_key(|&(_, len)| len)
+        .map(|(i, len)| (i, pos + len))
+        .ok_or_else(not_found)
+}
+
+/// If `s` starts with `prefix` (ignoring case), return the matched length in `s`.
+fn starts_with_ignore_case(s: &str, prefix: &str) -> Option<usize> {
+    if s.is_ascii() && prefix.is_ascii() {
+        // Fast path for the common case
+        return (s.len() >= prefix.len()
+            && s.as_bytes()[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes()))
+        .then_some(prefix.len());
+    }
+    let mut chars = s.char_indices();
+    for p in prefix.chars() {
+        let (_, c) = chars.next()?;
+        if c != p && !c.to_lowercase().eq(p.to_lowercase()) {
+            return None;
         }
     }
-    Err(format!("Cannot parse {} at position {}", field_name, pos))
+    Some(chars.next().map_or(s.len(), |(i, _)| i))
 }
 
 fn parse_offset_value(
@@ -1208,7 +2381,10 @@ fn parse_offset_value(
         return Ok((0, pos + 1));
     }
     if pos >= s.len() || (s[pos] != b'+' && s[pos] != b'-') {
-        return Err(format!("Expected offset sign at position {}", pos));
+        return Err(format!(
+            "Expected offset sign at position {}",
+            char_pos(s, pos)
+        ));
     }
     let sign: i32 = if s[pos] == b'+' { 1 } else { -1 };
     let mut p = pos + 1;
@@ -1228,7 +2404,7 @@ fn parse_offset_value(
     } else {
         // width 3 or 5: expect colon
         if p >= s.len() || s[p] != b':' {
-            return Err(format!("Expected ':' at position {}", p));
+            return Err(format!("Expected ':' at position {}", char_pos(s, p)));
         }
         p += 1;
         let (v, new_p) = parse_digits(s, p, 2)?;
@@ -1266,7 +2442,7 @@ fn parse_dot_frac(
     width: usize,
     state: &mut ParseState,
 ) -> Result<usize, String> {
-    if pos < s.len() && s[pos] == b'.' {
+    if pos < s.len() && (s[pos] == b'.' || (state.lenient && s[pos] == b',')) {
         let pos = pos + 1; // consume the dot
         let start = pos;
         let mut end = pos;
@@ -1297,6 +2473,7 @@ fn parse_field(
     s: &[u8],
     pos: usize,
     state: &mut ParseState,
+    locale: &Locale,
 ) -> Result<usize, String> {
     match field {
         Field::Year4 => {
@@ -1305,9 +2482,13 @@ fn parse_field(
                 Some(Year::new(v as u16).ok_or_else(|| format!("year out of range: {}", v))?);
             Ok(p)
         }
-        Field::Year2 => unreachable!("Year2 is format-only"),
-        Field::MonthNum => {
+        Field::Year2 => {
             let (v, p) = parse_digits(s, pos, 2)?;
+            state.year2 = Some(v as u8);
+            Ok(p)
+        }
+        Field::MonthNum => {
+            let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
             state.month =
                 Some(Month::new(v as u8).ok_or_else(|| format!("month out of range: {}", v))?);
             Ok(p)
@@ -1319,19 +2500,19 @@ fn parse_field(
             Ok(p)
         }
         Field::MonthAbbr => {
-            let (v, p) = parse_name_match(s, pos, &MONTH_ABBR_SORTED, "month")?;
-            // SAFETY: MONTH_ABBR_SORTED only contains month values in 1..=12.
-            state.month = Some(unsafe { Month::new_unchecked(v as u8) });
+            let (i, p) = parse_name_match(s, pos, &locale.month_abbr, "month")?;
+            // SAFETY: the index of a 12-element array is in 0..12
+            state.month = Some(unsafe { Month::new_unchecked(i as u8 + 1) });
             Ok(p)
         }
         Field::MonthFull => {
-            let (v, p) = parse_name_match(s, pos, &MONTH_FULL_SORTED, "month")?;
-            // SAFETY: MONTH_FULL_SORTED only contains month values in 1..=12.
-            state.month = Some(unsafe { Month::new_unchecked(v as u8) });
+            let (i, p) = parse_name_match(s, pos, &locale.month_full, "month")?;
+            // SAFETY: the index of a 12-element array is in 0..12
+            state.month = Some(unsafe { Month::new_unchecked(i as u8 + 1) });
             Ok(p)
         }
         Field::Day => {
-            let (v, p) = parse_digits(s, pos, 2)?;
+            let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
             state.day = Some(v as u8);
             Ok(p)
         }
@@ -1341,19 +2522,61 @@ fn parse_field(
             Ok(p)
         }
         Field::WeekdayAbbr => {
-            let (v, p) = parse_name_match(s, pos, &WEEKDAY_ABBR_SORTED, "weekday")?;
-            // SAFETY: WEEKDAY_ABBR_SORTED contains values in 0..=6.
-            state.weekday = Some(unsafe { Weekday::from_iso_unchecked((v + 1) as u8) });
+            let (i, p) = parse_name_match(s, pos, &locale.weekday_abbr, "weekday")?;
+            // SAFETY: the index of a 7-element array is in 0..7
+            state.weekday = Some(unsafe { Weekday::from_iso_unchecked(i as u8 + 1) });
             Ok(p)
         }
         Field::WeekdayFull => {
-            let (v, p) = parse_name_match(s, pos, &WEEKDAY_FULL_SORTED, "weekday")?;
-            // SAFETY: WEEKDAY_FULL_SORTED contains values in 0..=6.
-            state.weekday = Some(unsafe { Weekday::from_iso_unchecked((v + 1) as u8) });
+            let (i, p) = parse_name_match(s, pos, &locale.weekday_full, "weekday")?;
+            // SAFETY: the index of a 7-element array is in 0..7
+            state.weekday = Some(unsafe { Weekday::from_iso_unchecked(i as u8 + 1) });
+            Ok(p)
+        }
+        Field::DayOfYear => {
+            let (v, p) = parse_padded(s, pos, 3, state.lenient)?;
+            if !(1..=366).contains(&v) {
+                return Err(format!("day of year out of range: {}", v));
+            }
+            state.day_of_year = Some(v as u16);
+            Ok(p)
+        }
+        Field::IsoWeek => {
+            let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
+            state.iso_week = Some(check_iso_week(v)?);
+            Ok(p)
+        }
+        Field::IsoWeekUnpadded => {
+            let (v, p) = parse_1or2_digits(s, pos)?;
+            state.iso_week = Some(check_iso_week(v)?);
+            Ok(p)
+        }
+        Field::IsoYear => {
+            let (v, p) = parse_digits(s, pos, 4)?;
+            state.iso_year =
+                Some(Year::new(v as u16).ok_or_else(|| format!("year out of range: {}", v))?);
+            Ok(p)
+        }
+        Field::Quarter => {
+            let (v, p) = parse_digits(s, pos, 1)?;
+            if !(1..=4).contains(&v) {
+                return Err(format!("quarter out of range: {}", v));
+            }
+            state.quarter = Some(v as u8);
+            Ok(p)
+        }
+        Field::Era => {
+            let (i, p) = parse_name_match(s, pos, &locale.era, "era")?;
+            if i == 0 {
+                return Err(format!(
+                    "Years before the common era are not supported (position {})",
+                    char_pos(s, pos)
+                ));
+            }
             Ok(p)
         }
         Field::Hour24 => {
-            let (v, p) = parse_digits(s, pos, 2)?;
+            let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
             state.hour = Some(v as u8);
             Ok(p)
         }
@@ -1363,7 +2586,7 @@ fn parse_field(
             Ok(p)
         }
         Field::Hour12 => {
-            let (v, p) = parse_digits(s, pos, 2)?;
+            let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
             if !(1..=12).contains(&v) {
                 return Err(format!("12-hour format requires hour in 1..12, got {}", v));
             }
@@ -1379,7 +2602,7 @@ fn parse_field(
             Ok(p)
         }
         Field::Minute => {
-            let (v, p) = parse_digits(s, pos, 2)?;
+            let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
             state.minute = Some(v as u8);
             Ok(p)
         }
@@ -1389,7 +2612,7 @@ fn parse_field(
             Ok(p)
         }
         Field::Second => {
-            let (v, p) = parse_digits(s, pos, 2)?;
+            let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
             state.second = Some(if v == 60 { 59 } else { v as u8 });
             Ok(p)
         }
@@ -1400,7 +2623,7 @@ fn parse_field(
         }
         Field::SecondOpt => {
             if pos < s.len() && s[pos].is_ascii_digit() {
-                let (v, p) = parse_digits(s, pos, 2)?;
+                let (v, p) = parse_padded(s, pos, 2, state.lenient)?;
                 state.second = Some(if v == 60 { 59 } else { v as u8 });
                 Ok(p)
             } else {
@@ -1411,7 +2634,7 @@ fn parse_field(
         }
         Field::ColonSec => {
             if pos < s.len() && s[pos] == b':' {
-                let (v, p) = parse_digits(s, pos + 1, 2)?;
+                let (v, p) = parse_padded(s, pos + 1, 2, state.lenient)?;
                 state.second = Some(if v == 60 { 59 } else { v as u8 });
                 Ok(p)
             } else {
@@ -1452,41 +2675,8 @@ fn parse_field(
             }
             parse_dot_frac(s, pos, width as usize, state)
         }
-        Field::AmPmShort => {
-            if pos >= s.len() {
-                return Err(format!("Expected AM/PM at position {}", pos));
-            }
-            let ch = s[pos].to_ascii_uppercase();
-            if ch == b'A' {
-                state.ampm = Some(AmPm::Am);
-            } else if ch == b'P' {
-                state.ampm = Some(AmPm::Pm);
-            } else {
-                return Err(format!(
-                    "Expected AM/PM at position {}, got {:?}",
-                    pos,
-                    std::str::from_utf8(&s[pos..pos + 1]).unwrap_or("?")
-                ));
-            }
-            Ok(pos + 1)
-        }
-        Field::AmPmFull => {
-            if pos + 2 > s.len() {
-                return Err(format!("Expected AM/PM at position {}", pos));
-            }
-            let mut chunk = [0u8; 2];
-            chunk[0] = s[pos].to_ascii_uppercase();
-            chunk[1] = s[pos + 1].to_ascii_uppercase();
-            if &chunk == b"AM" {
-                state.ampm = Some(AmPm::Am);
-            } else if &chunk == b"PM" {
-                state.ampm = Some(AmPm::Pm);
-            } else {
-                let got = std::str::from_utf8(&s[pos..pos + 2]).unwrap_or("?");
-                return Err(format!("Expected AM/PM at position {}, got {:?}", pos, got));
-            }
-            Ok(pos + 2)
-        }
+        Field::AmPmShort | Field::AmPmLetter(..) => parse_ampm(s, pos, &locale.am_pm_short, state),
+        Field::AmPmFull => parse_ampm(s, pos, &locale.am_pm, state),
         Field::OffsetLower(width) => {
             let (secs, p) = parse_offset_value(s, pos, width, false)?;
             // SAFETY: parse_offset_value validates components, so secs is within Offset bounds.
@@ -1508,34 +2698,420 @@ fn parse_field(
                 p += 1;
             }
             if p == start {
-                return Err(format!("Expected timezone ID at position {}", pos));
+                return Err(format!(
+                    "Expected timezone ID at position {}",
+                    char_pos(s, pos)
+                ));
             }
             // SAFETY: is_tz_id_char only passes ASCII bytes
             state.tz_id = Some(unsafe { std::str::from_utf8_unchecked(&s[start..p]) }.to_string());
             Ok(p)
         }
         Field::TzAbbrev => unreachable!("TzAbbrev is format-only"),
+        Field::SignMinus | Field::SignAlways => {
+            Err("Cannot parse sign: not available for this type".into())
+        }
+        Field::Elapsed(..) => Err("Cannot parse elapsed time: not available for this type".into()),
+    }
+}
+
+fn check_iso_week(v: u32) -> Result<u8, String> {
+    if !(1..=53).contains(&v) {
+        return Err(format!("ISO week out of range: {}", v));
     }
+    Ok(v as u8)
+}
+
+/// The quarter (1-4) of the year that the month falls in.
+fn quarter(month: Month) -> u8 {
+    (month.get() - 1) / 3 + 1
+}
+
+fn parse_ampm(
+    s: &[u8],
+    pos: usize,
+    markers: &[&str; 2],
+    state: &mut ParseState,
+) -> Result<usize, String> {
+    let (i, p) = parse_name_match(s, pos, markers, "AM/PM")?;
+    state.ampm = Some(if i == 0 { AmPm::Am } else { AmPm::Pm });
+    Ok(p)
 }
 
 // ---- Validation ----
 
 /// Raise a `ValueError` if any element's field is not in `allowed`.
 fn validate_fields(
-    elements: &[Element<'_>],
+    elements: &[Element],
+    sources: &[Box<str>],
     allowed: CategorySet,
     type_name: &str,
 ) -> PyResult<()> {
-    for el in elements {
+    for (index, el) in elements.iter().enumerate() {
         if let Element::Field(field) = el
             && !allowed.contains(field.category())
         {
             return raise_value_err(format!(
                 "{} does not support pattern field {}",
                 type_name,
-                field.display_name()
+                field_name(sources, index, *field)
             ));
         }
     }
     Ok(())
 }
+
+// ---- Deltas ----
+
+/// A unit of a delta pattern, smallest first
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum DeltaUnit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl DeltaUnit {
+    const ALL: [Self; 4] = [Self::Seconds, Self::Minutes, Self::Hours, Self::Days];
+
+    const fn secs(self) -> u64 {
+        match self {
+            Self::Seconds => 1,
+            Self::Minutes => 60,
+            Self::Hours => 3600,
+            Self::Days => 86_400,
+        }
+    }
+
+    /// How many of this unit fit in the next larger unit
+    const fn ratio(self) -> Option<u64> {
+        match self {
+            Self::Seconds | Self::Minutes => Some(60),
+            Self::Hours => Some(24),
+            Self::Days => None,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Seconds => "seconds",
+            Self::Minutes => "minutes",
+            Self::Hours => "hours",
+            Self::Days => "days",
+        }
+    }
+
+    const fn fields(self) -> &'static str {
+        match self {
+            Self::Seconds => "s/ss",
+            Self::Minutes => "m/mm",
+            Self::Hours => "h/hh",
+            Self::Days => "D/DD",
+        }
+    }
+}
+
+impl Field {
+    /// The unit of a delta field, and whether it's zero-padded
+    fn delta_unit(self) -> Option<(DeltaUnit, bool)> {
+        Some(match self {
+            Self::Day => (DeltaUnit::Days, true),
+            Self::DayUnpadded => (DeltaUnit::Days, false),
+            Self::Hour24 => (DeltaUnit::Hours, true),
+            Self::Hour24Unpadded => (DeltaUnit::Hours, false),
+            Self::Minute => (DeltaUnit::Minutes, true),
+            Self::MinuteUnpadded => (DeltaUnit::Minutes, false),
+            Self::Second => (DeltaUnit::Seconds, true),
+            Self::SecondUnpadded => (DeltaUnit::Seconds, false),
+            Self::Elapsed(unit, padded) => (unit, padded),
+            _ => return None,
+        })
+    }
+}
+
+/// Check that a pattern only has delta fields, and that its units are
+/// consecutive (e.g. not hours and seconds without minutes).
+fn validate_delta_fields(
+    elements: &[Element],
+    sources: &[Box<str>],
+    type_name: &str,
+) -> Result<(), String> {
+    let mut units = [false; 4];
+    let mut has_frac = false;
+    for (index, el) in elements.iter().enumerate() {
+        let Element::Field(field) = el else {
+            continue;
+        };
+        match field {
+            Field::FracExact(_) | Field::FracTrim(_) | Field::DotFrac(_) => has_frac = true,
+            Field::SignMinus | Field::SignAlways => {}
+            f => match f.delta_unit() {
+                Some((unit, _)) => units[unit as usize] = true,
+                None => {
+                    return Err(format!(
+                        "{} does not support pattern field {}",
+                        type_name,
+                        field_name(sources, index, *f)
+                    ));
+                }
+            },
+        }
+    }
+    let (Some(smallest), Some(largest)) = (
+        units.iter().position(|&u| u),
+        units.iter().rposition(|&u| u),
+    ) else {
+        return Err(format!(
+            "{} pattern must include days (D/DD), hours (h/hh), minutes (m/mm), or seconds (s/ss)",
+            type_name
+        ));
+    };
+    if let Some(missing) = (smallest..largest).find(|&i| !units[i]) {
+        let unit = DeltaUnit::ALL[missing];
+        return Err(format!(
+            "Pattern fields must be consecutive units, but {} ({}) is missing",
+            unit.name(),
+            unit.fields()
+        ));
+    }
+    if has_frac && !units[DeltaUnit::Seconds as usize] {
+        return Err("Fractional seconds (f/F) require a seconds field (s/ss)".into());
+    }
+    digits_after_largest(elements, sources).map(|_| ())
+}
+
+/// The number of digits of the fixed-width fields directly following the
+/// field of the largest unit. Since the latter may have any number of digits,
+/// it needs to leave these for the following fields when parsing.
+/// Fails if a variable-width field follows without a separator,
+/// which would be ambiguous.
+fn digits_after_largest(elements: &[Element], sources: &[Box<str>]) -> Result<usize, String> {
+    let largest = largest_delta_unit(elements);
+    let mut count = 0;
+    let following = elements
+        .iter()
+        .enumerate()
+        .skip_while(|(_, el)| {
+            !matches!(el, Element::Field(f) if f.delta_unit().is_some_and(|(u, _)| u == largest))
+        })
+        .skip(1);
+    let ambiguous = |index, field| ambiguous_after_largest(field_name(sources, index, field));
+    for (index, el) in following {
+        match el {
+            Element::Field(Field::FracExact(width)) => count += *width as usize,
+            Element::Field(f @ Field::FracTrim(_)) => return Err(ambiguous(index, *f)),
+            Element::Field(f) => match f.delta_unit() {
+                Some((_, true)) => count += 2,
+                Some((_, false)) => return Err(ambiguous(index, *f)),
+                None => break,
+            },
+            Element::Literal(_) => break,
+        }
+    }
+    Ok(count)
+}
+
+fn ambiguous_after_largest(name: &str) -> String {
+    format!(
+        "Field {} can't follow the field of the largest unit \
+         without a separator, since both have a variable number of digits",
+        name
+    )
+}
+
+/// Check that the field of the largest unit of a spreadsheet format
+/// is an elapsed-time code (e.g. `[h]`), and the other fields aren't.
+/// Without one, spreadsheets show a time of day instead of a duration.
+fn validate_elapsed_fields(elements: &[Element], type_name: &str) -> Result<(), String> {
+    let largest = largest_delta_unit(elements);
+    let valid = elements.iter().all(|el| match el {
+        Element::Field(f) => f
+            .delta_unit()
+            .is_none_or(|(unit, _)| matches!(f, Field::Elapsed(..)) == (unit == largest)),
+        Element::Literal(_) => true,
+    });
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Spreadsheet formats for {} must use an elapsed-time code \
+             ([h], [m], or [s]) for the largest unit, e.g. [h]:mm:ss",
+            type_name
+        ))
+    }
+}
+
+/// The largest unit of a (validated) delta pattern.
+/// Its field holds the total amount, while smaller units hold the remainder.
+fn largest_delta_unit(elements: &[Element]) -> DeltaUnit {
+    elements
+        .iter()
+        .filter_map(|el| match el {
+            Element::Field(f) => f.delta_unit(),
+            Element::Literal(_) => None,
+        })
+        .map(|(unit, _)| unit)
+        .max()
+        .unwrap_or(DeltaUnit::Seconds)
+}
+
+fn format_delta(elements: &[Element], delta: TimeDelta) -> Result<String, String> {
+    let neg = delta.is_negative();
+    let abs = delta.abs();
+    let secs = abs.secs.get() as u64;
+    let largest = largest_delta_unit(elements);
+    let mut has_sign = false;
+    let mut out = Vec::new();
+    for el in elements {
+        let field = match el {
+            Element::Literal(text) => {
+                out.extend_from_slice(text);
+                continue;
+            }
+            Element::Field(f) => *f,
+        };
+        match field {
+            Field::SignMinus => {
+                has_sign = true;
+                if neg {
+                    out.push(b'-');
+                }
+            }
+            Field::SignAlways => {
+                has_sign = true;
+                out.push(if neg { b'-' } else { b'+' });
+            }
+            Field::FracExact(w) => write_nanos_digits(abs.subsec, w as usize, &mut out),
+            Field::FracTrim(w) => write_nanos_trimmed(abs.subsec, w as usize, &mut out),
+            Field::DotFrac(w) => {
+                if !frac_trim_is_empty(abs.subsec, w as usize) {
+                    out.push(b'.');
+                    write_nanos_trimmed(abs.subsec, w as usize, &mut out);
+                }
+            }
+            f => {
+                let (unit, padded) = f.delta_unit().expect("validated delta pattern");
+                if let Field::Elapsed(..) = f {
+                    has_sign = true;
+                    if neg {
+                        out.push(b'-');
+                    }
+                }
+                let mut n = secs / unit.secs();
+                if unit < largest
+                    && let Some(ratio) = unit.ratio()
+                {
+                    n %= ratio;
+                }
+                let width = if padded { 2 } else { 1 };
+                out.extend_from_slice(format!("{:0width$}", n).as_bytes());
+            }
+        }
+    }
+    if neg && !has_sign {
+        return Err("Cannot format a negative delta without a sign field (n or nn)".into());
+    }
+    // SAFETY: patterns are ASCII, and so is all formatted output
+    Ok(unsafe { String::from_utf8_unchecked(out) })
+}
+
+/// Parse a delta. The field of the largest unit may have any number of digits,
+/// while the smaller units must be in range (e.g. minutes below 60).
+fn parse_delta(elements: &[Element], s: &[u8], lenient: bool) -> Result<TimeDelta, ParseFailure> {
+    const OUT_OF_RANGE: &str = "delta out of range";
+    let largest = largest_delta_unit(elements);
+    let reserved = digits_after_largest(elements, &[]).expect("validated delta pattern");
+    let mut neg = false;
+    let mut secs: u64 = 0;
+    // Fractional seconds are parsed as for other types
+    let mut frac = ParseState {
+        lenient,
+        ..ParseState::default()
+    };
+    parse_elements(elements, s, lenient, |field, pos| match field {
+        Field::SignMinus | Field::SignAlways => match s.get(pos) {
+            Some(b'-') => {
+                neg = true;
+                Ok(pos + 1)
+            }
+            Some(b'+') if lenient || matches!(field, Field::SignAlways) => Ok(pos + 1),
+            _ if lenient || matches!(field, Field::SignMinus) => Ok(pos),
+            _ => Err(format!(
+                "Expected sign (+ or -) at position {}",
+                char_pos(s, pos)
+            )),
+        },
+        Field::FracExact(_) | Field::FracTrim(_) | Field::DotFrac(_) => {
+            parse_field(field, s, pos, &mut frac, &locale::EN)
+        }
+        f => {
+            let (unit, padded) = f.delta_unit().expect("validated delta pattern");
+            let mut pos = pos;
+            if let (Field::Elapsed(..), Some(b'-')) = (f, s.get(pos)) {
+                neg = true;
+                pos += 1;
+            }
+            let (n, p) = if unit == largest {
+                parse_delta_total(s, pos, padded && !lenient, reserved)?
+            } else {
+                let (n, p) = if padded {
+                    parse_padded(s, pos, 2, lenient)?
+                } else {
+                    parse_1or2_digits(s, pos)?
+                };
+                if unit.ratio().is_some_and(|r| n as u64 >= r) {
+                    return Err(format!("{} out of range: {}", unit.name(), n));
+                }
+                (n as u64, p)
+            };
+            secs = n
+                .checked_mul(unit.secs())
+                .and_then(|n| secs.checked_add(n))
+                .ok_or(OUT_OF_RANGE)?;
+            Ok(p)
+        }
+    })?;
+    i64::try_from(secs)
+        .ok()
+        .and_then(|secs| {
+            TimeDelta::from_nanos(secs as i128 * NS_PER_SEC as i128 + frac.nanos.get() as i128)
+        })
+        .map(|d| d.negate_if(neg))
+        .ok_or_else(|| ParseFailure::from(OUT_OF_RANGE.to_string()))
+}
+
+/// Parse the total amount of the largest unit of a delta:
+/// any number of digits, but at least two if `padded`.
+/// The last `reserved` digits are left for the fields that follow.
+fn parse_delta_total(
+    s: &[u8],
+    pos: usize,
+    padded: bool,
+    reserved: usize,
+) -> Result<(u64, usize), String> {
+    let count = s[pos..]
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .count()
+        .saturating_sub(reserved);
+    if count == 0 || (padded && count < 2) {
+        let expected = if padded {
+            "at least 2 digits"
+        } else {
+            "a digit"
+        };
+        return Err(format!(
+            "Expected {} at position {}",
+            expected,
+            char_pos(s, pos)
+        ));
+    }
+    let n = s[pos..pos + count]
+        .iter()
+        .try_fold(0u64, |acc, &b| {
+            acc.checked_mul(10)?.checked_add((b - b'0') as u64)
+        })
+        .ok_or("delta out of range")?;
+    Ok((n, pos + count))
+}
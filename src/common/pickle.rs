@@ -5,6 +5,7 @@
 //! | Type | Fields | Bytes |
 //! | --- | --- | ---: |
 //! | Date | `u16 year, u8 month, u8 day` | 4 |
+//! | ExpandedDate | `i32 year, u8 month, u8 day` | 6 |
 //! | Time | `u8 hour, u8 minute, u8 second, u32 nanos` | 7 |
 //! | PlainDateTime | Date + Time | 11 |
 //! | Instant | `i64 epoch seconds, u32 nanos` | 12 |
@@ -13,16 +14,17 @@
 //! | ZonedDateTime | OffsetDateTime + separate timezone ID | 15 + ID |
 
 use crate::domain::{
-    date::Date,
+    date::{Date, ExpandedDate},
     instant::Instant,
     offset_datetime::OffsetDateTime,
     plain_datetime::PlainDateTime,
-    scalar::{EpochSecs, Month, Offset, SubSecNanos, Year},
+    scalar::{EpochSecs, ExpandedYear, Month, Offset, SubSecNanos, Year},
     time::Time,
     time_delta::TimeDelta,
 };
 
 pub(crate) const DATE_LEN: usize = 4;
+pub(crate) const EXPANDED_DATE_LEN: usize = 6;
 pub(crate) const TIME_LEN: usize = 7;
 pub(crate) const PLAIN_DATETIME_LEN: usize = DATE_LEN + TIME_LEN;
 pub(crate) const INSTANT_LEN: usize = 12;
@@ -45,6 +47,21 @@ pub(crate) fn decode_date(data: &[u8]) -> Option<Date> {
     Date::new(year, month, data[3])
 }
 
+pub(crate) fn encode_expanded_date(value: ExpandedDate) -> [u8; EXPANDED_DATE_LEN] {
+    let mut data = [0; EXPANDED_DATE_LEN];
+    data[..4].copy_from_slice(&value.year.get().to_le_bytes());
+    data[4] = value.month.get();
+    data[5] = value.day;
+    data
+}
+
+pub(crate) fn decode_expanded_date(data: &[u8]) -> Option<ExpandedDate> {
+    let data: &[u8; EXPANDED_DATE_LEN] = data.try_into().ok()?;
+    let year = ExpandedYear::new(i32::from_le_bytes(data[..4].try_into().unwrap()))?;
+    let month = Month::new(data[4])?;
+    ExpandedDate::new(year, month, data[5])
+}
+
 pub(crate) fn encode_time(value: Time) -> [u8; TIME_LEN] {
     let mut data = [0; TIME_LEN];
     data[0] = value.hour;
@@ -165,6 +182,12 @@ mod tests {
         let offset = plain.assume_offset(Offset::new(-3_723).unwrap()).unwrap();
 
         assert_eq!(encode_date(date), [0xe8, 0x07, 2, 29]);
+        assert_eq!(
+            encode_expanded_date(
+                ExpandedDate::new(ExpandedYear::new(-44).unwrap(), Month::March, 15).unwrap()
+            ),
+            [0xd4, 0xff, 0xff, 0xff, 3, 15]
+        );
         assert_eq!(encode_time(time), [3, 4, 5, 0x80, 0xf7, 0xcd, 0x23]);
         assert_eq!(
             encode_plain(plain),
@@ -195,6 +218,12 @@ mod tests {
         for value in [Date::MIN, Date::MAX] {
             assert_eq!(decode_date(&encode_date(value)), Some(value));
         }
+        for value in [ExpandedDate::MIN, ExpandedDate::MAX] {
+            assert_eq!(
+                decode_expanded_date(&encode_expanded_date(value)),
+                Some(value)
+            );
+        }
         for value in [Time::MIN, Time::MAX] {
             assert_eq!(decode_time(&encode_time(value)), Some(value));
         }
@@ -246,6 +275,10 @@ mod tests {
         assert_eq!(decode_date(&[0xe8, 0x07, 13, 1]), None);
         assert_eq!(decode_date(&[0xe8, 0x07, 2, 30]), None);
 
+        assert_eq!(decode_expanded_date(&[0, 0, 0, 0, 1]), None);
+        assert_eq!(decode_expanded_date(&[0x40, 0x42, 0x0f, 0x00, 1, 1]), None);
+        assert_eq!(decode_expanded_date(&[0xfc, 0xff, 0xff, 0xff, 2, 30]), None);
+
         assert_eq!(decode_time(&[24, 0, 0, 0, 0, 0, 0]), None);
         assert_eq!(decode_time(&[0, 60, 0, 0, 0, 0, 0]), None);
         assert_eq!(decode_time(&[0, 0, 60, 0, 0, 0, 0]), None);
@@ -77,42 +77,100 @@ const MONTH_NAMES: [&[u8]; 12] = [
     b"Jan", b"Feb", b"Mar", b"Apr", b"May", b"Jun", b"Jul", b"Aug", b"Sep", b"Oct", b"Nov", b"Dec",
 ];
 
-pub(crate) fn parse(s: &[u8]) -> Option<(Date, Time, Offset)> {
-    let mut scan = Scan::new(s);
-    scan.ascii_whitespace();
-    let expected_weekday = match scan.peek()? {
-        c if c.is_ascii_alphabetic() => Some(parse_weekday(&mut scan)?),
+/// Read an RFC 2822 date string.
+///
+/// Unless `strict`, the obsolete syntax of RFC 5322 §4.3 is accepted too:
+/// named zones, two- and three-digit years, and whitespace and comments
+/// between all components. Strict parsing only allows a trailing comment,
+/// and `GMT` as the only named zone.
+pub(crate) fn read(s: &mut Scan, strict: bool) -> Option<(Date, Time, Offset)> {
+    s.ascii_whitespace();
+    if !strict {
+        cfws(s)?;
+    }
+    let expected_weekday = match s.peek() {
+        Some(c) if c.is_ascii_alphabetic() => Some((s.pos(), parse_weekday(s, strict)?)),
         _ => None,
     };
-    let date = parse_date(&mut scan, expected_weekday)?;
-    let time = parse_time(&mut scan)?;
-    let offset = parse_offset(&mut scan)?;
-    scan.ascii_whitespace();
-    scan.is_done().then_some((date, time, offset))
-}
-
-fn parse_weekday(s: &mut Scan) -> Option<Weekday> {
-    s.take(3)
-        .and_then(|day_str| {
-            WEEKDAY_NAMES.iter().enumerate().find_map(|(i, &b)| {
-                day_str
-                    .eq_ignore_ascii_case(b)
-                    // SAFETY: enumerate indices are 0..=6.
-                    .then(|| unsafe { Weekday::from_iso_unchecked(i as u8 + 1) })
-            })
-        })
-        .and_then(|day| {
-            s.ascii_whitespace();
-            s.expect(b',')?;
-            s.ascii_whitespace();
-            Some(day)
+    let date = parse_date(s, expected_weekday, strict)?;
+    let time = parse_time(s, strict)?;
+    let offset = parse_offset(s, strict)?;
+    cfws(s)?;
+    Some((date, time, offset))
+}
+
+/// Skip whitespace, or in lenient mode also comments.
+/// Returns whether anything was skipped, or `None` for an invalid comment.
+fn fws(s: &mut Scan, strict: bool) -> Option<bool> {
+    if strict {
+        Some(s.ascii_whitespace())
+    } else {
+        cfws(s)
+    }
+}
+
+/// Skip whitespace and (possibly nested) comments in parentheses.
+/// Returns whether anything was skipped, or `None` for an invalid comment.
+fn cfws(s: &mut Scan) -> Option<bool> {
+    let mut skipped = s.ascii_whitespace();
+    while s.peek() == Some(b'(') {
+        let mut depth = 0;
+        loop {
+            match s.peek() {
+                Some(b'(') => depth += 1,
+                Some(b')') => depth -= 1,
+                Some(b'\\') if s.get(1).is_some_and(|c| c.is_ascii()) => {
+                    s.skip(1);
+                }
+                Some(c) if c.is_ascii() && c != b'\\' => {}
+                _ => return s.fail(")"),
+            }
+            s.skip(1);
+            if depth == 0 {
+                break;
+            }
+        }
+        s.ascii_whitespace();
+        skipped = true;
+    }
+    Some(skipped)
+}
+
+/// Require whitespace (or in lenient mode, comments)
+fn require_fws(s: &mut Scan, strict: bool) -> Option<()> {
+    fws(s, strict)?
+        .then_some(())
+        .or_else(|| s.fail("whitespace"))
+}
+
+fn parse_weekday(s: &mut Scan, strict: bool) -> Option<Weekday> {
+    let day = s.read("weekday", |s| {
+        let day_str = s.take(3)?;
+        WEEKDAY_NAMES.iter().enumerate().find_map(|(i, &b)| {
+            day_str
+                .eq_ignore_ascii_case(b)
+                // SAFETY: enumerate indices are 0..=6.
+                .then(|| unsafe { Weekday::from_iso_unchecked(i as u8 + 1) })
         })
+    })?;
+    if !strict {
+        cfws(s)?;
+    }
+    s.expect(b',').or_else(|| s.fail(","))?;
+    fws(s, strict)?;
+    Some(day)
 }
 
-fn parse_date(s: &mut Scan, expect_weekday: Option<Weekday>) -> Option<Date> {
-    let day = s.up_to_2_digits()?;
-    s.ascii_whitespace().then_some(())?;
-    let month = s.take(3).and_then(|month_str| {
+fn parse_date(
+    s: &mut Scan,
+    expect_weekday: Option<(usize, Weekday)>,
+    strict: bool,
+) -> Option<Date> {
+    let day_pos = s.pos();
+    let day = s.read("day", Scan::up_to_2_digits)?;
+    require_fws(s, strict)?;
+    let month = s.read("month", |s| {
+        let month_str = s.take(3)?;
         MONTH_NAMES.iter().enumerate().find_map(|(i, &b)| {
             month_str
                 .eq_ignore_ascii_case(b)
@@ -120,12 +178,12 @@ fn parse_date(s: &mut Scan, expect_weekday: Option<Weekday>) -> Option<Date> {
                 .then(|| unsafe { Month::new_unchecked(i as u8 + 1) })
         })
     })?;
-    s.ascii_whitespace().then_some(())?;
-    let year = s
-        .take_until(Scan::is_whitespace)
-        .and_then(|y_str| match y_str.len() {
+    require_fws(s, strict)?;
+    let year = s.read("year", |s| {
+        let y_str = s.take_until(|b| Scan::is_whitespace(b) || b == b'(')?;
+        match y_str.len() {
             4 => extract_year(y_str, 0),
-            2 => extract_2_digits(y_str, 0).map(|y| {
+            2 if !strict => extract_2_digits(y_str, 0).map(|y| {
                 if y < 50 {
                     // SAFETY: a two-digit year below 50 maps to 2000..=2049.
                     unsafe { Year::new_unchecked(2000 + y as u16) }
@@ -135,7 +193,7 @@ fn parse_date(s: &mut Scan, expect_weekday: Option<Weekday>) -> Option<Date> {
                 }
             }),
             // SAFETY: three decimal digits plus 1900 produce 1900..=2899.
-            3 => Some(unsafe {
+            3 if !strict => Some(unsafe {
                 Year::new_unchecked(
                     1900 + (extract_digit(y_str, 0)? as u16) * 100
                         + (extract_digit(y_str, 1)? as u16) * 10
@@ -143,34 +201,40 @@ fn parse_date(s: &mut Scan, expect_weekday: Option<Weekday>) -> Option<Date> {
                 )
             }),
             _ => None,
-        })?;
-    s.ascii_whitespace();
-    let date = Date::new(year, month, day)?;
-    if let Some(weekday) = expect_weekday
+        }
+    })?;
+    fws(s, strict)?;
+    let date = Date::new(year, month, day).or_else(|| s.fail_at(day_pos, "day"))?;
+    if let Some((pos, weekday)) = expect_weekday
         && date.day_of_week() != weekday
     {
-        return None;
+        return s.fail_at(pos, "weekday");
     }
     Some(date)
 }
 
-fn parse_time(s: &mut Scan) -> Option<Time> {
-    let hour = s.digits00_23()?;
-    s.ascii_whitespace();
-    s.expect(b':')?;
-    s.ascii_whitespace();
-    let minute = s.digits00_59()?;
-    let whitespace_after_mins = s.ascii_whitespace();
-    let second = match s.peek()? {
-        b':' => {
-            s.skip(1).ascii_whitespace();
-            let val = s.digits00_60_leap()?;
+fn parse_time(s: &mut Scan, strict: bool) -> Option<Time> {
+    // Only the obsolete syntax allows whitespace within the time
+    let inner_ws = |s: &mut Scan| if strict { Some(false) } else { cfws(s) };
+    let hour = s.read("hour", Scan::digits00_23)?;
+    inner_ws(s)?;
+    s.expect(b':').or_else(|| s.fail(":"))?;
+    inner_ws(s)?;
+    let minute = s.read("minute", Scan::digits00_59)?;
+    let whitespace_after_mins = inner_ws(s)?;
+    let second = match s.peek() {
+        Some(b':') => {
+            s.skip(1);
+            inner_ws(s)?;
+            let val = s.read("second", Scan::digits00_60_leap)?;
             // Whitespace after seconds is required!
-            s.ascii_whitespace().then_some(())?;
+            require_fws(s, strict)?;
             val
         }
         _ if whitespace_after_mins => 0,
-        _ => None?,
+        // In strict mode, whitespace before the zone is still required
+        _ if strict && s.ascii_whitespace() => 0,
+        _ => s.fail("whitespace")?,
     };
 
     Some(Time {
@@ -194,24 +258,162 @@ const TIMEZONES: [(&[u8], i32); 10] = [
     (b"PDT", -7 * 3600),
 ];
 
-fn parse_offset(s: &mut Scan) -> Option<Offset> {
-    Some(Offset::new_unchecked(match s.peek()? {
-        b'+' => s.skip(1).digits00_23()? as i32 * 3600 + s.digits00_59()? as i32 * 60,
-        b'-' => -(s.skip(1).digits00_23()? as i32 * 3600 + s.digits00_59()? as i32 * 60),
-        _ => {
+fn parse_offset(s: &mut Scan, strict: bool) -> Option<Offset> {
+    let signed = |s: &mut Scan| {
+        s.skip(1);
+        let hours = s.read("offset hour", Scan::digits00_23)? as i32;
+        let minutes = s.read("offset minute", Scan::digits00_59)? as i32;
+        Some(hours * 3600 + minutes * 60)
+    };
+    Some(Offset::new_unchecked(match s.peek() {
+        Some(b'+') => signed(s)?,
+        Some(b'-') => -signed(s)?,
+        Some(c) if c.is_ascii_alphabetic() => {
+            let start = s.pos();
             let tz = match s.take_until(|b| !b.is_ascii_alphabetic()) {
                 Some(tz) => tz,
                 None => s.drain(),
             };
-            if tz.is_empty() {
-                return None;
+            // The modern syntax only has numeric offsets, but we accept GMT
+            // as well: it's what format_rfc2822() and HTTP dates use.
+            if strict && !tz.eq_ignore_ascii_case(b"GMT") {
+                return s.fail_at(start, "offset");
             }
             TIMEZONES
                 .iter()
                 .find_map(|&(tz_name, offset)| tz.eq_ignore_ascii_case(tz_name).then_some(offset))
-                // According to specification, if the timezone is not recognized, it should be
-                // treated as GMT.
+                // Military zones and other unrecognized zones should be
+                // treated as -0000 (i.e. UTC) according to the specification.
                 .unwrap_or(0)
         }
+        _ => s.fail("offset")?,
     }))
 }
+
+const WEEKDAY_NAMES_FULL: [&[u8]; 7] = [
+    b"Monday",
+    b"Tuesday",
+    b"Wednesday",
+    b"Thursday",
+    b"Friday",
+    b"Saturday",
+    b"Sunday",
+];
+
+/// Read an HTTP-date (RFC 9110 §5.6.7) in any of its three forms:
+/// IMF-fixdate (`Sun, 06 Nov 1994 08:49:37 GMT`),
+/// RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`),
+/// and asctime (`Sun Nov  6 08:49:37 1994`).
+///
+/// As required by the spec, the format is case-sensitive and the
+/// two-digit RFC 850 year is taken to be at most 50 years after `current_year`.
+pub(crate) fn read_http_date(s: &mut Scan, current_year: Year) -> Option<(Date, Time)> {
+    let start = s.pos();
+    let name = s
+        .take_until(|b| !b.is_ascii_alphabetic())
+        .or_else(|| s.fail("weekday"))?;
+    let (weekday, date, time) = if let Some(weekday) = http_weekday(&WEEKDAY_NAMES, name) {
+        let sep_pos = s.pos();
+        match s.next() {
+            // IMF-fixdate
+            Some(b',') => {
+                http_expect(s, " ")?;
+                let day_pos = s.pos();
+                let day = s.read("day", |s| extract_2_digits(s.take(2)?, 0))?;
+                http_expect(s, " ")?;
+                let month = s.read("month", http_month)?;
+                http_expect(s, " ")?;
+                let year = s.read("year", |s| extract_year(s.take(4)?, 0))?;
+                http_expect(s, " ")?;
+                let time = http_time(s)?;
+                http_expect(s, " ")?;
+                http_gmt(s)?;
+                (weekday, http_date(s, year, month, day, day_pos)?, time)
+            }
+            // asctime
+            Some(b' ') => {
+                let month = s.read("month", http_month)?;
+                http_expect(s, " ")?;
+                let day_pos = s.pos();
+                let day = s.read("day", |s| match s.peek()? {
+                    b' ' => s.skip(1).digit(),
+                    _ => extract_2_digits(s.take(2)?, 0),
+                })?;
+                http_expect(s, " ")?;
+                let time = http_time(s)?;
+                http_expect(s, " ")?;
+                let year = s.read("year", |s| extract_year(s.take(4)?, 0))?;
+                (weekday, http_date(s, year, month, day, day_pos)?, time)
+            }
+            _ => s.fail_at(sep_pos, ",")?,
+        }
+    } else {
+        // RFC 850
+        let weekday =
+            http_weekday(&WEEKDAY_NAMES_FULL, name).or_else(|| s.fail_at(start, "weekday"))?;
+        http_expect(s, ",")?;
+        http_expect(s, " ")?;
+        let day_pos = s.pos();
+        let day = s.read("day", |s| extract_2_digits(s.take(2)?, 0))?;
+        http_expect(s, "-")?;
+        let month = s.read("month", http_month)?;
+        http_expect(s, "-")?;
+        let year_pos = s.pos();
+        let yy = s.read("year", |s| extract_2_digits(s.take(2)?, 0))? as i32;
+        http_expect(s, " ")?;
+        let time = http_time(s)?;
+        http_expect(s, " ")?;
+        http_gmt(s)?;
+        // A year more than 50 years in the future is interpreted as
+        // the most recent year in the past with the same last two digits.
+        let latest = current_year.get() as i32 + 50;
+        let year = Year::from_i32(latest - (latest - yy).rem_euclid(100))
+            .or_else(|| s.fail_at(year_pos, "year"))?;
+        (weekday, http_date(s, year, month, day, day_pos)?, time)
+    };
+    if date.day_of_week() != weekday {
+        return s.fail_at(start, "weekday");
+    }
+    Some((date, time))
+}
+
+fn http_expect(s: &mut Scan, c: &'static str) -> Option<()> {
+    s.expect(c.as_bytes()[0]).or_else(|| s.fail(c))
+}
+
+fn http_gmt(s: &mut Scan) -> Option<()> {
+    s.read("GMT", |s| s.take(3).filter(|&z| z == b"GMT"))
+        .map(|_| ())
+}
+
+fn http_date(s: &mut Scan, year: Year, month: Month, day: u8, day_pos: usize) -> Option<Date> {
+    Date::new(year, month, day).or_else(|| s.fail_at(day_pos, "day"))
+}
+
+/// Find the (case-sensitive) name in the list, returning its 1-based index.
+fn find_name(names: &[&[u8]], name: &[u8]) -> Option<u8> {
+    names.iter().position(|&n| n == name).map(|i| i as u8 + 1)
+}
+
+fn http_weekday(names: &[&[u8]; 7], name: &[u8]) -> Option<Weekday> {
+    // SAFETY: the index of a list of seven names is 1..=7.
+    find_name(names, name).map(|n| unsafe { Weekday::from_iso_unchecked(n) })
+}
+
+fn http_month(s: &mut Scan) -> Option<Month> {
+    Month::new(find_name(&MONTH_NAMES, s.take(3)?)?)
+}
+
+fn http_time(s: &mut Scan) -> Option<Time> {
+    let hour = s.read("hour", Scan::digits00_23)?;
+    s.expect(b':').or_else(|| s.fail(":"))?;
+    let minute = s.read("minute", Scan::digits00_59)?;
+    s.expect(b':').or_else(|| s.fail(":"))?;
+    let second = s.read("second", Scan::digits00_60_leap)?;
+    Some(Time {
+        hour,
+        minute,
+        second,
+        subsec: SubSecNanos::MIN,
+    })
+}
@@ -120,6 +120,25 @@ To suppress when exact 24-hour arithmetic is genuinely intended, pass
 
     TimeDelta(days=1, days_assumed_24h_ok=True)
 ";
+pub(crate) const EXPANDEDDATE: &CStr = c"\
+A date in the proleptic Gregorian calendar, with an expanded
+year range of -999,999 to 999,999.
+
+Use this type for historical or astronomical dates beyond
+the 1--9999 range of :class:`~whenever.Date`.
+Years are numbered astronomically: year 0 is 1 BCE,
+year -1 is 2 BCE, and so on.
+
+>>> ExpandedDate(-44, 3, 15)
+ExpandedDate(\"-000044-03-15\")
+>>> ExpandedDate(12025, 1, 1)
+ExpandedDate(\"+012025-01-01\")
+
+Can also be constructed from an ISO 8601 string:
+
+>>> ExpandedDate(\"+012025-01-01\")
+ExpandedDate(\"+012025-01-01\")
+";
 pub(crate) const IMPLICITLYIGNORINGDST: &CStr = c"\
 Raised when an operation would silently ignore DST transitions.
 
@@ -367,6 +386,55 @@ original offset, which may become stale if DST rules have changed.
 Use :meth:`assume_tz` to convert to a ``ZonedDateTime`` first if you
 need DST-aware arithmetic.
 ";
+pub(crate) const OFFSETPERIOD: &CStr = c"\
+The span of time during which a timezone keeps the same
+UTC offset and abbreviation.
+
+Returned by :meth:`~whenever.ZonedDateTime.offset_period`.
+
+>>> d = ZonedDateTime(2024, 7, 1, tz=\"Europe/Amsterdam\")
+>>> p = d.offset_period()
+>>> p.start, p.end
+(Instant(\"2024-03-31 01:00:00Z\"), Instant(\"2024-10-27 01:00:00Z\"))
+>>> p.abbrev, p.is_dst
+('CEST', True)
+";
+pub(crate) const PARSEERROR: &CStr = c"\
+Raised when a string can't be parsed.
+
+It's a subclass of :class:`ValueError`, so existing ``except ValueError``
+handlers keep working. It has extra attributes for reporting
+the failure to users, e.g. in form validation:
+
+- ``input``: the string that failed to parse
+- ``position``: the index in ``input`` where the mismatching
+  field or literal starts, or ``None`` if the failure isn't
+  tied to a single location (e.g. a value out of range)
+- ``expected``: the field or literal that was expected
+  at ``position``, or ``None`` if unknown (e.g. for trailing text)
+
+>>> try:
+...     Date.parse(\"2024/03/15\", format=\"YYYY-MM-DD\")
+... except ParseError as e:
+...     print(e.render())
+Expected '-' at position 4, got '/'
+  2024/03/15
+      ^
+";
+pub(crate) const PATTERN: &CStr = c"\
+A pattern, compiled once for formatting and parsing values of one type.
+
+Prefer this over passing the same pattern string to ``format()``
+or ``parse()`` many times, for example in a loop.
+The pattern is validated against the type when it's created.
+See :ref:`pattern-format` for the pattern syntax.
+
+>>> p = Pattern(\"YYYY/MM/DD\", Date)
+>>> p.format(Date(2024, 3, 15))
+'2024/03/15'
+>>> p.parse(\"2024/03/15\")
+Date(\"2024-03-15\")
+";
 pub(crate) const PLAINDATETIME: &CStr = c"\
 A date and time-of-day without any timezone information.
 
@@ -735,6 +803,9 @@ Date(\"2024-08-18\")
 See also :meth:`start_of`
 ";
 pub(crate) const DATE_FORMAT: &CStr = c"\
+format($self, pattern, /, *, locale=None, style='pattern')
+--
+
 Format as a custom pattern string.
 
 See :ref:`pattern-format` for details.
@@ -743,6 +814,8 @@ See :ref:`pattern-format` for details.
 '2024/03/15'
 >>> Date(2024, 3, 15).format(\"DD MMM YYYY\")
 '15 Mar 2024'
+>>> Date(2024, 3, 15).format(\"D. MMMM YYYY\", locale=\"de\")
+'15. März 2024'
 ";
 pub(crate) const DATE_FORMAT_ISO: &CStr = c"\
 format_iso($self, *, basic=False)
@@ -757,6 +830,36 @@ Inverse of :meth:`parse_iso`.
 >>> Date(1992, 9, 4).format_iso(basic=True)
 '19920904'
 ";
+pub(crate) const DATE_FROM_EXCEL_SERIAL: &CStr = c"\
+from_excel_serial(n, /, *, epoch_1904=False)
+--
+
+Create a date from a spreadsheet serial date number.
+Inverse of :meth:`to_excel_serial`.
+
+>>> Date.from_excel_serial(45366)
+Date(\"2024-03-15\")
+
+Raises
+------
+ValueError
+    If the serial is out of range, or for serial 60 in the 1900
+    date system, which is the nonexistent date 1900-02-29.
+";
+pub(crate) const DATE_FROM_JULIAN_DAY: &CStr = c"\
+Create a date from a Julian Day Number.
+Inverse of :meth:`to_julian_day`.
+
+>>> Date.from_julian_day(2451545)
+Date(\"2000-01-01\")
+";
+pub(crate) const DATE_FROM_MJD: &CStr = c"\
+Create a date from a Modified Julian Date.
+Inverse of :meth:`to_mjd`.
+
+>>> Date.from_mjd(51544)
+Date(\"2000-01-01\")
+";
 pub(crate) const DATE_FROM_PY_DATE: &CStr = c"\
 Create from a :class:`~datetime.date`
 
@@ -767,6 +870,13 @@ Date(\"2021-01-02\")
 
     Use the constructor ``Date(d)`` instead.
 ";
+pub(crate) const DATE_FROM_RATA_DIE: &CStr = c"\
+Create a date from a Rata Die day number.
+Inverse of :meth:`to_rata_die`.
+
+>>> Date.from_rata_die(730120)
+Date(\"2000-01-01\")
+";
 pub(crate) const DATE_IN_LEAP_YEAR: &CStr = c"\
 Whether this date's year is a leap year
 
@@ -828,7 +938,7 @@ Date(\"2024-08-09\")
 Date(\"2024-08-30\")
 ";
 pub(crate) const DATE_PARSE: &CStr = c"\
-parse(s, /, *, format)
+parse(s, /, *, format, locale=None, style='pattern', lenient=False, pivot_year=None)
 --
 
 Parse a date from a custom pattern string.
@@ -839,6 +949,8 @@ See :ref:`pattern-format` for details.
 Date(\"2024-03-15\")
 >>> Date.parse(\"15 Mar 2024\", format=\"DD MMM YYYY\")
 Date(\"2024-03-15\")
+>>> Date.parse(\"15 mars 2024\", format=\"DD MMMM YYYY\", locale=\"fr\")
+Date(\"2024-03-15\")
 ";
 pub(crate) const DATE_PARSE_ISO: &CStr = c"\
 Parse a date from an ISO8601 string
@@ -846,11 +958,17 @@ Parse a date from an ISO8601 string
 The following formats are accepted:
 - ``YYYY-MM-DD`` (\"extended\" format)
 - ``YYYYMMDD`` (\"basic\" format)
+- ``YYYY-DDD`` or ``YYYYDDD`` (ordinal date)
+- ``YYYY-Www-D`` or ``YYYYWwwD`` (week date)
 
 Inverse of :meth:`format_iso`
 
 >>> Date.parse_iso(\"2021-01-02\")
 Date(\"2021-01-02\")
+>>> Date.parse_iso(\"2021-002\")
+Date(\"2021-01-02\")
+>>> Date.parse_iso(\"2020-W53-6\")
+Date(\"2021-01-02\")
 ";
 pub(crate) const DATE_PREV_DAY: &CStr = c"\
 The date immediately preceding
@@ -944,6 +1062,51 @@ Date(\"2019-10-30\")
 >>> Date(2021, 3, 1).subtract(years=1)
 Date(\"2020-03-01\")
 ";
+pub(crate) const DATE_TO_EXCEL_SERIAL: &CStr = c"\
+to_excel_serial($self, *, epoch_1904=False)
+--
+
+The serial date number used by spreadsheets such as Excel
+
+By default, Excel's 1900 date system is used, in which
+1900-01-01 is serial 1.
+For compatibility with Lotus 1-2-3, this system treats 1900
+as a leap year. Dates before 1900-03-01 are therefore
+one lower than the actual number of days.
+Lotus 1-2-3 serial dates are the same as this system.
+
+Pass ``epoch_1904=True`` to use the 1904 date system instead,
+in which 1904-01-01 is serial 0.
+
+>>> Date(2024, 3, 15).to_excel_serial()
+45366
+>>> Date(2024, 3, 15).to_excel_serial(epoch_1904=True)
+43904
+";
+pub(crate) const DATE_TO_JULIAN_DAY: &CStr = c"\
+The Julian Day Number: the number of days since
+4714-11-24 BCE in the proleptic Gregorian calendar.
+
+Note that Julian days start at noon UTC.
+The number returned is that of the Julian day starting
+at noon on this date.
+Use :meth:`Instant.to_julian_day` for fractional Julian dates.
+
+>>> Date(2000, 1, 1).to_julian_day()
+2451545
+";
+pub(crate) const DATE_TO_MJD: &CStr = c"\
+The Modified Julian Date: the number of days since 1858-11-17
+
+>>> Date(2000, 1, 1).to_mjd()
+51544
+";
+pub(crate) const DATE_TO_RATA_DIE: &CStr = c"\
+The Rata Die day number, where 0001-01-01 is day 1
+
+>>> Date(2000, 1, 1).to_rata_die()
+730120
+";
 pub(crate) const DATE_TO_STDLIB: &CStr = c"\
 Convert to a standard library :class:`~datetime.date`";
 pub(crate) const DATE_TODAY_IN_SYSTEM_TZ: &CStr = c"\
@@ -974,6 +1137,9 @@ The year and month (without a day component)
 YearMonth(\"2021-01\")
 ";
 pub(crate) const DATEDELTA_FORMAT_ISO: &CStr = c"\
+format_iso($self, *, alternative=False, basic=False)
+--
+
 Format as the *popular interpretation* of the ISO 8601 duration format.
 May not strictly adhere to (all versions of) the standard.
 See :ref:`here <iso8601-durations>` for more information.
@@ -1000,6 +1166,13 @@ For example:
     P2M
     P1Y2M3W4D
 
+With ``alternative=True``, the ISO 8601 alternative format is used
+(in basic format if ``basic=True``).
+It can only express up to 30 days.
+
+>>> p = DateDelta(years=1, months=2, days=3)
+>>> p.format_iso(alternative=True)
+'P0001-02-03'
 ";
 pub(crate) const DATEDELTA_IN_MONTHS_DAYS: &CStr = c"\
 Convert to a tuple of months and days.
@@ -1022,6 +1195,9 @@ Parse the *popular interpretation* of the ISO 8601 duration format.
 Does not parse all possible ISO 8601 durations.
 See :ref:`here <iso8601-durations>` for more information.
 
+The :ref:`alternative format <iso8601-alternative-durations>`
+(e.g. ``P0001-02-03``) is also accepted.
+
 Inverse of :meth:`format_iso`
 
 >>> DateDelta.parse_iso(\"P1W11D\")
@@ -1044,6 +1220,9 @@ The date part of the delta
 .. deprecated:: 0.10.0
 ";
 pub(crate) const DATETIMEDELTA_FORMAT_ISO: &CStr = c"\
+format_iso($self, *, alternative=False, basic=False)
+--
+
 Format as the *popular interpretation* of the ISO 8601 duration format.
 May not strictly adhere to (all versions of) the standard.
 See :ref:`here <iso8601-durations>` for more information.
@@ -1064,6 +1243,13 @@ The format is:
 ... )
 >>> d.format_iso()
 'P1W11DT4H0.012S'
+
+With ``alternative=True``, the ISO 8601 alternative format is used
+(in basic format if ``basic=True``).
+It can only express up to 30 days and 24 hours.
+
+>>> DateTimeDelta(months=2, hours=4).format_iso(alternative=True)
+'P0000-02-00T04:00:00'
 ";
 pub(crate) const DATETIMEDELTA_IN_MONTHS_DAYS_SECS_NANOS: &CStr = c"\
 Convert to a tuple of (months, days, seconds, nanoseconds)
@@ -1086,6 +1272,9 @@ See :ref:`here <iso8601-durations>` for more information.
    -PT7H4M    # -7 hours and -4 minutes (-7:04:00)
    +PT7H4M    # 7 hours and 4 minutes (7:04:00)
 
+The :ref:`alternative format <iso8601-alternative-durations>`
+(e.g. ``P0001-02-03T04:05:06``) is also accepted.
+
 Inverse of :meth:`format_iso`
 
 >>> DateTimeDelta.parse_iso(\"-P1W11DT4H\")
@@ -1093,6 +1282,157 @@ DateTimeDelta(-P1w11dT4h)
 ";
 pub(crate) const DATETIMEDELTA_TIME_PART: &CStr = c"\
 The time part of the delta";
+pub(crate) const EXPANDEDDATE_ADD: &CStr = c"\
+add($self, delta=None, /, *, years=0, months=0, weeks=0, days=0)
+--
+
+Add components to a date.
+
+Years and months are added first, clamping the day to the end
+of the month if needed. Weeks and days are added afterwards.
+This matches the behavior of :meth:`Date.add <whenever.Date.add>`.
+
+>>> ExpandedDate(-44, 3, 15).add(years=-1000, days=20)
+ExpandedDate(\"-001044-04-04\")
+>>> ExpandedDate(-4, 2, 29).add(years=1)
+ExpandedDate(\"-000003-02-28\")
+";
+pub(crate) const EXPANDEDDATE_DATE: &CStr = c"\
+Convert to a :class:`~whenever.Date`
+
+>>> ExpandedDate(2024, 3, 15).date()
+Date(\"2024-03-15\")
+
+Raises
+------
+ValueError
+    If the year is outside the 1--9999 range supported by
+    :class:`~whenever.Date`.
+";
+pub(crate) const EXPANDEDDATE_DAY: &CStr = c"\
+The day of the month
+
+>>> ExpandedDate(-44, 3, 15).day
+15
+";
+pub(crate) const EXPANDEDDATE_DAY_OF_WEEK: &CStr = c"\
+The day of the week
+
+>>> ExpandedDate(-44, 3, 15).day_of_week()
+Weekday.THURSDAY
+";
+pub(crate) const EXPANDEDDATE_DAY_OF_YEAR: &CStr = c"\
+The day of the year (1--366)
+
+>>> ExpandedDate(-44, 3, 15).day_of_year()
+75
+";
+pub(crate) const EXPANDEDDATE_DAYS_IN_MONTH: &CStr = c"\
+The number of days in the month
+
+>>> ExpandedDate(-4, 2, 1).days_in_month()
+29
+";
+pub(crate) const EXPANDEDDATE_DAYS_IN_YEAR: &CStr = c"\
+The number of days in the year (365 or 366)
+
+>>> ExpandedDate(-4, 1, 1).days_in_year()
+366
+";
+pub(crate) const EXPANDEDDATE_FORMAT_ISO: &CStr = c"\
+format_iso($self, *, basic=False)
+--
+
+Format as an ISO 8601 date string.
+
+Years 0--9999 use the regular four-digit form.
+Other years use the expanded form with a sign and six digits.
+
+>>> ExpandedDate(2024, 3, 15).format_iso()
+'2024-03-15'
+>>> ExpandedDate(-44, 3, 15).format_iso()
+'-000044-03-15'
+>>> ExpandedDate(12025, 1, 1).format_iso(basic=True)
+'+0120250101'
+";
+pub(crate) const EXPANDEDDATE_FROM_DATE: &CStr = c"\
+Create an expanded date from a :class:`~whenever.Date`
+
+>>> ExpandedDate.from_date(Date(2024, 3, 15))
+ExpandedDate(\"2024-03-15\")
+";
+pub(crate) const EXPANDEDDATE_IN_LEAP_YEAR: &CStr = c"\
+Whether the year is a leap year in the proleptic
+Gregorian calendar
+
+>>> ExpandedDate(0, 1, 1).in_leap_year()
+True
+>>> ExpandedDate(-100, 1, 1).in_leap_year()
+False
+";
+pub(crate) const EXPANDEDDATE_MONTH: &CStr = c"\
+The month (1--12)
+
+>>> ExpandedDate(-44, 3, 15).month
+3
+";
+pub(crate) const EXPANDEDDATE_PARSE_ISO: &CStr = c"\
+Parse an ISO 8601 date string, in either the regular
+or the expanded (signed) year format.
+Both the extended and basic formats are supported.
+
+>>> ExpandedDate.parse_iso(\"-0044-03-15\")
+ExpandedDate(\"-000044-03-15\")
+>>> ExpandedDate.parse_iso(\"+0120250101\")
+ExpandedDate(\"+012025-01-01\")
+";
+pub(crate) const EXPANDEDDATE_REPLACE: &CStr = c"\
+replace($self, /, *, year=None, month=None, day=None)
+--
+
+Return a new :class:`ExpandedDate` with the given fields replaced
+
+>>> ExpandedDate(-44, 3, 15).replace(year=-43)
+ExpandedDate(\"-000043-03-15\")
+";
+pub(crate) const EXPANDEDDATE_SINCE: &CStr = c"\
+since($self, b, /, *, total=..., in_units=..., round_mode=..., round_increment=...)
+--
+
+Calculate the difference between this date and another date.
+Works the same as :meth:`Date.since <whenever.Date.since>`.
+Note that an :class:`~whenever.ItemizedDateDelta` is limited to
+9999 years, so use ``total`` for larger differences.
+
+>>> d = ExpandedDate(2025, 1, 1)
+>>> d.since(ExpandedDate(-44, 3, 15), in_units=[\"years\", \"days\"])
+ItemizedDateDelta(\"P2068y292d\")
+>>> d.since(ExpandedDate(-44, 3, 15), total=\"days\")
+755614.0
+";
+pub(crate) const EXPANDEDDATE_SUBTRACT: &CStr = c"\
+subtract($self, delta=None, /, *, years=0, months=0, weeks=0, days=0)
+--
+
+Subtract components from a date.
+See :meth:`add` for more information.
+
+>>> ExpandedDate(1, 1, 1).subtract(days=1)
+ExpandedDate(\"0000-12-31\")
+";
+pub(crate) const EXPANDEDDATE_UNTIL: &CStr = c"\
+until($self, b, /, *, total=..., in_units=..., round_mode=..., round_increment=...)
+--
+
+Companion to :meth:`since` that calculates the difference
+until another date. See :meth:`since` for more information.
+";
+pub(crate) const EXPANDEDDATE_YEAR: &CStr = c"\
+The (astronomical) year, which may be zero or negative
+
+>>> ExpandedDate(-44, 3, 15).year
+-44
+";
 pub(crate) const INSTANT_ADD: &CStr = c"\
 add($self, delta=None, /, *, hours=0, minutes=0, seconds=0, milliseconds=0, microseconds=0, nanoseconds=0)
 --
@@ -1102,6 +1442,9 @@ Add a time amount to this instant.
 See the `docs on arithmetic <https://whenever.rtfd.io/en/latest/guide/arithmetic.html>`__ for more information.
 ";
 pub(crate) const INSTANT_FORMAT: &CStr = c"\
+format($self, pattern, /, *, locale=None, style='pattern')
+--
+
 Format as a custom pattern string.
 
 Instant formats as UTC; See :ref:`pattern-format` for details.
@@ -1128,12 +1471,120 @@ Note
 ----
 The output is also compatible with the (stricter) RFC 9110 standard.
 
+";
+pub(crate) const INSTANT_FROM_CBOR: &CStr = c"\
+Decode a CBOR item with tag 0 (RFC 3339 text),
+1 (epoch-based), or 1001 (extended time).
+See :meth:`to_cbor`.
+
+>>> Instant.from_cbor(bytes.fromhex(\"c11a514b67b0\"))
+Instant(\"2013-03-21 20:04:00Z\")
+";
+pub(crate) const INSTANT_FROM_COCOA: &CStr = c"\
+Create an Instant from a number of seconds since Apple's
+reference date. Inverse of :meth:`to_cocoa`.
+
+>>> Instant.from_cocoa(732196800.25)
+Instant(\"2024-03-15 12:00:00.25Z\")
+";
+pub(crate) const INSTANT_FROM_DOTNET_TICKS: &CStr = c"\
+Create an Instant from a number of .NET ticks, interpreted as UTC.
+Inverse of :meth:`to_dotnet_ticks`.
+
+>>> Instant.from_dotnet_ticks(638460576000000000)
+Instant(\"2024-03-15 00:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_EXCEL_SERIAL: &CStr = c"\
+from_excel_serial(n, /, *, epoch_1904=False)
+--
+
+Create an Instant from a (fractional) spreadsheet serial date
+number, interpreted as UTC.
+Inverse of :meth:`to_excel_serial`.
+
+>>> Instant.from_excel_serial(45366.75)
+Instant(\"2024-03-15 18:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_FILETIME: &CStr = c"\
+Create an Instant from a Windows FILETIME value.
+Inverse of :meth:`to_filetime`.
+
+>>> Instant.from_filetime(133549344000000000)
+Instant(\"2024-03-15 00:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_GPS_NANOS: &CStr = c"\
+Create an Instant from a number of nanoseconds in GPS time.
+Inverse of :meth:`to_gps_nanos`.
+A leap second itself (23:59:60 UTC), which an Instant can't
+represent, becomes the second before it.
+
+>>> Instant.from_gps_nanos(1394496018000000000)
+Instant(\"2024-03-15 00:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_JULIAN_DAY: &CStr = c"\
+Create an Instant from a (fractional) Julian date.
+Inverse of :meth:`to_julian_day`.
+
+>>> Instant.from_julian_day(2451545.25)
+Instant(\"2000-01-01 18:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_MJD: &CStr = c"\
+Create an Instant from a (fractional) Modified Julian Date.
+Inverse of :meth:`to_mjd`.
+
+>>> Instant.from_mjd(51544.5)
+Instant(\"2000-01-01 12:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_MSGPACK: &CStr = c"\
+Decode a MessagePack timestamp extension value,
+including its header. See :meth:`to_msgpack`.
+
+>>> Instant.from_msgpack(bytes.fromhex(\"d6ff514b67b0\"))
+Instant(\"2013-03-21 20:04:00Z\")
+";
+pub(crate) const INSTANT_FROM_NTP: &CStr = c"\
+Create an Instant from a 64-bit NTP timestamp in era 0.
+Inverse of :meth:`to_ntp`.
+Fractions smaller than a nanosecond are truncated.
+
+>>> Instant.from_ntp(3919492800 << 32 | 1 << 31)
+Instant(\"2024-03-15 12:00:00.5Z\")
+";
+pub(crate) const INSTANT_FROM_POSTGRES_MICROS: &CStr = c"\
+Create an Instant from a number of microseconds
+since 2000-01-01 00:00 UTC. Inverse of :meth:`to_postgres_micros`.
+
+>>> Instant.from_postgres_micros(763776000000000)
+Instant(\"2024-03-15 00:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_RATA_DIE: &CStr = c"\
+Create an Instant from a (fractional) Rata Die day number.
+Inverse of :meth:`to_rata_die`.
+
+>>> Instant.from_rata_die(730120.25)
+Instant(\"2000-01-01 06:00:00Z\")
+";
+pub(crate) const INSTANT_FROM_SNOWFLAKE: &CStr = c"\
+from_snowflake(i, /, *, epoch)
+--
+
+Extract the timestamp of a Snowflake ID,
+given the epoch of its issuer. See :meth:`to_snowflake`.
+
+>>> discord = Instant.from_utc(2015, 1, 1)
+>>> Instant.from_snowflake(175928847299117063, epoch=discord)
+Instant(\"2016-04-30 11:18:25.796Z\")
 ";
 pub(crate) const INSTANT_FROM_TIMESTAMP: &CStr = c"\
 Create an Instant from a UNIX timestamp (in seconds).
 
 The inverse of the ``timestamp()`` method.
 ";
+pub(crate) const INSTANT_FROM_TIMESTAMP_MICROS: &CStr = c"\
+Create an Instant from a UNIX timestamp (in microseconds).
+
+The inverse of the ``timestamp_micros()`` method.
+";
 pub(crate) const INSTANT_FROM_TIMESTAMP_MILLIS: &CStr = c"\
 Create an Instant from a UNIX timestamp (in milliseconds).
 
@@ -1144,11 +1595,43 @@ Create an Instant from a UNIX timestamp (in nanoseconds).
 
 The inverse of the ``timestamp_nanos()`` method.
 ";
+pub(crate) const INSTANT_FROM_ULID: &CStr = c"\
+Extract the timestamp of a ULID string. Case-insensitive.
+
+>>> Instant.from_ulid(\"01HS0ZQEKXQ2Z5VHKCM5H6G1B8\")
+Instant(\"2024-03-15 12:00:00.125Z\")
+";
 pub(crate) const INSTANT_FROM_UTC: &CStr = c"\
 from_utc(year, month, day, hour=0, minute=0, second=0, *, nanosecond=0)
 --
 
 Create an Instant defined by a UTC date and time.";
+pub(crate) const INSTANT_FROM_UUID7: &CStr = c"\
+Extract the timestamp of a UUIDv7, given as a
+:class:`~uuid.UUID` or as its 16 bytes.
+
+>>> Instant.from_uuid7(UUID(\"018e41fb-bb2a-7b3c-9d4e-5f6a7b8c9d0e\"))
+Instant(\"2024-03-15 12:00:00.298Z\")
+
+Raises ValueError if the UUID isn't version 7.
+";
+pub(crate) const INSTANT_HUMANIZE: &CStr = c"\
+humanize($self, *, relative_to=..., granularity='seconds', round_mode='trunc', locale=None, days_assumed_24h_ok=False)
+--
+
+Describe this moment relative to another (by default, now),
+such as \"in 3 hours\" or \"2 days ago\"
+
+This is equivalent to calling :meth:`TimeDelta.humanize` on the
+difference between the two. To use calendar days, months,
+and years, see :meth:`ZonedDateTime.humanize`.
+
+>>> i = Instant.from_utc(2024, 3, 1, 12)
+>>> i.humanize(relative_to=Instant.from_utc(2024, 3, 1, 9, 30))
+'in 2 hours'
+>>> i.humanize(relative_to=Instant.from_utc(2024, 3, 1, 12, 45))
+'45 minutes ago'
+";
 pub(crate) const INSTANT_NOW: &CStr = c"\
 Create an Instant from the current time.
 
@@ -1156,7 +1639,7 @@ Create an Instant from the current time.
 Instant(\"2024-06-15 12:34:56.789123456Z\")
 ";
 pub(crate) const INSTANT_PARSE: &CStr = c"\
-parse(s, /, *, format)
+parse(s, /, *, format, locale=None, style='pattern', lenient=False, pivot_year=None)
 --
 
 Parse an instant from a custom pattern string.
@@ -1177,15 +1660,40 @@ Instant(\"2024-03-15 14:30:00Z\")
 >>> Instant.parse(\"2024-03-15 14:30+05:30\", format=\"YYYY-MM-DD hh:mmxxx\")
 Instant(\"2024-03-15 09:00:00Z\")
 ";
+pub(crate) const INSTANT_PARSE_HTTP_DATE: &CStr = c"\
+Parse an HTTP date in any of the formats of RFC 9110.
+
+Next to the preferred IMF-fixdate format (as produced by
+``format_rfc2822()``), the obsolete RFC 850 and asctime formats
+are accepted too.
+
+>>> Instant.parse_http_date(\"Sun, 06 Nov 1994 08:49:37 GMT\")
+Instant(\"1994-11-06 08:49:37Z\")
+>>> Instant.parse_http_date(\"Sunday, 06-Nov-94 08:49:37 GMT\")
+Instant(\"1994-11-06 08:49:37Z\")
+>>> Instant.parse_http_date(\"Sun Nov  6 08:49:37 1994\")
+Instant(\"1994-11-06 08:49:37Z\")
+
+Note
+----
+As the specification requires, parsing is case-sensitive,
+and the two-digit year of the RFC 850 format is interpreted
+relative to the current year:
+a date more than 50 years in the future is taken to be
+in the previous century.
+";
 pub(crate) const INSTANT_PARSE_ISO: &CStr = c"\
 Parse an ISO 8601 string. Supports basic and extended formats,
-but not week dates or ordinal dates.
+as well as week dates and ordinal dates.
 
 See the `docs on ISO8601 support <https://whenever.rtfd.io/en/latest/reference/iso8601.html>`__ for more information.
 
 The inverse of the ``format_iso()`` method.
 ";
 pub(crate) const INSTANT_PARSE_RFC2822: &CStr = c"\
+parse_rfc2822(s, /, *, strict=False)
+--
+
 Parse a UTC datetime in RFC 2822 format.
 
 The inverse of the ``format_rfc2822()`` method.
@@ -1199,11 +1707,16 @@ Instant(\"2020-08-15 23:12:00Z\")
 >>> Instant.parse_rfc2822(\"Sat, 15 Aug 2020 23:12:00 -0000\")
 >>> Instant.parse_rfc2822(\"Sat, 15 Aug 2020 23:12:00 UT\")
 >>> Instant.parse_rfc2822(\"Sat, 15 Aug 2020 23:12:00 MST\")
+>>> Instant.parse_rfc2822(\"15 Aug 20 23:12 (UTC) Z\")
 
 Note
 ----
-- Although technically part of the RFC 2822 standard,
-  comments within folding whitespace are not supported.
+By default, the obsolete syntax of RFC 5322 is accepted too:
+named and military zones, two-digit years, omitted seconds,
+and comments in parentheses. Unrecognized zones are taken as UTC.
+With ``strict=True``, only the modern syntax is accepted:
+a numeric offset or ``GMT`` (as output by :meth:`format_rfc2822`),
+four-digit year, and at most a trailing comment.
 ";
 pub(crate) const INSTANT_ROUND: &CStr = c"\
 round($self, unit='second', /, *, increment=1, mode='half_even')
@@ -1228,6 +1741,169 @@ Subtract a time amount from this instant.
 
 See the `docs on arithmetic <https://whenever.rtfd.io/en/latest/guide/arithmetic.html>`__ for more information.
 ";
+pub(crate) const INSTANT_TIMESTAMP_MICROS: &CStr = c"\
+Like :meth:`timestamp`, but with microsecond precision.";
+pub(crate) const INSTANT_TO_CBOR: &CStr = c"\
+to_cbor($self, *, tag=1)
+--
+
+Encode as a CBOR (RFC 8949) tagged date/time item.
+
+The ``tag`` determines the representation:
+
+- ``1`` (default): seconds since the UNIX epoch.
+  An integer if there are no fractional seconds,
+  otherwise a float (which may lose precision).
+- ``0``: RFC 3339 text, in UTC.
+- ``1001``: extended time (RFC 9581), a map of seconds and
+  nanoseconds. Unlike tag 1, this is always lossless.
+
+>>> Instant.from_utc(2013, 3, 21, 20, 4).to_cbor().hex()
+'c11a514b67b0'
+>>> Instant.from_utc(2013, 3, 21, 20, 4).to_cbor(tag=1001).hex()
+'d903e9a1011a514b67b0'
+";
+pub(crate) const INSTANT_TO_COCOA: &CStr = c"\
+The number of seconds since Apple's reference date,
+2001-01-01 00:00 UTC, as used by ``NSDate`` and Core Data.
+
+>>> Instant.from_utc(2024, 3, 15, 12).to_cocoa()
+732196800.0
+";
+pub(crate) const INSTANT_TO_DOTNET_TICKS: &CStr = c"\
+The number of .NET ticks: 100-nanosecond intervals
+since 0001-01-01 00:00 UTC, as used by ``DateTime.Ticks``.
+
+>>> Instant.from_utc(2024, 3, 15).to_dotnet_ticks()
+638460576000000000
+";
+pub(crate) const INSTANT_TO_EXCEL_SERIAL: &CStr = c"\
+to_excel_serial($self, *, epoch_1904=False)
+--
+
+The fractional serial date number used by spreadsheets
+such as Excel, in UTC.
+See :meth:`Date.to_excel_serial` for details on the date systems.
+
+>>> Instant.from_utc(2024, 3, 15, hour=18).to_excel_serial()
+45366.75
+";
+pub(crate) const INSTANT_TO_FILETIME: &CStr = c"\
+The Windows FILETIME value: the number of 100-nanosecond
+intervals since 1601-01-01 00:00 UTC.
+
+>>> Instant.from_utc(2024, 3, 15).to_filetime()
+133549344000000000
+";
+pub(crate) const INSTANT_TO_GPS_NANOS: &CStr = c"\
+The number of nanoseconds since the GPS epoch,
+1980-01-06 00:00 UTC, as counted by GPS time.
+
+>>> Instant.from_utc(2024, 3, 15).to_gps_nanos()
+1394496018000000000
+
+Note
+----
+GPS time doesn't have leap seconds, so it's ahead of UTC by
+the leap seconds inserted since 1980: 18 seconds since 2017.
+The conversion uses a table of these leap seconds,
+so future leap seconds (if any) require an update of whenever.
+";
+pub(crate) const INSTANT_TO_JULIAN_DAY: &CStr = c"\
+The Julian date: the fractional number of days since
+noon UTC on 4714-11-24 BCE in the proleptic Gregorian calendar.
+
+>>> Instant.from_utc(2000, 1, 1, hour=12).to_julian_day()
+2451545.0
+
+Note
+----
+A float can't represent all instants exactly.
+For Julian dates in the present era, the precision is
+around 40 microseconds.
+";
+pub(crate) const INSTANT_TO_MJD: &CStr = c"\
+The Modified Julian Date: the fractional number of days since
+1858-11-17 00:00 UTC
+
+>>> Instant.from_utc(2000, 1, 1, hour=12).to_mjd()
+51544.5
+";
+pub(crate) const INSTANT_TO_MSGPACK: &CStr = c"\
+Encode as a MessagePack timestamp extension value (type -1),
+including its header. The smallest of the 32, 64, and 96-bit
+variants is used.
+
+>>> Instant.from_utc(2013, 3, 21, 20, 4).to_msgpack().hex()
+'d6ff514b67b0'
+";
+pub(crate) const INSTANT_TO_NTP: &CStr = c"\
+The 64-bit NTP timestamp: seconds since 1900-01-01 00:00 UTC
+as a fixed-point number, with the fraction in the lower 32 bits.
+
+>>> Instant.from_utc(2024, 3, 15, 12).to_ntp() >> 32
+3919492800
+
+Raises ValueError if the instant is outside NTP era 0,
+which ends at 2036-02-07 06:28:16 UTC.
+
+Note
+----
+The fraction is rounded up to the next 1/2^32 second,
+so that :meth:`from_ntp` returns the original instant.
+";
+pub(crate) const INSTANT_TO_POSTGRES_MICROS: &CStr = c"\
+The number of microseconds since 2000-01-01 00:00 UTC,
+as used by PostgreSQL's binary ``timestamptz`` format.
+
+>>> Instant.from_utc(2024, 3, 15).to_postgres_micros()
+763776000000000
+";
+pub(crate) const INSTANT_TO_RATA_DIE: &CStr = c"\
+The fractional Rata Die day number,
+where 0001-01-01 00:00 UTC is day 1.0
+
+>>> Instant.from_utc(2000, 1, 1, hour=6).to_rata_die()
+730120.25
+";
+pub(crate) const INSTANT_TO_SNOWFLAKE: &CStr = c"\
+to_snowflake($self, *, epoch)
+--
+
+The smallest Snowflake ID with this instant's (millisecond)
+timestamp. The worker ID and sequence number are zero.
+This makes it useful as a bound when querying time-ordered IDs.
+
+Snowflake IDs count milliseconds since an epoch which differs
+per issuer. For example, Discord's epoch is 2015-01-01 00:00 UTC,
+and Twitter's is 1288834974657 milliseconds after the UNIX epoch.
+
+>>> discord = Instant.from_utc(2015, 1, 1)
+>>> Instant.from_utc(2024, 3, 15, 12).to_snowflake(epoch=discord)
+1218166810214400000
+";
+pub(crate) const INSTANT_TO_ULID: &CStr = c"\
+The smallest ULID with this instant's (millisecond) timestamp.
+The random part is all zeros.
+This makes it useful as a bound when querying time-ordered IDs.
+
+>>> Instant.from_utc(2024, 3, 15, 12).to_ulid()
+'01HS0ZQEG00000000000000000'
+
+Raises ValueError for instants before 1970,
+which ULIDs can't represent.
+";
+pub(crate) const INSTANT_TO_UUID7: &CStr = c"\
+The smallest UUIDv7 with this instant's (millisecond) timestamp.
+All bits except the timestamp, version, and variant are zero.
+This makes it useful as a bound when querying time-ordered IDs.
+
+>>> Instant.from_utc(2024, 3, 15, 12).to_uuid7()
+UUID('018e41fb-ba00-7000-8000-000000000000')
+
+Raises ValueError for instants before 1970,
+which UUIDv7 can't represent.
+";
 pub(crate) const ITEMIZEDDATEDELTA_ADD: &CStr = c"\
 add($self, arg=..., /, *, relative_to=..., in_units=..., round_mode=..., round_increment=..., cal_unit_composition_ok=..., **kwargs)
 --
@@ -1397,6 +2073,33 @@ The format is:
 >>> d.format_iso()
 'P1W11DT4H1.000012S'
 ";
+pub(crate) const ITEMIZEDDELTA_HUMANIZE: &CStr = c"\
+humanize($self, *, relative_to, granularity='seconds', round_mode='trunc', locale=None)
+--
+
+Describe the delta in words, such as \"in 3 hours\" or \"2 months ago\"
+
+The delta is added to ``relative_to``, and the result is described
+with :meth:`ZonedDateTime.humanize`.
+
+Parameters
+----------
+relative_to
+    A :class:`ZonedDateTime`, :class:`PlainDateTime`, or
+    :class:`OffsetDateTime` reference point.
+
+    - :class:`ZonedDateTime`: DST-aware; emits no warning
+    - :class:`PlainDateTime`: emits :class:`NaiveArithmeticWarning`
+      if the delta contains exact time units
+    - :class:`OffsetDateTime`: emits :class:`StaleOffsetWarning`
+
+>>> d = ItemizedDelta(months=1, days=20)
+>>> ref = ZonedDateTime(2024, 1, 1, tz=\"Europe/Paris\")
+>>> d.humanize(relative_to=ref)
+'in 1 month'
+>>> d.humanize(relative_to=ref, round_mode=\"half_even\")
+'in 2 months'
+";
 pub(crate) const ITEMIZEDDELTA_IN_UNITS: &CStr = c"\
 in_units($self, units, /, *, relative_to, round_mode='trunc', round_increment=1)
 --
@@ -1524,6 +2227,9 @@ OffsetDateTime(\"2024-08-15 23:59:59.999999999+05:00\")
 See also :meth:`start_of`
 ";
 pub(crate) const OFFSETDATETIME_FORMAT: &CStr = c"\
+format($self, pattern, /, *, locale=None, style='pattern')
+--
+
 Format as a custom pattern string.
 
 See :ref:`pattern-format` for details.
@@ -1549,6 +2255,14 @@ The inverse of the ``parse_rfc2822()`` method.
 >>> OffsetDateTime(2020, 8, 15, 23, 12, offset=hours(2)).format_rfc2822()
 \"Sat, 15 Aug 2020 23:12:00 +0200\"
 ";
+pub(crate) const OFFSETDATETIME_FROM_CBOR: &CStr = c"\
+Decode a CBOR item with tag 0 (RFC 3339 text).
+See :meth:`to_cbor`.
+
+>>> data = OffsetDateTime(2013, 3, 21, 21, 4, offset=1).to_cbor()
+>>> OffsetDateTime.from_cbor(data)
+OffsetDateTime(\"2013-03-21 21:04:00+01:00\")
+";
 pub(crate) const OFFSETDATETIME_FROM_TIMESTAMP: &CStr = c"\
 from_timestamp(i, /, *, offset, ignore_dst=..., stale_offset_ok=...)
 --
@@ -1603,7 +2317,7 @@ Use ``ZonedDateTime.now('<tz>')`` if you know the timezone, or
 Pass ``stale_offset_ok=True`` to suppress.
 ";
 pub(crate) const OFFSETDATETIME_PARSE: &CStr = c"\
-parse(s, /, *, format)
+parse(s, /, *, format, locale=None, style='pattern', lenient=False, pivot_year=None)
 --
 
 Parse an offset datetime from a custom pattern string.
@@ -1640,6 +2354,9 @@ Strictly speaking, ``Z`` means \"UTC\" (i.e. no fixed offset),
 but in practice it is almost universally used as a synonym for ``+00:00``.
 ";
 pub(crate) const OFFSETDATETIME_PARSE_RFC2822: &CStr = c"\
+parse_rfc2822(s, /, *, strict=False)
+--
+
 Parse an offset datetime in RFC 2822 format.
 
 The inverse of the ``format_rfc2822()`` method.
@@ -1650,13 +2367,18 @@ OffsetDateTime(\"2020-08-15 23:12:00+02:00\")
 >>> OffsetDateTime.parse_rfc2822(\"Sat, 15 Aug 2020 23:12:00 UT\")
 >>> OffsetDateTime.parse_rfc2822(\"Sat, 15 Aug 2020 23:12:00 GMT\")
 >>> OffsetDateTime.parse_rfc2822(\"Sat, 15 Aug 2020 23:12:00 MST\")
+>>> OffsetDateTime.parse_rfc2822(\"Sat, 15 Aug 20 23:12 -0330 (NST)\")
 
 Note
 ----
+- By default, the obsolete syntax of RFC 5322 is accepted too:
+  named and military zones, two-digit years, omitted seconds,
+  and comments in parentheses. Unrecognized zones are taken as UTC.
+  With ``strict=True``, only the modern syntax is accepted:
+  a numeric offset or ``GMT``, four-digit year,
+  and at most a trailing comment.
 - Strictly speaking, an offset of ``-0000`` means that the offset
   is \"unknown\". Here, we treat it the same as +0000.
-- Although technically part of the RFC 2822 standard,
-  comments within folding whitespace are not supported.
 ";
 pub(crate) const OFFSETDATETIME_PARSE_STRPTIME: &CStr = c"\
 parse_strptime(s, /, *, format)
@@ -1763,11 +2485,73 @@ Subtract a time amount from this datetime.
 
 See :meth:`add` for more information.
 ";
+pub(crate) const OFFSETDATETIME_TO_CBOR: &CStr = c"\
+Encode as a CBOR (RFC 8949) item with tag 0: RFC 3339 text.
+
+>>> d = OffsetDateTime(2013, 3, 21, 21, 4, offset=1)
+>>> d.to_cbor()[3:]  # after the tag and text headers
+b'2013-03-21T21:04:00+01:00'
+
+Raises ValueError if the offset has seconds,
+which RFC 3339 doesn't allow.
+The other CBOR date/time tags don't store an offset.
+Use :meth:`Instant.to_cbor` for those.
+";
 pub(crate) const OFFSETDATETIME_UNTIL: &CStr = c"\
 until($self, b, /, *, total=..., in_units=..., round_mode=..., round_increment=...)
 --
 
 Inverse of the ``since()`` method. See :meth:`since` for more information.";
+pub(crate) const OFFSETPERIOD_ABBREV: &CStr = c"\
+The timezone abbreviation during the period (e.g. ``\"CEST\"``)";
+pub(crate) const OFFSETPERIOD_DST_OFFSET: &CStr = c"\
+The DST adjustment during the period.
+Zero if DST is not in effect.";
+pub(crate) const OFFSETPERIOD_END: &CStr = c"\
+The moment the period ends (exclusive),
+or ``None`` if there is no later transition";
+pub(crate) const OFFSETPERIOD_IS_DST: &CStr = c"\
+Whether DST is in effect during the period";
+pub(crate) const OFFSETPERIOD_OBSERVES_DST: &CStr = c"\
+Whether the timezone observes DST at any point
+in the (local) calendar year of the originating datetime";
+pub(crate) const OFFSETPERIOD_OFFSET: &CStr = c"\
+The UTC offset during the period";
+pub(crate) const OFFSETPERIOD_START: &CStr = c"\
+The moment the period starts (inclusive),
+or ``None`` if there is no earlier transition";
+pub(crate) const OFFSETPERIOD_STD_OFFSET: &CStr = c"\
+The standard UTC offset during the period, i.e.
+the offset without any DST adjustment";
+pub(crate) const PARSEERROR_RENDER: &CStr = c"\
+The error message, followed by the input with a caret (``^``)
+pointing at the position where parsing failed.
+
+If the position is unknown, only the message is returned.
+";
+pub(crate) const PATTERN_FORMAT: &CStr = c"\
+format($self, value, /, *, locale=None)
+--
+
+Format a value of the pattern's type.
+
+>>> Pattern(\"hh:mm\", Time).format(Time(14, 30))
+'14:30'
+";
+pub(crate) const PATTERN_PARSE: &CStr = c"\
+parse($self, s, /, *, locale=None, disambiguate=None, lenient=False, pivot_year=None)
+--
+
+Parse a string into a value of the pattern's type.
+
+The ``disambiguate`` argument is only accepted by
+:class:`ZonedDateTime` patterns. See :meth:`ZonedDateTime.parse`.
+
+>>> Pattern(\"hh:mm\", Time).parse(\"14:30\")
+Time(\"14:30:00\")
+";
+pub(crate) const PATTERN_PATTERN: &CStr = c"\
+The pattern string";
 pub(crate) const PLAINDATETIME_ADD: &CStr = c"\
 add($self, delta=None, /, *, years=0, months=0, weeks=0, days=0, hours=0, minutes=0, seconds=0, milliseconds=0, microseconds=0, nanoseconds=0, ignore_dst=..., naive_arithmetic_ok=False)
 --
@@ -1864,6 +2648,9 @@ PlainDateTime(\"2024-08-15 14:59:59.999999999\")
 See also :meth:`start_of`
 ";
 pub(crate) const PLAINDATETIME_FORMAT: &CStr = c"\
+format($self, pattern, /, *, locale=None, style='pattern')
+--
+
 Format as a custom pattern string.
 
 Also available via ``f\"{dt:YYYY-MM-DD hh:mm}\"`` (Python's ``__format__``
@@ -1883,7 +2670,7 @@ Convert to the popular ISO format ``YYYY-MM-DDTHH:MM:SS``
 The inverse of the ``parse_iso()`` method.
 ";
 pub(crate) const PLAINDATETIME_PARSE: &CStr = c"\
-parse(s, /, *, format)
+parse(s, /, *, format, locale=None, style='pattern', lenient=False, pivot_year=None)
 --
 
 Parse a plain datetime from a custom pattern string.
@@ -1972,6 +2759,9 @@ until($self, b, /, *, total=..., in_units=..., round_mode=..., round_increment=.
 
 Inverse of the ``since()`` method. See :meth:`since` for more information.";
 pub(crate) const TIME_FORMAT: &CStr = c"\
+format($self, pattern, /, *, locale=None, style='pattern')
+--
+
 Format as a custom pattern string.
 
 See :ref:`pattern-format` for details.
@@ -2037,7 +2827,7 @@ to find the corresponding exact time:
 ExactDateTime(\"2021-01-02 12:30:00-05:00[America/New_York]\")
 ";
 pub(crate) const TIME_PARSE: &CStr = c"\
-parse(s, /, *, format)
+parse(s, /, *, format, locale=None, style='pattern', lenient=False)
 --
 
 Parse a time from a custom pattern string.
@@ -2111,7 +2901,47 @@ Add time to this delta, returning a new delta.
 
 Days and weeks are treated as exact 24-hour and 168-hour units,
 which emits a :class:`~whenever.DaysAssumed24HoursWarning`.";
+pub(crate) const TIMEDELTA_FORMAT: &CStr = c"\
+format($self, pattern, /, *, style='pattern')
+--
+
+Format as a custom pattern string.
+
+The largest unit in the pattern holds the total amount,
+while smaller units hold the remainder.
+See :ref:`delta-patterns` for details.
+With ``style=\"spreadsheet\"``, spreadsheet formats with an
+elapsed-time code (e.g. ``[h]:mm:ss``) are accepted instead.
+
+>>> d = TimeDelta(hours=50, minutes=15, seconds=9, milliseconds=250)
+>>> d.format(\"hh:mm:ss.fff\")
+'50:15:09.250'
+>>> d.format(\"D'd' hh'h'\")
+'2d 02h'
+>>> (-d).format(\"nh:mm\")
+'-50:15'
+>>> d.format(\"[h]:mm:ss\", style=\"spreadsheet\")
+'50:15:09'
+";
+pub(crate) const TIMEDELTA_FORMAT_GO: &CStr = c"\
+Format in the style of Go's ``Duration.String()``.
+
+Hours, minutes, and seconds are used,
+or a single smaller unit if the delta is less than a second.
+The result can be parsed by :meth:`parse_go`
+and :meth:`parse_human`.
+
+>>> TimeDelta(hours=1, minutes=30).format_go()
+'1h30m0s'
+>>> TimeDelta(milliseconds=-250).format_go()
+'-250ms'
+>>> TimeDelta(microseconds=1.5).format_go()
+'1.5µs'
+";
 pub(crate) const TIMEDELTA_FORMAT_ISO: &CStr = c"\
+format_iso($self, *, alternative=False, basic=False)
+--
+
 Format as the *popular interpretation* of the ISO 8601 duration format.
 May not strictly adhere to (all versions of) the standard.
 See :ref:`here <iso8601-durations>` for more information.
@@ -2120,6 +2950,26 @@ Inverse of :meth:`parse_iso`.
 
 >>> TimeDelta(hours=1, minutes=30).format_iso()
 'PT1H30M'
+
+With ``alternative=True``, the ISO 8601 alternative format is used
+(in basic format if ``basic=True``).
+It can only express durations of up to 24 hours.
+
+>>> TimeDelta(hours=1, minutes=30).format_iso(alternative=True)
+'PT01:30:00'
+";
+pub(crate) const TIMEDELTA_FROM_CBOR: &CStr = c"\
+Decode a CBOR item with tag 1002 (duration).
+See :meth:`to_cbor`.
+
+>>> TimeDelta.from_cbor(bytes.fromhex(\"d903eaa101185a\"))
+TimeDelta(\"PT1m30s\")
+";
+pub(crate) const TIMEDELTA_FROM_MSGPACK: &CStr = c"\
+Decode the format of :meth:`to_msgpack`.
+
+>>> TimeDelta.from_msgpack(bytes.fromhex(\"d6ff0000005a\"))
+TimeDelta(\"PT1m30s\")
 ";
 pub(crate) const TIMEDELTA_FROM_PY_TIMEDELTA: &CStr = c"\
 Create from a :class:`~datetime.timedelta`
@@ -2131,6 +2981,31 @@ TimeDelta(\"PT1h30m\")
 
     Use the constructor ``TimeDelta(td)`` instead.
 ";
+pub(crate) const TIMEDELTA_HUMANIZE: &CStr = c"\
+humanize($self, *, granularity='seconds', round_mode='trunc', locale=None, days_assumed_24h_ok=False)
+--
+
+Describe the delta in words, such as \"in 3 hours\" or \"2 days ago\"
+
+The largest unit (up to weeks) of which the delta is at least one
+is used, but never a unit smaller than ``granularity``.
+The amount is rounded with ``round_mode``.
+Positive deltas are described as being in the future,
+negative ones as being in the past.
+
+Since days and weeks are taken to be 24 hours and 7 days,
+a :class:`DaysAssumed24HoursWarning` is emitted if they're used,
+unless ``days_assumed_24h_ok=True``.
+
+>>> TimeDelta(hours=3, minutes=40).humanize()
+'in 3 hours'
+>>> TimeDelta(minutes=-90).humanize(round_mode=\"half_even\")
+'2 hours ago'
+>>> TimeDelta(seconds=20).humanize(granularity=\"minutes\")
+'now'
+>>> TimeDelta(minutes=5).humanize(locale=\"de\")
+'in 5 Minuten'
+";
 pub(crate) const TIMEDELTA_IN_DAYS_OF_24H: &CStr = c"\
 The total size in days (of exactly 24 hours each)
 
@@ -2256,11 +3131,70 @@ relative_to
     - :class:`OffsetDateTime`: does not account for DST changes; emits
       :class:`StaleOffsetWarning`
 ";
+pub(crate) const TIMEDELTA_PARSE: &CStr = c"\
+parse(s, /, *, format, lenient=False, style='pattern')
+--
+
+Parse a delta from a custom pattern string.
+
+Inverse of :meth:`format`.
+See :ref:`delta-patterns` for details.
+
+>>> TimeDelta.parse(\"50:15:09.250\", format=\"hh:mm:ss.fff\")
+TimeDelta(\"PT50h15m9.25s\")
+>>> TimeDelta.parse(\"-1:30\", format=\"nh:mm\")
+TimeDelta(\"-PT1h30m\")
+>>> TimeDelta.parse(\"-26:00\", format=\"[h]:mm\", style=\"spreadsheet\")
+TimeDelta(\"-PT26h\")
+";
+pub(crate) const TIMEDELTA_PARSE_GO: &CStr = c"\
+Parse the duration syntax of Go's ``time.ParseDuration``.
+
+This is a sequence of decimal numbers, each with a unit
+(``ns``, ``us``/``µs``, ``ms``, ``s``, ``m``, or ``h``),
+optionally preceded by a sign. Units may not be separated by spaces.
+Fractions smaller than a nanosecond are truncated.
+
+Inverse of :meth:`format_go`.
+
+>>> TimeDelta.parse_go(\"1h30m\")
+TimeDelta(\"PT1h30m\")
+>>> TimeDelta.parse_go(\"-2.5s\")
+TimeDelta(\"-PT2.5s\")
+>>> TimeDelta.parse_go(\"250ms\")
+TimeDelta(\"PT0.25s\")
+";
+pub(crate) const TIMEDELTA_PARSE_HUMAN: &CStr = c"\
+parse_human(s, /, *, days_assumed_24h_ok=False)
+--
+
+Parse a lenient, English duration string such as
+``\"1 day 3 hours\"`` or ``\"5 min, 30.5 sec\"``.
+
+Units may be written in full or abbreviated
+(e.g. ``hours``, ``hour``, ``hrs``, ``hr``, ``h``), in any case.
+Components may be separated by whitespace, commas, or ``and``.
+The Go-style syntax accepted by :meth:`parse_go` is accepted too.
+
+Since days and weeks are taken to be 24 hours and 7 days,
+a :class:`DaysAssumed24HoursWarning` is emitted if they're used,
+unless ``days_assumed_24h_ok=True``.
+
+>>> TimeDelta.parse_human(\"1 hour and 30 minutes\")
+TimeDelta(\"PT1h30m\")
+>>> TimeDelta.parse_human(\"2 Days, 3 hrs\", days_assumed_24h_ok=True)
+TimeDelta(\"PT51h\")
+>>> TimeDelta.parse_human(\"-1m30s\")
+TimeDelta(\"-PT1m30s\")
+";
 pub(crate) const TIMEDELTA_PARSE_ISO: &CStr = c"\
 Parse the *popular interpretation* of the ISO 8601 duration format.
 Does not parse all possible ISO 8601 durations.
 See :ref:`here <iso8601-durations>` for more information.
 
+The :ref:`alternative format <iso8601-alternative-durations>`
+(e.g. ``PT01:30:00``) is also accepted.
+
 Inverse of :meth:`format_iso`
 
 >>> TimeDelta.parse_iso(\"PT1H80M\")
@@ -2303,6 +3237,20 @@ Subtract time from this delta, returning a new delta.
 
 Days and weeks are treated as exact 24-hour and 168-hour units,
 which emits a :class:`~whenever.DaysAssumed24HoursWarning`.";
+pub(crate) const TIMEDELTA_TO_CBOR: &CStr = c"\
+Encode as a CBOR item with tag 1002: a duration (RFC 9581),
+as a map of seconds and nanoseconds.
+
+>>> TimeDelta(seconds=-1.5).to_cbor().hex()
+'d903eaa20121281a1dcd6500'
+";
+pub(crate) const TIMEDELTA_TO_MSGPACK: &CStr = c"\
+Encode in the format of the MessagePack timestamp extension
+(type -1), counting from zero instead of the UNIX epoch.
+
+>>> TimeDelta(minutes=1, seconds=30).to_msgpack().hex()
+'d6ff0000005a'
+";
 pub(crate) const TIMEDELTA_TO_STDLIB: &CStr = c"\
 Convert to a :class:`~datetime.timedelta`
 
@@ -2388,6 +3336,9 @@ ZonedDateTime(\"2024-08-15 23:59:59.999999999-04:00[America/New_York]\")
 See also :meth:`start_of`
 ";
 pub(crate) const ZONEDDATETIME_FORMAT: &CStr = c"\
+format($self, pattern, /, *, locale=None, style='pattern')
+--
+
 Format as a custom pattern string.
 
 See :ref:`pattern-format` for details.
@@ -2432,6 +3383,21 @@ The timezone ID is a recent extension to the ISO 8601 format (RFC 9557).
 Although it is gaining popularity, it is not yet widely supported
 by ISO 8601 parsers.
 ";
+pub(crate) const ZONEDDATETIME_FORMAT_RFC2822: &CStr = c"\
+format_rfc2822($self, *, zone_comment=False)
+--
+
+Format as an RFC 2822 string, with a numeric offset.
+
+With ``zone_comment=True``, the timezone abbreviation
+is added as a comment, as is common in email headers.
+
+>>> zdt = ZonedDateTime(2020, 8, 15, hour=23, tz=\"Europe/Paris\")
+>>> zdt.format_rfc2822()
+'Sat, 15 Aug 2020 23:00:00 +0200'
+>>> zdt.format_rfc2822(zone_comment=True)
+'Sat, 15 Aug 2020 23:00:00 +0200 (CEST)'
+";
 pub(crate) const ZONEDDATETIME_FROM_SYSTEM_TZ: &CStr = c"\
 from_system_tz(year, month, day, hour=0, minute=0, second=0, *, nanosecond=0, disambiguate='compatible')
 --
@@ -2469,6 +3435,30 @@ Create an instance from a UNIX timestamp (in nanoseconds).
 
 The inverse of the ``timestamp_nanos()`` method.
 ";
+pub(crate) const ZONEDDATETIME_HUMANIZE: &CStr = c"\
+humanize($self, *, relative_to=..., granularity='seconds', round_mode='trunc', locale=None, relative_day=False)
+--
+
+Describe this datetime relative to another (by default, now),
+such as \"in 3 hours\" or \"2 months ago\"
+
+This works like :meth:`TimeDelta.humanize`, except that calendar
+units (days, weeks, months, and years) are also available.
+These are measured in the timezone of ``relative_to``,
+so days aren't necessarily 24 hours.
+
+With ``relative_day=True``, a datetime on the day before or after
+``relative_to`` is described by that day and its time of day,
+such as \"yesterday at 14:05\".
+
+>>> d = ZonedDateTime(2024, 3, 9, 12, tz=\"America/New_York\")
+>>> d.add(days=1).humanize(relative_to=d)  # only 23 hours due to DST
+'in 1 day'
+>>> d.humanize(relative_to=d.add(months=2, days=20))
+'2 months ago'
+>>> d.add(hours=-20).humanize(relative_to=d, relative_day=True)
+'yesterday at 16:00'
+";
 pub(crate) const ZONEDDATETIME_IS_AMBIGUOUS: &CStr = c"\
 Whether the date and time-of-day are ambiguous, e.g. due to a DST transition.
 
@@ -2494,8 +3484,25 @@ Create an instance from the current time in the system timezone.
 
 Equivalent to ``Instant.now().to_system_tz()``.
 ";
+pub(crate) const ZONEDDATETIME_OFFSET_PERIOD: &CStr = c"\
+Information about the span of time during which the
+current UTC offset and abbreviation apply.
+
+>>> d = ZonedDateTime(2024, 7, 1, tz=\"Europe/Amsterdam\")
+>>> p = d.offset_period()
+>>> p.start
+Instant(\"2024-03-31 01:00:00Z\")
+>>> p.end
+Instant(\"2024-10-27 01:00:00Z\")
+>>> p.is_dst, p.std_offset, p.abbrev
+(True, TimeDelta(\"PT1h\"), 'CEST')
+
+Unlike :meth:`next_transition` and :meth:`prev_transition`,
+transitions which change neither the offset nor the abbreviation
+are skipped. The bounds are ``None`` if there is no such transition.
+";
 pub(crate) const ZONEDDATETIME_PARSE: &CStr = c"\
-parse(s, /, *, format, disambiguate='compatible')
+parse(s, /, *, format, locale=None, disambiguate='compatible', style='pattern', lenient=False, pivot_year=None)
 --
 
 Parse a zoned datetime from a custom pattern string.
@@ -2867,6 +3874,7 @@ pub(crate) const CANNOT_ROUND_DAY_MSG: &CStr = c"Cannot round to day, because da
 pub(crate) const DAYS_NOT_ALWAYS_24H_MSG: &CStr = c"You are using days or weeks as exact time, so Whenever will treat each day as exactly 24 hours. A calendar day can be 23 or 25 hours during a DST transition, so this may differ from calendar arithmetic. If you mean calendar days, perform the operation on a ZonedDateTime or pass `relative_to=...` where supported. If fixed 24-hour periods are intentional, pass `days_assumed_24h_ok=True`. For project-wide warning configuration, see https://whenever.readthedocs.io/en/latest/guide/warnings.html";
 pub(crate) const FORMAT_ISO_NO_TZ_MSG: &CStr = c"This ZonedDateTime has no timezone ID and cannot be formatted in the standard ISO format, which requires it. This typically means the ZonedDateTime was created from a system timezone with an unknown ID. To format without the timezone designator, set the `tz=` argument to 'never' or 'auto'.";
 pub(crate) const IGNORE_DST_DEPRECATED_MSG: &CStr = c"`ignore_dst` is deprecated and will be removed in a future release. For OffsetDateTime operations, use `stale_offset_ok`; for PlainDateTime operations, use `naive_arithmetic_ok`.";
+pub(crate) const MISSING_DATE_FIELDS: &CStr = c"Pattern must include date fields: year (YYYY/YY), month (MM/MMM/MMMM), and day (DD); year and day of year (DDD); or ISO week-based year (GGGG), week (WW/W), and weekday (EEE/EEEE)";
 pub(crate) const OFFSET_FROM_TIMESTAMP_STALE_MSG: &CStr = c"You are converting a timestamp using a fixed UTC offset. The result is correct for that offset, but the offset may be stale at this timestamp—no longer matching the actual offset used by the region you intend. If you mean a named timezone, use ZonedDateTime.from_timestamp(ts, tz='<tz>'); if you only need the instant, use Instant.from_timestamp(ts). If the fixed offset is intentional, pass `stale_offset_ok=True`. For project-wide warning configuration, see https://whenever.readthedocs.io/en/latest/guide/warnings.html";
 pub(crate) const OFFSET_NOW_STALE_MSG: &CStr = c"You are getting the current time using a fixed UTC offset. A fixed offset has no timezone rules, so it may be stale for the region you intend—no longer matching that region's actual offset after a DST or other rule change. If you mean a named timezone, use ZonedDateTime.now('<tz>'); if you only need the current instant, use Instant.now(). If the fixed offset is intentional, pass `stale_offset_ok=True`. For project-wide warning configuration, see https://whenever.readthedocs.io/en/latest/guide/warnings.html";
 pub(crate) const OFFSET_REPLACE_STALE_MSG: &CStr = c"Replacing fields of an OffsetDateTime keeps its fixed UTC offset. The offset may become stale—no longer matching the region's actual offset—if the result is in a different DST or timezone-rule period (e.g. after replacing the month on a European-timezone datetime). Convert to ZonedDateTime first (using .assume_tz()) for timezone-aware field replacement. If the fixed offset is intentional, pass `stale_offset_ok=True`. For project-wide warning configuration, see https://whenever.readthedocs.io/en/latest/guide/warnings.html";
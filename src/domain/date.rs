@@ -1,12 +1,15 @@
 use super::{
     plain_datetime::PlainDateTime,
-    scalar::{DeltaDays, DeltaMonths, EpochSecs, Month, UnixDays, Weekday, Year},
+    scalar::{
+        DAYS_BEFORE_MONTH, DeltaDays, DeltaMonths, EpochSecs, ExpandedYear, Month, UnixDays,
+        Weekday, Year, civil_from_days, days_from_civil, days_in_month,
+    },
     shift::CalendarShift,
     time::Time,
 };
 use crate::common::{
     fmt::{self, Chunk},
-    parse::{extract_2_digits, extract_digit},
+    parse::{Scan, extract_digit},
 };
 use std::fmt::{Display, Formatter};
 
@@ -60,6 +63,26 @@ impl Date {
         }
     }
 
+    /// The date with the given ordinal day (1-based) within the year.
+    pub(crate) fn from_ordinal(year: Year, day_of_year: u16) -> Option<Self> {
+        let days_in_year = if year.is_leap() { 366 } else { 365 };
+        (1..=days_in_year).contains(&day_of_year).then(|| {
+            UnixDays::new_unchecked(year.unix_days_at_jan1().get() + day_of_year as i32 - 1).date()
+        })
+    }
+
+    /// The date for the given ISO 8601 week date.
+    /// Returns `None` if the week doesn't exist in that week-based year,
+    /// or the date is out of range.
+    pub(crate) fn from_iso_week_date(iso_year: Year, week: u8, weekday: Weekday) -> Option<Self> {
+        // January 4th is always in week 1
+        let jan4 = iso_year.unix_days_at_jan1().get() + 3;
+        let week1_monday = jan4 - UnixDays::new_unchecked(jan4).day_of_week().iso() as i32 + 1;
+        let date =
+            UnixDays::new(week1_monday + (week as i32 - 1) * 7 + weekday.iso() as i32 - 1)?.date();
+        (date.iso_year_week() == (iso_year.get() as i32, week)).then_some(date)
+    }
+
     /// Find the nth weekday in a month.
     pub(crate) fn nth_weekday_in_month(
         year: Year,
@@ -131,34 +154,47 @@ impl Date {
         self.shift_days(DeltaDays::new(days_fwd).unwrap())
     }
 
-    /// Parse YYYY-MM-DD.
-    pub(crate) fn parse_iso_extended(s: [u8; 10]) -> Option<Self> {
-        (s[4] == b'-' && s[7] == b'-')
-            .then(|| {
-                Date::new(
-                    extract_year(&s, 0)?,
-                    extract_2_digits(&s, 5).and_then(Month::new)?,
-                    extract_2_digits(&s, 8)?,
-                )
-            })
-            .flatten()
-    }
-
-    /// Parse YYYYMMDD.
-    pub(crate) fn parse_iso_basic(s: [u8; 8]) -> Option<Self> {
-        Date::new(
-            extract_year(&s, 0)?,
-            extract_2_digits(&s, 4).and_then(Month::new)?,
-            extract_2_digits(&s, 6)?,
-        )
+    /// Read an ISO 8601 calendar date (YYYY-MM-DD), ordinal date (YYYY-DDD),
+    /// or week date (YYYY-Www-D), in extended or basic format.
+    pub(crate) fn read_iso(s: &mut Scan) -> Option<Self> {
+        let year = s.read("year", |s| extract_year(s.take(4)?, 0))?;
+        let extended = s.advance_on(b'-') == Some(true);
+        let sep = |s: &mut Scan| {
+            if extended {
+                s.expect(b'-').or_else(|| s.fail("-"))
+            } else {
+                Some(())
+            }
+        };
+        if s.advance_on(b'W') == Some(true) {
+            let pos = s.pos();
+            let week = s.read("week", |s| s.digits(2))? as u8;
+            sep(s)?;
+            let weekday = s.read("weekday", |s| {
+                // SAFETY: the range is checked
+                s.digit_ranged(b'1'..=b'7')
+                    .map(|d| unsafe { Weekday::from_iso_unchecked(d) })
+            })?;
+            Self::from_iso_week_date(year, week, weekday).or_else(|| s.fail_at(pos, "week"))
+        } else if s.rest().len() >= 3
+            && s.rest()[..3].iter().all(u8::is_ascii_digit)
+            && !s.get(3).is_some_and(|c| c.is_ascii_digit())
+        {
+            let pos = s.pos();
+            let day_of_year = s.digits(3)? as u16;
+            Self::from_ordinal(year, day_of_year).or_else(|| s.fail_at(pos, "day of year"))
+        } else {
+            let month = s.read("month", |s| Month::new(s.digits(2)? as u8))?;
+            sep(s)?;
+            let pos = s.pos();
+            let day = s.read("day", |s| s.digits(2))? as u8;
+            Self::new(year, month, day).or_else(|| s.fail_at(pos, "day"))
+        }
     }
 
+    #[cfg(test)]
     pub(crate) fn parse_iso(s: &[u8]) -> Option<Self> {
-        match s.len() {
-            8 => Self::parse_iso_basic(s.try_into().unwrap()),
-            10 => Self::parse_iso_extended(s.try_into().unwrap()),
-            _ => None,
-        }
+        Scan::new(s).parse_all(Self::read_iso)
     }
 
     pub(crate) fn iso_format(self, basic: bool) -> IsoFormat {
@@ -356,6 +392,204 @@ pub(crate) fn extract_year(s: &[u8], index: usize) -> Option<Year> {
     .map(|year| unsafe { Year::new_unchecked(year) })
 }
 
+/// A date with a year in the ISO 8601 expanded range.
+/// Since the range is too wide for [`UnixDays`], calendar arithmetic
+/// is done on plain day counts.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct ExpandedDate {
+    pub(crate) year: ExpandedYear,
+    pub(crate) month: Month,
+    pub(crate) day: u8,
+}
+
+impl ExpandedDate {
+    pub(crate) const MIN: ExpandedDate = ExpandedDate {
+        year: ExpandedYear::MIN,
+        month: Month::January,
+        day: 1,
+    };
+    pub(crate) const MAX: ExpandedDate = ExpandedDate {
+        year: ExpandedYear::MAX,
+        month: Month::December,
+        day: 31,
+    };
+    const MIN_DAYS: i64 = Self::MIN.unix_days();
+    const MAX_DAYS: i64 = Self::MAX.unix_days();
+
+    pub(crate) fn new(year: ExpandedYear, month: Month, day: u8) -> Option<Self> {
+        (day >= 1 && day <= year.days_in_month(month)).then_some(Self { year, month, day })
+    }
+
+    pub(crate) fn to_date(self) -> Option<Date> {
+        Some(Date {
+            year: Year::from_i32(self.year.get())?,
+            month: self.month,
+            day: self.day,
+        })
+    }
+
+    /// Number of days since 1970-01-01
+    pub(crate) const fn unix_days(self) -> i64 {
+        days_from_civil(self.year.get(), self.month, self.day)
+    }
+
+    pub(crate) fn from_unix_days(days: i64) -> Option<Self> {
+        (Self::MIN_DAYS..=Self::MAX_DAYS).contains(&days).then(|| {
+            let (year, month, day) = civil_from_days(days);
+            Self {
+                year: ExpandedYear::new_unchecked(year),
+                month,
+                day,
+            }
+        })
+    }
+
+    /// Shift by the given number of months, clamping the day to the end
+    /// of the month if needed. The result is returned as a day count, since
+    /// it may be (temporarily) out of range before days are added.
+    pub(crate) fn shift_months_to_days(self, months: i64) -> Option<i64> {
+        let months0 = (self.month as i64 - 1).checked_add(months)?;
+        let year =
+            i32::try_from((self.year.get() as i64).checked_add(months0.div_euclid(12))?).ok()?;
+        // SAFETY: adding one to a remainder modulo twelve produces 1..=12.
+        let month = unsafe { Month::new_unchecked(months0.rem_euclid(12) as u8 + 1) };
+        Some(days_from_civil(
+            year,
+            month,
+            self.day.min(days_in_month(year, month)),
+        ))
+    }
+
+    /// Shift by months (clamping the day if needed) and then by days
+    pub(crate) fn shift(self, months: i64, days: i64) -> Option<Self> {
+        Self::from_unix_days(self.shift_months_to_days(months)?.checked_add(days)?)
+    }
+
+    pub(crate) fn day_of_week(self) -> Weekday {
+        // SAFETY: adding one to a remainder modulo seven produces 1..=7.
+        unsafe { Weekday::from_iso_unchecked(((self.unix_days() + 3).rem_euclid(7) + 1) as _) }
+    }
+
+    pub(crate) fn day_of_year(self) -> u16 {
+        DAYS_BEFORE_MONTH[self.year.is_leap() as usize][self.month as usize] + self.day as u16
+    }
+
+    pub(crate) fn days_in_month(self) -> u8 {
+        self.year.days_in_month(self.month)
+    }
+
+    pub(crate) fn days_in_year(self) -> u16 {
+        if self.year.is_leap() { 366 } else { 365 }
+    }
+
+    pub(crate) fn is_in_leap_year(self) -> bool {
+        self.year.is_leap()
+    }
+
+    /// Read an ISO 8601 calendar date, in extended or basic format.
+    /// Without a sign, the year has exactly four digits. With a sign,
+    /// it has four to six digits, and negative zero isn't allowed.
+    pub(crate) fn read_iso(s: &mut Scan) -> Option<Self> {
+        let negative = match s.peek() {
+            Some(b'+') => Some(false),
+            Some(b'-') => Some(true),
+            _ => None,
+        };
+        if negative.is_some() {
+            s.skip(1);
+        }
+        let num_digits = s.rest().iter().take_while(|c| c.is_ascii_digit()).count();
+        let extended = s.get(num_digits) == Some(b'-');
+        // In the basic format, the month and day digits directly follow the year
+        let year_len = if extended {
+            num_digits
+        } else {
+            num_digits.saturating_sub(4)
+        };
+        let year = s.read("year", |s| {
+            if !(year_len == 4 || negative.is_some() && (4..=6).contains(&year_len)) {
+                return None;
+            }
+            let n = s.digits(year_len)? as i32;
+            match negative {
+                Some(true) if n == 0 => None,
+                Some(true) => Some(-n),
+                _ => Some(n),
+            }
+        })?;
+        // SAFETY: six digits are always within the expanded range
+        let year = ExpandedYear::new_unchecked(year);
+        let sep = |s: &mut Scan| {
+            if extended {
+                s.expect(b'-').or_else(|| s.fail("-"))
+            } else {
+                Some(())
+            }
+        };
+        sep(s)?;
+        let month = s.read("month", |s| Month::new(s.digits(2)? as u8))?;
+        sep(s)?;
+        let pos = s.pos();
+        let day = s.read("day", |s| s.digits(2))? as u8;
+        Self::new(year, month, day).or_else(|| s.fail_at(pos, "day"))
+    }
+
+    pub(crate) fn iso_format(self, basic: bool) -> ExpandedIsoFormat {
+        ExpandedIsoFormat { date: self, basic }
+    }
+}
+
+impl From<Date> for ExpandedDate {
+    fn from(d: Date) -> Self {
+        Self {
+            year: d.year.into(),
+            month: d.month,
+            day: d.day,
+        }
+    }
+}
+
+/// Years 0-9999 use the regular four-digit form,
+/// other years the expanded form with a sign and six digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ExpandedIsoFormat {
+    date: ExpandedDate,
+    basic: bool,
+}
+
+impl ExpandedIsoFormat {
+    fn has_short_year(&self) -> bool {
+        (0..=9999).contains(&self.date.year.get())
+    }
+}
+
+impl fmt::Chunk for ExpandedIsoFormat {
+    fn len(&self) -> usize {
+        (if self.has_short_year() { 4 } else { 7 }) + if self.basic { 4 } else { 6 }
+    }
+
+    fn write(&self, buf: &mut impl fmt::Sink) {
+        let ExpandedDate { year, month, day } = self.date;
+        let y = year.get();
+        if self.has_short_year() {
+            buf.write(fmt::format_4_digits(y as u16).as_ref());
+        } else {
+            buf.write(if y < 0 { b"-" } else { b"+" });
+            let abs = y.unsigned_abs();
+            buf.write(fmt::format_2_digits((abs / 10_000) as u8).as_ref());
+            buf.write(fmt::format_4_digits((abs % 10_000) as u16).as_ref());
+        }
+        if self.basic {
+            buf.write(fmt::format_2_digits(month.get()).as_ref());
+        } else {
+            buf.write(b"-");
+            buf.write(fmt::format_2_digits(month.get()).as_ref());
+            buf.write(b"-");
+        }
+        buf.write(fmt::format_2_digits(day).as_ref());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -378,4 +612,62 @@ mod tests {
         assert_eq!(common.days_in_year(), 365);
         assert!(!common.is_in_leap_year());
     }
+
+    #[test]
+    fn from_ordinal() {
+        let y2024 = Year::new(2024).unwrap();
+        assert_eq!(Date::from_ordinal(y2024, 1), Some(date(2024, 1, 1)));
+        assert_eq!(Date::from_ordinal(y2024, 60), Some(date(2024, 2, 29)));
+        assert_eq!(Date::from_ordinal(y2024, 366), Some(date(2024, 12, 31)));
+        assert_eq!(Date::from_ordinal(y2024, 0), None);
+        assert_eq!(Date::from_ordinal(y2024, 367), None);
+        assert_eq!(Date::from_ordinal(Year::new(2023).unwrap(), 366), None);
+    }
+
+    #[test]
+    fn from_iso_week_date() {
+        let week_date = |y, w, d| {
+            // SAFETY: weekdays below are all in 1..=7
+            let weekday = unsafe { Weekday::from_iso_unchecked(d) };
+            Date::from_iso_week_date(Year::new(y).unwrap(), w, weekday)
+        };
+        assert_eq!(week_date(2025, 1, 1), Some(date(2024, 12, 30)));
+        assert_eq!(week_date(2020, 53, 7), Some(date(2021, 1, 3)));
+        assert_eq!(week_date(2024, 9, 4), Some(date(2024, 2, 29)));
+        assert_eq!(week_date(1, 1, 1), Some(date(1, 1, 1)));
+        assert_eq!(week_date(9999, 52, 5), Some(date(9999, 12, 31)));
+        assert_eq!(week_date(9999, 52, 6), None);
+        assert_eq!(week_date(2021, 53, 1), None);
+        assert_eq!(week_date(2021, 0, 1), None);
+        for d in [date(2019, 12, 30), date(2021, 1, 3), date(2024, 6, 15)] {
+            let (y, w) = d.iso_year_week();
+            assert_eq!(week_date(y as u16, w, d.day_of_week().iso()), Some(d));
+        }
+    }
+
+    #[test]
+    fn parse_iso() {
+        let parse = |s: &str| Date::parse_iso(s.as_bytes());
+        assert_eq!(parse("2025-02-14"), Some(date(2025, 2, 14)));
+        assert_eq!(parse("20250214"), Some(date(2025, 2, 14)));
+        // ordinal dates
+        assert_eq!(parse("2025-045"), Some(date(2025, 2, 14)));
+        assert_eq!(parse("2025045"), Some(date(2025, 2, 14)));
+        assert_eq!(parse("2024-366"), Some(date(2024, 12, 31)));
+        assert_eq!(parse("2025-366"), None);
+        assert_eq!(parse("2025-000"), None);
+        assert_eq!(parse("2025-45"), None);
+        // week dates
+        assert_eq!(parse("2025-W07-5"), Some(date(2025, 2, 14)));
+        assert_eq!(parse("2025W075"), Some(date(2025, 2, 14)));
+        assert_eq!(parse("2025-W01-1"), Some(date(2024, 12, 30)));
+        assert_eq!(parse("2020-W53-7"), Some(date(2021, 1, 3)));
+        assert_eq!(parse("2021-W53-1"), None);
+        assert_eq!(parse("2025-W07-0"), None);
+        assert_eq!(parse("2025-W07-8"), None);
+        assert_eq!(parse("2025-w07-5"), None);
+        assert_eq!(parse("2025-W075"), None);
+        assert_eq!(parse("2025W07-5"), None);
+        assert_eq!(parse("9999-W52-6"), None);
+    }
 }
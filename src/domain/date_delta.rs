@@ -1,5 +1,5 @@
 use super::scalar::{DeltaDays, DeltaMonths};
-use crate::domain::difference::CalendarUnit;
+use crate::{common::parse::Scan, domain::difference::CalendarUnit};
 use std::{fmt, ops::Neg};
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -82,27 +82,49 @@ impl DateDelta {
         result
     }
 
-    pub(crate) fn parse_iso(mut s: &[u8]) -> Option<Self> {
-        if s.len() < 3 {
-            return None;
+    /// Format in the ISO 8601 alternative format, e.g. `P0001-02-03`.
+    /// Returns `None` if the days exceed the maximum of 30.
+    pub(crate) fn fmt_iso_alternative(self, basic: bool) -> Option<String> {
+        let mut result = String::with_capacity(12);
+        let absolute = if self.months.get() < 0 || self.days.get() < 0 {
+            result.push('-');
+            -self
+        } else {
+            self
+        };
+        result.push('P');
+        format_alternative(absolute, basic, &mut result)?;
+        Some(result)
+    }
+
+    pub(crate) fn read_iso(s: &mut Scan) -> Option<Self> {
+        let negative = read_prefix(s)?;
+        if s.rest().last().is_some_and(u8::is_ascii_digit) {
+            let (delta, _) = read_alternative(s)?;
+            return Some(if negative { -delta } else { delta });
         }
-        let negative = parse_prefix(&mut s)?;
         let mut months = 0;
         let mut days = 0;
         let mut previous = None;
-        while !s.is_empty() {
-            let (value, unit) = parse_component(&mut s)?;
+        loop {
+            let (value, unit) = read_component(s)?;
             match (unit, previous.replace(unit)) {
                 (CalendarUnit::Years, None) => months += value * 12,
                 (CalendarUnit::Months, None | Some(CalendarUnit::Years)) => months += value,
                 (CalendarUnit::Weeks, None | Some(CalendarUnit::Years | CalendarUnit::Months)) => {
                     days += value * 7
                 }
-                (CalendarUnit::Days, _) if s.is_empty() => days += value,
-                _ => return None,
+                // Days are always the last component
+                (CalendarUnit::Days, _) => {
+                    days += value;
+                    break;
+                }
+                _ => return s.fail_at(s.pos() - 1, "unit"),
+            }
+            if s.is_done() {
+                break;
             }
         }
-        previous?;
         if negative {
             months = -months;
             days = -days;
@@ -158,53 +180,85 @@ pub(crate) fn format_components(delta: DateDelta, result: &mut String) {
     }
 }
 
-pub(crate) fn parse_prefix(s: &mut &[u8]) -> Option<bool> {
-    debug_assert!(s.len() >= 2);
-    match s[0] {
-        b'P' | b'p' => {
-            *s = &s[1..];
-            Some(false)
-        }
-        b'-' if s[1].eq_ignore_ascii_case(&b'P') => {
-            *s = &s[2..];
-            Some(true)
-        }
-        b'+' if s[1].eq_ignore_ascii_case(&b'P') => {
-            *s = &s[2..];
-            Some(false)
-        }
-        _ => None,
+/// The largest number of days allowed in the ISO 8601 alternative format,
+/// i.e. its "carry-over point"
+const ALTERNATIVE_MAX_DAYS: i32 = 30;
+
+/// Format the date part of the ISO 8601 alternative format, e.g. `0001-02-03`
+pub(crate) fn format_alternative(delta: DateDelta, basic: bool, result: &mut String) -> Option<()> {
+    let months = delta.months.get();
+    let days = delta.days.get();
+    debug_assert!(months >= 0 && days >= 0);
+    if days > ALTERNATIVE_MAX_DAYS {
+        return None;
     }
+    let sep = if basic { "" } else { "-" };
+    result.push_str(&format!(
+        "{:04}{sep}{:02}{sep}{days:02}",
+        months / 12,
+        months % 12
+    ));
+    Some(())
 }
 
-fn finish_parsing_component(s: &mut &[u8], mut value: i32) -> Option<(i32, CalendarUnit)> {
-    for i in 1..s.len().min(9) {
-        match s[i] {
-            c if c.is_ascii_digit() => value = value * 10 + i32::from(c - b'0'),
-            b'D' | b'd' => {
-                *s = &s[i + 1..];
-                return Some((value, CalendarUnit::Days));
-            }
-            b'W' | b'w' => {
-                *s = &s[i + 1..];
-                return Some((value, CalendarUnit::Weeks));
-            }
-            b'M' | b'm' => {
-                *s = &s[i + 1..];
-                return Some((value, CalendarUnit::Months));
-            }
-            b'Y' | b'y' => {
-                *s = &s[i + 1..];
-                return Some((value, CalendarUnit::Years));
-            }
-            _ => return None,
-        }
+/// Read the date part of the ISO 8601 alternative format,
+/// e.g. `0001-02-03` or `00010203`. Also returns whether it's in basic format.
+pub(crate) fn read_alternative(s: &mut Scan) -> Option<(DateDelta, bool)> {
+    let years = s.read("year", |s| s.digits(4))? as i32;
+    let basic = s.advance_on(b'-') != Some(true);
+    let pos = s.pos();
+    let months = s.read("month", |s| s.digits(2))? as i32;
+    if months > 12 {
+        return s.fail_at(pos, "month");
+    }
+    if !basic {
+        s.expect(b'-').or_else(|| s.fail("-"))?;
     }
-    None
+    let pos = s.pos();
+    let days = s.read("day", |s| s.digits(2))? as i32;
+    if days > ALTERNATIVE_MAX_DAYS {
+        return s.fail_at(pos, "day");
+    }
+    Some((
+        DateDelta {
+            months: DeltaMonths::new(years * 12 + months)?,
+            days: DeltaDays::new(days)?,
+        },
+        basic,
+    ))
 }
 
-pub(crate) fn parse_component(s: &mut &[u8]) -> Option<(i32, CalendarUnit)> {
-    (s.len() >= 2 && s[0].is_ascii_digit())
-        .then(|| finish_parsing_component(s, (s[0] - b'0').into()))
-        .flatten()
+/// Read the sign (if any) and the `P` prefix. Returns whether it's negative.
+pub(crate) fn read_prefix(s: &mut Scan) -> Option<bool> {
+    let negative = match s.peek() {
+        Some(b'-') => {
+            s.skip(1);
+            true
+        }
+        Some(b'+') => {
+            s.skip(1);
+            false
+        }
+        _ => false,
+    };
+    s.expect_ignore_case(b'P').or_else(|| s.fail("P"))?;
+    Some(negative)
+}
+
+/// Read a component like `4D`
+pub(crate) fn read_component(s: &mut Scan) -> Option<(i32, CalendarUnit)> {
+    let digits = s.rest().iter().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 || digits > 8 {
+        return s.fail("number");
+    }
+    let value = s.digits(digits)? as i32;
+    let unit = match s.peek() {
+        Some(b'D' | b'd') => CalendarUnit::Days,
+        Some(b'W' | b'w') => CalendarUnit::Weeks,
+        Some(b'M' | b'm') => CalendarUnit::Months,
+        Some(b'Y' | b'y') => CalendarUnit::Years,
+        _ => return s.fail("unit"),
+    };
+    s.skip(1);
+    Some((value, unit))
 }
@@ -1,9 +1,9 @@
 use super::{
-    date_delta::{DateDelta, InitError, format_components, parse_component, parse_prefix},
+    date_delta::{self, DateDelta, InitError, format_components, read_component},
     scalar::{DeltaDays, DeltaMonths},
-    time_delta::{TimeDelta, fmt_components_abs, parse_all_components},
+    time_delta::{self, TimeDelta, fmt_components_abs},
 };
-use crate::domain::difference::CalendarUnit;
+use crate::{common::parse::Scan, domain::difference::CalendarUnit};
 use std::{fmt, ops::Neg};
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -70,24 +70,57 @@ impl DateTimeDelta {
         result
     }
 
-    pub(crate) fn parse_iso(mut s: &[u8]) -> Option<Self> {
-        if s.len() < 3 {
-            return None;
-        }
-        let negative = parse_prefix(&mut s)?;
-        if s.last()?.eq_ignore_ascii_case(&b'T') {
-            return None;
-        }
-        let mut date = parse_date_components(&mut s)?;
-        let mut time = if s.is_empty() {
-            TimeDelta::ZERO
-        } else if s[0].eq_ignore_ascii_case(&b'T') {
-            s = &s[1..];
-            let (nanos, _) = parse_all_components(&mut s)?;
-            TimeDelta::from_nanos(nanos as _)?
+    /// Format in the ISO 8601 alternative format, e.g. `P0001-02-03T04:05:06`.
+    /// Returns `None` if the days or time exceed their maximum (30 days, 24 hours).
+    pub(crate) fn fmt_iso_alternative(self, basic: bool) -> Option<String> {
+        let mut result = String::with_capacity(20);
+        let Self { date, time } =
+            if self.time.secs.get() < 0 || self.date.months.get() < 0 || self.date.days.get() < 0 {
+                result.push('-');
+                -self
+            } else {
+                self
+            };
+        result.push('P');
+        date_delta::format_alternative(date, basic, &mut result)?;
+        result.push('T');
+        time_delta::fmt_alternative_abs(time, basic, &mut result)?;
+        Some(result)
+    }
+
+    /// Read the ISO 8601 alternative format, e.g. `P0001-02-03T04:05:06`.
+    /// Either the date or the time part may be omitted.
+    pub(crate) fn read_iso_alternative(s: &mut Scan) -> Option<Self> {
+        let negative = date_delta::read_prefix(s)?;
+        let (date, date_basic) = if s.peek().is_some_and(|c| c.eq_ignore_ascii_case(&b'T')) {
+            (DateDelta::ZERO, None)
         } else {
-            return None;
+            let (date, basic) = date_delta::read_alternative(s)?;
+            (date, Some(basic))
         };
+        let mut time = TimeDelta::ZERO;
+        if !s.is_done() {
+            s.expect_ignore_case(b'T').or_else(|| s.fail("T"))?;
+            // Basic and extended format can't be mixed
+            (time, _) = time_delta::read_alternative(s, date_basic)?;
+        }
+        let delta = Self { date, time };
+        Some(if negative { -delta } else { delta })
+    }
+
+    pub(crate) fn read_iso(s: &mut Scan) -> Option<Self> {
+        if s.rest().last().is_some_and(u8::is_ascii_digit) {
+            return Self::read_iso_alternative(s);
+        }
+        let negative = date_delta::read_prefix(s)?;
+        let (mut date, has_date) = read_date_components(s)?;
+        let mut time = TimeDelta::ZERO;
+        if !s.is_done() {
+            s.expect_ignore_case(b'T').or_else(|| s.fail("T"))?;
+            time = TimeDelta::from_nanos(time_delta::read_all_components(s)? as _)?;
+        } else if !has_date {
+            return s.fail("number");
+        }
         if negative {
             date = -date;
             time = -time;
@@ -120,12 +153,14 @@ impl fmt::Display for DateTimeDelta {
     }
 }
 
-fn parse_date_components(s: &mut &[u8]) -> Option<DateDelta> {
+/// Read the components of the date part, if any.
+/// Also returns whether there were any.
+fn read_date_components(s: &mut Scan) -> Option<(DateDelta, bool)> {
     let mut months = 0;
     let mut days = 0;
     let mut previous = None;
-    while !s.is_empty() && !s[0].eq_ignore_ascii_case(&b'T') {
-        let (value, unit) = parse_component(s)?;
+    while s.peek().is_some_and(|c| !c.eq_ignore_ascii_case(&b'T')) {
+        let (value, unit) = read_component(s)?;
         match (unit, previous.replace(unit)) {
             (CalendarUnit::Years, None) => months += value * 12,
             (CalendarUnit::Months, None | Some(CalendarUnit::Years)) => months += value,
@@ -136,11 +171,14 @@ fn parse_date_components(s: &mut &[u8]) -> Option<DateDelta> {
                 days += value;
                 break;
             }
-            _ => return None,
+            _ => return s.fail_at(s.pos() - 1, "unit"),
         }
     }
-    Some(DateDelta {
-        months: DeltaMonths::new(months)?,
-        days: DeltaDays::new(days)?,
-    })
+    Some((
+        DateDelta {
+            months: DeltaMonths::new(months)?,
+            days: DeltaDays::new(days)?,
+        },
+        previous.is_some(),
+    ))
 }
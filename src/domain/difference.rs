@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 use std::num::NonZeroU128;
 
 use super::{
-    date::Date,
+    date::{Date, ExpandedDate},
     instant::Instant,
     itemized_date_delta::ItemizedDateDelta,
     round,
@@ -774,6 +774,67 @@ pub(crate) fn date_diff_single_unit(
     })
 }
 
+/// Like [`date_diff`], but for expanded dates. Since these are outside
+/// the range of `Date`, the arithmetic is done on day counts instead.
+/// Years and months are always applied together from the original day of `b`,
+/// so that day clamping only happens once.
+/// Returns (results_per_unit, trunc_days, expand_days). Note the results
+/// may be too large for an `ItemizedDateDelta`.
+pub(crate) fn expanded_date_diff(
+    a: ExpandedDate,
+    b: ExpandedDate,
+    round_increment: CalendarIncrement,
+    units: CalendarUnitSet,
+    neg: bool,
+) -> Option<(ItemizedDateDelta, i64, i64)> {
+    let sign: i64 = if neg { -1 } else { 1 };
+    let smallest = units.smallest();
+    let a_days = a.unix_days();
+    let a_months = a.year.get() as i64 * 12 + a.month as i64;
+    let b_months = b.year.get() as i64 * 12 + b.month as i64;
+    let mut months = 0; // months added to `b` so far
+    let mut days = 0; // days added to `b` (after the months) so far
+    let mut trunc = b.unix_days();
+    let mut expand = trunc;
+    let mut result = ItemizedDateDelta::UNSET;
+
+    for unit in units.iter() {
+        let inc = if unit == smallest {
+            round_increment.get() as i64
+        } else {
+            1
+        };
+        let value = match unit {
+            CalendarUnit::Years | CalendarUnit::Months => {
+                let step = if unit == CalendarUnit::Years { 12 } else { 1 } * inc;
+                let mut diff = (a_months - b_months - months).abs() / step * step * sign;
+                trunc = b.shift_months_to_days(months + diff)?;
+                // Check if we overshot
+                if trunc * sign > a_days * sign {
+                    diff -= step * sign;
+                    trunc = b.shift_months_to_days(months + diff)?;
+                }
+                expand = b.shift_months_to_days(months + diff + step * sign)?;
+                months += diff;
+                diff / step * inc
+            }
+            CalendarUnit::Weeks | CalendarUnit::Days => {
+                let step = if unit == CalendarUnit::Weeks { 7 } else { 1 } * inc;
+                let base = b.shift_months_to_days(months)? + days;
+                let diff = (a_days - base).abs() / step * step * sign;
+                days += diff;
+                trunc = base + diff;
+                expand = trunc + step * sign;
+                diff / step * inc
+            }
+        };
+        // The full expanded range spans fewer than i32::MAX days
+        *unit.field(&mut result) = DeltaField::new_unchecked(value as i32);
+    }
+
+    Some((result, trunc, expand))
+}
+
 /// Round a calendar unit value by the number of days between the truncated
 /// and expanded dates.
 pub(crate) fn round_by_days(
@@ -784,13 +845,34 @@ pub(crate) fn round_by_days(
     mode: round::AbsMode,
     increment: CalendarIncrement,
     neg: bool,
+) -> i32 {
+    round_by_day_counts(
+        value,
+        target.unix_days().get().into(),
+        trunc.unix_days().get().into(),
+        expand.unix_days().get().into(),
+        mode,
+        increment,
+        neg,
+    )
+}
+
+/// Like [`round_by_days`], but with the dates given as day counts
+/// (which may be outside the range of `Date`).
+pub(crate) fn round_by_day_counts(
+    value: i32,
+    target: i64,
+    trunc: i64,
+    expand: i64,
+    mode: round::AbsMode,
+    increment: CalendarIncrement,
+    neg: bool,
 ) -> i32 {
     if mode == round::AbsMode::Trunc {
         value
     } else {
-        let trunc_date = trunc.unix_days();
-        let r = target.unix_days().diff(trunc_date).abs().get();
-        let e = expand.unix_days().diff(trunc_date).abs().get();
+        let r = (target - trunc).abs();
+        let e = (expand - trunc).abs();
         debug_assert!(e > 0, "expand and trunc dates cannot be the same");
         round(value, r > 0, r.cmp(&(e - r)), mode, increment, neg)
     }
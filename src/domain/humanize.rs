@@ -0,0 +1,267 @@
+//! Selecting a unit and amount to describe a difference in time,
+//! e.g. "in 3 hours" or "2 days ago".
+
+use crate::domain::{
+    difference::CalendarUnit,
+    round::{AbsMode, Mode},
+    scalar::{NS_PER_DAY, NS_PER_HOUR, NS_PER_MINUTE, NS_PER_SEC, NS_PER_WEEK},
+};
+
+/// A unit used to describe a difference, smallest first
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Unit {
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+impl Unit {
+    const ALL: [Unit; 7] = [
+        Unit::Seconds,
+        Unit::Minutes,
+        Unit::Hours,
+        Unit::Days,
+        Unit::Weeks,
+        Unit::Months,
+        Unit::Years,
+    ];
+
+    /// The index in the locale tables, which list years first
+    pub(crate) const fn table_index(self) -> usize {
+        6 - self as usize
+    }
+
+    /// The size in nanoseconds, assuming 24-hour days
+    const fn exact_nanos(self) -> Option<u64> {
+        match self {
+            Unit::Seconds => Some(NS_PER_SEC as u64),
+            Unit::Minutes => Some(NS_PER_MINUTE),
+            Unit::Hours => Some(NS_PER_HOUR),
+            Unit::Days => Some(NS_PER_DAY),
+            Unit::Weeks => Some(NS_PER_WEEK),
+            Unit::Months | Unit::Years => None,
+        }
+    }
+
+    const fn to_calendar(self) -> Option<CalendarUnit> {
+        match self {
+            Unit::Days => Some(CalendarUnit::Days),
+            Unit::Weeks => Some(CalendarUnit::Weeks),
+            Unit::Months => Some(CalendarUnit::Months),
+            Unit::Years => Some(CalendarUnit::Years),
+            _ => None,
+        }
+    }
+
+    /// The next larger unit, if it always consists of a whole number
+    /// of this unit
+    const fn promote(self, calendar: bool) -> Option<(Unit, u64)> {
+        match self {
+            Unit::Seconds => Some((Unit::Minutes, 60)),
+            Unit::Minutes => Some((Unit::Hours, 60)),
+            // Calendar days aren't always 24 hours
+            Unit::Hours if !calendar => Some((Unit::Days, 24)),
+            Unit::Days => Some((Unit::Weeks, 7)),
+            Unit::Months => Some((Unit::Years, 12)),
+            _ => None,
+        }
+    }
+}
+
+/// The absolute amount of a unit, exact or fractional
+#[derive(Debug, Copy, Clone)]
+enum Amount {
+    Exact { nanos: u128, unit: u64 },
+    Fraction(f64),
+}
+
+impl Amount {
+    fn is_at_least_one(self) -> bool {
+        match self {
+            Amount::Exact { nanos, unit } => nanos >= unit as u128,
+            Amount::Fraction(f) => f >= 1.0,
+        }
+    }
+
+    fn round(self, mode: AbsMode) -> u64 {
+        // The quotient, and how the remainder compares to half a unit
+        let (quotient, remainder_cmp, has_remainder) = match self {
+            Amount::Exact { nanos, unit } => {
+                let unit = unit as u128;
+                let r = nanos % unit;
+                ((nanos / unit) as u64, (r * 2).cmp(&unit), r != 0)
+            }
+            Amount::Fraction(f) => {
+                let r = f.fract();
+                (f.trunc() as u64, (r * 2.0).total_cmp(&1.0), r != 0.0)
+            }
+        };
+        let expand = match mode {
+            AbsMode::Trunc => false,
+            AbsMode::Expand => has_remainder,
+            AbsMode::HalfTrunc => remainder_cmp.is_gt(),
+            AbsMode::HalfExpand => remainder_cmp.is_ge(),
+            AbsMode::HalfEven => {
+                remainder_cmp.is_gt() || (remainder_cmp.is_eq() && quotient % 2 == 1)
+            }
+        };
+        quotient + expand as u64
+    }
+}
+
+/// Select the unit and (rounded) amount to describe a difference of `nanos`.
+///
+/// The largest unit with an absolute amount of at least one is chosen,
+/// but never one smaller than `granularity`.
+/// If `calendar` is given, it returns the fractional amount of a calendar unit
+/// (days, weeks, months, years). Otherwise, days and weeks are taken to be
+/// 24 hours and 7 days, and months and years aren't used.
+/// Returns `None` if `calendar` does.
+pub(crate) fn select<F>(
+    nanos: i128,
+    mut calendar: Option<F>,
+    granularity: Unit,
+    mode: Mode,
+) -> Option<(Unit, i64)>
+where
+    F: FnMut(CalendarUnit) -> Option<f64>,
+{
+    let neg = nanos < 0;
+    let largest = if calendar.is_some() {
+        Unit::Years
+    } else {
+        Unit::Weeks
+    };
+    let mut chosen = None;
+    for &unit in Unit::ALL.iter().rev() {
+        if unit > largest || unit < granularity {
+            continue;
+        }
+        let amount = match (&mut calendar, unit.to_calendar()) {
+            (Some(f), Some(cal_unit)) => Amount::Fraction(f(cal_unit)?.abs()),
+            _ => Amount::Exact {
+                nanos: nanos.unsigned_abs(),
+                unit: unit.exact_nanos()?,
+            },
+        };
+        chosen = Some((unit, amount));
+        if amount.is_at_least_one() {
+            break;
+        }
+    }
+    let (mut unit, amount) = chosen?;
+    let mut n = amount.round(mode.to_abs_trunc(neg));
+    // Rounding may have reached a whole larger unit, e.g. 60 minutes
+    if let Some((next, ratio)) = unit.promote(calendar.is_some())
+        && n == ratio
+    {
+        unit = next;
+        n = 1;
+    }
+    let n = n as i64;
+    Some((unit, if neg { -n } else { n }))
+}
+
+/// Like [`select`], but without calendar units.
+/// Returns `None` if the granularity is months or years.
+pub(crate) fn select_exact(nanos: i128, granularity: Unit, mode: Mode) -> Option<(Unit, i64)> {
+    select(nanos, None::<fn(_) -> _>, granularity, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exact(nanos: i128, granularity: Unit, mode: Mode) -> (Unit, i64) {
+        select_exact(nanos, granularity, mode).unwrap()
+    }
+
+    #[test]
+    fn test_exact() {
+        let s = NS_PER_SEC as i128;
+        assert_eq!(exact(0, Unit::Seconds, Mode::Trunc), (Unit::Seconds, 0));
+        assert_eq!(
+            exact(59 * s, Unit::Seconds, Mode::Trunc),
+            (Unit::Seconds, 59)
+        );
+        assert_eq!(
+            exact(90 * s, Unit::Seconds, Mode::Trunc),
+            (Unit::Minutes, 1)
+        );
+        assert_eq!(
+            exact(-90 * s, Unit::Seconds, Mode::HalfEven),
+            (Unit::Minutes, -2)
+        );
+        assert_eq!(
+            exact(150 * s, Unit::Seconds, Mode::HalfEven),
+            (Unit::Minutes, 2)
+        );
+        assert_eq!(
+            exact(-150 * s, Unit::Seconds, Mode::Floor),
+            (Unit::Minutes, -3)
+        );
+        assert_eq!(
+            exact(30 * s, Unit::Minutes, Mode::Trunc),
+            (Unit::Minutes, 0)
+        );
+        assert_eq!(
+            exact(30 * s, Unit::Minutes, Mode::HalfExpand),
+            (Unit::Minutes, 1)
+        );
+        assert_eq!(
+            exact(60 * 59 * s, Unit::Hours, Mode::Ceil),
+            (Unit::Hours, 1)
+        );
+        assert_eq!(
+            exact(400 * 86_400 * s, Unit::Seconds, Mode::Trunc),
+            (Unit::Weeks, 57)
+        );
+        assert_eq!(select_exact(s, Unit::Months, Mode::Trunc), None);
+    }
+
+    #[test]
+    fn test_promote() {
+        let s = NS_PER_SEC as i128;
+        assert_eq!(
+            exact(3599 * s, Unit::Minutes, Mode::HalfEven),
+            (Unit::Hours, 1)
+        );
+        assert_eq!(
+            exact(-23 * 3600 * s - 1800 * s, Unit::Seconds, Mode::HalfExpand),
+            (Unit::Days, -1)
+        );
+        assert_eq!(
+            exact(6 * 86_400 * s + 43_200 * s, Unit::Seconds, Mode::HalfExpand),
+            (Unit::Weeks, 1)
+        );
+        // no promotion from hours to days when calendar days are used
+        let cal = Some(|_| Some(0.97));
+        assert_eq!(
+            select(86_000 * s, cal, Unit::Seconds, Mode::HalfExpand),
+            Some((Unit::Hours, 24))
+        );
+    }
+
+    #[test]
+    fn test_calendar() {
+        let months = |u| Some(if u == CalendarUnit::Years { 0.96 } else { 11.5 });
+        let n = NS_PER_DAY as i128 * 350;
+        assert_eq!(
+            select(n, Some(months), Unit::Seconds, Mode::Trunc),
+            Some((Unit::Months, 11))
+        );
+        assert_eq!(
+            select(n, Some(months), Unit::Seconds, Mode::HalfExpand),
+            Some((Unit::Years, 1))
+        );
+        assert_eq!(
+            select(n, Some(months), Unit::Years, Mode::Trunc),
+            Some((Unit::Years, 0))
+        );
+        assert_eq!(select(-n, Some(|_| None), Unit::Seconds, Mode::Trunc), None);
+    }
+}
@@ -1,9 +1,38 @@
 use super::{
     plain_datetime::PlainDateTime,
-    scalar::{EpochSecs, Offset, SubSecNanos},
+    scalar::{DayCount, EpochSecs, NS_PER_SEC, Offset, S_PER_DAY, SubSecNanos, TickCount},
     time_delta::TimeDelta,
 };
 
+/// The NTP epoch (1900-01-01), in seconds since 1970-01-01
+const NTP_EPOCH: i64 = -2_208_988_800;
+/// Apple's Cocoa reference date (2001-01-01), in seconds since 1970-01-01
+const COCOA_EPOCH: i64 = 978_307_200;
+/// The GPS epoch (1980-01-06), in seconds since 1970-01-01
+const GPS_EPOCH: i64 = 315_964_800;
+/// The UTC timestamps at which leap seconds took effect since the GPS epoch.
+/// GPS time doesn't have leap seconds, so it's ahead of UTC by their count.
+const GPS_LEAP_SECONDS: [i64; 18] = [
+    362_793_600,   // 1981-07-01
+    394_329_600,   // 1982-07-01
+    425_865_600,   // 1983-07-01
+    489_024_000,   // 1985-07-01
+    567_993_600,   // 1988-01-01
+    631_152_000,   // 1990-01-01
+    662_688_000,   // 1991-01-01
+    709_948_800,   // 1992-07-01
+    741_484_800,   // 1993-07-01
+    773_020_800,   // 1994-07-01
+    820_454_400,   // 1996-01-01
+    867_715_200,   // 1997-07-01
+    915_148_800,   // 1999-01-01
+    1_136_073_600, // 2006-01-01
+    1_230_768_000, // 2009-01-01
+    1_341_100_800, // 2012-07-01
+    1_435_708_800, // 2015-07-01
+    1_483_228_800, // 2017-01-01
+];
+
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
 pub(crate) struct Instant {
     pub(crate) epoch: EpochSecs,
@@ -35,7 +64,8 @@ impl Instant {
     }
 
     pub(crate) fn from_timestamp_f64(timestamp: f64) -> Option<Self> {
-        (EpochSecs::MIN.get() as f64..=EpochSecs::MAX.get() as f64)
+        // Any fraction of the last second is still in range
+        (EpochSecs::MIN.get() as f64..(EpochSecs::MAX.get() + 1) as f64)
             .contains(&timestamp)
             .then(|| Self {
                 epoch: EpochSecs::new_unchecked(timestamp.floor() as i64),
@@ -43,6 +73,104 @@ impl Instant {
             })
     }
 
+    pub(crate) fn from_day_count_f64(count: DayCount, days: f64) -> Option<Self> {
+        let epoch = match count {
+            DayCount::Excel1900 if days < 60.0 => count.unix_epoch_f64() - 1.0,
+            // Serial 60 is 1900-02-29, which doesn't exist.
+            DayCount::Excel1900 if days < 61.0 => None?,
+            _ => count.unix_epoch_f64(),
+        };
+        Self::from_timestamp_f64((days - epoch) * S_PER_DAY as f64)
+    }
+
+    pub(crate) fn day_count_f64(self, count: DayCount) -> f64 {
+        let days = (self.epoch.get() as f64 + self.subsec.get() as f64 / 1e9) / S_PER_DAY as f64
+            + count.unix_epoch_f64();
+        match count {
+            DayCount::Excel1900 if days < 61.0 => days - 1.0,
+            _ => days,
+        }
+    }
+
+    pub(crate) fn from_tick_count(count: TickCount, ticks: i128) -> Option<Self> {
+        let per_sec = i128::from(count.per_sec());
+        Some(Self {
+            epoch: i64::try_from(ticks.div_euclid(per_sec))
+                .ok()?
+                .checked_add(count.unix_epoch())
+                .and_then(EpochSecs::new)?,
+            subsec: SubSecNanos::new_unchecked(
+                (ticks.rem_euclid(per_sec) * (i128::from(NS_PER_SEC) / per_sec)) as i32,
+            ),
+        })
+    }
+
+    pub(crate) fn tick_count(self, count: TickCount) -> i128 {
+        let per_sec = count.per_sec();
+        // Safe: the largest result fits easily in an i128
+        i128::from(self.epoch.get() - count.unix_epoch()) * i128::from(per_sec)
+            + i128::from(self.subsec.get() as i64 / (i64::from(NS_PER_SEC) / per_sec))
+    }
+
+    /// Create from a 64-bit NTP timestamp (32.32 fixed point seconds since 1900).
+    /// Sub-nanosecond fractions are truncated.
+    pub(crate) fn from_ntp(ntp: u64) -> Option<Self> {
+        let nanos = (u128::from(ntp) * u128::from(NS_PER_SEC)) >> 32;
+        Self::from_timestamp_nanos(nanos as i128 + i128::from(NTP_EPOCH) * i128::from(NS_PER_SEC))
+    }
+
+    /// The 64-bit NTP timestamp, if within NTP era 0 (1900-2036).
+    /// Sub-nanosecond fractions are rounded up, so that `from_ntp()` roundtrips.
+    pub(crate) fn ntp(self) -> Option<u64> {
+        let ns_per_sec = i128::from(NS_PER_SEC);
+        let nanos = self.timestamp_nanos() - i128::from(NTP_EPOCH) * ns_per_sec;
+        if nanos < 0 {
+            return None;
+        }
+        u64::try_from(((nanos << 32) + ns_per_sec - 1) / ns_per_sec).ok()
+    }
+
+    /// Create from nanoseconds since the GPS epoch, in GPS time.
+    /// A leap second itself (23:59:60 UTC) becomes the second before it.
+    pub(crate) fn from_gps_nanos(nanos: i128) -> Option<Self> {
+        let ns_per_sec = i128::from(NS_PER_SEC);
+        let secs = nanos.div_euclid(ns_per_sec);
+        let leaps = GPS_LEAP_SECONDS
+            .iter()
+            .zip(0..)
+            .filter(|&(&t, n)| secs >= i128::from(t - GPS_EPOCH + n))
+            .count() as i128;
+        Self::from_timestamp_nanos(
+            (nanos - leaps * ns_per_sec).checked_add(i128::from(GPS_EPOCH) * ns_per_sec)?,
+        )
+    }
+
+    /// The nanoseconds since the GPS epoch, in GPS time
+    pub(crate) fn gps_nanos(self) -> i128 {
+        let leaps = GPS_LEAP_SECONDS.partition_point(|&t| t <= self.epoch.get()) as i64;
+        i128::from(self.epoch.get() - GPS_EPOCH + leaps) * i128::from(NS_PER_SEC)
+            + i128::from(self.subsec.get())
+    }
+
+    pub(crate) fn from_cocoa(secs: i64) -> Option<Self> {
+        Self::from_timestamp(secs.checked_add(COCOA_EPOCH)?)
+    }
+
+    pub(crate) fn from_cocoa_f64(secs: f64) -> Option<Self> {
+        // Any fraction of the last second is still in range
+        ((EpochSecs::MIN.get() - COCOA_EPOCH) as f64
+            ..(EpochSecs::MAX.get() - COCOA_EPOCH + 1) as f64)
+            .contains(&secs)
+            .then(|| Self {
+                epoch: EpochSecs::new_unchecked(secs.floor() as i64 + COCOA_EPOCH),
+                subsec: SubSecNanos::from_fract(secs),
+            })
+    }
+
+    pub(crate) fn cocoa(self) -> f64 {
+        (self.epoch.get() - COCOA_EPOCH) as f64 + self.subsec.get() as f64 / 1e9
+    }
+
     pub(crate) fn from_timestamp_millis(millis: i64) -> Option<Self> {
         Some(Self {
             epoch: EpochSecs::new(millis.div_euclid(1_000))?,
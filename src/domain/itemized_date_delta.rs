@@ -5,7 +5,15 @@ use super::{
     scalar::{DeltaDays, DeltaField, DeltaMonths},
     shift::CalendarShift,
 };
-use crate::domain::difference::{CalendarIncrement, CalendarUnit, round_by_days, round_by_time};
+use crate::domain::difference::{
+    CalendarIncrement, CalendarUnit, round_by_day_counts, round_by_days, round_by_time,
+};
+
+// The largest magnitude of each field, consistent with the Python implementation
+const MAX_YEARS: i32 = 9999;
+const MAX_MONTHS: i32 = MAX_YEARS * 12;
+const MAX_WEEKS: i32 = MAX_YEARS * 53;
+const MAX_DAYS: i32 = MAX_YEARS * 366;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) struct ItemizedDateDelta {
@@ -40,6 +48,19 @@ impl ItemizedDateDelta {
         })
     }
 
+    /// Whether all fields are within range. Only differences between
+    /// expanded dates can exceed it.
+    pub(crate) fn is_in_range(self) -> bool {
+        [
+            (self.years, MAX_YEARS),
+            (self.months, MAX_MONTHS),
+            (self.weeks, MAX_WEEKS),
+            (self.days, MAX_DAYS),
+        ]
+        .into_iter()
+        .all(|(field, max)| field.get_or(0).abs() <= max)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn round_by_days(
         &mut self,
@@ -63,6 +84,29 @@ impl ItemizedDateDelta {
         ));
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn round_by_day_counts(
+        &mut self,
+        unit: CalendarUnit,
+        target: i64,
+        trunc: i64,
+        expand: i64,
+        mode: round::AbsMode,
+        increment: CalendarIncrement,
+        neg: bool,
+    ) {
+        let field = unit.field(self);
+        field.replace_unchecked(round_by_day_counts(
+            field.as_option().unwrap(),
+            target,
+            trunc,
+            expand,
+            mode,
+            increment,
+            neg,
+        ));
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn round_by_time(
         &mut self,
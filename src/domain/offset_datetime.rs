@@ -85,40 +85,50 @@ impl Instant {
 
 impl Offset {
     pub(crate) fn read_iso(s: &mut Scan) -> Option<Self> {
-        let sign = match s.next() {
+        let sign = match s.peek() {
             Some(b'+') => Sign::Plus,
             Some(b'-') => Sign::Minus,
-            Some(b'Z' | b'z') => return Some(Self::ZERO),
-            _ => return None,
+            Some(b'Z' | b'z') => {
+                s.skip(1);
+                return Some(Self::ZERO);
+            }
+            _ => return s.fail("offset"),
         };
-        let mut total = s.digits00_23()? as i32 * 3600;
-        match s.advance_on(b':') {
-            Some(true) => {
-                total += s.digits00_59()? as i32 * 60;
-                if let Some(true) = s.advance_on(b':') {
-                    total += s.digits00_59()? as i32;
-                }
+        s.skip(1);
+        let mut total = s.read("offset hour", Scan::digits00_23)? as i32 * 3600;
+        if s.advance_on(b':') == Some(true) {
+            total += s.read("offset minute", Scan::digits00_59)? as i32 * 60;
+            if s.advance_on(b':') == Some(true) {
+                total += s.read("offset second", Scan::digits00_59)? as i32;
             }
-            Some(false) => {
-                if let Some(minutes) = s.digits00_59() {
-                    total += minutes as i32 * 60;
-                    if let Some(seconds) = s.digits00_59() {
-                        total += seconds as i32;
-                    }
-                }
+        } else if s.peek().is_some_and(|c| c.is_ascii_digit()) {
+            // No valid input continues with a digit after a component,
+            // so a digit always starts the next component
+            total += s.read("offset minute", Scan::digits00_59)? as i32 * 60;
+            if s.peek().is_some_and(|c| c.is_ascii_digit()) {
+                total += s.read("offset second", Scan::digits00_59)? as i32;
             }
-            None => {}
         }
         Some(Self::new_unchecked(total).with_sign(sign))
     }
 }
 
 fn skip_tzname(s: &mut Scan) -> Option<()> {
-    if let Some(true) = s.advance_on(b'[') {
-        match s.take_until_inclusive(|c| c == b']') {
-            Some(tz) if is_valid_key(std::str::from_utf8(&tz[..tz.len() - 1]).ok()?) => (),
-            _ => return None,
-        }
+    if s.advance_on(b'[') == Some(true) {
+        read_tzname(s)?;
     }
     Some(())
 }
+
+/// Read a timezone ID and its closing bracket, after the opening bracket
+pub(crate) fn read_tzname<'a>(s: &mut Scan<'a>) -> Option<&'a str> {
+    let pos = s.pos();
+    let Some(tz) = s.take_until(|c| c == b']') else {
+        return s.fail_at(s.pos() + s.rest().len(), "]");
+    };
+    s.skip(1);
+    std::str::from_utf8(tz)
+        .ok()
+        .filter(|tz| is_valid_key(tz))
+        .or_else(|| s.fail_at(pos, "timezone ID"))
+}
@@ -126,17 +126,10 @@ impl PlainDateTime {
     }
 
     pub(crate) fn read_iso(s: &mut Scan) -> Option<Self> {
-        if s.len() < 11 {
-            return None;
-        }
-        let date = if is_datetime_sep(s[10]) {
-            Date::parse_iso_extended(s.take_unchecked(10).try_into().unwrap())
-        } else if is_datetime_sep(s[8]) {
-            Date::parse_iso_basic(s.take_unchecked(8).try_into().unwrap())
-        } else {
-            return None;
-        }?;
-        let time = Time::read_iso(s.skip(1))?;
+        let date = Date::read_iso(s)?;
+        s.transform(|c| is_datetime_sep(c).then_some(()))
+            .or_else(|| s.fail("T"))?;
+        let time = Time::read_iso(s)?;
         Some(PlainDateTime { date, time })
     }
 
@@ -363,6 +363,97 @@ impl UnixDays {
         // SAFETY: adding one to a remainder modulo seven produces 1..=7.
         unsafe { Weekday::from_iso_unchecked(((self.get() + 3).rem_euclid(7) + 1) as _) }
     }
+
+    pub(crate) fn from_day_count(count: DayCount, n: i64) -> Option<Self> {
+        let offset = match count {
+            // Serial 60 is 1900-02-29, which doesn't exist.
+            DayCount::Excel1900 if n == 60 => None?,
+            DayCount::Excel1900 if n < 60 => count.unix_epoch() - 1,
+            _ => count.unix_epoch(),
+        };
+        n.checked_sub(offset.into())
+            .and_then(|d| i32::try_from(d).ok())
+            .and_then(Self::new)
+    }
+
+    pub(crate) fn day_count(self, count: DayCount) -> i32 {
+        let n = self.0 + count.unix_epoch();
+        match count {
+            DayCount::Excel1900 if n < 61 => n - 1,
+            _ => n,
+        }
+    }
+}
+
+/// A count of days since an epoch other than the Unix epoch
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum DayCount {
+    /// Julian Day Number: days since noon UTC, 4714-11-24 BCE
+    JulianDay,
+    /// Modified Julian Date: days since 1858-11-17
+    ModifiedJulianDay,
+    /// Rata Die: 0001-01-01 is day 1
+    RataDie,
+    /// Excel's (and Lotus 1-2-3's) 1900 date system.
+    /// It treats 1900 as a leap year, so dates before 1900-03-01
+    /// are offset by one day.
+    Excel1900,
+    /// Excel's 1904 date system: days since 1904-01-01
+    Excel1904,
+}
+
+impl DayCount {
+    /// The day count of 1970-01-01
+    const fn unix_epoch(self) -> i32 {
+        match self {
+            Self::JulianDay => 2_440_588,
+            Self::ModifiedJulianDay => 40_587,
+            Self::RataDie => 719_163,
+            Self::Excel1900 => 25_569,
+            Self::Excel1904 => 24_107,
+        }
+    }
+
+    /// The fractional day count at 1970-01-01 00:00 UTC.
+    /// Julian days start at noon, so they're offset by half a day.
+    pub(crate) fn unix_epoch_f64(self) -> f64 {
+        match self {
+            Self::JulianDay => 2_440_587.5,
+            _ => self.unix_epoch() as f64,
+        }
+    }
+}
+
+/// A count of fixed-size ticks since an epoch other than the Unix epoch
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum TickCount {
+    /// Windows FILETIME: 100ns intervals since 1601-01-01
+    FileTime,
+    /// .NET ticks: 100ns intervals since 0001-01-01
+    DotNet,
+    /// PostgreSQL timestamps: microseconds since 2000-01-01
+    PostgresMicros,
+    /// Microseconds since 1970-01-01
+    UnixMicros,
+}
+
+impl TickCount {
+    /// The epoch, in seconds since 1970-01-01
+    pub(crate) const fn unix_epoch(self) -> i64 {
+        match self {
+            Self::FileTime => -11_644_473_600,
+            Self::DotNet => -62_135_596_800,
+            Self::PostgresMicros => 946_684_800,
+            Self::UnixMicros => 0,
+        }
+    }
+
+    pub(crate) const fn per_sec(self) -> i64 {
+        match self {
+            Self::FileTime | Self::DotNet => 10_000_000,
+            Self::PostgresMicros | Self::UnixMicros => 1_000_000,
+        }
+    }
 }
 
 const MAX_MONTH_DAYS: [[u8; 13]; 2] = [
@@ -464,6 +555,95 @@ impl From<Year> for u16 {
     }
 }
 
+/// A year in the ISO 8601 expanded range, numbered astronomically:
+/// year 0 is 1 BCE, year -1 is 2 BCE, and so on.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExpandedYear(i32);
+
+impl ExpandedYear {
+    pub(crate) const MIN: ExpandedYear = ExpandedYear(-999_999);
+    pub(crate) const MAX: ExpandedYear = ExpandedYear(999_999);
+
+    pub(crate) const fn new(year: i32) -> Option<Self> {
+        if year >= Self::MIN.0 && year <= Self::MAX.0 {
+            Some(Self(year))
+        } else {
+            None
+        }
+    }
+
+    pub(crate) const fn new_unchecked(year: i32) -> Self {
+        debug_assert!(year >= Self::MIN.0 && year <= Self::MAX.0);
+        Self(year)
+    }
+
+    pub(crate) fn from_i64(y: i64) -> Option<Self> {
+        i32::try_from(y).ok().and_then(Self::new)
+    }
+
+    pub(crate) const fn get(self) -> i32 {
+        self.0
+    }
+
+    pub(crate) const fn is_leap(self) -> bool {
+        is_leap_year(self.0)
+    }
+
+    pub(crate) const fn days_in_month(self, month: Month) -> u8 {
+        days_in_month(self.0, month)
+    }
+}
+
+impl From<Year> for ExpandedYear {
+    fn from(y: Year) -> Self {
+        Self(y.get() as i32)
+    }
+}
+
+/// Whether a year of the proleptic Gregorian calendar is a leap year.
+/// Unlike [`Year::is_leap`], this also works for zero and negative years.
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// The number of days in a month of any proleptic Gregorian year
+pub(crate) const fn days_in_month(year: i32, month: Month) -> u8 {
+    MAX_MONTH_DAYS[is_leap_year(year) as usize][month as usize]
+}
+
+// The conversions between (year, month, day) and days since 1970-01-01
+// for any year are based on Howard Hinnant's algorithms.
+// See https://howardhinnant.github.io/date_algorithms.html
+// Unlike `UnixDays`, the day count isn't restricted to the range of `Date`.
+pub(crate) const fn days_from_civil(year: i32, month: Month, day: u8) -> i64 {
+    let m = month as i64;
+    let y = year as i64 - (m <= 2) as i64;
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let doy = (153 * ((m + 9) % 12) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// The inverse of [`days_from_civil`].
+/// The caller must ensure the resulting year fits in an `i32`.
+pub(crate) const fn civil_from_days(days: i64) -> (i32, Month, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    (
+        (yoe + era * 400 + (month <= 2) as i64) as i32,
+        // SAFETY: the algorithm always produces a month in 1..=12
+        unsafe { Month::new_unchecked(month as u8) },
+        day as u8,
+    )
+}
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Month {
@@ -798,8 +978,9 @@ impl SubSecNanos {
     }
 
     pub(crate) fn from_fract(frac: f64) -> Self {
-        // Safety: remainder is always in range
-        Self::new_unchecked((frac.fract() * 1_000_000_000_f64).rem_euclid(1_000_000_000_f64) as _)
+        let nanos = (frac.fract() * 1_000_000_000_f64).rem_euclid(1_000_000_000_f64) as i32;
+        // A tiny negative fraction may round up to a whole second
+        Self::new_unchecked(nanos.min(Self::MAX.0))
     }
 
     pub(crate) fn add(self, other: Self) -> (DeltaSeconds, Self) {
@@ -1037,6 +1218,14 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn subsec_from_fract() {
+        assert_eq!(SubSecNanos::from_fract(1.5).get(), 500_000_000);
+        assert_eq!(SubSecNanos::from_fract(-0.25).get(), 750_000_000);
+        assert_eq!(SubSecNanos::from_fract(-1e-20).get(), 999_999_999);
+        assert_eq!(SubSecNanos::from_fract(-5e-324).get(), 999_999_999);
+    }
+
     #[test]
     fn subsec_format_iso() {
         assert_eq!(
@@ -1077,4 +1266,35 @@ mod tests {
         assert_eq!(i32::from_i64(i32::MAX as i64 + 1), None);
         assert_eq!(i64::from_i64(i64::MIN), None);
     }
+
+    #[test]
+    fn civil_days() {
+        let civil = |y, m, d| days_from_civil(y, Month::new(m).unwrap(), d);
+        assert_eq!(civil(1970, 1, 1), 0);
+        assert_eq!(civil(0, 3, 1), -719_468);
+        assert_eq!(civil(1, 1, 1), UnixDays::MIN.get() as i64);
+        assert_eq!(civil(9999, 12, 31), UnixDays::MAX.get() as i64);
+        // Agrees with the regular date conversion
+        for n in (UnixDays::MIN.get()..=UnixDays::MAX.get()).step_by(97) {
+            let d = UnixDays::new_unchecked(n).date();
+            assert_eq!(civil(d.year.get() as i32, d.month as u8, d.day), n as i64);
+        }
+        // Round-trips across year zero and at the extremes
+        for (y, m, d) in [
+            (0, 2, 29),
+            (-1, 12, 31),
+            (-100, 2, 28),
+            (-400, 2, 29),
+            (-999_999, 1, 1),
+            (999_999, 12, 31),
+        ] {
+            let month = Month::new(m).unwrap();
+            assert_eq!(civil_from_days(days_from_civil(y, month, d)), (y, month, d));
+        }
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(-100));
+        assert!(is_leap_year(-400));
+        assert!(!is_leap_year(-1));
+    }
 }
@@ -68,12 +68,12 @@ impl Time {
     }
 
     pub(crate) fn read_iso_extended(s: &mut Scan) -> Option<Self> {
-        let hour = s.digits00_23()?;
+        let hour = s.read("hour", Scan::digits00_23)?;
         let (minute, second, subsec) = match s.advance_on(b':') {
             Some(true) => {
-                let minute = s.digits00_59()?;
+                let minute = s.read("minute", Scan::digits00_59)?;
                 let (second, subsec) = match s.advance_on(b':') {
-                    Some(true) => s.digits00_60_leap().zip(s.subsec())?,
+                    Some(true) => read_seconds(s)?,
                     _ => (0, SubSecNanos::MIN),
                 };
                 (minute, second, subsec)
@@ -89,16 +89,19 @@ impl Time {
     }
 
     pub(crate) fn read_iso_basic(s: &mut Scan) -> Option<Self> {
-        let hour = s.digits00_23()?;
-        let (minute, second, subsec) = match s.digits00_59() {
-            Some(minute) => {
-                let (second, subsec) = match s.digits00_60_leap() {
-                    Some(second) => (second, s.subsec()?),
-                    None => (0, SubSecNanos::MIN),
+        let hour = s.read("hour", Scan::digits00_23)?;
+        // No valid input continues with a digit after a component,
+        // so a digit always starts the next component
+        let (minute, second, subsec) = match s.peek() {
+            Some(b'0'..=b'9') => {
+                let minute = s.read("minute", Scan::digits00_59)?;
+                let (second, subsec) = match s.peek() {
+                    Some(b'0'..=b'9') => read_seconds(s)?,
+                    _ => (0, SubSecNanos::MIN),
                 };
                 (minute, second, subsec)
             }
-            None => (0, 0, SubSecNanos::MIN),
+            _ => (0, 0, SubSecNanos::MIN),
         };
         Some(Time {
             hour,
@@ -115,6 +118,7 @@ impl Time {
         }
     }
 
+    #[cfg(test)]
     pub(crate) fn parse_iso(s: &[u8]) -> Option<Self> {
         Scan::new(s).parse_all(Self::read_iso)
     }
@@ -216,6 +220,12 @@ impl Time {
     }
 }
 
+/// Read the seconds (possibly a leap second) and the optional fraction
+fn read_seconds(s: &mut Scan) -> Option<(u8, SubSecNanos)> {
+    let second = s.read("second", Scan::digits00_60_leap)?;
+    Some((second, s.read("fraction", Scan::subsec)?))
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum TimeBoundaryUnit {
     Hour,
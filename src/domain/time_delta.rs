@@ -1,13 +1,15 @@
 use super::{
+    date_delta,
+    datetime_delta::DateTimeDelta,
     difference::{self, ExactUnit, ExactUnitSet},
     itemized_delta::ItemizedDelta,
     round,
     scalar::{
-        DeltaField, DeltaNanos, DeltaSeconds, NS_PER_HOUR, NS_PER_MINUTE, NS_PER_SEC, Offset,
-        SubSecNanos,
+        DeltaField, DeltaNanos, DeltaSeconds, NS_PER_DAY, NS_PER_HOUR, NS_PER_MICROSEC,
+        NS_PER_MILLISEC, NS_PER_MINUTE, NS_PER_SEC, NS_PER_WEEK, Offset, SubSecNanos,
     },
 };
-use crate::common::parse::extract_digit;
+use crate::common::parse::Scan;
 use std::{fmt, ops::Neg};
 
 /// A duration of time with nanosecond precision.
@@ -79,15 +81,19 @@ impl TimeDelta {
         self.secs.get() == 0 && self.subsec.get() == 0
     }
 
-    pub(crate) fn parse_iso(mut s: &[u8]) -> Result<Self, ParseError> {
-        let negate = (s.len() >= 4)
-            .then(|| parse_prefix(&mut s))
-            .flatten()
-            .ok_or(ParseError::Invalid)?;
-        let (nanos, is_empty) = parse_all_components(&mut s).ok_or(ParseError::Invalid)?;
-        if is_empty {
-            return Err(ParseError::Invalid);
+    pub(crate) fn parse_iso(s: &mut Scan) -> Result<Self, ParseError> {
+        if s.rest().last().is_some_and(u8::is_ascii_digit) {
+            // The alternative format, with a date part (if any) of zero
+            return s
+                .parse_all(DateTimeDelta::read_iso_alternative)
+                .filter(|d| d.date.is_zero())
+                .map(|d| d.time)
+                .ok_or(ParseError::Invalid);
         }
+        let negate = read_prefix(s).ok_or(ParseError::Invalid)?;
+        let nanos = s
+            .parse_all(read_all_components)
+            .ok_or(ParseError::Invalid)?;
         Self::from_nanos(i128::try_from(nanos).map_err(|_| ParseError::OutOfRange)?)
             .ok_or(ParseError::OutOfRange)
             .map(|d| d.negate_if(negate))
@@ -159,6 +165,169 @@ impl TimeDelta {
         result
     }
 
+    /// Format in the ISO 8601 alternative format, e.g. `PT04:05:06`.
+    /// Returns `None` if it exceeds 24 hours.
+    pub(crate) fn fmt_iso_alternative(self, basic: bool) -> Option<String> {
+        let mut result = String::with_capacity(12);
+        let absolute = if self.is_negative() {
+            result.push('-');
+            -self
+        } else {
+            self
+        };
+        result.push_str("PT");
+        fmt_alternative_abs(absolute, basic, &mut result)?;
+        Some(result)
+    }
+
+    /// Format in the style of Go's `Duration.String()`, e.g. `1h30m0s` or `1.5ms`
+    pub(crate) fn fmt_go(self) -> String {
+        if self.is_zero() {
+            return "0s".to_string();
+        }
+        let mut result = String::with_capacity(8);
+        if self.is_negative() {
+            result.push('-');
+        }
+        let nanos = self.total_nanos().unsigned_abs();
+        if nanos < NS_PER_SEC as u128 {
+            let (unit, suffix) = if nanos < NS_PER_MICROSEC as u128 {
+                (1, "ns")
+            } else if nanos < NS_PER_MILLISEC as u128 {
+                (NS_PER_MICROSEC, "\u{b5}s")
+            } else {
+                (NS_PER_MILLISEC, "ms")
+            };
+            push_decimal(&mut result, nanos, unit as u128);
+            result.push_str(suffix);
+        } else {
+            let secs = nanos / NS_PER_SEC as u128;
+            let (hours, minutes) = (secs / 3600, secs / 60 % 60);
+            if hours != 0 {
+                result.push_str(&format!("{hours}h"));
+            }
+            if hours != 0 || minutes != 0 {
+                result.push_str(&format!("{minutes}m"));
+            }
+            push_decimal(
+                &mut result,
+                nanos % NS_PER_MINUTE as u128,
+                NS_PER_SEC as u128,
+            );
+            result.push('s');
+        }
+        result
+    }
+
+    /// Parse the syntax of Go's `time.ParseDuration`, e.g. `1h30m` or `-2.5s`.
+    /// Consumes the rest of the scanner, recording where parsing failed.
+    pub(crate) fn parse_go(scan: &mut Scan) -> Result<Self, ParseError> {
+        let s = scan.drain();
+        // The position of the remaining input `s`
+        let end = scan.pos();
+        let at = |s: &[u8]| end - s.len();
+        let (negate, mut s) = match s {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            _ => (false, s),
+        };
+        if s == b"0" {
+            return Ok(Self::ZERO);
+        }
+        let mut nanos = Some(0);
+        loop {
+            let (int, frac) = take_decimal(&mut s)
+                .or_else(|| scan.fail_at(at(s), "number"))
+                .ok_or(ParseError::Invalid)?;
+            // Like Go, the unit is everything up to the next number
+            let unit_len = s
+                .iter()
+                .position(|&c| c == b'.' || c.is_ascii_digit())
+                .unwrap_or(s.len());
+            let unit = DurationUnit::from_go(&s[..unit_len])
+                .or_else(|| scan.fail_at(at(s), "unit"))
+                .ok_or(ParseError::Invalid)?;
+            s = &s[unit_len..];
+            nanos = nanos.and_then(|n: u128| n.checked_add(decimal_nanos(int, frac, unit)?));
+            if s.is_empty() {
+                break;
+            }
+        }
+        Self::from_component_nanos(nanos, negate)
+    }
+
+    /// Parse a lenient English duration, e.g. `1 day, 3 hours and 5.5 min`.
+    /// Go-style durations are accepted as well.
+    /// Also returns whether days or weeks were used.
+    /// Consumes the rest of the scanner, recording where parsing failed.
+    pub(crate) fn parse_english(scan: &mut Scan) -> Result<(Self, bool), ParseError> {
+        let start = scan.pos();
+        let input = scan.drain();
+        let leading = input
+            .iter()
+            .take_while(|&&c| Scan::is_whitespace(c))
+            .count();
+        let mut s = trim_whitespace(input);
+        // The position of the remaining input `s`, which excludes trailing whitespace
+        let end = start + leading + s.len();
+        let at = |s: &[u8]| end - s.len();
+        let negate = match s {
+            [b'-', rest @ ..] => {
+                s = rest;
+                true
+            }
+            [b'+', rest @ ..] => {
+                s = rest;
+                false
+            }
+            _ => false,
+        };
+        skip_whitespace(&mut s);
+        if s == b"0" {
+            return Ok((Self::ZERO, false));
+        }
+        let mut nanos = Some(0);
+        let mut uses_days = false;
+        loop {
+            let (int, frac) = take_decimal(&mut s)
+                .or_else(|| scan.fail_at(at(s), "number"))
+                .ok_or(ParseError::Invalid)?;
+            skip_whitespace(&mut s);
+            let unit_len = s
+                .iter()
+                .position(|&c| c.is_ascii() && !c.is_ascii_alphabetic())
+                .unwrap_or(s.len());
+            let unit = DurationUnit::from_english(&s[..unit_len])
+                .or_else(|| scan.fail_at(at(s), "unit"))
+                .ok_or(ParseError::Invalid)?;
+            s = &s[unit_len..];
+            uses_days |= matches!(unit, DurationUnit::Days | DurationUnit::Weeks);
+            nanos = nanos.and_then(|n: u128| n.checked_add(decimal_nanos(int, frac, unit)?));
+            if s.is_empty() {
+                break;
+            }
+            // Components may be separated by whitespace, a comma, and/or "and"
+            skip_whitespace(&mut s);
+            if let [b',', rest @ ..] = s {
+                s = rest;
+                skip_whitespace(&mut s);
+            }
+            if s.len() > 3 && s[..3].eq_ignore_ascii_case(b"and") && Scan::is_whitespace(s[3]) {
+                s = &s[3..];
+                skip_whitespace(&mut s);
+            }
+        }
+        Ok((Self::from_component_nanos(nanos, negate)?, uses_days))
+    }
+
+    fn from_component_nanos(nanos: Option<u128>, negate: bool) -> Result<Self, ParseError> {
+        nanos
+            .and_then(|n| i128::try_from(n).ok())
+            .and_then(Self::from_nanos)
+            .ok_or(ParseError::OutOfRange)
+            .map(|d| d.negate_if(negate))
+    }
+
     pub(crate) fn in_exact_units(
         self,
         units: ExactUnitSet,
@@ -255,94 +424,127 @@ pub(crate) fn fmt_components_abs(delta: TimeDelta, result: &mut String) {
     }
 }
 
-pub(crate) fn parse_prefix(s: &mut &[u8]) -> Option<bool> {
-    let negative = match s[0] {
-        b'+' => {
-            *s = &s[1..];
+/// The largest duration allowed in the ISO 8601 alternative format,
+/// i.e. its "carry-over point" of 24 hours
+const ALTERNATIVE_MAX: TimeDelta = TimeDelta {
+    secs: DeltaSeconds::new_unchecked(86_400),
+    subsec: SubSecNanos::MIN,
+};
+
+/// Format the time part of the ISO 8601 alternative format, e.g. `04:05:06.5`
+pub(crate) fn fmt_alternative_abs(
+    delta: TimeDelta,
+    basic: bool,
+    result: &mut String,
+) -> Option<()> {
+    debug_assert!(delta.secs.get() >= 0);
+    if delta > ALTERNATIVE_MAX {
+        return None;
+    }
+    let (hours, minutes, seconds) = delta.secs.abs_hms();
+    let sep = if basic { "" } else { ":" };
+    result.push_str(&format!(
+        "{hours:02}{sep}{minutes:02}{sep}{seconds:02}{}",
+        delta.subsec
+    ));
+    Some(())
+}
+
+/// Read the time part of the ISO 8601 alternative format,
+/// e.g. `04:05:06.5` or `040506.5`. Also returns whether it's in basic format.
+/// If `basic` is given, the format must match it.
+pub(crate) fn read_alternative(s: &mut Scan, basic: Option<bool>) -> Option<(TimeDelta, bool)> {
+    let start = s.pos();
+    let hours = s.read("hour", |s| s.digits(2))?;
+    let basic = match basic {
+        Some(true) => true,
+        Some(false) => {
+            s.expect(b':').or_else(|| s.fail(":"))?;
             false
         }
-        b'-' => {
-            *s = &s[1..];
-            true
-        }
-        _ => false,
+        None => s.advance_on(b':') != Some(true),
     };
-    s[..2].eq_ignore_ascii_case(b"PT").then(|| {
-        *s = &s[2..];
-        negative
-    })
+    let minutes = s.read("minute", Scan::digits00_59)?;
+    if !basic {
+        s.expect(b':').or_else(|| s.fail(":"))?;
+    }
+    let seconds = s.read("second", Scan::digits00_59)?;
+    let delta = TimeDelta {
+        secs: DeltaSeconds::new_unchecked(
+            i64::from(hours) * 3600 + i64::from(minutes) * 60 + i64::from(seconds),
+        ),
+        subsec: s.read("fraction", Scan::subsec)?,
+    };
+    if delta > ALTERNATIVE_MAX {
+        return s.fail_at(start, "hour");
+    }
+    Some((delta, basic))
+}
+
+/// Read the sign (if any) and the `PT` prefix. Returns whether it's negative.
+pub(crate) fn read_prefix(s: &mut Scan) -> Option<bool> {
+    let negative = date_delta::read_prefix(s)?;
+    s.expect_ignore_case(b'T').or_else(|| s.fail("T"))?;
+    Some(negative)
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub(crate) enum TimeUnit {
     Hours,
     Minutes,
-    Nanos { has_fraction: bool },
-}
-
-fn parse_nano_fractions(s: &[u8]) -> Option<u32> {
-    if s.is_empty() {
-        return None;
-    }
-    let mut tally = extract_digit(s, 0)? as u32 * 100_000_000;
-    for i in 1..s.len().min(9) {
-        match s[i] {
-            c if c.is_ascii_digit() => tally += u32::from(c - b'0') * 10_u32.pow(8 - i as u32),
-            b'S' | b's' if i + 1 == s.len() => return Some(tally),
-            _ => return None,
-        }
-    }
-    (s.len() == 10 && s[9].eq_ignore_ascii_case(&b's')).then_some(tally)
+    Nanos,
 }
 
-pub(crate) fn parse_time_component(s: &mut &[u8]) -> Option<(u128, TimeUnit)> {
-    if s.len() < 2 {
-        return None;
+/// Read a component like `4H` or `1.5S`
+fn read_time_component(s: &mut Scan) -> Option<(u128, TimeUnit)> {
+    let digits = s.rest().iter().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 || digits > 34 {
+        return s.fail("number");
     }
-    let mut tally: u128 = 0;
-    for i in 0..s.len().min(35) {
-        match s[i] {
-            c if c.is_ascii_digit() => tally = tally * 10 + u128::from(c - b'0'),
-            b'H' | b'h' if i > 0 => {
-                *s = &s[i + 1..];
-                return Some((tally, TimeUnit::Hours));
-            }
-            b'M' | b'm' if i > 0 => {
-                *s = &s[i + 1..];
-                return Some((tally, TimeUnit::Minutes));
-            }
-            b'S' | b's' if i > 0 => {
-                *s = &s[i + 1..];
-                return Some((
-                    tally.checked_mul(NS_PER_SEC as u128)?,
-                    TimeUnit::Nanos {
-                        has_fraction: false,
-                    },
-                ));
-            }
-            b'.' | b',' if i > 0 => {
-                let result = parse_nano_fractions(&s[i + 1..]).and_then(|nanos| {
-                    Some((
-                        tally
-                            .checked_mul(NS_PER_SEC as u128)?
-                            .checked_add(nanos as u128)?,
-                        TimeUnit::Nanos { has_fraction: true },
-                    ))
-                });
-                *s = &[];
-                return result;
+    let value = s
+        .take_unchecked(digits)
+        .iter()
+        .fold(0, |total, &c| total * 10 + u128::from(c - b'0'));
+    let unit = match s.peek() {
+        Some(b'H' | b'h') => TimeUnit::Hours,
+        Some(b'M' | b'm') => TimeUnit::Minutes,
+        Some(b'S' | b's') => TimeUnit::Nanos,
+        Some(b'.' | b',') => {
+            s.skip(1);
+            let digits = s
+                .rest()
+                .iter()
+                .take(9)
+                .take_while(|c| c.is_ascii_digit())
+                .count();
+            if digits == 0 {
+                return s.fail("fraction");
             }
-            _ => break,
+            let nanos = s.digits(digits)? * 10_u32.pow(9 - digits as u32);
+            s.expect_ignore_case(b'S').or_else(|| s.fail("S"))?;
+            return Some((
+                value
+                    .checked_mul(NS_PER_SEC as u128)?
+                    .checked_add(nanos as u128)?,
+                TimeUnit::Nanos,
+            ));
         }
-    }
-    None
+        _ => return s.fail("unit"),
+    };
+    s.skip(1);
+    Some(match unit {
+        TimeUnit::Nanos => (value.checked_mul(NS_PER_SEC as u128)?, unit),
+        _ => (value, unit),
+    })
 }
 
-pub(crate) fn parse_all_components(s: &mut &[u8]) -> Option<(u128, bool)> {
+/// Read the components of the time part, e.g. `4H30M`, in nanoseconds.
+/// At least one component is required.
+pub(crate) fn read_all_components(s: &mut Scan) -> Option<u128> {
     let mut previous = None;
     let mut nanos: u128 = 0;
-    while !s.is_empty() {
-        let (value, unit) = parse_time_component(s)?;
+    loop {
+        let (value, unit) = read_time_component(s)?;
         match (unit, previous.replace(unit)) {
             (TimeUnit::Hours, None) => {
                 nanos = nanos.checked_add(value.checked_mul(NS_PER_HOUR as u128)?)?;
@@ -350,17 +552,134 @@ pub(crate) fn parse_all_components(s: &mut &[u8]) -> Option<(u128, bool)> {
             (TimeUnit::Minutes, None | Some(TimeUnit::Hours)) => {
                 nanos = nanos.checked_add(value.checked_mul(NS_PER_MINUTE as u128)?)?;
             }
-            (TimeUnit::Nanos { .. }, _) => {
-                nanos = nanos.checked_add(value)?;
-                if !s.is_empty() {
-                    return None;
-                }
-                break;
-            }
-            _ => return None,
+            // Seconds are always the last component
+            (TimeUnit::Nanos, _) => return nanos.checked_add(value),
+            _ => return s.fail_at(s.pos() - 1, "unit"),
+        }
+        if s.is_done() {
+            return Some(nanos);
         }
     }
-    Some((nanos, previous.is_none()))
+}
+
+/// A unit in a Go-style or English duration, e.g. `1h30m` or `2 days`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum DurationUnit {
+    Nanos,
+    Micros,
+    Millis,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+}
+
+impl DurationUnit {
+    const fn nanos(self) -> u128 {
+        match self {
+            Self::Nanos => 1,
+            Self::Micros => NS_PER_MICROSEC as _,
+            Self::Millis => NS_PER_MILLISEC as _,
+            Self::Seconds => NS_PER_SEC as _,
+            Self::Minutes => NS_PER_MINUTE as _,
+            Self::Hours => NS_PER_HOUR as _,
+            Self::Days => NS_PER_DAY as _,
+            Self::Weeks => NS_PER_WEEK as _,
+        }
+    }
+
+    /// The (case-sensitive) units of Go's `time.ParseDuration`
+    fn from_go(s: &[u8]) -> Option<Self> {
+        Some(match s {
+            b"ns" => Self::Nanos,
+            // The micro sign (U+00B5) and the Greek small letter mu (U+03BC)
+            b"us" | b"\xc2\xb5s" | b"\xce\xbcs" => Self::Micros,
+            b"ms" => Self::Millis,
+            b"s" => Self::Seconds,
+            b"m" => Self::Minutes,
+            b"h" => Self::Hours,
+            _ => return None,
+        })
+    }
+
+    /// English unit names and abbreviations, case-insensitive
+    fn from_english(s: &[u8]) -> Option<Self> {
+        let mut buf = [0; 12];
+        let lower = buf.get_mut(..s.len())?;
+        lower.copy_from_slice(s);
+        lower.make_ascii_lowercase();
+        Some(match &*lower {
+            b"ns" | b"nsec" | b"nsecs" | b"nanosecond" | b"nanoseconds" => Self::Nanos,
+            b"us" | b"\xc2\xb5s" | b"\xce\xbcs" | b"usec" | b"usecs" | b"microsecond"
+            | b"microseconds" => Self::Micros,
+            b"ms" | b"msec" | b"msecs" | b"millisecond" | b"milliseconds" => Self::Millis,
+            b"s" | b"sec" | b"secs" | b"second" | b"seconds" => Self::Seconds,
+            b"m" | b"min" | b"mins" | b"minute" | b"minutes" => Self::Minutes,
+            b"h" | b"hr" | b"hrs" | b"hour" | b"hours" => Self::Hours,
+            b"d" | b"day" | b"days" => Self::Days,
+            b"w" | b"wk" | b"wks" | b"week" | b"weeks" => Self::Weeks,
+            _ => return None,
+        })
+    }
+}
+
+/// Take a decimal number like `1`, `2.5`, `.5` or `3.`,
+/// returning its integer and fractional digits.
+fn take_decimal<'a>(s: &mut &'a [u8]) -> Option<(&'a [u8], &'a [u8])> {
+    let count_digits = |s: &[u8]| s.iter().take_while(|c| c.is_ascii_digit()).count();
+    let (int, rest) = s.split_at(count_digits(s));
+    let (frac, rest) = match rest {
+        [b'.', rest @ ..] => rest.split_at(count_digits(rest)),
+        _ => (&[][..], rest),
+    };
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    *s = rest;
+    Some((int, frac))
+}
+
+/// The decimal number in the given unit, truncated to whole nanoseconds.
+/// Returns `None` on overflow.
+fn decimal_nanos(int: &[u8], frac: &[u8], unit: DurationUnit) -> Option<u128> {
+    let unit = unit.nanos();
+    let mut whole: u128 = 0;
+    for &c in int {
+        whole = whole.checked_mul(10)?.checked_add(u128::from(c - b'0'))?;
+    }
+    // Working from the last digit keeps the truncation exact,
+    // regardless of the number of digits.
+    let mut fraction = 0;
+    for &c in frac.iter().rev() {
+        fraction = (u128::from(c - b'0') * unit + fraction) / 10;
+    }
+    whole.checked_mul(unit)?.checked_add(fraction)
+}
+
+fn push_decimal(result: &mut String, value: u128, unit: u128) {
+    result.push_str(&(value / unit).to_string());
+    let frac = value % unit;
+    if frac != 0 {
+        let width = unit.ilog10() as usize;
+        result.push('.');
+        result.push_str(format!("{frac:0width$}").trim_end_matches('0'));
+    }
+}
+
+fn skip_whitespace(s: &mut &[u8]) {
+    let n = s.iter().take_while(|&&c| Scan::is_whitespace(c)).count();
+    *s = &s[n..];
+}
+
+fn trim_whitespace(mut s: &[u8]) -> &[u8] {
+    skip_whitespace(&mut s);
+    while let [rest @ .., c] = s
+        && Scan::is_whitespace(*c)
+    {
+        s = rest;
+    }
+    s
 }
 
 impl Offset {
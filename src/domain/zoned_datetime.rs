@@ -5,20 +5,17 @@ use super::{
     itemized_date_delta::ItemizedDateDelta,
     itemized_delta::ItemizedDelta,
     local::{Disambiguation, ResolveError, ResolvePolicy},
-    offset_datetime::OffsetDateTime,
+    offset_datetime::{OffsetDateTime, read_tzname},
     plain_datetime::PlainDateTime,
     round,
     scalar::Offset,
     time::Time,
 };
-use crate::tz::tzif::TimeZone;
-use crate::{
-    common::{
-        fmt::{self, Sink},
-        parse::Scan,
-    },
-    tz::tzif::is_valid_key,
+use crate::common::{
+    fmt::{self, Sink},
+    parse::Scan,
 };
+use crate::tz::tzif::TimeZone;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -168,7 +165,7 @@ pub(crate) enum OffsetInIsoString {
     Missing,
 }
 
-pub(crate) fn read_offset_and_tzname<'a>(s: &'a mut Scan) -> Option<(OffsetInIsoString, &'a str)> {
+pub(crate) fn read_offset_and_tzname<'a>(s: &mut Scan<'a>) -> Option<(OffsetInIsoString, &'a str)> {
     let offset = match s.peek() {
         Some(b'[') => OffsetInIsoString::Missing,
         Some(b'Z' | b'z') => {
@@ -177,17 +174,8 @@ pub(crate) fn read_offset_and_tzname<'a>(s: &'a mut Scan) -> Option<(OffsetInIso
         }
         _ => OffsetInIsoString::Some(Offset::read_iso(s)?),
     };
-    let tz = s.rest();
-    (tz.len() > 2
-        && tz[0] == b'['
-        && tz.iter().position(|&byte| byte == b']') == Some(tz.len() - 1)
-        && tz.is_ascii())
-    .then(|| {
-        // SAFETY: the preceding condition established that the bytes are ASCII.
-        unsafe { std::str::from_utf8_unchecked(&tz[1..tz.len() - 1]) }
-    })
-    .filter(|tz| is_valid_key(tz))
-    .map(|tz| (offset, tz))
+    s.expect(b'[').or_else(|| s.fail("["))?;
+    Some((offset, read_tzname(s)?))
 }
 
 pub(crate) struct TzFormat<'a> {
@@ -4,8 +4,10 @@ use crate::{
         date::{self, unpickle as _unpkl_date},
         date_delta::{self, days, months, unpickle as _unpkl_ddelta, weeks, years},
         datetime_delta::{self, unpickle as _unpkl_dtdelta},
+        expanded_date::{self, unpickle as _unpkl_xdate},
         instant::{self, unpickle as _unpkl_inst, unpickle_pre_0_8 as _unpkl_utc},
         offset_datetime::{self, unpickle as _unpkl_offset},
+        pattern::{self, unpickle as _unpkl_pattern},
         plain_datetime::{self, unpickle as _unpkl_local},
         time::{self, unpickle as _unpkl_time},
         time_delta::{
@@ -15,12 +17,15 @@ use crate::{
         zoned_datetime::{self, unpickle as _unpkl_zoned},
     },
     common::{
+        locale::Registry as LocaleRegistry,
+        pattern::PatternCache,
         round_args as round,
         sync::{OncePyCell, SwapPtr, SyncCell},
     },
     docstrings as doc,
     py::*,
     pymodule::{
+        localeconf::*,
         patch::{_patch_time_frozen, _patch_time_keep_ticking, _unpatch_time, Patch},
         tzconf::*,
         utils::*,
@@ -70,6 +75,8 @@ static mut METHODS: &mut [PyMethodDef] = &mut [
     modmethod1!(_unpkl_utc, c""), // for backwards compatibility
     modmethod1!(_unpkl_offset, c""),
     modmethod_vararg!(_unpkl_zoned, c""),
+    modmethod_vararg!(_unpkl_pattern, c""),
+    modmethod1!(_unpkl_xdate, c""),
     // FUTURE: set __module__ on these
     modmethod1!(years, doc::YEARS),
     modmethod1!(months, doc::MONTHS),
@@ -88,7 +95,10 @@ static mut METHODS: &mut [PyMethodDef] = &mut [
     modmethod0!(_get_tzpath, c""),
     modmethod0!(_clear_tz_cache, c""),
     modmethod1!(_clear_tz_cache_by_keys, c""),
+    modmethod_vararg!(_derive_tz, c""),
+    modmethod1!(_remove_derived_tz, c""),
     modmethod0!(reset_system_tz, doc::RESET_SYSTEM_TZ),
+    modmethod_vararg!(_register_humanize_locale, c""),
     PyMethodDef::zeroed(),
 ];
 
@@ -172,11 +182,26 @@ struct InternedStrings {
     str_half_trunc: Owned<PyObj>,
     str_half_expand: Owned<PyObj>,
     str_format: Owned<PyObj>,
+    str_locale: Owned<PyObj>,
+    str_relative_day: Owned<PyObj>,
+    str_granularity: Owned<PyObj>,
+    str_style: Owned<PyObj>,
+    str_pattern: Owned<PyObj>,
+    str_strftime: Owned<PyObj>,
+    str_spreadsheet: Owned<PyObj>,
+    str_lenient: Owned<PyObj>,
+    str_strict: Owned<PyObj>,
+    str_zone_comment: Owned<PyObj>,
+    str_pivot_year: Owned<PyObj>,
     str_sep: Owned<PyObj>,
     str_space: Owned<PyObj>,
     str_t: Owned<PyObj>,
     str_auto: Owned<PyObj>,
     str_basic: Owned<PyObj>,
+    str_alternative: Owned<PyObj>,
+    str_epoch_1904: Owned<PyObj>,
+    str_epoch: Owned<PyObj>,
+    str_tag: Owned<PyObj>,
     str_always: Owned<PyObj>,
     str_never: Owned<PyObj>,
     str_offset_mismatch: Owned<PyObj>,
@@ -241,11 +266,26 @@ fn intern_strings() -> PyResult<InternedStrings> {
         str_half_trunc: intern(c"half_trunc")?,
         str_half_expand: intern(c"half_expand")?,
         str_format: intern(c"format")?,
+        str_locale: intern(c"locale")?,
+        str_relative_day: intern(c"relative_day")?,
+        str_granularity: intern(c"granularity")?,
+        str_style: intern(c"style")?,
+        str_pattern: intern(c"pattern")?,
+        str_strftime: intern(c"strftime")?,
+        str_spreadsheet: intern(c"spreadsheet")?,
+        str_lenient: intern(c"lenient")?,
+        str_strict: intern(c"strict")?,
+        str_zone_comment: intern(c"zone_comment")?,
+        str_pivot_year: intern(c"pivot_year")?,
         str_sep: intern(c"sep")?,
         str_space: intern(c" ")?,
         str_t: intern(c"T")?,
         str_auto: intern(c"auto")?,
         str_basic: intern(c"basic")?,
+        str_alternative: intern(c"alternative")?,
+        str_epoch_1904: intern(c"epoch_1904")?,
+        str_epoch: intern(c"epoch")?,
+        str_tag: intern(c"tag")?,
         str_always: intern(c"always")?,
         str_never: intern(c"never")?,
         str_offset_mismatch: intern(c"offset_mismatch")?,
@@ -278,6 +318,13 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         c"_unpkl_date",
     )?;
     create_singletons(*date_type, date::SINGLETONS)?;
+    let (expanded_date_type, unpickle_expanded_date) = new_class(
+        module,
+        *module_name,
+        &mut unsafe { expanded_date::SPEC },
+        c"_unpkl_xdate",
+    )?;
+    create_singletons(*expanded_date_type, expanded_date::SINGLETONS)?;
     let (time_type, unpickle_time) = new_class(
         module,
         *module_name,
@@ -332,6 +379,12 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         &mut unsafe { zoned_datetime::SPEC },
         c"_unpkl_zoned",
     )?;
+    let (pattern_type, unpickle_pattern) = new_class(
+        module,
+        *module_name,
+        &mut unsafe { pattern::SPEC },
+        c"_unpkl_pattern",
+    )?;
     module
         .getattr(c"_unpkl_utc")?
         .setattr(c"__module__", *module_name)?;
@@ -372,6 +425,10 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         doc::TIMEZONENOTFOUNDERROR,
         exc_value_error(),
     )?;
+    // Implemented in Python so the pure-Python parsers raise the same class
+    let exc_parse = import(c"whenever._common")?.getattr(c"ParseError")?;
+    exc_parse.setattr(c"__module__", *module_name)?;
+    module.setattr(c"ParseError", *exc_parse)?;
 
     // Warning classes. The root class is implemented in Python so pure-Python
     // itemized delta warnings and Rust-created warnings share one base.
@@ -465,11 +522,26 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         str_half_trunc,
         str_half_expand,
         str_format,
+        str_locale,
+        str_relative_day,
+        str_granularity,
+        str_style,
+        str_pattern,
+        str_strftime,
+        str_spreadsheet,
+        str_lenient,
+        str_strict,
+        str_zone_comment,
+        str_pivot_year,
         str_sep,
         str_space,
         str_t,
         str_auto,
         str_basic,
+        str_alternative,
+        str_epoch_1904,
+        str_epoch,
+        str_tag,
         str_always,
         str_never,
         str_offset_mismatch,
@@ -486,6 +558,7 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
     // to ensure we don't leak references to the above.
     let state = State {
         date_type,
+        expanded_date_type,
         time_type,
         date_delta_type,
         time_delta_type,
@@ -500,6 +573,7 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         instant_type,
         offset_datetime_type,
         zoned_datetime_type,
+        pattern_type,
 
         yearmonth_type: OncePyObj::new(|| import(c"whenever._shared")?.getattr(c"YearMonth")),
         monthday_type: OncePyObj::new(|| import(c"whenever._shared")?.getattr(c"MonthDay")),
@@ -508,6 +582,11 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
                 .getattr(c"IsoWeekDate")?
                 .getattr(c"_from_parts_unchecked")
         }),
+        offset_period_new: OncePyObj::new(|| {
+            import(c"whenever._shared")?
+                .getattr(c"OffsetPeriod")?
+                .getattr(c"_from_parts_unchecked")
+        }),
         weekday_enum_members: OncePyCell::new(|| {
             let shared_module = import(c"whenever._shared")?;
             let weekday_enum = shared_module.getattr(c"Weekday")?;
@@ -532,6 +611,7 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         }),
         time_ns: OncePyObj::new(|| import(c"time")?.getattr(c"time_ns")),
         zoneinfo_type: OncePyObj::new(|| import(c"zoneinfo")?.getattr(c"ZoneInfo")),
+        uuid_type: OncePyObj::new(|| import(c"uuid")?.getattr(c"UUID")),
         get_pydantic_schema: OncePyObj::new(|| {
             import(c"whenever._utils")?.getattr(c"pydantic_schema")
         }),
@@ -584,11 +664,26 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
             str_half_expand,
         },
         str_format,
+        str_locale,
+        str_relative_day,
+        str_granularity,
+        str_style,
+        str_pattern,
+        str_strftime,
+        str_spreadsheet,
+        str_lenient,
+        str_strict,
+        str_zone_comment,
+        str_pivot_year,
         str_sep,
         str_space,
         str_t,
         str_auto,
         str_basic,
+        str_alternative,
+        str_epoch_1904,
+        str_epoch,
+        str_tag,
         str_always,
         str_never,
         str_offset_mismatch,
@@ -605,6 +700,7 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         exc_invalid_offset,
         exc_implicitly_ignoring_dst,
         exc_tz_notfound,
+        exc_parse,
 
         warn_potential_dst_bug,
         warn_whenever,
@@ -629,9 +725,13 @@ fn module_exec(mut module: PyModule) -> PyResult<()> {
         unpickle_instant,
         unpickle_offset_datetime,
         unpickle_zoned_datetime,
+        unpickle_pattern,
+        unpickle_expanded_date,
 
         time_patch,
         tz_store,
+        pattern_cache: SyncCell::new(PatternCache::default()),
+        humanize_locales: LocaleRegistry::new(),
     };
     // SAFETY: module_exec exclusively owns the module-state lifecycle transition.
     unsafe { module.state_mut().assume_init_mut() }.replace(state);
@@ -714,6 +814,12 @@ fn module_traverse(mod_ptr: *mut PyObject, visit: visitproc, arg: *mut c_void) -
             *state.unpickle_zoned_datetime,
             0,
         ),
+        (state.pattern_type.as_type(), *state.unpickle_pattern, 0),
+        (
+            state.expanded_date_type.as_type(),
+            *state.unpickle_expanded_date,
+            expanded_date::SINGLETONS.len(),
+        ),
     ] {
         traverse_type(cls, visit, arg, num_singletons)?;
         unpkl.gc_traverse(visit, arg)?;
@@ -723,6 +829,7 @@ fn module_traverse(mod_ptr: *mut PyObject, visit: visitproc, arg: *mut c_void) -
     state.yearmonth_type.gc_traverse(visit, arg)?;
     state.monthday_type.gc_traverse(visit, arg)?;
     state.isoweekdate_new.gc_traverse(visit, arg)?;
+    state.offset_period_new.gc_traverse(visit, arg)?;
     state.itemized_date_delta_type.gc_traverse(visit, arg)?;
     state.itemized_delta_type.gc_traverse(visit, arg)?;
     state.unpickle_itemized_date_delta.gc_traverse(visit, arg)?;
@@ -742,6 +849,7 @@ fn module_traverse(mod_ptr: *mut PyObject, visit: visitproc, arg: *mut c_void) -
         *state.exc_invalid_offset,
         *state.exc_implicitly_ignoring_dst,
         *state.exc_tz_notfound,
+        *state.exc_parse,
         *state.warn_whenever,
         *state.warn_potential_dst_bug,
         *state.warn_days_not_always_24h,
@@ -757,6 +865,7 @@ fn module_traverse(mod_ptr: *mut PyObject, visit: visitproc, arg: *mut c_void) -
     state.strptime.gc_traverse(visit, arg)?;
     state.time_ns.gc_traverse(visit, arg)?;
     state.zoneinfo_type.gc_traverse(visit, arg)?;
+    state.uuid_type.gc_traverse(visit, arg)?;
     state.get_pydantic_schema.gc_traverse(visit, arg)?;
     Ok(())
 }
@@ -803,11 +912,14 @@ pub(crate) struct State {
     pub(crate) instant_type: Owned<PyClass<instant::Instant>>,
     pub(crate) offset_datetime_type: Owned<PyClass<offset_datetime::OffsetDateTime>>,
     pub(crate) zoned_datetime_type: Owned<PyClass<zoned_datetime::ZonedDateTime>>,
+    pub(crate) pattern_type: Owned<PyClass<pattern::Pattern>>,
+    pub(crate) expanded_date_type: Owned<PyClass<expanded_date::ExpandedDate>>,
 
     // Lazily imported from _shared
     pub(crate) yearmonth_type: OncePyObj,
     pub(crate) monthday_type: OncePyObj,
     pub(crate) isoweekdate_new: OncePyObj,
+    pub(crate) offset_period_new: OncePyObj,
     pub(crate) weekday_enum_members: OncePyCell<[Owned<PyObj>; 7]>,
 
     // Lazily imported from _ideltas
@@ -820,6 +932,7 @@ pub(crate) struct State {
     pub(crate) exc_invalid_offset: Owned<PyObj>,
     pub(crate) exc_implicitly_ignoring_dst: Owned<PyObj>,
     pub(crate) exc_tz_notfound: Owned<PyObj>,
+    pub(crate) exc_parse: Owned<PyObj>,
 
     // warnings
     pub(crate) warn_potential_dst_bug: Owned<PyObj>,
@@ -842,6 +955,8 @@ pub(crate) struct State {
     pub(crate) unpickle_instant: Owned<PyObj>,
     pub(crate) unpickle_offset_datetime: Owned<PyObj>,
     pub(crate) unpickle_zoned_datetime: Owned<PyObj>,
+    pub(crate) unpickle_pattern: Owned<PyObj>,
+    pub(crate) unpickle_expanded_date: Owned<PyObj>,
 
     pub(crate) py_api: SwapPtr<PyDateTime_CAPI>,
 
@@ -849,6 +964,7 @@ pub(crate) struct State {
     pub(crate) strptime: OncePyObj,
     pub(crate) time_ns: OncePyObj,
     pub(crate) zoneinfo_type: OncePyObj,
+    pub(crate) uuid_type: OncePyObj,
     pub(crate) get_pydantic_schema: OncePyObj,
 
     // strings
@@ -890,11 +1006,26 @@ pub(crate) struct State {
     pub(crate) str_relative_to: Owned<PyObj>,
     pub(crate) round_mode_strs: round::ModeStrs,
     pub(crate) str_format: Owned<PyObj>,
+    pub(crate) str_locale: Owned<PyObj>,
+    pub(crate) str_relative_day: Owned<PyObj>,
+    pub(crate) str_granularity: Owned<PyObj>,
+    pub(crate) str_style: Owned<PyObj>,
+    pub(crate) str_pattern: Owned<PyObj>,
+    pub(crate) str_strftime: Owned<PyObj>,
+    pub(crate) str_spreadsheet: Owned<PyObj>,
+    pub(crate) str_lenient: Owned<PyObj>,
+    pub(crate) str_strict: Owned<PyObj>,
+    pub(crate) str_zone_comment: Owned<PyObj>,
+    pub(crate) str_pivot_year: Owned<PyObj>,
     pub(crate) str_sep: Owned<PyObj>,
     pub(crate) str_space: Owned<PyObj>,
     pub(crate) str_t: Owned<PyObj>,
     pub(crate) str_auto: Owned<PyObj>,
     pub(crate) str_basic: Owned<PyObj>,
+    pub(crate) str_alternative: Owned<PyObj>,
+    pub(crate) str_epoch_1904: Owned<PyObj>,
+    pub(crate) str_epoch: Owned<PyObj>,
+    pub(crate) str_tag: Owned<PyObj>,
     pub(crate) str_always: Owned<PyObj>,
     pub(crate) str_never: Owned<PyObj>,
     pub(crate) str_offset_mismatch: Owned<PyObj>,
@@ -908,6 +1039,8 @@ pub(crate) struct State {
 
     pub(crate) time_patch: SyncCell<Patch>,
     pub(crate) tz_store: TzStore,
+    pub(crate) pattern_cache: SyncCell<PatternCache>,
+    pub(crate) humanize_locales: LocaleRegistry,
 }
 
 impl State {
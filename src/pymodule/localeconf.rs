@@ -0,0 +1,62 @@
+//! Functions in the `whenever` module that manage the humanize locales
+use crate::{
+    common::locale::{PluralRule, RelativeTime},
+    py::*,
+    pymodule::State,
+};
+
+/// The number of phrases in a flattened table: "now", 3 plural forms
+/// of 7 units for both future and past, and "yesterday", "tomorrow", "at time"
+const NUM_PHRASES: usize = 1 + 2 * 7 * 3 + 3;
+
+pub(crate) fn _register_humanize_locale(state: &State, args: &[PyObj]) -> PyReturn {
+    let &[lang_obj, plural_obj, phrases_obj] = args else {
+        raise_type_err("_register_humanize_locale() takes exactly 3 arguments")?
+    };
+    let lang_str = lang_obj
+        .cast_exact::<PyStr>()
+        .ok_or_type_err("language must be a string")?;
+    let lang = lang_str.as_str()?;
+    let plural = PluralRule::from_name(
+        plural_obj
+            .cast_exact::<PyStr>()
+            .ok_or_type_err("plural must be a string")?
+            .as_str()?,
+    )
+    .ok_or_else_value_err(|| format!("Invalid plural rule: {plural_obj}"))?;
+    let py_tuple = phrases_obj
+        .cast_exact::<PyTuple>()
+        .ok_or_type_err("phrases must be a tuple")?;
+    if py_tuple.len() as usize != NUM_PHRASES {
+        raise_value_err(format!("Expected {NUM_PHRASES} phrases"))?
+    }
+    let mut phrases = Vec::with_capacity(NUM_PHRASES);
+    for p in py_tuple.iter() {
+        phrases.push(Box::<str>::from(
+            p.cast_exact::<PyStr>()
+                .ok_or_type_err("phrase must be a string")?
+                .as_str()?,
+        ));
+    }
+    let mut it = phrases.into_iter();
+    let mut next = || it.next().unwrap();
+    let now = next();
+    let future = std::array::from_fn(|_| std::array::from_fn(|_| next()));
+    let past = std::array::from_fn(|_| std::array::from_fn(|_| next()));
+    let yesterday = next();
+    let tomorrow = next();
+    let at_time = next();
+    state.humanize_locales.set(
+        lang,
+        RelativeTime {
+            now,
+            yesterday,
+            tomorrow,
+            at_time,
+            plural,
+            future,
+            past,
+        },
+    );
+    Ok(none())
+}
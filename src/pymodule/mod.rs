@@ -1,6 +1,7 @@
 //! Functionality for declaring the `whenever` module in Python and its
 //! associated methods.
 pub(crate) mod def;
+mod localeconf;
 mod patch;
 mod tzconf;
 mod utils;
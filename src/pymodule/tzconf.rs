@@ -1,5 +1,10 @@
 //! Functions in the `whenever` module that manage the TZ cache and search path
-use crate::{py::*, pymodule::State};
+use crate::{
+    domain::scalar::EpochSecs,
+    py::*,
+    pymodule::State,
+    tz::{posix::TzStr, tzif::is_valid_key},
+};
 use std::path::PathBuf;
 
 pub(crate) fn _set_tzpath(state: &State, to: PyObj) -> PyReturn {
@@ -52,3 +57,43 @@ pub(crate) fn reset_system_tz(state: &State) -> PyReturn {
     state.tz_store.reset_system_tz()?;
     Ok(none())
 }
+
+pub(crate) fn _derive_tz(state: &State, args: &[PyObj]) -> PyReturn {
+    let &[key_obj, base_obj, start_obj, rule_obj] = args else {
+        raise_type_err("_derive_tz() takes exactly 4 arguments")?
+    };
+    let key_str = key_obj
+        .cast_allow_subclass::<PyStr>()
+        .ok_or_type_err("key must be a string")?;
+    let key = key_str.as_str()?;
+    if !is_valid_key(key) {
+        raise_value_err(format!("Invalid time zone key: {key_obj}"))?
+    }
+    let start = start_obj
+        .cast_allow_subclass::<PyInt>()
+        .ok_or_type_err("start must be an integer")?
+        .to_i64()
+        .ok()
+        .and_then(EpochSecs::new)
+        .ok_or_value_err("start is out of range")?;
+    let rule_pystr = rule_obj
+        .cast_allow_subclass::<PyStr>()
+        .ok_or_type_err("rule must be a string")?;
+    let rule = TzStr::parse(rule_pystr.as_str()?.as_bytes())
+        .ok_or_else_value_err(|| format!("Invalid POSIX TZ string: {rule_obj}"))?;
+    let derived = state
+        .tz_store
+        .obj_get(base_obj)?
+        .derive(key, start, rule)
+        .ok_or_value_err("Too many distinct time zone abbreviations")?;
+    state.tz_store.set_derived(derived);
+    Ok(none())
+}
+
+pub(crate) fn _remove_derived_tz(state: &State, key_obj: PyObj) -> PyReturn {
+    let key_str = key_obj
+        .cast_allow_subclass::<PyStr>()
+        .ok_or_type_err("key must be a string")?;
+    let key = key_str.as_str()?;
+    state.tz_store.remove_derived(key).to_py()
+}
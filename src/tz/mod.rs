@@ -2,3 +2,4 @@
 pub mod posix;
 pub mod store;
 pub mod tzif;
+pub mod zdump;
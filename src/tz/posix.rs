@@ -18,9 +18,10 @@ use std::num::{NonZeroU8, NonZeroU16};
 const DEFAULT_DST: OffsetDelta = OffsetDelta::new_unchecked(3_600);
 
 /// Result of a timezone metadata query.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct TzMetaResult {
     pub(crate) dst_saving: i32,
+    pub(crate) isdst: bool,
     pub(crate) abbrev: TzAbbrev,
 }
 
@@ -161,7 +162,7 @@ impl TzStr {
         }
     }
 
-    /// Timezone metadata: (dst_saving, abbreviation)
+    /// Timezone metadata: (dst_saving, isdst, abbreviation)
     pub(crate) fn meta_for_instant(&self, epoch: EpochSecs) -> TzMetaResult {
         match self.dst {
             Some(Dst {
@@ -170,10 +171,12 @@ impl TzStr {
                 ..
             }) if self.is_dst_at(epoch) => TzMetaResult {
                 dst_saving: dst_offset.get() - self.std.get(),
+                isdst: true,
                 abbrev: dst_abbrev,
             },
             _ => TzMetaResult {
                 dst_saving: 0,
+                isdst: false,
                 abbrev: self.std_abbrev,
             },
         }
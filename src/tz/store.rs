@@ -8,7 +8,10 @@ use std::{
     collections::VecDeque,
     fs,
     path::{Path, PathBuf},
-    sync::{Arc, RwLock, Weak},
+    sync::{
+        Arc, RwLock, Weak,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
 /// Timezone cache.
@@ -133,6 +136,12 @@ const LRU_CAPACITY: usize = 8; // this value seems to work well for Python's zon
 pub(crate) struct TzStore {
     // The zoneinfo timezone cache.
     cache: Cache,
+    // User-defined timezones derived from others. These take precedence over
+    // the cache, and are kept alive until explicitly removed.
+    derived: SyncCell<AHashMap<String, Arc<TimeZone>>>,
+    // Whether `derived` is non-empty. Most users never derive a timezone,
+    // so this lets lookups skip the (free-threaded) mutex entirely.
+    has_derived: AtomicBool,
     // The path to the `tzdata` Python package contents, if any.
     // Lazily initialized on first timezone lookup.
     tzdata_path: OncePyCell<Option<PathBuf>>,
@@ -150,6 +159,8 @@ impl TzStore {
     pub(crate) fn new(exc_notfound: PyObj) -> Self {
         Self {
             cache: Cache::new(),
+            derived: SyncCell::new(AHashMap::new()),
+            has_derived: AtomicBool::new(false),
             tzdata_path: OncePyCell::new(get_tzdata_path),
             paths: OncePyCell::new(init_paths),
             system_tz_cache: RwLock::new(None),
@@ -164,6 +175,9 @@ impl TzStore {
 
     /// Fetches the timezone definition for the given IANA time zone ID.
     pub(crate) fn get(&self, key: &str) -> PyResult<Arc<TimeZone>> {
+        if let Some(tz) = self.get_derived(key) {
+            return Ok(tz);
+        }
         self.cache
             .get_or_insert_with(key, || self.load_tzif(key))?
             .ok_or_else_raise(self.exc_notfound, || {
@@ -181,6 +195,32 @@ impl TzStore {
         )
     }
 
+    /// Register a derived timezone under the given key,
+    /// replacing any previous derived timezone with that key.
+    pub(crate) fn set_derived(&self, tz: TimeZone) {
+        let key = tz.key.clone().expect("derived timezones always have a key");
+        self.derived.with_mut(|d| {
+            d.insert(key, Arc::new(tz));
+            self.has_derived.store(true, Ordering::Release);
+        });
+    }
+
+    /// Remove a derived timezone. Returns whether it was present.
+    pub(crate) fn remove_derived(&self, key: &str) -> bool {
+        self.derived.with_mut(|d| {
+            let removed = d.remove(key).is_some();
+            self.has_derived.store(!d.is_empty(), Ordering::Release);
+            removed
+        })
+    }
+
+    fn get_derived(&self, key: &str) -> Option<Arc<TimeZone>> {
+        if !self.has_derived.load(Ordering::Acquire) {
+            return None;
+        }
+        self.derived.with(|d| d.get(key).map(Arc::clone))
+    }
+
     /// Retrieve the system timezone definition (cached for repeat calls).
     pub(crate) fn get_system_tz(&self) -> PyResult<Arc<TimeZone>> {
         // Fast path: clone the Arc under a read lock
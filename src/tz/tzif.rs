@@ -11,6 +11,8 @@ use std::{cmp::Ordering, fmt};
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct TransitionMeta {
     pub(crate) dst_saving: i32,
+    // The raw isdst flag, which may be set even if the offset doesn't change
+    pub(crate) isdst: bool,
     pub(crate) abbrev_idx: u8,
 }
 
@@ -132,13 +134,14 @@ impl TimeZone {
         })
     }
 
-    /// Get timezone metadata (dst_saving, abbreviation) at the given instant.
+    /// Get timezone metadata (dst_saving, isdst, abbreviation) at the given instant.
     pub(crate) fn meta_for_instant(&self, t: EpochSecs) -> TzMetaResult {
         bisect(&self.offsets_by_utc, t)
             .map(|i| {
                 let meta = &self.meta_by_utc[i.saturating_sub(1)];
                 TzMetaResult {
                     dst_saving: meta.dst_saving,
+                    isdst: meta.isdst,
                     abbrev: abbrev_from_data(&self.abbrev_data, meta.abbrev_idx),
                 }
             })
@@ -147,6 +150,7 @@ impl TimeZone {
                 let meta = self.meta_by_utc.last().unwrap();
                 TzMetaResult {
                     dst_saving: meta.dst_saving,
+                    isdst: meta.isdst,
                     abbrev: abbrev_from_data(&self.abbrev_data, meta.abbrev_idx),
                 }
             })
@@ -161,10 +165,14 @@ impl TimeZone {
 
     /// The previous UTC offset transition strictly before `t`, or None.
     pub(crate) fn prev_transition(&self, t: EpochSecs) -> Option<(EpochSecs, Offset)> {
-        // If past all recorded transitions, check POSIX first
+        // If past all recorded transitions, check POSIX first.
+        // Its result only counts if it's after the last recorded transition,
+        // since the rule doesn't apply before then (e.g. in derived zones).
+        let last = self.offsets_by_utc.last().map(|&(e, _)| e);
         if let Some(tz) = self.end
-            && self.offsets_by_utc.last().is_none_or(|&(last, _)| t > last)
+            && last.is_none_or(|last| t > last)
             && let Some(result) = tz.prev_transition(t)
+            && last.is_none_or(|last| result.0 > last)
         {
             return Some(result);
         }
@@ -174,6 +182,107 @@ impl TimeZone {
         (idx > 1).then(|| self.offsets_by_utc[idx - 1])
     }
 
+    /// The bounds of the period containing `t` during which the offset and
+    /// metadata stay the same. A transition exactly at `t` starts the period.
+    /// Transitions which change neither offset nor metadata are skipped.
+    pub(crate) fn period_for_instant(
+        &self,
+        t: EpochSecs,
+    ) -> (Option<EpochSecs>, Option<EpochSecs>) {
+        let offset = self.offset_for_instant(t);
+        let meta = self.meta_for_instant(t);
+        let unchanged =
+            |x: EpochSecs| self.offset_for_instant(x) == offset && self.meta_for_instant(x) == meta;
+
+        let mut start = self.prev_transition(t.saturating_add_i32(1));
+        while let Some((s, _)) = start
+            && unchanged(s.saturating_add_i32(-1))
+        {
+            start = self.prev_transition(s);
+        }
+        let mut end = self.next_transition(t);
+        while let Some((e, _)) = end
+            && unchanged(e)
+        {
+            end = self.next_transition(e);
+        }
+        (start.map(|(s, _)| s), end.map(|(e, _)| e))
+    }
+
+    /// Whether DST is in effect at any point in the range `[start, end)`.
+    pub(crate) fn has_dst_between(&self, start: EpochSecs, end: EpochSecs) -> bool {
+        let mut t = start;
+        loop {
+            if self.meta_for_instant(t).dst_saving != 0 {
+                return true;
+            }
+            match self.next_transition(t) {
+                Some((next, _)) if next < end => t = next,
+                _ => return false,
+            }
+        }
+    }
+
+    /// Derive a new timezone which follows this one until `start`,
+    /// and the given POSIX TZ rule from then on.
+    /// Returns None if the abbreviations don't fit the TZif-style index.
+    pub(crate) fn derive(&self, key: &str, start: EpochSecs, rule: TzStr) -> Option<Self> {
+        let mut abbrev_data = self.abbrev_data.clone();
+        let keep = self.offsets_by_utc.partition_point(|&(t, _)| t < start);
+        let mut offsets_by_utc = self.offsets_by_utc[..keep].to_vec();
+        let mut meta_by_utc = self.meta_by_utc[..keep].to_vec();
+
+        // If `start` lies beyond the explicit transitions, the POSIX tail
+        // defines the transitions in between. These need to be made explicit,
+        // since the tail itself is replaced by the new rule.
+        if let Some(tail) = self.end
+            && keep == self.offsets_by_utc.len()
+        {
+            if offsets_by_utc.is_empty() {
+                let meta = tail.meta_for_instant(EpochSecs::MIN);
+                offsets_by_utc.push((EpochSecs::MIN, tail.offset_for_instant(EpochSecs::MIN)));
+                meta_by_utc.push(TransitionMeta {
+                    dst_saving: meta.dst_saving,
+                    isdst: meta.isdst,
+                    abbrev_idx: intern_abbrev(&mut abbrev_data, meta.abbrev)?,
+                });
+            }
+            // SAFETY: we've ensured there's at least one entry
+            let mut t = offsets_by_utc.last().unwrap().0;
+            while let Some((next, offset)) = tail.next_transition(t)
+                && next < start
+            {
+                let meta = tail.meta_for_instant(next);
+                offsets_by_utc.push((next, offset));
+                meta_by_utc.push(TransitionMeta {
+                    dst_saving: meta.dst_saving,
+                    isdst: meta.isdst,
+                    abbrev_idx: intern_abbrev(&mut abbrev_data, meta.abbrev)?,
+                });
+                t = next;
+            }
+        }
+
+        // The new rule takes effect at `start`. This entry is needed even if
+        // the offset doesn't change, since it marks where the rule begins.
+        let meta = rule.meta_for_instant(start);
+        offsets_by_utc.push((start, rule.offset_for_instant(start)));
+        meta_by_utc.push(TransitionMeta {
+            dst_saving: meta.dst_saving,
+            isdst: meta.isdst,
+            abbrev_idx: intern_abbrev(&mut abbrev_data, meta.abbrev)?,
+        });
+
+        Some(Self {
+            key: Some(key.to_string()),
+            offsets_by_local: local_transitions(&offsets_by_utc),
+            offsets_by_utc,
+            end: Some(rule),
+            meta_by_utc,
+            abbrev_data,
+        })
+    }
+
     pub fn parse_tzif(s: &[u8], key: Option<&str>) -> ParseResult<Self> {
         let mut scan = Scan::new(s);
         let header = parse_header(&mut scan).ok_or(ErrorCause::Header)?;
@@ -387,6 +496,7 @@ fn load_transitions(
         // For the first entry (before all transitions), dst_saving is 0 since
         // types[0] is always a non-DST type (pre-standard LMT or similar).
         dst_saving: 0,
+        isdst: first_type.isdst,
         abbrev_idx: first_type.abbrev_idx,
     });
 
@@ -406,6 +516,7 @@ fn load_transitions(
 
         meta.push(TransitionMeta {
             dst_saving,
+            isdst: typ.isdst,
             abbrev_idx: typ.abbrev_idx,
         });
     }
@@ -426,6 +537,22 @@ fn abbrev_from_data(data: &[u8], idx: u8) -> TzAbbrev {
     TzAbbrev::from_bytes(&data[start..end]).unwrap_or(TzAbbrev::EMPTY)
 }
 
+/// Find the index of the abbreviation in the NUL-terminated data,
+/// appending it if it isn't present yet.
+fn intern_abbrev(data: &mut Vec<u8>, abbrev: TzAbbrev) -> Option<u8> {
+    let bytes = abbrev.as_bytes();
+    // NOTE: like in TZif files, an entry may be the suffix of another one
+    let idx = (0..data.len())
+        .find(|&i| data[i..].starts_with(bytes) && data.get(i + bytes.len()) == Some(&0))
+        .unwrap_or_else(|| {
+            let i = data.len();
+            data.extend_from_slice(bytes);
+            data.push(0);
+            i
+        });
+    u8::try_from(idx).ok()
+}
+
 fn parse_posix_tz(s: &mut Scan) -> Option<TzStr> {
     TzStr::parse(match s.take_until(|b| b == b'\n') {
         Some(x) => x,
@@ -1037,6 +1164,220 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_derive() {
+        const TZ_AMS: &[u8] = include_bytes!("../../tests/tzif/Amsterdam.tzif");
+        let tzif = TimeZone::parse_tzif(TZ_AMS, Some("Europe/Amsterdam")).unwrap();
+        let start = EpochSecs::ymdhms(2027, 3, 1, 0, 0, 0);
+        let derived = tzif
+            .derive("Custom/NoDST", start, TzStr::parse(b"CET-1").unwrap())
+            .unwrap();
+        assert_eq!(derived.key.as_deref(), Some("Custom/NoDST"));
+
+        // Unchanged before the start, including the transitions
+        // that were previously defined by the POSIX tail
+        for t in [
+            EpochSecs::ymdhms(1916, 5, 1, 0, 0, 0),
+            EpochSecs::ymdhms(1992, 8, 31, 23, 5, 0),
+            EpochSecs::ymdhms(2026, 3, 29, 0, 59, 59),
+            EpochSecs::ymdhms(2026, 3, 29, 1, 0, 0),
+            EpochSecs::ymdhms(2026, 10, 25, 1, 0, 0),
+            EpochSecs::ymdhms(2027, 2, 28, 23, 59, 59),
+        ] {
+            assert_eq!(derived.offset_for_instant(t), tzif.offset_for_instant(t));
+            assert_eq!(
+                derived.meta_for_instant(t).abbrev,
+                tzif.meta_for_instant(t).abbrev
+            );
+        }
+        assert_eq!(
+            mapping(&derived, EpochSecs::ymdhms(2026, 3, 29, 2, 30, 0)),
+            mapping(&tzif, EpochSecs::ymdhms(2026, 3, 29, 2, 30, 0)),
+        );
+
+        // No more DST after the start
+        assert_eq!(
+            derived.offset_for_instant(EpochSecs::ymdhms(2027, 7, 1, 0, 0, 0)),
+            Offset::hhmm(1, 0)
+        );
+        assert_eq!(
+            mapping(&derived, EpochSecs::ymdhms(2027, 3, 28, 2, 30, 0)),
+            unique(Offset::hhmm(1, 0))
+        );
+        assert_eq!(
+            derived
+                .meta_for_instant(EpochSecs::ymdhms(2030, 7, 1, 0, 0, 0))
+                .abbrev,
+            TzAbbrev::from_bytes(b"CET").unwrap()
+        );
+        assert_eq!(
+            derived.next_transition(EpochSecs::ymdhms(2027, 1, 1, 0, 0, 0)),
+            Some((start, Offset::hhmm(1, 0)))
+        );
+        assert_eq!(
+            derived.next_transition(EpochSecs::ymdhms(2027, 3, 1, 0, 0, 0)),
+            None
+        );
+        assert_eq!(
+            derived.prev_transition(EpochSecs::ymdhms(2027, 3, 1, 0, 0, 1)),
+            Some((start, Offset::hhmm(1, 0)))
+        );
+        assert_eq!(
+            derived.prev_transition(start),
+            Some((EpochSecs::ymdhms(2026, 10, 25, 1, 0, 0), Offset::hhmm(1, 0)))
+        );
+
+        // Starting in the middle of the explicit transitions, with a new DST rule
+        let derived = tzif
+            .derive(
+                "Custom/Other",
+                EpochSecs::ymdhms(1990, 1, 1, 0, 0, 0),
+                TzStr::parse(b"XST-3XDT,M4.1.0,M9.1.0").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            derived.offset_for_instant(EpochSecs::ymdhms(1989, 12, 31, 22, 59, 59)),
+            Offset::hhmm(1, 0)
+        );
+        assert_eq!(
+            derived.offset_for_instant(EpochSecs::ymdhms(1990, 1, 1, 0, 0, 0)),
+            Offset::hhmm(3, 0)
+        );
+        assert_eq!(
+            derived.offset_for_instant(EpochSecs::ymdhms(1990, 7, 1, 0, 0, 0)),
+            Offset::hhmm(4, 0)
+        );
+        assert_eq!(
+            derived.meta_for_instant(EpochSecs::ymdhms(2025, 7, 1, 0, 0, 0)),
+            TzMetaResult {
+                dst_saving: 3_600,
+                isdst: true,
+                abbrev: TzAbbrev::from_bytes(b"XDT").unwrap(),
+            }
+        );
+        // The switch from +01:00 to +03:00 creates a gap
+        assert_eq!(
+            mapping(&derived, EpochSecs::ymdhms(1990, 1, 1, 2, 0, 0)),
+            gap_at(
+                EpochSecs::ymdhms(1990, 1, 1, 3, 0, 0),
+                Offset::hhmm(3, 0),
+                Offset::hhmm(1, 0),
+            )
+        );
+        // Rule transitions from before the start don't count
+        assert_eq!(
+            derived.prev_transition(EpochSecs::ymdhms(1990, 2, 1, 0, 0, 0)),
+            Some((EpochSecs::ymdhms(1990, 1, 1, 0, 0, 0), Offset::hhmm(3, 0)))
+        );
+        assert_eq!(
+            derived.prev_transition(EpochSecs::ymdhms(1990, 5, 1, 0, 0, 0)),
+            Some((EpochSecs::ymdhms(1990, 3, 31, 23, 0, 0), Offset::hhmm(4, 0)))
+        );
+        assert_eq!(
+            derived.period_for_instant(EpochSecs::ymdhms(1990, 2, 1, 0, 0, 0)),
+            (
+                Some(EpochSecs::ymdhms(1990, 1, 1, 0, 0, 0)),
+                Some(EpochSecs::ymdhms(1990, 3, 31, 23, 0, 0))
+            )
+        );
+    }
+
+    #[test]
+    fn test_derive_posix_only() {
+        let tz = TimeZone::parse_posix("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let derived = tz
+            .derive(
+                "Custom/Fixed",
+                EpochSecs::ymdhms(2000, 1, 1, 0, 0, 0),
+                TzStr::parse(b"<+0530>-5:30").unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            derived.offset_for_instant(EpochSecs::ymdhms(1999, 7, 1, 0, 0, 0)),
+            Offset::hhmm(2, 0)
+        );
+        assert_eq!(
+            derived.offset_for_instant(EpochSecs::ymdhms(1899, 7, 1, 0, 0, 0)),
+            Offset::hhmm(2, 0)
+        );
+        assert_eq!(
+            derived.offset_for_instant(EpochSecs::ymdhms(2000, 7, 1, 0, 0, 0)),
+            Offset::hhmm(5, 30)
+        );
+        assert_eq!(
+            derived
+                .meta_for_instant(EpochSecs::ymdhms(2000, 7, 1, 0, 0, 0))
+                .abbrev,
+            TzAbbrev::from_bytes(b"+0530").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_period_for_instant() {
+        const TZ_AMS: &[u8] = include_bytes!("../../tests/tzif/Amsterdam.tzif");
+        let tzif = TimeZone::parse_tzif(TZ_AMS, Some("Europe/Amsterdam")).unwrap();
+        let spring = EpochSecs::ymdhms(2024, 3, 31, 1, 0, 0);
+        let autumn = EpochSecs::ymdhms(2024, 10, 27, 1, 0, 0);
+        let spring_next = EpochSecs::ymdhms(2025, 3, 30, 1, 0, 0);
+
+        // Explicit transitions and the POSIX tail
+        for t in [
+            spring,
+            EpochSecs::ymdhms(2024, 7, 1, 0, 0, 0),
+            autumn.saturating_add_i32(-1),
+        ] {
+            assert_eq!(tzif.period_for_instant(t), (Some(spring), Some(autumn)));
+        }
+        assert_eq!(
+            tzif.period_for_instant(autumn),
+            (Some(autumn), Some(spring_next))
+        );
+        assert_eq!(
+            tzif.period_for_instant(EpochSecs::ymdhms(2200, 1, 1, 0, 0, 0)),
+            (
+                Some(EpochSecs::ymdhms(2199, 10, 27, 1, 0, 0)),
+                Some(EpochSecs::ymdhms(2200, 3, 30, 1, 0, 0))
+            )
+        );
+        // Unbounded start before the first transition
+        assert_eq!(
+            tzif.period_for_instant(EpochSecs::ymdhms(1800, 1, 1, 0, 0, 0))
+                .0,
+            None
+        );
+
+        // Fixed offsets have no bounds, and no DST
+        let fixed = TimeZone::parse_posix("UTC0").unwrap();
+        assert_eq!(fixed.period_for_instant(spring), (None, None));
+        assert!(!fixed.has_dst_between(EpochSecs::MIN, EpochSecs::MAX));
+
+        assert!(tzif.has_dst_between(
+            EpochSecs::ymdhms(2024, 1, 1, 0, 0, 0),
+            EpochSecs::ymdhms(2025, 1, 1, 0, 0, 0)
+        ));
+        assert!(tzif.has_dst_between(autumn.saturating_add_i32(-1), autumn));
+        assert!(!tzif.has_dst_between(autumn, spring_next));
+        // No DST in Amsterdam between 1946 and 1977
+        assert!(!tzif.has_dst_between(
+            EpochSecs::ymdhms(1960, 1, 1, 0, 0, 0),
+            EpochSecs::ymdhms(1961, 1, 1, 0, 0, 0)
+        ));
+    }
+
+    #[test]
+    fn test_intern_abbrev() {
+        let mut data = b"LMT\0CEST\0".to_vec();
+        let abbrev = |s: &[u8]| TzAbbrev::from_bytes(s).unwrap();
+        assert_eq!(intern_abbrev(&mut data, abbrev(b"LMT")), Some(0));
+        assert_eq!(intern_abbrev(&mut data, abbrev(b"CEST")), Some(4));
+        // suffix of an existing entry
+        assert_eq!(intern_abbrev(&mut data, abbrev(b"EST")), Some(5));
+        // new entry
+        assert_eq!(intern_abbrev(&mut data, abbrev(b"CET")), Some(9));
+        assert_eq!(data, b"LMT\0CEST\0CET\0");
+        assert_eq!(abbrev_from_data(&data, 9), abbrev(b"CET"));
+    }
+
     /// Smoke test to see we don't crash parsing any TZif files in the tzdata database.
     /// It doesn't actually check whether the parsing is correct,
     /// but will give a good indication if the parser is robust.
@@ -0,0 +1,214 @@
+//! Transition dumps in the format of `zdump -v`.
+//!
+//! This allows diffing our interpretation of TZif files (including the
+//! POSIX TZ tail) against the system's, byte for byte.
+//! Like zdump, `isdst` is the raw flag from the TZif file, which may be
+//! set even if there's no DST saving (e.g. America/Cancun in 1998).
+use crate::{
+    common::locale::EN,
+    domain::{scalar::*, time::Time},
+    tz::{posix::TzAbbrev, tzif::TimeZone},
+};
+use std::fmt::{self, Write};
+
+// zdump probes the extremes of a 64-bit time_t, for which localtime() fails.
+const NULL_PROBES_LOW: [i64; 2] = [i64::MIN, i64::MIN + S_PER_DAY as i64];
+const NULL_PROBES_HIGH: [i64; 2] = [i64::MAX - S_PER_DAY as i64, i64::MAX];
+
+/// Dump the transitions of `tz` between the start of year `lo` and the start
+/// of year `hi` (UTC), in the same format as `zdump -v -c lo,hi <name>`.
+/// Years outside of the supported range are clamped.
+pub fn zdump(tz: &TimeZone, name: &str, lo: i32, hi: i32) -> String {
+    let mut out = String::new();
+    // Writing to a String never fails
+    write_zdump(&mut out, tz, name, year_start(lo), year_start(hi)).unwrap();
+    out
+}
+
+fn write_zdump(
+    out: &mut impl Write,
+    tz: &TimeZone,
+    name: &str,
+    lo: EpochSecs,
+    hi: EpochSecs,
+) -> fmt::Result {
+    for t in NULL_PROBES_LOW {
+        writeln!(out, "{name}  {t} = NULL")?;
+    }
+    let mut t = lo;
+    while let Some((next, _)) = tz.next_transition(t)
+        && next < hi
+    {
+        let before = next.saturating_add_i32(-1);
+        // Like zdump, only show transitions which change anything visible
+        if observed(tz, before) != observed(tz, next) {
+            write_line(out, tz, name, before)?;
+            write_line(out, tz, name, next)?;
+        }
+        t = next;
+    }
+    for t in NULL_PROBES_HIGH {
+        writeln!(out, "{name}  {t} = NULL")?;
+    }
+    Ok(())
+}
+
+/// The properties of a timezone at an instant which zdump distinguishes
+fn observed(tz: &TimeZone, t: EpochSecs) -> (Offset, bool, TzAbbrev) {
+    let meta = tz.meta_for_instant(t);
+    (tz.offset_for_instant(t), meta.isdst, meta.abbrev)
+}
+
+fn write_line(out: &mut impl Write, tz: &TimeZone, name: &str, t: EpochSecs) -> fmt::Result {
+    let offset = tz.offset_for_instant(t);
+    let meta = tz.meta_for_instant(t);
+    write!(out, "{name}  ")?;
+    write_asctime(out, t)?;
+    write!(out, " UT = ")?;
+    write_asctime(out, t.saturating_shift_by_offset(offset))?;
+    writeln!(
+        out,
+        // SAFETY: TzAbbrev always contains valid ASCII bytes
+        " {} isdst={} gmtoff={}",
+        unsafe { std::str::from_utf8_unchecked(meta.abbrev.as_bytes()) },
+        meta.isdst as u8,
+        offset.get()
+    )
+}
+
+/// Write a time in the format of C's `asctime()`, without the newline
+fn write_asctime(out: &mut impl Write, t: EpochSecs) -> fmt::Result {
+    let dt = t.datetime(SubSecNanos::MIN);
+    let date = dt.date;
+    write!(
+        out,
+        "{} {} {:>2} {:02}:{:02}:{:02} {}",
+        EN.weekday_abbr[date.day_of_week() as usize - 1],
+        EN.month_abbr[date.month as usize - 1],
+        date.day,
+        dt.time.hour,
+        dt.time.minute,
+        dt.time.second,
+        date.year.get()
+    )
+}
+
+fn year_start(year: i32) -> EpochSecs {
+    if year < Year::MIN.get() as i32 {
+        EpochSecs::MIN
+    } else {
+        u16::try_from(year)
+            .ok()
+            .and_then(Year::new)
+            .map_or(EpochSecs::MAX, |y| {
+                y.unix_days_at_jan1().epoch_at(Time::MIN)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zdump() {
+        const TZ_AMS: &[u8] = include_bytes!("../../tests/tzif/Amsterdam.tzif");
+        let tz = TimeZone::parse_tzif(TZ_AMS, Some("Europe/Amsterdam")).unwrap();
+        // Output of `zdump -v -c 2023,2025 Europe/Amsterdam`
+        assert_eq!(
+            zdump(&tz, "Europe/Amsterdam", 2023, 2025),
+            "\
+Europe/Amsterdam  -9223372036854775808 = NULL
+Europe/Amsterdam  -9223372036854689408 = NULL
+Europe/Amsterdam  Sun Mar 26 00:59:59 2023 UT = Sun Mar 26 01:59:59 2023 CET isdst=0 gmtoff=3600
+Europe/Amsterdam  Sun Mar 26 01:00:00 2023 UT = Sun Mar 26 03:00:00 2023 CEST isdst=1 gmtoff=7200
+Europe/Amsterdam  Sun Oct 29 00:59:59 2023 UT = Sun Oct 29 02:59:59 2023 CEST isdst=1 gmtoff=7200
+Europe/Amsterdam  Sun Oct 29 01:00:00 2023 UT = Sun Oct 29 02:00:00 2023 CET isdst=0 gmtoff=3600
+Europe/Amsterdam  Sun Mar 31 00:59:59 2024 UT = Sun Mar 31 01:59:59 2024 CET isdst=0 gmtoff=3600
+Europe/Amsterdam  Sun Mar 31 01:00:00 2024 UT = Sun Mar 31 03:00:00 2024 CEST isdst=1 gmtoff=7200
+Europe/Amsterdam  Sun Oct 27 00:59:59 2024 UT = Sun Oct 27 02:59:59 2024 CEST isdst=1 gmtoff=7200
+Europe/Amsterdam  Sun Oct 27 01:00:00 2024 UT = Sun Oct 27 02:00:00 2024 CET isdst=0 gmtoff=3600
+Europe/Amsterdam  9223372036854689407 = NULL
+Europe/Amsterdam  9223372036854775807 = NULL
+"
+        );
+        // The fixture is identical to Europe/Brussels in recent tzdata.
+        // Output of `zdump -v -c 1916,1917 Europe/Brussels`
+        assert_eq!(
+            zdump(&tz, "Europe/Brussels", 1916, 1917),
+            "\
+Europe/Brussels  -9223372036854775808 = NULL
+Europe/Brussels  -9223372036854689408 = NULL
+Europe/Brussels  Sun Apr 30 22:59:59 1916 UT = Sun Apr 30 23:59:59 1916 CET isdst=0 gmtoff=3600
+Europe/Brussels  Sun Apr 30 23:00:00 1916 UT = Mon May  1 01:00:00 1916 CEST isdst=1 gmtoff=7200
+Europe/Brussels  Sat Sep 30 22:59:59 1916 UT = Sun Oct  1 00:59:59 1916 CEST isdst=1 gmtoff=7200
+Europe/Brussels  Sat Sep 30 23:00:00 1916 UT = Sun Oct  1 00:00:00 1916 CET isdst=0 gmtoff=3600
+Europe/Brussels  9223372036854689407 = NULL
+Europe/Brussels  9223372036854775807 = NULL
+"
+        );
+    }
+
+    #[test]
+    fn test_zdump_isdst_without_saving() {
+        const TZ_CANCUN: &[u8] = include_bytes!("../../tests/tzif/Cancun.tzif");
+        let tz = TimeZone::parse_tzif(TZ_CANCUN, Some("America/Cancun")).unwrap();
+        // Output of `zdump -v -c 1998,1999 America/Cancun`.
+        // CDT is flagged as DST, although its offset equals the preceding EST.
+        assert_eq!(
+            zdump(&tz, "America/Cancun", 1998, 1999),
+            "\
+America/Cancun  -9223372036854775808 = NULL
+America/Cancun  -9223372036854689408 = NULL
+America/Cancun  Sun Apr  5 06:59:59 1998 UT = Sun Apr  5 01:59:59 1998 EST isdst=0 gmtoff=-18000
+America/Cancun  Sun Apr  5 07:00:00 1998 UT = Sun Apr  5 03:00:00 1998 EDT isdst=1 gmtoff=-14400
+America/Cancun  Sun Aug  2 05:59:59 1998 UT = Sun Aug  2 01:59:59 1998 EDT isdst=1 gmtoff=-14400
+America/Cancun  Sun Aug  2 06:00:00 1998 UT = Sun Aug  2 01:00:00 1998 CDT isdst=1 gmtoff=-18000
+America/Cancun  Sun Oct 25 06:59:59 1998 UT = Sun Oct 25 01:59:59 1998 CDT isdst=1 gmtoff=-18000
+America/Cancun  Sun Oct 25 07:00:00 1998 UT = Sun Oct 25 01:00:00 1998 CST isdst=0 gmtoff=-21600
+America/Cancun  9223372036854689407 = NULL
+America/Cancun  9223372036854775807 = NULL
+"
+        );
+        const TZ_BA: &[u8] = include_bytes!("../../tests/tzif/Buenos_Aires.tzif");
+        let tz = TimeZone::parse_tzif(TZ_BA, Some("America/Argentina/Buenos_Aires")).unwrap();
+        // Output of `zdump -v -c 1999,2001 America/Argentina/Buenos_Aires`.
+        // Only the isdst flag changes here, not the offset or abbreviation.
+        assert_eq!(
+            zdump(&tz, "America/Argentina/Buenos_Aires", 1999, 2001),
+            "\
+America/Argentina/Buenos_Aires  -9223372036854775808 = NULL
+America/Argentina/Buenos_Aires  -9223372036854689408 = NULL
+America/Argentina/Buenos_Aires  Sun Oct  3 02:59:59 1999 UT = Sat Oct  2 23:59:59 1999 -03 isdst=0 gmtoff=-10800
+America/Argentina/Buenos_Aires  Sun Oct  3 03:00:00 1999 UT = Sun Oct  3 00:00:00 1999 -03 isdst=1 gmtoff=-10800
+America/Argentina/Buenos_Aires  Fri Mar  3 02:59:59 2000 UT = Thu Mar  2 23:59:59 2000 -03 isdst=1 gmtoff=-10800
+America/Argentina/Buenos_Aires  Fri Mar  3 03:00:00 2000 UT = Fri Mar  3 00:00:00 2000 -03 isdst=0 gmtoff=-10800
+America/Argentina/Buenos_Aires  9223372036854689407 = NULL
+America/Argentina/Buenos_Aires  9223372036854775807 = NULL
+"
+        );
+    }
+
+    #[test]
+    fn test_zdump_no_transitions() {
+        let tz = TimeZone::parse_posix("UTC0").unwrap();
+        assert_eq!(
+            zdump(&tz, "UTC", 2023, 2025),
+            "\
+UTC  -9223372036854775808 = NULL
+UTC  -9223372036854689408 = NULL
+UTC  9223372036854689407 = NULL
+UTC  9223372036854775807 = NULL
+"
+        );
+    }
+
+    #[test]
+    fn test_year_start() {
+        assert_eq!(year_start(1970), EpochSecs::new(0).unwrap());
+        assert_eq!(year_start(0), EpochSecs::MIN);
+        assert_eq!(year_start(-5), EpochSecs::MIN);
+        assert_eq!(year_start(10_000), EpochSecs::MAX);
+        assert_eq!(year_start(100_000), EpochSecs::MAX);
+    }
+}
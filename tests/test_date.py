@@ -215,6 +215,19 @@ class TestParseIso:
             ("20000101", Date(2000, 1, 1)),
             ("20150902", Date(2015, 9, 2)),
             ("99991231", Date(9999, 12, 31)),
+            # Ordinal dates
+            ("2021-344", Date(2021, 12, 10)),
+            ("2021344", Date(2021, 12, 10)),
+            ("0001-001", Date(1, 1, 1)),
+            ("2024-366", Date(2024, 12, 31)),
+            ("9999365", Date(9999, 12, 31)),
+            # Week dates
+            ("2021-W13-4", Date(2021, 4, 1)),
+            ("2021W134", Date(2021, 4, 1)),
+            ("2025-W01-1", Date(2024, 12, 30)),
+            ("2020-W53-7", Date(2021, 1, 3)),
+            ("0001-W01-1", Date(1, 1, 1)),
+            ("9999W525", Date(9999, 12, 31)),
         ],
     )
     def test_valid(self, s, expected):
@@ -269,11 +282,20 @@ class TestParseIso:
             "1989-W53",
             "1989-W22-8",
             "1989-W22-0",
-            # Week and ordinal not implemented
+            "2021-W53-1",  # 2021 has no week 53
+            "9999-W52-6",  # out of range
+            "2021-W00-1",
+            "2021-000",
+            "2021000",
+            "2021-367",
+            "0000-001",
+            "0000-W01-1",
+            # wrong padding in week and ordinal dates
             "2021-W01-01",
-            "2021-344",
-            "2021W134",
-            "2021214",
+            "2021-W1-1",
+            "2021-34",
+            "2021-0344",
+            "202134",
             # incomplete
             "2021-01",
             "202101",
@@ -1654,3 +1676,97 @@ class TestEndOf:
             Date.MAX.end_of(unit)
         except (ValueError, OverflowError):
             pass
+
+
+class TestDayCounts:
+
+    @pytest.mark.parametrize(
+        "d, jd, mjd, rd",
+        [
+            (Date(2000, 1, 1), 2_451_545, 51_544, 730_120),
+            (Date(1970, 1, 1), 2_440_588, 40_587, 719_163),
+            (Date(1858, 11, 17), 2_400_001, 0, 678_576),
+            (Date(1, 1, 1), 1_721_426, -678_575, 1),
+            (Date(9999, 12, 31), 5_373_484, 2_973_483, 3_652_059),
+        ],
+    )
+    def test_known_values(self, d, jd, mjd, rd):
+        assert d.to_julian_day() == jd
+        assert d.to_mjd() == mjd
+        assert d.to_rata_die() == rd
+        assert Date.from_julian_day(jd) == d
+        assert Date.from_mjd(mjd) == d
+        assert Date.from_rata_die(rd) == d
+
+    @pytest.mark.parametrize(
+        "d, serial",
+        [
+            (Date(2024, 3, 15), 45_366),
+            (Date(1970, 1, 1), 25_569),
+            (Date(1900, 3, 1), 61),
+            (Date(1900, 2, 28), 59),
+            (Date(1900, 1, 1), 1),
+            (Date(1899, 12, 31), 0),
+        ],
+    )
+    def test_excel_1900(self, d, serial):
+        assert d.to_excel_serial() == serial
+        assert Date.from_excel_serial(serial) == d
+
+    def test_excel_1900_leap_year_bug(self):
+        with pytest.raises(ValueError, match="60"):
+            Date.from_excel_serial(60)
+
+    @pytest.mark.parametrize(
+        "d, serial",
+        [
+            (Date(2024, 3, 15), 43_904),
+            (Date(1904, 1, 1), 0),
+            (Date(1900, 2, 28), -1_402),
+        ],
+    )
+    def test_excel_1904(self, d, serial):
+        assert d.to_excel_serial(epoch_1904=True) == serial
+        assert Date.from_excel_serial(serial, epoch_1904=True) == d
+
+    @pytest.mark.parametrize(
+        "method",
+        [
+            Date.from_julian_day,
+            Date.from_mjd,
+            Date.from_rata_die,
+            Date.from_excel_serial,
+        ],
+    )
+    def test_out_of_range(self, method):
+        with pytest.raises(ValueError):
+            method(0 if method is Date.from_rata_die else -10_000_000)
+        with pytest.raises(ValueError):
+            method(10_000_000)
+        with pytest.raises(ValueError):
+            method(1 << 80)
+        with pytest.raises(ValueError):
+            method(-(10**30))
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Date.from_mjd(1.5)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            Date.from_julian_day("2451545")  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            Date(2024, 1, 1).to_excel_serial(epoch_1904=1)  # type: ignore
+        with pytest.raises(TypeError):
+            Date(2024, 1, 1).to_excel_serial(foo=True)  # type: ignore
+
+    def test_roundtrip(self):
+        for d in [Date.MIN, Date(1900, 2, 27), Date(2020, 2, 29), Date.MAX]:
+            assert Date.from_julian_day(d.to_julian_day()) == d
+            assert Date.from_mjd(d.to_mjd()) == d
+            assert Date.from_rata_die(d.to_rata_die()) == d
+            assert Date.from_excel_serial(d.to_excel_serial()) == d
+            assert (
+                Date.from_excel_serial(
+                    d.to_excel_serial(epoch_1904=True), epoch_1904=True
+                )
+                == d
+            )
@@ -9,6 +9,7 @@ from whenever import (
     DateDelta,
     DateTimeDelta,
     OffsetDateTime,
+    ParseError,
     Time,
     TimeDelta,
     WheneverDeprecationWarning,
@@ -248,11 +249,90 @@ class TestParseIso:
         ):
             DateDelta.parse_iso(s)
 
+    @pytest.mark.parametrize(
+        "s, position, expected",
+        [
+            ("P1X", 2, "unit"),
+            ("P", 1, "number"),
+            ("P1M1Y", 4, "unit"),
+            ("P0001-13-01", 6, "month"),
+            ("P1DT1H", 3, None),
+        ],
+    )
+    def test_invalid_position(self, s, position, expected):
+        with pytest.raises(ParseError) as e:
+            DateDelta.parse_iso(s)
+        assert e.value.position == position
+        assert e.value.expected == expected
+
     def test_invalid_type(self):
         with pytest.raises(TypeError):
             DateDelta.parse_iso(1)  # type: ignore[arg-type]
 
 
+class TestAlternativeFormat:
+    @pytest.mark.parametrize(
+        "s, expect",
+        [
+            ("P0001-02-03", make_ddelta(years=1, months=2, days=3)),
+            ("P00010203", make_ddelta(years=1, months=2, days=3)),
+            ("P0000-00-00", make_ddelta()),
+            ("P0000-12-30", make_ddelta(months=12, days=30)),
+            ("-P0002-00-07", make_ddelta(years=-2, weeks=-1)),
+            ("+p9998-00-00", make_ddelta(years=9998)),
+        ],
+    )
+    def test_parse(self, s, expect):
+        assert DateDelta.parse_iso(s) == expect
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "P0000-13-00",
+            "P0000-00-31",
+            "P9999-12-30",
+            "P001-02-03",
+            "P0001-02-3",
+            "P0001-0203",
+            "P000102-03",
+            "P0001-02-03T00:00:00",
+            "P00010203T000000",
+            "PT01:00:00",
+            "P0001",
+            "P٠٠٠١-٠٢-٠٣",
+        ],
+    )
+    def test_parse_invalid(self, s):
+        with pytest.raises(ValueError, match="Invalid format|range"):
+            DateDelta.parse_iso(s)
+
+    @pytest.mark.parametrize(
+        "d, extended, basic",
+        [
+            (make_ddelta(), "P0000-00-00", "P00000000"),
+            (
+                make_ddelta(years=1, months=2, days=3),
+                "P0001-02-03",
+                "P00010203",
+            ),
+            (make_ddelta(months=-14, days=-30), "-P0001-02-30", "-P00010230"),
+        ],
+    )
+    def test_format(self, d, extended, basic):
+        assert d.format_iso(alternative=True) == extended
+        assert d.format_iso(alternative=True, basic=True) == basic
+        assert DateDelta.parse_iso(extended) == d
+        assert DateDelta.parse_iso(basic) == d
+
+    def test_format_too_many_days(self):
+        with pytest.raises(ValueError, match="30 days"):
+            make_ddelta(weeks=5).format_iso(alternative=True)
+
+    def test_basic_requires_alternative(self):
+        with pytest.raises(ValueError, match="alternative"):
+            make_ddelta(days=1).format_iso(basic=True)
+
+
 @pytest.mark.parametrize(
     "p, expect",
     [
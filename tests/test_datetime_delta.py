@@ -8,6 +8,7 @@ import pytest
 from whenever import (
     DateDelta,
     DateTimeDelta,
+    ParseError,
     TimeDelta,
     WheneverDeprecationWarning,
 )
@@ -361,6 +362,106 @@ class TestParseIso:
         ):
             DateTimeDelta.parse_iso(s)
 
+    @pytest.mark.parametrize(
+        "s, position, expected",
+        [
+            ("P1D1H", 3, "T"),
+            ("P1DT", 4, "number"),
+            ("PT1H2H", 5, "unit"),
+            ("P0001-01-01T010000", 14, ":"),
+        ],
+    )
+    def test_invalid_position(self, s, position, expected):
+        with pytest.raises(ParseError) as e:
+            DateTimeDelta.parse_iso(s)
+        assert e.value.position == position
+        assert e.value.expected == expected
+
+
+class TestAlternativeFormat:
+    @pytest.mark.parametrize(
+        "s, expect",
+        [
+            (
+                "P0001-02-03T04:05:06",
+                make_dtdelta(
+                    years=1, months=2, days=3, hours=4, minutes=5, seconds=6
+                ),
+            ),
+            (
+                "P00010203T040506",
+                make_dtdelta(
+                    years=1, months=2, days=3, hours=4, minutes=5, seconds=6
+                ),
+            ),
+            ("P0000-01-00", make_dtdelta(months=1)),
+            ("PT00:00:01.5", make_dtdelta(seconds=1.5)),
+            ("PT000001,5", make_dtdelta(seconds=1.5)),
+            (
+                "-P0000-00-30T24:00:00",
+                make_dtdelta(days=-30, hours=-24),
+            ),
+            ("p0000-00-00t00:00:00", make_dtdelta()),
+        ],
+    )
+    def test_parse(self, s, expect):
+        assert DateTimeDelta.parse_iso(s) == expect
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "P0001-02-03T040506",  # mixed basic and extended
+            "P00010203T04:05:06",  # mixed basic and extended
+            "P0001-02-03T",
+            "P0001-02-03T04:05",
+            "P0001-02-31T00:00:00",
+            "P0001-13-00T00:00:00",
+            "P0001-02-03T24:00:01",
+            "P0001-02-03T00:60:00",
+            "P0001-02-03T04:05:06Z",
+            "P0001-02-03 04:05:06",
+            "P1DT04:05:06",
+        ],
+    )
+    def test_parse_invalid(self, s):
+        with pytest.raises(
+            ValueError, match=r"Invalid format.*" + re.escape(repr(s))
+        ):
+            DateTimeDelta.parse_iso(s)
+
+    @pytest.mark.parametrize(
+        "d, extended, basic",
+        [
+            (make_dtdelta(), "P0000-00-00T00:00:00", "P00000000T000000"),
+            (
+                make_dtdelta(months=2, hours=4),
+                "P0000-02-00T04:00:00",
+                "P00000200T040000",
+            ),
+            (
+                make_dtdelta(years=-1, days=-3, seconds=-1.25),
+                "-P0001-00-03T00:00:01.25",
+                "-P00010003T000001.25",
+            ),
+        ],
+    )
+    def test_format(self, d, extended, basic):
+        assert d.format_iso(alternative=True) == extended
+        assert d.format_iso(alternative=True, basic=True) == basic
+        assert DateTimeDelta.parse_iso(extended) == d
+        assert DateTimeDelta.parse_iso(basic) == d
+
+    @pytest.mark.parametrize(
+        "d", [make_dtdelta(days=31), make_dtdelta(hours=-25)]
+    )
+    def test_format_out_of_range(self, d):
+        with pytest.raises(ValueError, match="30 days or 24 hours"):
+            d.format_iso(alternative=True)
+
+    def test_basic_requires_alternative(self):
+        with pytest.raises(ValueError, match="alternative"):
+            make_dtdelta(days=1).format_iso(basic=True)
+
 
 @pytest.mark.parametrize(
     "d, expect",
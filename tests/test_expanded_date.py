@@ -0,0 +1,345 @@
+import pickle
+from copy import copy, deepcopy
+
+import pytest
+from whenever import (
+    Date,
+    ExpandedDate,
+    ItemizedDateDelta,
+    ParseError,
+    Weekday,
+)
+
+
+class TestConstructor:
+    def test_basic(self):
+        d = ExpandedDate(-44, 3, 15)
+        assert d.year == -44
+        assert d.month == 3
+        assert d.day == 15
+
+    def test_year_zero(self):
+        d = ExpandedDate(0, 2, 29)
+        assert d.year == 0
+        assert d.day == 29
+
+    def test_from_string(self):
+        assert ExpandedDate("+012025-01-01") == ExpandedDate(12025, 1, 1)
+
+    @pytest.mark.parametrize(
+        "args",
+        [
+            (1_000_000, 1, 1),
+            (-1_000_000, 12, 31),
+            (2024, 0, 1),
+            (2024, 13, 1),
+            (2024, 1, 0),
+            (2023, 2, 29),
+            (-100, 2, 29),
+            (2024, 4, 31),
+        ],
+    )
+    def test_invalid(self, args):
+        with pytest.raises(ValueError):
+            ExpandedDate(*args)
+
+    def test_min_max(self):
+        assert ExpandedDate.MIN == ExpandedDate(-999_999, 1, 1)
+        assert ExpandedDate.MAX == ExpandedDate(999_999, 12, 31)
+
+
+class TestDateConversion:
+    def test_from_date(self):
+        assert ExpandedDate.from_date(Date(2024, 2, 29)) == ExpandedDate(
+            2024, 2, 29
+        )
+
+    def test_to_date(self):
+        assert ExpandedDate(2024, 2, 29).date() == Date(2024, 2, 29)
+        assert ExpandedDate(1, 1, 1).date() == Date.MIN
+        assert ExpandedDate(9999, 12, 31).date() == Date.MAX
+
+    @pytest.mark.parametrize("year", [0, -1, 10_000])
+    def test_to_date_out_of_range(self, year):
+        with pytest.raises(ValueError, match="out of range"):
+            ExpandedDate(year, 1, 1).date()
+
+
+class TestCalendar:
+    @pytest.mark.parametrize(
+        "d, expect",
+        [
+            (ExpandedDate(1970, 1, 1), Weekday.THURSDAY),
+            (ExpandedDate(0, 1, 1), Weekday.SATURDAY),
+            (ExpandedDate(-1, 12, 31), Weekday.FRIDAY),
+            (ExpandedDate(-44, 3, 15), Weekday.THURSDAY),
+            (ExpandedDate(12025, 1, 1), Weekday.WEDNESDAY),
+            (ExpandedDate.MIN, Weekday.MONDAY),
+            (ExpandedDate.MAX, Weekday.FRIDAY),
+        ],
+    )
+    def test_day_of_week(self, d, expect):
+        assert d.day_of_week() is expect
+
+    def test_matches_date(self):
+        for s in ["0001-01-01", "1900-03-01", "2000-02-29", "9999-12-31"]:
+            d = Date(s)
+            x = ExpandedDate(s)
+            assert x.day_of_week() is d.day_of_week()
+            assert x.day_of_year() == d.day_of_year()
+            assert x.days_in_month() == d.days_in_month()
+            assert x.days_in_year() == d.days_in_year()
+            assert x.in_leap_year() == d.in_leap_year()
+
+    @pytest.mark.parametrize(
+        "year, leap",
+        [(0, True), (-4, True), (-100, False), (-400, True), (-1, False)],
+    )
+    def test_leap_years(self, year, leap):
+        d = ExpandedDate(year, 12, 31)
+        assert d.in_leap_year() is leap
+        assert d.days_in_year() == 365 + leap
+        assert d.day_of_year() == 365 + leap
+        assert ExpandedDate(year, 2, 1).days_in_month() == 28 + leap
+
+
+class TestFormatParse:
+    @pytest.mark.parametrize(
+        "d, extended, basic",
+        [
+            (ExpandedDate(2024, 3, 15), "2024-03-15", "20240315"),
+            (ExpandedDate(0, 1, 1), "0000-01-01", "00000101"),
+            (ExpandedDate(-1, 12, 31), "-000001-12-31", "-0000011231"),
+            (ExpandedDate(12025, 1, 1), "+012025-01-01", "+0120250101"),
+            (ExpandedDate.MIN, "-999999-01-01", "-9999990101"),
+            (ExpandedDate.MAX, "+999999-12-31", "+9999991231"),
+        ],
+    )
+    def test_format_roundtrip(self, d, extended, basic):
+        assert d.format_iso() == extended
+        assert d.format_iso(basic=True) == basic
+        assert str(d) == extended
+        assert ExpandedDate.parse_iso(extended) == d
+        assert ExpandedDate.parse_iso(basic) == d
+
+    def test_repr(self):
+        d = ExpandedDate(-44, 3, 15)
+        assert repr(d) == 'ExpandedDate("-000044-03-15")'
+
+    @pytest.mark.parametrize(
+        "s, expect",
+        [
+            ("-0044-03-15", ExpandedDate(-44, 3, 15)),
+            ("-00440315", ExpandedDate(-44, 3, 15)),
+            ("+2024-03-15", ExpandedDate(2024, 3, 15)),
+            ("+12025-01-01", ExpandedDate(12025, 1, 1)),
+            ("+0000-01-01", ExpandedDate(0, 1, 1)),
+        ],
+    )
+    def test_parse_variants(self, s, expect):
+        assert ExpandedDate.parse_iso(s) == expect
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "",
+            "+",
+            "12025-01-01",
+            "120250101",
+            "+1234567-01-01",
+            "+123-01-01",
+            "-000000-01-01",
+            "-0000-01-01",
+            "2024-3-15",
+            "2024-0315",
+            "202403-15",
+            "2024-02-30",
+            "+2024-13-01",
+            "--2024-01-01",
+            "2024-03-15T00:00",
+            "2024-W01-1",
+            "2024-075",
+            "２０２４-03-15",
+        ],
+    )
+    def test_parse_invalid(self, s):
+        with pytest.raises(ParseError, match="Invalid format"):
+            ExpandedDate.parse_iso(s)
+
+
+class TestArithmetic:
+    def test_add_kwargs(self):
+        d = ExpandedDate(-44, 3, 15)
+        assert d.add(years=-1000, days=20) == ExpandedDate(-1044, 4, 4)
+        assert d.add(weeks=1) == ExpandedDate(-44, 3, 22)
+        assert d.add() is d
+
+    def test_add_delta(self):
+        d = ExpandedDate(9999, 12, 31)
+        delta = ItemizedDateDelta(years=1, months=2, days=1)
+        assert d.add(delta) == ExpandedDate(10001, 3, 1)
+        assert d.subtract(delta) == ExpandedDate(9998, 10, 30)
+
+    def test_clamping(self):
+        assert ExpandedDate(-4, 2, 29).add(years=1) == ExpandedDate(-3, 2, 28)
+        assert ExpandedDate(-1, 1, 31).add(months=1) == ExpandedDate(
+            -1, 2, 28
+        )
+
+    def test_crosses_year_zero(self):
+        assert ExpandedDate(1, 1, 1).subtract(days=1) == ExpandedDate(
+            0, 12, 31
+        )
+        assert ExpandedDate(0, 1, 1).subtract(days=1) == ExpandedDate(
+            -1, 12, 31
+        )
+
+    @pytest.mark.parametrize(
+        "kwargs", [dict(days=1), dict(years=1), dict(months=1)]
+    )
+    def test_out_of_range(self, kwargs):
+        with pytest.raises(ValueError, match="out of range"):
+            ExpandedDate.MAX.add(**kwargs)
+        with pytest.raises(ValueError, match="out of range"):
+            ExpandedDate.MIN.subtract(**kwargs)
+
+    def test_temporarily_out_of_range(self):
+        d = ExpandedDate(999_999, 12, 1)
+        assert d.add(months=1, days=-1) == ExpandedDate(999_999, 12, 31)
+
+    def test_invalid_args(self):
+        d = ExpandedDate(2024, 1, 1)
+        with pytest.raises(TypeError):
+            d.add(ItemizedDateDelta(days=1), days=1)  # type: ignore[call-overload]
+        with pytest.raises(TypeError):
+            d.add(5)  # type: ignore[call-overload]
+        with pytest.raises(TypeError):
+            d.add(hours=1)  # type: ignore[call-overload]
+
+    @pytest.mark.parametrize(
+        "a, b",
+        [
+            ("2024-02-29", "2023-03-31"),
+            ("2023-03-01", "2024-02-29"),
+            ("2020-01-31", "2021-12-01"),
+            ("1999-12-31", "2000-01-01"),
+            ("2023-04-15", "2023-04-15"),
+        ],
+    )
+    def test_since_matches_date(self, a, b):
+        da, db = Date(a), Date(b)
+        xa, xb = ExpandedDate(a), ExpandedDate(b)
+        for units in (
+            ["years", "months", "days"],
+            ["months", "weeks", "days"],
+            ["weeks"],
+            ["years", "days"],
+        ):
+            assert xa.since(xb, in_units=units) == da.since(
+                db, in_units=units
+            )
+            assert xa.until(xb, in_units=units) == da.until(
+                db, in_units=units
+            )
+            assert xa.since(
+                xb, in_units=units, round_mode="half_even", round_increment=2
+            ) == da.since(
+                db, in_units=units, round_mode="half_even", round_increment=2
+            )
+        for unit in ("years", "months", "weeks", "days"):
+            assert xa.since(xb, total=unit) == pytest.approx(
+                da.since(db, total=unit)
+            )
+
+    def test_since_across_year_zero(self):
+        d = ExpandedDate(2025, 1, 1)
+        assert d.since(
+            ExpandedDate(-44, 3, 15), in_units=["years", "days"]
+        ) == ItemizedDateDelta(years=2068, days=292)
+        assert ExpandedDate(-44, 3, 15).until(
+            ExpandedDate(-45, 3, 15), total="years"
+        ) == pytest.approx(-1)
+
+    def test_since_full_range(self):
+        assert ExpandedDate.MAX.since(
+            ExpandedDate.MIN, total="months"
+        ) == pytest.approx(1_999_998 * 12 + 11 + 30 / 31)
+        # Itemized deltas can't express differences this large
+        with pytest.raises(ValueError, match="out of range"):
+            ExpandedDate.MAX.since(ExpandedDate.MIN, in_units=["years"])
+
+    def test_since_invalid(self):
+        d = ExpandedDate(2024, 1, 1)
+        with pytest.raises(TypeError):
+            d.since(d)  # type: ignore[call-overload]
+        with pytest.raises(TypeError):
+            d.since(d, total="days", in_units=["days"])  # type: ignore[call-overload]
+        with pytest.raises(TypeError):
+            d.since(Date(2024, 1, 1), total="days")  # type: ignore[call-overload]
+        with pytest.raises(ValueError):
+            d.since(d, total="hours")  # type: ignore[call-overload]
+        with pytest.raises(ValueError):
+            d.since(d, in_units=["days", "years"])
+
+
+class TestComparison:
+    def test_ordering(self):
+        a = ExpandedDate(-44, 3, 15)
+        b = ExpandedDate(-44, 3, 16)
+        c = ExpandedDate(12025, 1, 1)
+        assert a < b < c
+        assert a <= a
+        assert c > b >= b
+        assert a == ExpandedDate("-0044-03-15")
+        assert a != b
+
+    def test_other_type(self):
+        d = ExpandedDate(2024, 1, 1)
+        # Deliberately unequal to the same Date, like other distinct types
+        assert d != Date(2024, 1, 1)
+        assert Date(2024, 1, 1) != d
+        assert len({d, Date(2024, 1, 1)}) == 2
+        assert d.date() == Date(2024, 1, 1)
+        with pytest.raises(TypeError):
+            d < Date(2024, 1, 1)  # type: ignore[operator]
+
+    def test_hash(self):
+        assert hash(ExpandedDate(-44, 3, 15)) == hash(
+            ExpandedDate("-0044-03-15")
+        )
+        assert len({ExpandedDate(0, 1, 1), ExpandedDate(0, 1, 1)}) == 1
+
+
+class TestReplace:
+    def test_replace(self):
+        d = ExpandedDate(-44, 3, 15)
+        assert d.replace(year=12025) == ExpandedDate(12025, 3, 15)
+        assert d.replace(month=4, day=1) == ExpandedDate(-44, 4, 1)
+
+    def test_invalid(self):
+        with pytest.raises(ValueError):
+            ExpandedDate(-4, 2, 29).replace(year=-3)
+
+
+class TestPickle:
+    def test_roundtrip(self):
+        for d in (
+            ExpandedDate(-44, 3, 15),
+            ExpandedDate.MIN,
+            ExpandedDate.MAX,
+        ):
+            assert pickle.loads(pickle.dumps(d)) == d
+
+    def test_unpickle_compatibility(self):
+        dumped = (
+            b"\x80\x04\x95*\x00\x00\x00\x00\x00\x00\x00\x8c\x08whenever"
+            b"\x94\x8c\x0c_unpkl_xdate\x94\x93\x94C\x06\xd4\xff\xff\xff"
+            b"\x03\x0f\x94\x85\x94R\x94."
+        )
+        assert pickle.loads(dumped) == ExpandedDate(-44, 3, 15)
+
+
+def test_copy():
+    d = ExpandedDate(-44, 3, 15)
+    assert copy(d) is d
+    assert deepcopy(d) is d
@@ -1,15 +1,25 @@
 """Tests for custom format/parse patterns."""
 
+import copy
+import pickle
 import warnings
 
 import pytest
 from whenever import (
     Date,
     Instant,
+    IsoWeekDate,
+    ItemizedDateDelta,
+    ItemizedDelta,
+    MonthDay,
     OffsetDateTime,
+    ParseError,
+    Pattern,
     PlainDateTime,
     Time,
+    TimeDelta,
     WheneverWarning,
+    YearMonth,
     ZonedDateTime,
     hours,
     minutes,
@@ -62,7 +72,7 @@ class TestCompilePattern:
     def test_unrecognized_letter(self):
         d = Date(2024, 3, 15)
         with pytest.raises(ValueError, match="Unrecognized"):
-            d.format("YYYY-Q-DD")
+            d.format("YYYY-B-DD")
 
     def test_pending_not_consumed_by_specifier(self):
         """A pending '.' or ':' flushed as a literal when not consumed."""
@@ -128,9 +138,24 @@ class TestCompilePattern:
             assert "without an AM/PM field" in str(w[0].message)
             assert "24-hour fields" in str(w[0].message)
 
-    def test_yy_parse_disabled(self):
-        with pytest.raises(ValueError, match="YY.*only.*formatting"):
-            Date.parse("24-03-15", format="YY-MM-DD")
+    @pytest.mark.parametrize(
+        "s, format, style",
+        [
+            ("24-03-15", "YY-MM-DD", "pattern"),
+            # checked before any input is read
+            ("not a date", "YY-MM-DD", "pattern"),
+            ("2024-03-15", ["YYYY-MM-DD", "YY-MM-DD"], "pattern"),
+            ("24-03-15", "%y-%m-%d", "strftime"),
+            ("24-03-15", "yy-mm-dd", "spreadsheet"),
+        ],
+    )
+    def test_yy_parse_requires_pivot_year(self, s, format, style):
+        with pytest.raises(
+            ValueError, match="YY.*requires a pivot_year"
+        ) as exc_info:
+            Date.parse(s, format=format, style=style)
+        # A misuse of the API, not invalid input
+        assert type(exc_info.value) is ValueError
 
     def test_invalid_specifier_count(self):
         """E.g. YYY (3 Y's) is not valid — only 2 or 4."""
@@ -367,6 +392,384 @@ class TestMonthWeekdayCoverage:
         assert Date.parse(f"{full} {day}", format="EEEE YYYY-MM-DD") == day
 
 
+_LOCALES = [
+    "en",
+    "de",
+    "fr",
+    "es",
+    "it",
+    "pt",
+    "nl",
+    "sv",
+    "da",
+    "nb",
+    "fi",
+    "pl",
+    "cs",
+    "ru",
+    "uk",
+    "tr",
+    "ja",
+    "zh",
+    "ko",
+]
+
+
+class TestLocale:
+    def test_default_is_english(self):
+        d = Date(2024, 3, 15)
+        assert d.format("EEEE DD MMMM") == "Friday 15 March"
+        assert d.format("EEEE DD MMMM", locale=None) == "Friday 15 March"
+        assert d.format("EEEE DD MMMM", locale="en") == "Friday 15 March"
+        assert f"{d:EEEE DD MMMM}" == "Friday 15 March"
+
+    @pytest.mark.parametrize(
+        "locale, expect",
+        [
+            ("de", "Freitag, 15. März 2024"),
+            ("fr", "vendredi, 15. mars 2024"),
+            ("es", "viernes, 15. marzo 2024"),
+            ("ru", "пятница, 15. марта 2024"),
+            ("pl", "piątek, 15. marca 2024"),
+            ("ja", "金曜日, 15. 3月 2024"),
+        ],
+    )
+    def test_format_full(self, locale, expect):
+        d = Date(2024, 3, 15)
+        assert d.format("EEEE, D. MMMM YYYY", locale=locale) == expect
+
+    def test_format_abbreviated(self):
+        d = Date(2024, 9, 3)
+        assert d.format("EEE D MMM", locale="de") == "Di. 3 Sept."
+        assert d.format("EEE D MMM", locale="fr") == "mar. 3 sept."
+        assert d.format("EEE D MMM", locale="nl") == "di 3 sep"
+        assert d.format("EEE D MMM", locale="zh") == "周二 3 9月"
+
+    @pytest.mark.parametrize(
+        "locale", ["de-AT", "de_CH", "DE", "de-Latn-AT"]
+    )
+    def test_region_and_case_ignored(self, locale):
+        assert Date(2024, 3, 15).format("MMMM", locale=locale) == "März"
+
+    def test_norwegian(self):
+        d = Date(2024, 12, 1)
+        assert d.format("MMMM", locale="no") == "desember"
+        assert d.format("MMMM", locale="nb-NO") == "desember"
+
+    def test_ampm(self):
+        t = Time(14, 30)
+        assert t.format("ii:mm aa", locale="nl") == "02:30 p.m."
+        assert t.format("ii:mm aa", locale="ko") == "02:30 오후"
+        assert t.format("ii:mm aa", locale="es") == "02:30 p.\xa0m."
+        assert Time(9).format("ii a", locale="sv") == "09 f"
+        assert Time(21).format("ii a", locale="sv") == "09 e"
+
+    def test_parse(self):
+        assert Date.parse(
+            "Freitag, 15. März 2024",
+            format="EEEE, D. MMMM YYYY",
+            locale="de",
+        ) == Date(2024, 3, 15)
+        assert Date.parse(
+            "15 janv. 2024", format="D MMM YYYY", locale="fr"
+        ) == Date(2024, 1, 15)
+
+    def test_parse_case_insensitive(self):
+        assert Date.parse(
+            "15 MÄRZ 2024", format="D MMMM YYYY", locale="de"
+        ) == Date(2024, 3, 15)
+        assert Date.parse(
+            "15 МАРТА 2024", format="D MMMM YYYY", locale="ru"
+        ) == Date(2024, 3, 15)
+
+    def test_parse_longest_match(self):
+        fmt = "YYYY MMMM DD"
+        assert Date.parse("2024 1月 05", format=fmt, locale="ja") == Date(
+            2024, 1, 5
+        )
+        assert Date.parse("2024 11月 05", format=fmt, locale="ja") == Date(
+            2024, 11, 5
+        )
+        assert Date.parse(
+            "2024 十一月 05", format=fmt, locale="zh"
+        ) == Date(2024, 11, 5)
+
+    def test_parse_wrong_language(self):
+        with pytest.raises(ValueError, match="month"):
+            Date.parse("15 March 2024", format="D MMMM YYYY", locale="de")
+        with pytest.raises(ValueError, match="month"):
+            Date.parse("15 März 2024", format="D MMMM YYYY")
+
+    def test_parse_weekday_validated(self):
+        fmt = "EEEE YYYY-MM-DD"
+        assert Date.parse(
+            "vendredi 2024-03-15", format=fmt, locale="fr"
+        ) == Date(2024, 3, 15)
+        with pytest.raises(ValueError, match="weekday"):
+            Date.parse("lundi 2024-03-15", format=fmt, locale="fr")
+
+    def test_parse_ampm(self):
+        assert Time.parse("02:30 p.m.", format="ii:mm aa", locale="nl") == (
+            Time(14, 30)
+        )
+        assert Time.parse("02:30 P.M.", format="ii:mm aa", locale="nb") == (
+            Time(14, 30)
+        )
+        assert Time.parse("12:00 午前", format="ii:mm aa", locale="ja") == (
+            Time(0)
+        )
+        with pytest.raises(ValueError, match="AM/PM"):
+            Time.parse("02:30 PM", format="ii:mm aa", locale="nl")
+
+    @pytest.mark.parametrize("locale", _LOCALES)
+    def test_roundtrip_all_names(self, locale):
+        # The first 7 days of each month cover all weekdays
+        for fmt in ("EEE DD MMM YYYY", "EEEE DD MMMM YYYY"):
+            for month in range(1, 13):
+                for day in range(1, 8):
+                    d = Date(2024, month, day)
+                    s = d.format(fmt, locale=locale)
+                    assert Date.parse(s, format=fmt, locale=locale) == d
+        for fmt in ("ii:mm a", "ii:mm aa"):
+            for hour in (0, 11, 12, 23):
+                t = Time(hour, 5)
+                s = t.format(fmt, locale=locale)
+                assert Time.parse(s, format=fmt, locale=locale) == t
+
+    def test_other_types(self):
+        fmt = "EEEE D MMMM YYYY hh:mm"
+        pdt = PlainDateTime(2024, 7, 14, 12, 30)
+        assert pdt.format(fmt, locale="fr") == "dimanche 14 juillet 2024 12:30"
+        assert PlainDateTime.parse(
+            "dimanche 14 juillet 2024 12:30", format=fmt, locale="fr"
+        ) == pdt
+
+        fmt_off = fmt + "xxx"
+        odt = OffsetDateTime(2024, 7, 14, 12, 30, offset=2)
+        s = odt.format(fmt_off, locale="it")
+        assert s == "domenica 14 luglio 2024 12:30+02:00"
+        assert OffsetDateTime.parse(s, format=fmt_off, locale="it") == odt
+
+        inst = odt.to_instant()
+        s = inst.format(fmt_off, locale="tr")
+        assert s == "Pazar 14 Temmuz 2024 10:30+00:00"
+        assert Instant.parse(s, format=fmt_off, locale="tr") == inst
+
+        fmt_tz = fmt + " VV"
+        zdt = ZonedDateTime(2024, 7, 14, 12, 30, tz="Europe/Prague")
+        s = zdt.format(fmt_tz, locale="cs")
+        assert s == "neděle 14 července 2024 12:30 Europe/Prague"
+        assert ZonedDateTime.parse(s, format=fmt_tz, locale="cs") == zdt
+
+    def test_unsupported_locale(self):
+        with pytest.raises(ValueError, match="Unsupported locale"):
+            Date(2024, 3, 15).format("MMMM", locale="xx")
+        with pytest.raises(ValueError, match="Unsupported locale"):
+            Date.parse("15", format="DD", locale="")
+
+    def test_invalid_locale_type(self):
+        with pytest.raises(TypeError, match="locale"):
+            Date(2024, 3, 15).format("MMMM", locale=3)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="locale"):
+            Time.parse("12", format="hh", locale=b"de")  # type: ignore[arg-type]
+
+    def test_unexpected_kwarg(self):
+        with pytest.raises(TypeError):
+            Date(2024, 3, 15).format("MMMM", lang="de")  # type: ignore[call-arg]
+
+
+class TestCalendarFields:
+    @pytest.mark.parametrize(
+        "d, expect",
+        [
+            (Date(2024, 1, 1), "001 2024-W01 Q1"),
+            (Date(2024, 2, 29), "060 2024-W09 Q1"),
+            (Date(2024, 12, 30), "365 2025-W01 Q4"),
+            (Date(2021, 1, 3), "003 2020-W53 Q1"),
+            (Date(2023, 7, 1), "182 2023-W26 Q3"),
+            (Date(1, 1, 1), "001 0001-W01 Q1"),
+            (Date(9999, 12, 31), "365 9999-W52 Q4"),
+        ],
+    )
+    def test_format(self, d, expect):
+        assert d.format("DDD GGGG-'W'WW 'Q'Q") == expect
+
+    def test_format_unpadded_week(self):
+        assert Date(2024, 1, 1).format("GGGG 'w'W") == "2024 w1"
+        assert Date(2024, 12, 29).format("GGGG 'w'W") == "2024 w52"
+
+    def test_format_other_types(self):
+        dt = PlainDateTime(2024, 4, 9, 13, 30)
+        assert dt.format("YYYY-DDD hh:mm 'Q'Q") == "2024-100 13:30 Q2"
+        odt = OffsetDateTime(2024, 4, 9, 13, 30, offset=2)
+        assert odt.format("GGGG-'W'WW XXX") == "2024-W15 +02:00"
+
+    def test_era(self):
+        d = Date(2024, 3, 15)
+        assert d.format("YYYY N") == "2024 AD"
+        assert d.format("YYYY N", locale="de") == "2024 n. Chr."
+        assert d.format("N YYYY", locale="ja") == "西暦 2024"
+        assert Date.parse("2024-03-15 ad", format="YYYY-MM-DD N") == d
+        assert Date.parse(
+            "公元 2024-03-15", format="N YYYY-MM-DD", locale="zh"
+        ) == d
+        with pytest.raises(ValueError, match="common era"):
+            Date.parse("2024-03-15 BC", format="YYYY-MM-DD N")
+        with pytest.raises(ValueError, match="Cannot parse era"):
+            Date.parse("2024-03-15 XX", format="YYYY-MM-DD N")
+
+    @pytest.mark.parametrize(
+        "s, expect",
+        [
+            ("2024-001", Date(2024, 1, 1)),
+            ("2024-060", Date(2024, 2, 29)),
+            ("2024-366", Date(2024, 12, 31)),
+            ("2023-365", Date(2023, 12, 31)),
+        ],
+    )
+    def test_parse_day_of_year(self, s, expect):
+        assert Date.parse(s, format="YYYY-DDD") == expect
+
+    @pytest.mark.parametrize(
+        "s, msg",
+        [
+            ("2023-366", "day of year out of range"),
+            ("2024-000", "day of year out of range"),
+            ("2024-367", "day of year out of range"),
+            ("2024-60", "3 digits"),
+        ],
+    )
+    def test_parse_day_of_year_invalid(self, s, msg):
+        with pytest.raises(ValueError, match=msg):
+            Date.parse(s, format="YYYY-DDD")
+
+    @pytest.mark.parametrize(
+        "s, expect",
+        [
+            ("2025-W01 Mon", Date(2024, 12, 30)),
+            ("2020-W53 Sun", Date(2021, 1, 3)),
+            ("2024-W09 Thu", Date(2024, 2, 29)),
+            ("0001-W01 Mon", Date(1, 1, 1)),
+            ("9999-W52 Fri", Date(9999, 12, 31)),
+        ],
+    )
+    def test_parse_week_date(self, s, expect):
+        assert Date.parse(s, format="GGGG-'W'WW EEE") == expect
+
+    def test_parse_week_date_other_types(self):
+        assert PlainDateTime.parse(
+            "2025 1 Monday 08:00", format="GGGG W EEEE hh:mm"
+        ) == PlainDateTime(2024, 12, 30, 8)
+        assert OffsetDateTime.parse(
+            "2025-W01-Tue 08:00+01:00", format="GGGG-'W'WW-EEE hh:mmXXX"
+        ) == OffsetDateTime(2024, 12, 31, 8, offset=1)
+
+    @pytest.mark.parametrize(
+        "s, msg",
+        [
+            ("2021-W53 Mon", "Invalid ISO week date"),
+            ("9999-W52 Sat", "Invalid ISO week date"),
+            ("2021-W00 Mon", "ISO week out of range"),
+            ("2021-W54 Mon", "ISO week out of range"),
+            ("0000-W01 Mon", "year out of range"),
+        ],
+    )
+    def test_parse_week_date_invalid(self, s, msg):
+        with pytest.raises(ValueError, match=msg):
+            Date.parse(s, format="GGGG-'W'WW EEE")
+
+    def test_parse_week_date_requires_weekday(self):
+        with pytest.raises(ValueError, match="must include"):
+            Date.parse("2025-W01", format="GGGG-'W'WW")
+
+    @pytest.mark.parametrize(
+        "cls, s, fmt",
+        [
+            (Date, "2024-03", "YYYY-MM"),
+            (PlainDateTime, "2024 12:00", "YYYY hh:mm"),
+            (OffsetDateTime, "2024-03 +01:00", "YYYY-MM xxx"),
+            (Instant, "03-15 +01:00", "MM-DD xxx"),
+            (ZonedDateTime, "03-15 UTC", "MM-DD VV"),
+        ],
+    )
+    def test_missing_date_fields_message(self, cls, s, fmt):
+        with pytest.raises(ValueError) as exc_info:
+            cls.parse(s, format=fmt)
+        assert str(exc_info.value) == (
+            "Pattern must include date fields: "
+            "year (YYYY/YY), month (MM/MMM/MMMM), and day (DD); "
+            "year and day of year (DDD); "
+            "or ISO week-based year (GGGG), week (WW/W), "
+            "and weekday (EEE/EEEE)"
+        )
+
+    @pytest.mark.parametrize(
+        "s, fmt, msg",
+        [
+            ("2024-03-15 076", "YYYY-MM-DD DDD", "day of year"),
+            ("2024-03-15 Q2", "YYYY-MM-DD 'Q'Q", "quarter"),
+            ("2024-03-15 W12", "YYYY-MM-DD 'W'WW", "ISO week"),
+            (
+                "2024-12-30 2024-W01",
+                "YYYY-MM-DD GGGG-'W'WW",
+                "week-based year",
+            ),
+            ("2025 2025-W01 Mon", "YYYY GGGG-'W'WW EEE", "year"),
+            ("2024-075 Thu", "YYYY-DDD EEE", "weekday"),
+        ],
+    )
+    def test_parse_mismatch(self, s, fmt, msg):
+        with pytest.raises(ValueError, match=f"{msg} does not match"):
+            Date.parse(s, format=fmt)
+
+    @pytest.mark.parametrize(
+        "s, fmt",
+        [
+            ("2024-03-15 075 Q1", "YYYY-MM-DD DDD 'Q'Q"),
+            ("2024-03-15 2024-W11", "YYYY-MM-DD GGGG-'W'WW"),
+            ("2024 2024-W11 Fri", "YYYY GGGG-'W'WW EEE"),
+            ("2024-075 Fri Q1", "YYYY-DDD EEE 'Q'Q"),
+            ("Q1 2024-03-15", "'Q'Q YYYY-MM-DD"),
+        ],
+    )
+    def test_parse_consistent(self, s, fmt):
+        assert Date.parse(s, format=fmt) == Date(2024, 3, 15)
+
+    def test_parse_quarter_invalid(self):
+        with pytest.raises(ValueError, match="quarter out of range"):
+            Date.parse("2024-03-15 Q0", format="YYYY-MM-DD 'Q'Q")
+
+    @pytest.mark.parametrize(
+        "pattern, msg",
+        [
+            ("GGGG-MM-DD", "requires an ISO week field"),
+            ("YYYY-'W'WW", "week-based year"),
+            ("YYYY-'W'W EEE", "week-based year"),
+            ("DDD DDD", "Duplicate field"),
+            ("W WW", "Duplicate field"),
+            ("DDDD", r"Valid counts for 'D': \[3, 2, 1\]"),
+            ("GG", "Valid counts for 'G'"),
+            ("QQ", "Valid counts for 'Q'"),
+            ("WWW", "Valid counts for 'W'"),
+            ("NN", "Valid counts for 'N'"),
+        ],
+    )
+    def test_invalid_patterns(self, pattern, msg):
+        with pytest.raises(ValueError, match=msg):
+            Date(2024, 3, 15).format(pattern)
+
+    def test_week_with_calendar_month_allowed(self):
+        assert (
+            Date(2024, 12, 30).format("YYYY-MM-DD 'W'WW")
+            == "2024-12-30 W01"
+        )
+
+    def test_time_does_not_support(self):
+        for pattern in ("DDD", "WW", "GGGG-'W'WW", "Q", "N"):
+            with pytest.raises(ValueError, match="does not support"):
+                Time(12).format(pattern)
+
+
 class TestTimeFormat:
     def test_basic(self):
         assert Time(14, 30, 5).format("hh:mm:ss") == "14:30:05"
@@ -913,6 +1316,193 @@ class TestInstantParse:
         assert Instant.parse(i.format(pattern), format=pattern) == i
 
 
+class TestTimeDeltaFormat:
+    D = TimeDelta(hours=50, minutes=15, seconds=9, milliseconds=250)
+
+    @pytest.mark.parametrize(
+        "pattern, expected",
+        [
+            ("hh:mm:ss.fff", "50:15:09.250"),
+            ("h:mm:ss.FFF", "50:15:09.25"),
+            ("hh:mm:ss.FFF", "50:15:09.25"),
+            ("mm:ss", "3015:09"),
+            ("s.ff", "180909.25"),
+            ("hh:mm", "50:15"),
+            ("D'd' hh'h'", "2d 02h"),
+            ("D'd' h'h' m'm' s's'", "2d 2h 15m 9s"),
+            ("DD hh:mm:ss", "02 02:15:09"),
+            ("nnhh:mm", "+50:15"),
+            ("nhh:mm", "50:15"),
+        ],
+    )
+    def test_fields(self, pattern, expected):
+        assert self.D.format(pattern) == expected
+
+    def test_padding_and_zero(self):
+        assert TimeDelta(minutes=5).format("hh:mm:ss") == "00:05:00"
+        assert TimeDelta(minutes=5).format("h:m:s") == "0:5:0"
+        assert TimeDelta.ZERO.format("nnm:ss.FFF") == "+0:00"
+
+    def test_negative(self):
+        d = -self.D
+        assert d.format("nhh:mm:ss.fff") == "-50:15:09.250"
+        assert d.format("nnD'd' hh'h'") == "-2d 02h"
+        # the sign is kept, even if the value is truncated to zero
+        assert TimeDelta(seconds=-30).format("nh:mm") == "-0:00"
+        with pytest.raises(ValueError, match="sign field"):
+            d.format("hh:mm")
+
+    def test_extremes(self):
+        assert TimeDelta.MAX.format("D hh:mm:ss.fffffffff") == (
+            "3659634 00:00:00.000000000"
+        )
+        assert TimeDelta.MIN.format("nh:mm") == "-87831216:00"
+
+    @pytest.mark.parametrize(
+        "pattern, match",
+        [
+            ("YYYY hh", "TimeDelta does not support pattern field YYYY"),
+            ("ii:mm", "TimeDelta does not support pattern field ii"),
+            ("nhh:mm:SS", "TimeDelta does not support pattern field :SS"),
+            ("hh:ss", "minutes \\(m/mm\\) is missing"),
+            ("D mm", "hours \\(h/hh\\) is missing"),
+            ("mm.fff", "require a seconds field"),
+            ("n'x'", "must include"),
+            ("hh:mm nn n", "Duplicate field"),
+            ("hm", "Field m can't follow .* without a separator"),
+            ("hhmmssF", "Field F can't follow .* without a separator"),
+        ],
+    )
+    def test_invalid_pattern(self, pattern, match):
+        with pytest.raises(ValueError, match=match):
+            self.D.format(pattern)
+        with pytest.raises(ValueError, match=match):
+            TimeDelta.parse("1", format=pattern)
+
+    def test_sign_not_supported_by_other_types(self):
+        with pytest.raises(ValueError, match="does not support.*n"):
+            Time(1).format("nhh:mm")
+
+    def test_invalid_args(self):
+        with pytest.raises(TypeError, match="str"):
+            self.D.format(1)  # type: ignore[arg-type]
+
+    def test_dunder_format(self):
+        assert f"{self.D:hh:mm}" == "50:15"
+        assert f"{self.D}" == str(self.D)
+
+
+class TestTimeDeltaParse:
+    @pytest.mark.parametrize(
+        "s, pattern, expected",
+        [
+            (
+                "50:15:09.250",
+                "hh:mm:ss.fff",
+                TimeDelta(hours=50, minutes=15, seconds=9.25),
+            ),
+            ("1:05", "h:mm", TimeDelta(hours=1, minutes=5)),
+            ("1:5", "h:m", TimeDelta(hours=1, minutes=5)),
+            ("3d 04h", "D'd' hh'h'", TimeDelta(hours=76)),
+            ("125", "mm", TimeDelta(minutes=125)),
+            ("12.5", "s.FFF", TimeDelta(seconds=12.5)),
+            ("12", "s.FFF", TimeDelta(seconds=12)),
+            ("-1:30", "nh:mm", TimeDelta(hours=-1, minutes=-30)),
+            ("1:30", "nh:mm", TimeDelta(hours=1, minutes=30)),
+            ("+1:30", "nnh:mm", TimeDelta(hours=1, minutes=30)),
+            ("-0:00.5", "nm:ss.f", TimeDelta(seconds=-0.5)),
+        ],
+    )
+    def test_valid(self, s, pattern, expected):
+        assert TimeDelta.parse(s, format=pattern) == expected
+
+    @pytest.mark.parametrize(
+        "s, pattern, match",
+        [
+            ("1:75", "h:mm", "minutes out of range: 75"),
+            ("1:00:60", "h:mm:ss", "seconds out of range: 60"),
+            ("3 24", "D hh", "hours out of range: 24"),
+            ("5:00", "hh:mm", "at least 2 digits"),
+            ("1:5", "h:mm", "Expected 2 digits"),
+            ("1:30", "nnh:mm", "Expected sign"),
+            ("+1:30", "nh:mm", "Expected a digit"),
+            ("1:30 ", "h:mm", "trailing text"),
+            ("x", "hh", "at least 2 digits"),
+            ("99999999999999999999:00", "hh:mm", "out of range"),
+            ("9999999999:00", "hh:mm", "out of range"),
+            ("05", "hmm", "Expected a digit"),
+        ],
+    )
+    def test_invalid(self, s, pattern, match):
+        with pytest.raises(ParseError, match=match):
+            TimeDelta.parse(s, format=pattern)
+
+    def test_lenient(self):
+        assert TimeDelta.parse("5:00", format="hh:mm", lenient=True) == (
+            TimeDelta(hours=5)
+        )
+        assert TimeDelta.parse("1:5", format="h:mm", lenient=True) == (
+            TimeDelta(hours=1, minutes=5)
+        )
+        assert TimeDelta.parse(
+            "3D  04H ", format="D'd' hh'h'", lenient=True
+        ) == TimeDelta(hours=76)
+        assert TimeDelta.parse("+1:30", format="nh:mm", lenient=True) == (
+            TimeDelta(hours=1, minutes=30)
+        )
+        assert TimeDelta.parse("1:30", format="nnh:mm", lenient=True) == (
+            TimeDelta(hours=1, minutes=30)
+        )
+        assert TimeDelta.parse("12,5", format="s.F", lenient=True) == (
+            TimeDelta(seconds=12.5)
+        )
+
+    def test_multiple_formats(self):
+        formats = ["nh:mm:ss", "D'd' hh'h'"]
+        assert TimeDelta.parse("1:02:03", format=formats) == TimeDelta(
+            hours=1, minutes=2, seconds=3
+        )
+        assert TimeDelta.parse("1d 02h", format=formats) == TimeDelta(
+            hours=26
+        )
+        with pytest.raises(ParseError, match="any of the 2 patterns"):
+            TimeDelta.parse("1h", format=formats)
+
+    def test_error_position(self):
+        with pytest.raises(ParseError) as exc_info:
+            TimeDelta.parse("12:3x", format="hh:mm")
+        assert exc_info.value.position == 3
+        assert exc_info.value.expected == "mm"
+
+    def test_roundtrip(self):
+        d = TimeDelta(hours=-1234, minutes=5, nanoseconds=6)
+        pattern = "nnD hh:mm:ss.fffffffff"
+        assert TimeDelta.parse(d.format(pattern), format=pattern) == d
+
+    @pytest.mark.parametrize(
+        "pattern, d",
+        [
+            ("hhmmss", TimeDelta(hours=123, minutes=4, seconds=5)),
+            ("hhmmss", TimeDelta(seconds=5)),
+            ("nhhmm", TimeDelta(hours=-7, minutes=-30)),
+            ("hmmssfff", TimeDelta(hours=1, milliseconds=3)),
+            ("Dhh:mm", TimeDelta(hours=250, minutes=1)),
+        ],
+    )
+    def test_roundtrip_adjacent_fields(self, pattern, d):
+        assert TimeDelta.parse(d.format(pattern), format=pattern) == d
+
+    def test_invalid_args(self):
+        with pytest.raises(TypeError, match="str"):
+            TimeDelta.parse(1, format="hh")  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            TimeDelta.parse("1", format=1)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="format"):
+            TimeDelta.parse("1")  # type: ignore[call-arg]
+        with pytest.raises(TypeError):
+            TimeDelta.parse("1", format="h", locale="en")  # type: ignore
+
+
 class TestStrftimeParity:
     """Verify we can express common strftime patterns."""
 
@@ -1308,3 +1898,1086 @@ class TestDunderFormat:
     def test_zoned_datetime_empty_spec(self):
         zdt = ZonedDateTime(2024, 3, 15, 14, 30, tz="Europe/Paris")
         assert f"{zdt}" == str(zdt)
+
+
+class TestPattern:
+    """Tests for reusable compiled patterns."""
+
+    @pytest.mark.parametrize(
+        "pattern, cls, value, text",
+        [
+            ("YYYY/MM/DD", Date, Date(2024, 3, 15), "2024/03/15"),
+            ("hh:mm:ss", Time, Time(14, 30, 5), "14:30:05"),
+            (
+                "YYYY-MM-DD hh:mm",
+                PlainDateTime,
+                PlainDateTime(2024, 3, 15, 14, 30),
+                "2024-03-15 14:30",
+            ),
+            (
+                "YYYY-MM-DD hh:mmxxx",
+                OffsetDateTime,
+                OffsetDateTime(2024, 3, 15, 14, 30, offset=hours(2)),
+                "2024-03-15 14:30+02:00",
+            ),
+            (
+                "YYYY-MM-DD hh:mmxxx'['VV']'",
+                ZonedDateTime,
+                ZonedDateTime(2024, 3, 15, 14, 30, tz="Europe/Paris"),
+                "2024-03-15 14:30+01:00[Europe/Paris]",
+            ),
+            (
+                "YYYY-MM-DD hh:mmXXX",
+                Instant,
+                Instant.from_utc(2024, 3, 15, 14, 30),
+                "2024-03-15 14:30Z",
+            ),
+            (
+                "nhh:mm:ss.fff",
+                TimeDelta,
+                TimeDelta(hours=-50, milliseconds=-250),
+                "-50:00:00.250",
+            ),
+        ],
+    )
+    def test_roundtrip(self, pattern, cls, value, text):
+        p = Pattern(pattern, cls)
+        assert p.pattern == pattern
+        assert p.format(value) == text
+        assert value.format(pattern) == text
+        assert p.parse(text) == value
+
+    def test_locale(self):
+        p = Pattern("D. MMMM YYYY", Date)
+        assert p.format(Date(2024, 3, 15), locale="de") == "15. März 2024"
+        assert p.parse("15. März 2024", locale="de") == Date(2024, 3, 15)
+        with pytest.raises(ValueError, match="locale"):
+            p.format(Date(2024, 3, 15), locale="xx")
+
+    def test_validated_on_creation(self):
+        with pytest.raises(ValueError, match="Date does not support"):
+            Pattern("YYYY-MM-DD hh:mm", Date)
+        with pytest.raises(ValueError, match="Unrecognized"):
+            Pattern("YYYY-B-DD", Date)
+
+    def test_invalid_args(self):
+        with pytest.raises(TypeError, match="Pattern type"):
+            Pattern("YYYY", str)  # type: ignore[type-var]
+        with pytest.raises(TypeError, match="Pattern type"):
+            Pattern("YYYY", Date(2024, 1, 1))  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="str"):
+            Pattern(b"YYYY", Date)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            Pattern("YYYY")  # type: ignore[call-arg]
+
+    def test_format_wrong_type(self):
+        p = Pattern("YYYY-MM-DD", Date)
+        with pytest.raises(TypeError, match="Expected a Date"):
+            p.format(PlainDateTime(2024, 3, 15))  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="Expected a Date"):
+            p.format("2024-03-15")  # type: ignore[arg-type]
+
+    def test_parse_errors(self):
+        p = Pattern("YYYY-MM-DD", Date)
+        with pytest.raises(ValueError, match="Expected"):
+            p.parse("2024/03/15")
+        with pytest.raises(ValueError, match="Pattern must include"):
+            Pattern("YYYY-MM", Date).parse("2024-03")
+
+    def test_disambiguate(self):
+        p = Pattern("YYYY-MM-DD hh:mm VV", ZonedDateTime)
+        s = "2023-10-29 02:30 Europe/Amsterdam"
+        assert p.parse(s).offset == hours(2)
+        assert p.parse(s, disambiguate="later").offset == hours(1)
+        with pytest.raises(ValueError):
+            p.parse(s, disambiguate="foo")  # type: ignore[arg-type]
+
+        t = Pattern("hh:mm", Time)
+        assert t.parse("12:30", disambiguate=None) == Time(12, 30)
+        with pytest.raises(TypeError, match="disambiguate"):
+            t.parse("12:30", disambiguate="raise")
+
+    def test_time_delta(self):
+        p = Pattern("nD'd' hh'h'", TimeDelta)
+        assert p.parse("3d 04h", lenient=True) == TimeDelta(hours=76)
+        with pytest.raises(TypeError, match="Expected a TimeDelta"):
+            p.format(Time(1))  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="TimeDelta does not support"):
+            Pattern("YYYY", TimeDelta)
+        with pytest.raises(ValueError, match="strftime"):
+            Pattern("%H:%M", TimeDelta, style="strftime")
+        with pytest.raises(TypeError, match="disambiguate"):
+            p.parse("3d 04h", disambiguate="raise")
+        assert pickle.loads(pickle.dumps(p)) == p
+
+    def test_12h_without_ampm_warns_on_creation(self):
+        with warnings.catch_warnings(record=True) as w:
+            warnings.simplefilter("always")
+            Pattern("ii:mm'!'", Time)
+        assert len(w) == 1
+        assert w[0].category is WheneverWarning
+
+    def test_repr(self):
+        assert (
+            repr(Pattern("YYYY-MM-DD", Date)) == "Pattern('YYYY-MM-DD', Date)"
+        )
+        assert (
+            repr(Pattern("hh:mm", PlainDateTime))
+            == "Pattern('hh:mm', PlainDateTime)"
+        )
+
+    def test_equality(self):
+        p = Pattern("YYYY-MM-DD", Date)
+        same = Pattern("YYYY-MM-DD", Date)
+        assert p == same
+        assert hash(p) == hash(same)
+        assert p != Pattern("YYYY-MM-DD", PlainDateTime)
+        assert p != Pattern("YYYY/MM/DD", Date)
+        assert p != "YYYY-MM-DD"
+
+    def test_pickle_and_copy(self):
+        p = Pattern("YYYY-MM-DD hh:mm VV", ZonedDateTime)
+        assert pickle.loads(pickle.dumps(p)) == p
+        assert copy.copy(p) is p
+        assert copy.deepcopy(p) is p
+
+
+class TestStrftimeStyle:
+    """Tests for translating strftime format strings into patterns."""
+
+    @pytest.mark.parametrize(
+        "fmt, pattern",
+        [
+            ("%Y-%m-%d", "YYYY-MM-DD"),
+            ("%Y-%m-%d %H:%M:%S%z", "YYYY-MM-DD hh:mm:ssxxxx"),
+            ("%d %b %Y", "DD MMM YYYY"),
+            ("%A %-d %B", "EEEE D MMMM"),
+            ("%I:%M %p", "ii:mm aa"),
+            ("%H:%M:%S.%f%:z", "hh:mm:ss.ffffffxxxxx"),
+            ("%G-W%V", "GGGG'-W'WW"),
+            ("%j", "DDD"),
+            ("%F %T", "YYYY-MM-DD hh:mm:ss"),
+            ("%D %R", "MM/DD/YY hh:mm"),
+            ("100%%", "'100%'"),
+            ("%Y%n%t", "YYYY'\n\t'"),
+        ],
+    )
+    def test_translation(self, fmt, pattern):
+        assert repr(compile_pattern(fmt, "strftime")) == repr(
+            compile_pattern(pattern)
+        )
+
+    def test_roundtrip(self):
+        fmt = "%Y-%m-%d %H:%M:%S%z"
+        d = OffsetDateTime(2024, 3, 15, 14, 30, 5, offset=hours(1))
+        assert d.format(fmt, style="strftime") == "2024-03-15 14:30:05+0100"
+        assert (
+            OffsetDateTime.parse(
+                "2024-03-15 14:30:05+0100", format=fmt, style="strftime"
+            )
+            == d
+        )
+
+    def test_all_types(self):
+        assert Date(2024, 3, 5).format("%-d/%-m", style="strftime") == "5/3"
+        assert Date.parse(
+            "05 Mar 2024", format="%d %b %Y", style="strftime"
+        ) == Date(2024, 3, 5)
+        assert Time.parse(
+            "02:30 PM", format="%I:%M %p", style="strftime"
+        ) == Time(14, 30)
+        assert PlainDateTime(2024, 3, 5, 9).format(
+            "%F %-H:%M", style="strftime"
+        ) == "2024-03-05 9:00"
+        assert (
+            Instant.from_utc(2024, 3, 5).format("%F %T%:z", style="strftime")
+            == "2024-03-05 00:00:00+00:00"
+        )
+        assert ZonedDateTime(2024, 3, 5, tz="Europe/Paris").format(
+            "%F %H:%M %Z", style="strftime"
+        ) == "2024-03-05 00:00 CET"
+
+    def test_strict_parsing(self):
+        with pytest.raises(ValueError):
+            Date.parse("2024-3-5", format="%Y-%m-%d", style="strftime")
+
+    @pytest.mark.parametrize(
+        "fmt, hint",
+        [
+            ("%Y %U", "ISO weeks"),
+            ("%Y %W", "ISO weeks"),
+            ("%w", "weekday"),
+            ("%c", "Locale"),
+            ("%e", "%d"),
+            ("%Q", ""),
+        ],
+    )
+    def test_unsupported_directive(self, fmt, hint):
+        directive = fmt[-2:]
+        with pytest.raises(
+            ValueError, match=f"Unsupported strftime directive '{directive}'"
+        ) as exc:
+            Date(2024, 1, 1).format(fmt, style="strftime")
+        assert hint in str(exc.value)
+
+    @pytest.mark.parametrize("fmt", ["%Y-%", "%Y %-", "%Y %:"])
+    def test_incomplete_directive(self, fmt):
+        with pytest.raises(ValueError, match="Incomplete strftime directive"):
+            Date(2024, 1, 1).format(fmt, style="strftime")
+
+    @pytest.mark.parametrize(
+        "fmt, am, pm",
+        [
+            ("h a/p", "2 a", "2 p"),
+            ("h A/P", "2 A", "2 P"),
+            ("h a/P", "2 a", "2 P"),
+            ("h AM/PM", "2 AM", "2 PM"),
+        ],
+    )
+    def test_am_pm_letter_case(self, fmt, am, pm):
+        assert Time(2).format(fmt, style="spreadsheet") == am
+        assert Time(14).format(fmt, style="spreadsheet") == pm
+        # Parsing ignores case
+        assert Time.parse(
+            pm.swapcase(), format=fmt, style="spreadsheet"
+        ) == Time(14)
+
+    @pytest.mark.parametrize(
+        "fmt, locale, expect",
+        [
+            ("A/P", "es", "P"),
+            ("a/p", "de", "p"),
+            ("a/p", "tr", "ös"),
+        ],
+    )
+    def test_am_pm_letter_case_localized(self, fmt, locale, expect):
+        assert (
+            Time(14).format(fmt, style="spreadsheet", locale=locale) == expect
+        )
+
+    def test_fields_validated_for_type(self):
+        with pytest.raises(ValueError, match="Date does not support"):
+            Date(2024, 1, 1).format("%Y %H", style="strftime")
+
+    def test_invalid_style(self):
+        d = Date(2024, 1, 1)
+        with pytest.raises(ValueError, match="style"):
+            d.format("%Y", style="foo")  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="style"):
+            d.parse("2024", format="%Y", style="foo")  # type: ignore[arg-type]
+
+    def test_pattern(self):
+        p = Pattern("%Y-%m-%d", Date, style="strftime")
+        assert p.pattern == "%Y-%m-%d"
+        assert p.format(Date(2024, 3, 15)) == "2024-03-15"
+        assert p.parse("2024-03-15") == Date(2024, 3, 15)
+        assert repr(p) == "Pattern('%Y-%m-%d', Date, style='strftime')"
+        assert p == Pattern("%Y-%m-%d", Date, style="strftime")
+        assert hash(p) == hash(Pattern("%Y-%m-%d", Date, style="strftime"))
+        assert Pattern("YYYY", Date) != Pattern("YYYY", Date, style="strftime")
+        assert pickle.loads(pickle.dumps(p)) == p
+        with pytest.raises(ValueError, match="style"):
+            Pattern("%Y", Date, style="foo")  # type: ignore[arg-type]
+
+
+class TestSpreadsheetStyle:
+    """Tests for translating spreadsheet number formats into patterns."""
+
+    @pytest.mark.parametrize(
+        "fmt, pattern",
+        [
+            ("dd/mm/yyyy hh:mm AM/PM", "DD/MM/YYYY ii:mm aa"),
+            ("m/d/yy h:mm", "M/D/YY h:mm"),
+            ("mmm-yy", "MMM-YY"),
+            ("[$-409]mmmm d, yyyy", "MMMM D, YYYY"),
+            ("[$USD-409]YYYY", "'USD'YYYY"),
+            ("dddd, mmmm dd", "EEEE, MMMM DD"),
+            ("mm:ss.000", "mm:ss.fff"),
+            ("yyyy-mm-dd hh:mm:ss.00", "YYYY-MM-DD hh:mm:ss.ff"),
+            ('"Date:" d-mmm', "'Date: 'D-MMM"),
+            ("\\Q yy", "'Q 'YY"),
+            ("_(mm_)", "' 'MM' '"),
+            ("*-m", "M"),
+            ("[Red][Color10]yyyy", "YYYY"),
+            # 'm' is a minute only after hours or before seconds
+            ("m:s", "m:s"),
+            ('m"x"s', "m'x's"),
+            ("h mm", "h mm"),
+            ("mm yyyy ss", "MM YYYY ss"),
+        ],
+    )
+    def test_translation(self, fmt, pattern):
+        assert repr(compile_pattern(fmt, "spreadsheet")) == repr(
+            compile_pattern(pattern)
+        )
+
+    def test_all_types(self):
+        assert (
+            PlainDateTime(2024, 3, 5, 14, 7).format(
+                "dd/mm/yyyy hh:mm AM/PM", style="spreadsheet"
+            )
+            == "05/03/2024 02:07 PM"
+        )
+        assert PlainDateTime.parse(
+            "05/03/2024 02:07 PM",
+            format="dd/mm/yyyy hh:mm AM/PM",
+            style="spreadsheet",
+        ) == PlainDateTime(2024, 3, 5, 14, 7)
+        assert (
+            Date(2024, 3, 5).format(
+                "dddd d mmmm yyyy", style="spreadsheet", locale="de"
+            )
+            == "Dienstag 5 März 2024"
+        )
+        assert Date.parse(
+            "5-Mar-24", format="d-mmm-yy", style="spreadsheet", pivot_year=2000
+        ) == Date(2024, 3, 5)
+        assert Time.parse(
+            "2:07:09 P", format="h:mm:ss a/p", style="spreadsheet"
+        ) == Time(14, 7, 9)
+        assert (
+            Instant.from_utc(2024, 3, 5).format(
+                "yyyy-mm-dd hh:mm", style="spreadsheet"
+            )
+            == "2024-03-05 00:00"
+        )
+
+    @pytest.mark.parametrize(
+        "fmt, msg",
+        [
+            ("yyyy;@", "single section"),
+            ("0.00", "Digits are only supported"),
+            ("General", "Unsupported spreadsheet code 'G' at position 0"),
+            ("yyyyy", "Unsupported spreadsheet code 'yyyyy'"),
+            ("mmmmm", "Unsupported spreadsheet code 'mmmmm'"),
+            ("[foo]", "Unsupported spreadsheet code '\\[foo\\]'"),
+            ("[$-F800]", "system date or time format"),
+            ("[$-x-sysdate]", "system date or time format"),
+            ("[$-xx]", "Invalid locale ID '\\[\\$-xx\\]' at position 0"),
+            ("[$-]", "Invalid locale ID"),
+            (
+                "d [$-41E]",
+                "Unsupported locale ID '\\[\\$-41E\\]' at position 2",
+            ),
+            ("[$-1000409]", "Unsupported locale ID"),
+            ("[$-407]mmmm [$-409]", "conflicts with an earlier locale ID"),
+            ("ss.0000000000", "At most 9 decimals"),
+            ('"abc', "Unterminated quoted text at position 0"),
+            ("yyyy [h", "Unterminated bracket at position 5"),
+            ("yyyy\\", "Incomplete escape"),
+            ("d é", "Non-ASCII"),
+        ],
+    )
+    def test_unsupported(self, fmt, msg):
+        with pytest.raises(ValueError, match=msg):
+            Date(2024, 1, 1).format(fmt, style="spreadsheet")
+
+    def test_locale_id(self):
+        d = Date(2024, 3, 5)
+        assert d.format("[$-407]mmmm", style="spreadsheet") == "März"
+        assert d.format("[$-409]mmmm", style="spreadsheet") == "March"
+        assert d.format("[$-809]mmmm", style="spreadsheet") == "March"
+        assert d.format("[$-40C]dddd", style="spreadsheet") == "mardi"
+        assert d.format("[$-410]mmmm", style="spreadsheet") == "marzo"
+        assert d.format("[$-411]mmmm", style="spreadsheet") == "3月"
+        assert d.format("[$-419]mmmm", style="spreadsheet") == "марта"
+        assert (
+            d.format("[$EUR-C07]mmmm yyyy", style="spreadsheet")
+            == "EURMärz 2024"
+        )
+        assert (
+            PlainDateTime(2024, 3, 5, 14).format(
+                "[$-407]d. mmmm h AM/PM", style="spreadsheet"
+            )
+            == "5. März 2 PM"
+        )
+        # The same language may be passed explicitly
+        assert (
+            d.format("[$-407]mmmm", style="spreadsheet", locale="de-AT")
+            == "März"
+        )
+        assert Date.parse(
+            "5. März 2024", format="[$-407]d. mmmm yyyy", style="spreadsheet"
+        ) == Date(2024, 3, 5)
+        assert Date.parse(
+            "5. März 2024",
+            format=["[$-409]d. mmmm yyyy", "[$-407]d. mmmm yyyy"],
+            style="spreadsheet",
+        ) == Date(2024, 3, 5)
+        p = Pattern("[$-407]d. mmmm yyyy", Date, style="spreadsheet")
+        assert p.format(d) == "5. März 2024"
+        assert p.parse("5. März 2024") == d
+
+    def test_non_ascii_literals(self):
+        d = Date(2024, 3, 5)
+        fmt = 'yyyy"年"m"月"d"日"'
+        assert d.format(fmt, style="spreadsheet") == "2024年3月5日"
+        assert (
+            Date.parse("2024年3月5日", format=fmt, style="spreadsheet") == d
+        )
+        assert d.format("yyyy\\年m\\月", style="spreadsheet") == "2024年3月"
+        assert d.format("[$€-407]d. mmmm", style="spreadsheet") == "€5. März"
+        # Error positions count characters, not bytes
+        with pytest.raises(ValueError, match="'x' at position 7"):
+            d.format('yyyy"年"x', style="spreadsheet")
+        with pytest.raises(ValueError, match="Non-ASCII character at position 5"):
+            d.format('"年"yy年', style="spreadsheet")
+
+    def test_locale_id_conflicts_with_locale_argument(self):
+        msg = "locale ID selects locale 'de', which conflicts with locale='fr'"
+        d = Date(2024, 3, 5)
+        with pytest.raises(ValueError, match=msg):
+            d.format("[$-407]mmmm", style="spreadsheet", locale="fr")
+        with pytest.raises(ValueError, match=msg):
+            Date.parse(
+                "mars",
+                format=["mmmm", "[$-407]mmmm"],
+                style="spreadsheet",
+                locale="fr",
+            )
+        p = Pattern("[$-407]d. mmmm yyyy", Date, style="spreadsheet")
+        with pytest.raises(ValueError, match=msg):
+            p.format(d, locale="fr")
+        with pytest.raises(ValueError, match=msg):
+            p.parse("5. mars 2024", locale="fr")
+
+    @pytest.mark.parametrize(
+        "fmt, am, pm",
+        [
+            ("h a/p", "2 a", "2 p"),
+            ("h A/P", "2 A", "2 P"),
+            ("h a/P", "2 a", "2 P"),
+            ("h AM/PM", "2 AM", "2 PM"),
+        ],
+    )
+    def test_am_pm_letter_case(self, fmt, am, pm):
+        assert Time(2).format(fmt, style="spreadsheet") == am
+        assert Time(14).format(fmt, style="spreadsheet") == pm
+        # Parsing ignores case
+        assert Time.parse(
+            pm.swapcase(), format=fmt, style="spreadsheet"
+        ) == Time(14)
+
+    @pytest.mark.parametrize(
+        "fmt, locale, expect",
+        [
+            ("A/P", "es", "P"),
+            ("a/p", "de", "p"),
+            ("a/p", "tr", "ös"),
+        ],
+    )
+    def test_am_pm_letter_case_localized(self, fmt, locale, expect):
+        assert (
+            Time(14).format(fmt, style="spreadsheet", locale=locale) == expect
+        )
+
+    def test_fields_validated_for_type(self):
+        with pytest.raises(ValueError, match="Date does not support"):
+            Date(2024, 1, 1).format("yyyy hh", style="spreadsheet")
+        with pytest.raises(ValueError, match="does not support.*\\[h\\]"):
+            Time(1).format("[h]:mm", style="spreadsheet")
+
+    @pytest.mark.parametrize(
+        "cls, fmt, msg",
+        [
+            (Date, "dd/mm/yyyy hh:mm AM/PM", "Date does not support .* hh$"),
+            (Date, "yyyy-mm-dd H:MM", "Date does not support .* H$"),
+            (Date, "d A/P", "Date does not support .* A/P$"),
+            (Time, "hh:mm:ss.000 yy", "Time does not support .* yy$"),
+            (Date, "YYYY yy", "Duplicate field: yy conflicts with YYYY"),
+            (TimeDelta, "[h]:mm yyyy", "TimeDelta does not .* yyyy$"),
+        ],
+    )
+    def test_errors_show_code_as_written(self, cls, fmt, msg):
+        with pytest.raises(ValueError, match=msg):
+            Pattern(fmt, cls, style="spreadsheet")
+
+    def test_pattern(self):
+        p = Pattern("dd/mm/yyyy", Date, style="spreadsheet")
+        assert p.format(Date(2024, 3, 15)) == "15/03/2024"
+        assert p.parse("15/03/2024") == Date(2024, 3, 15)
+        assert repr(p) == "Pattern('dd/mm/yyyy', Date, style='spreadsheet')"
+        assert p == Pattern("dd/mm/yyyy", Date, style="spreadsheet")
+        assert p != Pattern("dd/mm/yyyy", Date, style="strftime")
+        assert pickle.loads(pickle.dumps(p)) == p
+
+
+class TestSpreadsheetTimeDelta:
+    """Tests for spreadsheet elapsed-time formats for TimeDelta."""
+
+    @pytest.mark.parametrize(
+        "fmt, expect, parsed",
+        [
+            ("[h]:mm:ss", "50:03:04", TimeDelta(hours=50, seconds=184)),
+            ("[hh]:mm", "50:03", TimeDelta(hours=50, minutes=3)),
+            ("[mm]:ss.00", "3003:04.50", TimeDelta(seconds=180_184.5)),
+            ("[s].000", "180184.500", TimeDelta(seconds=180_184.5)),
+            ("[h]", "50", TimeDelta(hours=50)),
+        ],
+    )
+    def test_format_and_parse(self, fmt, expect, parsed):
+        d = TimeDelta(hours=50, minutes=3, seconds=4, milliseconds=500)
+        assert d.format(fmt, style="spreadsheet") == expect
+        assert (-d).format(fmt, style="spreadsheet") == "-" + expect
+        assert (
+            TimeDelta.parse(expect, format=fmt, style="spreadsheet") == parsed
+        )
+        assert (
+            TimeDelta.parse("-" + expect, format=fmt, style="spreadsheet")
+            == -parsed
+        )
+
+    def test_parse(self):
+        assert TimeDelta.parse(
+            "26:30", format="[h]:mm", style="spreadsheet"
+        ) == TimeDelta(hours=26, minutes=30)
+        with pytest.raises(ParseError, match="minutes out of range"):
+            TimeDelta.parse("2:60", format="[h]:mm", style="spreadsheet")
+
+    @pytest.mark.parametrize("fmt", ["hh:mm", "mm:ss", "[h]:[mm]", "d hh"])
+    def test_requires_elapsed_largest_unit(self, fmt):
+        with pytest.raises(ValueError, match="elapsed-time code"):
+            TimeDelta(hours=1).format(fmt, style="spreadsheet")
+        with pytest.raises(ValueError, match="elapsed-time code"):
+            Pattern(fmt, TimeDelta, style="spreadsheet")
+
+    def test_unsupported_fields(self):
+        with pytest.raises(ValueError, match="TimeDelta does not support"):
+            TimeDelta(hours=1).format("yyyy [h]", style="spreadsheet")
+        with pytest.raises(ValueError, match="strftime"):
+            TimeDelta(hours=1).format("%H", style="strftime")
+
+    def test_pattern(self):
+        p = Pattern("[h]:mm", TimeDelta, style="spreadsheet")
+        assert p.format(TimeDelta(hours=-26)) == "-26:00"
+        assert p.parse("-26:00") == TimeDelta(hours=-26)
+        assert (
+            repr(p) == "Pattern('[h]:mm', TimeDelta, style='spreadsheet')"
+        )
+        assert pickle.loads(pickle.dumps(p)) == p
+
+
+class TestLenientParsing:
+    """Tests for the opt-in lenient parsing mode."""
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "2024-03-05 09:30",
+            "2024-3-5 9:30",
+            "2024-03-05   09:30",
+            "2024-03-05\t9:30",
+            "2024-03-05 09:30  ",
+        ],
+    )
+    def test_accepts(self, s):
+        assert PlainDateTime.parse(
+            s, format="YYYY-MM-DD hh:mm", lenient=True
+        ) == PlainDateTime(2024, 3, 5, 9, 30)
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "2024-3-5 9:30",
+            "2024-03-05   09:30",
+            "2024-03-05 09:30  ",
+        ],
+    )
+    def test_strict_by_default(self, s):
+        with pytest.raises(ValueError):
+            PlainDateTime.parse(s, format="YYYY-MM-DD hh:mm")
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "2024-03-0509:30",  # whitespace is still required
+            " 2024-03-05 09:30",  # leading whitespace
+            "24-03-05 09:30",  # years must have 4 digits
+            "2024-03-05 009:30",  # too many digits
+            "2024/03/05 09:30",  # other punctuation
+        ],
+    )
+    def test_still_rejects(self, s):
+        with pytest.raises(ValueError):
+            PlainDateTime.parse(s, format="YYYY-MM-DD hh:mm", lenient=True)
+
+    def test_literal_case(self):
+        assert PlainDateTime.parse(
+            "2024-03-05t09:30", format="YYYY-MM-DD'T'hh:mm", lenient=True
+        ) == PlainDateTime(2024, 3, 5, 9, 30)
+        assert Date.parse(
+            "5 MAR 2024", format="D MMM YYYY", lenient=True
+        ) == Date(2024, 3, 5)
+
+    @pytest.mark.parametrize(
+        "fmt", ["hh:mm:ss.fff", "hh:mm:ss,fff", "hh:mm:ss.FFF"]
+    )
+    @pytest.mark.parametrize("s", ["09:30:05.120", "09:30:05,120"])
+    def test_fraction_separator(self, fmt, s):
+        assert Time.parse(s, format=fmt, lenient=True) == Time(
+            9, 30, 5, nanosecond=120_000_000
+        )
+
+    def test_comma_not_accepted_elsewhere(self):
+        with pytest.raises(ValueError):
+            Date.parse("2024,03-05", format="YYYY-MM-DD", lenient=True)
+
+    def test_optional_fields(self):
+        assert Time.parse(
+            "9:30:5,25", format="hh:mm:SS.FFF", lenient=True
+        ) == Time(9, 30, 5, nanosecond=250_000_000)
+        assert Time.parse("9:30", format="hh:mm:SS", lenient=True) == Time(
+            9, 30
+        )
+
+    def test_other_types(self):
+        assert Time.parse("9:5", format="hh:mm", lenient=True) == Time(9, 5)
+        assert OffsetDateTime.parse(
+            "2024-3-5 9:30 +01:00 ",
+            format="YYYY-MM-DD hh:mm xxx",
+            lenient=True,
+        ) == OffsetDateTime(2024, 3, 5, 9, 30, offset=1)
+        assert Instant.parse(
+            "2024-3-5 9:30 Z", format="YYYY-MM-DD hh:mm XXX", lenient=True
+        ) == Instant.from_utc(2024, 3, 5, 9, 30)
+        assert ZonedDateTime.parse(
+            "2024-3-5  9:30 Europe/Paris",
+            format="YYYY-MM-DD hh:mm VV",
+            lenient=True,
+        ) == ZonedDateTime(2024, 3, 5, 9, 30, tz="Europe/Paris")
+
+    def test_pattern(self):
+        p = Pattern("DD/MM/YYYY", Date)
+        assert p.parse("5/3/2024 ", lenient=True) == Date(2024, 3, 5)
+        with pytest.raises(ValueError):
+            p.parse("5/3/2024 ")
+
+    def test_invalid_value(self):
+        with pytest.raises(TypeError, match="lenient"):
+            Date.parse(
+                "2024", format="YYYY", lenient=1  # type: ignore[arg-type]
+            )
+        with pytest.raises(TypeError, match="lenient"):
+            Pattern("YYYY-MM-DD", Date).parse(
+                "2024-03-05", lenient="yes"  # type: ignore[arg-type]
+            )
+
+
+class TestMultipleFormats:
+    """Tests for parsing with several candidate patterns."""
+
+    FORMATS = ["YYYY-MM-DD", "DD/MM/YYYY", "MMM D, YYYY"]
+
+    @pytest.mark.parametrize("s", ["2024-03-05", "05/03/2024", "Mar 5, 2024"])
+    def test_first_match(self, s):
+        assert Date.parse(s, format=self.FORMATS) == Date(2024, 3, 5)
+        assert Date.parse(s, format=tuple(self.FORMATS)) == Date(2024, 3, 5)
+
+    def test_order(self):
+        s = "01/02/2024 10:00"
+        assert PlainDateTime.parse(
+            s, format=["DD/MM/YYYY hh:mm", "MM/DD/YYYY hh:mm"]
+        ) == PlainDateTime(2024, 2, 1, 10)
+        assert PlainDateTime.parse(
+            s, format=["MM/DD/YYYY hh:mm", "DD/MM/YYYY hh:mm"]
+        ) == PlainDateTime(2024, 1, 2, 10)
+
+    def test_no_match(self):
+        with pytest.raises(ValueError) as exc:
+            Date.parse("5.3.2024", format=self.FORMATS)
+        lines = str(exc.value).splitlines()
+        assert lines[0] == "Input doesn't match any of the 3 patterns:"
+        assert len(lines) == 4
+        for line, fmt in zip(lines[1:], self.FORMATS):
+            assert line.startswith("- ") and fmt in line
+        assert "Cannot parse month at position 0" in lines[3]
+
+    def test_invalid_values_try_next(self):
+        formats = ["DD/MM/YY", "YY/MM/DD"]
+        assert Date.parse(
+            "30/02/01", format=formats, pivot_year=2000
+        ) == Date(2030, 2, 1)
+        assert Date.parse(
+            "Fri 2024-03-15", format=["EEE YYYY-DD-MM", "EEE YYYY-MM-DD"]
+        ) == Date(2024, 3, 15)
+        assert Time.parse("25:30", format=["hh:mm", "mm:ss"]) == Time(
+            0, 25, 30
+        )
+        with pytest.raises(ParseError) as exc:
+            Date.parse("30/02/31", format=formats, pivot_year=2000)
+        assert str(exc.value).splitlines()[1:] == [
+            "- 'DD/MM/YY': Invalid date",
+            "- 'YY/MM/DD': Invalid date",
+        ]
+
+    def test_single_item(self):
+        assert Date.parse("2024-03-05", format=["YYYY-MM-DD"]) == Date(
+            2024, 3, 5
+        )
+        with pytest.raises(ValueError, match="^Expected 4 digits"):
+            Date.parse("5.3.2024", format=["YYYY-MM-DD"])
+
+    def test_all_patterns_validated(self):
+        with pytest.raises(ValueError, match="Date does not support"):
+            Date.parse("2024-03-05", format=["YYYY-MM-DD", "YYYY-MM-DD hh"])
+        with pytest.raises(ValueError, match="Unrecognized"):
+            Date.parse("2024-03-05", format=["YYYY-MM-DD", "YYYY-B"])
+
+    def test_invalid_format_arg(self):
+        with pytest.raises(ValueError, match="at least one"):
+            Date.parse("2024-03-05", format=[])
+        with pytest.raises(TypeError, match="list or tuple"):
+            Date.parse("2024-03-05", format=3)  # type: ignore[arg-type]
+        with pytest.raises(TypeError, match="list or tuple"):
+            Date.parse(
+                "2024-03-05", format=["YYYY", 3]  # type: ignore[list-item]
+            )
+        with pytest.raises(TypeError, match="list or tuple"):
+            Date.parse(
+                "2024-03-05",
+                format=iter(["YYYY-MM-DD"]),  # type: ignore[arg-type]
+            )
+
+    def test_with_options(self):
+        assert Date.parse(
+            "5 mar 2024 ",
+            format=["YYYY-MM-DD", "DD MMM YYYY"],
+            lenient=True,
+        ) == Date(2024, 3, 5)
+        assert Date.parse(
+            "05/03/2024", format=["%Y-%m-%d", "%d/%m/%Y"], style="strftime"
+        ) == Date(2024, 3, 5)
+
+    def test_other_types(self):
+        assert Time.parse("9:30 PM", format=["hh:mm", "i:mm aa"]) == Time(
+            21, 30
+        )
+        assert OffsetDateTime.parse(
+            "2024-03-05 09:30Z",
+            format=["YYYY-MM-DD hh:mmxxx", "YYYY-MM-DD hh:mmXXX"],
+        ) == OffsetDateTime(2024, 3, 5, 9, 30, offset=0)
+        assert Instant.parse(
+            "20240305T0930+0000",
+            format=["YYYY-MM-DD hh:mmXXX", "YYYYMMDD'T'hhmmxx"],
+        ) == Instant.from_utc(2024, 3, 5, 9, 30)
+        assert ZonedDateTime.parse(
+            "2024-03-05 Europe/Paris",
+            format=["YYYY-MM-DD hh:mm VV", "YYYY-MM-DD VV"],
+        ) == ZonedDateTime(2024, 3, 5, tz="Europe/Paris")
+
+
+class TestTwoDigitYear:
+    """Tests for parsing two-digit years with a pivot year."""
+
+    @pytest.mark.parametrize(
+        "pivot, s, expected",
+        [
+            (2000, "49", 2049),
+            (2000, "50", 1950),
+            (2000, "99", 1999),
+            (2000, "00", 2000),
+            (2050, "00", 2000),
+            (2050, "99", 2099),
+            (1970, "19", 2019),
+            (1970, "20", 1920),
+            (100, "49", 149),
+            (100, "50", 50),
+        ],
+    )
+    def test_window(self, pivot, s, expected):
+        assert Date.parse(
+            f"{s}-03-15", format="YY-MM-DD", pivot_year=pivot
+        ) == Date(expected, 3, 15)
+
+    def test_out_of_range(self):
+        with pytest.raises(ValueError, match="year out of range"):
+            Date.parse("00-03-15", format="YY-MM-DD", pivot_year=30)
+        with pytest.raises(ValueError, match="year out of range"):
+            Date.parse("39-03-15", format="YY-MM-DD", pivot_year=9990)
+
+    def test_roundtrip(self):
+        d = Date(2024, 3, 15)
+        assert Date.parse(
+            d.format("MM/DD/YY"), format="MM/DD/YY", pivot_year=2000
+        ) == d
+
+    def test_other_types(self):
+        assert PlainDateTime.parse(
+            "03/15/24 10:00", format="MM/DD/YY hh:mm", pivot_year=2000
+        ) == PlainDateTime(2024, 3, 15, 10)
+        assert OffsetDateTime.parse(
+            "24-03-15 10:00Z", format="YY-MM-DD hh:mmXXX", pivot_year=2000
+        ) == OffsetDateTime(2024, 3, 15, 10, offset=0)
+        assert Instant.parse(
+            "24-03-15 10:00Z", format="YY-MM-DD hh:mmXXX", pivot_year=2000
+        ) == Instant.from_utc(2024, 3, 15, 10)
+        assert ZonedDateTime.parse(
+            "24-03-15 Europe/Paris", format="YY-MM-DD VV", pivot_year=2000
+        ) == ZonedDateTime(2024, 3, 15, tz="Europe/Paris")
+
+    def test_with_day_of_year(self):
+        assert Date.parse(
+            "24-075", format="YY-DDD", pivot_year=2000
+        ) == Date(2024, 3, 15)
+
+    def test_year_after_other_date_fields(self):
+        assert Date.parse(
+            "366 24", format="DDD YY", pivot_year=2000
+        ) == Date(2024, 12, 31)
+        assert Date.parse(
+            "Tue 31/12/24", format="EEE DD/MM/YY", pivot_year=2000
+        ) == Date(2024, 12, 31)
+        with pytest.raises(ValueError, match="weekday does not match"):
+            Date.parse("Mon 31/12/24", format="EEE DD/MM/YY", pivot_year=2000)
+
+    def test_strftime(self):
+        assert Date.parse(
+            "03/15/24", format="%D", style="strftime", pivot_year=2000
+        ) == Date(2024, 3, 15)
+
+    def test_pattern(self):
+        p = Pattern("MM/YY", PlainDateTime)
+        with pytest.raises(ValueError, match="pivot_year") as exc_info:
+            p.parse("03/24")
+        assert type(exc_info.value) is ValueError
+        assert Pattern("DD.MM.YY", Date).parse(
+            "15.03.24", pivot_year=2000
+        ) == Date(2024, 3, 15)
+
+    def test_ignored_without_yy(self):
+        assert Date.parse(
+            "2024-03-15", format="YYYY-MM-DD", pivot_year=1900
+        ) == Date(2024, 3, 15)
+        assert Date.parse(
+            "2024-03-15", format="YYYY-MM-DD", pivot_year=None
+        ) == Date(2024, 3, 15)
+
+    def test_invalid_pivot_year(self):
+        with pytest.raises(ValueError, match="pivot_year"):
+            Date.parse("24-03-15", format="YY-MM-DD", pivot_year=0)
+        with pytest.raises(ValueError, match="pivot_year"):
+            Date.parse("24-03-15", format="YY-MM-DD", pivot_year=10_000)
+        with pytest.raises(TypeError, match="pivot_year"):
+            Date.parse(
+                "24-03-15",
+                format="YY-MM-DD",
+                pivot_year="2000",  # type: ignore[arg-type]
+            )
+
+
+class TestParseError:
+
+    def test_is_value_error(self):
+        assert issubclass(ParseError, ValueError)
+        assert ParseError.__module__ == "whenever"
+
+    def test_literal(self):
+        with pytest.raises(ParseError, match="position 4") as exc:
+            Date.parse("2024/03/15", format="YYYY-MM-DD")
+        assert str(exc.value) == "Expected '-' at position 4, got '/'"
+        assert exc.value.input == "2024/03/15"
+        assert exc.value.position == 4
+        assert exc.value.expected == "-"
+
+    def test_field(self):
+        with pytest.raises(ParseError) as exc:
+            Time.parse("12:3x", format="hh:mm")
+        assert exc.value.position == 3
+        assert exc.value.expected == "mm"
+
+    def test_field_out_of_range(self):
+        with pytest.raises(ParseError, match="month") as exc:
+            Date.parse("2024-13-15", format="YYYY-MM-DD")
+        assert exc.value.position == 5
+        assert exc.value.expected == "MM"
+
+    def test_field_position_is_start_of_field(self):
+        with pytest.raises(ParseError) as exc:
+            OffsetDateTime.parse(
+                "2024-03-15 12:00 +0x", format="YYYY-MM-DD hh:mm xx"
+            )
+        assert exc.value.position == 17
+        assert exc.value.expected == "xx"
+
+    def test_trailing_text(self):
+        with pytest.raises(ParseError, match="trailing") as exc:
+            Date.parse("2024-03-15 extra", format="YYYY-MM-DD")
+        assert exc.value.position == 10
+        assert exc.value.expected is None
+
+    def test_no_position(self):
+        with pytest.raises(ParseError, match="quarter") as exc:
+            Date.parse("2024-03-15 Q2", format="YYYY-MM-DD 'Q'Q")
+        assert exc.value.position is None
+        assert exc.value.expected is None
+        assert exc.value.render() == str(exc.value)
+
+    def test_position_is_str_index(self):
+        with pytest.raises(ParseError) as exc:
+            Date.parse(
+                "2024 mars 15", format="YYYY MMMM-DD", locale="fr"
+            )
+        assert exc.value.position == 9
+        assert exc.value.input[exc.value.position] == " "
+
+        with pytest.raises(ParseError) as exc:
+            Date.parse("é2024-03-15", format="'é'YYYY/MM-DD")
+        assert exc.value.position == 5
+
+    @pytest.mark.parametrize(
+        "s, fmt, msg",
+        [
+            ("éé24/03/15", "'éé'YY-MM-DD", "Expected '-' at position 4"),
+            ("éé2024-03-1x", "'éé'YYYY-MM-DD", "digits at position 10"),
+            ("ü2024-03-15ü", "'ü'YYYY-MM-DD", "text at position 11"),
+            ("é2024-03-15", "'éé'YYYY-MM-DD", "got 'é2'"),
+        ],
+    )
+    def test_message_uses_str_index(self, s, fmt, msg):
+        with pytest.raises(ParseError) as exc:
+            Date.parse(s, format=fmt, pivot_year=2000)
+        assert msg in str(exc.value)
+        if exc.value.position is not None:
+            assert f"position {exc.value.position}" in str(exc.value)
+
+    def test_multiple_formats_furthest(self):
+        with pytest.raises(ParseError, match="any of the 2") as exc:
+            Date.parse("2024-03-15", format=["YYYY/MM/DD", "YYYY-MM/DD"])
+        assert exc.value.position == 7
+        assert exc.value.expected == "/"
+
+    def test_pattern(self):
+        with pytest.raises(ParseError) as exc:
+            Pattern("YYYY-MM-DD", Date).parse("2024_03-15")
+        assert exc.value.position == 4
+
+    def test_render(self):
+        with pytest.raises(ParseError) as exc:
+            Date.parse("2024/03/15", format="YYYY-MM-DD")
+        assert exc.value.render() == (
+            str(exc.value) + "\n  2024/03/15\n      ^"
+        )
+
+    def test_render_at_end(self):
+        with pytest.raises(ParseError) as exc:
+            Date.parse("2024-03", format="YYYY-MM-DD")
+        assert exc.value.render().endswith("\n  2024-03\n         ^")
+
+    @pytest.mark.parametrize(
+        "func, arg, position, expected",
+        [
+            (Date.parse_iso, "2024-03-1x", 8, "day"),
+            (Date, "2024-03-1x", 8, "day"),
+            (Date.parse_iso, "2024-13-01", 5, "month"),
+            (Date.parse_iso, "2023-02-29", 8, "day"),
+            (Date.parse_iso, "2023-W53-1", 6, "week"),
+            (Date.parse_iso, "2023-366", 5, "day of year"),
+            (Date.parse_iso, "2024-03-15x", 10, None),
+            (Time.parse_iso, "12:3x", 3, "minute"),
+            (Time.parse_iso, "12:30:00.x", 8, "fraction"),
+            (PlainDateTime.parse_iso, "2020-08-15Tx", 11, "hour"),
+            (PlainDateTime.parse_iso, "2020-08-15_12:00", 10, "T"),
+            (OffsetDateTime.parse_iso, "2020-08-15T12:00", 16, "offset"),
+            (
+                OffsetDateTime.parse_iso,
+                "2020-08-15T12:00+0560",
+                19,
+                "offset minute",
+            ),
+            (Instant.parse_iso, "2020-08-15T12:00", 16, "offset"),
+            (Instant.parse_iso, "2024-01-01T25:00Z", 11, "hour"),
+            (ZonedDateTime.parse_iso, "2020-08-15T12:00", 16, "offset"),
+            (ZonedDateTime.parse_iso, "2020-08-15T12:00Z", 17, "["),
+            (ZonedDateTime.parse_iso, "2020-08-15T12:00[UTC", 20, "]"),
+            (
+                ZonedDateTime.parse_iso,
+                "2020-08-15T12:00[../x]",
+                17,
+                "timezone ID",
+            ),
+            (Instant.parse_rfc2822, "Sat, 15 Aug 2020 23:12:00 X0", 27, None),
+            (OffsetDateTime.parse_rfc2822, "Sat, 15 Aug 2020", 12, "year"),
+            (
+                OffsetDateTime.parse_rfc2822,
+                "Mon, 1 Foo 2024 00:00 +0000",
+                7,
+                "month",
+            ),
+            (
+                OffsetDateTime.parse_rfc2822,
+                "Tue, 1 Jan 2024 00:00 +0000",
+                0,
+                "weekday",
+            ),
+            (OffsetDateTime.parse_rfc2822, "1 Jan 2024 00:00 (x", 19, ")"),
+            (TimeDelta.parse_iso, "PT1X", 3, "unit"),
+            (TimeDelta, "PT1X", 3, "unit"),
+            (TimeDelta.parse_iso, "PT1M1H", 5, "unit"),
+            (TimeDelta.parse_iso, "PT25:00:00", 2, "hour"),
+            (TimeDelta.parse_go, "1h30x", 4, "unit"),
+            (TimeDelta.parse_go, "1h.", 2, "number"),
+            (TimeDelta.parse_go, "", 0, "number"),
+            (TimeDelta.parse_human, "  1 hour, 5 foo ", 12, "unit"),
+            (TimeDelta.parse_human, "5µs x", 4, "number"),
+            (
+                Instant.parse_http_date,
+                "Sun, 06 Nov 1994 08:49:37 UTC",
+                26,
+                "GMT",
+            ),
+            (
+                Instant.parse_http_date,
+                "Mon, 06 Nov 1994 08:49:37 GMT",
+                0,
+                "weekday",
+            ),
+            (Instant.parse_http_date, "Sun Nov 31 08:49:37 1994", 8, "day"),
+            (Instant.parse_http_date, "Sun Nov  6 08:49:37 94", 20, "year"),
+            (
+                Instant.parse_http_date,
+                "Sunday, 06-Nov-94 08:61:37 GMT",
+                21,
+                "minute",
+            ),
+            (
+                Instant.parse_http_date,
+                "Sun, 06 Nov 1994 08:49:37 GMT ",
+                29,
+                None,
+            ),
+            (ItemizedDelta.parse_iso, "PT1X", 3, "unit"),
+            (ItemizedDateDelta.parse_iso, "P1X", 2, "unit"),
+        ],
+    )
+    def test_iso_and_rfc2822(self, func, arg, position, expected):
+        with pytest.raises(ParseError, match="Invalid") as exc:
+            func(arg)
+        assert exc.value.input == arg
+        assert exc.value.position == position
+        assert exc.value.expected == expected
+
+    @pytest.mark.parametrize(
+        "func, arg",
+        [
+            # Out of range, rather than mismatching at a location
+            (OffsetDateTime.parse_iso, "0001-01-01T00:00+01:00"),
+            # Formats that don't report positions
+            (YearMonth.parse_iso, "2020-1x"),
+            (MonthDay.parse_iso, "--1x-01"),
+            (IsoWeekDate.parse_iso, "2024-Wxx-1"),
+        ],
+    )
+    def test_no_position(self, func, arg):
+        with pytest.raises(ParseError, match="Invalid") as exc:
+            func(arg)
+        assert exc.value.input == arg
+        assert exc.value.position is None
+        assert exc.value.expected is None
+
+    def test_pickle(self):
+        with pytest.raises(ParseError) as exc:
+            Date.parse("2024/03/15", format="YYYY-MM-DD")
+        e = pickle.loads(pickle.dumps(exc.value))
+        assert type(e) is ParseError
+        assert str(e) == str(exc.value)
+        assert (e.input, e.position, e.expected) == ("2024/03/15", 4, "-")
@@ -3,6 +3,7 @@ import pickle
 import re
 from copy import copy, deepcopy
 from datetime import datetime as py_datetime, timedelta, timezone, tzinfo
+from uuid import UUID
 from zoneinfo import ZoneInfo
 
 import pytest
@@ -12,6 +13,7 @@ from whenever import (
     DaysAssumed24HoursWarning,
     Instant,
     OffsetDateTime,
+    ParseError,
     PlainDateTime,
     StaleOffsetWarning,
     TimeDelta,
@@ -20,6 +22,7 @@ from whenever import (
     hours,
     milliseconds,
     nanoseconds,
+    patch_current_time,
     seconds,
 )
 
@@ -336,6 +339,16 @@ class TestFromTimestamp:
             -9.000_000_100
         ) == Instant.from_timestamp(-9) - nanoseconds(100)
 
+        # a tiny negative fraction doesn't round up to a whole second
+        assert Instant.from_timestamp(-1e-20) == Instant.from_timestamp(
+            0
+        ) - nanoseconds(1)
+
+        # a fraction of the last second is still in range
+        assert Instant.from_timestamp(253_402_300_799.5) == Instant.from_utc(
+            9999, 12, 31, 23, 59, 59, nanosecond=500_000_000
+        )
+
         with pytest.raises((ValueError, OverflowError)):
             Instant.from_timestamp(9e200)
 
@@ -367,6 +380,602 @@ class TestFromTimestamp:
         assert method(MyInt(0)) == Instant.from_utc(1970, 1, 1)
 
 
+class TestDayCounts:
+
+    def test_julian_day(self):
+        i = Instant.from_utc(2000, 1, 1, 12)
+        assert i.to_julian_day() == 2_451_545.0
+        assert Instant.from_julian_day(2_451_545) == i
+        assert Instant.from_julian_day(2_451_545.25) == Instant.from_utc(
+            2000, 1, 1, 18
+        )
+
+    def test_mjd(self):
+        i = Instant.from_utc(2000, 1, 1, 12)
+        assert i.to_mjd() == 51_544.5
+        assert Instant.from_mjd(51_544.5) == i
+        assert Instant.from_mjd(0) == Instant.from_utc(1858, 11, 17)
+
+    def test_rata_die(self):
+        i = Instant.from_utc(2000, 1, 1, 6)
+        assert i.to_rata_die() == 730_120.25
+        assert Instant.from_rata_die(730_120.25) == i
+        assert Instant.from_rata_die(1) == Instant.MIN
+
+    def test_excel_serial(self):
+        i = Instant.from_utc(2024, 3, 15, 18)
+        assert i.to_excel_serial() == 45_366.75
+        assert i.to_excel_serial(epoch_1904=True) == 43_904.75
+        assert Instant.from_excel_serial(45_366.75) == i
+        assert Instant.from_excel_serial(43_904.75, epoch_1904=True) == i
+
+    def test_excel_1900_leap_year_bug(self):
+        assert Instant.from_excel_serial(59.5) == Instant.from_utc(
+            1900, 2, 28, 12
+        )
+        assert Instant.from_excel_serial(61.5) == Instant.from_utc(
+            1900, 3, 1, 12
+        )
+        assert Instant.from_utc(1900, 3, 1, 12).to_excel_serial() == 61.5
+        assert Instant.from_utc(1900, 2, 28, 12).to_excel_serial() == 59.5
+        with pytest.raises(ValueError, match="60"):
+            Instant.from_excel_serial(60.5)
+
+    @pytest.mark.parametrize(
+        "method",
+        [
+            Instant.from_julian_day,
+            Instant.from_mjd,
+            Instant.from_rata_die,
+            Instant.from_excel_serial,
+        ],
+    )
+    def test_out_of_range(self, method):
+        for n in [-1e7, 1e7, float("inf"), float("nan"), 1 << 80, 10**400]:
+            with pytest.raises(ValueError):
+                method(n)
+
+    def test_last_second(self):
+        last = Instant.from_utc(9999, 12, 31, 23, 59, 59)
+        for i in [
+            Instant.from_excel_serial(2_958_465.99999),
+            Instant.from_julian_day(5_373_484.4999999),
+            Instant.from_mjd(2_973_483.99999),
+        ]:
+            assert last <= i <= Instant.MAX
+        i = last.add(milliseconds=500)
+        assert abs(Instant.from_julian_day(i.to_julian_day()) - i) < (
+            milliseconds(1)
+        )
+        assert abs(Instant.from_excel_serial(i.to_excel_serial()) - i) < (
+            milliseconds(1)
+        )
+        with pytest.raises(ValueError):
+            Instant.from_excel_serial(2_958_466)
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Instant.from_mjd("51544")  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            Instant.MIN.to_excel_serial(epoch_1904=None)  # type: ignore
+
+    def test_roundtrip(self):
+        for i in [
+            Instant.MIN,
+            Instant.from_utc(1900, 2, 27, 3),
+            Instant.from_utc(2020, 2, 29, 23, 59, 30),
+        ]:
+            # Floating point days only have sub-millisecond precision
+            assert abs(
+                Instant.from_julian_day(i.to_julian_day()) - i
+            ) < milliseconds(1)
+            assert abs(Instant.from_mjd(i.to_mjd()) - i) < milliseconds(1)
+            assert abs(
+                Instant.from_excel_serial(i.to_excel_serial()) - i
+            ) < milliseconds(1)
+
+
+
+class TestOtherTimestamps:
+
+    @pytest.mark.parametrize(
+        "to, from_, epoch, per_sec",
+        [
+            (
+                Instant.timestamp_micros,
+                Instant.from_timestamp_micros,
+                Instant.from_utc(1970, 1, 1),
+                1_000_000,
+            ),
+            (
+                Instant.to_filetime,
+                Instant.from_filetime,
+                Instant.from_utc(1601, 1, 1),
+                10_000_000,
+            ),
+            (
+                Instant.to_dotnet_ticks,
+                Instant.from_dotnet_ticks,
+                Instant.MIN,
+                10_000_000,
+            ),
+            (
+                Instant.to_postgres_micros,
+                Instant.from_postgres_micros,
+                Instant.from_utc(2000, 1, 1),
+                1_000_000,
+            ),
+            (
+                Instant.to_gps_nanos,
+                Instant.from_gps_nanos,
+                Instant.from_utc(1980, 1, 6),
+                1_000_000_000,
+            ),
+        ],
+    )
+    def test_ticks(self, to, from_, epoch, per_sec):
+        assert to(epoch) == 0
+        assert from_(0) == epoch
+        assert from_(per_sec * 3 + 1) == epoch + seconds(3) + nanoseconds(
+            1_000_000_000 // per_sec
+        )
+        # sub-tick precision is truncated
+        assert to(epoch + nanoseconds(1_000_000_000 // per_sec - 1)) == 0
+        if epoch > Instant.MIN:
+            assert to(epoch - nanoseconds(1)) == -1
+            assert from_(-1) == epoch - nanoseconds(1_000_000_000 // per_sec)
+        assert from_(to(Instant.MAX)) == Instant.MAX.round(
+            "nanosecond", increment=1_000_000_000 // per_sec, mode="floor"
+        )
+        assert from_(to(Instant.MIN)) == Instant.MIN
+
+        with pytest.raises((ValueError, OverflowError)):
+            from_(to(Instant.MAX) + per_sec)
+        with pytest.raises((ValueError, OverflowError)):
+            from_(to(Instant.MIN) - 1)
+        with pytest.raises((ValueError, OverflowError)):
+            from_(1 << 200)
+        with pytest.raises(TypeError):
+            from_(1.0)
+
+    def test_known_values(self):
+        i = Instant.from_utc(2024, 3, 15)
+        assert i.timestamp_micros() == 1_710_460_800_000_000
+        assert i.to_filetime() == 133_549_344_000_000_000
+        assert i.to_dotnet_ticks() == 638_460_576_000_000_000
+        assert i.to_postgres_micros() == 763_776_000_000_000
+        # GPS time is 18 leap seconds ahead of UTC
+        assert i.to_gps_nanos() == 1_394_496_018_000_000_000
+
+    def test_gps(self):
+        epoch = Instant.from_utc(1980, 1, 6)
+        assert epoch.to_gps_nanos() == 0
+        assert Instant.from_gps_nanos(0) == epoch
+        # The first leap second, at the end of 1981-06-30
+        assert Instant.from_utc(1981, 6, 30, 23, 59, 59).to_gps_nanos() == (
+            46_828_799_000_000_000
+        )
+        assert Instant.from_utc(1981, 7, 1).to_gps_nanos() == (
+            46_828_801_000_000_000
+        )
+
+        # Around the last leap second, at the end of 2016-12-31
+        before = Instant.from_utc(2016, 12, 31, 23, 59, 59, nanosecond=5)
+        after = Instant.from_utc(2017, 1, 1)
+        gps_before = 1_167_264_016_000_000_005
+        gps_after = 1_167_264_018_000_000_000
+        assert before.to_gps_nanos() == gps_before
+        assert after.to_gps_nanos() == gps_after
+        assert Instant.from_gps_nanos(gps_before) == before
+        assert Instant.from_gps_nanos(gps_after) == after
+        # The leap second itself becomes the second before it
+        assert Instant.from_gps_nanos(
+            gps_after - 1_000_000_000
+        ) == Instant.from_utc(2016, 12, 31, 23, 59, 59)
+        assert Instant.from_gps_nanos(gps_after - 1) == Instant.from_utc(
+            2016, 12, 31, 23, 59, 59, nanosecond=999_999_999
+        )
+
+        for d in [
+            Instant.MIN,
+            Instant.from_utc(1990, 5, 5, nanosecond=1),
+            Instant.from_utc(2012, 6, 30, 23, 59, 59),
+            Instant.from_utc(2012, 7, 1),
+            Instant.MAX,
+        ]:
+            assert Instant.from_gps_nanos(d.to_gps_nanos()) == d
+
+    def test_ntp(self):
+        i = Instant.from_utc(2024, 3, 15, 12)
+        assert i.to_ntp() == 3_919_492_800 << 32
+        assert Instant.from_ntp(0) == Instant.from_utc(1900, 1, 1)
+        assert Instant.from_ntp(3_919_492_800 << 32 | 1 << 31) == i + (
+            milliseconds(500)
+        )
+        # the fraction is truncated to nanoseconds
+        assert Instant.from_ntp(4) == Instant.from_utc(1900, 1, 1)
+        assert Instant.from_ntp(4).to_ntp() == 0
+        assert Instant.from_ntp((1 << 64) - 1) == Instant.from_utc(
+            2036, 2, 7, 6, 28, 15, nanosecond=999_999_999
+        )
+        # ...and rounded up when converting back, to make roundtrips exact
+        assert (i + nanoseconds(1)).to_ntp() == (3_919_492_800 << 32) + 5
+        for ns in [1, 7, 123_456_789, 999_999_999]:
+            j = i + nanoseconds(ns)
+            assert Instant.from_ntp(j.to_ntp()) == j
+
+    def test_ntp_out_of_range(self):
+        with pytest.raises(ValueError, match="era"):
+            (Instant.from_utc(1900, 1, 1) - nanoseconds(1)).to_ntp()
+        with pytest.raises(ValueError, match="era"):
+            Instant.from_utc(2036, 2, 7, 6, 28, 16).to_ntp()
+        with pytest.raises((ValueError, OverflowError)):
+            Instant.from_ntp(-1)
+        with pytest.raises((ValueError, OverflowError)):
+            Instant.from_ntp(1 << 64)
+        with pytest.raises(TypeError):
+            Instant.from_ntp(1.0)  # type: ignore[arg-type]
+
+    def test_cocoa(self):
+        i = Instant.from_utc(2024, 3, 15, 12)
+        assert i.to_cocoa() == 732_196_800.0
+        assert (i + milliseconds(250)).to_cocoa() == 732_196_800.25
+        assert Instant.from_cocoa(0) == Instant.from_utc(2001, 1, 1)
+        assert Instant.from_cocoa(732_196_800) == i
+        assert Instant.from_cocoa(732_196_800.25) == i + milliseconds(250)
+        assert Instant.from_cocoa(-0.25) == Instant.from_utc(
+            2000, 12, 31, 23, 59, 59, nanosecond=750_000_000
+        )
+        assert Instant.from_cocoa(Instant.MIN.to_cocoa()) == Instant.MIN
+        # a tiny negative fraction doesn't round up to a whole second
+        assert Instant.from_cocoa(-1e-20) == Instant.from_utc(
+            2000, 12, 31, 23, 59, 59, nanosecond=999_999_999
+        )
+        # any fraction of the last second is in range
+        assert Instant.from_cocoa(252_423_993_599.5) == Instant.from_utc(
+            9999, 12, 31, 23, 59, 59, nanosecond=500_000_000
+        )
+
+    def test_cocoa_out_of_range(self):
+        for n in [
+            Instant.MAX.to_cocoa() + 1,
+            Instant.MIN.to_cocoa() - 1,
+            252_423_993_600.0,
+            252_423_993_600,
+            float("inf"),
+            float("nan"),
+            1 << 62,
+            1 << 63,
+            -(1 << 63) - 1,
+            1 << 200,
+        ]:
+            with pytest.raises(ValueError, match="out of range"):
+                Instant.from_cocoa(n)
+        with pytest.raises(TypeError):
+            Instant.from_cocoa("0")  # type: ignore[arg-type]
+
+
+
+class TestTimeOrderedIds:
+
+    def test_uuid7(self):
+        # Example from RFC 9562, appendix A.6
+        u = UUID("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")
+        expect = Instant.from_timestamp_millis(0x017F22E279B0)
+        assert Instant.from_uuid7(u) == expect
+        assert Instant.from_uuid7(u.bytes) == expect
+        assert expect.to_uuid7() == UUID(
+            "017f22e2-79b0-7000-8000-000000000000"
+        )
+        assert expect.to_uuid7() <= u
+        assert (expect + milliseconds(1)).to_uuid7() > u
+        # sub-millisecond precision is truncated
+        assert (expect + nanoseconds(999_999)).to_uuid7() == expect.to_uuid7()
+        assert Instant.from_timestamp(0).to_uuid7().version == 7
+        assert Instant.MAX.to_uuid7().version == 7
+
+    def test_uuid7_invalid(self):
+        u = UUID("017f22e2-79b0-7cc3-98c4-dc0c0c07398f")
+        with pytest.raises(ValueError, match="version 7"):
+            Instant.from_uuid7(UUID("017f22e2-79b0-4cc3-98c4-dc0c0c07398f"))
+        with pytest.raises(ValueError, match="version 7"):
+            Instant.from_uuid7(UUID("017f22e2-79b0-7cc3-18c4-dc0c0c07398f"))
+        with pytest.raises(ValueError, match="version 7"):
+            Instant.from_uuid7(u.bytes[:15])
+        with pytest.raises(ValueError, match="out of range"):
+            # beyond the year 9999
+            Instant.from_uuid7(UUID("ffffffff-ffff-7000-8000-000000000000"))
+        with pytest.raises(TypeError):
+            Instant.from_uuid7(str(u))  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            Instant.from_uuid7(bytearray(u.bytes))  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="1970"):
+            (Instant.from_timestamp(0) - nanoseconds(1)).to_uuid7()
+
+    def test_ulid(self):
+        # Example from the ULID spec
+        expect = Instant.from_timestamp_millis(1_469_922_850_259)
+        assert Instant.from_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAV") == expect
+        assert Instant.from_ulid("01arz3ndektsv4rrffq69g5fav") == expect
+        assert expect.to_ulid() == "01ARZ3NDEK0000000000000000"
+        assert Instant.from_timestamp(0).to_ulid() == "0" * 26
+        assert Instant.from_ulid(Instant.MAX.to_ulid()) == Instant.MAX.round(
+            "millisecond", mode="floor"
+        )
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "",
+            "01ARZ3NDEKTSV4RRFFQ69G5FA",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAVX",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU",  # U isn't in the alphabet
+            "01ARZ3NDEKTSV4RRFFQ69G5FA\u017f",  # non-ASCII 's'
+            "80000000000000000000000000",  # overflow
+        ],
+    )
+    def test_ulid_invalid(self, s):
+        with pytest.raises(ValueError, match="Invalid ULID"):
+            Instant.from_ulid(s)
+
+    def test_ulid_invalid_type_or_range(self):
+        with pytest.raises(TypeError):
+            Instant.from_ulid(b"01ARZ3NDEKTSV4RRFFQ69G5FAV")  # type: ignore
+        with pytest.raises(ValueError, match="out of range"):
+            # beyond the year 9999
+            Instant.from_ulid("7ZZZZZZZZZ0000000000000000")
+        with pytest.raises(ValueError, match="1970"):
+            (Instant.from_timestamp(0) - nanoseconds(1)).to_ulid()
+
+    def test_snowflake(self):
+        # Example from Discord's API documentation
+        discord = Instant.from_utc(2015, 1, 1)
+        expect = Instant.from_timestamp_millis(1_462_015_105_796)
+        assert (
+            Instant.from_snowflake(175928847299117063, epoch=discord)
+            == expect
+        )
+        assert expect.to_snowflake(epoch=discord) == (
+            175928847299117063 >> 22 << 22
+        )
+        assert Instant.from_snowflake(0, epoch=discord) == discord
+        assert discord.to_snowflake(epoch=discord) == 0
+        # other exact types are accepted as epoch
+        assert expect.to_snowflake(
+            epoch=discord.to_fixed_offset(2)
+        ) == expect.to_snowflake(epoch=discord)
+
+    def test_snowflake_invalid(self):
+        discord = Instant.from_utc(2015, 1, 1)
+        with pytest.raises(ValueError):
+            Instant.from_snowflake(-1, epoch=discord)
+        with pytest.raises(ValueError):
+            Instant.from_snowflake(1 << 63, epoch=discord)
+        with pytest.raises(ValueError):
+            (discord - nanoseconds(1)).to_snowflake(epoch=discord)
+        with pytest.raises(ValueError):
+            (discord + milliseconds(1 << 41)).to_snowflake(epoch=discord)
+        with pytest.raises(ValueError, match="out of range"):
+            # epoch pushes the result out of range
+            Instant.from_snowflake((1 << 63) - 1, epoch=Instant.MAX)
+        with pytest.raises(TypeError):
+            Instant.from_snowflake(1.0, epoch=discord)  # type: ignore
+        with pytest.raises(TypeError):
+            Instant.from_snowflake(1, epoch=1)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            Instant.from_snowflake(1)  # type: ignore[call-arg]
+        with pytest.raises(TypeError):
+            discord.to_snowflake()  # type: ignore[call-arg]
+        with pytest.raises(TypeError):
+            discord.to_snowflake(discord)  # type: ignore[misc]
+
+
+class TestCbor:
+
+    @pytest.mark.parametrize(
+        "i, tag, data",
+        [
+            # Examples from RFC 8949, appendix A
+            (Instant.from_timestamp(1363896240), 1, "c11a514b67b0"),
+            (
+                Instant.from_timestamp(1363896240.5),
+                1,
+                "c1fb41d452d9ec200000",
+            ),
+            (
+                Instant.from_utc(2013, 3, 21, 20, 4),
+                0,
+                "c074323031332d30332d32315432303a30343a30305a",
+            ),
+            # Example from RFC 9581
+            (
+                Instant.from_timestamp(1363896240),
+                1001,
+                "d903e9a1011a514b67b0",
+            ),
+            (Instant.from_timestamp(-1), 1, "c120"),
+            (
+                Instant.from_timestamp_nanos(-999_999_999),
+                1001,
+                "d903e9a201202801",
+            ),
+        ],
+    )
+    def test_known_values(self, i, tag, data):
+        assert i.to_cbor(tag=tag).hex() == data
+        assert Instant.from_cbor(bytes.fromhex(data)) == i
+
+    def test_default_tag(self):
+        i = Instant.from_utc(2013, 3, 21, 20, 4)
+        assert i.to_cbor() == i.to_cbor(tag=1)
+
+    @pytest.mark.parametrize(
+        "data, expect",
+        [
+            # fraction in milliseconds or microseconds
+            ("d903e9a2011a514b67b0221901f4", 1363896240_500_000_000),
+            ("d903e9a2011a514b67b0251901f4", 1363896240_000_500_000),
+            # keys in a different order
+            ("d903e9a2221901f4011a514b67b0", 1363896240_500_000_000),
+            # float base time
+            ("d903e9a101fb41d452d9ec200000", 1363896240_500_000_000),
+            ("d903e9a101f93c00", 1_000_000_000),
+            # unknown elective keys are ignored, whatever their value
+            (
+                "d903e9a3011a514b67b00a8261784301f40018636162",
+                1363896240_000_000_000,
+            ),
+            ("d903e9a20a01011a514b67b0", 1363896240_000_000_000),
+            ("d903e9a2011a514b67b00ac100", 1363896240_000_000_000),
+            # half and single precision floats
+            ("c1f93e00", 1_500_000_000),
+            ("c1fa3fc00000", 1_500_000_000),
+            # non-shortest integer
+            ("c11b0000000000000001", 1_000_000_000),
+            # RFC 3339 with an offset
+            (
+                "c0781b323031332d30332d32315432313a30343a30302e352b30313a3030",
+                1363896240_500_000_000,
+            ),
+            # RFC 3339 in lowercase
+            (
+                "c076323031332d30332d32317432303a30343a30302e357a",
+                1363896240_500_000_000,
+            ),
+        ],
+    )
+    def test_decode_variants(self, data, expect):
+        assert Instant.from_cbor(
+            bytes.fromhex(data)
+        ) == Instant.from_timestamp_nanos(expect)
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            "",
+            "c1",
+            "1a514b67b0",  # untagged
+            "c21a514b67b0",  # unsupported tag
+            "d903eaa1011a514b67b0",  # duration tag
+            "c11a514b67b000",  # trailing data
+            "c11a514b67",  # truncated
+            "c11b7fffffffffffffff",  # out of range
+            "c1f97c00",  # infinity
+            "c1f97e00",  # NaN
+            "c17f6131ff",  # indefinite length
+            "c0636162",  # invalid text
+            "c043616263",  # bytes instead of text
+            # ISO 8601, but not RFC 3339
+            "c074323031332d30332d32312032303a30343a30305a",
+            "c071323031332d30332d32315432303a30345a",
+            "d903e9a12801",  # missing seconds
+            "d903e9a201010101",  # duplicate key
+            "d903e9a201012001",  # unknown critical key
+            "d903e9a3010128012801",  # duplicate fraction
+            "d903e9a20101221903e8",  # fraction out of range
+            "d903e9a201012821",  # negative fraction
+            "d903e9a201f93c002801",  # float seconds with fraction
+            "d903e9a101f97c00",  # infinite float seconds
+            "d903e9a201010a82",  # truncated elective value
+            "d903e9a201010a9f00ff",  # indefinite length elective value
+        ],
+    )
+    def test_invalid(self, data):
+        with pytest.raises(ValueError, match="invalid CBOR data"):
+            Instant.from_cbor(bytes.fromhex(data))
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Instant.from_cbor(bytearray(b"\xc1\x00"))  # type: ignore
+        with pytest.raises(TypeError):
+            Instant.from_cbor("c100")  # type: ignore[arg-type]
+
+    @pytest.mark.parametrize("tag", [2, -1, True, 1.0, "1", None])
+    def test_invalid_tag(self, tag):
+        with pytest.raises(ValueError, match="tag"):
+            Instant.MIN.to_cbor(tag=tag)
+
+    @pytest.mark.parametrize("tag", [0, 1001])
+    @given(
+        nanos=integers(
+            Instant.MIN.timestamp_nanos(), Instant.MAX.timestamp_nanos()
+        )
+    )
+    def test_roundtrip(self, tag, nanos):
+        i = Instant.from_timestamp_nanos(nanos)
+        assert Instant.from_cbor(i.to_cbor(tag=tag)) == i
+
+    def test_roundtrip_extremes(self):
+        for i in (Instant.MIN, Instant.MAX):
+            for tag in (0, 1001):
+                assert Instant.from_cbor(i.to_cbor(tag=tag)) == i
+        assert Instant.from_cbor(Instant.MIN.to_cbor(tag=1)) == Instant.MIN
+        # The float may lose precision, but stays in range
+        decoded = Instant.from_cbor(Instant.MAX.to_cbor(tag=1))
+        assert Instant.MAX - TimeDelta(milliseconds=1) < decoded
+        assert decoded <= Instant.MAX
+        i = Instant.MIN + TimeDelta(seconds=0.5)
+        assert Instant.from_cbor(i.to_cbor(tag=1)) == i
+
+
+class TestMsgpack:
+
+    @pytest.mark.parametrize(
+        "i, data",
+        [
+            # 32-bit
+            (Instant.from_timestamp(0), "d6ff00000000"),
+            (Instant.from_timestamp(2**32 - 1), "d6ffffffffff"),
+            # 64-bit
+            (
+                Instant.from_timestamp_nanos(1_000_000_001),
+                "d7ff0000000400000001",
+            ),
+            (Instant.from_timestamp(2**32), "d7ff0000000100000000"),
+            (Instant.from_timestamp(2**34 - 1), "d7ff00000003ffffffff"),
+            # 96-bit
+            (Instant.from_timestamp(2**34), "c70cff000000000000000400000000"),
+            (
+                Instant.from_timestamp_nanos(-500_000_000),
+                "c70cff1dcd6500ffffffffffffffff",
+            ),
+        ],
+    )
+    def test_known_values(self, i, data):
+        assert i.to_msgpack().hex() == data
+        assert Instant.from_msgpack(bytes.fromhex(data)) == i
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            "",
+            "d6ff000000",  # truncated
+            "d60000000000",  # wrong extension type
+            "d6ff0000000000",  # trailing data
+            "d7ffee6b280000000000",  # nanoseconds out of range
+            "c70cff3b9aca000000000000000000",
+            "c70bff0000000000000000000000",  # wrong length
+            "c70cff000000007fffffffffffffff",  # out of range
+        ],
+    )
+    def test_invalid(self, data):
+        with pytest.raises(ValueError, match="MessagePack"):
+            Instant.from_msgpack(bytes.fromhex(data))
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Instant.from_msgpack("d6ff00000000")  # type: ignore[arg-type]
+
+    @given(
+        nanos=integers(
+            Instant.MIN.timestamp_nanos(), Instant.MAX.timestamp_nanos()
+        )
+    )
+    def test_roundtrip(self, nanos):
+        i = Instant.from_timestamp_nanos(nanos)
+        assert Instant.from_msgpack(i.to_msgpack()) == i
+
+
 def test_repr():
     d = Instant.from_utc(2020, 8, 15, 23, 12, 9, nanosecond=987_654)
     assert repr(d) == 'Instant("2020-08-15 23:12:09.000987654Z")'
@@ -925,6 +1534,144 @@ class TestParseRFC2822:
         with pytest.raises(ValueError, match=re.escape(repr(s))):
             Instant.parse_rfc2822(s)
 
+    def test_strict(self):
+        assert Instant.parse_rfc2822(
+            "Sat, 15 Aug 2020 23:12:09 +0200 (CEST)", strict=True
+        ) == Instant.from_utc(2020, 8, 15, 21, 12, 9)
+        with pytest.raises(ParseError):
+            Instant.parse_rfc2822("Sat, 15 Aug 2020 23:12:09 UT", strict=True)
+
+    def test_strict_roundtrip(self):
+        i = Instant.from_utc(2024, 7, 1, 12)
+        assert Instant.parse_rfc2822(i.format_rfc2822(), strict=True) == i
+
+
+class TestParseHTTPDate:
+    @pytest.mark.parametrize(
+        "s, expected",
+        [
+            # IMF-fixdate
+            (
+                "Sun, 06 Nov 1994 08:49:37 GMT",
+                Instant.from_utc(1994, 11, 6, 8, 49, 37),
+            ),
+            (
+                "Mon, 01 Jan 0001 00:00:00 GMT",
+                Instant.from_utc(1, 1, 1),
+            ),
+            (
+                "Fri, 31 Dec 9999 23:59:60 GMT",
+                Instant.from_utc(9999, 12, 31, 23, 59, 59),
+            ),
+            # RFC 850
+            (
+                "Sunday, 06-Nov-94 08:49:37 GMT",
+                Instant.from_utc(1994, 11, 6, 8, 49, 37),
+            ),
+            (
+                "Wednesday, 15-Jul-20 23:12:00 GMT",
+                Instant.from_utc(2020, 7, 15, 23, 12),
+            ),
+            # asctime
+            (
+                "Sun Nov  6 08:49:37 1994",
+                Instant.from_utc(1994, 11, 6, 8, 49, 37),
+            ),
+            (
+                "Thu Oct 15 00:00:01 2020",
+                Instant.from_utc(2020, 10, 15, 0, 0, 1),
+            ),
+            (
+                "Thu Oct 01 00:00:01 2020",
+                Instant.from_utc(2020, 10, 1, 0, 0, 1),
+            ),
+        ],
+    )
+    def test_valid(self, s, expected):
+        with patch_current_time(
+            Instant.from_utc(2025, 1, 1), keep_ticking=False
+        ):
+            assert Instant.parse_http_date(s) == expected
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "",
+            "Sun",
+            # wrong weekday
+            "Mon, 06 Nov 1994 08:49:37 GMT",
+            "Monday, 06-Nov-94 08:49:37 GMT",
+            "Mon Nov  6 08:49:37 1994",
+            # case-sensitive
+            "sun, 06 Nov 1994 08:49:37 GMT",
+            "Sun, 06 NOV 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49:37 gmt",
+            "SUNDAY, 06-Nov-94 08:49:37 GMT",
+            # other zones or offsets
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "Sun, 06 Nov 1994 08:49:37 +0000",
+            "Sunday, 06-Nov-94 08:49:37 UT",
+            # non-standard whitespace or padding
+            "Sun,  06 Nov 1994 08:49:37 GMT",
+            "Sun, 6 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49:37 GMT ",
+            " Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sun Nov 6 08:49:37 1994",
+            "Sun Nov  6 08:49:37 1994 ",
+            "Sun Nov  6 8:49:37 1994",
+            "Sunday, 6-Nov-94 08:49:37 GMT",
+            # mixed up formats
+            "Sun, 06-Nov-94 08:49:37 GMT",
+            "Sunday, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-1994 08:49:37 GMT",
+            "Sunday Nov  6 08:49:37 1994",
+            "Sun, Nov  6 08:49:37 1994",
+            # seconds are required
+            "Sun, 06 Nov 1994 08:49 GMT",
+            "Sun Nov  6 08:49 1994",
+            # invalid values
+            "Sun, 31 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:00:00 GMT",
+            "Sun, 06 Nov 1994 08:60:00 GMT",
+            "Sun, 06 Nov 1994 08:49:61 GMT",
+            "Sun, 06 Nov 0000 08:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49:37.5 GMT",
+            "Sun, ०६ Nov 1994 08:49:37 GMT",
+        ],
+    )
+    def test_invalid(self, s):
+        with pytest.raises(ParseError, match=re.escape(repr(s))):
+            Instant.parse_http_date(s)
+
+    @pytest.mark.parametrize(
+        "now, s, expected",
+        [
+            # up to 50 years in the future is allowed
+            (2025, "Tuesday, 31-Dec-75 00:00:00 GMT", 2075),
+            (2025, "Thursday, 01-Jan-76 00:00:00 GMT", 1976),
+            (2025, "Wednesday, 01-Jan-25 00:00:00 GMT", 2025),
+            (2025, "Monday, 01-Jan-24 00:00:00 GMT", 2024),
+            (2090, "Sunday, 01-Jan-30 00:00:00 GMT", 2130),
+            (2090, "Friday, 01-Jan-40 00:00:00 GMT", 2140),
+            (2090, "Tuesday, 01-Jan-41 00:00:00 GMT", 2041),
+        ],
+    )
+    def test_rfc850_year(self, now, s, expected):
+        with patch_current_time(
+            Instant.from_utc(now, 6, 1), keep_ticking=False
+        ):
+            assert Instant.parse_http_date(s).to_fixed_offset().year == (
+                expected
+            )
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Instant.parse_http_date(b"Sun, 06 Nov 1994")  # type: ignore[arg-type]
+
+    def test_roundtrip(self):
+        i = Instant.from_utc(2024, 2, 29, 13, 1, 2)
+        assert Instant.parse_http_date(i.format_rfc2822()) == i
+
 
 class TestFormatIso:
     @pytest.mark.parametrize(
@@ -1229,6 +1976,55 @@ class TestRound:
             d.round(TimeDelta(hours=1), increment=2)  # type: ignore[call-overload]
 
 
+class TestHumanize:
+    def test_relative_to(self):
+        i = Instant.from_utc(2024, 3, 1, 12)
+        assert (
+            i.humanize(relative_to=Instant.from_utc(2024, 3, 1, 9, 30))
+            == "in 2 hours"
+        )
+        assert (
+            i.humanize(relative_to=Instant.from_utc(2024, 3, 1, 12, 45))
+            == "45 minutes ago"
+        )
+        assert i.humanize(relative_to=i) == "now"
+        assert (
+            i.humanize(
+                relative_to=i.to_tz("Asia/Tokyo").add(minutes=30),
+                locale="nl",
+            )
+            == "30 minuten geleden"
+        )
+        assert (
+            i.humanize(
+                relative_to=i.to_fixed_offset(2).add(seconds=-90),
+                round_mode="half_even",
+            )
+            == "in 2 minutes"
+        )
+
+    def test_default_now(self):
+        i = Instant.from_utc(2024, 3, 1, 12)
+        with patch_current_time(i.add(hours=5), keep_ticking=False):
+            assert i.humanize() == "5 hours ago"
+
+    def test_days(self):
+        i = Instant.from_utc(2024, 3, 1, 12)
+        with pytest.warns(DaysAssumed24HoursWarning):
+            assert i.humanize(relative_to=i.add(hours=-50)) == "in 2 days"
+        assert (
+            i.humanize(relative_to=i.add(hours=-50), days_assumed_24h_ok=True)
+            == "in 2 days"
+        )
+
+    def test_invalid(self):
+        i = Instant.from_utc(2024, 3, 1, 12)
+        with pytest.raises(TypeError, match="humanize"):
+            i.humanize(relative_to=PlainDateTime(2024, 3, 1))  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="granularity"):
+            i.humanize(relative_to=i, granularity="years")  # type: ignore[arg-type]
+
+
 class TestDeprecations:
     def test_py_datetime(self):
         d = Instant.from_utc(2020, 8, 15, 23, 12, 9, nanosecond=987_654)
@@ -0,0 +1,582 @@
+import pickle
+from itertools import islice
+
+import pytest
+from whenever import (
+    Date,
+    Instant,
+    Interval,
+    ItemizedDateDelta,
+    ItemizedDelta,
+    OffsetDateTime,
+    ParseError,
+    PlainDateTime,
+    RepeatingInterval,
+    ZonedDateTime,
+)
+
+from .common import AlwaysEqual, NeverEqual
+
+
+class TestInit:
+    def test_start_end(self):
+        i = Interval(Date(2025, 2, 10), Date(2025, 2, 15))
+        assert i.start == Date(2025, 2, 10)
+        assert i.end == Date(2025, 2, 15)
+        assert i.duration is None
+
+    def test_empty(self):
+        d = Date(2025, 2, 10)
+        i = Interval(d, d)
+        assert i.start == i.end == d
+
+    def test_start_duration(self):
+        i = Interval(
+            OffsetDateTime(2025, 2, 10, 9, offset=1),
+            duration=ItemizedDelta(hours=8),
+        )
+        assert i.end.exact_eq(OffsetDateTime(2025, 2, 10, 17, offset=1))
+        assert i.duration == ItemizedDelta(hours=8)
+
+    def test_duration_end(self):
+        i = Interval(
+            end=Date(2025, 3, 31), duration=ItemizedDateDelta(months=1)
+        )
+        assert i.start == Date(2025, 2, 28)
+        assert i.end == Date(2025, 3, 31)
+
+    def test_instant_duration_uses_utc(self):
+        i = Interval(
+            Instant.from_utc(2025, 1, 31), duration=ItemizedDelta(months=1)
+        )
+        assert i.end == Instant.from_utc(2025, 2, 28)
+
+    @pytest.mark.parametrize(
+        "args, kwargs",
+        [
+            ((), {}),
+            ((Date(2025, 1, 1),), {}),
+            ((), {"end": Date(2025, 1, 1)}),
+            ((), {"duration": ItemizedDateDelta(days=1)}),
+            (
+                (Date(2025, 1, 1), Date(2025, 1, 2)),
+                {"duration": ItemizedDateDelta(days=1)},
+            ),
+        ],
+    )
+    def test_wrong_number_of_arguments(self, args, kwargs):
+        with pytest.raises(TypeError, match="two of"):
+            Interval(*args, **kwargs)
+
+    def test_mixed_types(self):
+        with pytest.raises(TypeError, match="same type"):
+            Interval(
+                PlainDateTime(2025, 1, 1),
+                OffsetDateTime(2025, 1, 2, offset=0),  # type: ignore[arg-type]
+            )
+
+    @pytest.mark.parametrize(
+        "start",
+        [
+            ZonedDateTime(2025, 1, 1, tz="Europe/Amsterdam"),
+            "2025-01-01",
+            None,
+        ],
+    )
+    def test_unsupported_types(self, start):
+        with pytest.raises(TypeError):
+            Interval(start, start)
+
+    def test_end_before_start(self):
+        with pytest.raises(ValueError, match="before"):
+            Interval(Date(2025, 1, 2), Date(2025, 1, 1))
+
+    def test_negative_duration(self):
+        with pytest.raises(ValueError):
+            Interval(Date(2025, 1, 2), duration=ItemizedDateDelta(days=-1))
+
+    def test_wrong_duration_type(self):
+        with pytest.raises(TypeError):
+            Interval(
+                Date(2025, 1, 2),
+                duration=ItemizedDelta(hours=1),  # type: ignore[arg-type]
+            )
+        with pytest.raises(TypeError):
+            Interval(
+                PlainDateTime(2025, 1, 2),
+                duration=ItemizedDateDelta(days=1),  # type: ignore
+            )
+
+
+def test_contains():
+    i = Interval.parse_iso("2025-02-10T09:00Z/17:00", Instant)
+    assert Instant.from_utc(2025, 2, 10, 9) in i
+    assert Instant.from_utc(2025, 2, 10, 16, 59) in i
+    assert Instant.from_utc(2025, 2, 10, 17) not in i
+    assert Instant.from_utc(2025, 2, 10, 8, 59) not in i
+    assert PlainDateTime(2025, 2, 10, 10) not in i  # type: ignore[operator]
+    assert "foo" not in i  # type: ignore[operator]
+
+
+class TestParseIso:
+    @pytest.mark.parametrize(
+        "s, kind, start, end",
+        [
+            (
+                "2025-02-10/2025-02-15",
+                Date,
+                Date(2025, 2, 10),
+                Date(2025, 2, 15),
+            ),
+            ("2025-02-10/15", Date, Date(2025, 2, 10), Date(2025, 2, 15)),
+            ("2025-02-10/03-01", Date, Date(2025, 2, 10), Date(2025, 3, 1)),
+            ("20250210/15", Date, Date(2025, 2, 10), Date(2025, 2, 15)),
+            ("20250210/0301", Date, Date(2025, 2, 10), Date(2025, 3, 1)),
+            (
+                "2025-02-10/P1W",
+                Date,
+                Date(2025, 2, 10),
+                Date(2025, 2, 17),
+            ),
+            (
+                "P1M/2025-03-31",
+                Date,
+                Date(2025, 2, 28),
+                Date(2025, 3, 31),
+            ),
+            (
+                "2025-02-10T09:00/2025-02-12T17:00",
+                PlainDateTime,
+                PlainDateTime(2025, 2, 10, 9),
+                PlainDateTime(2025, 2, 12, 17),
+            ),
+            (
+                "2025-02-10T09:00/17:30",
+                PlainDateTime,
+                PlainDateTime(2025, 2, 10, 9),
+                PlainDateTime(2025, 2, 10, 17, 30),
+            ),
+            (
+                "2025-02-10T09:00/12T17:30",
+                PlainDateTime,
+                PlainDateTime(2025, 2, 10, 9),
+                PlainDateTime(2025, 2, 12, 17, 30),
+            ),
+            (
+                "2025-02-10T09:00/PT8H30M",
+                PlainDateTime,
+                PlainDateTime(2025, 2, 10, 9),
+                PlainDateTime(2025, 2, 10, 17, 30),
+            ),
+            (
+                "2025-02-10T09:00+01:00/2025-02-10T17:00+02:00",
+                OffsetDateTime,
+                OffsetDateTime(2025, 2, 10, 9, offset=1),
+                OffsetDateTime(2025, 2, 10, 17, offset=2),
+            ),
+            (
+                "2025-02-10T09:00+01:00/17:00",
+                OffsetDateTime,
+                OffsetDateTime(2025, 2, 10, 9, offset=1),
+                OffsetDateTime(2025, 2, 10, 17, offset=1),
+            ),
+            (
+                "PT1H/2025-02-10T09:00-05:00",
+                OffsetDateTime,
+                OffsetDateTime(2025, 2, 10, 8, offset=-5),
+                OffsetDateTime(2025, 2, 10, 9, offset=-5),
+            ),
+            (
+                "2025-01-01T00:00Z/P1D",
+                Instant,
+                Instant.from_utc(2025, 1, 1),
+                Instant.from_utc(2025, 1, 2),
+            ),
+            (
+                "2025-02-10T09:00+01:00/17:00",
+                Instant,
+                Instant.from_utc(2025, 2, 10, 8),
+                Instant.from_utc(2025, 2, 10, 16),
+            ),
+            (
+                "2025-02-10t09:00z/p1dt1h",
+                Instant,
+                Instant.from_utc(2025, 2, 10, 9),
+                Instant.from_utc(2025, 2, 11, 10),
+            ),
+        ],
+    )
+    def test_valid(self, s, kind, start, end):
+        i = Interval.parse_iso(s, kind)
+        assert type(i.start) is type(i.end) is kind
+        assert i.start == start
+        assert i.end == end
+        if kind is OffsetDateTime:
+            assert i.start.exact_eq(start)
+            assert i.end.exact_eq(end)
+
+    @pytest.mark.parametrize(
+        "s, kind",
+        [
+            ("", Date),
+            ("2025-02-10", Date),
+            ("2025-02-10/", Date),
+            ("/2025-02-10", Date),
+            ("2025-02-10/2025-02-15/2025-02-20", Date),
+            ("P1D/P2D", Date),
+            ("2025-02-10/PT1H", Date),
+            ("2025-02-10/P", Date),
+            ("2025-02-10/2025-02-32", Date),
+            ("2025-02-10/1", Date),
+            ("2025-02-15/10", Date),
+            ("2025-02-10/P-1D", Date),
+            ("2025-02-10T09:00/2025-02-10T08:00", PlainDateTime),
+            ("2025-02-10T09:00+01:00/2025-02-10T17:00", PlainDateTime),
+            ("2025-02-10T09:00/2025-02-10T17:00", OffsetDateTime),
+            ("2025-02-10T09:00/2025-02-10T17:00Z", Instant),
+            ("2025-02-10/2025-02-12", Instant),
+            ("2025-02-10T09:00Z/17:00/", Instant),
+            ("2025-02-10T09:00Z / 17:00", Instant),
+        ],
+    )
+    def test_invalid(self, s, kind):
+        with pytest.raises(ParseError, match=r"Invalid format.*" + s[:4]):
+            Interval.parse_iso(s, kind)
+
+    @pytest.mark.parametrize(
+        "s, kind, position, expected",
+        [
+            ("2025-02-10", Date, 10, "/"),
+            ("2025-02-10/", Date, 11, "year"),
+            ("2025-02-10/2025-02-32", Date, 19, "day"),
+            ("2025-02-15/2025-02-10", Date, 11, "date"),
+            ("2025-02-10/1", Date, 11, "date"),
+            ("P1D/P2D", Date, 4, "date"),
+            ("2025-01-01/PT1H", Date, 12, "number"),
+            ("P1X/2025-01-01", Date, 2, "unit"),
+            ("9999-12-30/P5D", Date, 11, "duration"),
+            ("2025-02-10/2025-02-15/2025-02-20", Date, 21, None),
+            ("2025-02-10T09:00/08:00", PlainDateTime, 17, "datetime"),
+            (
+                "2025-02-10T09:00/2025-02-10T17:00",
+                OffsetDateTime,
+                16,
+                "offset",
+            ),
+            ("2025-02-10T09:00Z/17:7x", Instant, 21, "minute"),
+            ("2025-02-10T09:00Z--P1DT1X", Instant, 24, "unit"),
+        ],
+    )
+    def test_invalid_position(self, s, kind, position, expected):
+        with pytest.raises(ParseError) as e:
+            Interval.parse_iso(s, kind)
+        assert e.value.input == s
+        assert e.value.position == position
+        assert e.value.expected == expected
+
+    @pytest.mark.parametrize(
+        "s, kind, expect",
+        [
+            ("2025-02-10--2025-02-15", Date, "2025-02-10/2025-02-15"),
+            ("2025-02-10--15", Date, "2025-02-10/2025-02-15"),
+            ("P5D--2025-02-15", Date, "P5D/2025-02-15"),
+            (
+                "2025-02-10T09:00-05:00--PT8H",
+                OffsetDateTime,
+                "2025-02-10T09:00:00-05:00/PT8H",
+            ),
+        ],
+    )
+    def test_double_hyphen_separator(self, s, kind, expect):
+        assert Interval.parse_iso(s, kind) == Interval.parse_iso(expect, kind)
+
+    def test_unsupported_type(self):
+        with pytest.raises(TypeError):
+            Interval.parse_iso(
+                "2025-02-10T09:00+01:00[Europe/Paris]/PT1H",
+                ZonedDateTime,  # type: ignore[type-var]
+            )
+
+    def test_no_string(self):
+        with pytest.raises((TypeError, AttributeError)):
+            Interval.parse_iso(1, Date)  # type: ignore[arg-type]
+
+
+class TestFormatIso:
+    @pytest.mark.parametrize(
+        "s, kind, expect, abbreviated",
+        [
+            (
+                "2025-02-10/15",
+                Date,
+                "2025-02-10/2025-02-15",
+                "2025-02-10/15",
+            ),
+            (
+                "2025-02-10/2025-03-01",
+                Date,
+                "2025-02-10/2025-03-01",
+                "2025-02-10/03-01",
+            ),
+            (
+                "2025-12-30/2026-01-02",
+                Date,
+                "2025-12-30/2026-01-02",
+                "2025-12-30/2026-01-02",
+            ),
+            (
+                "2025-02-10T09:00/17:30",
+                PlainDateTime,
+                "2025-02-10T09:00:00/2025-02-10T17:30:00",
+                "2025-02-10T09:00:00/17:30:00",
+            ),
+            (
+                "2025-02-10T09:00/2025-02-12T17:30",
+                PlainDateTime,
+                "2025-02-10T09:00:00/2025-02-12T17:30:00",
+                "2025-02-10T09:00:00/12T17:30:00",
+            ),
+            (
+                "2025-02-10T09:00+01:00/17:00",
+                OffsetDateTime,
+                "2025-02-10T09:00:00+01:00/2025-02-10T17:00:00+01:00",
+                "2025-02-10T09:00:00+01:00/17:00:00+01:00",
+            ),
+            (
+                "2025-02-10T09:00Z/P1D",
+                Instant,
+                "2025-02-10T09:00:00Z/P1D",
+                "2025-02-10T09:00:00Z/P1D",
+            ),
+            (
+                "PT8H/2025-02-10T09:00Z",
+                Instant,
+                "PT8H/2025-02-10T09:00:00Z",
+                "PT8H/2025-02-10T09:00:00Z",
+            ),
+        ],
+    )
+    def test_format(self, s, kind, expect, abbreviated):
+        i = Interval.parse_iso(s, kind)
+        assert i.format_iso() == expect
+        assert str(i) == expect
+        assert i.format_iso(abbreviate=True) == abbreviated
+        assert Interval.parse_iso(abbreviated, kind) == i
+        assert Interval.parse_iso(expect, kind) == i
+
+
+def test_repr():
+    i = Interval(Date(2025, 2, 10), duration=ItemizedDateDelta(days=5))
+    assert repr(i) == 'Interval("2025-02-10/P5D")'
+
+
+def test_eq():
+    i = Interval(Date(2025, 2, 10), Date(2025, 2, 15))
+    same = Interval.parse_iso("2025-02-10/15", Date)
+    by_duration = Interval(
+        Date(2025, 2, 10), duration=ItemizedDateDelta(days=5)
+    )
+    different = Interval(Date(2025, 2, 10), Date(2025, 2, 16))
+
+    assert i == same
+    assert hash(i) == hash(same)
+    assert i != by_duration
+    assert i != different
+    assert by_duration == Interval.parse_iso("2025-02-10/P5D", Date)
+
+    assert i == AlwaysEqual()
+    assert i != NeverEqual()
+    assert not i == NeverEqual()
+    assert i != 42  # type: ignore[comparison-overlap]
+
+    # Same moments, but different offsets
+    a = Interval.parse_iso("2025-02-10T09:00+01:00/17:00", OffsetDateTime)
+    b = Interval.parse_iso("2025-02-10T10:00+02:00/18:00", OffsetDateTime)
+    assert a != b
+
+    # Different types
+    c = Interval.parse_iso("2025-02-10T08:00Z/16:00", Instant)
+    assert a != c  # type: ignore[comparison-overlap]
+    assert c == Interval(a.start.to_instant(), a.end.to_instant())
+
+
+class TestRepeatingInterval:
+    def test_init(self):
+        i = Interval(Date(2025, 1, 1), duration=ItemizedDateDelta(days=1))
+        r = RepeatingInterval(i, 3)
+        assert r.interval == i
+        assert r.repetitions == 3
+        assert RepeatingInterval(i).repetitions is None
+
+        with pytest.raises(TypeError):
+            RepeatingInterval("2025-01-01/P1D")  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            RepeatingInterval(i, 1.5)  # type: ignore[arg-type]
+        with pytest.raises(ValueError):
+            RepeatingInterval(i, -1)
+
+    def test_iterate_start_duration(self):
+        r = RepeatingInterval.parse_iso("R5/2025-01-01T00:00Z/P1D", Instant)
+        starts = [i.start for i in r]
+        assert starts == [Instant.from_utc(2025, 1, d) for d in range(1, 6)]
+        assert all(i.duration == ItemizedDelta(days=1) for i in r)
+
+    def test_iterate_calendar_duration(self):
+        r = RepeatingInterval.parse_iso("R3/2025-01-31/P1M", Date)
+        # Each interval is offset from the first, so the day doesn't drift
+        assert [(i.start, i.end) for i in r] == [
+            (Date(2025, 1, 31), Date(2025, 2, 28)),
+            (Date(2025, 2, 28), Date(2025, 3, 31)),
+            (Date(2025, 3, 31), Date(2025, 4, 30)),
+        ]
+        assert all(i.duration == ItemizedDateDelta(months=1) for i in r)
+
+        r2 = RepeatingInterval.parse_iso("R3/P1M/2025-03-31", Date)
+        assert [(i.start, i.end) for i in r2] == [
+            (Date(2025, 2, 28), Date(2025, 3, 31)),
+            (Date(2025, 1, 31), Date(2025, 2, 28)),
+            (Date(2024, 12, 31), Date(2025, 1, 31)),
+        ]
+
+    def test_iterate_start_end(self):
+        r = RepeatingInterval.parse_iso(
+            "R3/2025-02-10T09:00+01:00/17:00", OffsetDateTime
+        )
+        intervals = list(r)
+        assert len(intervals) == 3
+        assert intervals[2].start.exact_eq(
+            OffsetDateTime(2025, 2, 11, 1, offset=1)
+        )
+        assert intervals[2].end.exact_eq(
+            OffsetDateTime(2025, 2, 11, 9, offset=1)
+        )
+
+        r2 = RepeatingInterval.parse_iso("R2/2025-02-10/15", Date)
+        assert [i.end for i in r2] == [Date(2025, 2, 15), Date(2025, 2, 20)]
+
+    def test_iterate_backwards(self):
+        r = RepeatingInterval.parse_iso(
+            "R3/PT1H/2025-01-01T00:00", PlainDateTime
+        )
+        assert [i.start for i in r] == [
+            PlainDateTime(2024, 12, 31, 23),
+            PlainDateTime(2024, 12, 31, 22),
+            PlainDateTime(2024, 12, 31, 21),
+        ]
+
+    def test_zero_repetitions(self):
+        r = RepeatingInterval.parse_iso("R0/2025-01-01/P1D", Date)
+        assert list(r) == []
+
+    def test_unbounded(self):
+        r = RepeatingInterval.parse_iso("R/2025-01-01/P1D", Date)
+        assert r.repetitions is None
+        assert [i.start for i in islice(r, 3)] == [
+            Date(2025, 1, 1),
+            Date(2025, 1, 2),
+            Date(2025, 1, 3),
+        ]
+
+    def test_unbounded_stops_at_range_end(self):
+        r = RepeatingInterval.parse_iso("R/9999-12-20/P5D", Date)
+        assert [i.start for i in r] == [
+            Date(9999, 12, 20),
+            Date(9999, 12, 25),
+        ]
+        r2 = RepeatingInterval.parse_iso("R/P1Y/0003-06-01", Date)
+        assert [i.start for i in r2] == [
+            Date(2, 6, 1),
+            Date(1, 6, 1),
+        ]
+
+    @pytest.mark.parametrize(
+        "s, kind, expect",
+        [
+            (
+                "R5/2025-01-01T00:00Z/P1D",
+                Instant,
+                "R5/2025-01-01T00:00:00Z/P1D",
+            ),
+            ("r/2025-01-01/p1w", Date, "R/2025-01-01/P1W"),
+            ("R12/2025-01-01/15", Date, "R12/2025-01-01/2025-01-15"),
+        ],
+    )
+    def test_parse_and_format(self, s, kind, expect):
+        r = RepeatingInterval.parse_iso(s, kind)
+        assert r.format_iso() == expect
+        assert str(r) == expect
+        assert repr(r) == f'RepeatingInterval("{expect}")'
+        assert RepeatingInterval.parse_iso(expect, kind) == r
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "",
+            "R",
+            "R5",
+            "R5/",
+            "2025-01-01/P1D",
+            "X5/2025-01-01/P1D",
+            "R-1/2025-01-01/P1D",
+            "R+1/2025-01-01/P1D",
+            "R 1/2025-01-01/P1D",
+            "R٣/2025-01-01/P1D",
+            "R5/2025-01-01",
+            "R5/2025-01-01/P1D/P1D",
+        ],
+    )
+    def test_parse_invalid(self, s):
+        with pytest.raises(ParseError, match="Invalid format"):
+            RepeatingInterval.parse_iso(s, Date)
+
+    @pytest.mark.parametrize(
+        "s, position, expected",
+        [
+            ("X5/2025-01-01/P1D", 0, "R"),
+            ("R5", 2, "/"),
+            ("R-1/2025-01-01/P1D", 1, "/"),
+            ("R5/2025-01-01/PT1D", 15, "number"),
+        ],
+    )
+    def test_parse_invalid_position(self, s, position, expected):
+        with pytest.raises(ParseError) as e:
+            RepeatingInterval.parse_iso(s, Date)
+        assert e.value.input == s
+        assert e.value.position == position
+        assert e.value.expected == expected
+
+    def test_parse_double_hyphen_separator(self):
+        assert RepeatingInterval.parse_iso(
+            "R5--2025-01-01--P1D", Date
+        ) == RepeatingInterval.parse_iso("R5/2025-01-01/P1D", Date)
+
+    def test_eq(self):
+        r = RepeatingInterval.parse_iso("R5/2025-01-01/P1D", Date)
+        assert r == RepeatingInterval.parse_iso("R5/2025-01-01/P1D", Date)
+        assert hash(r) == hash(
+            RepeatingInterval.parse_iso("R5/2025-01-01/P1D", Date)
+        )
+        assert r != RepeatingInterval.parse_iso("R4/2025-01-01/P1D", Date)
+        assert r != RepeatingInterval.parse_iso("R/2025-01-01/P1D", Date)
+        assert r != RepeatingInterval.parse_iso("R5/2025-01-02/P1D", Date)
+        assert r != r.interval  # type: ignore[comparison-overlap]
+        assert r == AlwaysEqual()
+        assert r != NeverEqual()
+
+
+@pytest.mark.parametrize(
+    "s, kind",
+    [
+        ("2025-02-10/15", Date),
+        ("P1D/2025-02-10", Date),
+        ("2025-02-10T09:00+01:00/PT8H", OffsetDateTime),
+        ("2025-02-10T09:00/17:00", PlainDateTime),
+        ("2025-02-10T09:00Z/17:00", Instant),
+    ],
+)
+def test_pickle(s, kind):
+    i = Interval.parse_iso(s, kind)
+    assert pickle.loads(pickle.dumps(i)) == i
+    assert pickle.loads(pickle.dumps(i)).format_iso() == i.format_iso()
+    r = RepeatingInterval(i, 3)
+    assert pickle.loads(pickle.dumps(r)) == r
@@ -9,6 +9,7 @@ from whenever import (
     Date,
     ItemizedDateDelta,
     ItemizedDelta,
+    ParseError,
     ZonedDateTime,
 )
 
@@ -429,6 +430,21 @@ class TestParseIso:
         with pytest.raises(ValueError):
             ItemizedDateDelta.parse_iso(s)
 
+    @pytest.mark.parametrize(
+        "s, position, expected",
+        [
+            ("P", 1, "number"),
+            ("P1X", 2, "unit"),
+            ("PT1H", 1, "number"),
+            ("P1M1Y", 4, "unit"),
+        ],
+    )
+    def test_invalid_position(self, s, position, expected):
+        with pytest.raises(ParseError) as e:
+            ItemizedDateDelta.parse_iso(s)
+        assert e.value.position == position
+        assert e.value.expected == expected
+
 
 # These tests are relatively simple because since() does most of the heavy lifting,
 # and is tested more thoroughly elsewhere.
@@ -12,6 +12,7 @@ from whenever import (
     ItemizedDelta,
     NaiveArithmeticWarning,
     OffsetDateTime,
+    ParseError,
     PlainDateTime,
     StaleOffsetWarning,
     TimeDelta,
@@ -614,6 +615,22 @@ class TestParseIso:
         with pytest.raises(ValueError):
             ItemizedDelta.parse_iso(s)
 
+    @pytest.mark.parametrize(
+        "s, position, expected",
+        [
+            ("P", 1, "number"),
+            ("PT1X", 3, "unit"),
+            ("P1DT", 4, "number"),
+            ("P1D2Y", 3, "T"),
+            ("XP1D", 0, "P"),
+        ],
+    )
+    def test_invalid_position(self, s, position, expected):
+        with pytest.raises(ParseError) as e:
+            ItemizedDelta.parse_iso(s)
+        assert e.value.position == position
+        assert e.value.expected == expected
+
 
 # These tests are relatively simple because since() does most of the heavy lifting,
 # and is tested more thoroughly elsewhere.
@@ -1551,3 +1568,52 @@ def test_compatible_unpickle():
             nanoseconds=8,
         )
     )
+
+
+class TestHumanize:
+    # Few test cases since it reuses ZonedDateTime.humanize()
+    def test_zoned(self):
+        ref = ZonedDateTime(2024, 1, 1, tz="Europe/Paris")
+        d = ItemizedDelta(months=1, days=20)
+        assert d.humanize(relative_to=ref) == "in 1 month"
+        assert (
+            d.humanize(relative_to=ref, round_mode="half_even")
+            == "in 2 months"
+        )
+        assert (
+            (-d).humanize(relative_to=ref, locale="de") == "vor 1 Monat"
+        )
+        assert (
+            ItemizedDelta(days=3, hours=5).humanize(
+                relative_to=ref, granularity="weeks"
+            )
+            == "now"
+        )
+
+    def test_plain(self):
+        ref = PlainDateTime(2024, 1, 1)
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            assert (
+                ItemizedDelta(years=2).humanize(relative_to=ref)
+                == "in 2 years"
+            )
+        with pytest.warns(NaiveArithmeticWarning):
+            assert (
+                ItemizedDelta(hours=5).humanize(relative_to=ref)
+                == "in 5 hours"
+            )
+
+    def test_offset(self):
+        ref = OffsetDateTime(2024, 1, 1, offset=2)
+        with pytest.warns(StaleOffsetWarning):
+            assert (
+                ItemizedDelta(weeks=-3).humanize(relative_to=ref)
+                == "3 weeks ago"
+            )
+
+    def test_invalid(self):
+        with pytest.raises(TypeError, match="relative_to"):
+            ItemizedDelta(hours=1).humanize(
+                relative_to=Instant.from_utc(2024, 1, 1)  # type: ignore[arg-type]
+            )
@@ -17,6 +17,7 @@ from whenever import (
     Date,
     DateDelta,
     DateTimeDelta,
+    ExpandedDate,
     ImplicitlyIgnoringDST,
     Instant,
     InvalidOffsetError,
@@ -368,6 +369,7 @@ def test_text_signature():
         ZonedDateTime,
         PlainDateTime,
         Date,
+        ExpandedDate,
         Time,
         TimeDelta,
         DateDelta,
@@ -15,6 +15,7 @@ from whenever import (
     ItemizedDateDelta,
     ItemizedDelta,
     OffsetDateTime,
+    ParseError,
     PlainDateTime,
     StaleOffsetWarning,
     Time,
@@ -295,9 +296,9 @@ INVALID_ISO_STRINGS = [
     "2020-08-15T12:08:30+05:00[]",
     "2020-08-15T12:08:30+05:00]",
     "2020-08-15T12:08:30+05:00[abc]foo",
-    # unsupported ISO features (weekdays, ordinal dates)
-    "2020-W08-1T23:12:09-01",
-    "2020-123T23:12:09-01",
+    # invalid week and ordinal dates
+    "2020-W54-1T23:12:09-01",
+    "2020-000T23:12:09-01",
     # invalid seconds (61 and above should be rejected)
     "2020-08-15T12:34:61+00:00",
     "2020-08-15T12:34:99+00:00",
@@ -430,6 +431,15 @@ VALID_ISO_STRINGS = [
             2020, 8, 15, 12, 34, 59, nanosecond=500_000_000, offset=0
         ),
     ),
+    # Week and ordinal dates
+    (
+        "2020-W08-1T23:12:09-01",
+        OffsetDateTime(2020, 2, 17, 23, 12, 9, offset=-1),
+    ),
+    (
+        "2020123T231209Z",
+        OffsetDateTime(2020, 5, 2, 23, 12, 9, offset=0),
+    ),
 ]
 
 
@@ -665,6 +675,13 @@ class TestFromTimestamp:
             )
         )
 
+        # a tiny negative fraction doesn't round up to a whole second
+        assert OffsetDateTime.from_timestamp(-1e-20, offset=0).exact_eq(
+            OffsetDateTime(
+                1969, 12, 31, 23, 59, 59, nanosecond=999_999_999, offset=0
+            )
+        )
+
         with pytest.raises((ValueError, OverflowError)):
             OffsetDateTime.from_timestamp(9e200, offset=0)
 
@@ -1786,6 +1803,28 @@ VALID_RFC2822 = [
         "Sat, 31 Dec 2016 23:59:60 +0000",
         OffsetDateTime(2016, 12, 31, 23, 59, 59, offset=0),
     ),
+    # Comments
+    (
+        "Sat, 15 Aug 2020 23:12:09 +0200 (CEST)",
+        OffsetDateTime(2020, 8, 15, 23, 12, 9, offset=2),
+    ),
+    (
+        "Thu,\r\n 13\r\n Feb\r\n 1969\r\n 23:32\r\n -0330 (Newfoundland Time)",
+        OffsetDateTime(1969, 2, 13, 23, 32, offset=hours(-3.5)),
+    ),
+    (
+        "(Received) Fri(day), 21 Nov 1997 09(comment):   55  :  06 -0600",
+        OffsetDateTime(1997, 11, 21, 9, 55, 6, offset=-6),
+    ),
+    (
+        "21 Nov 1997(a (nested) \\) comment)09:55 EST(Eastern)",
+        OffsetDateTime(1997, 11, 21, 9, 55, offset=-5),
+    ),
+    # Military zones are treated as UTC
+    (
+        "Fri, 21 Nov 1997 09:55:06 Z",
+        OffsetDateTime(1997, 11, 21, 9, 55, 6, offset=0),
+    ),
 ]
 
 INVALID_RFC2822 = [
@@ -1823,6 +1862,13 @@ INVALID_RFC2822 = [
     "Wed, 30 Feb 2024 22:22 -0400",
     "Sat, 1 Foo 2020 14:12 +0400",
     "Sat, 15 Aug 2𝟘2𝟘 23:12:09 +0400",  # non-ascii
+    # invalid comments
+    "Sat, 15 Aug 2020 23:12:09 +0400 (CEST",
+    "Sat, 15 Aug 2020 23:12:09 +0400 ((CEST)",
+    "Sat, 15 Aug 2020 23:12:09 +0400 CEST)",
+    "Sat, 15 Aug 2020 23:12:09 +0400 (CEST\\)",
+    "Sat, 15 Aug 2020 23:12:09 +0400 (Mitteleuropäische Zeit)",
+    "Sat, 15 Aug 2020 23:(x)1(y)2:09 +0400",
     # invalid comma
     "Mon 28 Feb 2023 22:22 -0400",
     "Sat. 15 Aug 2020 23:12:09 GMT",
@@ -1866,6 +1912,41 @@ INVALID_RFC2822 = [
 ]
 
 
+STRICT_VALID_RFC2822 = [
+    (
+        "Sat, 15 Aug 2020 23:12:09 +0200",
+        OffsetDateTime(2020, 8, 15, 23, 12, 9, offset=2),
+    ),
+    (
+        " Sat,15 Aug 2020\t23:12 -0000 (comment (nested))  ",
+        OffsetDateTime(2020, 8, 15, 23, 12, offset=0),
+    ),
+    (
+        "1 aug 2020 23:12:60 -0330",
+        OffsetDateTime(2020, 8, 1, 23, 12, 59, offset=hours(-3.5)),
+    ),
+    (
+        "Sat, 15 Aug 2020 23:12:09 GMT (comment)",
+        OffsetDateTime(2020, 8, 15, 23, 12, 9, offset=0),
+    ),
+]
+
+# Valid in the obsolete syntax, but not in the modern one
+STRICT_INVALID_RFC2822 = [
+    "Sat, 15 Aug 2020 23:12:09 UT",
+    "Sat, 15 Aug 2020 23:12:09 GMTX",
+    "Sat, 15 Aug 2020 23:12:09 Z",
+    "Sat, 15 Aug 20 23:12:09 +0000",
+    "Sat, 15 Aug 120 23:12:09 +0000",
+    "Sat , 15 Aug 2020 23:12:09 +0000",
+    "Sat, 15 Aug 2020 23 : 12 +0000",
+    "Sat, 15 Aug 2020 23:12 : 09 +0000",
+    "(comment) Sat, 15 Aug 2020 23:12:09 +0000",
+    "Sat, 15 Aug 2020 23:12:09 (comment) +0000",
+    "Sat(day), 15 Aug 2020 23:12:09 +0000",
+]
+
+
 class TestParseRFC2822:
     @pytest.mark.parametrize("s, expected", VALID_RFC2822)
     def test_valid(self, s, expected):
@@ -1876,6 +1957,31 @@ class TestParseRFC2822:
         with pytest.raises(ValueError, match=re.escape(repr(s))):
             OffsetDateTime.parse_rfc2822(s)
 
+    @pytest.mark.parametrize("s, expected", STRICT_VALID_RFC2822)
+    def test_strict_valid(self, s, expected):
+        assert OffsetDateTime.parse_rfc2822(s, strict=True) == expected
+        assert OffsetDateTime.parse_rfc2822(s) == expected
+
+    @pytest.mark.parametrize(
+        "s", STRICT_INVALID_RFC2822 + INVALID_RFC2822
+    )
+    def test_strict_invalid(self, s):
+        with pytest.raises(ParseError, match=re.escape(repr(s))):
+            OffsetDateTime.parse_rfc2822(s, strict=True)
+
+    @pytest.mark.parametrize("offset", [hours(2), hours(-3.5), hours(0)])
+    def test_strict_roundtrip(self, offset):
+        d = OffsetDateTime(2024, 7, 1, 12, 30, 5, offset=offset)
+        assert OffsetDateTime.parse_rfc2822(
+            d.format_rfc2822(), strict=True
+        ).exact_eq(d)
+
+    def test_strict_must_be_bool(self):
+        with pytest.raises(TypeError, match="strict"):
+            OffsetDateTime.parse_rfc2822(
+                "15 Aug 2020 23:12 +0000", strict=1  # type: ignore[arg-type]
+            )
+
     @pytest.mark.parametrize(
         "s",
         [
@@ -1888,6 +1994,95 @@ class TestParseRFC2822:
             OffsetDateTime.parse_rfc2822(s)
 
 
+class TestCbor:
+    def test_known_value(self):
+        d = OffsetDateTime(
+            2013, 3, 21, 21, 4, 0, nanosecond=500_000_000, offset=1
+        )
+        data = b"\xc0\x78\x1b2013-03-21T21:04:00.5+01:00"
+        assert d.to_cbor() == data
+        assert OffsetDateTime.from_cbor(data).exact_eq(d)
+
+    @pytest.mark.parametrize(
+        "d",
+        [
+            OffsetDateTime(1, 1, 1, offset=0),
+            OffsetDateTime(
+                9999, 12, 31, 23, 59, 59, nanosecond=999_999_999, offset=1
+            ),
+            OffsetDateTime(2020, 8, 15, 23, 12, offset=hours(-5.5)),
+        ],
+    )
+    def test_roundtrip(self, d):
+        assert OffsetDateTime.from_cbor(d.to_cbor()).exact_eq(d)
+
+    def test_offset_with_seconds(self):
+        d = OffsetDateTime(2020, 1, 1, offset=seconds(3630))
+        with pytest.raises(ValueError, match="seconds"):
+            d.to_cbor()
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            b"",
+            b"\xc1\x1a\x51\x4b\x67\xb0",  # epoch tag
+            b"\xc0\x6a2013-03-21",
+            b"\xc0\x742013-03-21T20:04:00Z\x00",  # trailing data
+        ],
+    )
+    def test_invalid(self, data):
+        with pytest.raises(ValueError, match="invalid CBOR data"):
+            OffsetDateTime.from_cbor(data)
+
+    @pytest.mark.parametrize(
+        "s, expect",
+        [
+            (
+                "2013-03-21t21:04:00.5+01:00",
+                OffsetDateTime(
+                    2013, 3, 21, 21, 4, nanosecond=500_000_000, offset=1
+                ),
+            ),
+            (
+                "2013-03-21T21:04:00z",
+                OffsetDateTime(2013, 3, 21, 21, 4, offset=0),
+            ),
+            (
+                "2013-03-21T21:04:00.123456789-00:00",
+                OffsetDateTime(
+                    2013, 3, 21, 21, 4, nanosecond=123_456_789, offset=0
+                ),
+            ),
+        ],
+    )
+    def test_rfc3339_variants(self, s, expect):
+        data = b"\xc0\x78" + bytes([len(s)]) + s.encode()
+        assert OffsetDateTime.from_cbor(data).exact_eq(expect)
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "2013-03-21 21:04:00+01:00",
+            "2013-03-21T21:04+01:00",
+            "20130321T210400+0100",
+            "2013-03-21T21:04:00+01",
+            "2013-03-21T21:04:00,5+01:00",
+            "2013-03-21T21:04:00.+01:00",
+            "2013-03-21T21:04:00.1234567891+01:00",
+            "2013-03-21T21:04:00",
+            "2013-W12-4T21:04:00+01:00",
+        ],
+    )
+    def test_not_rfc3339(self, s):
+        data = b"\xc0\x78" + bytes([len(s)]) + s.encode()
+        with pytest.raises(ValueError, match="invalid CBOR data"):
+            OffsetDateTime.from_cbor(data)
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            OffsetDateTime.from_cbor("2020-01-01T00:00:00Z")  # type: ignore
+
+
 class TestRound:
     @pytest.mark.parametrize(
         "d, increment, unit, floor, ceil, half_floor, half_ceil, half_even",
@@ -315,6 +315,11 @@ class TestParseIso:
                 (2020, 8, 15, 12, 34, 59, 123_456_000),
             ),
             ("2020-08-15T12:34:60,5", (2020, 8, 15, 12, 34, 59, 500_000_000)),
+            # ordinal and week dates
+            ("2020-W08-1T12:08:30", (2020, 2, 17, 12, 8, 30, 0)),
+            ("2020W081 120830", (2020, 2, 17, 12, 8, 30, 0)),
+            ("2020-081T12:08:30", (2020, 3, 21, 12, 8, 30, 0)),
+            ("2020081T12", (2020, 3, 21, 12, 0, 0, 0)),
         ],
     )
     def test_valid(self, s, expected):
@@ -372,11 +377,13 @@ class TestParseIso:
             "2020-11-21T24:08:30",
             "2020-11-21T22:68:30",
             "2020-11-21T22:48:62",
-            # ordinal and week days
-            "2020-W08-1T12:08:30",
-            "2020W081T12:08:30",
-            "2020081T12:08:30",
-            "2020-081T12:08:30",
+            # invalid ordinal and week dates
+            "2020-W08-8T12:08:30",
+            "2020-W081T12:08:30",
+            "2020W08-1T12:08:30",
+            "2020-367T12:08:30",
+            "2020-08T12:08:30",
+            "202081T12:08:30",
             # invalid leap second cases
             "2020-08-15T12:34:61",
             "2020-08-15T12:34:99",
@@ -1,3 +1,4 @@
+import doctest
 import pickle
 import re
 import warnings
@@ -12,6 +13,7 @@ from whenever import (
     ItemizedDelta,
     NaiveArithmeticWarning,
     OffsetDateTime,
+    ParseError,
     PlainDateTime,
     StaleOffsetWarning,
     TimeDelta,
@@ -22,8 +24,10 @@ from whenever import (
     milliseconds,
     minutes,
     nanoseconds,
+    register_humanize_locale,
     seconds,
 )
+from whenever._locale import get_locale
 
 from .common import (
     AlwaysEqual,
@@ -876,6 +880,385 @@ class TestParseIso:
             TimeDelta.parse_iso("PT999999999999999999999999999H")
 
 
+class TestAlternativeFormat:
+    @pytest.mark.parametrize(
+        "s, expected",
+        [
+            ("PT04:05:06", TimeDelta(hours=4, minutes=5, seconds=6)),
+            ("PT040506", TimeDelta(hours=4, minutes=5, seconds=6)),
+            ("PT00:00:00", TimeDelta()),
+            ("PT24:00:00", TimeDelta(hours=24)),
+            ("-PT01:30:00", TimeDelta(hours=-1, minutes=-30)),
+            ("+pt01:30:00", TimeDelta(hours=1, minutes=30)),
+            ("PT00:00:01.5", TimeDelta(seconds=1.5)),
+            ("PT000001,000000001", TimeDelta(seconds=1, nanoseconds=1)),
+            ("P0000-00-00T01:00:00", TimeDelta(hours=1)),
+            ("P00000000T010000", TimeDelta(hours=1)),
+            ("P0000-00-00", TimeDelta()),
+        ],
+    )
+    def test_parse(self, s, expected):
+        assert TimeDelta.parse_iso(s) == expected
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "PT24:00:01",
+            "PT25:00:00",
+            "PT00:60:00",
+            "PT00:00:60",
+            "PT1:00:00",
+            "PT01:00",
+            "PT0100",
+            "PT01:0000",
+            "PT00:00:00.",
+            "PT00:00:00.0000000001",
+            "P0000-00-01T00:00:00",
+            "P0000-00-00T010000",
+            "P0000-01-00",
+            "PT",
+            "PT٠١:٠٠:٠٠",
+            "--PT01:00:00",
+        ],
+    )
+    def test_parse_invalid(self, s):
+        with pytest.raises(ValueError, match="Invalid format"):
+            TimeDelta.parse_iso(s)
+
+    @pytest.mark.parametrize(
+        "d, extended, basic",
+        [
+            (TimeDelta(), "PT00:00:00", "PT000000"),
+            (
+                TimeDelta(hours=4, minutes=5, seconds=6),
+                "PT04:05:06",
+                "PT040506",
+            ),
+            (TimeDelta(hours=24), "PT24:00:00", "PT240000"),
+            (TimeDelta(seconds=-1.25), "-PT00:00:01.25", "-PT000001.25"),
+            (TimeDelta(nanoseconds=1), "PT00:00:00.000000001", None),
+        ],
+    )
+    def test_format(self, d, extended, basic):
+        assert d.format_iso(alternative=True) == extended
+        assert TimeDelta.parse_iso(extended) == d
+        if basic is not None:
+            assert d.format_iso(alternative=True, basic=True) == basic
+            assert TimeDelta.parse_iso(basic) == d
+
+    @pytest.mark.parametrize(
+        "d", [TimeDelta(hours=24, nanoseconds=1), TimeDelta(hours=-25)]
+    )
+    def test_format_too_large(self, d):
+        with pytest.raises(ValueError, match="24 hours"):
+            d.format_iso(alternative=True)
+
+    def test_basic_requires_alternative(self):
+        with pytest.raises(ValueError, match="alternative"):
+            TimeDelta(hours=1).format_iso(basic=True)
+        assert TimeDelta(hours=1).format_iso(alternative=False) == "PT1H"
+
+
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (TimeDelta(), "0s"),
+        (TimeDelta(nanoseconds=1), "1ns"),
+        (TimeDelta(nanoseconds=999), "999ns"),
+        (TimeDelta(nanoseconds=1_500), "1.5µs"),
+        (TimeDelta(microseconds=-3), "-3µs"),
+        (TimeDelta(milliseconds=250), "250ms"),
+        (TimeDelta(nanoseconds=999_999_999), "999.999999ms"),
+        (TimeDelta(seconds=1), "1s"),
+        (TimeDelta(seconds=2.5), "2.5s"),
+        (TimeDelta(seconds=90), "1m30s"),
+        (TimeDelta(minutes=1, nanoseconds=10), "1m0.00000001s"),
+        (TimeDelta(hours=1), "1h0m0s"),
+        (TimeDelta(hours=1, minutes=30), "1h30m0s"),
+        (TimeDelta(hours=-100, seconds=1), "-99h59m59s"),
+        (TimeDelta.MAX, f"{MAX_HOURS}h0m0s"),
+        (TimeDelta.MIN, f"-{MAX_HOURS}h0m0s"),
+    ],
+)
+def test_format_go(d, expected):
+    assert d.format_go() == expected
+    assert TimeDelta.parse_go(expected) == d
+    assert TimeDelta.parse_human(expected) == d
+
+
+class TestParseGo:
+    @pytest.mark.parametrize(
+        "s, expected",
+        [
+            ("1h30m", TimeDelta(hours=1, minutes=30)),
+            ("250ms", TimeDelta(milliseconds=250)),
+            ("2.5s", TimeDelta(seconds=2.5)),
+            ("-1.5h", TimeDelta(minutes=-90)),
+            ("+3m", TimeDelta(minutes=3)),
+            ("0", TimeDelta()),
+            ("-0", TimeDelta()),
+            (".5s", TimeDelta(milliseconds=500)),
+            ("5.s", TimeDelta(seconds=5)),
+            ("1us", TimeDelta(microseconds=1)),
+            ("1µs", TimeDelta(microseconds=1)),  # micro sign
+            ("1μs", TimeDelta(microseconds=1)),  # Greek mu
+            ("3ns", TimeDelta(nanoseconds=3)),
+            # any order, and repeated units
+            ("1s1h", TimeDelta(hours=1, seconds=1)),
+            ("1m1m", TimeDelta(minutes=2)),
+            # the sign applies to the whole duration
+            ("-1h30m", TimeDelta(hours=-1, minutes=-30)),
+            # sub-nanosecond fractions are truncated
+            ("1.9999999999999999999ns", TimeDelta(nanoseconds=1)),
+            (
+                "0.333333333333333333333333h",
+                TimeDelta(minutes=20, nanoseconds=-1),
+            ),
+            ("0" * 50 + "1s", TimeDelta(seconds=1)),
+            (f"{MAX_HOURS}h", TimeDelta.MAX),
+        ],
+    )
+    def test_valid(self, s, expected):
+        assert TimeDelta.parse_go(s) == expected
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "",
+            "-",
+            "1",  # missing unit
+            "1h30",
+            "h",
+            ".s",
+            "1.2.3s",
+            "1e3s",
+            "1d",  # no days in Go
+            "1H",  # case-sensitive
+            "1 h",  # no spaces
+            " 1h",
+            "1h 30m",
+            "1h-30m",
+            "--1h",
+            "١s",  # non-ASCII digit
+        ],
+    )
+    def test_invalid(self, s):
+        with pytest.raises(ParseError, match=re.escape(repr(s))) as e:
+            TimeDelta.parse_go(s)
+        assert e.value.input == s
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            f"{MAX_HOURS + 1}h",
+            f"-{MAX_HOURS + 1}h",
+            "9" * 50 + "ns",
+            "1h" + "9" * 50 + "h",
+        ],
+    )
+    def test_out_of_range(self, s):
+        with pytest.raises(ParseError, match="range"):
+            TimeDelta.parse_go(s)
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError, match="str"):
+            TimeDelta.parse_go(1)  # type: ignore[arg-type]
+
+
+class TestCbor:
+    @pytest.mark.parametrize(
+        "d, data",
+        [
+            (TimeDelta(), "d903eaa10100"),
+            (TimeDelta(minutes=1, seconds=30), "d903eaa101185a"),
+            (TimeDelta(seconds=-1.5), "d903eaa20121281a1dcd6500"),
+            (TimeDelta(nanoseconds=1), "d903eaa201002801"),
+        ],
+    )
+    def test_known_values(self, d, data):
+        assert d.to_cbor().hex() == data
+        assert TimeDelta.from_cbor(bytes.fromhex(data)) == d
+
+    def test_fraction_units(self):
+        # milliseconds and microseconds
+        assert TimeDelta.from_cbor(
+            bytes.fromhex("d903eaa20101221901f4")
+        ) == TimeDelta(seconds=1.5)
+        assert TimeDelta.from_cbor(
+            bytes.fromhex("d903eaa20101251901f4")
+        ) == TimeDelta(seconds=1, microseconds=500)
+
+    def test_float_seconds(self):
+        assert TimeDelta.from_cbor(bytes.fromhex("d903eaa101f9be00")) == (
+            TimeDelta(seconds=-1.5)
+        )
+        assert TimeDelta.from_cbor(
+            bytes.fromhex("d903eaa101fb3ff0000000000001")
+        ) == TimeDelta(seconds=1)
+
+    def test_unknown_elective_keys(self):
+        assert TimeDelta.from_cbor(
+            bytes.fromhex("d903eaa3185a6178010a1864a10001")
+        ) == TimeDelta(seconds=10)
+
+    @pytest.mark.parametrize(
+        "d",
+        [TimeDelta.MAX, TimeDelta.MIN, -nanoseconds(1), hours(-MAX_HOURS)],
+    )
+    def test_roundtrip(self, d):
+        assert TimeDelta.from_cbor(d.to_cbor()) == d
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            "",
+            "d903e9a10100",  # extended time tag
+            "c100",
+            "d903eaa10100ff",  # trailing data
+            "d903eaa1011b00000400000000000000",  # out of range
+            "d903eaa20101221903e8",  # fraction out of range
+            "d903eaa201f93c002201",  # float seconds with fraction
+            "d903eaa101fb7ff8000000000000",  # NaN seconds
+            "d903eaa101fb47efffffe0000000",  # float seconds out of range
+            "d903eaa201012101",  # unknown critical key
+        ],
+    )
+    def test_invalid(self, data):
+        with pytest.raises(ValueError, match="invalid CBOR data"):
+            TimeDelta.from_cbor(bytes.fromhex(data))
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            TimeDelta.from_cbor("d903eaa10100")  # type: ignore[arg-type]
+
+
+class TestMsgpack:
+    @pytest.mark.parametrize(
+        "d, data",
+        [
+            (TimeDelta(), "d6ff00000000"),
+            (TimeDelta(minutes=1, seconds=30), "d6ff0000005a"),
+            (TimeDelta(seconds=1, nanoseconds=1), "d7ff0000000400000001"),
+            (TimeDelta(seconds=-1.5), "c70cff1dcd6500fffffffffffffffe"),
+        ],
+    )
+    def test_known_values(self, d, data):
+        assert d.to_msgpack().hex() == data
+        assert TimeDelta.from_msgpack(bytes.fromhex(data)) == d
+
+    @pytest.mark.parametrize(
+        "d",
+        [TimeDelta.MAX, TimeDelta.MIN, -nanoseconds(1), hours(MAX_HOURS)],
+    )
+    def test_roundtrip(self, d):
+        assert TimeDelta.from_msgpack(d.to_msgpack()) == d
+
+    @pytest.mark.parametrize(
+        "data",
+        [
+            "",
+            "d6ff000000",  # truncated
+            "d7ffee6b280000000000",  # nanoseconds out of range
+            "c70cff000000007fffffffffffffff",  # out of range
+        ],
+    )
+    def test_invalid(self, data):
+        with pytest.raises(ValueError, match="MessagePack"):
+            TimeDelta.from_msgpack(bytes.fromhex(data))
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            TimeDelta.from_msgpack(None)  # type: ignore[arg-type]
+
+
+class TestParseHuman:
+    @pytest.mark.parametrize(
+        "s, expected",
+        [
+            ("1 hour", TimeDelta(hours=1)),
+            ("1 hour 30 minutes", TimeDelta(hours=1, minutes=30)),
+            ("1 hour and 30 minutes", TimeDelta(hours=1, minutes=30)),
+            ("1 Hour, 30 MINS", TimeDelta(hours=1, minutes=30)),
+            ("1 hr, and 30 min", TimeDelta(hours=1, minutes=30)),
+            ("5 min, 30.5 sec", TimeDelta(minutes=5, seconds=30.5)),
+            ("2 seconds 250 ms", TimeDelta(seconds=2, milliseconds=250)),
+            ("3 msecs", TimeDelta(milliseconds=3)),
+            ("3 microseconds", TimeDelta(microseconds=3)),
+            ("3 µs", TimeDelta(microseconds=3)),
+            ("7 nanosecond", TimeDelta(nanoseconds=7)),
+            ("1.5hrs", TimeDelta(minutes=90)),
+            ("  - 5 minutes ", TimeDelta(minutes=-5)),
+            ("+5m", TimeDelta(minutes=5)),
+            ("0", TimeDelta()),
+            # Go-style syntax is accepted too
+            ("1h30m", TimeDelta(hours=1, minutes=30)),
+            ("-2.5s", TimeDelta(seconds=-2.5)),
+        ],
+    )
+    def test_valid(self, s, expected):
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            assert TimeDelta.parse_human(s) == expected
+
+    @pytest.mark.parametrize(
+        "s, expected",
+        [
+            ("1 day 3 hours", TimeDelta(hours=27)),
+            ("2 Weeks", TimeDelta(hours=336)),
+            ("1.5d", TimeDelta(hours=36)),
+            ("1 wk, 1 day", TimeDelta(hours=192)),
+        ],
+    )
+    def test_days_and_weeks(self, s, expected):
+        with pytest.warns(DaysAssumed24HoursWarning):
+            assert TimeDelta.parse_human(s) == expected
+
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            assert (
+                TimeDelta.parse_human(s, days_assumed_24h_ok=True) == expected
+            )
+
+    def test_days_warning_stacklevel(self):
+        with pytest.warns(DaysAssumed24HoursWarning) as w:
+            TimeDelta.parse_human("1 day")
+        assert w[0].filename == __file__
+
+    @pytest.mark.parametrize(
+        "s",
+        [
+            "",
+            " ",
+            "1",  # missing unit
+            "hour",
+            "1 hour 30",
+            "1 hours,",
+            "1 hour and",
+            "and 1 hour",
+            "1 hand",
+            "1 hour, , 2 min",
+            "1 lightyear",
+            "1 microsecondss",
+            "1 hour -30 min",
+            "1e3 s",
+        ],
+    )
+    def test_invalid(self, s):
+        with pytest.raises(ParseError, match=re.escape(repr(s))) as e:
+            TimeDelta.parse_human(s)
+        assert e.value.input == s
+
+    def test_out_of_range(self):
+        with pytest.raises(ParseError, match="range"):
+            TimeDelta.parse_human(f"{MAX_HOURS + 1} hours")
+
+    def test_invalid_args(self):
+        with pytest.raises(TypeError, match="str"):
+            TimeDelta.parse_human(1)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            TimeDelta.parse_human("1h", foo=1)  # type: ignore[call-arg]
+
+
 class TestAddSubtract:
     @pytest.mark.parametrize(
         "kwargs, expected",
@@ -1862,6 +2245,211 @@ def test_compatible_unpickle():
     )
 
 
+class TestHumanize:
+    @pytest.mark.parametrize(
+        "delta, expect",
+        [
+            (TimeDelta(), "now"),
+            (TimeDelta(nanoseconds=999_999_999), "now"),
+            (TimeDelta(seconds=1), "in 1 second"),
+            (TimeDelta(seconds=-59), "59 seconds ago"),
+            (TimeDelta(minutes=1, seconds=59), "in 1 minute"),
+            (TimeDelta(hours=-3, minutes=-40), "3 hours ago"),
+            (TimeDelta(hours=23, minutes=59), "in 23 hours"),
+        ],
+    )
+    def test_default(self, delta, expect):
+        assert delta.humanize() == expect
+
+    def test_days_and_weeks(self):
+        with pytest.warns(DaysAssumed24HoursWarning):
+            assert TimeDelta(hours=47).humanize() == "in 1 day"
+        with pytest.warns(DaysAssumed24HoursWarning):
+            assert TimeDelta(hours=-24 * 400).humanize() == "57 weeks ago"
+        with warnings.catch_warnings():
+            warnings.simplefilter("error")
+            assert (
+                TimeDelta(hours=24 * 13).humanize(days_assumed_24h_ok=True)
+                == "in 1 week"
+            )
+
+    @pytest.mark.parametrize(
+        "mode, expect",
+        [
+            ("floor", ("3 minutes ago", "in 2 minutes")),
+            ("ceil", ("2 minutes ago", "in 3 minutes")),
+            ("trunc", ("2 minutes ago", "in 2 minutes")),
+            ("expand", ("3 minutes ago", "in 3 minutes")),
+            ("half_floor", ("3 minutes ago", "in 2 minutes")),
+            ("half_ceil", ("2 minutes ago", "in 3 minutes")),
+            ("half_even", ("2 minutes ago", "in 2 minutes")),
+            ("half_trunc", ("2 minutes ago", "in 2 minutes")),
+            ("half_expand", ("3 minutes ago", "in 3 minutes")),
+        ],
+    )
+    def test_round_mode(self, mode, expect):
+        d = TimeDelta(minutes=2, seconds=30)
+        assert (-d).humanize(round_mode=mode) == expect[0]
+        assert d.humanize(round_mode=mode) == expect[1]
+
+    def test_rounding_promotes_unit(self):
+        assert (
+            TimeDelta(minutes=59, seconds=30).humanize(round_mode="half_even")
+            == "in 1 hour"
+        )
+        assert (
+            TimeDelta(seconds=-59.5).humanize(round_mode="expand")
+            == "1 minute ago"
+        )
+
+    def test_granularity(self):
+        d = TimeDelta(minutes=40)
+        assert d.humanize(granularity="minutes") == "in 40 minutes"
+        assert d.humanize(granularity="hours") == "now"
+        assert (
+            d.humanize(granularity="hours", round_mode="half_even")
+            == "in 1 hour"
+        )
+        with pytest.raises(ValueError, match="granularity"):
+            d.humanize(granularity="months")
+        with pytest.raises(ValueError, match="granularity"):
+            d.humanize(granularity="hour")  # type: ignore[arg-type]
+
+    @pytest.mark.parametrize(
+        "locale, delta, expect",
+        [
+            ("de", TimeDelta(minutes=5), "in 5 Minuten"),
+            ("fr", TimeDelta(hours=-1), "il y a 1 heure"),
+            ("ru", TimeDelta(minutes=-21), "21 минуту назад"),
+            ("ru", TimeDelta(minutes=-22), "22 минуты назад"),
+            ("ru", TimeDelta(minutes=-25), "25 минут назад"),
+            ("pl", TimeDelta(hours=12), "za 12 godzin"),
+            ("pl", TimeDelta(hours=22), "za 22 godziny"),
+            ("cs", TimeDelta(seconds=3), "za 3 sekundy"),
+            ("ja", TimeDelta(seconds=-10), "10 秒前"),
+            ("ko", TimeDelta(hours=2), "2시간 후"),
+            ("es-MX", TimeDelta(), "ahora"),
+        ],
+    )
+    def test_locale(self, locale, delta, expect):
+        assert delta.humanize(locale=locale) == expect
+
+    def test_invalid(self):
+        d = TimeDelta(hours=1)
+        with pytest.raises(ValueError, match="round_mode"):
+            d.humanize(round_mode="up")  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="locale"):
+            d.humanize(locale="xx")
+        with pytest.raises(TypeError):
+            d.humanize("seconds")  # type: ignore[misc]
+        with pytest.raises(TypeError):
+            d.humanize(relative_to=d)  # type: ignore[call-arg]
+
+
+_UNITS = ("years", "months", "weeks", "days", "hours", "minutes", "seconds")
+
+
+def _register_esperanto(tag="eo", **kwargs):
+    register_humanize_locale(
+        tag,
+        **{
+            "now": "nun",
+            "future": {
+                u: ("post {0} " + u[:-1], "post {0} " + u) for u in _UNITS
+            },
+            "past": {u: "antaŭ {0} " + u for u in _UNITS},
+            "yesterday": "hieraŭ",
+            "tomorrow": "morgaŭ",
+            "at_time": "{0} je {1}",
+            **kwargs,
+        },
+    )
+
+
+class TestRegisterHumanizeLocale:
+    @pytest.fixture
+    def restore_nl(self):
+        yield
+        rt = get_locale("nl").relative
+        register_humanize_locale(
+            "nl",
+            now=rt.now,
+            future={u: (p[0], p[2]) for u, p in zip(_UNITS, rt.future)},
+            past={u: (p[0], p[2]) for u, p in zip(_UNITS, rt.past)},
+            yesterday=rt.yesterday,
+            tomorrow=rt.tomorrow,
+            at_time=rt.at_time,
+        )
+
+    def test_docstring_example(self):
+        finder = doctest.DocTestFinder()
+        runner = doctest.DocTestRunner()
+        globs = {
+            "register_humanize_locale": register_humanize_locale,
+            "TimeDelta": TimeDelta,
+        }
+        [test] = finder.find(register_humanize_locale, globs=globs)
+        assert test.examples
+        assert runner.run(test).failed == 0
+
+    def test_new_language(self):
+        _register_esperanto()
+        assert TimeDelta(hours=3).humanize(locale="eo") == "post 3 hours"
+        assert TimeDelta(hours=1).humanize(locale="eo") == "post 1 hour"
+        assert TimeDelta(minutes=-1).humanize(locale="eo") == "antaŭ 1 minutes"
+        assert TimeDelta().humanize(locale="eo") == "nun"
+        # only the language subtag is considered
+        assert TimeDelta(seconds=2).humanize(locale="EO_x") == "post 2 seconds"
+
+    def test_plural_rules(self):
+        _register_esperanto(
+            plural="polish",
+            future={u: ("1 " + u, "few {0}", "many {0}") for u in _UNITS},
+        )
+        assert TimeDelta(hours=1).humanize(locale="eo") == "1 hours"
+        assert TimeDelta(hours=22).humanize(locale="eo") == "few 22"
+        assert TimeDelta(hours=12).humanize(locale="eo") == "many 12"
+        _register_esperanto(
+            plural="other", future={u: "+{0}" for u in _UNITS}
+        )
+        assert TimeDelta(hours=1).humanize(locale="eo") == "+1"
+
+    def test_replaces_builtin(self, restore_nl):
+        assert TimeDelta(hours=2).humanize(locale="nl") == "over 2 uur"
+        register_humanize_locale(
+            "nl",
+            now="nu",
+            future={u: "straks" for u in _UNITS},
+            past={u: "eerder" for u in _UNITS},
+            yesterday="gisteren",
+            tomorrow="morgen",
+            at_time="{0} om {1}",
+        )
+        assert TimeDelta(hours=2).humanize(locale="nl-BE") == "straks"
+        # patterns are unaffected
+        assert (
+            ZonedDateTime(2024, 3, 9, tz="UTC").format("MMMM", locale="nl")
+            == "maart"
+        )
+
+    def test_invalid(self):
+        with pytest.raises(ValueError, match="locale"):
+            _register_esperanto("e")
+        with pytest.raises(ValueError, match="locale"):
+            _register_esperanto("1x")
+        with pytest.raises(TypeError, match="locale"):
+            _register_esperanto(5)
+        with pytest.raises(ValueError, match="plural"):
+            _register_esperanto(plural="dual")
+        with pytest.raises(ValueError, match="units"):
+            _register_esperanto(past={"hours": "antaŭ {0} horoj"})
+        with pytest.raises(ValueError, match="2 phrase"):
+            _register_esperanto(past={u: ("a", "b", "c") for u in _UNITS})
+        with pytest.raises(TypeError, match="string"):
+            _register_esperanto(now=None)
+
+
+
 class TestAssume24hDaysKwarg:
     def test_init(self):
         with warnings.catch_warnings():
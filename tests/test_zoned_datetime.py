@@ -1,3 +1,4 @@
+import doctest
 import pickle
 import re
 from copy import copy, deepcopy
@@ -24,6 +25,7 @@ from whenever import (
     ItemizedDateDelta,
     ItemizedDelta,
     OffsetDateTime,
+    OffsetPeriod,
     PlainDateTime,
     RepeatedTime,
     SkippedTime,
@@ -36,10 +38,15 @@ from whenever import (
     available_timezones,
     clear_tzcache,
     days,
+    derive_tz,
     hours,
     milliseconds,
     minutes,
+    patch_current_time,
+    register_humanize_locale,
+    remove_derived_tz,
     reset_tzpath,
+    seconds,
     weeks,
     years,
 )
@@ -749,6 +756,36 @@ class TestReplaceTime:
             d2.replace_time(Time(23), disambiguate="compatible")
 
 
+class TestFormatRFC2822:
+    def test_default(self):
+        d = ZonedDateTime(2020, 8, 15, 23, 12, 9, tz="Europe/Paris")
+        assert d.format_rfc2822() == "Sat, 15 Aug 2020 23:12:09 +0200"
+
+    def test_zone_comment(self):
+        d = ZonedDateTime(2020, 1, 15, 9, nanosecond=1, tz="America/New_York")
+        s = d.format_rfc2822(zone_comment=True)
+        assert s == "Wed, 15 Jan 2020 09:00:00 -0500 (EST)"
+        assert OffsetDateTime.parse_rfc2822(s, strict=True) == OffsetDateTime(
+            2020, 1, 15, 9, offset=-5
+        )
+        assert d.format_rfc2822(zone_comment=False) == s[:-6]
+
+    @pytest.mark.parametrize("zone_comment", [False, True])
+    def test_strict_roundtrip(self, zone_comment):
+        d = ZonedDateTime(2024, 7, 1, 12, 30, 5, tz="Europe/Amsterdam")
+        s = d.format_rfc2822(zone_comment=zone_comment)
+        assert OffsetDateTime.parse_rfc2822(s, strict=True).exact_eq(
+            d.to_fixed_offset()
+        )
+
+    def test_invalid_args(self):
+        d = ZonedDateTime(2020, 1, 15, tz="Europe/Paris")
+        with pytest.raises(TypeError, match="zone_comment"):
+            d.format_rfc2822(zone_comment=1)  # type: ignore[arg-type]
+        with pytest.raises(TypeError):
+            d.format_rfc2822(True)  # type: ignore[misc]
+
+
 class TestFormatIso:
     @pytest.mark.parametrize(
         "d, expected",
@@ -2159,6 +2196,122 @@ class TestTzAbbrev:
         assert d.tz_abbrev() == py_dt.tzname()
 
 
+class TestOffsetPeriod:
+    @pytest.mark.parametrize(
+        "tz",
+        ["Europe/Amsterdam", AMS_TZ_POSIX, AMS_TZ_RAWFILE],
+    )
+    def test_summer(self, tz: str):
+        d = create_zdt(2024, 7, 1, 12, tz=tz)
+        p = d.offset_period()
+        assert type(p) is OffsetPeriod
+        assert p.start == Instant.from_utc(2024, 3, 31, 1)
+        assert p.end == Instant.from_utc(2024, 10, 27, 1)
+        assert p.offset == hours(2)
+        assert p.std_offset == hours(1)
+        assert p.dst_offset == hours(1)
+        assert p.is_dst
+        assert p.abbrev == "CEST"
+        assert p.observes_dst
+
+    @pytest.mark.parametrize(
+        "tz",
+        ["Europe/Amsterdam", AMS_TZ_POSIX, AMS_TZ_RAWFILE],
+    )
+    def test_winter(self, tz: str):
+        d = create_zdt(2024, 1, 15, tz=tz)
+        p = d.offset_period()
+        assert p.start == Instant.from_utc(2023, 10, 29, 1)
+        assert p.end == Instant.from_utc(2024, 3, 31, 1)
+        assert p.offset == hours(1)
+        assert p.std_offset == hours(1)
+        assert p.dst_offset == TimeDelta.ZERO
+        assert not p.is_dst
+        assert p.abbrev == "CET"
+        assert p.observes_dst
+
+    def test_bounds(self):
+        start = Instant.from_utc(2024, 3, 31, 1)
+        end = Instant.from_utc(2024, 10, 27, 1)
+        tz = "Europe/Amsterdam"
+        # A transition exactly at the instant starts the period
+        assert start.to_tz(tz).offset_period().start == start
+        assert (end - seconds(1)).to_tz(tz).offset_period().end == end
+        assert end.to_tz(tz).offset_period().start == end
+        # Both sides of a fold are in different periods
+        d = ZonedDateTime(2024, 10, 27, 2, 30, tz=tz, disambiguate="earlier")
+        assert d.offset_period().end == end
+        assert d.replace(disambiguate="later").offset_period().start == end
+
+    def test_consistent_with_transitions(self):
+        d = create_zdt(2024, 7, 1, tz="America/New_York")
+        p = d.offset_period()
+        prev = d.prev_transition()
+        next = d.next_transition()
+        assert prev is not None and next is not None
+        assert p.start == prev.to_instant()
+        assert p.end == next.to_instant()
+
+    def test_no_transitions(self):
+        d = create_zdt(2024, 7, 1, tz="UTC")
+        p = d.offset_period()
+        assert p.start is None
+        assert p.end is None
+        assert p.offset == TimeDelta.ZERO
+        assert p.std_offset == TimeDelta.ZERO
+        assert not p.is_dst
+        assert p.abbrev == "UTC"
+        assert not p.observes_dst
+
+    def test_no_further_transitions(self):
+        p = create_zdt(2024, 7, 1, tz="Asia/Tokyo").offset_period()
+        assert p.start is not None
+        assert p.start < Instant.from_utc(1952, 1, 1)
+        assert p.end is None
+        assert p.abbrev == "JST"
+        assert p.offset == hours(9)
+        assert not p.observes_dst
+
+    def test_observes_dst(self):
+        assert not (
+            create_zdt(2024, 7, 1, tz="America/Phoenix")
+            .offset_period()
+            .observes_dst
+        )
+        # Ends with DST in 2016, but not anymore in 2017
+        assert (
+            create_zdt(2016, 1, 1, tz="America/Sao_Paulo")
+            .offset_period()
+            .observes_dst
+        )
+        p = create_zdt(2024, 1, 1, tz="America/Sao_Paulo").offset_period()
+        assert not p.observes_dst
+        assert p.end is None
+
+    def test_negative_dst(self):
+        p = create_zdt(2024, 1, 15, tz="Europe/Dublin").offset_period()
+        assert p.abbrev == "GMT"
+        assert p.offset == TimeDelta.ZERO
+        assert p.std_offset == hours(1)
+        assert p.dst_offset == hours(-1)
+        assert p.is_dst
+        assert p.observes_dst
+
+    def test_equality(self):
+        p = create_zdt(2024, 7, 1, tz="Europe/Amsterdam").offset_period()
+        same = create_zdt(2024, 8, 1, tz="Europe/Amsterdam").offset_period()
+        other = create_zdt(2024, 12, 1, tz="Europe/Amsterdam").offset_period()
+        assert p == same
+        assert hash(p) == hash(same)
+        assert p != other
+        assert p != NeverEqual()
+        assert "CEST" in repr(p)
+
+    def test_cannot_instantiate(self):
+        with pytest.raises(TypeError):
+            OffsetPeriod()
+
+
 class TestDayLength:
     @pytest.mark.parametrize(
         "d, expect",
@@ -2729,6 +2882,15 @@ class TestParseIso:
                     2020, 8, 15, 12, 34, 59, nanosecond=500_000_000, tz="UTC"
                 ),
             ),
+            # week and ordinal dates
+            (
+                "2020-W33-6T12:08:30+02:00[Europe/Amsterdam]",
+                ZonedDateTime(2020, 8, 15, 12, 8, 30, tz="Europe/Amsterdam"),
+            ),
+            (
+                "2020228T12:08:30[Europe/Amsterdam]",
+                ZonedDateTime(2020, 8, 15, 12, 8, 30, tz="Europe/Amsterdam"),
+            ),
         ],
     )
     def test_valid(self, s, expect):
@@ -3070,6 +3232,13 @@ class TestFromTimestamp:
             )
         )
 
+        # a tiny negative fraction doesn't round up to a whole second
+        assert ZonedDateTime.from_timestamp(-1e-20, tz="UTC").exact_eq(
+            ZonedDateTime(
+                1969, 12, 31, 23, 59, 59, nanosecond=999_999_999, tz="UTC"
+            )
+        )
+
         with pytest.raises((ValueError, OverflowError)):
             ZonedDateTime.from_timestamp(9e200, tz="America/New_York")
 
@@ -5390,6 +5559,164 @@ class TestRound:
             d.round(TimeDelta(hours=1), increment=2)  # type: ignore[call-overload]
 
 
+class TestHumanize:
+    @pytest.mark.parametrize(
+        "kwargs, expect",
+        [
+            (dict(), "now"),
+            (dict(seconds=59), "in 59 seconds"),
+            (dict(hours=-5, minutes=-59), "5 hours ago"),
+            (dict(days=1, hours=-1), "in 22 hours"),
+            # only 23 hours due to DST, but still one calendar day
+            (dict(days=1), "in 1 day"),
+            (dict(days=6), "in 6 days"),
+            (dict(days=-13), "1 week ago"),
+            (dict(months=1, days=-1), "in 4 weeks"),
+            (dict(months=2, days=20), "in 2 months"),
+            (dict(years=-3, months=-11), "3 years ago"),
+        ],
+    )
+    def test_default(self, kwargs, expect):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        assert d.add(**kwargs).humanize(relative_to=d) == expect
+
+    def test_round_mode(self):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        assert (
+            d.add(months=11, days=20).humanize(
+                relative_to=d, round_mode="half_even"
+            )
+            == "in 1 year"
+        )
+        assert (
+            d.add(days=-6, hours=-13).humanize(
+                relative_to=d, round_mode="half_expand"
+            )
+            == "1 week ago"
+        )
+        # no promotion to days, since calendar days aren't always 24 hours
+        d2 = ZonedDateTime(2024, 3, 1, 12, tz="America/New_York")
+        assert (
+            d2.add(hours=23, minutes=40).humanize(
+                relative_to=d2, round_mode="half_expand"
+            )
+            == "in 24 hours"
+        )
+
+    def test_granularity(self):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        assert d.add(days=13).humanize(relative_to=d) == "in 1 week"
+        assert (
+            d.add(days=13).humanize(relative_to=d, granularity="months")
+            == "now"
+        )
+        assert (
+            d.add(days=20).humanize(
+                relative_to=d, granularity="months", round_mode="half_even"
+            )
+            == "in 1 month"
+        )
+
+    def test_locale(self):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        assert (
+            d.add(years=3, months=11).humanize(relative_to=d, locale="pl")
+            == "za 3 lata"
+        )
+        assert (
+            d.add(months=-5).humanize(relative_to=d, locale="zh")
+            == "5个月前"
+        )
+
+    def test_timezone_of_relative_to(self):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        other = d.add(days=1).to_tz("Europe/Amsterdam")
+        # a day in New York, but not in Amsterdam
+        assert other.humanize(relative_to=d) == "in 1 day"
+        assert (
+            d.add(days=1).humanize(relative_to=d.to_tz("Europe/Amsterdam"))
+            == "in 23 hours"
+        )
+
+    def test_default_now(self):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        with patch_current_time(d.add(months=3), keep_ticking=False):
+            assert d.humanize() == "3 months ago"
+
+    @pytest.mark.parametrize(
+        "kwargs, locale, expect",
+        [
+            (dict(hours=-20), None, "yesterday at 16:00"),
+            (dict(hours=-12, minutes=-1), "en", "yesterday at 23:59"),
+            (dict(hours=-12), "en", "12 hours ago"),
+            # only 23 hours due to DST, but still the next day
+            (dict(days=1, minutes=5), "de", "morgen um 12:05"),
+            (dict(days=2, hours=-23), "ja", "明日 13:00"),
+            (dict(days=-2), "en", "2 days ago"),
+            (dict(), "en", "now"),
+        ],
+    )
+    def test_relative_day(self, kwargs, locale, expect):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        assert (
+            d.add(**kwargs).humanize(
+                relative_to=d, locale=locale, relative_day=True
+            )
+            == expect
+        )
+        # without the option, the day isn't considered
+        assert not d.add(**kwargs).humanize(relative_to=d).startswith(
+            ("yesterday", "morgen", "明日")
+        )
+
+    def test_relative_day_timezone(self):
+        d = ZonedDateTime(2024, 3, 9, 23, 30, tz="Europe/Amsterdam")
+        ny = d.to_tz("America/New_York")
+        # the day and time are those in the timezone of `relative_to`
+        assert (
+            d.add(hours=1).humanize(relative_to=d, relative_day=True)
+            == "tomorrow at 00:30"
+        )
+        assert (
+            d.add(hours=1).humanize(relative_to=ny, relative_day=True)
+            == "in 1 hour"
+        )
+        assert (
+            ny.humanize(relative_to=d.add(hours=1), relative_day=True)
+            == "yesterday at 23:30"
+        )
+
+    def test_relative_day_registered_locale(self):
+        units = ("years", "months", "weeks", "days", "hours", "minutes")
+        register_humanize_locale(
+            "eo",
+            now="nun",
+            future={u: "post {0} " + u for u in (*units, "seconds")},
+            past={u: "antaŭ {0} " + u for u in (*units, "seconds")},
+            yesterday="hieraŭ",
+            tomorrow="morgaŭ",
+            at_time="{0} je {1}",
+        )
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        assert (
+            d.add(hours=-13).humanize(
+                relative_to=d, locale="eo", relative_day=True
+            )
+            == "hieraŭ je 23:00"
+        )
+        assert (
+            d.add(days=-3).humanize(relative_to=d, locale="eo")
+            == "antaŭ 3 days"
+        )
+
+    def test_invalid(self):
+        d = ZonedDateTime(2024, 3, 9, 12, tz="America/New_York")
+        with pytest.raises(TypeError, match="ZonedDateTime"):
+            d.humanize(relative_to=d.to_instant())  # type: ignore[arg-type]
+        with pytest.raises(ValueError, match="granularity"):
+            d.humanize(relative_to=d, granularity="decades")  # type: ignore[arg-type]
+
+
 class TestPickle:
     def test_simple(self):
         d = ZonedDateTime(
@@ -6150,3 +6477,241 @@ class TestClearTzCache:
         # Now clear that exact key — _last_tz_key should be reset
         clear_tzcache(only_keys=["US/Eastern"])
         assert store._last_tz_key is None
+
+
+class TestDeriveTz:
+    @pytest.fixture(autouse=True)
+    def _cleanup(self):
+        yield
+        for key in (
+            "Test/NoDST",
+            "Test/Other",
+            "Europe/Amsterdam",
+            "Custom/Amsterdam",
+        ):
+            remove_derived_tz(key)
+
+    def test_docstring_example(self):
+        finder = doctest.DocTestFinder()
+        runner = doctest.DocTestRunner()
+        globs = {
+            "derive_tz": derive_tz,
+            "Instant": Instant,
+            "ZonedDateTime": ZonedDateTime,
+        }
+        [test] = finder.find(derive_tz, globs=globs)
+        assert test.examples
+        assert runner.run(test).failed == 0
+
+    def test_abolish_dst(self):
+        derive_tz(
+            "Test/NoDST",
+            "Europe/Amsterdam",
+            start=Instant.from_utc(2027, 3, 1),
+            rule="CET-1",
+        )
+        # Unchanged before the start
+        d = ZonedDateTime(2026, 7, 1, 12, tz="Test/NoDST")
+        assert d.offset == hours(2)
+        assert d.tz == "Test/NoDST"
+        assert d.tz_abbrev() == "CEST"
+        assert d.to_instant() == ZonedDateTime(
+            2026, 7, 1, 12, tz="Europe/Amsterdam"
+        ).to_instant()
+        # No more DST afterwards
+        d2 = ZonedDateTime(2027, 7, 1, 12, tz="Test/NoDST")
+        assert d2.offset == hours(1)
+        assert d2.tz_abbrev() == "CET"
+        assert d2.dst_offset() == hours(0)
+        assert ZonedDateTime(2040, 7, 1, tz="Test/NoDST").offset == hours(1)
+        # The gap in March is gone
+        assert ZonedDateTime(
+            2027, 3, 28, 2, 30, tz="Test/NoDST", disambiguate="raise"
+        ).offset == hours(1)
+        # The rule start is reported as a transition
+        assert ZonedDateTime(
+            2027, 1, 1, tz="Test/NoDST"
+        ).next_transition() == ZonedDateTime(2027, 3, 1, 1, tz="Test/NoDST")
+        assert (
+            ZonedDateTime(2027, 3, 2, tz="Test/NoDST").next_transition()
+            is None
+        )
+
+    def test_other_apis_accept_key(self):
+        derive_tz(
+            "Test/NoDST",
+            "Europe/Amsterdam",
+            start=ZonedDateTime(2027, 3, 1, tz="Europe/Amsterdam"),
+            rule="CET-1",
+        )
+        i = Instant.from_utc(2027, 7, 1)
+        assert i.to_tz("Test/NoDST").offset == hours(1)
+        assert PlainDateTime(2027, 7, 1).assume_tz("Test/NoDST").offset == (
+            hours(1)
+        )
+        d = ZonedDateTime.parse_iso("2027-07-01T12:00+01:00[Test/NoDST]")
+        assert d.offset == hours(1)
+        assert str(d) == "2027-07-01T12:00:00+01:00[Test/NoDST]"
+        assert pickle.loads(pickle.dumps(d)).exact_eq(d)
+
+    def test_fixed_offset(self):
+        derive_tz(
+            "Test/Other",
+            "America/New_York",
+            start=Instant.from_utc(2030, 1, 1),
+            rule=hours(-4) - minutes(30),
+        )
+        d = ZonedDateTime(2030, 12, 1, tz="Test/Other")
+        assert d.offset == hours(-4) - minutes(30)
+        assert d.tz_abbrev() == "-0430"
+        # the standard time offset from before still applies
+        d2 = ZonedDateTime(2029, 12, 31, 18, 59, tz="Test/Other")
+        assert d2.offset == hours(-5)
+
+    def test_new_dst_rule(self):
+        derive_tz(
+            "Test/Other",
+            "Asia/Tokyo",
+            start=Instant.from_utc(2030, 1, 1),
+            rule="JST-9JDT,M4.1.0,M10.1.0",
+        )
+        assert ZonedDateTime(2029, 7, 1, tz="Test/Other").offset == hours(9)
+        d = ZonedDateTime(2031, 7, 1, tz="Test/Other")
+        assert d.offset == hours(10)
+        assert d.tz_abbrev() == "JDT"
+        assert d.dst_offset() == hours(1)
+        with pytest.raises(SkippedTime):
+            ZonedDateTime(
+                2031, 4, 6, 2, 30, tz="Test/Other", disambiguate="raise"
+            )
+
+    def test_start_in_standard_time_of_rule(self):
+        start = Instant.from_utc(1990, 1, 1)
+        derive_tz(
+            "Test/Other",
+            "Europe/Amsterdam",
+            start=start,
+            rule="XST-3XDT,M4.1.0,M9.1.0",
+        )
+        d = ZonedDateTime(1990, 2, 1, tz="Test/Other")
+        # The rule's transition in September 1989 never happened in this zone
+        assert d.prev_transition() == start.to_tz("Test/Other")
+        assert d.offset_period().start == start
+        assert d.offset_period().end == Instant.from_utc(1990, 3, 31, 23)
+        assert ZonedDateTime(
+            1990, 5, 1, tz="Test/Other"
+        ).prev_transition() == ZonedDateTime(1990, 4, 1, 3, tz="Test/Other")
+
+    def test_shadow_existing_key(self):
+        before = ZonedDateTime(2027, 7, 1, tz="Europe/Amsterdam")
+        derive_tz(
+            "Europe/Amsterdam",
+            "Europe/Amsterdam",
+            start=Instant.from_utc(2027, 3, 1),
+            rule="CET-1",
+        )
+        after = ZonedDateTime(2027, 7, 1, tz="Europe/Amsterdam")
+        assert after.offset == hours(1)
+        # existing instances are unaffected
+        assert before.offset == hours(2)
+
+        assert remove_derived_tz("Europe/Amsterdam")
+        assert not remove_derived_tz("Europe/Amsterdam")
+        assert ZonedDateTime(2027, 7, 1, tz="Europe/Amsterdam").offset == (
+            hours(2)
+        )
+
+    def test_derive_from_derived(self):
+        derive_tz(
+            "Test/NoDST",
+            "Europe/Amsterdam",
+            start=Instant.from_utc(2027, 3, 1),
+            rule="CET-1",
+        )
+        derive_tz(
+            "Test/Other",
+            "Test/NoDST",
+            start=Instant.from_utc(2030, 3, 1),
+            rule="<+02>-2",
+        )
+        assert ZonedDateTime(2026, 7, 1, tz="Test/Other").offset == hours(2)
+        assert ZonedDateTime(2028, 7, 1, tz="Test/Other").offset == hours(1)
+        assert ZonedDateTime(2031, 1, 1, tz="Test/Other").offset == hours(2)
+
+    def test_replace_derived(self):
+        for offset in (1, 2):
+            derive_tz(
+                "Test/Other",
+                "UTC",
+                start=Instant.from_utc(2000, 1, 1),
+                rule=hours(offset),
+            )
+            assert ZonedDateTime(
+                2020, 1, 1, tz="Test/Other"
+            ).offset == hours(offset)
+
+    def test_removed_key_not_found(self):
+        derive_tz(
+            "Test/Other",
+            "UTC",
+            start=Instant.from_utc(2000, 1, 1),
+            rule="<+02>-2",
+        )
+        ZonedDateTime(2020, 1, 1, tz="Test/Other")
+        remove_derived_tz("Test/Other")
+        with pytest.raises(TimeZoneNotFoundError):
+            ZonedDateTime(2020, 1, 1, tz="Test/Other")
+
+    @pytest.mark.parametrize(
+        "key, base, rule, exc",
+        [
+            ("Test/Other", "Not/AZone", "CET-1", TimeZoneNotFoundError),
+            ("../etc", "UTC", "CET-1", ValueError),
+            ("", "UTC", "CET-1", ValueError),
+            ("Test/Other", "UTC", "CET", ValueError),
+            ("Test/Other", "UTC", "CET-1CEST", ValueError),
+            ("Test/Other", "UTC", milliseconds(1), ValueError),
+            ("Test/Other", "UTC", hours(25), ValueError),
+        ],
+    )
+    def test_invalid(self, key, base, rule, exc):
+        with pytest.raises(exc):
+            derive_tz(key, base, start=Instant.from_utc(2000, 1, 1), rule=rule)
+
+    @pytest.mark.parametrize(
+        "key, rule, msg",
+        [
+            ("Custom/Amsterdam~nodst", "CET-1", "Invalid time zone key: '"),
+            ("../etc", "CET-1", "Invalid time zone key: '../etc'"),
+            ("", "CET-1", "Invalid time zone key: ''"),
+            ("Test/Other", "CET", "Invalid POSIX TZ string: 'CET'"),
+            ("Test/Other", "<>1", "Invalid POSIX TZ string: '<>1'"),
+            ("Test/Other", "A1:60", "Invalid POSIX TZ string: 'A1:60'"),
+            (
+                "Test/Other",
+                "CET-1CEST,M3.5.0/x,M10.5.0",
+                "Invalid POSIX TZ string: 'CET-1CEST,M3.5.0/x,M10.5.0'",
+            ),
+        ],
+    )
+    def test_invalid_message(self, key, rule, msg):
+        with pytest.raises(ValueError, match=re.escape(msg)) as exc_info:
+            derive_tz(
+                key, "UTC", start=Instant.from_utc(2000, 1, 1), rule=rule
+            )
+        # Not a lookup failure, but a bad argument
+        assert type(exc_info.value) is ValueError
+
+    @pytest.mark.parametrize(
+        "base", ["UTC", "America/New_York", "Asia/Kolkata"]
+    )
+    def test_start_at_extremes(self, base):
+        for start in (Instant.MIN, Instant.MAX):
+            derive_tz(
+                "Test/Other", base, start=start, rule="EST5EDT,M3.2.0,M11.1.0"
+            )
+            assert ZonedDateTime(1, 1, 2, tz="Test/Other").offset == (
+                ZonedDateTime(1, 1, 2, tz=base).offset
+                if start == Instant.MAX
+                else hours(-5)
+            )